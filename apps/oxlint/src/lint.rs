@@ -799,6 +799,12 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn lint_vue_template() {
+        let args = &["--vue-plugin", "fixtures/vue/template.vue"];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn lint_empty_vue_file() {
         let args = &["fixtures/vue/empty.vue"];
@@ -817,6 +823,18 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn lint_markdown_file() {
+        let args = &["fixtures/markdown/debugger.md", "fixtures/markdown/debugger.mdx"];
+        Tester::new().test_and_snapshot(args);
+    }
+
+    #[test]
+    fn lint_html_file() {
+        let args = &["fixtures/html/debugger.html"];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn test_tsconfig_option() {
         // passed
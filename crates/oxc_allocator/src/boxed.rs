@@ -110,6 +110,14 @@ impl<T: ?Sized> Box<'_, T> {
     pub fn into_non_null(boxed: Self) -> NonNull<T> {
         boxed.0
     }
+
+    /// Get a mutable reference to the [`NonNull`] pointer to this [`Box`]'s contents.
+    ///
+    /// Only used by [`Relocate`](crate::Relocate), to rebase the pointer.
+    #[inline]
+    pub(crate) fn non_null_mut(&mut self) -> &mut NonNull<T> {
+        &mut self.0
+    }
 }
 
 impl<T: ?Sized> ops::Deref for Box<'_, T> {
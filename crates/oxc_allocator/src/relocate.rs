@@ -0,0 +1,444 @@
+//! Relocatable images of arena contents.
+//!
+//! An image is a copy of a memory chunk of an [`Allocator`], plus the address of a root object
+//! within it. [`Allocator::write_image`] creates an image, and [`Allocator::read_image`] loads it into
+//! another [`Allocator`] (possibly in another process). Loading copies the data into the new arena,
+//! and then rebases all pointers within it using the [`Relocate`] trait.
+//!
+//! Image format (all integers are `u64`, little-endian):
+//!
+//! ```text
+//! original address of data
+//! length of data in bytes
+//! offset of root within data
+//! checksum of data
+//! data
+//! ```
+//!
+//! Images contain raw memory, so are only valid for types which have exactly the same memory layout
+//! as when the image was created. It's the caller's responsibility to check that an image was created
+//! by a compatible build (e.g. by comparing a hash of all type layouts).
+
+use std::{alloc::Layout, fmt, hash::Hasher, mem, ptr::NonNull, slice, str};
+
+use bumpalo::Bump;
+use rustc_hash::FxHasher;
+
+use crate::{Allocator, Box, CloneIn, Vec};
+
+/// Size of image header (address + length + root offset + checksum).
+const HEADER_SIZE: usize = 32;
+
+/// Alignment of image data.
+///
+/// Start of data is rounded down to this alignment, so that all data has the same alignment
+/// relative to the start after it's copied.
+/// `bumpalo` aligns chunks on 16, so rounding down never goes outside the chunk.
+const DATA_ALIGN: usize = 16;
+
+/// Error when loading an image with [`Allocator::read_image`] which is truncated or corrupt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MalformedImage;
+
+impl fmt::Display for MalformedImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Arena image is malformed")
+    }
+}
+
+impl std::error::Error for MalformedImage {}
+
+/// Trait for types which contain pointers into an arena, which can be rebased after the arena's
+/// contents have been copied to another address.
+///
+/// Implementations for AST types are generated by `oxc_ast_tools`.
+pub trait Relocate {
+    /// Rebase all pointers in `self`, and in all data `self` points to.
+    ///
+    /// Pointers are rebased before they're followed, so on entry `self` may contain pointers
+    /// to memory which is no longer valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pointer is outside of the image, or is not aligned,
+    /// or a string is not valid UTF-8. Pointers which have not been rebased yet are not followed.
+    ///
+    /// # SAFETY
+    ///
+    /// * `self` must be within the data known to `relocator`, and all pointers in `self` must be
+    ///   original addresses, or invalid.
+    /// * `relocate` must be called only once for each object.
+    ///   Arena types (e.g. [`Box`], [`Vec`]) are not `Clone`, so this holds for any tree of objects.
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage>;
+}
+
+/// Maps original addresses of arena data to the addresses it's been copied to.
+///
+/// Created by [`Allocator::read_image`].
+pub struct Relocator<'alloc> {
+    /// Original start address
+    old_start: usize,
+    /// Original end address (exclusive)
+    old_end: usize,
+    /// Pointer to start of data after it's been copied
+    new_start: NonNull<u8>,
+    /// Allocator which relocated data has been copied into
+    allocator: &'alloc Allocator,
+}
+
+impl Relocator<'_> {
+    /// Get pointer to the copy of `count` `T`s, which were originally at `ptr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `ptr` is not aligned for `T`, or the `T`s are not within the image.
+    fn relocate_ptr<T>(&self, ptr: NonNull<T>, count: usize) -> Result<NonNull<T>, MalformedImage> {
+        let addr = ptr.as_ptr() as usize;
+        // Start of data is aligned on `DATA_ALIGN` in both old and new locations,
+        // so relocated pointer is aligned if original was
+        if addr % mem::align_of::<T>() != 0 || mem::align_of::<T>() > DATA_ALIGN {
+            return Err(MalformedImage);
+        }
+        let size = mem::size_of::<T>().checked_mul(count).ok_or(MalformedImage)?;
+        Ok(self.relocate_addr(addr, size)?.cast::<T>())
+    }
+
+    /// Get pointer to the copy of `size` bytes which were originally at `addr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `addr..addr + size` is not within the image.
+    fn relocate_addr(&self, addr: usize, size: usize) -> Result<NonNull<u8>, MalformedImage> {
+        let end = addr.checked_add(size).ok_or(MalformedImage)?;
+        if addr < self.old_start || end > self.old_end {
+            return Err(MalformedImage);
+        }
+        // SAFETY: `addr..end` is within the image data, which was copied to `new_start`
+        Ok(unsafe { self.new_start.add(addr - self.old_start) })
+    }
+}
+
+impl<T: Relocate> Relocate for Box<'_, T> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        let ptr = self.non_null_mut();
+        // Zero-sized types are not allocated in arena, so pointer is dangling
+        if mem::size_of::<T>() != 0 {
+            *ptr = relocator.relocate_ptr(*ptr, 1)?;
+        }
+        // SAFETY: Pointer has been relocated to the copy, which is within the image data
+        unsafe { ptr.as_mut().relocate(relocator) }
+    }
+}
+
+impl<'a, T: Relocate> Relocate for Vec<'a, T> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        if self.len() > self.capacity() {
+            return Err(MalformedImage);
+        }
+        // Zero-sized types and empty `Vec`s have a dangling pointer, which is not in the arena.
+        // Elements beyond `len` are uninitialized, so only the pointer and allocator are updated.
+        let mut ptr = NonNull::new(self.as_mut_ptr()).ok_or(MalformedImage)?;
+        if mem::size_of::<T>() != 0 && self.capacity() != 0 {
+            ptr = relocator.relocate_ptr(ptr, self.capacity())?;
+        }
+
+        // SAFETY: `relocator.allocator` outlives `'a`, as caller guarantees that `self` is within
+        // the copy of the image data, which was allocated in that arena.
+        // `ptr` points to a copy of the `Vec`'s contents in that arena, with room for `capacity` `T`s.
+        unsafe {
+            let bump: &'a Bump = NonNull::from(relocator.allocator.bump()).as_ref();
+            self.set_ptr_and_alloc(ptr, bump);
+        }
+
+        for element in self.iter_mut() {
+            // SAFETY: Elements are within the copy of the image data
+            unsafe { element.relocate(relocator)? };
+        }
+        Ok(())
+    }
+}
+
+impl<T: Relocate> Relocate for Option<T> {
+    #[inline]
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        match self {
+            // SAFETY: `it` is within the image data, as `self` is
+            Some(it) => unsafe { it.relocate(relocator) },
+            None => Ok(()),
+        }
+    }
+}
+
+impl Relocate for &str {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // Empty strings may not point into arena (e.g. `""` literal)
+        if self.is_empty() {
+            *self = "";
+            return Ok(());
+        }
+        let ptr =
+            relocator.relocate_ptr(NonNull::from(self.as_bytes()).cast::<u8>(), self.len())?;
+        // SAFETY: `ptr..ptr + len` is within the copy of the image data.
+        // Caller guarantees the copy lives as long as the original.
+        let bytes = unsafe { slice::from_raw_parts(ptr.as_ptr(), self.len()) };
+        *self = str::from_utf8(bytes).map_err(|_| MalformedImage)?;
+        Ok(())
+    }
+}
+
+impl Allocator {
+    /// Write an image of `root` to `out`.
+    ///
+    /// The image can be loaded into another [`Allocator`] with [`Allocator::read_image`].
+    ///
+    /// `root` and all data reachable from it is first copied with [`CloneIn::clone_in_with_semantic_ids`]
+    /// into a fresh arena, whose memory is zeroed before it's used. So the image is self-contained,
+    /// and contains no memory which has not been written to, or data which is not reachable from `root`.
+    pub fn write_image<T>(root: &T, out: &mut std::vec::Vec<u8>)
+    where
+        T: for<'new> CloneIn<'new>,
+    {
+        // Copy `root` once to find out how much memory it requires, so it can be copied into
+        // an arena with a single zeroed chunk. Alignment of the start of each chunk may differ
+        // from the single chunk by less than `DATA_ALIGN` bytes.
+        let (used_bytes, chunk_count) = {
+            let allocator = Allocator::default();
+            allocator.alloc(root.clone_in_with_semantic_ids(&allocator));
+            // SAFETY: No allocations are made while `chunks_iter` is alive. No data is read from the chunks.
+            let chunk_count = unsafe { allocator.bump().iter_allocated_chunks_raw() }.count();
+            (allocator.used_bytes(), chunk_count)
+        };
+        let allocator = zeroed_allocator(used_bytes + chunk_count * DATA_ALIGN);
+        let root = allocator.alloc(root.clone_in_with_semantic_ids(&allocator));
+
+        // SAFETY: No allocations are made in this `Allocator` while `chunks_iter` is alive
+        let mut chunks_iter = unsafe { allocator.bump().iter_allocated_chunks_raw() };
+        let Some((ptr, len)) = chunks_iter.next() else {
+            unreachable!("`root` is allocated in the arena");
+        };
+
+        // Memory before start of allocated part of chunk is zeroed too
+        let start = ptr as usize;
+        let aligned_start = start & !(DATA_ALIGN - 1);
+        let len = len + (start - aligned_start);
+        // SAFETY: `aligned_start..aligned_start + len` is within the chunk, and whole chunk was zeroed
+        // before use, so all its bytes are initialized
+        let data = unsafe { slice::from_raw_parts(aligned_start as *const u8, len) };
+
+        let root_offset = std::ptr::from_ref(root) as usize - aligned_start;
+        out.reserve(HEADER_SIZE + len);
+        write_usize(out, aligned_start);
+        write_usize(out, len);
+        write_usize(out, root_offset);
+        out.extend_from_slice(&checksum(data).to_le_bytes());
+        out.extend_from_slice(data);
+    }
+
+    /// Load an image created by [`Allocator::write_image`] into this [`Allocator`].
+    ///
+    /// Copies the image's data into this arena, relocates all pointers within it,
+    /// and returns a reference to the root object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `image` is truncated or corrupt. The image's data is checked against
+    /// the checksum in its header, and all pointers are checked to be within the image.
+    ///
+    /// # SAFETY
+    ///
+    /// `image` must have been created by [`Allocator::write_image`] with a root of type `T`,
+    /// by a build in which every type reachable from `T` has the same memory layout as in this build.
+    /// Accidental corruption is detected, but an image which has been deliberately crafted to pass
+    /// the checks can still contain invalid values (e.g. an invalid enum discriminant).
+    #[expect(clippy::mut_from_ref)]
+    pub unsafe fn read_image<T: Relocate>(&self, image: &[u8]) -> Result<&mut T, MalformedImage> {
+        let mut reader = ImageReader(image);
+        let old_start = reader.read_usize()?;
+        let data_len = reader.read_usize()?;
+        let root_offset = reader.read_usize()?;
+        let checksum_in_header = reader.read_u64()?;
+
+        let data = reader.0;
+        if data.len() != data_len
+            || data_len == 0
+            || old_start % DATA_ALIGN != 0
+            || checksum(data) != checksum_in_header
+        {
+            return Err(MalformedImage);
+        }
+        let old_end = old_start.checked_add(data_len).ok_or(MalformedImage)?;
+
+        let layout = Layout::from_size_align(data_len, DATA_ALIGN).map_err(|_| MalformedImage)?;
+        let new_start = self.alloc_layout(layout);
+        // SAFETY: `new_start` is a fresh allocation of `data_len` bytes, and `data` is `data_len` bytes
+        unsafe { new_start.as_ptr().copy_from_nonoverlapping(data.as_ptr(), data_len) };
+
+        let relocator = Relocator { old_start, old_end, new_start, allocator: self };
+        let root_addr = old_start.checked_add(root_offset).ok_or(MalformedImage)?;
+        let root_ptr = NonNull::new(root_addr as *mut T).ok_or(MalformedImage)?;
+        let mut root_ptr = relocator.relocate_ptr(root_ptr, 1)?;
+
+        // SAFETY: Caller guarantees that image was created with a root of type `T` with same layout.
+        // `root_ptr` is aligned for `T`, and within the copy of the image data.
+        let root = unsafe { root_ptr.as_mut() };
+        // SAFETY: `root` is within the copy of the image data. Caller guarantees image was created by
+        // `write_image`, so all pointers in it are original addresses, unless image is corrupt,
+        // in which case they're checked to be within the image before they're followed.
+        unsafe { root.relocate(&relocator)? };
+        Ok(root)
+    }
+}
+
+/// Create an [`Allocator`] with a single chunk of at least `capacity` bytes, which is zeroed.
+///
+/// The [`Allocator`] cannot allocate further chunks, as they would not be zeroed.
+fn zeroed_allocator(capacity: usize) -> Allocator {
+    let mut allocator = Allocator::with_capacity(capacity.max(DATA_ALIGN));
+    let chunk_capacity = allocator.bump().chunk_capacity();
+    let ptr = allocator.alloc_layout(Layout::from_size_align(chunk_capacity, 1).unwrap());
+    // SAFETY: `ptr` is an allocation of `chunk_capacity` bytes
+    unsafe { ptr.as_ptr().write_bytes(0, chunk_capacity) };
+    // Resetting keeps the current chunk, and does not modify its contents
+    allocator.reset();
+    let bump = allocator.bump();
+    bump.set_allocation_limit(Some(bump.allocated_bytes()));
+    allocator
+}
+
+/// Checksum of image data, to detect truncated or corrupt images.
+///
+/// Images are only compatible with the same build, so the hash does not need to be stable
+/// across versions.
+fn checksum(data: &[u8]) -> u64 {
+    let mut hasher = FxHasher::default();
+    hasher.write(data);
+    hasher.finish()
+}
+
+fn write_usize(out: &mut std::vec::Vec<u8>, value: usize) {
+    out.extend_from_slice(&(value as u64).to_le_bytes());
+}
+
+struct ImageReader<'b>(&'b [u8]);
+
+impl ImageReader<'_> {
+    fn read_u64(&mut self) -> Result<u64, MalformedImage> {
+        let (bytes, rest) = self.0.split_first_chunk::<8>().ok_or(MalformedImage)?;
+        self.0 = rest;
+        Ok(u64::from_le_bytes(*bytes))
+    }
+
+    fn read_usize(&mut self) -> Result<usize, MalformedImage> {
+        usize::try_from(self.read_u64()?).map_err(|_| MalformedImage)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Allocator, Box, CloneIn, Vec};
+
+    use super::{MalformedImage, Relocate, Relocator};
+
+    struct Node<'a> {
+        name: &'a str,
+        value: u32,
+        children: Vec<'a, Box<'a, Node<'a>>>,
+    }
+
+    impl<'new_alloc> CloneIn<'new_alloc> for Node<'_> {
+        type Cloned = Node<'new_alloc>;
+
+        fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+            Node {
+                name: allocator.alloc_str(self.name),
+                value: self.value,
+                children: self.children.clone_in(allocator),
+            }
+        }
+    }
+
+    impl Relocate for Node<'_> {
+        unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+            // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+            unsafe {
+                Relocate::relocate(&mut self.name, relocator)?;
+                Relocate::relocate(&mut self.children, relocator)?;
+            }
+            Ok(())
+        }
+    }
+
+    fn build(allocator: &Allocator, depth: u32) -> Node<'_> {
+        let mut children = Vec::new_in(allocator);
+        if depth > 0 {
+            for _ in 0..3 {
+                children.push(Box::new_in(build(allocator, depth - 1), allocator));
+            }
+        }
+        let name = allocator.alloc_str(&format!("node{depth}"));
+        Node { name, value: depth, children }
+    }
+
+    fn check(node: &Node, depth: u32) {
+        assert_eq!(node.name, format!("node{depth}"));
+        assert_eq!(node.value, depth);
+        assert_eq!(node.children.len(), if depth > 0 { 3 } else { 0 });
+        for child in &node.children {
+            check(child, depth - 1);
+        }
+    }
+
+    fn image(depth: u32) -> std::vec::Vec<u8> {
+        // Small capacity, to require multiple chunks
+        let allocator = Allocator::with_capacity(64);
+        let root = build(&allocator, depth);
+        let mut image = vec![];
+        Allocator::write_image(&root, &mut image);
+        image
+    }
+
+    #[test]
+    fn round_trip() {
+        let image = image(6);
+
+        let allocator = Allocator::default();
+        // SAFETY: Image was created with a root of type `Node`
+        let root = unsafe { allocator.read_image::<Node>(&image) }.unwrap();
+        check(root, 6);
+
+        // Relocated `Vec`s are usable
+        root.children.push(Box::new_in(build(&allocator, 0), &allocator));
+        assert_eq!(root.children.len(), 4);
+    }
+
+    #[test]
+    fn unreachable_data_is_not_written() {
+        let allocator = Allocator::default();
+        allocator.alloc_str("unreachable");
+        let root = build(&allocator, 1);
+        allocator.alloc_str("unreachable");
+        let mut image = vec![];
+        Allocator::write_image(&root, &mut image);
+        assert!(!image.windows(11).any(|window| window == b"unreachable"));
+    }
+
+    #[test]
+    fn malformed() {
+        let image = image(2);
+        let read = |image: &[u8]| {
+            let allocator = Allocator::default();
+            // SAFETY: Images are created by `write_image`, and may be truncated or corrupt
+            unsafe { allocator.read_image::<Node>(image) }.map(|_| ())
+        };
+
+        assert_eq!(read(&image), Ok(()));
+        assert_eq!(read(&[]), Err(MalformedImage));
+        assert_eq!(read(&[0; 32]), Err(MalformedImage));
+        assert_eq!(read(&image[..image.len() - 1]), Err(MalformedImage));
+        for index in [0, 8, 16, 24, 40, image.len() - 1] {
+            let mut corrupt = image.clone();
+            corrupt[index] ^= 1;
+            assert_eq!(read(&corrupt), Err(MalformedImage), "{index}");
+        }
+    }
+}
@@ -740,6 +740,16 @@ impl<'a, T: 'a, A: Alloc> Vec<'a, T, A> {
         self.buf.bump()
     }
 
+    /// Replace the pointer and allocator of this `Vec`, keeping length and capacity.
+    ///
+    /// # Safety
+    ///
+    /// See [`RawVec::set_ptr_and_alloc`].
+    #[inline]
+    pub(crate) unsafe fn set_ptr_and_alloc(&mut self, ptr: NonNull<T>, alloc: &'a A) {
+        unsafe { self.buf.set_ptr_and_alloc(ptr, alloc) };
+    }
+
     /// Reserves capacity for at least `additional` more elements to be inserted
     /// in the given `Vec<'a, T>`. The collection may reserve more space to avoid
     /// frequent reallocations. After calling `reserve`, capacity will be
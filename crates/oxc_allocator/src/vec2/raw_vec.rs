@@ -197,6 +197,21 @@ impl<'a, T, A: Alloc> RawVec<'a, T, A> {
         self.alloc
     }
 
+    /// Replace the pointer and allocator of this RawVec, keeping length and capacity.
+    ///
+    /// Used by [`Relocate`] when the contents of an arena have been copied to a new address.
+    ///
+    /// # SAFETY
+    ///
+    /// * `ptr` must point to an allocation in `alloc`, with the capacity of this RawVec.
+    /// * `ptr` must point to a copy of this RawVec's contents.
+    ///
+    /// [`Relocate`]: crate::Relocate
+    pub unsafe fn set_ptr_and_alloc(&mut self, ptr: NonNull<T>, alloc: &'a A) {
+        self.ptr = ptr;
+        self.alloc = alloc;
+    }
+
     fn current_layout(&self) -> Option<Layout> {
         if self.cap == 0 {
             None
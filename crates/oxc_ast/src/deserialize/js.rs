@@ -0,0 +1,1274 @@
+//! Statements, expressions, patterns, functions, classes and modules.
+
+use oxc_allocator::{Box, Vec};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_syntax::operator::{
+    AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
+};
+
+use crate::ast::*;
+
+use super::{
+    DeserializeError, ESTreeDeserializer, Result, bool_field, field, invalid_value, json::Json,
+    node_type, required_field, str_field, unexpected,
+};
+
+const ASSIGNMENT_OPERATORS: [AssignmentOperator; 16] = [
+    AssignmentOperator::Assign,
+    AssignmentOperator::Addition,
+    AssignmentOperator::Subtraction,
+    AssignmentOperator::Multiplication,
+    AssignmentOperator::Division,
+    AssignmentOperator::Remainder,
+    AssignmentOperator::Exponential,
+    AssignmentOperator::ShiftLeft,
+    AssignmentOperator::ShiftRight,
+    AssignmentOperator::ShiftRightZeroFill,
+    AssignmentOperator::BitwiseOR,
+    AssignmentOperator::BitwiseXOR,
+    AssignmentOperator::BitwiseAnd,
+    AssignmentOperator::LogicalOr,
+    AssignmentOperator::LogicalAnd,
+    AssignmentOperator::LogicalNullish,
+];
+
+const BINARY_OPERATORS: [BinaryOperator; 22] = [
+    BinaryOperator::Equality,
+    BinaryOperator::Inequality,
+    BinaryOperator::StrictEquality,
+    BinaryOperator::StrictInequality,
+    BinaryOperator::LessThan,
+    BinaryOperator::LessEqualThan,
+    BinaryOperator::GreaterThan,
+    BinaryOperator::GreaterEqualThan,
+    BinaryOperator::Addition,
+    BinaryOperator::Subtraction,
+    BinaryOperator::Multiplication,
+    BinaryOperator::Division,
+    BinaryOperator::Remainder,
+    BinaryOperator::Exponential,
+    BinaryOperator::ShiftLeft,
+    BinaryOperator::ShiftRight,
+    BinaryOperator::ShiftRightZeroFill,
+    BinaryOperator::BitwiseOR,
+    BinaryOperator::BitwiseXOR,
+    BinaryOperator::BitwiseAnd,
+    BinaryOperator::In,
+    BinaryOperator::Instanceof,
+];
+
+const LOGICAL_OPERATORS: [LogicalOperator; 3] =
+    [LogicalOperator::Or, LogicalOperator::And, LogicalOperator::Coalesce];
+
+const UNARY_OPERATORS: [UnaryOperator; 7] = [
+    UnaryOperator::UnaryPlus,
+    UnaryOperator::UnaryNegation,
+    UnaryOperator::LogicalNot,
+    UnaryOperator::BitwiseNot,
+    UnaryOperator::Typeof,
+    UnaryOperator::Void,
+    UnaryOperator::Delete,
+];
+
+const UPDATE_OPERATORS: [UpdateOperator; 2] =
+    [UpdateOperator::Increment, UpdateOperator::Decrement];
+
+/// Get operator in field `operator` of `node`, by matching against string representation
+/// of each of `operators`.
+fn operator<T: Copy>(node: &Json, operators: &[T], as_str: fn(T) -> &'static str) -> Result<T> {
+    let operator = str_field(node, "operator")?;
+    operators
+        .iter()
+        .copied()
+        .find(|&op| as_str(op) == operator)
+        .ok_or_else(|| invalid_value("operator", operator))
+}
+
+// ----------------------------------------
+// Identifiers
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    /// Span and name of an `Identifier`.
+    fn identifier(&self, node: &Json) -> Result<(Span, Atom<'a>)> {
+        let ty = node_type(node)?;
+        if ty != "Identifier" {
+            return Err(unexpected(ty, "Identifier"));
+        }
+        Ok((self.span(node), self.atom(str_field(node, "name")?)))
+    }
+
+    pub(super) fn identifier_name(&self, node: &Json) -> Result<IdentifierName<'a>> {
+        let (span, name) = self.identifier(node)?;
+        Ok(self.ast.identifier_name(span, name))
+    }
+
+    pub(super) fn identifier_reference(&self, node: &Json) -> Result<IdentifierReference<'a>> {
+        let (span, name) = self.identifier(node)?;
+        Ok(self.ast.identifier_reference(span, name))
+    }
+
+    pub(super) fn binding_identifier(&self, node: &Json) -> Result<BindingIdentifier<'a>> {
+        let (span, name) = self.identifier(node)?;
+        Ok(self.ast.binding_identifier(span, name))
+    }
+
+    fn label_identifier(&self, node: &Json) -> Result<LabelIdentifier<'a>> {
+        let (span, name) = self.identifier(node)?;
+        Ok(self.ast.label_identifier(span, name))
+    }
+
+    pub(super) fn private_identifier(&self, node: &Json) -> Result<PrivateIdentifier<'a>> {
+        let ty = node_type(node)?;
+        if ty != "PrivateIdentifier" {
+            return Err(unexpected(ty, "PrivateIdentifier"));
+        }
+        Ok(self.ast.private_identifier(self.span(node), self.atom(str_field(node, "name")?)))
+    }
+}
+
+// ----------------------------------------
+// Statements
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    pub(super) fn statement(&self, node: &Json) -> Result<Statement<'a>> {
+        let ty = node_type(node)?;
+        let span = self.span(node);
+        let statement = match ty {
+            "ExpressionStatement" => {
+                let expression = self.child(node, "expression", Self::expression)?;
+                self.ast.statement_expression(span, expression)
+            }
+            "BlockStatement" => Statement::BlockStatement(self.block_statement(node)?),
+            "EmptyStatement" => self.ast.statement_empty(span),
+            "DebuggerStatement" => self.ast.statement_debugger(span),
+            "IfStatement" => self.ast.statement_if(
+                span,
+                self.child(node, "test", Self::expression)?,
+                self.child(node, "consequent", Self::statement)?,
+                self.opt_child(node, "alternate", Self::statement)?,
+            ),
+            "DoWhileStatement" => self.ast.statement_do_while(
+                span,
+                self.child(node, "body", Self::statement)?,
+                self.child(node, "test", Self::expression)?,
+            ),
+            "WhileStatement" => self.ast.statement_while(
+                span,
+                self.child(node, "test", Self::expression)?,
+                self.child(node, "body", Self::statement)?,
+            ),
+            "ForStatement" => self.ast.statement_for(
+                span,
+                self.opt_child(node, "init", Self::for_statement_init)?,
+                self.opt_child(node, "test", Self::expression)?,
+                self.opt_child(node, "update", Self::expression)?,
+                self.child(node, "body", Self::statement)?,
+            ),
+            "ForInStatement" => self.ast.statement_for_in(
+                span,
+                self.child(node, "left", Self::for_statement_left)?,
+                self.child(node, "right", Self::expression)?,
+                self.child(node, "body", Self::statement)?,
+            ),
+            "ForOfStatement" => self.ast.statement_for_of(
+                span,
+                bool_field(node, "await"),
+                self.child(node, "left", Self::for_statement_left)?,
+                self.child(node, "right", Self::expression)?,
+                self.child(node, "body", Self::statement)?,
+            ),
+            "ContinueStatement" => self
+                .ast
+                .statement_continue(span, self.opt_child(node, "label", Self::label_identifier)?),
+            "BreakStatement" => self
+                .ast
+                .statement_break(span, self.opt_child(node, "label", Self::label_identifier)?),
+            "ReturnStatement" => {
+                self.ast.statement_return(span, self.opt_child(node, "argument", Self::expression)?)
+            }
+            "WithStatement" => self.ast.statement_with(
+                span,
+                self.child(node, "object", Self::expression)?,
+                self.child(node, "body", Self::statement)?,
+            ),
+            "SwitchStatement" => self.ast.statement_switch(
+                span,
+                self.child(node, "discriminant", Self::expression)?,
+                self.list(node, "cases", Self::switch_case)?,
+            ),
+            "LabeledStatement" => self.ast.statement_labeled(
+                span,
+                self.child(node, "label", Self::label_identifier)?,
+                self.child(node, "body", Self::statement)?,
+            ),
+            "ThrowStatement" => {
+                self.ast.statement_throw(span, self.child(node, "argument", Self::expression)?)
+            }
+            "TryStatement" => {
+                let block = self.child(node, "block", Self::block_statement)?;
+                let handler = self.opt_child(node, "handler", Self::catch_clause)?;
+                let finalizer = self.opt_child(node, "finalizer", Self::block_statement)?;
+                if handler.is_none() && finalizer.is_none() {
+                    return Err(DeserializeError::new(
+                        "`TryStatement` must have a `handler` or `finalizer`",
+                    ));
+                }
+                self.ast.statement_try(span, block, handler, finalizer)
+            }
+            "ImportDeclaration" => Statement::ImportDeclaration(self.import_declaration(node)?),
+            "ExportNamedDeclaration" => {
+                Statement::ExportNamedDeclaration(self.export_named_declaration(node)?)
+            }
+            "ExportDefaultDeclaration" => {
+                Statement::ExportDefaultDeclaration(self.export_default_declaration(node)?)
+            }
+            "ExportAllDeclaration" => {
+                Statement::ExportAllDeclaration(self.export_all_declaration(node)?)
+            }
+            "TSExportAssignment" => {
+                Statement::TSExportAssignment(self.ast.alloc_ts_export_assignment(
+                    span,
+                    self.child(node, "expression", Self::expression)?,
+                ))
+            }
+            "TSNamespaceExportDeclaration" => Statement::TSNamespaceExportDeclaration(
+                self.ast.alloc_ts_namespace_export_declaration(
+                    span,
+                    self.child(node, "id", Self::identifier_name)?,
+                ),
+            ),
+            _ => match self.declaration_of_type(node, ty)? {
+                Some(declaration) => Statement::from(declaration),
+                None => return Err(unexpected(ty, "Statement")),
+            },
+        };
+        Ok(statement)
+    }
+
+    fn declaration(&self, node: &Json) -> Result<Declaration<'a>> {
+        let ty = node_type(node)?;
+        self.declaration_of_type(node, ty)?.ok_or_else(|| unexpected(ty, "Declaration"))
+    }
+
+    /// Convert `node` to a [`Declaration`]. Returns `None` if `ty` is not a declaration type.
+    fn declaration_of_type(&self, node: &Json, ty: &str) -> Result<Option<Declaration<'a>>> {
+        let declaration = match ty {
+            "VariableDeclaration" => {
+                Declaration::VariableDeclaration(self.variable_declaration(node)?)
+            }
+            "FunctionDeclaration" | "TSDeclareFunction" => Declaration::FunctionDeclaration(
+                self.function(node, FormalParameterKind::FormalParameter)?,
+            ),
+            "ClassDeclaration" => {
+                Declaration::ClassDeclaration(self.class(node, ClassType::ClassDeclaration)?)
+            }
+            _ => return self.ts_declaration(node, ty),
+        };
+        Ok(Some(declaration))
+    }
+
+    pub(super) fn block_statement(&self, node: &Json) -> Result<Box<'a, BlockStatement<'a>>> {
+        let ty = node_type(node)?;
+        if ty != "BlockStatement" {
+            return Err(unexpected(ty, "BlockStatement"));
+        }
+        let body = self.list(node, "body", Self::statement)?;
+        Ok(self.ast.alloc_block_statement(self.span(node), body))
+    }
+
+    fn variable_declaration(&self, node: &Json) -> Result<Box<'a, VariableDeclaration<'a>>> {
+        let kind = match str_field(node, "kind")? {
+            "var" => VariableDeclarationKind::Var,
+            "let" => VariableDeclarationKind::Let,
+            "const" => VariableDeclarationKind::Const,
+            "using" => VariableDeclarationKind::Using,
+            "await using" => VariableDeclarationKind::AwaitUsing,
+            kind => return Err(invalid_value("kind", kind)),
+        };
+        let declarations = self.list(node, "declarations", |d, node| {
+            let ty = node_type(node)?;
+            if ty != "VariableDeclarator" {
+                return Err(unexpected(ty, "VariableDeclarator"));
+            }
+            Ok(d.ast.variable_declarator(
+                d.span(node),
+                kind,
+                d.child(node, "id", Self::binding_pattern)?,
+                d.opt_child(node, "init", Self::expression)?,
+                bool_field(node, "definite"),
+            ))
+        })?;
+        if declarations.is_empty() {
+            return Err(DeserializeError::new("`declarations` must not be empty"));
+        }
+        Ok(self.ast.alloc_variable_declaration(
+            self.span(node),
+            kind,
+            declarations,
+            bool_field(node, "declare"),
+        ))
+    }
+
+    fn for_statement_init(&self, node: &Json) -> Result<ForStatementInit<'a>> {
+        if node_type(node)? == "VariableDeclaration" {
+            Ok(ForStatementInit::VariableDeclaration(self.variable_declaration(node)?))
+        } else {
+            self.expression(node).map(ForStatementInit::from)
+        }
+    }
+
+    fn for_statement_left(&self, node: &Json) -> Result<ForStatementLeft<'a>> {
+        if node_type(node)? == "VariableDeclaration" {
+            Ok(ForStatementLeft::VariableDeclaration(self.variable_declaration(node)?))
+        } else {
+            self.assignment_target(node).map(ForStatementLeft::from)
+        }
+    }
+
+    fn switch_case(&self, node: &Json) -> Result<SwitchCase<'a>> {
+        let ty = node_type(node)?;
+        if ty != "SwitchCase" {
+            return Err(unexpected(ty, "SwitchCase"));
+        }
+        Ok(self.ast.switch_case(
+            self.span(node),
+            self.opt_child(node, "test", Self::expression)?,
+            self.list(node, "consequent", Self::statement)?,
+        ))
+    }
+
+    fn catch_clause(&self, node: &Json) -> Result<Box<'a, CatchClause<'a>>> {
+        let ty = node_type(node)?;
+        if ty != "CatchClause" {
+            return Err(unexpected(ty, "CatchClause"));
+        }
+        let param = self.opt_child(node, "param", |d, param| {
+            let pattern = d.binding_pattern(param)?;
+            Ok(d.ast.catch_parameter(d.span(param), pattern))
+        })?;
+        let body = self.child(node, "body", Self::block_statement)?;
+        Ok(self.ast.alloc_catch_clause(self.span(node), param, body))
+    }
+}
+
+// ----------------------------------------
+// Expressions
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    pub(super) fn expression(&self, node: &Json) -> Result<Expression<'a>> {
+        let ty = node_type(node)?;
+        let span = self.span(node);
+        let expression = match ty {
+            "Identifier" => Expression::Identifier(self.alloc(self.identifier_reference(node)?)),
+            "Literal" => self.literal(node)?,
+            "TemplateLiteral" => {
+                Expression::TemplateLiteral(self.alloc(self.template_literal(node)?))
+            }
+            "TaggedTemplateExpression" => self.ast.expression_tagged_template(
+                span,
+                self.child(node, "tag", Self::expression)?,
+                self.opt_child(node, "typeArguments", Self::ts_type_parameter_instantiation)?,
+                self.child(node, "quasi", Self::template_literal)?,
+            ),
+            "ThisExpression" => self.ast.expression_this(span),
+            "Super" => self.ast.expression_super(span),
+            "ArrayExpression" => self.ast.expression_array(
+                span,
+                self.list(node, "elements", Self::array_expression_element)?,
+            ),
+            "ObjectExpression" => self.ast.expression_object(
+                span,
+                self.list(node, "properties", Self::object_property_kind)?,
+            ),
+            "FunctionExpression" => Expression::FunctionExpression(
+                self.function(node, FormalParameterKind::FormalParameter)?,
+            ),
+            "ArrowFunctionExpression" => self.arrow_function_expression(node)?,
+            "ClassExpression" => {
+                Expression::ClassExpression(self.class(node, ClassType::ClassExpression)?)
+            }
+            "MemberExpression" => Expression::from(self.member_expression(node)?),
+            "CallExpression" => self.call_expression(node)?,
+            "NewExpression" => self.ast.expression_new(
+                span,
+                self.child(node, "callee", Self::expression)?,
+                self.opt_child(node, "typeArguments", Self::ts_type_parameter_instantiation)?,
+                self.list(node, "arguments", Self::argument)?,
+            ),
+            "ChainExpression" => {
+                let expression = self.child(node, "expression", Self::chain_element)?;
+                self.ast.expression_chain(span, expression)
+            }
+            "MetaProperty" => self.ast.expression_meta_property(
+                span,
+                self.child(node, "meta", Self::identifier_name)?,
+                self.child(node, "property", Self::identifier_name)?,
+            ),
+            "UpdateExpression" => {
+                let argument = self.child(node, "argument", Self::simple_assignment_target)?;
+                self.ast.expression_update(
+                    span,
+                    operator(node, &UPDATE_OPERATORS, UpdateOperator::as_str)?,
+                    bool_field(node, "prefix"),
+                    argument,
+                )
+            }
+            "UnaryExpression" => self.ast.expression_unary(
+                span,
+                operator(node, &UNARY_OPERATORS, UnaryOperator::as_str)?,
+                self.child(node, "argument", Self::expression)?,
+            ),
+            "BinaryExpression" => {
+                let left = required_field(node, "left")?;
+                if node_type(left).ok() == Some("PrivateIdentifier") {
+                    if str_field(node, "operator")? != "in" {
+                        return Err(invalid_value("operator", str_field(node, "operator")?));
+                    }
+                    self.ast.expression_private_in(
+                        span,
+                        self.child(node, "left", Self::private_identifier)?,
+                        self.child(node, "right", Self::expression)?,
+                    )
+                } else {
+                    self.ast.expression_binary(
+                        span,
+                        self.child(node, "left", Self::expression)?,
+                        operator(node, &BINARY_OPERATORS, BinaryOperator::as_str)?,
+                        self.child(node, "right", Self::expression)?,
+                    )
+                }
+            }
+            "LogicalExpression" => self.ast.expression_logical(
+                span,
+                self.child(node, "left", Self::expression)?,
+                operator(node, &LOGICAL_OPERATORS, LogicalOperator::as_str)?,
+                self.child(node, "right", Self::expression)?,
+            ),
+            "ConditionalExpression" => self.ast.expression_conditional(
+                span,
+                self.child(node, "test", Self::expression)?,
+                self.child(node, "consequent", Self::expression)?,
+                self.child(node, "alternate", Self::expression)?,
+            ),
+            "AssignmentExpression" => self.ast.expression_assignment(
+                span,
+                operator(node, &ASSIGNMENT_OPERATORS, AssignmentOperator::as_str)?,
+                self.child(node, "left", Self::assignment_target)?,
+                self.child(node, "right", Self::expression)?,
+            ),
+            "SequenceExpression" => self
+                .ast
+                .expression_sequence(span, self.list(node, "expressions", Self::expression)?),
+            "AwaitExpression" => {
+                self.ast.expression_await(span, self.child(node, "argument", Self::expression)?)
+            }
+            "YieldExpression" => self.ast.expression_yield(
+                span,
+                bool_field(node, "delegate"),
+                self.opt_child(node, "argument", Self::expression)?,
+            ),
+            "ImportExpression" => self.ast.expression_import(
+                span,
+                self.child(node, "source", Self::expression)?,
+                self.opt_child(node, "options", Self::expression)?,
+                Self::import_phase(node)?,
+            ),
+            "ParenthesizedExpression" => self
+                .ast
+                .expression_parenthesized(span, self.child(node, "expression", Self::expression)?),
+            "V8IntrinsicExpression" => self.ast.expression_v_8_intrinsic(
+                span,
+                self.child(node, "name", Self::identifier_name)?,
+                self.list(node, "arguments", Self::argument)?,
+            ),
+            "JSXElement" => Expression::JSXElement(self.jsx_element(node)?),
+            "JSXFragment" => Expression::JSXFragment(self.jsx_fragment(node)?),
+            _ => match self.ts_expression(node, ty)? {
+                Some(expression) => expression,
+                None => return Err(unexpected(ty, "Expression")),
+            },
+        };
+        Ok(expression)
+    }
+
+    fn array_expression_element(&self, node: &Json) -> Result<ArrayExpressionElement<'a>> {
+        match node {
+            Json::Null => Ok(ArrayExpressionElement::Elision(self.ast.elision(SPAN))),
+            _ if node_type(node)? == "SpreadElement" => {
+                Ok(ArrayExpressionElement::SpreadElement(self.spread_element(node)?))
+            }
+            _ => self.expression(node).map(ArrayExpressionElement::from),
+        }
+    }
+
+    fn argument(&self, node: &Json) -> Result<Argument<'a>> {
+        if node_type(node)? == "SpreadElement" {
+            Ok(Argument::SpreadElement(self.spread_element(node)?))
+        } else {
+            self.expression(node).map(Argument::from)
+        }
+    }
+
+    fn spread_element(&self, node: &Json) -> Result<Box<'a, SpreadElement<'a>>> {
+        let argument = self.child(node, "argument", Self::expression)?;
+        Ok(self.ast.alloc_spread_element(self.span(node), argument))
+    }
+
+    fn object_property_kind(&self, node: &Json) -> Result<ObjectPropertyKind<'a>> {
+        let ty = node_type(node)?;
+        match ty {
+            "SpreadElement" => Ok(ObjectPropertyKind::SpreadProperty(self.spread_element(node)?)),
+            "Property" => {
+                let kind = match str_field(node, "kind")? {
+                    "init" => PropertyKind::Init,
+                    "get" => PropertyKind::Get,
+                    "set" => PropertyKind::Set,
+                    kind => return Err(invalid_value("kind", kind)),
+                };
+                let computed = bool_field(node, "computed");
+                Ok(ObjectPropertyKind::ObjectProperty(self.ast.alloc_object_property(
+                    self.span(node),
+                    kind,
+                    self.child(node, "key", |d, key| d.property_key(key, computed))?,
+                    self.child(node, "value", Self::expression)?,
+                    bool_field(node, "method"),
+                    bool_field(node, "shorthand"),
+                    computed,
+                )))
+            }
+            _ => Err(unexpected(ty, "Property or SpreadElement")),
+        }
+    }
+
+    /// Key of an object property, class member or TS signature.
+    pub(super) fn property_key(&self, node: &Json, computed: bool) -> Result<PropertyKey<'a>> {
+        match node_type(node)? {
+            "Identifier" if !computed => {
+                Ok(PropertyKey::StaticIdentifier(self.alloc(self.identifier_name(node)?)))
+            }
+            "PrivateIdentifier" => {
+                Ok(PropertyKey::PrivateIdentifier(self.alloc(self.private_identifier(node)?)))
+            }
+            _ => self.expression(node).map(PropertyKey::from),
+        }
+    }
+
+    fn member_expression(&self, node: &Json) -> Result<MemberExpression<'a>> {
+        let span = self.span(node);
+        let object = self.child(node, "object", Self::expression)?;
+        let optional = bool_field(node, "optional");
+        let property = required_field(node, "property")?;
+        let member_expression = if bool_field(node, "computed") {
+            let expression = self.child(node, "property", Self::expression)?;
+            self.ast.member_expression_computed(span, object, expression, optional)
+        } else if node_type(property).map_err(|err| err.at_key("property"))? == "PrivateIdentifier"
+        {
+            let field = self.child(node, "property", Self::private_identifier)?;
+            self.ast.member_expression_private_field_expression(span, object, field, optional)
+        } else {
+            let property = self.child(node, "property", Self::identifier_name)?;
+            self.ast.member_expression_static(span, object, property, optional)
+        };
+        Ok(member_expression)
+    }
+
+    fn call_expression(&self, node: &Json) -> Result<Expression<'a>> {
+        let span = self.span(node);
+        // Babel represents `import(source)` as a `CallExpression` with an `Import` callee
+        let callee = required_field(node, "callee")?;
+        if node_type(callee).ok() == Some("Import") {
+            let mut arguments = self.list(node, "arguments", Self::expression)?.into_iter();
+            let Some(source) = arguments.next() else {
+                return Err(DeserializeError::new("missing import source").at_key("arguments"));
+            };
+            return Ok(self.ast.expression_import(span, source, arguments.next(), None));
+        }
+        Ok(self.ast.expression_call(
+            span,
+            self.child(node, "callee", Self::expression)?,
+            self.opt_child(node, "typeArguments", Self::ts_type_parameter_instantiation)?,
+            self.list(node, "arguments", Self::argument)?,
+            bool_field(node, "optional"),
+        ))
+    }
+
+    fn chain_element(&self, node: &Json) -> Result<ChainElement<'a>> {
+        let ty = node_type(node)?;
+        match self.expression(node)? {
+            Expression::CallExpression(call) => Ok(ChainElement::CallExpression(call)),
+            Expression::TSNonNullExpression(expr) => Ok(ChainElement::TSNonNullExpression(expr)),
+            expression => MemberExpression::try_from(expression)
+                .map(ChainElement::from)
+                .map_err(|()| unexpected(ty, "CallExpression or MemberExpression")),
+        }
+    }
+
+    fn import_phase(node: &Json) -> Result<Option<ImportPhase>> {
+        match field(node, "phase").and_then(Json::as_str) {
+            Some("source") => Ok(Some(ImportPhase::Source)),
+            Some("defer") => Ok(Some(ImportPhase::Defer)),
+            Some(phase) => Err(invalid_value("phase", phase)),
+            None => Ok(None),
+        }
+    }
+}
+
+// ----------------------------------------
+// Assignment targets
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    fn assignment_target(&self, node: &Json) -> Result<AssignmentTarget<'a>> {
+        match node_type(node)? {
+            "ArrayPattern" => self.array_assignment_target(node),
+            "ObjectPattern" => self.object_assignment_target(node),
+            _ => self.simple_assignment_target(node).map(AssignmentTarget::from),
+        }
+    }
+
+    fn simple_assignment_target(&self, node: &Json) -> Result<SimpleAssignmentTarget<'a>> {
+        let ty = node_type(node)?;
+        let target = match self.expression(node)? {
+            Expression::Identifier(ident) => {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident)
+            }
+            Expression::TSAsExpression(expr) => SimpleAssignmentTarget::TSAsExpression(expr),
+            Expression::TSSatisfiesExpression(expr) => {
+                SimpleAssignmentTarget::TSSatisfiesExpression(expr)
+            }
+            Expression::TSNonNullExpression(expr) => {
+                SimpleAssignmentTarget::TSNonNullExpression(expr)
+            }
+            Expression::TSTypeAssertion(expr) => SimpleAssignmentTarget::TSTypeAssertion(expr),
+            // Parentheses around assignment targets are not represented in the AST
+            Expression::ParenthesizedExpression(_) => {
+                return self.child(node, "expression", Self::simple_assignment_target);
+            }
+            expression => {
+                MemberExpression::try_from(expression).map(SimpleAssignmentTarget::from).map_err(
+                    |()| DeserializeError::new(format!("`{ty}` is not a valid assignment target")),
+                )?
+            }
+        };
+        Ok(target)
+    }
+
+    fn assignment_target_maybe_default(
+        &self,
+        node: &Json,
+    ) -> Result<AssignmentTargetMaybeDefault<'a>> {
+        if node_type(node)? == "AssignmentPattern" {
+            Ok(AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                self.ast.alloc_assignment_target_with_default(
+                    self.span(node),
+                    self.child(node, "left", Self::assignment_target)?,
+                    self.child(node, "right", Self::expression)?,
+                ),
+            ))
+        } else {
+            self.assignment_target(node).map(AssignmentTargetMaybeDefault::from)
+        }
+    }
+
+    fn assignment_target_rest(&self, node: &Json) -> Result<AssignmentTargetRest<'a>> {
+        let target = self.child(node, "argument", Self::assignment_target)?;
+        Ok(self.ast.assignment_target_rest(self.span(node), target))
+    }
+
+    fn array_assignment_target(&self, node: &Json) -> Result<AssignmentTarget<'a>> {
+        let elements = Self::array(node, "elements")?;
+        let mut targets = self.ast.vec_with_capacity(elements.len());
+        let mut rest = None;
+        for (index, element) in elements.iter().enumerate() {
+            let result = self.pattern_element(element, index, elements.len(), |d, element| {
+                if element.is_null() {
+                    targets.push(None);
+                } else if node_type(element)? == "RestElement" {
+                    rest = Some(d.assignment_target_rest(element)?);
+                } else {
+                    targets.push(Some(d.assignment_target_maybe_default(element)?));
+                }
+                Ok(())
+            });
+            result.map_err(|err| err.at_index(index).at_key("elements"))?;
+        }
+        Ok(AssignmentTarget::ArrayAssignmentTarget(self.ast.alloc_array_assignment_target(
+            self.span(node),
+            targets,
+            rest,
+        )))
+    }
+
+    fn object_assignment_target(&self, node: &Json) -> Result<AssignmentTarget<'a>> {
+        let properties = Self::array(node, "properties")?;
+        let mut targets = self.ast.vec_with_capacity(properties.len());
+        let mut rest = None;
+        for (index, property) in properties.iter().enumerate() {
+            let result = self.pattern_element(property, index, properties.len(), |d, property| {
+                if node_type(property)? == "RestElement" {
+                    rest = Some(d.assignment_target_rest(property)?);
+                } else {
+                    targets.push(d.assignment_target_property(property)?);
+                }
+                Ok(())
+            });
+            result.map_err(|err| err.at_index(index).at_key("properties"))?;
+        }
+        Ok(AssignmentTarget::ObjectAssignmentTarget(self.ast.alloc_object_assignment_target(
+            self.span(node),
+            targets,
+            rest,
+        )))
+    }
+
+    fn assignment_target_property(&self, node: &Json) -> Result<AssignmentTargetProperty<'a>> {
+        let ty = node_type(node)?;
+        if ty != "Property" {
+            return Err(unexpected(ty, "Property or RestElement"));
+        }
+        let span = self.span(node);
+        let computed = bool_field(node, "computed");
+        if bool_field(node, "shorthand") && !computed {
+            // `{ a } = obj` or `{ a = 1 } = obj`
+            let value = required_field(node, "value")?;
+            let (binding, init) = if node_type(value).ok() == Some("AssignmentPattern") {
+                let binding = self.child(value, "left", Self::identifier_reference);
+                let init = self.child(value, "right", Self::expression);
+                (binding.map_err(|err| err.at_key("value"))?, Some(init))
+            } else {
+                (self.child(node, "value", Self::identifier_reference)?, None)
+            };
+            let init = init.transpose().map_err(|err| err.at_key("value"))?;
+            return Ok(AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(
+                self.ast.alloc_assignment_target_property_identifier(span, binding, init),
+            ));
+        }
+        Ok(AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+            self.ast.alloc_assignment_target_property_property(
+                span,
+                self.child(node, "key", |d, key| d.property_key(key, computed))?,
+                self.child(node, "value", Self::assignment_target_maybe_default)?,
+                computed,
+            ),
+        ))
+    }
+
+    /// Process an element of an array or object pattern with `f`,
+    /// checking that a `RestElement` is only the last element.
+    fn pattern_element(
+        &self,
+        element: &Json,
+        index: usize,
+        len: usize,
+        mut f: impl FnMut(&Self, &Json) -> Result<()>,
+    ) -> Result<()> {
+        if index + 1 < len && node_type(element).ok() == Some("RestElement") {
+            return Err(DeserializeError::new("`RestElement` must be the last element"));
+        }
+        f(self, element)
+    }
+}
+
+// ----------------------------------------
+// Binding patterns
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    pub(super) fn binding_pattern(&self, node: &Json) -> Result<BindingPattern<'a>> {
+        let ty = node_type(node)?;
+        let span = self.span(node);
+        let kind = match ty {
+            "Identifier" => {
+                BindingPatternKind::BindingIdentifier(self.alloc(self.binding_identifier(node)?))
+            }
+            "AssignmentPattern" => self.ast.binding_pattern_kind_assignment_pattern(
+                span,
+                self.child(node, "left", Self::binding_pattern)?,
+                self.child(node, "right", Self::expression)?,
+            ),
+            "ObjectPattern" => {
+                let properties = Self::array(node, "properties")?;
+                let mut bindings = self.ast.vec_with_capacity(properties.len());
+                let mut rest = None;
+                for (index, property) in properties.iter().enumerate() {
+                    let result =
+                        self.pattern_element(property, index, properties.len(), |d, property| {
+                            if node_type(property)? == "RestElement" {
+                                rest = Some(d.binding_rest_element(property)?);
+                            } else {
+                                bindings.push(d.binding_property(property)?);
+                            }
+                            Ok(())
+                        });
+                    result.map_err(|err| err.at_index(index).at_key("properties"))?;
+                }
+                self.ast.binding_pattern_kind_object_pattern(span, bindings, rest)
+            }
+            "ArrayPattern" => {
+                let elements = Self::array(node, "elements")?;
+                let mut bindings = self.ast.vec_with_capacity(elements.len());
+                let mut rest = None;
+                for (index, element) in elements.iter().enumerate() {
+                    let result =
+                        self.pattern_element(element, index, elements.len(), |d, element| {
+                            if element.is_null() {
+                                bindings.push(None);
+                            } else if node_type(element)? == "RestElement" {
+                                rest = Some(d.binding_rest_element(element)?);
+                            } else {
+                                bindings.push(Some(d.binding_pattern(element)?));
+                            }
+                            Ok(())
+                        });
+                    result.map_err(|err| err.at_index(index).at_key("elements"))?;
+                }
+                self.ast.binding_pattern_kind_array_pattern(span, bindings, rest)
+            }
+            _ => return Err(unexpected(ty, "BindingPattern")),
+        };
+        Ok(self.ast.binding_pattern(
+            kind,
+            self.opt_child(node, "typeAnnotation", Self::ts_type_annotation)?,
+            bool_field(node, "optional"),
+        ))
+    }
+
+    fn binding_property(&self, node: &Json) -> Result<BindingProperty<'a>> {
+        let ty = node_type(node)?;
+        if ty != "Property" {
+            return Err(unexpected(ty, "Property or RestElement"));
+        }
+        let computed = bool_field(node, "computed");
+        Ok(self.ast.binding_property(
+            self.span(node),
+            self.child(node, "key", |d, key| d.property_key(key, computed))?,
+            self.child(node, "value", Self::binding_pattern)?,
+            bool_field(node, "shorthand"),
+            computed,
+        ))
+    }
+
+    /// `RestElement` in a binding pattern or function params.
+    ///
+    /// In function params, TS-ESTree puts `typeAnnotation` and `optional` on the `RestElement`
+    /// rather than its `argument`, so move them to the argument.
+    fn binding_rest_element(&self, node: &Json) -> Result<Box<'a, BindingRestElement<'a>>> {
+        let mut argument = self.child(node, "argument", Self::binding_pattern)?;
+        if argument.type_annotation.is_none() {
+            argument.type_annotation =
+                self.opt_child(node, "typeAnnotation", Self::ts_type_annotation)?;
+        }
+        argument.optional |= bool_field(node, "optional");
+        Ok(self.ast.alloc_binding_rest_element(self.span(node), argument))
+    }
+}
+
+// ----------------------------------------
+// Functions
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    /// `FunctionDeclaration`, `FunctionExpression`, `TSDeclareFunction`
+    /// or `TSEmptyBodyFunctionExpression`.
+    pub(super) fn function(
+        &self,
+        node: &Json,
+        params_kind: FormalParameterKind,
+    ) -> Result<Box<'a, Function<'a>>> {
+        let ty = node_type(node)?;
+        let r#type = match ty {
+            "FunctionDeclaration" => FunctionType::FunctionDeclaration,
+            "FunctionExpression" => FunctionType::FunctionExpression,
+            "TSDeclareFunction" => FunctionType::TSDeclareFunction,
+            "TSEmptyBodyFunctionExpression" => FunctionType::TSEmptyBodyFunctionExpression,
+            _ => return Err(unexpected(ty, "function")),
+        };
+        let (this_param, params) =
+            self.child(node, "params", |d, params| d.formal_parameters(params, params_kind))?;
+        Ok(self.ast.alloc_function(
+            self.span(node),
+            r#type,
+            self.opt_child(node, "id", Self::binding_identifier)?,
+            bool_field(node, "generator"),
+            bool_field(node, "async"),
+            bool_field(node, "declare"),
+            self.opt_child(node, "typeParameters", Self::ts_type_parameter_declaration)?,
+            this_param,
+            params,
+            self.opt_child(node, "returnType", Self::ts_type_annotation)?,
+            self.opt_child(node, "body", Self::function_body)?,
+        ))
+    }
+
+    /// Function params. First param is converted to a `this` param if it's named `this`.
+    pub(super) fn formal_parameters(
+        &self,
+        node: &Json,
+        kind: FormalParameterKind,
+    ) -> Result<(Option<Box<'a, TSThisParameter<'a>>>, Box<'a, FormalParameters<'a>>)> {
+        let Some(params) = node.as_array() else {
+            return Err(DeserializeError::new("expected an array"));
+        };
+        let mut this_param = None;
+        let mut items = self.ast.vec_with_capacity(params.len());
+        let mut rest = None;
+        for (index, param) in params.iter().enumerate() {
+            let result = self.pattern_element(param, index, params.len(), |d, param| {
+                match node_type(param)? {
+                    "Identifier" if index == 0 && str_field(param, "name")? == "this" => {
+                        let span = d.span(param);
+                        let this_span = Span::sized(span.start, 4);
+                        let type_annotation =
+                            d.opt_child(param, "typeAnnotation", Self::ts_type_annotation)?;
+                        this_param =
+                            Some(d.ast.alloc_ts_this_parameter(span, this_span, type_annotation));
+                    }
+                    "RestElement" => rest = Some(d.binding_rest_element(param)?),
+                    "TSParameterProperty" => {
+                        let accessibility = Self::accessibility(param)?;
+                        items.push(d.ast.formal_parameter(
+                            d.span(param),
+                            d.decorators(param)?,
+                            d.child(param, "parameter", Self::binding_pattern)?,
+                            accessibility,
+                            bool_field(param, "readonly"),
+                            bool_field(param, "override"),
+                        ));
+                    }
+                    _ => items.push(d.ast.formal_parameter(
+                        d.span(param),
+                        d.decorators(param)?,
+                        d.binding_pattern(param)?,
+                        None,
+                        false,
+                        false,
+                    )),
+                }
+                Ok(())
+            });
+            result.map_err(|err| err.at_index(index))?;
+        }
+        let span = self.children_span(node).unwrap_or(SPAN);
+        Ok((this_param, self.ast.alloc_formal_parameters(span, kind, items, rest)))
+    }
+
+    pub(super) fn function_body(&self, node: &Json) -> Result<Box<'a, FunctionBody<'a>>> {
+        let ty = node_type(node)?;
+        if ty != "BlockStatement" {
+            return Err(unexpected(ty, "BlockStatement"));
+        }
+        let (directives, statements) = self.child(node, "body", Self::directives_and_statements)?;
+        Ok(self.ast.alloc_function_body(self.span(node), directives, statements))
+    }
+
+    fn arrow_function_expression(&self, node: &Json) -> Result<Expression<'a>> {
+        let expression = bool_field(node, "expression");
+        let body = if expression {
+            self.child(node, "body", |d, body| {
+                let expression = d.expression(body)?;
+                let span = d.span(body);
+                let statement = d.ast.statement_expression(span, expression);
+                Ok(d.ast.alloc_function_body(span, d.ast.vec(), d.ast.vec1(statement)))
+            })?
+        } else {
+            self.child(node, "body", Self::function_body)?
+        };
+        let (this_param, params) = self.child(node, "params", |d, params| {
+            d.formal_parameters(params, FormalParameterKind::ArrowFormalParameters)
+        })?;
+        if this_param.is_some() {
+            return Err(DeserializeError::new("arrow function cannot have a `this` parameter")
+                .at_key("params"));
+        }
+        Ok(self.ast.expression_arrow_function(
+            self.span(node),
+            expression,
+            bool_field(node, "async"),
+            self.opt_child(node, "typeParameters", Self::ts_type_parameter_declaration)?,
+            params,
+            self.opt_child(node, "returnType", Self::ts_type_annotation)?,
+            body,
+        ))
+    }
+
+    pub(super) fn decorators(&self, node: &Json) -> Result<Vec<'a, Decorator<'a>>> {
+        self.list(node, "decorators", |d, decorator| {
+            let ty = node_type(decorator)?;
+            if ty != "Decorator" {
+                return Err(unexpected(ty, "Decorator"));
+            }
+            let expression = d.child(decorator, "expression", Self::expression)?;
+            Ok(d.ast.decorator(d.span(decorator), expression))
+        })
+    }
+}
+
+// ----------------------------------------
+// Classes
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    pub(super) fn class(&self, node: &Json, r#type: ClassType) -> Result<Box<'a, Class<'a>>> {
+        let body = self.child(node, "body", |d, body| {
+            let ty = node_type(body)?;
+            if ty != "ClassBody" {
+                return Err(unexpected(ty, "ClassBody"));
+            }
+            let elements = d.list(body, "body", Self::class_element)?;
+            Ok(d.ast.alloc_class_body(d.span(body), elements))
+        })?;
+        Ok(self.ast.alloc_class(
+            self.span(node),
+            r#type,
+            self.decorators(node)?,
+            self.opt_child(node, "id", Self::binding_identifier)?,
+            self.opt_child(node, "typeParameters", Self::ts_type_parameter_declaration)?,
+            self.opt_child(node, "superClass", Self::expression)?,
+            self.opt_child(node, "superTypeArguments", Self::ts_type_parameter_instantiation)?,
+            self.list(node, "implements", Self::ts_class_implements)?,
+            body,
+            bool_field(node, "abstract"),
+            bool_field(node, "declare"),
+        ))
+    }
+
+    fn class_element(&self, node: &Json) -> Result<ClassElement<'a>> {
+        let ty = node_type(node)?;
+        let span = self.span(node);
+        let computed = bool_field(node, "computed");
+        let element = match ty {
+            "StaticBlock" => {
+                let body = self.list(node, "body", Self::statement)?;
+                ClassElement::StaticBlock(self.ast.alloc_static_block(span, body))
+            }
+            "MethodDefinition" | "TSAbstractMethodDefinition" => {
+                let r#type = if ty == "MethodDefinition" {
+                    MethodDefinitionType::MethodDefinition
+                } else {
+                    MethodDefinitionType::TSAbstractMethodDefinition
+                };
+                let kind = match str_field(node, "kind")? {
+                    "constructor" => MethodDefinitionKind::Constructor,
+                    "method" => MethodDefinitionKind::Method,
+                    "get" => MethodDefinitionKind::Get,
+                    "set" => MethodDefinitionKind::Set,
+                    kind => return Err(invalid_value("kind", kind)),
+                };
+                let value = self.child(node, "value", |d, value| {
+                    d.function(value, FormalParameterKind::UniqueFormalParameters)
+                })?;
+                ClassElement::MethodDefinition(self.ast.alloc_method_definition(
+                    span,
+                    r#type,
+                    self.decorators(node)?,
+                    self.child(node, "key", |d, key| d.property_key(key, computed))?,
+                    value,
+                    kind,
+                    computed,
+                    bool_field(node, "static"),
+                    bool_field(node, "override"),
+                    bool_field(node, "optional"),
+                    Self::accessibility(node)?,
+                ))
+            }
+            "PropertyDefinition" | "TSAbstractPropertyDefinition" => {
+                let r#type = if ty == "PropertyDefinition" {
+                    PropertyDefinitionType::PropertyDefinition
+                } else {
+                    PropertyDefinitionType::TSAbstractPropertyDefinition
+                };
+                ClassElement::PropertyDefinition(self.ast.alloc_property_definition(
+                    span,
+                    r#type,
+                    self.decorators(node)?,
+                    self.child(node, "key", |d, key| d.property_key(key, computed))?,
+                    self.opt_child(node, "typeAnnotation", Self::ts_type_annotation)?,
+                    self.opt_child(node, "value", Self::expression)?,
+                    computed,
+                    bool_field(node, "static"),
+                    bool_field(node, "declare"),
+                    bool_field(node, "override"),
+                    bool_field(node, "optional"),
+                    bool_field(node, "definite"),
+                    bool_field(node, "readonly"),
+                    Self::accessibility(node)?,
+                ))
+            }
+            "AccessorProperty" | "TSAbstractAccessorProperty" => {
+                let r#type = if ty == "AccessorProperty" {
+                    AccessorPropertyType::AccessorProperty
+                } else {
+                    AccessorPropertyType::TSAbstractAccessorProperty
+                };
+                ClassElement::AccessorProperty(self.ast.alloc_accessor_property(
+                    span,
+                    r#type,
+                    self.decorators(node)?,
+                    self.child(node, "key", |d, key| d.property_key(key, computed))?,
+                    self.opt_child(node, "typeAnnotation", Self::ts_type_annotation)?,
+                    self.opt_child(node, "value", Self::expression)?,
+                    computed,
+                    bool_field(node, "static"),
+                    bool_field(node, "override"),
+                    bool_field(node, "definite"),
+                    Self::accessibility(node)?,
+                ))
+            }
+            "TSIndexSignature" => ClassElement::TSIndexSignature(self.ts_index_signature(node)?),
+            _ => return Err(unexpected(ty, "class element")),
+        };
+        Ok(element)
+    }
+}
+
+// ----------------------------------------
+// Modules
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    fn import_declaration(&self, node: &Json) -> Result<Box<'a, ImportDeclaration<'a>>> {
+        let specifiers = self.list(node, "specifiers", |d, specifier| {
+            let ty = node_type(specifier)?;
+            let span = d.span(specifier);
+            let local = d.child(specifier, "local", Self::binding_identifier)?;
+            let specifier = match ty {
+                "ImportSpecifier" => d.ast.import_declaration_specifier_import_specifier(
+                    span,
+                    d.child(specifier, "imported", |d, imported| {
+                        d.module_export_name(imported, false)
+                    })?,
+                    local,
+                    Self::import_or_export_kind(specifier, "importKind")?,
+                ),
+                "ImportDefaultSpecifier" => {
+                    d.ast.import_declaration_specifier_import_default_specifier(span, local)
+                }
+                "ImportNamespaceSpecifier" => {
+                    d.ast.import_declaration_specifier_import_namespace_specifier(span, local)
+                }
+                _ => return Err(unexpected(ty, "import specifier")),
+            };
+            Ok(specifier)
+        })?;
+        // `import {} from 'x'` and `import 'x'` are indistinguishable in ESTree
+        let specifiers = if specifiers.is_empty() { None } else { Some(specifiers) };
+        Ok(self.ast.alloc_import_declaration(
+            self.span(node),
+            specifiers,
+            self.child(node, "source", Self::string_literal)?,
+            Self::import_phase(node)?,
+            self.with_clause(node)?,
+            Self::import_or_export_kind(node, "importKind")?,
+        ))
+    }
+
+    fn export_named_declaration(&self, node: &Json) -> Result<Box<'a, ExportNamedDeclaration<'a>>> {
+        let source = self.opt_child(node, "source", Self::string_literal)?;
+        // Local names are references to bindings in this module, unless re-exporting
+        let local_is_reference = source.is_none();
+        let specifiers = self.list(node, "specifiers", |d, specifier| {
+            let ty = node_type(specifier)?;
+            if ty != "ExportSpecifier" {
+                return Err(unexpected(ty, "ExportSpecifier"));
+            }
+            Ok(d.ast.export_specifier(
+                d.span(specifier),
+                d.child(specifier, "local", |d, local| {
+                    d.module_export_name(local, local_is_reference)
+                })?,
+                d.child(specifier, "exported", |d, exported| {
+                    d.module_export_name(exported, false)
+                })?,
+                Self::import_or_export_kind(specifier, "exportKind")?,
+            ))
+        })?;
+        Ok(self.ast.alloc_export_named_declaration(
+            self.span(node),
+            self.opt_child(node, "declaration", Self::declaration)?,
+            specifiers,
+            source,
+            Self::import_or_export_kind(node, "exportKind")?,
+            self.with_clause(node)?,
+        ))
+    }
+
+    fn export_default_declaration(
+        &self,
+        node: &Json,
+    ) -> Result<Box<'a, ExportDefaultDeclaration<'a>>> {
+        let declaration = self.child(node, "declaration", |d, declaration| {
+            let kind = match node_type(declaration)? {
+                "FunctionDeclaration" | "TSDeclareFunction" => {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(
+                        d.function(declaration, FormalParameterKind::FormalParameter)?,
+                    )
+                }
+                "ClassDeclaration" => ExportDefaultDeclarationKind::ClassDeclaration(
+                    d.class(declaration, ClassType::ClassDeclaration)?,
+                ),
+                "TSInterfaceDeclaration" => ExportDefaultDeclarationKind::TSInterfaceDeclaration(
+                    d.ts_interface_declaration(declaration)?,
+                ),
+                _ => ExportDefaultDeclarationKind::from(d.expression(declaration)?),
+            };
+            Ok(kind)
+        })?;
+        let exported = self.ast.module_export_name_identifier_name(SPAN, "default");
+        Ok(self.ast.alloc_export_default_declaration(self.span(node), exported, declaration))
+    }
+
+    fn export_all_declaration(&self, node: &Json) -> Result<Box<'a, ExportAllDeclaration<'a>>> {
+        Ok(self.ast.alloc_export_all_declaration(
+            self.span(node),
+            self.opt_child(node, "exported", |d, exported| d.module_export_name(exported, false))?,
+            self.child(node, "source", Self::string_literal)?,
+            self.with_clause(node)?,
+            Self::import_or_export_kind(node, "exportKind")?,
+        ))
+    }
+
+    fn module_export_name(&self, node: &Json, reference: bool) -> Result<ModuleExportName<'a>> {
+        let ty = node_type(node)?;
+        match ty {
+            "Identifier" if reference => {
+                Ok(ModuleExportName::IdentifierReference(self.identifier_reference(node)?))
+            }
+            "Identifier" => Ok(ModuleExportName::IdentifierName(self.identifier_name(node)?)),
+            "Literal" => Ok(ModuleExportName::StringLiteral(self.string_literal(node)?)),
+            _ => Err(unexpected(ty, "Identifier or Literal")),
+        }
+    }
+
+    /// `WithClause` from `attributes` field. `None` if there are no attributes.
+    fn with_clause(&self, node: &Json) -> Result<Option<Box<'a, WithClause<'a>>>> {
+        let attributes = self.list(node, "attributes", |d, attribute| {
+            let ty = node_type(attribute)?;
+            if ty != "ImportAttribute" {
+                return Err(unexpected(ty, "ImportAttribute"));
+            }
+            let key = d.child(attribute, "key", |d, key| match node_type(key)? {
+                "Literal" => Ok(ImportAttributeKey::StringLiteral(d.string_literal(key)?)),
+                _ => Ok(ImportAttributeKey::Identifier(d.identifier_name(key)?)),
+            })?;
+            let value = d.child(attribute, "value", Self::string_literal)?;
+            Ok(d.ast.import_attribute(d.span(attribute), key, value))
+        })?;
+        if attributes.is_empty() {
+            return Ok(None);
+        }
+        let span = node.get("attributes").and_then(|attributes| self.children_span(attributes));
+        let keyword = self.ast.identifier_name(SPAN, "with");
+        Ok(Some(self.ast.alloc_with_clause(span.unwrap_or(SPAN), keyword, attributes)))
+    }
+
+    pub(super) fn import_or_export_kind(node: &Json, key: &str) -> Result<ImportOrExportKind> {
+        match field(node, key).and_then(Json::as_str) {
+            Some("type") => Ok(ImportOrExportKind::Type),
+            Some("value") | None => Ok(ImportOrExportKind::Value),
+            Some(kind) => Err(invalid_value(key, kind)),
+        }
+    }
+}
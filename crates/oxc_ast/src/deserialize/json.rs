@@ -0,0 +1,289 @@
+//! Minimal JSON reader for ESTree JSON.
+//!
+//! A general purpose JSON library is not used for 2 reasons:
+//!
+//! 1. Our serializer represents `Infinity` as `1e+400`, which is out of range for `f64` parsers
+//!    which reject overflowing numbers.
+//! 2. JSON strings can contain lone surrogates (e.g. `"\ud800"`), which cannot be represented in
+//!    a Rust `str`. These are encoded as `\u{FFFD}` followed by the code point as 4 hex digits,
+//!    in the same way as the lexer does for `StringLiteral`s.
+
+use std::{borrow::Cow, fmt::Write};
+
+/// A JSON value.
+#[derive(Debug, PartialEq)]
+pub enum Json<'j> {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(JsonString<'j>),
+    Array(Vec<Json<'j>>),
+    Object(Vec<(Cow<'j, str>, Json<'j>)>),
+}
+
+/// A JSON string.
+#[derive(Debug, PartialEq, Eq)]
+pub struct JsonString<'j> {
+    pub value: Cow<'j, str>,
+    /// `true` if string contains lone surrogates.
+    /// See module docs for how they are encoded in `value`.
+    pub lone_surrogates: bool,
+}
+
+impl<'j> Json<'j> {
+    /// Get value of `key`, if this is an object which contains it.
+    pub fn get(&self, key: &str) -> Option<&Json<'j>> {
+        match self {
+            Json::Object(entries) => {
+                entries.iter().find(|(entry_key, _)| entry_key == key).map(|(_, value)| value)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(&s.value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Json::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json<'j>]> {
+        match self {
+            Json::Array(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Json::Null)
+    }
+}
+
+/// Parse JSON text. On failure, returns error message and byte offset.
+pub fn parse(text: &str) -> Result<Json<'_>, (String, usize)> {
+    let mut parser = JsonParser { text, pos: 0 };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.pos < text.len() {
+        return Err(parser.error("trailing characters"));
+    }
+    Ok(value)
+}
+
+struct JsonParser<'j> {
+    text: &'j str,
+    pos: usize,
+}
+
+impl<'j> JsonParser<'j> {
+    fn error(&self, message: &str) -> (String, usize) {
+        (message.to_string(), self.pos)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), (String, usize)> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", byte as char)))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Json<'j>, (String, usize)> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(b'"') => self.parse_string().map(Json::String),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(b't') => self.parse_keyword("true", Json::Bool(true)),
+            Some(b'f') => self.parse_keyword("false", Json::Bool(false)),
+            Some(b'n') => self.parse_keyword("null", Json::Null),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn parse_keyword(
+        &mut self,
+        keyword: &str,
+        value: Json<'j>,
+    ) -> Result<Json<'j>, (String, usize)> {
+        if self.text[self.pos..].starts_with(keyword) {
+            self.pos += keyword.len();
+            Ok(value)
+        } else {
+            Err(self.error("unexpected character"))
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Json<'j>, (String, usize)> {
+        self.expect(b'{')?;
+        let mut entries = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected object key"));
+            }
+            let key = self.parse_string()?.value;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            let value = self.parse_value()?;
+            entries.push((key, value));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(entries));
+                }
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Json<'j>, (String, usize)> {
+        self.expect(b'[')?;
+        let mut elements = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Json::Array(elements));
+        }
+        loop {
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(elements));
+                }
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Json<'j>, (String, usize)> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        while matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-')) {
+            self.pos += 1;
+        }
+        // Overflowing numbers (e.g. `1e+400`) parse as infinity
+        self.text[start..self.pos].parse::<f64>().map(Json::Number).map_err(|_| {
+            self.pos = start;
+            self.error("invalid number")
+        })
+    }
+
+    fn parse_string(&mut self) -> Result<JsonString<'j>, (String, usize)> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        // Fast path for strings without escapes
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    let value = Cow::Borrowed(&self.text[start..self.pos]);
+                    self.pos += 1;
+                    return Ok(JsonString { value, lone_surrogates: false });
+                }
+                Some(b'\\') => break,
+                Some(0..0x20) => return Err(self.error("control character in string")),
+                Some(_) => self.pos += 1,
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+
+        // Slow path. Collect UTF-16 code units, so surrogate pairs can be combined.
+        let mut units = self.text[start..self.pos].encode_utf16().collect::<Vec<_>>();
+        loop {
+            let Some(ch) = self.text[self.pos..].chars().next() else {
+                return Err(self.error("unterminated string"));
+            };
+            match ch {
+                '"' => {
+                    self.pos += 1;
+                    break;
+                }
+                '\\' => {
+                    self.pos += 1;
+                    let unit = match self.peek() {
+                        Some(b'"') => u16::from(b'"'),
+                        Some(b'\\') => u16::from(b'\\'),
+                        Some(b'/') => u16::from(b'/'),
+                        Some(b'b') => 0x08,
+                        Some(b'f') => 0x0C,
+                        Some(b'n') => u16::from(b'\n'),
+                        Some(b'r') => u16::from(b'\r'),
+                        Some(b't') => u16::from(b'\t'),
+                        Some(b'u') => {
+                            let hex = self.text.get(self.pos + 1..self.pos + 5);
+                            let unit = hex.and_then(|hex| u16::from_str_radix(hex, 16).ok());
+                            let Some(unit) = unit else {
+                                return Err(self.error("invalid unicode escape"));
+                            };
+                            self.pos += 4;
+                            unit
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    self.pos += 1;
+                    units.push(unit);
+                }
+                '\0'..='\x1F' => return Err(self.error("control character in string")),
+                _ => {
+                    self.pos += ch.len_utf8();
+                    let mut buf = [0; 2];
+                    units.extend_from_slice(ch.encode_utf16(&mut buf));
+                }
+            }
+        }
+
+        let lone_surrogates =
+            char::decode_utf16(units.iter().copied()).any(|result| result.is_err());
+        let mut value = String::with_capacity(units.len());
+        for result in char::decode_utf16(units) {
+            match result {
+                Ok('\u{FFFD}') if lone_surrogates => value.push_str("\u{FFFD}fffd"),
+                Ok(ch) => value.push(ch),
+                Err(err) => {
+                    write!(value, "\u{FFFD}{:04x}", err.unpaired_surrogate()).unwrap();
+                }
+            }
+        }
+        Ok(JsonString { value: Cow::Owned(value), lone_surrogates })
+    }
+}
@@ -0,0 +1,206 @@
+//! JSX elements, fragments and attributes.
+
+use oxc_allocator::Box;
+use oxc_span::{Atom, Span};
+
+use crate::ast::*;
+
+use super::{
+    DeserializeError, ESTreeDeserializer, Result, field, json::Json, node_type, str_field,
+    unexpected,
+};
+
+impl<'a> ESTreeDeserializer<'a> {
+    pub(super) fn jsx_element(&self, node: &Json) -> Result<Box<'a, JSXElement<'a>>> {
+        let ty = node_type(node)?;
+        if ty != "JSXElement" {
+            return Err(unexpected(ty, "JSXElement"));
+        }
+        let opening_element = self.child(node, "openingElement", |d, opening| {
+            let ty = node_type(opening)?;
+            if ty != "JSXOpeningElement" {
+                return Err(unexpected(ty, "JSXOpeningElement"));
+            }
+            Ok(d.ast.alloc_jsx_opening_element(
+                d.span(opening),
+                d.child(opening, "name", Self::jsx_element_name)?,
+                d.opt_child(opening, "typeArguments", Self::ts_type_parameter_instantiation)?,
+                d.list(opening, "attributes", Self::jsx_attribute_item)?,
+            ))
+        })?;
+        let closing_element = self.opt_child(node, "closingElement", |d, closing| {
+            let ty = node_type(closing)?;
+            if ty != "JSXClosingElement" {
+                return Err(unexpected(ty, "JSXClosingElement"));
+            }
+            Ok(d.ast.alloc_jsx_closing_element(
+                d.span(closing),
+                d.child(closing, "name", Self::jsx_element_name)?,
+            ))
+        })?;
+        Ok(self.ast.alloc_jsx_element(
+            self.span(node),
+            opening_element,
+            self.list(node, "children", Self::jsx_child)?,
+            closing_element,
+        ))
+    }
+
+    pub(super) fn jsx_fragment(&self, node: &Json) -> Result<Box<'a, JSXFragment<'a>>> {
+        let ty = node_type(node)?;
+        if ty != "JSXFragment" {
+            return Err(unexpected(ty, "JSXFragment"));
+        }
+        let opening_fragment = self.child(node, "openingFragment", |d, opening| {
+            Ok(d.ast.jsx_opening_fragment(d.span(opening)))
+        })?;
+        let closing_fragment = self.child(node, "closingFragment", |d, closing| {
+            Ok(d.ast.jsx_closing_fragment(d.span(closing)))
+        })?;
+        Ok(self.ast.alloc_jsx_fragment(
+            self.span(node),
+            opening_fragment,
+            self.list(node, "children", Self::jsx_child)?,
+            closing_fragment,
+        ))
+    }
+
+    /// `JSXIdentifier`, returning its span and name.
+    fn jsx_identifier_parts(&self, node: &Json) -> Result<(Span, Atom<'a>)> {
+        let ty = node_type(node)?;
+        if ty != "JSXIdentifier" {
+            return Err(unexpected(ty, "JSXIdentifier"));
+        }
+        Ok((self.span(node), self.atom(str_field(node, "name")?)))
+    }
+
+    fn jsx_identifier(&self, node: &Json) -> Result<JSXIdentifier<'a>> {
+        let (span, name) = self.jsx_identifier_parts(node)?;
+        Ok(self.ast.jsx_identifier(span, name))
+    }
+
+    fn jsx_namespaced_name(&self, node: &Json) -> Result<Box<'a, JSXNamespacedName<'a>>> {
+        Ok(self.ast.alloc_jsx_namespaced_name(
+            self.span(node),
+            self.child(node, "namespace", Self::jsx_identifier)?,
+            self.child(node, "name", Self::jsx_identifier)?,
+        ))
+    }
+
+    /// `JSXElementName`.
+    ///
+    /// ESTree represents all names as `JSXIdentifier`s. Names starting with an uppercase letter,
+    /// `_` or `$` are references, same as the parser.
+    fn jsx_element_name(&self, node: &Json) -> Result<JSXElementName<'a>> {
+        match node_type(node)? {
+            "JSXNamespacedName" => {
+                Ok(JSXElementName::NamespacedName(self.jsx_namespaced_name(node)?))
+            }
+            "JSXMemberExpression" => {
+                Ok(JSXElementName::MemberExpression(self.jsx_member_expression(node)?))
+            }
+            _ => {
+                let (span, name) = self.jsx_identifier_parts(node)?;
+                let is_reference = name
+                    .chars()
+                    .next()
+                    .is_some_and(|ch| if ch.is_ascii() { ch < 'a' } else { ch.is_uppercase() });
+                if is_reference {
+                    Ok(self.ast.jsx_element_name_identifier_reference(span, name))
+                } else if name == "this" {
+                    Ok(self.ast.jsx_element_name_this_expression(span))
+                } else {
+                    Ok(self.ast.jsx_element_name_identifier(span, name))
+                }
+            }
+        }
+    }
+
+    fn jsx_member_expression(&self, node: &Json) -> Result<Box<'a, JSXMemberExpression<'a>>> {
+        let object = self.child(node, "object", |d, object| {
+            if node_type(object)? == "JSXMemberExpression" {
+                return Ok(JSXMemberExpressionObject::MemberExpression(
+                    d.jsx_member_expression(object)?,
+                ));
+            }
+            let (span, name) = d.jsx_identifier_parts(object)?;
+            if name == "this" {
+                Ok(d.ast.jsx_member_expression_object_this_expression(span))
+            } else {
+                Ok(d.ast.jsx_member_expression_object_identifier_reference(span, name))
+            }
+        })?;
+        Ok(self.ast.alloc_jsx_member_expression(
+            self.span(node),
+            object,
+            self.child(node, "property", Self::jsx_identifier)?,
+        ))
+    }
+
+    fn jsx_attribute_item(&self, node: &Json) -> Result<JSXAttributeItem<'a>> {
+        let ty = node_type(node)?;
+        let span = self.span(node);
+        match ty {
+            "JSXAttribute" => {
+                let name = self.child(node, "name", |d, name| {
+                    if node_type(name)? == "JSXNamespacedName" {
+                        Ok(JSXAttributeName::NamespacedName(d.jsx_namespaced_name(name)?))
+                    } else {
+                        Ok(JSXAttributeName::Identifier(d.alloc(d.jsx_identifier(name)?)))
+                    }
+                })?;
+                let value = self.opt_child(node, "value", |d, value| match node_type(value)? {
+                    "Literal" => {
+                        Ok(JSXAttributeValue::StringLiteral(d.alloc(d.string_literal(value)?)))
+                    }
+                    "JSXExpressionContainer" => Ok(JSXAttributeValue::ExpressionContainer(
+                        d.jsx_expression_container(value)?,
+                    )),
+                    "JSXElement" => Ok(JSXAttributeValue::Element(d.jsx_element(value)?)),
+                    "JSXFragment" => Ok(JSXAttributeValue::Fragment(d.jsx_fragment(value)?)),
+                    ty => Err(unexpected(ty, "JSX attribute value")),
+                })?;
+                Ok(self.ast.jsx_attribute_item_attribute(span, name, value))
+            }
+            "JSXSpreadAttribute" => Ok(self.ast.jsx_attribute_item_spread_attribute(
+                span,
+                self.child(node, "argument", Self::expression)?,
+            )),
+            _ => Err(unexpected(ty, "JSXAttribute or JSXSpreadAttribute")),
+        }
+    }
+
+    fn jsx_expression_container(&self, node: &Json) -> Result<Box<'a, JSXExpressionContainer<'a>>> {
+        let expression = self.child(node, "expression", |d, expression| {
+            if node_type(expression)? == "JSXEmptyExpression" {
+                Ok(d.ast.jsx_expression_empty_expression(d.span(expression)))
+            } else {
+                d.expression(expression).map(JSXExpression::from)
+            }
+        })?;
+        Ok(self.ast.alloc_jsx_expression_container(self.span(node), expression))
+    }
+
+    fn jsx_child(&self, node: &Json) -> Result<JSXChild<'a>> {
+        let ty = node_type(node)?;
+        let span = self.span(node);
+        match ty {
+            "JSXText" => {
+                let Some(value) = field(node, "value").and_then(Json::as_str) else {
+                    return Err(DeserializeError::new("expected a string").at_key("value"));
+                };
+                let raw = node.get("raw").and_then(Json::as_str).map(|raw| self.atom(raw));
+                Ok(self.ast.jsx_child_text(span, self.atom(value), raw))
+            }
+            "JSXElement" => Ok(JSXChild::Element(self.jsx_element(node)?)),
+            "JSXFragment" => Ok(JSXChild::Fragment(self.jsx_fragment(node)?)),
+            "JSXExpressionContainer" => {
+                Ok(JSXChild::ExpressionContainer(self.jsx_expression_container(node)?))
+            }
+            "JSXSpreadChild" => Ok(self
+                .ast
+                .jsx_child_spread(span, self.child(node, "expression", Self::expression)?)),
+            _ => Err(unexpected(ty, "JSX child")),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Literals and template literals.
+
+use std::borrow::Cow;
+
+use oxc_span::Span;
+use oxc_syntax::number::{BigintBase, NumberBase};
+
+use crate::ast::*;
+
+use super::{
+    DeserializeError, ESTreeDeserializer, Result, bool_field, field, invalid_value, json::Json,
+    node_type, str_field, unexpected,
+};
+
+impl<'a> ESTreeDeserializer<'a> {
+    /// `Literal` of any kind.
+    ///
+    /// `BigInt` and `RegExp` literals are identified by `bigint` and `regex` fields,
+    /// because their `value` is `null` in JSON.
+    pub(super) fn literal(&self, node: &Json) -> Result<Expression<'a>> {
+        let span = self.span(node);
+        let raw = node.get("raw").and_then(Json::as_str);
+
+        if let Some(regex) = field(node, "regex") {
+            let pattern = str_field(regex, "pattern").map_err(|err| err.at_key("regex"))?;
+            let flags = match regex.get("flags").and_then(Json::as_str) {
+                Some(flags) => parse_regexp_flags(flags)
+                    .ok_or_else(|| invalid_value("flags", flags).at_key("regex"))?,
+                None => RegExpFlags::empty(),
+            };
+            let regex = RegExp {
+                pattern: RegExpPattern { text: self.atom(pattern), pattern: None },
+                flags,
+            };
+            let raw = raw.map(|raw| self.atom(raw));
+            return Ok(Expression::RegExpLiteral(self.ast.alloc_reg_exp_literal(span, regex, raw)));
+        }
+
+        if let Some(bigint) = field(node, "bigint") {
+            let Some(value) = bigint.as_str() else {
+                return Err(DeserializeError::new("expected a string").at_key("bigint"));
+            };
+            let base = raw.map_or(BigintBase::Decimal, |raw| match raw.get(..2) {
+                Some("0x" | "0X") => BigintBase::Hex,
+                Some("0o" | "0O") => BigintBase::Octal,
+                Some("0b" | "0B") => BigintBase::Binary,
+                _ => BigintBase::Decimal,
+            });
+            let raw = raw.map(|raw| self.atom(raw));
+            return Ok(self.ast.expression_big_int_literal(span, self.atom(value), raw, base));
+        }
+
+        match node.get("value") {
+            Some(Json::Null) => Ok(self.ast.expression_null_literal(span)),
+            Some(Json::Bool(value)) => Ok(self.ast.expression_boolean_literal(span, *value)),
+            Some(Json::Number(value)) => {
+                let value = *value;
+                // Discard `raw` if it doesn't match `value` (e.g. value altered by a codemod)
+                let (raw, base) = match raw.and_then(parse_number_raw) {
+                    Some((raw_value, base)) if raw_value.to_bits() == value.to_bits() => {
+                        (raw, base)
+                    }
+                    _ => (None, number_base(value)),
+                };
+                let raw = raw.map(|raw| self.atom(raw));
+                Ok(self.ast.expression_numeric_literal(span, value, raw, base))
+            }
+            Some(Json::String(value)) => {
+                let raw = raw.map(|raw| self.atom(raw));
+                Ok(self.ast.expression_string_literal_with_lone_surrogates(
+                    span,
+                    self.atom(&value.value),
+                    raw,
+                    value.lone_surrogates,
+                ))
+            }
+            Some(_) => Err(DeserializeError::new("invalid literal value").at_key("value")),
+            None => Err(DeserializeError::new("missing field `value`")),
+        }
+    }
+
+    /// `Literal` with a string value.
+    pub(super) fn string_literal(&self, node: &Json) -> Result<StringLiteral<'a>> {
+        let ty = node_type(node)?;
+        if ty != "Literal" {
+            return Err(unexpected(ty, "string Literal"));
+        }
+        let Some(Json::String(value)) = node.get("value") else {
+            return Err(DeserializeError::new("expected a string").at_key("value"));
+        };
+        let raw = node.get("raw").and_then(Json::as_str).map(|raw| self.atom(raw));
+        Ok(self.ast.string_literal_with_lone_surrogates(
+            self.span(node),
+            self.atom(&value.value),
+            raw,
+            value.lone_surrogates,
+        ))
+    }
+
+    pub(super) fn template_literal(&self, node: &Json) -> Result<TemplateLiteral<'a>> {
+        let ty = node_type(node)?;
+        if ty != "TemplateLiteral" {
+            return Err(unexpected(ty, "TemplateLiteral"));
+        }
+        let quasis = self.list(node, "quasis", Self::template_element)?;
+        let expressions = self.list(node, "expressions", Self::expression)?;
+        if quasis.len() != expressions.len() + 1 {
+            return Err(DeserializeError::new(
+                "`quasis` must contain 1 more element than `expressions`",
+            ));
+        }
+        Ok(self.ast.template_literal(self.span(node), quasis, expressions))
+    }
+
+    pub(super) fn template_element(&self, node: &Json) -> Result<TemplateElement<'a>> {
+        let ty = node_type(node)?;
+        if ty != "TemplateElement" {
+            return Err(unexpected(ty, "TemplateElement"));
+        }
+        let tail = bool_field(node, "tail");
+        let mut span = self.span(node);
+        // TS-ESTree template element spans include the delimiters (`` ` ``, `${` and `}`)
+        if self.is_ts() && span != Span::default() {
+            span.start += 1;
+            span.end = span.end.saturating_sub(if tail { 1 } else { 2 }).max(span.start);
+        }
+        let (value, lone_surrogates) = self.child(node, "value", |d, value| {
+            let raw = str_field(value, "raw")?;
+            let (cooked, lone_surrogates) = match field(value, "cooked") {
+                Some(Json::String(cooked)) => (Some(d.atom(&cooked.value)), cooked.lone_surrogates),
+                Some(_) => return Err(DeserializeError::new("expected a string").at_key("cooked")),
+                None => (None, false),
+            };
+            Ok((TemplateElementValue { raw: d.atom(raw), cooked }, lone_surrogates))
+        })?;
+        Ok(self.ast.template_element_with_lone_surrogates(span, value, tail, lone_surrogates))
+    }
+}
+
+/// Parse regular expression flags. Returns `None` if flags are invalid or repeated.
+pub(super) fn parse_regexp_flags(flags: &str) -> Option<RegExpFlags> {
+    let mut result = RegExpFlags::empty();
+    for ch in flags.chars() {
+        let flag = RegExpFlags::try_from(ch).ok()?;
+        if result.contains(flag) {
+            return None;
+        }
+        result |= flag;
+    }
+    Some(result)
+}
+
+/// Parse `raw` of a numeric literal, returning its value and base.
+/// Returns `None` if `raw` is not a valid numeric literal.
+fn parse_number_raw(raw: &str) -> Option<(f64, NumberBase)> {
+    let raw: Cow<str> = if raw.contains('_') {
+        Cow::Owned(raw.chars().filter(|&ch| ch != '_').collect())
+    } else {
+        Cow::Borrowed(raw)
+    };
+    let (digits, radix, base) = match raw.get(..2) {
+        Some("0x" | "0X") => (&raw[2..], 16, NumberBase::Hex),
+        Some("0o" | "0O") => (&raw[2..], 8, NumberBase::Octal),
+        Some("0b" | "0B") => (&raw[2..], 2, NumberBase::Binary),
+        // Legacy octal e.g. `017`
+        _ if raw.len() > 1
+            && raw.starts_with('0')
+            && raw.bytes().all(|b| matches!(b, b'0'..=b'7')) =>
+        {
+            (&raw[1..], 8, NumberBase::Octal)
+        }
+        _ => {
+            let base = if raw.bytes().all(|b| b.is_ascii_digit()) {
+                NumberBase::Decimal
+            } else {
+                NumberBase::Float
+            };
+            return raw
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(|value| (value, base));
+        }
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut value = 0f64;
+    for ch in digits.chars() {
+        value = value.mul_add(f64::from(radix), f64::from(ch.to_digit(radix)?));
+    }
+    Some((value, base))
+}
+
+/// Base for a `NumericLiteral` without `raw`.
+fn number_base(value: f64) -> NumberBase {
+    if value.fract() == 0.0 { NumberBase::Decimal } else { NumberBase::Float }
+}
@@ -0,0 +1,470 @@
+//! Deserialize ESTree / TS-ESTree JSON into an Oxc AST.
+//!
+//! Accepts the JSON produced by the serializer in [`crate::serialize`] (with or without TS fields,
+//! and with or without the `{ "node": ..., "fixes": [...] }` wrapper), and ESTree produced by other
+//! tools (e.g. Acorn, Espree, TS-ESLint, Babel with the `estree` plugin).
+//!
+//! Spans are taken from `start` and `end` fields, or `range`, or `loc` (if source text is provided).
+//! If a node has none of these, its span is reconstructed from the spans of its children.
+
+use std::fmt;
+
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_span::{Atom, SPAN, SourceType, Span};
+
+use crate::{AstBuilder, ast::*};
+
+mod js;
+mod json;
+mod jsx;
+mod literal;
+mod ts;
+
+use json::Json;
+
+/// Options for [`ESTreeDeserializer`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DeserializeOptions {
+    /// Offsets in `start`, `end` and `range` fields, and columns in `loc` fields,
+    /// are UTF-16 offsets (as produced by JS tools) rather than UTF-8 byte offsets.
+    ///
+    /// Conversion requires source text.
+    pub utf16_offsets: bool,
+}
+
+/// Error when deserializing ESTree JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeserializeError {
+    path: String,
+    message: String,
+}
+
+impl DeserializeError {
+    fn new(message: impl Into<String>) -> Self {
+        Self { path: String::new(), message: message.into() }
+    }
+
+    /// Path to the node which caused the error, e.g. `body[0].expression.left`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn at_key(mut self, key: &str) -> Self {
+        if self.path.is_empty() || self.path.starts_with('[') {
+            self.path.insert_str(0, key);
+        } else {
+            self.path.insert(0, '.');
+            self.path.insert_str(0, key);
+        }
+        self
+    }
+
+    fn at_index(mut self, index: usize) -> Self {
+        if !self.path.is_empty() && !self.path.starts_with('[') {
+            self.path.insert(0, '.');
+        }
+        self.path.insert_str(0, &format!("[{index}]"));
+        self
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+type Result<T> = std::result::Result<T, DeserializeError>;
+
+/// Deserializer from ESTree / TS-ESTree JSON to [`Program`].
+///
+/// ```ignore
+/// let program = ESTreeDeserializer::new(&allocator, source_text, SourceType::ts())
+///     .deserialize(&json)?;
+/// ```
+pub struct ESTreeDeserializer<'a> {
+    ast: AstBuilder<'a>,
+    source_text: &'a str,
+    source_type: SourceType,
+    options: DeserializeOptions,
+    offsets: Offsets,
+}
+
+impl<'a> ESTreeDeserializer<'a> {
+    /// Create a new [`ESTreeDeserializer`].
+    ///
+    /// `source_text` is stored in the [`Program`], and used to reconstruct spans from `loc` fields
+    /// and UTF-16 offsets. It can be empty if spans are not needed.
+    ///
+    /// `source_type` determines whether the JSON is expected to be TS-ESTree.
+    /// Module kind is taken from `sourceType` field of `Program`, if present.
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            source_text,
+            source_type,
+            options: DeserializeOptions::default(),
+            offsets: Offsets::default(),
+        }
+    }
+
+    /// Set options.
+    #[must_use]
+    pub fn with_options(mut self, options: DeserializeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Deserialize `json` into a [`Program`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not valid JSON, or is not a valid ESTree `Program`.
+    pub fn deserialize(mut self, json: &str) -> Result<Program<'a>> {
+        self.offsets = Offsets::new(self.source_text, self.options.utf16_offsets);
+        let root = json::parse(json).map_err(|(message, offset)| {
+            DeserializeError::new(format!("invalid JSON at offset {offset}: {message}"))
+        })?;
+        // Output of `to_estree_*_json_with_fixes` is wrapped in `{ "node": ..., "fixes": [...] }`
+        let node = match root.get("node") {
+            Some(node) if root.get("type").is_none() => node.get("type").map_or(&root, |_| node),
+            _ => &root,
+        };
+        self.program(node).map_err(|err| if node == &root { err } else { err.at_key("node") })
+    }
+
+    fn program(&mut self, node: &Json) -> Result<Program<'a>> {
+        let ty = node_type(node)?;
+        if ty != "Program" {
+            return Err(unexpected(ty, "Program"));
+        }
+        match node.get("sourceType").and_then(Json::as_str) {
+            Some("module") => self.source_type = self.source_type.with_module(true),
+            Some("script") => self.source_type = self.source_type.with_script(true),
+            Some("commonjs") => self.source_type = SourceType::cjs().with_typescript(self.is_ts()),
+            _ => {}
+        }
+        let (directives, body) = self.child(node, "body", Self::directives_and_statements)?;
+        let hashbang = self.opt_child(node, "hashbang", |d, node| {
+            Ok(d.ast.hashbang(d.span(node), d.atom(str_field(node, "value")?)))
+        })?;
+        let span = self.span(node);
+        let end = span.end.max(u32::try_from(self.source_text.len()).unwrap_or(u32::MAX));
+        Ok(self.ast.program(
+            Span::new(0, end),
+            self.source_type,
+            self.source_text,
+            self.ast.vec(),
+            hashbang,
+            directives,
+            body,
+        ))
+    }
+
+    /// `true` if JSON is expected to be TS-ESTree.
+    fn is_ts(&self) -> bool {
+        self.source_type.is_typescript()
+    }
+
+    fn atom(&self, s: &str) -> Atom<'a> {
+        self.ast.atom(s)
+    }
+
+    fn alloc<T>(&self, value: T) -> Box<'a, T> {
+        self.ast.alloc(value)
+    }
+
+    /// Deserialize required field `key` with `f`.
+    fn child<T>(
+        &self,
+        node: &Json,
+        key: &str,
+        f: impl FnOnce(&Self, &Json) -> Result<T>,
+    ) -> Result<T> {
+        let Some(value) = field(node, key) else {
+            return Err(DeserializeError::new(format!("missing field `{key}`")));
+        };
+        f(self, value).map_err(|err| err.at_key(key))
+    }
+
+    /// Deserialize optional field `key` with `f`. Missing fields and `null` are `None`.
+    fn opt_child<T>(
+        &self,
+        node: &Json,
+        key: &str,
+        f: impl FnOnce(&Self, &Json) -> Result<T>,
+    ) -> Result<Option<T>> {
+        match field(node, key) {
+            Some(value) => f(self, value).map(Some).map_err(|err| err.at_key(key)),
+            None => Ok(None),
+        }
+    }
+
+    /// Deserialize each element of array field `key` with `f`. Missing field is an empty array.
+    fn list<T>(
+        &self,
+        node: &Json,
+        key: &str,
+        mut f: impl FnMut(&Self, &Json) -> Result<T>,
+    ) -> Result<Vec<'a, T>> {
+        let elements = Self::array(node, key)?;
+        let mut vec = self.ast.vec_with_capacity(elements.len());
+        for (index, element) in elements.iter().enumerate() {
+            vec.push(f(self, element).map_err(|err| err.at_index(index).at_key(key))?);
+        }
+        Ok(vec)
+    }
+
+    /// Get elements of array field `key`. Missing field is an empty array.
+    fn array<'j>(node: &'j Json<'j>, key: &str) -> Result<&'j [Json<'j>]> {
+        match field(node, key) {
+            Some(value) => value
+                .as_array()
+                .ok_or_else(|| DeserializeError::new("expected an array").at_key(key)),
+            None => Ok(&[]),
+        }
+    }
+
+    /// Span of `node`.
+    fn span(&self, node: &Json) -> Span {
+        self.own_span(node).or_else(|| self.children_span(node)).unwrap_or(SPAN)
+    }
+
+    fn own_span(&self, node: &Json) -> Option<Span> {
+        if let (Some(start), Some(end)) =
+            (node.get("start").and_then(Json::as_f64), node.get("end").and_then(Json::as_f64))
+        {
+            return Some(self.offsets.span(start, end));
+        }
+        if let Some([start, end]) = node.get("range").and_then(Json::as_array) {
+            if let (Some(start), Some(end)) = (start.as_f64(), end.as_f64()) {
+                return Some(self.offsets.span(start, end));
+            }
+        }
+        let loc = node.get("loc")?;
+        let start = self.offsets.position(self.source_text, loc.get("start")?)?;
+        let end = self.offsets.position(self.source_text, loc.get("end")?)?;
+        Some(Span::new(start, end))
+    }
+
+    /// Span covering all child nodes of `node` which have spans.
+    fn children_span(&self, node: &Json) -> Option<Span> {
+        let mut span: Option<Span> = None;
+        let mut add = |child: &Json| {
+            let child_span = match child {
+                Json::Object(_) => self.own_span(child).or_else(|| self.children_span(child)),
+                Json::Array(_) => self.children_span(child),
+                _ => None,
+            };
+            if let Some(child_span) = child_span {
+                span = Some(span.map_or(child_span, |span| span.merge(child_span)));
+            }
+        };
+        match node {
+            Json::Object(entries) => {
+                entries.iter().filter(|(key, _)| key != "loc").for_each(|(_, value)| add(value));
+            }
+            Json::Array(elements) => elements.iter().for_each(add),
+            _ => {}
+        }
+        span
+    }
+
+    /// Statements, with leading directives split off.
+    fn directives_and_statements(
+        &self,
+        node: &Json,
+    ) -> Result<(Vec<'a, Directive<'a>>, Vec<'a, Statement<'a>>)> {
+        let Some(elements) = node.as_array() else {
+            return Err(DeserializeError::new("expected an array"));
+        };
+        let mut directives = self.ast.vec();
+        let mut statements = self.ast.vec_with_capacity(elements.len());
+        for (index, element) in elements.iter().enumerate() {
+            let result = match element.get("directive").and_then(Json::as_str) {
+                Some(directive) if statements.is_empty() => {
+                    self.directive(element, directive).map(|directive| directives.push(directive))
+                }
+                _ => self.statement(element).map(|statement| statements.push(statement)),
+            };
+            result.map_err(|err| err.at_index(index))?;
+        }
+        Ok((directives, statements))
+    }
+
+    fn directive(&self, node: &Json, directive: &str) -> Result<Directive<'a>> {
+        let expression = self.child(node, "expression", Self::string_literal)?;
+        Ok(self.ast.directive(self.span(node), expression, self.atom(directive)))
+    }
+}
+
+/// Type of a node.
+fn node_type<'j>(node: &'j Json<'j>) -> Result<&'j str> {
+    match node {
+        Json::Object(_) => node
+            .get("type")
+            .and_then(Json::as_str)
+            .ok_or_else(|| DeserializeError::new("missing field `type`")),
+        Json::Null => Err(DeserializeError::new("unexpected `null`")),
+        _ => Err(DeserializeError::new("expected an ESTree node")),
+    }
+}
+
+/// Get field `key` of `node`. `null` is treated as missing.
+fn field<'j>(node: &'j Json<'j>, key: &str) -> Option<&'j Json<'j>> {
+    node.get(key).filter(|value| !value.is_null())
+}
+
+/// Get required field `key` of `node`.
+fn required_field<'j>(node: &'j Json<'j>, key: &str) -> Result<&'j Json<'j>> {
+    field(node, key).ok_or_else(|| DeserializeError::new(format!("missing field `{key}`")))
+}
+
+/// Get required string field `key` of `node`.
+fn str_field<'j>(node: &'j Json<'j>, key: &str) -> Result<&'j str> {
+    match field(node, key) {
+        Some(value) => {
+            value.as_str().ok_or_else(|| DeserializeError::new("expected a string").at_key(key))
+        }
+        None => Err(DeserializeError::new(format!("missing field `{key}`"))),
+    }
+}
+
+/// Get boolean field `key` of `node`. Missing field is `false`.
+fn bool_field(node: &Json, key: &str) -> bool {
+    node.get(key).and_then(Json::as_bool).unwrap_or(false)
+}
+
+fn unexpected(ty: &str, expected: &str) -> DeserializeError {
+    DeserializeError::new(format!("unexpected node type `{ty}`, expected {expected}"))
+}
+
+fn invalid_value(key: &str, value: &str) -> DeserializeError {
+    DeserializeError::new(format!("invalid value `{value}`")).at_key(key)
+}
+
+/// Conversion of offsets in JSON to UTF-8 offsets in source text.
+#[derive(Default)]
+struct Offsets {
+    /// `(utf16_offset, utf8_offset)` after each non-ASCII character, if converting UTF-16 offsets.
+    /// Empty if offsets are UTF-8, or source text is ASCII.
+    utf16_checkpoints: std::vec::Vec<(u32, u32)>,
+    /// Start offsets of lines, for `loc` fields. Empty if no source text.
+    line_starts: std::vec::Vec<u32>,
+    utf16: bool,
+}
+
+impl Offsets {
+    #[expect(clippy::cast_possible_truncation)]
+    fn new(source_text: &str, utf16: bool) -> Self {
+        let mut utf16_checkpoints = vec![];
+        let mut line_starts = vec![];
+        if !source_text.is_empty() {
+            line_starts.push(0);
+        }
+        let mut utf16_offset = 0;
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((utf8_offset, ch)) = chars.next() {
+            utf16_offset += ch.len_utf16() as u32;
+            let next_offset = (utf8_offset + ch.len_utf8()) as u32;
+            match ch {
+                '\r' if chars.peek().is_some_and(|&(_, next)| next == '\n') => {}
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(next_offset),
+                _ => {}
+            }
+            if utf16 && !ch.is_ascii() {
+                utf16_checkpoints.push((utf16_offset, next_offset));
+            }
+        }
+        Self { utf16_checkpoints, line_starts, utf16 }
+    }
+
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn span(&self, start: f64, end: f64) -> Span {
+        Span::new(self.offset(start as u32), self.offset(end as u32))
+    }
+
+    fn offset(&self, offset: u32) -> u32 {
+        if !self.utf16 || self.utf16_checkpoints.is_empty() {
+            return offset;
+        }
+        let index = self.utf16_checkpoints.partition_point(|&(utf16, _)| utf16 <= offset);
+        match index.checked_sub(1) {
+            Some(index) => {
+                let (utf16, utf8) = self.utf16_checkpoints[index];
+                utf8 + (offset - utf16)
+            }
+            None => offset,
+        }
+    }
+
+    /// Convert `{ line, column }` to an offset. Lines are 1-based, columns 0-based.
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn position(&self, source_text: &str, position: &Json) -> Option<u32> {
+        let line = position.get("line")?.as_f64()? as usize;
+        let column = position.get("column")?.as_f64()? as usize;
+        let line_start = *self.line_starts.get(line.checked_sub(1)?)?;
+        if !self.utf16 {
+            return Some(line_start + column as u32);
+        }
+        let mut remaining = column;
+        let mut offset = line_start as usize;
+        for ch in source_text[offset..].chars() {
+            if remaining == 0 {
+                break;
+            }
+            remaining = remaining.saturating_sub(ch.len_utf16());
+            offset += ch.len_utf8();
+        }
+        Some(offset as u32)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DeserializeError, Offsets, json};
+
+    #[test]
+    fn error_path() {
+        let err = DeserializeError::new("oops").at_key("left").at_index(2).at_key("body");
+        assert_eq!(err.to_string(), "body[2].left: oops");
+        let err = DeserializeError::new("oops").at_index(0).at_index(1);
+        assert_eq!(err.path(), "[1][0]");
+    }
+
+    #[test]
+    fn utf16_offsets() {
+        let offsets = Offsets::new("a😀b\nc", true);
+        assert_eq!(offsets.offset(1), 1);
+        assert_eq!(offsets.offset(3), 5);
+        assert_eq!(offsets.offset(5), 7);
+        let position = json::parse(r#"{"line":1,"column":3}"#).unwrap();
+        assert_eq!(offsets.position("a😀b\nc", &position), Some(5));
+        let position = json::parse(r#"{"line":2,"column":1}"#).unwrap();
+        assert_eq!(offsets.position("a😀b\nc", &position), Some(8));
+    }
+
+    #[test]
+    fn json_strings() {
+        let value = json::parse(r#"["a\"bé", "😀", "\ud800�", 1e+400]"#).unwrap();
+        let values = value.as_array().unwrap();
+        assert_eq!(values[0].as_str(), Some("a\"bé"));
+        assert_eq!(values[1].as_str(), Some("😀"));
+        let json::Json::String(lone) = &values[2] else { unreachable!() };
+        assert!(lone.lone_surrogates);
+        assert_eq!(lone.value, "\u{FFFD}d800\u{FFFD}fffd");
+        assert_eq!(values[3].as_f64(), Some(f64::INFINITY));
+        assert!(json::parse("[1,]").is_err());
+    }
+}
@@ -0,0 +1,660 @@
+//! TypeScript types, declarations and expressions.
+
+use oxc_allocator::Box;
+use oxc_span::{GetSpan, Span};
+
+use crate::ast::*;
+
+use super::{
+    DeserializeError, ESTreeDeserializer, Result, bool_field, field, invalid_value, json::Json,
+    node_type, required_field, str_field, unexpected,
+};
+
+// ----------------------------------------
+// Declarations and expressions
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    /// Convert `node` to a TS [`Declaration`]. Returns `None` if `ty` is not a TS declaration type.
+    pub(super) fn ts_declaration(&self, node: &Json, ty: &str) -> Result<Option<Declaration<'a>>> {
+        let span = self.span(node);
+        let declaration = match ty {
+            "TSTypeAliasDeclaration" => {
+                Declaration::TSTypeAliasDeclaration(self.ast.alloc_ts_type_alias_declaration(
+                    span,
+                    self.child(node, "id", Self::binding_identifier)?,
+                    self.opt_child(node, "typeParameters", Self::ts_type_parameter_declaration)?,
+                    self.child(node, "typeAnnotation", Self::ts_type)?,
+                    bool_field(node, "declare"),
+                ))
+            }
+            "TSInterfaceDeclaration" => {
+                Declaration::TSInterfaceDeclaration(self.ts_interface_declaration(node)?)
+            }
+            "TSEnumDeclaration" => {
+                let body = self.child(node, "body", |d, body| {
+                    let members = d.list(body, "members", Self::ts_enum_member)?;
+                    Ok(d.ast.ts_enum_body(d.span(body), members))
+                })?;
+                Declaration::TSEnumDeclaration(self.ast.alloc_ts_enum_declaration(
+                    span,
+                    self.child(node, "id", Self::binding_identifier)?,
+                    body,
+                    bool_field(node, "const"),
+                    bool_field(node, "declare"),
+                ))
+            }
+            "TSModuleDeclaration" => {
+                Declaration::TSModuleDeclaration(self.ts_module_declaration(node)?)
+            }
+            "TSImportEqualsDeclaration" => {
+                let module_reference = self.child(node, "moduleReference", |d, reference| {
+                    if node_type(reference)? == "TSExternalModuleReference" {
+                        let expression = d.child(reference, "expression", Self::string_literal)?;
+                        Ok(TSModuleReference::ExternalModuleReference(
+                            d.ast.alloc_ts_external_module_reference(d.span(reference), expression),
+                        ))
+                    } else {
+                        d.ts_type_name(reference).map(TSModuleReference::from)
+                    }
+                })?;
+                Declaration::TSImportEqualsDeclaration(self.ast.alloc_ts_import_equals_declaration(
+                    span,
+                    self.child(node, "id", Self::binding_identifier)?,
+                    module_reference,
+                    Self::import_or_export_kind(node, "importKind")?,
+                ))
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(declaration))
+    }
+
+    pub(super) fn ts_interface_declaration(
+        &self,
+        node: &Json,
+    ) -> Result<Box<'a, TSInterfaceDeclaration<'a>>> {
+        let extends = self.list(node, "extends", |d, heritage| {
+            let ty = node_type(heritage)?;
+            if ty != "TSInterfaceHeritage" {
+                return Err(unexpected(ty, "TSInterfaceHeritage"));
+            }
+            Ok(d.ast.ts_interface_heritage(
+                d.span(heritage),
+                d.child(heritage, "expression", Self::expression)?,
+                d.opt_child(heritage, "typeArguments", Self::ts_type_parameter_instantiation)?,
+            ))
+        })?;
+        let body = self.child(node, "body", |d, body| {
+            let signatures = d.list(body, "body", Self::ts_signature)?;
+            Ok(d.ast.alloc_ts_interface_body(d.span(body), signatures))
+        })?;
+        Ok(self.ast.alloc_ts_interface_declaration(
+            self.span(node),
+            self.child(node, "id", Self::binding_identifier)?,
+            self.opt_child(node, "typeParameters", Self::ts_type_parameter_declaration)?,
+            extends,
+            body,
+            bool_field(node, "declare"),
+        ))
+    }
+
+    fn ts_enum_member(&self, node: &Json) -> Result<TSEnumMember<'a>> {
+        let computed = bool_field(node, "computed");
+        let id = self.child(node, "id", |d, id| match node_type(id)? {
+            "Identifier" => Ok(TSEnumMemberName::Identifier(d.alloc(d.identifier_name(id)?))),
+            "TemplateLiteral" => {
+                Ok(TSEnumMemberName::ComputedTemplateString(d.alloc(d.template_literal(id)?)))
+            }
+            _ if computed => Ok(TSEnumMemberName::ComputedString(d.alloc(d.string_literal(id)?))),
+            _ => Ok(TSEnumMemberName::String(d.alloc(d.string_literal(id)?))),
+        })?;
+        Ok(self.ast.ts_enum_member(
+            self.span(node),
+            id,
+            self.opt_child(node, "initializer", Self::expression)?,
+        ))
+    }
+
+    /// `TSModuleDeclaration`.
+    ///
+    /// TS-ESTree represents `module X.Y.Z {}` as a single `TSModuleDeclaration` with a `TSQualifiedName`
+    /// as `id`. Our AST represents it as 3 nested `TSModuleDeclaration`s.
+    fn ts_module_declaration(&self, node: &Json) -> Result<Box<'a, TSModuleDeclaration<'a>>> {
+        let span = self.span(node);
+        let kind = match str_field(node, "kind")? {
+            "global" => TSModuleDeclarationKind::Global,
+            "module" => TSModuleDeclarationKind::Module,
+            "namespace" => TSModuleDeclarationKind::Namespace,
+            kind => return Err(invalid_value("kind", kind)),
+        };
+        let body = self.opt_child(node, "body", |d, body| {
+            let ty = node_type(body)?;
+            if ty != "TSModuleBlock" {
+                return Err(unexpected(ty, "TSModuleBlock"));
+            }
+            let (directives, statements) =
+                d.child(body, "body", Self::directives_and_statements)?;
+            Ok(d.ast.alloc_ts_module_block(d.span(body), directives, statements))
+        })?;
+        let mut body = body.map(TSModuleDeclarationBody::TSModuleBlock);
+
+        let id = required_field(node, "id")?;
+        if node_type(id).map_err(|err| err.at_key("id"))? != "TSQualifiedName" {
+            let id = self.child(node, "id", |d, id| match node_type(id)? {
+                "Literal" => Ok(TSModuleDeclarationName::StringLiteral(d.string_literal(id)?)),
+                _ => Ok(TSModuleDeclarationName::Identifier(d.binding_identifier(id)?)),
+            })?;
+            return Ok(self.ast.alloc_ts_module_declaration(
+                span,
+                id,
+                body,
+                kind,
+                bool_field(node, "declare"),
+            ));
+        }
+
+        let mut parts = vec![];
+        self.child(node, "id", |d, id| d.qualified_name_parts(id, &mut parts))?;
+        let (first, rest) = parts.split_first().unwrap();
+        for part in rest.iter().rev() {
+            let inner = self.ast.alloc_ts_module_declaration(
+                Span::new(part.span.start, span.end),
+                TSModuleDeclarationName::Identifier(part.clone()),
+                body,
+                kind,
+                false,
+            );
+            body = Some(TSModuleDeclarationBody::TSModuleDeclaration(inner));
+        }
+        Ok(self.ast.alloc_ts_module_declaration(
+            span,
+            TSModuleDeclarationName::Identifier(first.clone()),
+            body,
+            kind,
+            bool_field(node, "declare"),
+        ))
+    }
+
+    /// Collect identifiers in a `TSQualifiedName` e.g. `X.Y.Z`, in order.
+    fn qualified_name_parts(
+        &self,
+        node: &Json,
+        parts: &mut std::vec::Vec<BindingIdentifier<'a>>,
+    ) -> Result<()> {
+        if node_type(node)? == "TSQualifiedName" {
+            self.child(node, "left", |d, left| d.qualified_name_parts(left, parts))?;
+            parts.push(self.child(node, "right", Self::binding_identifier)?);
+        } else {
+            parts.push(self.binding_identifier(node)?);
+        }
+        Ok(())
+    }
+
+    /// Convert `node` to a TS [`Expression`]. Returns `None` if `ty` is not a TS expression type.
+    pub(super) fn ts_expression(&self, node: &Json, ty: &str) -> Result<Option<Expression<'a>>> {
+        let span = self.span(node);
+        let expression = match ty {
+            "TSAsExpression" => self.ast.expression_ts_as(
+                span,
+                self.child(node, "expression", Self::expression)?,
+                self.child(node, "typeAnnotation", Self::ts_type)?,
+            ),
+            "TSSatisfiesExpression" => self.ast.expression_ts_satisfies(
+                span,
+                self.child(node, "expression", Self::expression)?,
+                self.child(node, "typeAnnotation", Self::ts_type)?,
+            ),
+            "TSTypeAssertion" => self.ast.expression_ts_type_assertion(
+                span,
+                self.child(node, "typeAnnotation", Self::ts_type)?,
+                self.child(node, "expression", Self::expression)?,
+            ),
+            "TSNonNullExpression" => self
+                .ast
+                .expression_ts_non_null(span, self.child(node, "expression", Self::expression)?),
+            "TSInstantiationExpression" => self.ast.expression_ts_instantiation(
+                span,
+                self.child(node, "expression", Self::expression)?,
+                self.child(node, "typeArguments", Self::ts_type_parameter_instantiation)?,
+            ),
+            _ => return Ok(None),
+        };
+        Ok(Some(expression))
+    }
+
+    /// `accessibility` field of a class member or parameter property.
+    pub(super) fn accessibility(node: &Json) -> Result<Option<TSAccessibility>> {
+        match field(node, "accessibility").and_then(Json::as_str) {
+            Some("private") => Ok(Some(TSAccessibility::Private)),
+            Some("protected") => Ok(Some(TSAccessibility::Protected)),
+            Some("public") => Ok(Some(TSAccessibility::Public)),
+            Some(accessibility) => Err(invalid_value("accessibility", accessibility)),
+            None => Ok(None),
+        }
+    }
+
+    /// `TSClassImplements`. TS-ESTree represents a qualified name `X.Y` as a `MemberExpression`.
+    pub(super) fn ts_class_implements(&self, node: &Json) -> Result<TSClassImplements<'a>> {
+        let ty = node_type(node)?;
+        if ty != "TSClassImplements" {
+            return Err(unexpected(ty, "TSClassImplements"));
+        }
+        Ok(self.ast.ts_class_implements(
+            self.span(node),
+            self.child(node, "expression", Self::ts_type_name)?,
+            self.opt_child(node, "typeArguments", Self::ts_type_parameter_instantiation)?,
+        ))
+    }
+}
+
+// ----------------------------------------
+// Types
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    pub(super) fn ts_type_annotation(&self, node: &Json) -> Result<Box<'a, TSTypeAnnotation<'a>>> {
+        let ty = node_type(node)?;
+        if ty != "TSTypeAnnotation" {
+            return Err(unexpected(ty, "TSTypeAnnotation"));
+        }
+        let type_annotation = self.child(node, "typeAnnotation", Self::ts_type)?;
+        Ok(self.ast.alloc_ts_type_annotation(self.span(node), type_annotation))
+    }
+
+    pub(super) fn ts_type(&self, node: &Json) -> Result<TSType<'a>> {
+        let ty = node_type(node)?;
+        let span = self.span(node);
+        let ts_type = match ty {
+            "TSAnyKeyword" => self.ast.ts_type_any_keyword(span),
+            "TSBigIntKeyword" => self.ast.ts_type_big_int_keyword(span),
+            "TSBooleanKeyword" => self.ast.ts_type_boolean_keyword(span),
+            "TSIntrinsicKeyword" => self.ast.ts_type_intrinsic_keyword(span),
+            "TSNeverKeyword" => self.ast.ts_type_never_keyword(span),
+            "TSNullKeyword" => self.ast.ts_type_null_keyword(span),
+            "TSNumberKeyword" => self.ast.ts_type_number_keyword(span),
+            "TSObjectKeyword" => self.ast.ts_type_object_keyword(span),
+            "TSStringKeyword" => self.ast.ts_type_string_keyword(span),
+            "TSSymbolKeyword" => self.ast.ts_type_symbol_keyword(span),
+            "TSUndefinedKeyword" => self.ast.ts_type_undefined_keyword(span),
+            "TSUnknownKeyword" => self.ast.ts_type_unknown_keyword(span),
+            "TSVoidKeyword" => self.ast.ts_type_void_keyword(span),
+            "TSThisType" => self.ast.ts_type_this_type(span),
+            "TSJSDocUnknownType" => self.ast.ts_type_js_doc_unknown_type(span),
+            "TSArrayType" => {
+                self.ast.ts_type_array_type(span, self.child(node, "elementType", Self::ts_type)?)
+            }
+            "TSConditionalType" => self.ast.ts_type_conditional_type(
+                span,
+                self.child(node, "checkType", Self::ts_type)?,
+                self.child(node, "extendsType", Self::ts_type)?,
+                self.child(node, "trueType", Self::ts_type)?,
+                self.child(node, "falseType", Self::ts_type)?,
+            ),
+            "TSFunctionType" | "TSConstructorType" => {
+                let (this_param, params) = self.child(node, "params", |d, params| {
+                    d.formal_parameters(params, FormalParameterKind::Signature)
+                })?;
+                let type_parameters =
+                    self.opt_child(node, "typeParameters", Self::ts_type_parameter_declaration)?;
+                let return_type = self.child(node, "returnType", Self::ts_type_annotation)?;
+                if ty == "TSFunctionType" {
+                    self.ast.ts_type_function_type(
+                        span,
+                        type_parameters,
+                        this_param,
+                        params,
+                        return_type,
+                    )
+                } else {
+                    if this_param.is_some() {
+                        return Err(DeserializeError::new(
+                            "constructor type cannot have a `this` parameter",
+                        )
+                        .at_key("params"));
+                    }
+                    self.ast.ts_type_constructor_type(
+                        span,
+                        bool_field(node, "abstract"),
+                        type_parameters,
+                        params,
+                        return_type,
+                    )
+                }
+            }
+            "TSImportType" => TSType::TSImportType(self.ts_import_type(node)?),
+            "TSIndexedAccessType" => self.ast.ts_type_indexed_access_type(
+                span,
+                self.child(node, "objectType", Self::ts_type)?,
+                self.child(node, "indexType", Self::ts_type)?,
+            ),
+            "TSInferType" => self.ast.ts_type_infer_type(
+                span,
+                self.child(node, "typeParameter", Self::ts_type_parameter)?,
+            ),
+            "TSUnionType" => {
+                self.ast.ts_type_union_type(span, self.list(node, "types", Self::ts_type)?)
+            }
+            "TSIntersectionType" => {
+                self.ast.ts_type_intersection_type(span, self.list(node, "types", Self::ts_type)?)
+            }
+            "TSLiteralType" => {
+                self.ast.ts_type_literal_type(span, self.child(node, "literal", Self::ts_literal)?)
+            }
+            "TSMappedType" => self.ts_mapped_type(node)?,
+            "TSNamedTupleMember" => self.ast.ts_type_named_tuple_member(
+                span,
+                self.child(node, "label", Self::identifier_name)?,
+                self.child(node, "elementType", Self::ts_tuple_element)?,
+                bool_field(node, "optional"),
+            ),
+            "TSTemplateLiteralType" => self.ast.ts_type_template_literal_type(
+                span,
+                self.list(node, "quasis", Self::template_element)?,
+                self.list(node, "types", Self::ts_type)?,
+            ),
+            "TSTupleType" => self
+                .ast
+                .ts_type_tuple_type(span, self.list(node, "elementTypes", Self::ts_tuple_element)?),
+            "TSTypeLiteral" => {
+                self.ast.ts_type_type_literal(span, self.list(node, "members", Self::ts_signature)?)
+            }
+            "TSTypeOperator" => {
+                let operator = match str_field(node, "operator")? {
+                    "keyof" => TSTypeOperatorOperator::Keyof,
+                    "unique" => TSTypeOperatorOperator::Unique,
+                    "readonly" => TSTypeOperatorOperator::Readonly,
+                    operator => return Err(invalid_value("operator", operator)),
+                };
+                self.ast.ts_type_type_operator_type(
+                    span,
+                    operator,
+                    self.child(node, "typeAnnotation", Self::ts_type)?,
+                )
+            }
+            "TSTypePredicate" => {
+                let parameter_name = self.child(node, "parameterName", |d, name| {
+                    if node_type(name)? == "TSThisType" {
+                        Ok(TSTypePredicateName::This(d.ast.ts_this_type(d.span(name))))
+                    } else {
+                        Ok(TSTypePredicateName::Identifier(d.alloc(d.identifier_name(name)?)))
+                    }
+                })?;
+                self.ast.ts_type_type_predicate(
+                    span,
+                    parameter_name,
+                    bool_field(node, "asserts"),
+                    self.opt_child(node, "typeAnnotation", Self::ts_type_annotation)?,
+                )
+            }
+            "TSTypeQuery" => {
+                let expr_name = self.child(node, "exprName", |d, name| {
+                    if node_type(name)? == "TSImportType" {
+                        Ok(TSTypeQueryExprName::TSImportType(d.ts_import_type(name)?))
+                    } else {
+                        d.ts_type_name(name).map(TSTypeQueryExprName::from)
+                    }
+                })?;
+                self.ast.ts_type_type_query(
+                    span,
+                    expr_name,
+                    self.opt_child(node, "typeArguments", Self::ts_type_parameter_instantiation)?,
+                )
+            }
+            "TSTypeReference" => self.ast.ts_type_type_reference(
+                span,
+                self.child(node, "typeName", Self::ts_type_name)?,
+                self.opt_child(node, "typeArguments", Self::ts_type_parameter_instantiation)?,
+            ),
+            "TSParenthesizedType" => self.ast.ts_type_parenthesized_type(
+                span,
+                self.child(node, "typeAnnotation", Self::ts_type)?,
+            ),
+            "TSJSDocNullableType" => self.ast.ts_type_js_doc_nullable_type(
+                span,
+                self.child(node, "typeAnnotation", Self::ts_type)?,
+                bool_field(node, "postfix"),
+            ),
+            "TSJSDocNonNullableType" => self.ast.ts_type_js_doc_non_nullable_type(
+                span,
+                self.child(node, "typeAnnotation", Self::ts_type)?,
+                bool_field(node, "postfix"),
+            ),
+            _ => return Err(unexpected(ty, "TSType")),
+        };
+        Ok(ts_type)
+    }
+
+    fn ts_tuple_element(&self, node: &Json) -> Result<TSTupleElement<'a>> {
+        let span = self.span(node);
+        match node_type(node)? {
+            "TSOptionalType" => Ok(self.ast.ts_tuple_element_optional_type(
+                span,
+                self.child(node, "typeAnnotation", Self::ts_type)?,
+            )),
+            "TSRestType" => Ok(self.ast.ts_tuple_element_rest_type(
+                span,
+                self.child(node, "typeAnnotation", Self::ts_type)?,
+            )),
+            _ => self.ts_type(node).map(TSTupleElement::from),
+        }
+    }
+
+    /// `literal` field of `TSLiteralType`.
+    fn ts_literal(&self, node: &Json) -> Result<TSLiteral<'a>> {
+        let ty = node_type(node)?;
+        let literal = match self.expression(node)? {
+            Expression::BooleanLiteral(lit) => TSLiteral::BooleanLiteral(lit),
+            Expression::NumericLiteral(lit) => TSLiteral::NumericLiteral(lit),
+            Expression::BigIntLiteral(lit) => TSLiteral::BigIntLiteral(lit),
+            Expression::StringLiteral(lit) => TSLiteral::StringLiteral(lit),
+            Expression::TemplateLiteral(lit) => TSLiteral::TemplateLiteral(lit),
+            Expression::UnaryExpression(expr) => TSLiteral::UnaryExpression(expr),
+            _ => return Err(unexpected(ty, "literal type")),
+        };
+        Ok(literal)
+    }
+
+    /// `TSMappedType`. TS-ESTree has `key` and `constraint` fields instead of a `TSTypeParameter`.
+    fn ts_mapped_type(&self, node: &Json) -> Result<TSType<'a>> {
+        let type_parameter = if field(node, "typeParameter").is_some() {
+            self.child(node, "typeParameter", Self::ts_type_parameter)?
+        } else {
+            let name = self.child(node, "key", Self::binding_identifier)?;
+            let constraint = self.opt_child(node, "constraint", Self::ts_type)?;
+            let span = name.span.merge(constraint.as_ref().map_or(name.span, GetSpan::span));
+            self.ast.alloc_ts_type_parameter(span, name, constraint, None, false, false, false)
+        };
+        let modifier = |key: &str| match node.get(key) {
+            None | Some(Json::Null | Json::Bool(false)) => Ok(None),
+            Some(Json::Bool(true)) => Ok(Some(TSMappedTypeModifierOperator::True)),
+            Some(Json::String(s)) if s.value == "+" => Ok(Some(TSMappedTypeModifierOperator::Plus)),
+            Some(Json::String(s)) if s.value == "-" => {
+                Ok(Some(TSMappedTypeModifierOperator::Minus))
+            }
+            Some(_) => Err(DeserializeError::new("invalid modifier").at_key(key)),
+        };
+        Ok(self.ast.ts_type_mapped_type(
+            self.span(node),
+            type_parameter,
+            self.opt_child(node, "nameType", Self::ts_type)?,
+            self.opt_child(node, "typeAnnotation", Self::ts_type)?,
+            modifier("optional")?,
+            modifier("readonly")?,
+        ))
+    }
+
+    fn ts_import_type(&self, node: &Json) -> Result<Box<'a, TSImportType<'a>>> {
+        let options =
+            self.opt_child(node, "options", |d, options| match d.expression(options)? {
+                Expression::ObjectExpression(options) => Ok(options),
+                _ => Err(unexpected(node_type(options)?, "ObjectExpression")),
+            })?;
+        Ok(self.ast.alloc_ts_import_type(
+            self.span(node),
+            self.child(node, "argument", Self::ts_type)?,
+            options,
+            self.opt_child(node, "qualifier", Self::ts_type_name)?,
+            self.opt_child(node, "typeArguments", Self::ts_type_parameter_instantiation)?,
+        ))
+    }
+
+    /// `TSTypeName`. `MemberExpression`s (as used in `TSClassImplements`) are converted
+    /// to `TSQualifiedName`s.
+    fn ts_type_name(&self, node: &Json) -> Result<TSTypeName<'a>> {
+        let ty = node_type(node)?;
+        let span = self.span(node);
+        match ty {
+            "Identifier" => {
+                Ok(TSTypeName::IdentifierReference(self.alloc(self.identifier_reference(node)?)))
+            }
+            "ThisExpression" => Ok(self.ast.ts_type_name_this_expression(span)),
+            "TSQualifiedName" => Ok(self.ast.ts_type_name_qualified_name(
+                span,
+                self.child(node, "left", Self::ts_type_name)?,
+                self.child(node, "right", Self::identifier_name)?,
+            )),
+            "MemberExpression" if !bool_field(node, "computed") => {
+                Ok(self.ast.ts_type_name_qualified_name(
+                    span,
+                    self.child(node, "object", Self::ts_type_name)?,
+                    self.child(node, "property", Self::identifier_name)?,
+                ))
+            }
+            _ => Err(unexpected(ty, "TSTypeName")),
+        }
+    }
+
+    pub(super) fn ts_type_parameter_instantiation(
+        &self,
+        node: &Json,
+    ) -> Result<Box<'a, TSTypeParameterInstantiation<'a>>> {
+        let ty = node_type(node)?;
+        if ty != "TSTypeParameterInstantiation" {
+            return Err(unexpected(ty, "TSTypeParameterInstantiation"));
+        }
+        let params = self.list(node, "params", Self::ts_type)?;
+        Ok(self.ast.alloc_ts_type_parameter_instantiation(self.span(node), params))
+    }
+
+    pub(super) fn ts_type_parameter_declaration(
+        &self,
+        node: &Json,
+    ) -> Result<Box<'a, TSTypeParameterDeclaration<'a>>> {
+        let ty = node_type(node)?;
+        if ty != "TSTypeParameterDeclaration" {
+            return Err(unexpected(ty, "TSTypeParameterDeclaration"));
+        }
+        let params =
+            self.list(node, "params", |d, param| d.ts_type_parameter(param).map(Box::unbox))?;
+        Ok(self.ast.alloc_ts_type_parameter_declaration(self.span(node), params))
+    }
+
+    fn ts_type_parameter(&self, node: &Json) -> Result<Box<'a, TSTypeParameter<'a>>> {
+        let ty = node_type(node)?;
+        if ty != "TSTypeParameter" {
+            return Err(unexpected(ty, "TSTypeParameter"));
+        }
+        Ok(self.ast.alloc_ts_type_parameter(
+            self.span(node),
+            self.child(node, "name", Self::binding_identifier)?,
+            self.opt_child(node, "constraint", Self::ts_type)?,
+            self.opt_child(node, "default", Self::ts_type)?,
+            bool_field(node, "in"),
+            bool_field(node, "out"),
+            bool_field(node, "const"),
+        ))
+    }
+}
+
+// ----------------------------------------
+// Signatures
+// ----------------------------------------
+
+impl<'a> ESTreeDeserializer<'a> {
+    fn ts_signature(&self, node: &Json) -> Result<TSSignature<'a>> {
+        let ty = node_type(node)?;
+        let span = self.span(node);
+        let computed = bool_field(node, "computed");
+        let signature = match ty {
+            "TSIndexSignature" => TSSignature::TSIndexSignature(self.ts_index_signature(node)?),
+            "TSPropertySignature" => self.ast.ts_signature_property_signature(
+                span,
+                computed,
+                bool_field(node, "optional"),
+                bool_field(node, "readonly"),
+                self.child(node, "key", |d, key| d.property_key(key, computed))?,
+                self.opt_child(node, "typeAnnotation", Self::ts_type_annotation)?,
+            ),
+            "TSCallSignatureDeclaration" => {
+                let (this_param, params) = self.child(node, "params", |d, params| {
+                    d.formal_parameters(params, FormalParameterKind::Signature)
+                })?;
+                self.ast.ts_signature_call_signature_declaration(
+                    span,
+                    self.opt_child(node, "typeParameters", Self::ts_type_parameter_declaration)?,
+                    this_param,
+                    params,
+                    self.opt_child(node, "returnType", Self::ts_type_annotation)?,
+                )
+            }
+            "TSConstructSignatureDeclaration" => {
+                let (this_param, params) = self.child(node, "params", |d, params| {
+                    d.formal_parameters(params, FormalParameterKind::Signature)
+                })?;
+                if this_param.is_some() {
+                    return Err(DeserializeError::new(
+                        "construct signature cannot have a `this` parameter",
+                    )
+                    .at_key("params"));
+                }
+                self.ast.ts_signature_construct_signature_declaration(
+                    span,
+                    self.opt_child(node, "typeParameters", Self::ts_type_parameter_declaration)?,
+                    params,
+                    self.opt_child(node, "returnType", Self::ts_type_annotation)?,
+                )
+            }
+            "TSMethodSignature" => {
+                let kind = match str_field(node, "kind")? {
+                    "method" => TSMethodSignatureKind::Method,
+                    "get" => TSMethodSignatureKind::Get,
+                    "set" => TSMethodSignatureKind::Set,
+                    kind => return Err(invalid_value("kind", kind)),
+                };
+                let (this_param, params) = self.child(node, "params", |d, params| {
+                    d.formal_parameters(params, FormalParameterKind::Signature)
+                })?;
+                self.ast.ts_signature_method_signature(
+                    span,
+                    self.child(node, "key", |d, key| d.property_key(key, computed))?,
+                    computed,
+                    bool_field(node, "optional"),
+                    kind,
+                    self.opt_child(node, "typeParameters", Self::ts_type_parameter_declaration)?,
+                    this_param,
+                    params,
+                    self.opt_child(node, "returnType", Self::ts_type_annotation)?,
+                )
+            }
+            _ => return Err(unexpected(ty, "TSSignature")),
+        };
+        Ok(signature)
+    }
+
+    pub(super) fn ts_index_signature(&self, node: &Json) -> Result<Box<'a, TSIndexSignature<'a>>> {
+        let parameters = self.list(node, "parameters", |d, parameter| {
+            let (span, name) = (d.span(parameter), str_field(parameter, "name")?);
+            let type_annotation = d.child(parameter, "typeAnnotation", Self::ts_type_annotation)?;
+            Ok(d.ast.ts_index_signature_name(span, d.atom(name), type_annotation))
+        })?;
+        let type_annotation = self.child(node, "typeAnnotation", Self::ts_type_annotation)?;
+        Ok(self.ast.alloc_ts_index_signature(
+            self.span(node),
+            parameters,
+            type_annotation,
+            bool_field(node, "readonly"),
+            bool_field(node, "static"),
+        ))
+    }
+}
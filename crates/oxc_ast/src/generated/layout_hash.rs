@@ -0,0 +1,14 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/generators/assert_layouts.rs`.
+
+/// Hash of memory layouts of all AST types.
+///
+/// Changes if size, alignment, or field offsets of any type in the AST change.
+#[cfg(target_pointer_width = "64")]
+pub const AST_LAYOUT_HASH: u64 = 0xf514_a227_6af3_3e53;
+
+/// Hash of memory layouts of all AST types.
+///
+/// Changes if size, alignment, or field offsets of any type in the AST change.
+#[cfg(target_pointer_width = "32")]
+pub const AST_LAYOUT_HASH: u64 = 0x81cf_e3cc_8c04_3042;
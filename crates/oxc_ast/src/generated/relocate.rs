@@ -0,0 +1,2882 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/generators/relocate.rs`.
+
+#![allow(clippy::match_same_arms)]
+
+use oxc_allocator::{MalformedImage, Relocate, Relocator};
+
+use crate::ast::*;
+
+impl Relocate for Program<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.source_text, relocator)?;
+            Relocate::relocate(&mut self.comments, relocator)?;
+            Relocate::relocate(&mut self.hashbang, relocator)?;
+            Relocate::relocate(&mut self.directives, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Expression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::BooleanLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NullLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NumericLiteral(it) => Relocate::relocate(it, relocator),
+                Self::BigIntLiteral(it) => Relocate::relocate(it, relocator),
+                Self::RegExpLiteral(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TemplateLiteral(it) => Relocate::relocate(it, relocator),
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::MetaProperty(it) => Relocate::relocate(it, relocator),
+                Self::Super(it) => Relocate::relocate(it, relocator),
+                Self::ArrayExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrowFunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentExpression(it) => Relocate::relocate(it, relocator),
+                Self::AwaitExpression(it) => Relocate::relocate(it, relocator),
+                Self::BinaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::CallExpression(it) => Relocate::relocate(it, relocator),
+                Self::ChainExpression(it) => Relocate::relocate(it, relocator),
+                Self::ClassExpression(it) => Relocate::relocate(it, relocator),
+                Self::ConditionalExpression(it) => Relocate::relocate(it, relocator),
+                Self::FunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::ImportExpression(it) => Relocate::relocate(it, relocator),
+                Self::LogicalExpression(it) => Relocate::relocate(it, relocator),
+                Self::NewExpression(it) => Relocate::relocate(it, relocator),
+                Self::ObjectExpression(it) => Relocate::relocate(it, relocator),
+                Self::ParenthesizedExpression(it) => Relocate::relocate(it, relocator),
+                Self::SequenceExpression(it) => Relocate::relocate(it, relocator),
+                Self::TaggedTemplateExpression(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+                Self::UnaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::UpdateExpression(it) => Relocate::relocate(it, relocator),
+                Self::YieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateInExpression(it) => Relocate::relocate(it, relocator),
+                Self::JSXElement(it) => Relocate::relocate(it, relocator),
+                Self::JSXFragment(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSInstantiationExpression(it) => Relocate::relocate(it, relocator),
+                Self::V8IntrinsicExpression(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for IdentifierName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for IdentifierReference<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for BindingIdentifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for LabelIdentifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ThisExpression {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for ArrayExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.elements, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ArrayExpressionElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::SpreadElement(it) => Relocate::relocate(it, relocator),
+                Self::BooleanLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NullLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NumericLiteral(it) => Relocate::relocate(it, relocator),
+                Self::BigIntLiteral(it) => Relocate::relocate(it, relocator),
+                Self::RegExpLiteral(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TemplateLiteral(it) => Relocate::relocate(it, relocator),
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::MetaProperty(it) => Relocate::relocate(it, relocator),
+                Self::Super(it) => Relocate::relocate(it, relocator),
+                Self::ArrayExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrowFunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentExpression(it) => Relocate::relocate(it, relocator),
+                Self::AwaitExpression(it) => Relocate::relocate(it, relocator),
+                Self::BinaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::CallExpression(it) => Relocate::relocate(it, relocator),
+                Self::ChainExpression(it) => Relocate::relocate(it, relocator),
+                Self::ClassExpression(it) => Relocate::relocate(it, relocator),
+                Self::ConditionalExpression(it) => Relocate::relocate(it, relocator),
+                Self::FunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::ImportExpression(it) => Relocate::relocate(it, relocator),
+                Self::LogicalExpression(it) => Relocate::relocate(it, relocator),
+                Self::NewExpression(it) => Relocate::relocate(it, relocator),
+                Self::ObjectExpression(it) => Relocate::relocate(it, relocator),
+                Self::ParenthesizedExpression(it) => Relocate::relocate(it, relocator),
+                Self::SequenceExpression(it) => Relocate::relocate(it, relocator),
+                Self::TaggedTemplateExpression(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+                Self::UnaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::UpdateExpression(it) => Relocate::relocate(it, relocator),
+                Self::YieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateInExpression(it) => Relocate::relocate(it, relocator),
+                Self::JSXElement(it) => Relocate::relocate(it, relocator),
+                Self::JSXFragment(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSInstantiationExpression(it) => Relocate::relocate(it, relocator),
+                Self::V8IntrinsicExpression(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::Elision(_) => Ok(()),
+            }
+        }
+    }
+}
+
+impl Relocate for ObjectExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.properties, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ObjectPropertyKind<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::ObjectProperty(it) => Relocate::relocate(it, relocator),
+                Self::SpreadProperty(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for ObjectProperty<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.key, relocator)?;
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for PropertyKey<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::StaticIdentifier(it) => Relocate::relocate(it, relocator),
+                Self::PrivateIdentifier(it) => Relocate::relocate(it, relocator),
+                Self::BooleanLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NullLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NumericLiteral(it) => Relocate::relocate(it, relocator),
+                Self::BigIntLiteral(it) => Relocate::relocate(it, relocator),
+                Self::RegExpLiteral(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TemplateLiteral(it) => Relocate::relocate(it, relocator),
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::MetaProperty(it) => Relocate::relocate(it, relocator),
+                Self::Super(it) => Relocate::relocate(it, relocator),
+                Self::ArrayExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrowFunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentExpression(it) => Relocate::relocate(it, relocator),
+                Self::AwaitExpression(it) => Relocate::relocate(it, relocator),
+                Self::BinaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::CallExpression(it) => Relocate::relocate(it, relocator),
+                Self::ChainExpression(it) => Relocate::relocate(it, relocator),
+                Self::ClassExpression(it) => Relocate::relocate(it, relocator),
+                Self::ConditionalExpression(it) => Relocate::relocate(it, relocator),
+                Self::FunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::ImportExpression(it) => Relocate::relocate(it, relocator),
+                Self::LogicalExpression(it) => Relocate::relocate(it, relocator),
+                Self::NewExpression(it) => Relocate::relocate(it, relocator),
+                Self::ObjectExpression(it) => Relocate::relocate(it, relocator),
+                Self::ParenthesizedExpression(it) => Relocate::relocate(it, relocator),
+                Self::SequenceExpression(it) => Relocate::relocate(it, relocator),
+                Self::TaggedTemplateExpression(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+                Self::UnaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::UpdateExpression(it) => Relocate::relocate(it, relocator),
+                Self::YieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateInExpression(it) => Relocate::relocate(it, relocator),
+                Self::JSXElement(it) => Relocate::relocate(it, relocator),
+                Self::JSXFragment(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSInstantiationExpression(it) => Relocate::relocate(it, relocator),
+                Self::V8IntrinsicExpression(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TemplateLiteral<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.quasis, relocator)?;
+            Relocate::relocate(&mut self.expressions, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TaggedTemplateExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.tag, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Relocate::relocate(&mut self.quasi, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TemplateElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TemplateElementValue<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.raw, relocator)?;
+            Relocate::relocate(&mut self.cooked, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ComputedMemberExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.object, relocator)?;
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for StaticMemberExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.object, relocator)?;
+            Relocate::relocate(&mut self.property, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for PrivateFieldExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.object, relocator)?;
+            Relocate::relocate(&mut self.field, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for CallExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.callee, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Relocate::relocate(&mut self.arguments, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for NewExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.callee, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Relocate::relocate(&mut self.arguments, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for MetaProperty<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.meta, relocator)?;
+            Relocate::relocate(&mut self.property, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for SpreadElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Argument<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::SpreadElement(it) => Relocate::relocate(it, relocator),
+                Self::BooleanLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NullLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NumericLiteral(it) => Relocate::relocate(it, relocator),
+                Self::BigIntLiteral(it) => Relocate::relocate(it, relocator),
+                Self::RegExpLiteral(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TemplateLiteral(it) => Relocate::relocate(it, relocator),
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::MetaProperty(it) => Relocate::relocate(it, relocator),
+                Self::Super(it) => Relocate::relocate(it, relocator),
+                Self::ArrayExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrowFunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentExpression(it) => Relocate::relocate(it, relocator),
+                Self::AwaitExpression(it) => Relocate::relocate(it, relocator),
+                Self::BinaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::CallExpression(it) => Relocate::relocate(it, relocator),
+                Self::ChainExpression(it) => Relocate::relocate(it, relocator),
+                Self::ClassExpression(it) => Relocate::relocate(it, relocator),
+                Self::ConditionalExpression(it) => Relocate::relocate(it, relocator),
+                Self::FunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::ImportExpression(it) => Relocate::relocate(it, relocator),
+                Self::LogicalExpression(it) => Relocate::relocate(it, relocator),
+                Self::NewExpression(it) => Relocate::relocate(it, relocator),
+                Self::ObjectExpression(it) => Relocate::relocate(it, relocator),
+                Self::ParenthesizedExpression(it) => Relocate::relocate(it, relocator),
+                Self::SequenceExpression(it) => Relocate::relocate(it, relocator),
+                Self::TaggedTemplateExpression(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+                Self::UnaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::UpdateExpression(it) => Relocate::relocate(it, relocator),
+                Self::YieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateInExpression(it) => Relocate::relocate(it, relocator),
+                Self::JSXElement(it) => Relocate::relocate(it, relocator),
+                Self::JSXFragment(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSInstantiationExpression(it) => Relocate::relocate(it, relocator),
+                Self::V8IntrinsicExpression(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for UpdateExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for UnaryExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for BinaryExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.left, relocator)?;
+            Relocate::relocate(&mut self.right, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for PrivateInExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.left, relocator)?;
+            Relocate::relocate(&mut self.right, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for LogicalExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.left, relocator)?;
+            Relocate::relocate(&mut self.right, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ConditionalExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.test, relocator)?;
+            Relocate::relocate(&mut self.consequent, relocator)?;
+            Relocate::relocate(&mut self.alternate, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for AssignmentExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.left, relocator)?;
+            Relocate::relocate(&mut self.right, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for AssignmentTarget<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::AssignmentTargetIdentifier(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrayAssignmentTarget(it) => Relocate::relocate(it, relocator),
+                Self::ObjectAssignmentTarget(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for SimpleAssignmentTarget<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::AssignmentTargetIdentifier(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for ArrayAssignmentTarget<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.elements, relocator)?;
+            Relocate::relocate(&mut self.rest, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ObjectAssignmentTarget<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.properties, relocator)?;
+            Relocate::relocate(&mut self.rest, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for AssignmentTargetRest<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.target, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for AssignmentTargetMaybeDefault<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::AssignmentTargetWithDefault(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentTargetIdentifier(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrayAssignmentTarget(it) => Relocate::relocate(it, relocator),
+                Self::ObjectAssignmentTarget(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for AssignmentTargetWithDefault<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.binding, relocator)?;
+            Relocate::relocate(&mut self.init, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for AssignmentTargetProperty<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::AssignmentTargetPropertyIdentifier(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentTargetPropertyProperty(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for AssignmentTargetPropertyIdentifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.binding, relocator)?;
+            Relocate::relocate(&mut self.init, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for AssignmentTargetPropertyProperty<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Relocate::relocate(&mut self.binding, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for SequenceExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expressions, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Super {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for AwaitExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ChainExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ChainElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::CallExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for ParenthesizedExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Statement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::BlockStatement(it) => Relocate::relocate(it, relocator),
+                Self::BreakStatement(it) => Relocate::relocate(it, relocator),
+                Self::ContinueStatement(it) => Relocate::relocate(it, relocator),
+                Self::DebuggerStatement(it) => Relocate::relocate(it, relocator),
+                Self::DoWhileStatement(it) => Relocate::relocate(it, relocator),
+                Self::EmptyStatement(it) => Relocate::relocate(it, relocator),
+                Self::ExpressionStatement(it) => Relocate::relocate(it, relocator),
+                Self::ForInStatement(it) => Relocate::relocate(it, relocator),
+                Self::ForOfStatement(it) => Relocate::relocate(it, relocator),
+                Self::ForStatement(it) => Relocate::relocate(it, relocator),
+                Self::IfStatement(it) => Relocate::relocate(it, relocator),
+                Self::LabeledStatement(it) => Relocate::relocate(it, relocator),
+                Self::ReturnStatement(it) => Relocate::relocate(it, relocator),
+                Self::SwitchStatement(it) => Relocate::relocate(it, relocator),
+                Self::ThrowStatement(it) => Relocate::relocate(it, relocator),
+                Self::TryStatement(it) => Relocate::relocate(it, relocator),
+                Self::WhileStatement(it) => Relocate::relocate(it, relocator),
+                Self::WithStatement(it) => Relocate::relocate(it, relocator),
+                Self::VariableDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::FunctionDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::ClassDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAliasDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSInterfaceDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSEnumDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSModuleDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSImportEqualsDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::ImportDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::ExportAllDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::ExportDefaultDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::ExportNamedDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSExportAssignment(it) => Relocate::relocate(it, relocator),
+                Self::TSNamespaceExportDeclaration(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for Directive<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Relocate::relocate(&mut self.directive, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Hashbang<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for BlockStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Declaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::VariableDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::FunctionDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::ClassDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAliasDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSInterfaceDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSEnumDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSModuleDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSImportEqualsDeclaration(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for VariableDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.declarations, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for VariableDeclarator<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.id, relocator)?;
+            Relocate::relocate(&mut self.init, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for EmptyStatement {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for ExpressionStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for IfStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.test, relocator)?;
+            Relocate::relocate(&mut self.consequent, relocator)?;
+            Relocate::relocate(&mut self.alternate, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for DoWhileStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Relocate::relocate(&mut self.test, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for WhileStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.test, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ForStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.init, relocator)?;
+            Relocate::relocate(&mut self.test, relocator)?;
+            Relocate::relocate(&mut self.update, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ForStatementInit<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::VariableDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::BooleanLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NullLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NumericLiteral(it) => Relocate::relocate(it, relocator),
+                Self::BigIntLiteral(it) => Relocate::relocate(it, relocator),
+                Self::RegExpLiteral(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TemplateLiteral(it) => Relocate::relocate(it, relocator),
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::MetaProperty(it) => Relocate::relocate(it, relocator),
+                Self::Super(it) => Relocate::relocate(it, relocator),
+                Self::ArrayExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrowFunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentExpression(it) => Relocate::relocate(it, relocator),
+                Self::AwaitExpression(it) => Relocate::relocate(it, relocator),
+                Self::BinaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::CallExpression(it) => Relocate::relocate(it, relocator),
+                Self::ChainExpression(it) => Relocate::relocate(it, relocator),
+                Self::ClassExpression(it) => Relocate::relocate(it, relocator),
+                Self::ConditionalExpression(it) => Relocate::relocate(it, relocator),
+                Self::FunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::ImportExpression(it) => Relocate::relocate(it, relocator),
+                Self::LogicalExpression(it) => Relocate::relocate(it, relocator),
+                Self::NewExpression(it) => Relocate::relocate(it, relocator),
+                Self::ObjectExpression(it) => Relocate::relocate(it, relocator),
+                Self::ParenthesizedExpression(it) => Relocate::relocate(it, relocator),
+                Self::SequenceExpression(it) => Relocate::relocate(it, relocator),
+                Self::TaggedTemplateExpression(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+                Self::UnaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::UpdateExpression(it) => Relocate::relocate(it, relocator),
+                Self::YieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateInExpression(it) => Relocate::relocate(it, relocator),
+                Self::JSXElement(it) => Relocate::relocate(it, relocator),
+                Self::JSXFragment(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSInstantiationExpression(it) => Relocate::relocate(it, relocator),
+                Self::V8IntrinsicExpression(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for ForInStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.left, relocator)?;
+            Relocate::relocate(&mut self.right, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ForStatementLeft<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::VariableDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentTargetIdentifier(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrayAssignmentTarget(it) => Relocate::relocate(it, relocator),
+                Self::ObjectAssignmentTarget(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for ForOfStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.left, relocator)?;
+            Relocate::relocate(&mut self.right, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ContinueStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.label, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for BreakStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.label, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ReturnStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for WithStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.object, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for SwitchStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.discriminant, relocator)?;
+            Relocate::relocate(&mut self.cases, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for SwitchCase<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.test, relocator)?;
+            Relocate::relocate(&mut self.consequent, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for LabeledStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.label, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ThrowStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TryStatement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.block, relocator)?;
+            Relocate::relocate(&mut self.handler, relocator)?;
+            Relocate::relocate(&mut self.finalizer, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for CatchClause<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.param, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for CatchParameter<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.pattern, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for DebuggerStatement {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for BindingPattern<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.kind, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for BindingPatternKind<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::BindingIdentifier(it) => Relocate::relocate(it, relocator),
+                Self::ObjectPattern(it) => Relocate::relocate(it, relocator),
+                Self::ArrayPattern(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentPattern(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for AssignmentPattern<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.left, relocator)?;
+            Relocate::relocate(&mut self.right, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ObjectPattern<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.properties, relocator)?;
+            Relocate::relocate(&mut self.rest, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for BindingProperty<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.key, relocator)?;
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ArrayPattern<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.elements, relocator)?;
+            Relocate::relocate(&mut self.rest, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for BindingRestElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Function<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.id, relocator)?;
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.this_param, relocator)?;
+            Relocate::relocate(&mut self.params, relocator)?;
+            Relocate::relocate(&mut self.return_type, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for FormalParameters<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.items, relocator)?;
+            Relocate::relocate(&mut self.rest, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for FormalParameter<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.decorators, relocator)?;
+            Relocate::relocate(&mut self.pattern, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for FunctionBody<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.directives, relocator)?;
+            Relocate::relocate(&mut self.statements, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ArrowFunctionExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.params, relocator)?;
+            Relocate::relocate(&mut self.return_type, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for YieldExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Class<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.decorators, relocator)?;
+            Relocate::relocate(&mut self.id, relocator)?;
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.super_class, relocator)?;
+            Relocate::relocate(&mut self.super_type_arguments, relocator)?;
+            Relocate::relocate(&mut self.implements, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ClassBody<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ClassElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::StaticBlock(it) => Relocate::relocate(it, relocator),
+                Self::MethodDefinition(it) => Relocate::relocate(it, relocator),
+                Self::PropertyDefinition(it) => Relocate::relocate(it, relocator),
+                Self::AccessorProperty(it) => Relocate::relocate(it, relocator),
+                Self::TSIndexSignature(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for MethodDefinition<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.decorators, relocator)?;
+            Relocate::relocate(&mut self.key, relocator)?;
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for PropertyDefinition<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.decorators, relocator)?;
+            Relocate::relocate(&mut self.key, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for PrivateIdentifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for StaticBlock<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for AccessorProperty<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.decorators, relocator)?;
+            Relocate::relocate(&mut self.key, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ImportExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.source, relocator)?;
+            Relocate::relocate(&mut self.options, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ImportDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.specifiers, relocator)?;
+            Relocate::relocate(&mut self.source, relocator)?;
+            Relocate::relocate(&mut self.with_clause, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ImportDeclarationSpecifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::ImportSpecifier(it) => Relocate::relocate(it, relocator),
+                Self::ImportDefaultSpecifier(it) => Relocate::relocate(it, relocator),
+                Self::ImportNamespaceSpecifier(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for ImportSpecifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.imported, relocator)?;
+            Relocate::relocate(&mut self.local, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ImportDefaultSpecifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.local, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ImportNamespaceSpecifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.local, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for WithClause<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.attributes_keyword, relocator)?;
+            Relocate::relocate(&mut self.with_entries, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ImportAttribute<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.key, relocator)?;
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ImportAttributeKey<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for ExportNamedDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.declaration, relocator)?;
+            Relocate::relocate(&mut self.specifiers, relocator)?;
+            Relocate::relocate(&mut self.source, relocator)?;
+            Relocate::relocate(&mut self.with_clause, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ExportDefaultDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.exported, relocator)?;
+            Relocate::relocate(&mut self.declaration, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ExportAllDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.exported, relocator)?;
+            Relocate::relocate(&mut self.source, relocator)?;
+            Relocate::relocate(&mut self.with_clause, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ExportSpecifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.local, relocator)?;
+            Relocate::relocate(&mut self.exported, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ExportDefaultDeclarationKind<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::FunctionDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::ClassDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSInterfaceDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::BooleanLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NullLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NumericLiteral(it) => Relocate::relocate(it, relocator),
+                Self::BigIntLiteral(it) => Relocate::relocate(it, relocator),
+                Self::RegExpLiteral(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TemplateLiteral(it) => Relocate::relocate(it, relocator),
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::MetaProperty(it) => Relocate::relocate(it, relocator),
+                Self::Super(it) => Relocate::relocate(it, relocator),
+                Self::ArrayExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrowFunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentExpression(it) => Relocate::relocate(it, relocator),
+                Self::AwaitExpression(it) => Relocate::relocate(it, relocator),
+                Self::BinaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::CallExpression(it) => Relocate::relocate(it, relocator),
+                Self::ChainExpression(it) => Relocate::relocate(it, relocator),
+                Self::ClassExpression(it) => Relocate::relocate(it, relocator),
+                Self::ConditionalExpression(it) => Relocate::relocate(it, relocator),
+                Self::FunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::ImportExpression(it) => Relocate::relocate(it, relocator),
+                Self::LogicalExpression(it) => Relocate::relocate(it, relocator),
+                Self::NewExpression(it) => Relocate::relocate(it, relocator),
+                Self::ObjectExpression(it) => Relocate::relocate(it, relocator),
+                Self::ParenthesizedExpression(it) => Relocate::relocate(it, relocator),
+                Self::SequenceExpression(it) => Relocate::relocate(it, relocator),
+                Self::TaggedTemplateExpression(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+                Self::UnaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::UpdateExpression(it) => Relocate::relocate(it, relocator),
+                Self::YieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateInExpression(it) => Relocate::relocate(it, relocator),
+                Self::JSXElement(it) => Relocate::relocate(it, relocator),
+                Self::JSXFragment(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSInstantiationExpression(it) => Relocate::relocate(it, relocator),
+                Self::V8IntrinsicExpression(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for ModuleExportName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::IdentifierName(it) => Relocate::relocate(it, relocator),
+                Self::IdentifierReference(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for V8IntrinsicExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Relocate::relocate(&mut self.arguments, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for BooleanLiteral {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for NullLiteral {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for NumericLiteral<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.raw, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for StringLiteral<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.value, relocator)?;
+            Relocate::relocate(&mut self.raw, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for BigIntLiteral<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.value, relocator)?;
+            Relocate::relocate(&mut self.raw, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for RegExpLiteral<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.regex, relocator)?;
+            Relocate::relocate(&mut self.raw, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for RegExp<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.pattern, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for RegExpPattern<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.text, relocator)?;
+            Relocate::relocate(&mut self.pattern, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.opening_element, relocator)?;
+            Relocate::relocate(&mut self.children, relocator)?;
+            Relocate::relocate(&mut self.closing_element, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXOpeningElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Relocate::relocate(&mut self.attributes, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXClosingElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXFragment<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.children, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXElementName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::IdentifierReference(it) => Relocate::relocate(it, relocator),
+                Self::NamespacedName(it) => Relocate::relocate(it, relocator),
+                Self::MemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for JSXNamespacedName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.namespace, relocator)?;
+            Relocate::relocate(&mut self.name, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXMemberExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.object, relocator)?;
+            Relocate::relocate(&mut self.property, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXMemberExpressionObject<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::IdentifierReference(it) => Relocate::relocate(it, relocator),
+                Self::MemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for JSXExpressionContainer<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::BooleanLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NullLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NumericLiteral(it) => Relocate::relocate(it, relocator),
+                Self::BigIntLiteral(it) => Relocate::relocate(it, relocator),
+                Self::RegExpLiteral(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TemplateLiteral(it) => Relocate::relocate(it, relocator),
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::MetaProperty(it) => Relocate::relocate(it, relocator),
+                Self::Super(it) => Relocate::relocate(it, relocator),
+                Self::ArrayExpression(it) => Relocate::relocate(it, relocator),
+                Self::ArrowFunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::AssignmentExpression(it) => Relocate::relocate(it, relocator),
+                Self::AwaitExpression(it) => Relocate::relocate(it, relocator),
+                Self::BinaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::CallExpression(it) => Relocate::relocate(it, relocator),
+                Self::ChainExpression(it) => Relocate::relocate(it, relocator),
+                Self::ClassExpression(it) => Relocate::relocate(it, relocator),
+                Self::ConditionalExpression(it) => Relocate::relocate(it, relocator),
+                Self::FunctionExpression(it) => Relocate::relocate(it, relocator),
+                Self::ImportExpression(it) => Relocate::relocate(it, relocator),
+                Self::LogicalExpression(it) => Relocate::relocate(it, relocator),
+                Self::NewExpression(it) => Relocate::relocate(it, relocator),
+                Self::ObjectExpression(it) => Relocate::relocate(it, relocator),
+                Self::ParenthesizedExpression(it) => Relocate::relocate(it, relocator),
+                Self::SequenceExpression(it) => Relocate::relocate(it, relocator),
+                Self::TaggedTemplateExpression(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+                Self::UnaryExpression(it) => Relocate::relocate(it, relocator),
+                Self::UpdateExpression(it) => Relocate::relocate(it, relocator),
+                Self::YieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateInExpression(it) => Relocate::relocate(it, relocator),
+                Self::JSXElement(it) => Relocate::relocate(it, relocator),
+                Self::JSXFragment(it) => Relocate::relocate(it, relocator),
+                Self::TSAsExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSSatisfiesExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeAssertion(it) => Relocate::relocate(it, relocator),
+                Self::TSNonNullExpression(it) => Relocate::relocate(it, relocator),
+                Self::TSInstantiationExpression(it) => Relocate::relocate(it, relocator),
+                Self::V8IntrinsicExpression(it) => Relocate::relocate(it, relocator),
+                Self::ComputedMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::StaticMemberExpression(it) => Relocate::relocate(it, relocator),
+                Self::PrivateFieldExpression(it) => Relocate::relocate(it, relocator),
+                Self::EmptyExpression(_) => Ok(()),
+            }
+        }
+    }
+}
+
+impl Relocate for JSXAttributeItem<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::Attribute(it) => Relocate::relocate(it, relocator),
+                Self::SpreadAttribute(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for JSXAttribute<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXSpreadAttribute<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXAttributeName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::NamespacedName(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for JSXAttributeValue<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+                Self::ExpressionContainer(it) => Relocate::relocate(it, relocator),
+                Self::Element(it) => Relocate::relocate(it, relocator),
+                Self::Fragment(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for JSXIdentifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXChild<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::Text(it) => Relocate::relocate(it, relocator),
+                Self::Element(it) => Relocate::relocate(it, relocator),
+                Self::Fragment(it) => Relocate::relocate(it, relocator),
+                Self::ExpressionContainer(it) => Relocate::relocate(it, relocator),
+                Self::Spread(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for JSXSpreadChild<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSXText<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.value, relocator)?;
+            Relocate::relocate(&mut self.raw, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSThisParameter<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSEnumDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.id, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSEnumBody<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.members, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSEnumMember<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.id, relocator)?;
+            Relocate::relocate(&mut self.initializer, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSEnumMemberName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::String(it) => Relocate::relocate(it, relocator),
+                Self::ComputedString(it) => Relocate::relocate(it, relocator),
+                Self::ComputedTemplateString(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSTypeAnnotation<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSLiteralType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.literal, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSLiteral<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::BooleanLiteral(it) => Relocate::relocate(it, relocator),
+                Self::NumericLiteral(it) => Relocate::relocate(it, relocator),
+                Self::BigIntLiteral(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TemplateLiteral(it) => Relocate::relocate(it, relocator),
+                Self::UnaryExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::TSAnyKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSBigIntKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSBooleanKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSIntrinsicKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSNeverKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSNullKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSNumberKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSObjectKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSStringKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSSymbolKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSUndefinedKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSUnknownKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSVoidKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSArrayType(it) => Relocate::relocate(it, relocator),
+                Self::TSConditionalType(it) => Relocate::relocate(it, relocator),
+                Self::TSConstructorType(it) => Relocate::relocate(it, relocator),
+                Self::TSFunctionType(it) => Relocate::relocate(it, relocator),
+                Self::TSImportType(it) => Relocate::relocate(it, relocator),
+                Self::TSIndexedAccessType(it) => Relocate::relocate(it, relocator),
+                Self::TSInferType(it) => Relocate::relocate(it, relocator),
+                Self::TSIntersectionType(it) => Relocate::relocate(it, relocator),
+                Self::TSLiteralType(it) => Relocate::relocate(it, relocator),
+                Self::TSMappedType(it) => Relocate::relocate(it, relocator),
+                Self::TSNamedTupleMember(it) => Relocate::relocate(it, relocator),
+                Self::TSTemplateLiteralType(it) => Relocate::relocate(it, relocator),
+                Self::TSThisType(it) => Relocate::relocate(it, relocator),
+                Self::TSTupleType(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeOperatorType(it) => Relocate::relocate(it, relocator),
+                Self::TSTypePredicate(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeQuery(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeReference(it) => Relocate::relocate(it, relocator),
+                Self::TSUnionType(it) => Relocate::relocate(it, relocator),
+                Self::TSParenthesizedType(it) => Relocate::relocate(it, relocator),
+                Self::JSDocNullableType(it) => Relocate::relocate(it, relocator),
+                Self::JSDocNonNullableType(it) => Relocate::relocate(it, relocator),
+                Self::JSDocUnknownType(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSConditionalType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.check_type, relocator)?;
+            Relocate::relocate(&mut self.extends_type, relocator)?;
+            Relocate::relocate(&mut self.true_type, relocator)?;
+            Relocate::relocate(&mut self.false_type, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSUnionType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.types, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSIntersectionType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.types, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSParenthesizedType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeOperator<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSArrayType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.element_type, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSIndexedAccessType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.object_type, relocator)?;
+            Relocate::relocate(&mut self.index_type, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTupleType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.element_types, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSNamedTupleMember<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.label, relocator)?;
+            Relocate::relocate(&mut self.element_type, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSOptionalType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSRestType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTupleElement<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::TSOptionalType(it) => Relocate::relocate(it, relocator),
+                Self::TSRestType(it) => Relocate::relocate(it, relocator),
+                Self::TSAnyKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSBigIntKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSBooleanKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSIntrinsicKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSNeverKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSNullKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSNumberKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSObjectKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSStringKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSSymbolKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSUndefinedKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSUnknownKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSVoidKeyword(it) => Relocate::relocate(it, relocator),
+                Self::TSArrayType(it) => Relocate::relocate(it, relocator),
+                Self::TSConditionalType(it) => Relocate::relocate(it, relocator),
+                Self::TSConstructorType(it) => Relocate::relocate(it, relocator),
+                Self::TSFunctionType(it) => Relocate::relocate(it, relocator),
+                Self::TSImportType(it) => Relocate::relocate(it, relocator),
+                Self::TSIndexedAccessType(it) => Relocate::relocate(it, relocator),
+                Self::TSInferType(it) => Relocate::relocate(it, relocator),
+                Self::TSIntersectionType(it) => Relocate::relocate(it, relocator),
+                Self::TSLiteralType(it) => Relocate::relocate(it, relocator),
+                Self::TSMappedType(it) => Relocate::relocate(it, relocator),
+                Self::TSNamedTupleMember(it) => Relocate::relocate(it, relocator),
+                Self::TSTemplateLiteralType(it) => Relocate::relocate(it, relocator),
+                Self::TSThisType(it) => Relocate::relocate(it, relocator),
+                Self::TSTupleType(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeLiteral(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeOperatorType(it) => Relocate::relocate(it, relocator),
+                Self::TSTypePredicate(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeQuery(it) => Relocate::relocate(it, relocator),
+                Self::TSTypeReference(it) => Relocate::relocate(it, relocator),
+                Self::TSUnionType(it) => Relocate::relocate(it, relocator),
+                Self::TSParenthesizedType(it) => Relocate::relocate(it, relocator),
+                Self::JSDocNullableType(it) => Relocate::relocate(it, relocator),
+                Self::JSDocNonNullableType(it) => Relocate::relocate(it, relocator),
+                Self::JSDocUnknownType(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSAnyKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSStringKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSBooleanKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSNumberKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSNeverKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSIntrinsicKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSUnknownKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSNullKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSUndefinedKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSVoidKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSSymbolKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSThisType {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSObjectKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSBigIntKeyword {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for TSTypeReference<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_name, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::IdentifierReference(it) => Relocate::relocate(it, relocator),
+                Self::QualifiedName(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSQualifiedName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.left, relocator)?;
+            Relocate::relocate(&mut self.right, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeParameterInstantiation<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.params, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeParameter<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Relocate::relocate(&mut self.constraint, relocator)?;
+            Relocate::relocate(&mut self.default, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeParameterDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.params, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeAliasDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.id, relocator)?;
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSClassImplements<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSInterfaceDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.id, relocator)?;
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.extends, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSInterfaceBody<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSPropertySignature<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.key, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSSignature<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::TSIndexSignature(it) => Relocate::relocate(it, relocator),
+                Self::TSPropertySignature(it) => Relocate::relocate(it, relocator),
+                Self::TSCallSignatureDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSConstructSignatureDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSMethodSignature(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSIndexSignature<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.parameters, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSCallSignatureDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.this_param, relocator)?;
+            Relocate::relocate(&mut self.params, relocator)?;
+            Relocate::relocate(&mut self.return_type, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSMethodSignature<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.key, relocator)?;
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.this_param, relocator)?;
+            Relocate::relocate(&mut self.params, relocator)?;
+            Relocate::relocate(&mut self.return_type, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSConstructSignatureDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.params, relocator)?;
+            Relocate::relocate(&mut self.return_type, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSIndexSignatureName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSInterfaceHeritage<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypePredicate<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.parameter_name, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypePredicateName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::This(_) => Ok(()),
+            }
+        }
+    }
+}
+
+impl Relocate for TSModuleDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.id, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSModuleDeclarationName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::Identifier(it) => Relocate::relocate(it, relocator),
+                Self::StringLiteral(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSModuleDeclarationBody<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::TSModuleDeclaration(it) => Relocate::relocate(it, relocator),
+                Self::TSModuleBlock(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSModuleBlock<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.directives, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeLiteral<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.members, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSInferType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_parameter, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeQuery<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expr_name, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeQueryExprName<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::TSImportType(it) => Relocate::relocate(it, relocator),
+                Self::IdentifierReference(it) => Relocate::relocate(it, relocator),
+                Self::QualifiedName(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSImportType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.argument, relocator)?;
+            Relocate::relocate(&mut self.options, relocator)?;
+            Relocate::relocate(&mut self.qualifier, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSFunctionType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.this_param, relocator)?;
+            Relocate::relocate(&mut self.params, relocator)?;
+            Relocate::relocate(&mut self.return_type, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSConstructorType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_parameters, relocator)?;
+            Relocate::relocate(&mut self.params, relocator)?;
+            Relocate::relocate(&mut self.return_type, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSMappedType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_parameter, relocator)?;
+            Relocate::relocate(&mut self.name_type, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTemplateLiteralType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.quasis, relocator)?;
+            Relocate::relocate(&mut self.types, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSAsExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSSatisfiesExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSTypeAssertion<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSImportEqualsDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.id, relocator)?;
+            Relocate::relocate(&mut self.module_reference, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSModuleReference<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::ExternalModuleReference(it) => Relocate::relocate(it, relocator),
+                Self::IdentifierReference(it) => Relocate::relocate(it, relocator),
+                Self::QualifiedName(it) => Relocate::relocate(it, relocator),
+                Self::ThisExpression(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for TSExternalModuleReference<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSNonNullExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Decorator<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSExportAssignment<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSNamespaceExportDeclaration<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.id, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for TSInstantiationExpression<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.expression, relocator)?;
+            Relocate::relocate(&mut self.type_arguments, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSDocNullableType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSDocNonNullableType<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.type_annotation, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for JSDocUnknownType {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for Comment {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
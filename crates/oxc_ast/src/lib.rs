@@ -23,6 +23,7 @@
 //!
 //! ## Cargo Features
 //! * `"serialize"` enables support for serialization to ESTree JSON
+//! * `"deserialize"` enables support for deserialization from ESTree JSON
 //!
 //! [`BindingIdentifier`]: ast::BindingIdentifier
 //! [`IdentifierReference`]: ast::IdentifierReference
@@ -43,6 +44,8 @@
 
 #![warn(missing_docs)]
 
+#[cfg(feature = "deserialize")]
+mod deserialize;
 #[cfg(feature = "serialize")]
 mod serialize;
 
@@ -51,6 +54,7 @@ mod ast_builder_impl;
 mod ast_impl;
 mod ast_kind_impl;
 pub mod precedence;
+pub mod snapshot;
 mod trivia;
 
 mod generated {
@@ -68,6 +72,8 @@ mod generated {
     pub mod derive_get_span_mut;
     pub mod derive_take_in;
     pub mod get_id;
+    pub mod layout_hash;
+    mod relocate;
 }
 
 pub use generated::{ast_builder, ast_kind};
@@ -80,6 +86,9 @@ pub use crate::{
     trivia::{CommentsRange, comments_range, has_comments_between},
 };
 
+#[cfg(feature = "deserialize")]
+pub use crate::deserialize::{DeserializeError, DeserializeOptions, ESTreeDeserializer};
+
 // After experimenting with two types of boxed enum variants:
 //   1.
 //   ```
@@ -1,10 +1,11 @@
-use std::cmp;
+use std::{borrow::Cow, cmp};
 
 use oxc_ast_macros::ast_meta;
 use oxc_estree::{
-    CompactFixesJSSerializer, CompactFixesTSSerializer, CompactJSSerializer, CompactTSSerializer,
-    Concat2, ESTree, JsonSafeString, PrettyFixesJSSerializer, PrettyFixesTSSerializer,
-    PrettyJSSerializer, PrettyTSSerializer, Serializer, StructSerializer,
+    CompactBabelJSSerializer, CompactBabelTSSerializer, CompactFixesJSSerializer,
+    CompactFixesTSSerializer, CompactJSSerializer, CompactTSSerializer, Concat2, ESTree,
+    JsonSafeString, PrettyFixesJSSerializer, PrettyFixesTSSerializer, PrettyJSSerializer,
+    PrettyTSSerializer, SequenceSerializer, Serializer, StructSerializer,
 };
 use oxc_span::GetSpan;
 
@@ -105,6 +106,28 @@ impl Program<'_> {
         let serializer = PrettyFixesJSSerializer::with_capacity(capacity, ranges);
         serializer.serialize_with_fixes(self)
     }
+
+    /// Serialize AST to JSON which is the base for a Babel-compatible AST, including TypeScript fields.
+    ///
+    /// `Program` is in Babel form, with separate `directives` and `interpreter` fields.
+    /// All other nodes are in ESTree form, and are converted to Babel form on JS side.
+    pub fn to_babel_ts_json(&self, ranges: bool) -> String {
+        let capacity = self.source_text.len() * JSON_CAPACITY_RATIO_COMPACT;
+        let mut serializer = CompactBabelTSSerializer::with_capacity(capacity, ranges);
+        self.serialize(&mut serializer);
+        serializer.into_string()
+    }
+
+    /// Serialize AST to JSON which is the base for a Babel-compatible AST, without TypeScript fields.
+    ///
+    /// `Program` is in Babel form, with separate `directives` and `interpreter` fields.
+    /// All other nodes are in ESTree form, and are converted to Babel form on JS side.
+    pub fn to_babel_js_json(&self, ranges: bool) -> String {
+        let capacity = self.source_text.len() * JSON_CAPACITY_RATIO_COMPACT;
+        let mut serializer = CompactBabelJSSerializer::with_capacity(capacity, ranges);
+        self.serialize(&mut serializer);
+        serializer.into_string()
+    }
 }
 
 /// Serializer for `Program`.
@@ -166,6 +189,11 @@ impl ESTree for ProgramConverter<'_, '_> {
     fn serialize<S: Serializer>(&self, serializer: S) {
         let program = self.0;
 
+        if S::BABEL {
+            serialize_babel_program(program, serializer);
+            return;
+        }
+
         let mut state = serializer.serialize_struct();
         state.serialize_field("type", &JsonSafeString("Program"));
         state.serialize_field("body", &Concat2(&program.directives, &program.body));
@@ -183,6 +211,97 @@ impl ESTree for ProgramConverter<'_, '_> {
     }
 }
 
+/// Serialize `Program` in Babel form.
+///
+/// Babel keeps directives separate from statements, and represents hashbang as `interpreter`.
+/// Unlike TS-ESLint, Babel's `Program` span covers the whole file, in TS files too.
+fn serialize_babel_program<S: Serializer>(program: &Program<'_>, serializer: S) {
+    let mut state = serializer.serialize_struct();
+    state.serialize_field("type", &JsonSafeString("Program"));
+    state.serialize_field("body", &program.body);
+    state.serialize_field("directives", &BabelDirectives(&program.directives));
+    state.serialize_field("sourceType", &program.source_type.module_kind());
+    state.serialize_field("interpreter", &program.hashbang.as_ref().map(BabelInterpreter));
+    state.serialize_span(program.span);
+    state.end();
+}
+
+/// Serializer for `Program::directives` in Babel form.
+struct BabelDirectives<'a, 'b>(&'b [Directive<'a>]);
+
+impl ESTree for BabelDirectives<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut seq = serializer.serialize_sequence();
+        for directive in self.0 {
+            seq.serialize_element(&BabelDirective(directive));
+        }
+        seq.end();
+    }
+}
+
+/// Serializer for `Directive` in Babel form.
+struct BabelDirective<'a, 'b>(&'b Directive<'a>);
+
+impl ESTree for BabelDirective<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let directive = self.0;
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString("Directive"));
+        state.serialize_field("value", &BabelDirectiveLiteral(directive));
+        state.serialize_span(directive.span);
+        state.end();
+    }
+}
+
+/// Serializer for `DirectiveLiteral` (Babel). `value` is the directive as written, without unescaping.
+struct BabelDirectiveLiteral<'a, 'b>(&'b Directive<'a>);
+
+impl ESTree for BabelDirectiveLiteral<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let directive = self.0;
+        let value = directive.directive.as_str();
+        let raw = match directive.expression.raw {
+            Some(raw) => Cow::Borrowed(raw.as_str()),
+            None => Cow::Owned(format!("\"{value}\"")),
+        };
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString("DirectiveLiteral"));
+        state.serialize_field("value", value);
+        state.serialize_field("extra", &BabelExtra { raw: &raw, raw_value: value });
+        state.serialize_span(directive.expression.span);
+        state.end();
+    }
+}
+
+/// Serializer for `extra` field of Babel nodes.
+struct BabelExtra<'s> {
+    raw: &'s str,
+    raw_value: &'s str,
+}
+
+impl ESTree for BabelExtra<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("raw", self.raw);
+        state.serialize_field("rawValue", self.raw_value);
+        state.end();
+    }
+}
+
+/// Serializer for `Hashbang` as Babel's `InterpreterDirective`.
+struct BabelInterpreter<'a, 'b>(&'b Hashbang<'a>);
+
+impl ESTree for BabelInterpreter<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let hashbang = self.0;
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString("InterpreterDirective"));
+        state.serialize_field("value", &hashbang.value);
+        state.serialize_span(hashbang.span);
+        state.end();
+    }
+}
+
 fn get_ts_start_span(program: &Program<'_>) -> u32 {
     if let Some(first_directive) = program.directives.first() {
         return first_directive.span.start;
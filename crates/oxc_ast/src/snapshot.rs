@@ -0,0 +1,128 @@
+//! Binary snapshots of a [`Program`], for caching parsed ASTs on disk.
+//!
+//! A snapshot is a relocatable image of an arena containing the AST (see [`Allocator::write_image`]).
+//! Loading a snapshot copies it into an [`Allocator`] and rebases the pointers within it,
+//! which is much faster than parsing the source text again.
+//!
+//! Snapshots are only compatible with builds in which all AST types have the same memory layout.
+//! This is checked by comparing [`AST_LAYOUT_HASH`], which `oxc_ast_tools` generates
+//! from the same layouts as `assert_layouts`.
+//!
+//! ```ignore
+//! let mut bytes = vec![];
+//! oxc_ast::snapshot::write_program(&program, &mut bytes);
+//!
+//! // Later, maybe in another process
+//! let allocator = Allocator::default();
+//! let program = unsafe { oxc_ast::snapshot::read_program(&allocator, &bytes) }?;
+//! ```
+//!
+//! Semantic IDs (`scope_id`, `symbol_id`, `reference_id`) are preserved, so `Scoping` can be cached
+//! alongside the AST with `Scoping::write_snapshot`.
+
+use std::{fmt, ptr};
+
+use oxc_allocator::Allocator;
+
+use crate::ast::Program;
+
+pub use crate::generated::layout_hash::AST_LAYOUT_HASH;
+
+/// Magic bytes at start of a snapshot.
+const MAGIC: [u8; 8] = *b"OXCAST\0\0";
+
+/// Version of snapshot format.
+///
+/// Must be incremented whenever the format changes.
+const VERSION: u32 = 1;
+
+/// Written in native byte order, to detect snapshots created on a platform with different endianness.
+const ENDIANNESS: u32 = 0x0102_0304;
+
+/// Size of snapshot header (magic + version + endianness + layout hash).
+const HEADER_SIZE: usize = 24;
+
+/// Error when loading a snapshot with [`read_program`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// Bytes are not an AST snapshot.
+    NotASnapshot,
+    /// Snapshot was created with a different version of the snapshot format,
+    /// or on a platform with different endianness.
+    IncompatibleVersion,
+    /// Snapshot was created by a build with different AST type layouts.
+    IncompatibleLayout,
+    /// Snapshot is truncated or corrupt.
+    Malformed,
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::NotASnapshot => "Not an AST snapshot",
+            Self::IncompatibleVersion => "AST snapshot was created by an incompatible version",
+            Self::IncompatibleLayout => {
+                "AST snapshot was created with a different AST memory layout"
+            }
+            Self::Malformed => "AST snapshot is malformed",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Serialize a [`Program`] into a binary snapshot, appended to `out`.
+///
+/// The AST is copied into a fresh [`Allocator`] along with all strings it references
+/// (including source text), so the snapshot is self-contained (see [`Allocator::write_image`]).
+pub fn write_program(program: &Program<'_>, out: &mut Vec<u8>) {
+    out.extend_from_slice(&MAGIC);
+    out.extend_from_slice(&VERSION.to_le_bytes());
+    out.extend_from_slice(&ENDIANNESS.to_ne_bytes());
+    out.extend_from_slice(&AST_LAYOUT_HASH.to_le_bytes());
+    Allocator::write_image(program, out);
+}
+
+/// Load a [`Program`] from a snapshot created by [`write_program`] into `allocator`.
+///
+/// # Errors
+///
+/// Returns an error if `bytes` is not a snapshot, the snapshot was created by an incompatible build,
+/// or it is truncated or corrupt.
+///
+/// # SAFETY
+///
+/// `bytes` must be a snapshot created by [`write_program`].
+/// Truncated and corrupted snapshots are detected with a checksum, and all pointers in the AST are
+/// checked to be within the snapshot. But the AST's contents are not validated, so loading
+/// a snapshot which has been crafted to pass those checks is undefined behavior.
+/// If snapshots are stored in a location which may be tampered with, do not load them.
+pub unsafe fn read_program<'a>(
+    allocator: &'a Allocator,
+    bytes: &[u8],
+) -> Result<Program<'a>, SnapshotError> {
+    let Some((header, image)) = bytes.split_at_checked(HEADER_SIZE) else {
+        return Err(SnapshotError::NotASnapshot);
+    };
+    let (magic, header) = header.split_at(MAGIC.len());
+    if magic != MAGIC {
+        return Err(SnapshotError::NotASnapshot);
+    }
+    let (version, header) = header.split_at(4);
+    let (endianness, layout_hash) = header.split_at(4);
+    if version != VERSION.to_le_bytes() || endianness != ENDIANNESS.to_ne_bytes() {
+        return Err(SnapshotError::IncompatibleVersion);
+    }
+    if layout_hash != AST_LAYOUT_HASH.to_le_bytes() {
+        return Err(SnapshotError::IncompatibleLayout);
+    }
+
+    // SAFETY: Caller guarantees that `image` was created by `write_program`, which writes an image
+    // with a `Program` as root. Layout hash matches, so all AST types have same layout as in this build.
+    let program = unsafe { allocator.read_image::<Program<'a>>(image) };
+    let program = program.map_err(|_| SnapshotError::Malformed)?;
+    // SAFETY: `program` is a valid `Program` in the arena. Arena never drops its contents,
+    // and `program` reference is not used again, so this is a move out of the arena.
+    Ok(unsafe { ptr::read(program) })
+}
@@ -4,7 +4,7 @@ use std::borrow::Cow;
 use oxc_ast::{Comment, CommentKind, ast::Program};
 use oxc_syntax::identifier::is_line_terminator;
 
-use crate::{Codegen, LegalComment, options::CommentOptions};
+use crate::{Codegen, IndentChar, LegalComment, options::CommentOptions};
 
 pub type CommentsMap = FxHashMap</* attached_to */ u32, Vec<Comment>>;
 
@@ -83,6 +83,12 @@ impl Codegen<'_> {
         }
     }
 
+    /// Whether the current line only contains indentation with spaces.
+    fn is_indented_line_start(&self) -> bool {
+        self.options.indent_char == IndentChar::Space
+            && self.code.as_bytes().iter().rev().take_while(|&&b| b != b'\n').all(|&b| b == b' ')
+    }
+
     pub(crate) fn print_comments(&mut self, comments: &[Comment]) {
         for (i, comment) in comments.iter().enumerate() {
             if i == 0 {
@@ -92,6 +98,7 @@ impl Codegen<'_> {
                         match b {
                             b'\n' => self.print_indent(),
                             b'\t' => { /* noop */ }
+                            b' ' if self.is_indented_line_start() => { /* noop */ }
                             _ => {
                                 self.print_hard_newline();
                                 self.print_indent();
@@ -50,7 +50,11 @@ impl Gen for Program<'_> {
             hashbang.print(p, ctx);
         }
         p.print_directives_and_statements(&self.directives, &self.body, ctx);
-        p.print_semicolon_if_needed();
+        // Not `print_semicolon_if_needed`, which may also break the line.
+        if p.needs_semicolon {
+            p.print_semicolon();
+            p.needs_semicolon = false;
+        }
         // Print trailing statement comments.
         p.print_comments_at(self.span.end);
     }
@@ -20,6 +20,8 @@ mod str;
 
 use std::borrow::Cow;
 
+use sha1::{Digest, Sha1};
+
 use oxc_ast::ast::*;
 use oxc_data_structures::{code_buffer::CodeBuffer, stack::Stack};
 use oxc_semantic::Scoping;
@@ -40,7 +42,7 @@ use crate::{
 pub use crate::{
     context::Context,
     r#gen::{Gen, GenExpr},
-    options::{CodegenOptions, CommentOptions, LegalComment},
+    options::{CodegenOptions, CommentOptions, IndentChar, LegalComment, LineEnding},
 };
 
 /// Output from [`Codegen::build`]
@@ -109,6 +111,11 @@ pub struct Codegen<'a> {
     /// Track the current indentation level
     indent: u32,
 
+    /// Start of the current line, for [CodegenOptions::max_line_len].
+    line_start: usize,
+    /// End of the code which has been searched for line breaks to update `line_start`.
+    line_start_searched: usize,
+
     /// Fast path for [CodegenOptions::single_quote]
     quote: Quote,
 
@@ -161,6 +168,8 @@ impl<'a> Codegen<'a> {
             start_of_default_export: 0,
             is_jsx: false,
             indent: 0,
+            line_start: 0,
+            line_start_searched: 0,
             quote: Quote::Double,
             comments: CommentsMap::default(),
             sourcemap_builder: None,
@@ -205,11 +214,44 @@ impl<'a> Codegen<'a> {
         }
         program.print(&mut self, Context::default());
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
-        let code = self.code.into_string();
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let debug_id = (self.sourcemap_builder.is_some() && self.options.source_map_debug_id)
+            .then(|| self.print_debug_id());
+        let code = self.options.line_ending.convert(self.code.into_string());
+        let map = self
+            .sourcemap_builder
+            .map(|builder| builder.into_sourcemap(&self.options, debug_id.as_deref()));
         CodegenReturn { code, map, legal_comments }
     }
 
+    /// Print `//# debugId=` with an ID derived from the code printed so far, and return the ID.
+    ///
+    /// The ID is taken from the SHA-1 hash of the code, so it is the same across runs and platforms,
+    /// and formatted as a UUID, as required by the
+    /// [Debug ID proposal](https://github.com/tc39/ecma426/blob/main/proposals/debug-id.md).
+    fn print_debug_id(&mut self) -> String {
+        let hash = Sha1::digest(self.code.as_bytes());
+        let high = u64::from_be_bytes(hash[..8].try_into().unwrap());
+        let low = u64::from_be_bytes(hash[8..16].try_into().unwrap());
+        // Set the version (4) and variant (RFC 4122) bits.
+        let high = (high & !0xF000) | 0x4000;
+        let low = (low & !(0b11 << 62)) | (0b10 << 62);
+        let debug_id = format!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            high >> 32,
+            (high >> 16) & 0xFFFF,
+            high & 0xFFFF,
+            low >> 48,
+            low & 0xFFFF_FFFF_FFFF
+        );
+        if self.code.last_byte().is_some_and(|b| b != b'\n') {
+            self.print_ascii_byte(b'\n');
+        }
+        self.print_str("//# debugId=");
+        self.print_str(&debug_id);
+        self.print_ascii_byte(b'\n');
+        debug_id
+    }
+
     /// Turn what's been built so far into a string. Like [`build`],
     /// this fininishes a print and returns the generated source code. Unlike
     /// [`build`], no source map is generated.
@@ -220,7 +262,7 @@ impl<'a> Codegen<'a> {
     /// [`print_expression`]: Codegen::print_expression
     #[must_use]
     pub fn into_source_text(self) -> String {
-        self.code.into_string()
+        self.options.line_ending.convert(self.code.into_string())
     }
 
     /// Push a single ASCII byte into the buffer.
@@ -401,6 +443,7 @@ impl<'a> Codegen<'a> {
     #[inline]
     fn print_comma(&mut self) {
         self.print_ascii_byte(b',');
+        self.print_newline_if_over_line_limit();
     }
 
     #[inline]
@@ -467,7 +510,10 @@ impl<'a> Codegen<'a> {
             self.print_next_indent_as_space = false;
             return;
         }
-        self.code.print_indent(self.indent as usize);
+        self.code.print_indent_with(
+            self.options.indent_char,
+            self.indent as usize * self.options.indent_width,
+        );
     }
 
     #[inline]
@@ -485,6 +531,29 @@ impl<'a> Codegen<'a> {
             self.print_semicolon();
             self.needs_semicolon = false;
         }
+        self.print_newline_if_over_line_limit();
+    }
+
+    /// Print a newline if the current line is longer than [CodegenOptions::max_line_len].
+    ///
+    /// Only call this between tokens where a line break does not change the meaning of the code,
+    /// e.g. not after `return` or before postfix `++`.
+    fn print_newline_if_over_line_limit(&mut self) {
+        let Some(max_line_len) = self.options.max_line_len else { return };
+        if !self.options.minify {
+            return;
+        }
+        // Only search code printed since the last call, so long lines are not searched repeatedly.
+        let code = self.code.as_bytes();
+        if let Some(i) = code[self.line_start_searched..].iter().rposition(|&b| b == b'\n') {
+            self.line_start = self.line_start_searched + i + 1;
+        }
+        self.line_start_searched = code.len();
+        if code.len() - self.line_start > max_line_len {
+            self.print_hard_newline();
+            self.line_start = self.code.len();
+            self.line_start_searched = self.line_start;
+        }
     }
 
     #[inline]
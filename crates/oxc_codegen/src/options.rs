@@ -1,18 +1,52 @@
 use std::path::PathBuf;
 
+pub use oxc_data_structures::code_buffer::IndentChar;
+
 /// Codegen Options.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct CodegenOptions {
     /// Use single quotes instead of double quotes.
     ///
     /// Default is `false`.
     pub single_quote: bool,
 
+    /// Use the other quote for strings which contain more of the preferred quote than the other,
+    /// so fewer quotes need to be escaped. e.g. `"it's"` instead of `'it\'s'` with [Self::single_quote].
+    ///
+    /// Always enabled with [Self::minify].
+    ///
+    /// Default is `false`.
+    pub minimize_quote_escapes: bool,
+
     /// Remove whitespace.
     ///
     /// Default is `false`.
     pub minify: bool,
 
+    /// Character to indent with.
+    ///
+    /// Default is [IndentChar::Tab].
+    pub indent_char: IndentChar,
+
+    /// Number of [Self::indent_char] per indentation level.
+    ///
+    /// Default is `1`.
+    pub indent_width: usize,
+
+    /// Line ending to print.
+    ///
+    /// Default is [LineEnding::Lf].
+    pub line_ending: LineEnding,
+
+    /// Break lines longer than this number of bytes in minified output, like esbuild's `--line-limit`.
+    ///
+    /// Lines are only broken between statements, class members and after commas,
+    /// where a line break does not change the meaning of the code,
+    /// so some lines may be longer. Only applies with [Self::minify].
+    ///
+    /// Default is `None` - no limit.
+    pub max_line_len: Option<usize>,
+
     /// Print comments?
     ///
     /// At present, only some leading comments are preserved.
@@ -26,18 +60,57 @@ pub struct CodegenOptions {
     ///
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
+
+    /// Include the source text in the `sourcesContent` field of the sourcemap.
+    ///
+    /// Default is `true`.
+    pub source_map_sources_content: bool,
+
+    /// Set the `sourceRoot` field of the sourcemap.
+    ///
+    /// Default is `None`.
+    pub source_map_source_root: Option<String>,
+
+    /// List the source in the `x_google_ignoreList` field of the sourcemap,
+    /// so that debuggers skip it, e.g. for vendor files.
+    ///
+    /// Default is `false`.
+    pub source_map_ignore_list: bool,
+
+    /// Add a Debug ID, derived from the generated code, to the `debugId` field of the sourcemap
+    /// and as a `//# debugId=` comment at the end of the code.
+    ///
+    /// <https://github.com/tc39/ecma426/blob/main/proposals/debug-id.md>
+    ///
+    /// Default is `false`.
+    pub source_map_debug_id: bool,
 }
 
-impl CodegenOptions {
-    /// Minify whitespace and remove comments.
-    pub fn minify() -> Self {
+impl Default for CodegenOptions {
+    fn default() -> Self {
         Self {
             single_quote: false,
-            minify: true,
-            comments: CommentOptions::disabled(),
+            minimize_quote_escapes: false,
+            minify: false,
+            indent_char: IndentChar::default(),
+            indent_width: 1,
+            line_ending: LineEnding::default(),
+            max_line_len: None,
+            comments: CommentOptions::default(),
             source_map_path: None,
+            source_map_sources_content: true,
+            source_map_source_root: None,
+            source_map_ignore_list: false,
+            source_map_debug_id: false,
         }
     }
+}
+
+impl CodegenOptions {
+    /// Minify whitespace and remove comments.
+    pub fn minify() -> Self {
+        Self { minify: true, comments: CommentOptions::disabled(), ..Self::default() }
+    }
 
     #[inline]
     pub(crate) fn print_normal_comment(&self) -> bool {
@@ -60,6 +133,43 @@ impl CodegenOptions {
     }
 }
 
+/// Line ending
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum LineEnding {
+    /// `\n` (default).
+    #[default]
+    Lf,
+    /// `\r\n`.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Convert `\n` in `code` to this line ending.
+    ///
+    /// `\r\n` is not changed. It can only come from comments printed as they are in the source.
+    /// Line breaks in template literals are normalized to `\n` when evaluated,
+    /// so converting them does not change the meaning of the code.
+    /// Source maps are not affected, because they count `\r\n` as a single line break
+    /// and `\r` is only inserted at the end of lines.
+    pub(crate) fn convert(self, code: String) -> String {
+        match self {
+            Self::Lf => code,
+            Self::CrLf => {
+                let mut converted = String::with_capacity(code.len() + code.len() / 32);
+                let mut prev = '\0';
+                for c in code.chars() {
+                    if c == '\n' && prev != '\r' {
+                        converted.push('\r');
+                    }
+                    converted.push(c);
+                    prev = c;
+                }
+                converted
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Comment Options
 pub struct CommentOptions {
@@ -1,10 +1,12 @@
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
 use nonmax::NonMaxU32;
 use oxc_index::{Idx, IndexVec};
 use oxc_span::Span;
 use oxc_syntax::identifier::{LS, PS};
 
+use crate::CodegenOptions;
+
 // Irregular line breaks - '\u{2028}' (LS) and '\u{2029}' (PS)
 const LS_OR_PS_FIRST: u8 = 0xE2;
 const LS_OR_PS_SECOND: u8 = 0x80;
@@ -99,8 +101,34 @@ impl<'a> SourcemapBuilder<'a> {
         }
     }
 
-    pub fn into_sourcemap(self) -> oxc_sourcemap::SourceMap {
-        self.sourcemap_builder.into_sourcemap()
+    pub fn into_sourcemap(
+        self,
+        options: &CodegenOptions,
+        debug_id: Option<&str>,
+    ) -> oxc_sourcemap::SourceMap {
+        let mut sourcemap = self.sourcemap_builder.into_sourcemap();
+        if !options.source_map_sources_content {
+            sourcemap.set_source_contents(vec![]);
+        }
+        // `oxc_sourcemap::SourceMap` has no setter for `sourceRoot`, it can only be passed to the constructor.
+        if let Some(source_root) = &options.source_map_source_root {
+            sourcemap = oxc_sourcemap::SourceMap::new(
+                sourcemap.get_file().map(Arc::from),
+                sourcemap.get_names().map(Arc::from).collect(),
+                Some(source_root.clone()),
+                sourcemap.get_sources().map(Arc::from).collect(),
+                sourcemap.get_source_contents().map(|content| content.map(Arc::from)).collect(),
+                sourcemap.get_tokens().cloned().collect(),
+                None,
+            );
+        }
+        if options.source_map_ignore_list {
+            sourcemap.set_x_google_ignore_list(vec![self.source_id]);
+        }
+        if let Some(debug_id) = debug_id {
+            sourcemap.set_debug_id(debug_id);
+        }
+        sourcemap
     }
 
     pub fn add_source_mapping_for_name(&mut self, output: &[u8], span: Span, name: &str) {
@@ -538,7 +566,7 @@ mod test {
         let mut builder = SourcemapBuilder::new(Path::new("x.js"), "ab");
         builder.add_source_mapping_for_name(output, Span::new(0, 1), "a");
         builder.add_source_mapping_for_name(output, Span::new(1, 2), "c");
-        let sm = builder.into_sourcemap();
+        let sm = builder.into_sourcemap(&CodegenOptions::default(), None);
         // The name `a` not change.
         assert_eq!(
             sm.get_source_view_token(0_u32).as_ref().and_then(|token| token.get_name()),
@@ -557,7 +585,7 @@ mod test {
         let mut builder = SourcemapBuilder::new(Path::new("x.js"), "ab");
         builder.add_source_mapping(output, 1, None);
         builder.add_source_mapping(output, 0, None);
-        let sm = builder.into_sourcemap();
+        let sm = builder.into_sourcemap(&CodegenOptions::default(), None);
         assert_eq!(sm.get_tokens().count(), 2);
     }
 
@@ -1,4 +1,4 @@
-use std::slice;
+use std::{cmp::Ordering, slice};
 
 use oxc_ast::ast::StringLiteral;
 use oxc_data_structures::{
@@ -35,13 +35,18 @@ impl Codegen<'_> {
         // This avoids iterating through the string twice if it contains no quotes (common case).
         // Don't print opening quote now, because we don't know what it is yet.
         //
-        // If not in `minify` mode, print the quote requested in options.
-        let quote = if self.options.minify {
-            None
+        // If `minimize_quote_escapes` option enabled, quote will be chosen in the same way,
+        // but backtick is not an option, and the quote requested in options is preferred.
+        //
+        // Otherwise, print the quote requested in options.
+        let (quote, allow_backtick) = if self.options.minify {
+            (None, allow_backtick)
+        } else if self.options.minimize_quote_escapes {
+            (None, false)
         } else {
             let quote = self.quote;
             quote.print(self);
-            Some(quote)
+            (Some(quote), allow_backtick)
         };
 
         // Loop through bytes, looking for any which need to be escaped.
@@ -213,7 +218,9 @@ impl PrintStringState<'_> {
         let quote = if self.allow_backtick {
             self.calculate_quote_maybe_backtick()
         } else {
-            self.calculate_quote_no_backtick()
+            // Prefer double quote in `minify` mode, for consistent output.
+            let preferred = if codegen.options.minify { Quote::Double } else { codegen.quote };
+            self.calculate_quote_no_backtick(preferred)
         };
 
         quote.print(codegen);
@@ -265,7 +272,7 @@ impl PrintStringState<'_> {
     }
 
     /// Calculate optimum quote character to use, when backtick (`) is not an option.
-    fn calculate_quote_no_backtick(&self) -> Quote {
+    fn calculate_quote_no_backtick(&self, preferred: Quote) -> Quote {
         // String length is max `u32::MAX`, so `i64` cannot overflow
         let mut single_cost: i64 = 0;
         for &b in self.bytes.clone() {
@@ -276,8 +283,11 @@ impl PrintStringState<'_> {
             }
         }
 
-        // Prefer double quote over single quote if cost is the same
-        if single_cost < 0 { Quote::Single } else { Quote::Double }
+        match single_cost.cmp(&0) {
+            Ordering::Less => Quote::Single,
+            Ordering::Greater => Quote::Double,
+            Ordering::Equal => preferred,
+        }
     }
 }
 
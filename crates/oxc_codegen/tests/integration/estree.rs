@@ -0,0 +1,163 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{DeserializeOptions, ESTreeDeserializer};
+use oxc_codegen::Codegen;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+/// Parse `source_text`, serialize it to ESTree JSON with all 4 serializers,
+/// deserialize it again, and check codegen output is the same as for the original AST.
+#[track_caller]
+fn test_round_trip(source_text: &str, source_type: SourceType) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let expected = Codegen::new().build(&ret.program).code;
+
+    let program = &ret.program;
+    let jsons = if source_type.is_typescript() {
+        [program.to_estree_ts_json(false), program.to_estree_ts_json_with_fixes(true)]
+    } else {
+        [program.to_estree_js_json(false), program.to_estree_js_json_with_fixes(true)]
+    };
+    for json in jsons {
+        let allocator = Allocator::default();
+        let program = ESTreeDeserializer::new(&allocator, source_text, source_type)
+            .deserialize(&json)
+            .unwrap_or_else(|err| panic!("{err}\nfor source: {source_text}\n{json}"));
+        let result = Codegen::new().build(&program).code;
+        assert_eq!(result, expected, "\nfor source: {source_text}");
+    }
+}
+
+#[test]
+fn js() {
+    let cases = [
+        "'use strict'; let a = 1, b = 'x', c = 0x1F, d = 1_000n, e = /a[b]/giu, f = null;",
+        "for (let i = 0; i < 10; i++) { if (i % 2) continue; else break; }",
+        "for (const [a, { b, ...c }] of d) {} for (x in y) {} for await (const z of w) {}",
+        "label: while (a) { do { a--; } while (a > 0); break label; }",
+        "switch (x) { case 1: y(); default: z(); } try { a(); } catch ({ message }) {} finally {}",
+        "function* f(a, b = 1, ...c) { yield* a; return b ?? c; }",
+        "async function g() { await h?.i?.[j]?.(k); }",
+        "const o = { a, b: 1, [c]: 2, get d() { return 1; }, set d(v) {}, ...e, f() {} };",
+        "[a, b = 2, ...c] = d; ({ a, b: { c = 1 } = {}, ...e } = f); a.b ||= c; a[0] **= 2;",
+        "const t = tag`a${b}c\\n${d}`; const u = `x`;",
+        "class A extends B { #a = 1; static b; static { this.c = 1; } constructor() { super(); } get #d() { return this.#a; } static async *e() {} accessor f = 1; }",
+        "const f = async (a) => a + 1, g = () => ({}), h = (...args) => { return args; };",
+        "new A(...b); new.target; void 0, typeof a, delete b.c, !d, -e, ~f;",
+        "a ? b : c; (a, b); a in b; a instanceof B; #x in this;",
+        "import.meta.url; import('./a', { with: { type: 'json' } });",
+        "debugger; ;",
+    ];
+    for source_text in cases {
+        test_round_trip(source_text, SourceType::mjs());
+    }
+}
+
+#[test]
+fn modules() {
+    let cases = [
+        "import a, { b as c, default as d } from 'x'; import * as e from 'y'; import 'z';",
+        "import j from './a.json' with { type: 'json' };",
+        "export { a, b as c }; export { d as 'e' } from 'f'; export * from 'g'; export * as h from 'i';",
+        "export default function () {} export const x = 1;",
+        "export default class {}",
+        "export default 1 + 2;",
+    ];
+    for source_text in cases {
+        test_round_trip(source_text, SourceType::mjs());
+    }
+}
+
+#[test]
+fn jsx() {
+    let cases = [
+        "<div className=\"a\" {...b} c={d}>text{e}<F.G h /><this.i /><j:k l:m='n' /></div>;",
+        "<><A>{}</A><_b /><$c></$c></>;",
+        "<a b=<c /> d=<></>>{...e}</a>;",
+    ];
+    for source_text in cases {
+        test_round_trip(source_text, SourceType::jsx());
+    }
+}
+
+#[test]
+fn ts() {
+    let cases = [
+        "let a: number = 1, b: string[] | null, c: [x: number, y?: string, ...z: boolean[]];",
+        "function f<T extends object = {}, const U>(this: Window, a?: T, ...b: U[]): asserts a is T {}",
+        "type A<in out T> = { readonly [K in keyof T as `get${K & string}`]-?: T[K] } & B.C<T>;",
+        "type D = T extends (infer U)[] ? U : never; type E = typeof import('./e', { with: { a: 'b' } }).F<G>;",
+        "type H = new (a: string) => void; type I = abstract new () => object; type J = (a: 1 | -1 | 'x' | 1n | true) => unknown;",
+        "interface K<T> extends L<T>, M.N { (a: T): void; new (): K<T>; m?(): this; get p(): number; set p(v); readonly [key: string]: any; q: unique symbol; }",
+        "enum O { A, B = 1, 'C' = B << 1 } declare const enum P {}",
+        "namespace Q.R.S { export const t = 1; } declare module 'u' { export = v; } declare global {}",
+        "import w = require('w'); import type x = y.z; export import aa = bb.cc; export as namespace dd;",
+        "abstract class E<T> extends F<T> implements G, H.I<T> { private readonly a: T; protected abstract b(): void; declare c?: string; constructor(public d: number, override readonly e = 1) { super(); } [key: string]: any; }",
+        "const j = a as unknown as B; const k = c satisfies D; const l = <E>f; g!.h; const i = j<K>;",
+        "function l(a: unknown): a is string { return typeof a === 'string'; } declare function m(): void;",
+        "let n: `a${string}b`; let o: keyof typeof p; let q: readonly string[]; let r: (string);",
+    ];
+    for source_text in cases {
+        test_round_trip(source_text, SourceType::ts());
+    }
+    test_round_trip("const a = <T,>(b: T) => <C<T> d={b} />;", SourceType::tsx());
+}
+
+#[test]
+fn utf16_offsets() {
+    let source_text = "const a = '😀'; b;";
+    let json = r#"{"type":"Program","start":0,"end":18,"sourceType":"module","body":[
+        {"type":"ExpressionStatement","start":16,"end":18,"expression":
+            {"type":"Identifier","start":16,"end":17,"name":"b"}}
+    ]}"#;
+    let allocator = Allocator::default();
+    let program = ESTreeDeserializer::new(&allocator, source_text, SourceType::mjs())
+        .with_options(DeserializeOptions { utf16_offsets: true })
+        .deserialize(json)
+        .unwrap();
+    let oxc_ast::ast::Statement::ExpressionStatement(stmt) = &program.body[0] else {
+        unreachable!()
+    };
+    assert_eq!((stmt.span.start, stmt.span.end), (18, 20));
+}
+
+#[test]
+fn codemod_output() {
+    // No spans, no `raw`, as produced by e.g. `recast` builders
+    let json = r#"{"type":"Program","sourceType":"module","body":[
+        {"type":"VariableDeclaration","kind":"const","declarations":[
+            {"type":"VariableDeclarator","id":{"type":"Identifier","name":"x"},
+             "init":{"type":"BinaryExpression","operator":"+",
+                "left":{"type":"Literal","value":1.5},"right":{"type":"Literal","value":"a\"b"}}}
+        ]}
+    ]}"#;
+    let allocator = Allocator::default();
+    let program =
+        ESTreeDeserializer::new(&allocator, "", SourceType::mjs()).deserialize(json).unwrap();
+    assert_eq!(Codegen::new().build(&program).code, "const x = 1.5 + \"a\\\"b\";\n");
+}
+
+#[test]
+fn errors() {
+    let cases = [
+        ("{", "expected"),
+        (r#"{"type":"Program","body":[{"type":"Foo"}]}"#, "body[0]: unexpected node type `Foo`"),
+        (
+            r#"{"type":"Program","body":[{"type":"ExpressionStatement","expression":{"type":"BinaryExpression","operator":"<=>","left":{"type":"Identifier","name":"a"},"right":{"type":"Identifier","name":"b"}}}]}"#,
+            "body[0].expression.operator: invalid value `<=>`",
+        ),
+        (
+            r#"{"type":"Program","body":[{"type":"ExpressionStatement"}]}"#,
+            "body[0]: missing field `expression`",
+        ),
+    ];
+    for (json, expected) in cases {
+        let allocator = Allocator::default();
+        let err = ESTreeDeserializer::new(&allocator, "", SourceType::mjs())
+            .deserialize(json)
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains(expected), "{message:?} does not contain {expected:?}");
+    }
+}
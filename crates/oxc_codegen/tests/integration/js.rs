@@ -1,4 +1,4 @@
-use oxc_codegen::CodegenOptions;
+use oxc_codegen::{CodegenOptions, IndentChar, LineEnding};
 
 use crate::tester::{
     test, test_minify, test_minify_same, test_options, test_same, test_with_parse_options,
@@ -593,3 +593,60 @@ fn v8_intrinsics() {
         parse_opts,
     );
 }
+
+#[test]
+fn indent() {
+    let source_text = "function f() { if (a) { b() } }";
+    let options = CodegenOptions {
+        indent_char: IndentChar::Space,
+        indent_width: 2,
+        ..CodegenOptions::default()
+    };
+    test_options(source_text, "function f() {\n  if (a) {\n    b();\n  }\n}\n", options);
+    let options = CodegenOptions { indent_width: 2, ..CodegenOptions::default() };
+    test_options(source_text, "function f() {\n\t\tif (a) {\n\t\t\t\tb();\n\t\t}\n}\n", options);
+}
+
+#[test]
+fn minimize_quote_escapes() {
+    let source_text = r#"x = "it's"; y = 'a"b'; z = "a'b\"c"; w = "plain""#;
+    let options = CodegenOptions { single_quote: true, ..CodegenOptions::default() };
+    test_options(
+        source_text,
+        "x = 'it\\'s';\ny = 'a\"b';\nz = 'a\\'b\"c';\nw = 'plain';\n",
+        options.clone(),
+    );
+    let options = CodegenOptions { minimize_quote_escapes: true, ..options };
+    test_options(
+        source_text,
+        "x = \"it's\";\ny = 'a\"b';\nz = 'a\\'b\"c';\nw = 'plain';\n",
+        options,
+    );
+    let options = CodegenOptions { minimize_quote_escapes: true, ..CodegenOptions::default() };
+    test_options(
+        source_text,
+        "x = \"it's\";\ny = 'a\"b';\nz = \"a'b\\\"c\";\nw = \"plain\";\n",
+        options,
+    );
+}
+
+#[test]
+fn line_ending() {
+    let options = CodegenOptions { line_ending: LineEnding::CrLf, ..CodegenOptions::default() };
+    test_options("a; x = `b\nc`", "a;\r\nx = `b\r\nc`;\r\n", options);
+}
+
+#[test]
+fn max_line_len() {
+    let options =
+        CodegenOptions { minify: true, max_line_len: Some(10), ..CodegenOptions::default() };
+    test_options("f(aaaa, bbbb, cccc); g(); h()", "f(aaaa,bbbb,\ncccc);g();h();", options.clone());
+    test_options(
+        "function f() { return aaaaaaaaaaaa } g()",
+        "function f(){return aaaaaaaaaaaa}\ng();",
+        options.clone(),
+    );
+    // Ignored without `minify`.
+    let options = CodegenOptions { minify: false, ..options };
+    test_options("f(aaaa, bbbb, cccc)", "f(aaaa, bbbb, cccc);\n", options);
+}
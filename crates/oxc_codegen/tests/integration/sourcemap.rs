@@ -1,6 +1,6 @@
 use oxc_allocator::Allocator;
 use oxc_ast::ast::{Expression, Statement};
-use oxc_codegen::Codegen;
+use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::Parser;
 use oxc_span::{SourceType, Span};
 
@@ -27,3 +27,65 @@ fn incorrect_ast() {
     let ret = Codegen::new().with_options(default_options()).build(&program);
     assert!(ret.map.is_some(), "sourcemap exists");
 }
+
+#[test]
+fn source_map_options() {
+    let allocator = Allocator::default();
+    let source_text = "const vendor = 1;";
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let options = CodegenOptions {
+        source_map_sources_content: false,
+        source_map_source_root: Some("/src/".into()),
+        source_map_ignore_list: true,
+        source_map_debug_id: true,
+        ..default_options()
+    };
+
+    let ret = Codegen::new().with_options(options.clone()).build(&ret.program);
+    let (code, debug_id) = ret.code.split_once("//# debugId=").unwrap();
+    assert_eq!(code, "const vendor = 1;\n");
+    let debug_id = debug_id.strip_suffix('\n').unwrap();
+    assert_eq!(debug_id.len(), 36);
+
+    let json = ret.map.unwrap().to_json_string();
+    assert!(json.contains(&format!(r#""debugId":"{debug_id}""#)), "{json}");
+    assert!(json.contains(r#""sourceRoot":"/src/""#), "{json}");
+    assert!(json.contains(r#""x_google_ignoreList":[0]"#), "{json}");
+    assert!(!json.contains("vendor = 1"), "{json}");
+
+    // The Debug ID is deterministic.
+    let ret2 = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let code2 = Codegen::new().with_options(options).build(&ret2.program).code;
+    assert_eq!(code2, ret.code);
+}
+
+#[test]
+fn source_root() {
+    let allocator = Allocator::default();
+    let source_text = "let x = 1;";
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let options =
+        CodegenOptions { source_map_source_root: Some("/src/".into()), ..default_options() };
+    let map = Codegen::new().with_options(options).build(&ret.program).map.unwrap();
+    assert_eq!(map.get_source_root(), Some("/src/"));
+    let json = map.to_json_string();
+    assert!(json.contains(r#""sourceRoot":"/src/""#), "{json}");
+    assert!(json.contains(r#""sources":["test.js"]"#), "{json}");
+    assert!(json.contains(r#""sourcesContent":["let x = 1;"]"#), "{json}");
+    let map_without_root =
+        Codegen::new().with_options(default_options()).build(&ret.program).map.unwrap();
+    assert!(map.get_tokens().eq(map_without_root.get_tokens()));
+}
+
+#[test]
+fn max_line_len() {
+    let allocator = Allocator::default();
+    let source_text = "f(aaaa, bbbb, cccc)";
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let options = CodegenOptions { minify: true, max_line_len: Some(10), ..default_options() };
+    let ret = Codegen::new().with_options(options).build(&ret.program);
+    assert_eq!(ret.code, "f(aaaa,bbbb,\ncccc);");
+    let map = ret.map.unwrap();
+    let token = map.get_tokens().find(|token| token.get_dst_line() == 1).unwrap();
+    assert_eq!((token.get_dst_col(), token.get_src_line(), token.get_src_col()), (0, 0, 14));
+}
@@ -4,6 +4,17 @@ use std::iter;
 
 use crate::assert_unchecked;
 
+/// Character used for indentation by [`CodeBuffer::print_indent_with`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum IndentChar {
+    /// Indent with tabs (default).
+    #[default]
+    Tab = b'\t',
+    /// Indent with spaces.
+    Space = b' ',
+}
+
 /// A string builder for constructing source code.
 ///
 /// `CodeBuffer` provides safe abstractions over a byte array.
@@ -413,9 +424,19 @@ impl CodeBuffer {
 
     /// Print `n` tab characters into the buffer (indentation).
     ///
+    /// Same as [`print_indent_with`] with [`IndentChar::Tab`].
+    ///
+    /// [`print_indent_with`]: CodeBuffer::print_indent_with
+    #[inline]
+    pub fn print_indent(&mut self, n: usize) {
+        self.print_indent_with(IndentChar::Tab, n);
+    }
+
+    /// Print `n` copies of `indent_char` into the buffer (indentation).
+    ///
     /// Optimized on assumption that more that 16 levels of indentation is rare.
     ///
-    /// Fast path is to write 16 bytes of tabs in a single load + store,
+    /// Fast path is to write 16 bytes of indent characters in a single load + store,
     /// but only advance `len` by `n` bytes. This avoids a `memset` function call.
     ///
     /// Take alternative slow path if either:
@@ -424,31 +445,41 @@ impl CodeBuffer {
     /// Both of these cases should be rare.
     ///
     /// <https://godbolt.org/z/e1EP5cnPc>
+    ///
+    /// # Example
+    /// ```
+    /// # use oxc_data_structures::code_buffer::{CodeBuffer, IndentChar};
+    /// let mut code = CodeBuffer::new();
+    /// code.print_indent_with(IndentChar::Space, 4);
+    /// assert_eq!(code.into_string(), "    ");
+    /// ```
     #[inline]
-    pub fn print_indent(&mut self, n: usize) {
+    pub fn print_indent_with(&mut self, indent_char: IndentChar, n: usize) {
         /// Size of chunks to write indent in.
         /// 16 is largest register size (XMM) available on all x86_84 targets.
         const CHUNK_SIZE: usize = 16;
 
         #[cold]
         #[inline(never)]
-        fn write_slow(code_buffer: &mut CodeBuffer, n: usize) {
-            code_buffer.buf.extend(iter::repeat_n(b'\t', n));
+        fn write_slow(code_buffer: &mut CodeBuffer, byte: u8, n: usize) {
+            code_buffer.buf.extend(iter::repeat_n(byte, n));
         }
 
+        let byte = indent_char as u8;
         let len = self.len();
         let spare_capacity = self.capacity() - len;
         if n > CHUNK_SIZE || spare_capacity < CHUNK_SIZE {
-            write_slow(self, n);
+            write_slow(self, byte, n);
             return;
         }
 
-        // Write 16 tabs into buffer.
+        // Write 16 indent characters into buffer.
         // On x86_86, this is 1 XMM register load + 1 XMM store (16 byte copy).
         // SAFETY: We checked there are at least 16 bytes spare capacity.
+        // All variants of `IndentChar` are ASCII bytes, so the buffer remains valid UTF-8.
         unsafe {
             let ptr = self.buf.as_mut_ptr().add(len).cast::<[u8; CHUNK_SIZE]>();
-            ptr.write([b'\t'; CHUNK_SIZE]);
+            ptr.write([byte; CHUNK_SIZE]);
         }
 
         // Update length of buffer.
@@ -9,6 +9,7 @@ mod prop_name;
 // Abstract Operations
 mod array_join;
 mod is_less_than;
+mod regexp_exec;
 mod string_char_at;
 mod string_char_code_at;
 mod string_index_of;
@@ -38,6 +39,7 @@ pub use self::{
     is_simple_parameter_list::IsSimpleParameterList,
     private_bound_identifiers::PrivateBoundIdentifiers,
     prop_name::PropName,
+    regexp_exec::RegExpExec,
     string_char_at::{StringCharAt, StringCharAtResult},
     string_char_code_at::StringCharCodeAt,
     string_index_of::StringIndexOf,
@@ -0,0 +1,201 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{RegExp, RegExpFlags};
+use oxc_regular_expression::{LiteralParser, Match, MatchOptions, Matcher, Options};
+
+/// Methods of `RegExp.prototype` on a newly created regular expression, e.g. a literal.
+///
+/// Returns `None` if the result cannot be computed, e.g. if the pattern is invalid or matching
+/// exceeds the backtracking limit.
+pub trait RegExpExec {
+    /// `RegExp.prototype.test ( S )`
+    /// <https://tc39.es/ecma262/#sec-regexp.prototype.test>
+    fn regexp_test(&self, input: &str) -> Option<bool>;
+
+    /// `RegExp.prototype [ %Symbol.replace% ] ( string, replaceValue )` with a string
+    /// `replaceValue`, which is called by `String.prototype.replace` and `replaceAll`.
+    /// <https://tc39.es/ecma262/#sec-regexp.prototype-%symbol.replace%>
+    fn regexp_replace(&self, input: &str, replace_value: &str) -> Option<String>;
+}
+
+impl RegExpExec for RegExp<'_> {
+    fn regexp_test(&self, input: &str) -> Option<bool> {
+        with_matcher(self, |matcher| matcher.is_match(input).ok())
+    }
+
+    fn regexp_replace(&self, input: &str, replace_value: &str) -> Option<String> {
+        let input = input.encode_utf16().collect::<Vec<_>>();
+        let replace_value = replace_value.encode_utf16().collect::<Vec<_>>();
+        let global = self.flags.contains(RegExpFlags::G);
+        with_matcher(self, |matcher| {
+            let mut result = vec![];
+            let mut next_source_position = 0;
+            let mut last_index = 0;
+            while let Some(found) = matcher.exec(&input, last_index).ok()? {
+                let range = found.range();
+                result.extend_from_slice(&input[next_source_position..range.start]);
+                get_substitution(&found, &input, &replace_value, matcher, &mut result);
+                next_source_position = range.end;
+                if !global {
+                    break;
+                }
+                last_index = if range.is_empty() {
+                    matcher.advance_index(&input, range.end)
+                } else {
+                    range.end
+                };
+            }
+            result.extend_from_slice(&input[next_source_position..]);
+            String::from_utf16(&result).ok()
+        })
+    }
+}
+
+fn with_matcher<T>(regexp: &RegExp, f: impl FnOnce(&Matcher) -> Option<T>) -> Option<T> {
+    let flags = regexp.flags;
+    let options = MatchOptions {
+        global: flags.contains(RegExpFlags::G),
+        ignore_case: flags.contains(RegExpFlags::I),
+        multiline: flags.contains(RegExpFlags::M),
+        dot_all: flags.contains(RegExpFlags::S),
+        unicode: flags.contains(RegExpFlags::U),
+        unicode_sets: flags.contains(RegExpFlags::V),
+        sticky: flags.contains(RegExpFlags::Y),
+    };
+    if let Some(pattern) = &regexp.pattern.pattern {
+        return f(&Matcher::new(pattern, options).ok()?);
+    }
+    let allocator = Allocator::default();
+    let flags_text = flags.to_inline_string();
+    let pattern = LiteralParser::new(
+        &allocator,
+        regexp.pattern.text.as_str(),
+        Some(flags_text.as_str()),
+        Options::default(),
+    )
+    .parse()
+    .ok()?;
+    f(&Matcher::new(&pattern, options).ok()?)
+}
+
+/// `GetSubstitution ( matched, str, position, captures, namedCaptures, replacementTemplate )`
+/// <https://tc39.es/ecma262/#sec-getsubstitution>
+fn get_substitution(
+    found: &Match,
+    input: &[u16],
+    template: &[u16],
+    matcher: &Matcher,
+    result: &mut Vec<u16>,
+) {
+    let capture_count = matcher.capture_count();
+    let push_capture = |result: &mut Vec<u16>, index: usize| {
+        if let Some(range) = found.get(index) {
+            result.extend_from_slice(&input[range]);
+        }
+    };
+    let digit = |unit: u16| {
+        char::from_u32(unit.into()).and_then(|ch| ch.to_digit(10)).map(|digit| digit as usize)
+    };
+    let mut i = 0;
+    while i < template.len() {
+        let (unit, next) = (template[i], template.get(i + 1).copied());
+        if unit != u16::from(b'$') || next.is_none() {
+            result.push(unit);
+            i += 1;
+            continue;
+        }
+        let next = next.unwrap();
+        match u8::try_from(next) {
+            Ok(b'$') => result.push(next),
+            Ok(b'&') => push_capture(result, 0),
+            Ok(b'`') => result.extend_from_slice(&input[..found.start()]),
+            Ok(b'\'') => result.extend_from_slice(&input[found.end()..]),
+            Ok(b'0'..=b'9') => {
+                let first = digit(next).unwrap();
+                let (mut index, mut digit_count) =
+                    match template.get(i + 2).copied().and_then(digit) {
+                        Some(second) => (first * 10 + second, 2),
+                        None => (first, 1),
+                    };
+                if index > capture_count && digit_count == 2 {
+                    (index, digit_count) = (first, 1);
+                }
+                if (1..=capture_count).contains(&index) {
+                    push_capture(result, index);
+                } else {
+                    result.extend_from_slice(&template[i..=i + digit_count]);
+                }
+                i += 1 + digit_count;
+                continue;
+            }
+            Ok(b'<') if matcher.has_named_groups() => {
+                let Some(length) =
+                    template[i + 2..].iter().position(|&unit| unit == u16::from(b'>'))
+                else {
+                    result.extend_from_slice(&template[i..i + 2]);
+                    i += 2;
+                    continue;
+                };
+                let name = String::from_utf16_lossy(&template[i + 2..i + 2 + length]);
+                if let Some(range) = found.name(&name) {
+                    result.extend_from_slice(&input[range]);
+                }
+                i += 3 + length;
+                continue;
+            }
+            _ => {
+                result.push(unit);
+                i += 1;
+                continue;
+            }
+        }
+        i += 2;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::ast::{Atom, RegExp, RegExpFlags, RegExpPattern};
+
+    use super::RegExpExec;
+
+    fn regexp<'a>(pattern: &'a str, flags: &str) -> RegExp<'a> {
+        let flags = flags
+            .chars()
+            .fold(RegExpFlags::empty(), |flags, ch| flags | RegExpFlags::try_from(ch).unwrap());
+        RegExp { pattern: RegExpPattern { text: Atom::from(pattern), pattern: None }, flags }
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(regexp("abc", "").regexp_test("xabcx"), Some(true));
+        assert_eq!(regexp("^abc", "").regexp_test("xabc"), Some(false));
+        assert_eq!(regexp("ABC", "ig").regexp_test("abc"), Some(true));
+        assert_eq!(regexp(r"\p{Lu}", "u").regexp_test("a"), Some(false));
+        assert_eq!(regexp("(", "").regexp_test("("), None);
+        assert_eq!(regexp("(a+)+b", "").regexp_test(&"a".repeat(40)), None);
+    }
+
+    #[test]
+    fn replace() {
+        let replace = |pattern: &str, flags: &str, input: &str, replace_value: &str| {
+            regexp(pattern, flags).regexp_replace(input, replace_value)
+        };
+        assert_eq!(replace("-", "g", "a-b-c", "").as_deref(), Some("abc"));
+        assert_eq!(replace("-", "", "a-b-c", "").as_deref(), Some("ab-c"));
+        assert_eq!(replace("x", "g", "abc", "y").as_deref(), Some("abc"));
+        assert_eq!(replace("", "g", "abc", "-").as_deref(), Some("-a-b-c-"));
+        assert_eq!(replace("", "gu", "😀", "-").as_deref(), Some("-😀-"));
+        assert_eq!(replace("b", "", "abc", "[$&$`$'$$]").as_deref(), Some("a[bac$]c"));
+        assert_eq!(replace("(a)(b)?", "", "ac", "$2$1$3$0$").as_deref(), Some("a$3$0$c"));
+        assert_eq!(replace("(b)", "", "abc", "$01$10").as_deref(), Some("abb0c"));
+        assert_eq!(replace("(b)", "", "abc", "$11").as_deref(), Some("ab1c"));
+        assert_eq!(
+            replace(r"(?<y>\d{4})-(?<m>\d\d)", "", "2024-05", "$<m>/$<y>$<d>").as_deref(),
+            Some("05/2024")
+        );
+        assert_eq!(replace("(?<a>b)", "", "abc", "$<a").as_deref(), Some("a$<ac"));
+        assert_eq!(replace("(b)", "", "abc", "$<a>").as_deref(), Some("a$<a>c"));
+        assert_eq!(replace("A", "gi", "aAa", "b").as_deref(), Some("bbb"));
+        assert_eq!(replace("a", "y", "aab", "").as_deref(), Some("ab"));
+    }
+}
@@ -4,6 +4,8 @@ pub trait Config {
     const INCLUDE_TS_FIELDS: bool;
     /// `true` if should record paths to `Literal` nodes that need fixing on JS side
     const FIXES: bool;
+    /// `true` if output is the base for a Babel-compatible AST, rather than ESTree
+    const BABEL: bool;
 
     fn new(ranges: bool) -> Self;
 
@@ -19,6 +21,7 @@ pub struct ConfigTS {
 impl Config for ConfigTS {
     const INCLUDE_TS_FIELDS: bool = true;
     const FIXES: bool = false;
+    const BABEL: bool = false;
 
     #[inline(always)]
     fn new(ranges: bool) -> Self {
@@ -39,6 +42,7 @@ pub struct ConfigJS {
 impl Config for ConfigJS {
     const INCLUDE_TS_FIELDS: bool = false;
     const FIXES: bool = false;
+    const BABEL: bool = false;
 
     #[inline(always)]
     fn new(ranges: bool) -> Self {
@@ -59,6 +63,7 @@ pub struct ConfigFixesTS {
 impl Config for ConfigFixesTS {
     const INCLUDE_TS_FIELDS: bool = true;
     const FIXES: bool = true;
+    const BABEL: bool = false;
 
     #[inline(always)]
     fn new(ranges: bool) -> Self {
@@ -79,6 +84,49 @@ pub struct ConfigFixesJS {
 impl Config for ConfigFixesJS {
     const INCLUDE_TS_FIELDS: bool = false;
     const FIXES: bool = true;
+    const BABEL: bool = false;
+
+    #[inline(always)]
+    fn new(ranges: bool) -> Self {
+        Self { ranges }
+    }
+
+    #[inline(always)]
+    fn ranges(&self) -> bool {
+        self.ranges
+    }
+}
+
+/// Config for serializing AST in Babel-compatible form, with TypeScript fields.
+pub struct ConfigBabelTS {
+    ranges: bool,
+}
+
+impl Config for ConfigBabelTS {
+    const INCLUDE_TS_FIELDS: bool = true;
+    const FIXES: bool = false;
+    const BABEL: bool = true;
+
+    #[inline(always)]
+    fn new(ranges: bool) -> Self {
+        Self { ranges }
+    }
+
+    #[inline(always)]
+    fn ranges(&self) -> bool {
+        self.ranges
+    }
+}
+
+/// Config for serializing AST in Babel-compatible form, without TypeScript fields.
+pub struct ConfigBabelJS {
+    ranges: bool,
+}
+
+impl Config for ConfigBabelJS {
+    const INCLUDE_TS_FIELDS: bool = false;
+    const FIXES: bool = false;
+    const BABEL: bool = true;
 
     #[inline(always)]
     fn new(ranges: bool) -> Self {
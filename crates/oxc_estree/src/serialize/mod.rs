@@ -15,7 +15,9 @@ mod primitives;
 mod sequences;
 mod strings;
 mod structs;
-use config::{Config, ConfigFixesJS, ConfigFixesTS, ConfigJS, ConfigTS};
+use config::{
+    Config, ConfigBabelJS, ConfigBabelTS, ConfigFixesJS, ConfigFixesTS, ConfigJS, ConfigTS,
+};
 use formatter::{CompactFormatter, Formatter, PrettyFormatter};
 use sequences::ESTreeSequenceSerializer;
 use structs::ESTreeStructSerializer;
@@ -38,6 +40,11 @@ pub trait ESTree {
 pub trait Serializer: SerializerPrivate {
     /// `true` if output should contain TS fields
     const INCLUDE_TS_FIELDS: bool;
+    /// `true` if output is the base for a Babel-compatible AST, rather than ESTree.
+    ///
+    /// Serializers only alter the shape of `Program` in this mode.
+    /// Conversion of other nodes to Babel form happens on JS side.
+    const BABEL: bool;
 
     /// Type of struct serializer this serializer uses.
     type StructSerializer: StructSerializer;
@@ -97,6 +104,12 @@ pub type PrettyFixesTSSerializer = ESTreeSerializer<ConfigFixesTS, PrettyFormatt
 /// ESTree serializer which produces pretty JSON, excluding TypeScript fields.
 pub type PrettyFixesJSSerializer = ESTreeSerializer<ConfigFixesJS, PrettyFormatter>;
 
+/// Serializer which produces compact JSON for a Babel-compatible AST, including TypeScript fields.
+pub type CompactBabelTSSerializer = ESTreeSerializer<ConfigBabelTS, CompactFormatter>;
+
+/// Serializer which produces compact JSON for a Babel-compatible AST, excluding TypeScript fields.
+pub type CompactBabelJSSerializer = ESTreeSerializer<ConfigBabelJS, CompactFormatter>;
+
 /// ESTree serializer.
 pub struct ESTreeSerializer<C: Config, F: Formatter> {
     buffer: CodeBuffer,
@@ -177,6 +190,8 @@ impl<C: Config, F: Formatter> Default for ESTreeSerializer<C, F> {
 impl<'s, C: Config, F: Formatter> Serializer for &'s mut ESTreeSerializer<C, F> {
     /// `true` if output should contain TS fields
     const INCLUDE_TS_FIELDS: bool = C::INCLUDE_TS_FIELDS;
+    /// `true` if output is the base for a Babel-compatible AST
+    const BABEL: bool = C::BABEL;
 
     type StructSerializer = ESTreeStructSerializer<'s, C, F>;
     type SequenceSerializer = ESTreeSequenceSerializer<'s, C, F>;
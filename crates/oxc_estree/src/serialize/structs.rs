@@ -223,6 +223,8 @@ pub struct FlatStructSerializer<'p, P: StructSerializer>(pub &'p mut P);
 impl<'p, P: StructSerializer> Serializer for FlatStructSerializer<'p, P> {
     /// `true` if output should contain TS fields
     const INCLUDE_TS_FIELDS: bool = P::Config::INCLUDE_TS_FIELDS;
+    /// `true` if output is the base for a Babel-compatible AST
+    const BABEL: bool = P::Config::BABEL;
 
     type StructSerializer = Self;
     type SequenceSerializer = ESTreeSequenceSerializer<'p, P::Config, P::Formatter>;
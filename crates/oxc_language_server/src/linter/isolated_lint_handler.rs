@@ -69,7 +69,8 @@ impl IsolatedLintHandler {
     ) -> Self {
         let linter = Linter::new(lint_options, config_store, None);
         let lint_service_options = LintServiceOptions::new(options.root_path.clone())
-            .with_cross_module(options.use_cross_module);
+            .with_cross_module(options.use_cross_module)
+            .with_recover_from_errors(true);
 
         let service = LintService::new(linter, AllocatorPool::default(), lint_service_options);
 
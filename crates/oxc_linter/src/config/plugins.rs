@@ -47,6 +47,10 @@ bitflags! {
         const PROMISE = 1 << 11;
         /// `eslint-plugin-node`
         const NODE = 1 << 12;
+        /// `eslint-plugin-vue`
+        const VUE = 1 << 13;
+        /// `eslint-plugin-regexp`
+        const REGEXP = 1 << 14;
     }
 }
 
@@ -119,6 +123,8 @@ impl From<&str> for BuiltinLintPlugins {
             "react-perf" | "react_perf" => BuiltinLintPlugins::REACT_PERF,
             "promise" => BuiltinLintPlugins::PROMISE,
             "node" => BuiltinLintPlugins::NODE,
+            "vue" => BuiltinLintPlugins::VUE,
+            "regexp" => BuiltinLintPlugins::REGEXP,
             // "eslint" is not really a plugin, so it's 'empty'. This has the added benefit of
             // making it the default value.
             _ => BuiltinLintPlugins::empty(),
@@ -142,6 +148,8 @@ impl From<BuiltinLintPlugins> for &'static str {
             BuiltinLintPlugins::REACT_PERF => "react-perf",
             BuiltinLintPlugins::PROMISE => "promise",
             BuiltinLintPlugins::NODE => "node",
+            BuiltinLintPlugins::VUE => "vue",
+            BuiltinLintPlugins::REGEXP => "regexp",
             _ => "",
         }
     }
@@ -225,6 +233,8 @@ impl JsonSchema for LintPlugins {
             ReactPerf,
             Promise,
             Node,
+            Vue,
+            Regexp,
         }
 
         let enum_schema = r#gen.subschema_for::<LintPluginOptionsSchema>();
@@ -276,6 +286,8 @@ mod tests {
         assert_eq!(BuiltinLintPlugins::from("react"), BuiltinLintPlugins::REACT);
         assert_eq!(BuiltinLintPlugins::from("typescript-eslint"), BuiltinLintPlugins::TYPESCRIPT);
         assert_eq!(BuiltinLintPlugins::from("deepscan"), BuiltinLintPlugins::OXC);
+        assert_eq!(BuiltinLintPlugins::from("vue"), BuiltinLintPlugins::VUE);
+        assert_eq!(BuiltinLintPlugins::from("regexp"), BuiltinLintPlugins::REGEXP);
         assert_eq!(BuiltinLintPlugins::from("unknown"), BuiltinLintPlugins::empty());
     }
 
@@ -1,5 +1,6 @@
 use std::{borrow::Cow, fmt};
 
+use cow_utils::CowUtils;
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
 use schemars::{JsonSchema, r#gen::SchemaGenerator, schema::Schema};
@@ -245,9 +246,16 @@ fn parse_rule_key(name: &str) -> (String, String) {
         "react-hooks" => ("react", rule_name),
         // For backwards compatibility, deepscan rules reside in the oxc plugin.
         "deepscan" => ("oxc", rule_name),
+        // eslint-plugin-n is the maintained fork of eslint-plugin-node.
+        "n" => ("node", rule_name),
         _ => (plugin_name, rule_name),
     };
 
+    // e.g. "n/prefer-global/buffer" is "node/prefer-global-buffer"
+    if oxlint_plugin_name == "node" {
+        return (oxlint_plugin_name.to_string(), rule_name.cow_replace('/', "-").into_owned());
+    }
+
     (oxlint_plugin_name.to_string(), rule_name.to_string())
 }
 
@@ -328,6 +336,7 @@ mod test {
             "foo/no-unused-vars": [1],
             "dummy": ["error", "arg1", "args2"],
             "@next/next/noop": 2,
+            "n/prefer-global/buffer": "error",
         }))
         .unwrap();
         let mut rules = rules.rules.iter();
@@ -355,6 +364,11 @@ mod test {
         assert_eq!(r4.plugin_name, "nextjs");
         assert!(r4.severity.is_warn_deny());
         assert!(r4.config.is_none());
+
+        let r5 = rules.next().unwrap();
+        assert_eq!(r5.rule_name, "prefer-global-buffer");
+        assert_eq!(r5.plugin_name, "node");
+        assert!(r5.severity.is_warn_deny());
     }
 
     #[test]
@@ -13,6 +13,11 @@ use crate::{
     module_record::ModuleRecord,
     options::LintOptions,
     rules::RuleEnum,
+    template::{
+        Template,
+        markup::{Markup, MarkupKind},
+        vue::VueTemplate,
+    },
 };
 
 use super::{LintContext, plugin_name_to_prefix};
@@ -63,6 +68,9 @@ pub struct ContextHost<'a> {
     pub(super) config: Arc<LintConfig>,
     /// Front-end frameworks that might be in use in the target file.
     pub(super) frameworks: FrameworkFlags,
+    /// The template of a framework file, such as the `<template>` block of a
+    /// Vue single-file component.
+    pub(super) template: Option<Rc<Template<'a>>>,
 }
 
 impl<'a> ContextHost<'a> {
@@ -98,10 +106,25 @@ impl<'a> ContextHost<'a> {
             file_path,
             config,
             frameworks: options.framework_hints,
+            template: None,
         }
         .sniff_for_frameworks()
     }
 
+    /// Attach the template of a framework file.
+    pub(crate) fn with_template(mut self, template: Option<Rc<Template<'a>>>) -> Self {
+        match template.as_deref() {
+            Some(Template::Vue(_)) => self.frameworks |= FrameworkFlags::Vue,
+            Some(Template::Markup(markup)) => match markup.kind() {
+                MarkupKind::Svelte => self.frameworks |= FrameworkFlags::Svelte,
+                MarkupKind::Astro => self.frameworks |= FrameworkFlags::Astro,
+            },
+            None => {}
+        }
+        self.template = template;
+        self
+    }
+
     /// Shared reference to the [`Semantic`] analysis of the file.
     #[inline]
     pub fn semantic(&self) -> &Semantic<'a> {
@@ -130,6 +153,24 @@ impl<'a> ContextHost<'a> {
         self.semantic.source_type()
     }
 
+    /// The `<template>` block of the Vue single-file component being linted, if any.
+    #[inline]
+    pub fn vue_template(&self) -> Option<&VueTemplate<'a>> {
+        match self.template.as_deref() {
+            Some(Template::Vue(template)) => Some(template),
+            _ => None,
+        }
+    }
+
+    /// The lowered markup of the Svelte component or Astro page being linted, if any.
+    #[inline]
+    pub fn markup(&self) -> Option<&Markup> {
+        match self.template.as_deref() {
+            Some(Template::Markup(markup)) => Some(markup),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn plugins(&self) -> &LintPlugins {
         &self.config.plugins
@@ -17,6 +17,7 @@ use crate::{
     config::GlobalValue,
     disable_directives::DisableDirectives,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
+    template::{markup::Markup, vue::VueTemplate},
 };
 
 mod host;
@@ -114,6 +115,18 @@ impl<'a> LintContext<'a> {
         self.parent.module_record()
     }
 
+    /// The `<template>` block of the Vue single-file component being linted, if any.
+    #[inline]
+    pub fn vue_template(&self) -> Option<&VueTemplate<'a>> {
+        self.parent.vue_template()
+    }
+
+    /// The lowered markup of the Svelte component or Astro page being linted, if any.
+    #[inline]
+    pub fn markup(&self) -> Option<&Markup> {
+        self.parent.markup()
+    }
+
     /// Get the control flow graph for the current program.
     #[inline]
     pub fn cfg(&self) -> &ControlFlowGraph {
@@ -470,4 +483,6 @@ const PLUGIN_PREFIXES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "unicorn" => "eslint-plugin-unicorn",
     "vitest" => "eslint-plugin-vitest",
     "node" => "eslint-plugin-node",
+    "vue" => "eslint-plugin-vue",
+    "regexp" => "eslint-plugin-regexp",
 };
@@ -31,6 +31,7 @@ mod utils;
 pub mod loader;
 pub mod rules;
 pub mod table;
+pub mod template;
 
 #[cfg(all(feature = "oxlint2", not(feature = "disable_oxlint2")))]
 mod generated {
@@ -64,6 +65,7 @@ use crate::{
     context::ContextHost,
     fixer::{Fixer, Message},
     rules::RuleEnum,
+    template::Template,
     utils::iter_possible_jest_call_node,
 };
 
@@ -126,12 +128,15 @@ impl Linter {
         path: &Path,
         semantic: Rc<Semantic<'a>>,
         module_record: Arc<ModuleRecord>,
+        template: Option<Rc<Template<'a>>>,
         allocator: &Allocator,
     ) -> Vec<Message<'a>> {
         let ResolvedLinterState { rules, config, external_rules } = self.config.resolve(path);
 
-        let ctx_host =
-            Rc::new(ContextHost::new(path, semantic, module_record, self.options, config));
+        let ctx_host = Rc::new(
+            ContextHost::new(path, semantic, module_record, self.options, config)
+                .with_template(template),
+        );
 
         let rules = rules
             .iter()
@@ -4,6 +4,7 @@ use oxc_span::SourceType;
 
 mod partial_loader;
 mod source;
+pub(crate) use partial_loader::find_script_closing_angle;
 pub use partial_loader::{LINT_PARTIAL_LOADER_EXTENSIONS, LINTABLE_EXTENSIONS, PartialLoader};
 pub use source::JavaScriptSource;
 
@@ -64,6 +65,11 @@ mod test {
             "foo.astro",
             "foo.svelte",
             "foo.vue",
+            "foo.md",
+            "foo.markdown",
+            "foo.mdx",
+            "foo.html",
+            "foo.htm",
         ];
 
         for path in paths {
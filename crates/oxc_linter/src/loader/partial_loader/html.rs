@@ -0,0 +1,187 @@
+use cow_utils::CowUtils;
+use memchr::memmem::Finder;
+
+use oxc_span::SourceType;
+
+use crate::loader::JavaScriptSource;
+
+use super::{SCRIPT_END, SCRIPT_START, find_script_closing_angle};
+
+const COMMENT_START: &str = "<!--";
+const COMMENT_END: &str = "-->";
+
+pub struct HtmlPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> HtmlPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    /// Every inline `<script>` with a JavaScript `type` becomes its own source.
+    /// Scripts with a `src` attribute and data blocks such as `type="application/json"` are skipped.
+    /// <https://html.spec.whatwg.org/multipage/scripting.html#the-script-element>
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let script_start_finder = Finder::new(SCRIPT_START);
+        let script_end_finder = Finder::new(SCRIPT_END);
+        let comment_start_finder = Finder::new(COMMENT_START);
+        let comment_end_finder = Finder::new(COMMENT_END);
+
+        let mut results = vec![];
+        let mut pointer = 0;
+
+        loop {
+            // find opening "<script", skipping over commented out scripts
+            let Some(offset) = script_start_finder.find(&self.source_text.as_bytes()[pointer..])
+            else {
+                break;
+            };
+            if let Some(comment) =
+                comment_start_finder.find(&self.source_text.as_bytes()[pointer..pointer + offset])
+            {
+                let comment_start = pointer + comment + COMMENT_START.len();
+                let Some(comment_end) =
+                    comment_end_finder.find(&self.source_text.as_bytes()[comment_start..])
+                else {
+                    break;
+                };
+                pointer = comment_start + comment_end + COMMENT_END.len();
+                continue;
+            }
+            pointer += offset + SCRIPT_START.len();
+
+            // find closing ">"
+            let Some(offset) = find_script_closing_angle(self.source_text, pointer) else {
+                break;
+            };
+            let attributes = &self.source_text[pointer..pointer + offset];
+            pointer += offset + 1;
+            let js_start = pointer;
+
+            // find "</script>"
+            let Some(offset) = script_end_finder.find(&self.source_text.as_bytes()[pointer..])
+            else {
+                break;
+            };
+            let js_end = pointer + offset;
+            pointer += offset + SCRIPT_END.len();
+
+            if let Some(source_type) = script_source_type(attributes) {
+                // NOTE: loader checked that source_text.len() is less than u32::MAX
+                #[expect(clippy::cast_possible_truncation)]
+                results.push(JavaScriptSource::partial(
+                    &self.source_text[js_start..js_end],
+                    source_type,
+                    js_start as u32,
+                ));
+            }
+        }
+
+        results
+    }
+}
+
+/// Classic scripts are parsed as scripts, `type="module"` as modules.
+/// Returns `None` for external scripts and non-JavaScript types.
+fn script_source_type(attributes: &str) -> Option<SourceType> {
+    let mut attributes = attributes.trim_end_matches('/');
+    let mut source_type = Some(SourceType::cjs());
+    while let Some((name, value, rest)) = next_attribute(attributes) {
+        attributes = rest;
+        match name.cow_to_ascii_lowercase().as_ref() {
+            "src" => return None,
+            "type" => {
+                source_type = match value.trim().cow_to_ascii_lowercase().as_ref() {
+                    "module" => Some(SourceType::mjs()),
+                    ""
+                    | "text/javascript"
+                    | "application/javascript"
+                    | "text/ecmascript"
+                    | "application/ecmascript" => Some(SourceType::cjs()),
+                    _ => None,
+                };
+            }
+            _ => {}
+        }
+    }
+    source_type
+}
+
+/// Split the next `name="value"`, `name=value` or `name` attribute off `text`.
+fn next_attribute(text: &str) -> Option<(&str, &str, &str)> {
+    let text = text.trim_start();
+    if text.is_empty() {
+        return None;
+    }
+    let name_end = text.find(|c: char| c.is_whitespace() || c == '=').unwrap_or(text.len());
+    let (name, rest) = text.split_at(name_end);
+    let Some(rest) = rest.trim_start().strip_prefix('=') else {
+        return Some((name, "", rest));
+    };
+    let rest = rest.trim_start();
+    let (value, rest) = match rest.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let rest = &rest[1..];
+            let end = rest.find(quote).unwrap_or(rest.len());
+            (&rest[..end], rest.get(end + 1..).unwrap_or_default())
+        }
+        _ => rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len())),
+    };
+    Some((name, value, rest))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{HtmlPartialLoader, JavaScriptSource};
+
+    fn parse_html(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        HtmlPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let source_text = r#"
+        <!doctype html>
+        <html>
+          <head>
+            <script>
+              console.log("classic");
+            </script>
+            <script type="module" data-foo="a > b">
+              import foo from "./foo.js";
+            </script>
+          </head>
+        </html>
+        "#;
+
+        let sources = parse_html(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text.trim(), r#"console.log("classic");"#);
+        assert!(sources[0].source_type.is_script());
+        assert_eq!(sources[1].source_text.trim(), r#"import foo from "./foo.js";"#);
+        assert!(sources[1].source_type.is_module());
+
+        for source in &sources {
+            let start = source.start as usize;
+            assert_eq!(&source_text[start..start + source.source_text.len()], source.source_text);
+        }
+    }
+
+    #[test]
+    fn test_parse_html_skipped_scripts() {
+        let source_text = r#"
+        <script src="./app.js"></script>
+        <script type="application/ld+json">{ "@context": "https://schema.org" }</script>
+        <script type=importmap>{ "imports": {} }</script>
+        <!-- <script>commented()</script> -->
+        <script type='text/javascript' defer>
+          run();
+        </script>
+        "#;
+
+        let sources = parse_html(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text.trim(), "run();");
+    }
+}
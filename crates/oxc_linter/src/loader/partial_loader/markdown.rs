@@ -0,0 +1,308 @@
+use cow_utils::CowUtils;
+
+use oxc_span::SourceType;
+
+use crate::{loader::JavaScriptSource, template::markup::find_closing_brace};
+
+pub struct MarkdownPartialLoader<'a> {
+    source_text: &'a str,
+    /// MDX additionally allows ESM `import`/`export` blocks and `{expression}`s in content.
+    is_mdx: bool,
+}
+
+impl<'a> MarkdownPartialLoader<'a> {
+    pub fn new(source_text: &'a str, is_mdx: bool) -> Self {
+        Self { source_text, is_mdx }
+    }
+
+    /// Each fenced code block with a JavaScript or TypeScript language tag becomes its own
+    /// source. MDX ESM blocks and expressions are returned as separate sources as well.
+    /// <https://spec.commonmark.org/0.31.2/#fenced-code-blocks>
+    /// <https://mdxjs.com/docs/what-is-mdx/#mdx-syntax>
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let mut results = vec![];
+        let mut pointer = 0;
+
+        while pointer < self.source_text.len() {
+            let line_end = self.line_end(pointer);
+            let line = &self.source_text[pointer..line_end];
+
+            if let Some(fence) = Fence::open(line) {
+                pointer = self.parse_code_block(&fence, line_end, &mut results);
+            } else if self.is_mdx && is_esm_start(line) {
+                pointer = self.parse_esm(pointer, &mut results);
+            } else if self.is_mdx {
+                pointer = self.parse_expressions(pointer, line_end, &mut results);
+            } else {
+                pointer = line_end;
+            }
+        }
+
+        results
+    }
+
+    /// Offset just past the `\n` ending the line that contains `pointer`.
+    fn line_end(&self, pointer: usize) -> usize {
+        self.source_text[pointer..].find('\n').map_or(self.source_text.len(), |i| pointer + i + 1)
+    }
+
+    /// Unclosed fences run to the end of the document.
+    fn parse_code_block(
+        &self,
+        fence: &Fence,
+        content_start: usize,
+        results: &mut Vec<JavaScriptSource<'a>>,
+    ) -> usize {
+        let mut pointer = content_start;
+        let mut content_end = self.source_text.len();
+        while pointer < self.source_text.len() {
+            let line_end = self.line_end(pointer);
+            if fence.is_closed_by(&self.source_text[pointer..line_end]) {
+                content_end = pointer;
+                pointer = line_end;
+                break;
+            }
+            pointer = line_end;
+        }
+
+        if let Some(source_type) = fence.source_type {
+            results.push(self.source(content_start, content_end, source_type));
+        }
+        pointer
+    }
+
+    /// ESM blocks start with `import` or `export` at the beginning of a line and end at the
+    /// next blank line.
+    fn parse_esm(&self, start: usize, results: &mut Vec<JavaScriptSource<'a>>) -> usize {
+        let mut pointer = start;
+        while pointer < self.source_text.len() {
+            let line_end = self.line_end(pointer);
+            if self.source_text[pointer..line_end].trim().is_empty() {
+                break;
+            }
+            pointer = line_end;
+        }
+        results.push(self.source(start, pointer, SourceType::jsx()));
+        pointer
+    }
+
+    /// Find `{expression}`s in the line starting at `pointer`, both in text and in JSX
+    /// attributes. Expressions may span several lines, in which case scanning continues
+    /// until the end of the line the expression ends on.
+    fn parse_expressions(
+        &self,
+        mut pointer: usize,
+        mut line_end: usize,
+        results: &mut Vec<JavaScriptSource<'a>>,
+    ) -> usize {
+        let bytes = self.source_text.as_bytes();
+        while pointer < line_end {
+            match bytes[pointer] {
+                b'\\' => pointer += 1,
+                // `{` inside code spans is plain text
+                b'`' => {
+                    let ticks = bytes[pointer..].iter().take_while(|&&b| b == b'`').count();
+                    let fence = &self.source_text[pointer..pointer + ticks];
+                    pointer += ticks;
+                    if let Some(end) = self.source_text[pointer..line_end].find(fence) {
+                        pointer += end + ticks;
+                    }
+                    continue;
+                }
+                b'{' => {
+                    let start = pointer + 1;
+                    if let Some(end) = find_closing_brace(&self.source_text[start..]) {
+                        let end = start + end;
+                        if !self.source_text[start..end].trim().is_empty() {
+                            results.push(self.source(start, end, SourceType::jsx()));
+                        }
+                        pointer = end;
+                        line_end = line_end.max(self.line_end(end));
+                    }
+                }
+                _ => {}
+            }
+            pointer += 1;
+        }
+        line_end
+    }
+
+    fn source(&self, start: usize, end: usize, source_type: SourceType) -> JavaScriptSource<'a> {
+        // NOTE: loader checked that source_text.len() is less than u32::MAX
+        #[expect(clippy::cast_possible_truncation)]
+        JavaScriptSource::partial(&self.source_text[start..end], source_type, start as u32)
+    }
+}
+
+/// Opening line of a fenced code block, e.g. ```` ```ts title="foo.ts" ````.
+struct Fence {
+    marker: u8,
+    len: usize,
+    /// `None` if the language tag is not JavaScript or TypeScript.
+    source_type: Option<SourceType>,
+}
+
+impl Fence {
+    fn open(line: &str) -> Option<Self> {
+        let line = line.trim_start();
+        let marker = *line.as_bytes().first()?;
+        if marker != b'`' && marker != b'~' {
+            return None;
+        }
+        let len = line.bytes().take_while(|&b| b == marker).count();
+        if len < 3 {
+            return None;
+        }
+        let info = line[len..].trim();
+        // backtick fences can't have backticks in their info string, otherwise it is a code span
+        if marker == b'`' && info.contains('`') {
+            return None;
+        }
+        let lang = info.split(|c: char| c.is_whitespace() || c == '{').next().unwrap_or_default();
+        Some(Self { marker, len, source_type: language_source_type(lang) })
+    }
+
+    fn is_closed_by(&self, line: &str) -> bool {
+        let line = line.trim();
+        line.len() >= self.len && line.bytes().all(|b| b == self.marker)
+    }
+}
+
+fn language_source_type(lang: &str) -> Option<SourceType> {
+    let lang = lang.cow_to_ascii_lowercase();
+    let ext = match lang.as_ref() {
+        "javascript" => "js",
+        "typescript" => "ts",
+        ext => ext,
+    };
+    SourceType::from_extension(ext).ok()
+}
+
+fn is_esm_start(line: &str) -> bool {
+    ["import", "export"].iter().any(|keyword| {
+        line.strip_prefix(keyword).is_some_and(|rest| {
+            rest.starts_with(|c: char| c.is_whitespace() || c == '{' || c == '*')
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, MarkdownPartialLoader};
+
+    fn parse_markdown(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        MarkdownPartialLoader::new(source_text, false).parse()
+    }
+
+    fn parse_mdx(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        MarkdownPartialLoader::new(source_text, true).parse()
+    }
+
+    #[test]
+    fn test_parse_markdown() {
+        let source_text = "
+# Title
+
+```js
+console.log(\"hi\");
+```
+
+```ts title=\"foo.ts\"
+let x: number = 1;
+```
+
+```css
+a { color: red }
+```
+
+~~~~jsx
+<App />
+~~~~
+";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 3);
+        assert_eq!(sources[0].source_text, "console.log(\"hi\");\n");
+        assert!(!sources[0].source_type.is_typescript());
+        assert_eq!(sources[1].source_text, "let x: number = 1;\n");
+        assert!(sources[1].source_type.is_typescript());
+        assert_eq!(sources[2].source_text, "<App />\n");
+        assert!(sources[2].source_type.is_jsx());
+
+        for source in &sources {
+            let start = source.start as usize;
+            assert_eq!(&source_text[start..start + source.source_text.len()], source.source_text);
+        }
+    }
+
+    #[test]
+    fn test_parse_markdown_fences() {
+        // language aliases, nested fences and indented fences in lists
+        let source_text = "
+````markdown
+```js
+ignored();
+```
+````
+
+- item
+
+  ```TypeScript
+  let x = 1;
+  ```
+
+```javascript
+unclosed();
+";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text.trim(), "let x = 1;");
+        assert!(sources[0].source_type.is_typescript());
+        assert_eq!(sources[1].source_text, "unclosed();\n");
+    }
+
+    #[test]
+    fn test_parse_markdown_ignores_mdx_syntax() {
+        let source_text = "import foo from 'foo'\n\nText with {braces}.\n";
+        assert!(parse_markdown(source_text).is_empty());
+    }
+
+    #[test]
+    fn test_parse_mdx() {
+        let source_text = "import { Chart } from './chart.js'
+export const year = 2023
+
+# Last year's snowfall in {year}
+
+<Chart data={[1, 2, { a: '}' }]} />
+
+Text with `{code}` and \\{escaped}, {/* a comment */} and {
+  year + 1
+}.
+
+```js
+console.log(year);
+```
+";
+
+        let sources = parse_mdx(source_text);
+        let texts = sources.iter().map(|s| s.source_text).collect::<Vec<_>>();
+        assert_eq!(
+            texts,
+            [
+                "import { Chart } from './chart.js'\nexport const year = 2023\n",
+                "year",
+                "[1, 2, { a: '}' }]",
+                "/* a comment */",
+                "\n  year + 1\n",
+                "console.log(year);\n",
+            ]
+        );
+
+        for source in &sources {
+            let start = source.start as usize;
+            assert_eq!(&source_text[start..start + source.source_text.len()], source.source_text);
+        }
+    }
+}
@@ -3,9 +3,13 @@ use oxc_span::VALID_EXTENSIONS;
 use crate::loader::JavaScriptSource;
 
 mod astro;
+mod html;
+mod markdown;
 mod svelte;
 mod vue;
 pub use astro::AstroPartialLoader;
+pub use html::HtmlPartialLoader;
+pub use markdown::MarkdownPartialLoader;
 pub use svelte::SveltePartialLoader;
 pub use vue::VuePartialLoader;
 
@@ -14,10 +18,11 @@ const SCRIPT_END: &str = "</script>";
 
 /// File extensions that can contain JS/TS code in certain parts, such as in `<script>` tags, and can
 /// be loaded using the [`PartialLoader`].
-pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] = &["vue", "astro", "svelte"];
+pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] =
+    &["vue", "astro", "svelte", "md", "markdown", "mdx", "html", "htm"];
 
 /// All valid JavaScript/TypeScript extensions, plus additional framework files that
-/// contain JavaScript/TypeScript code in them (e.g., Vue, Astro, Svelte, etc.).
+/// contain JavaScript/TypeScript code in them (e.g., Vue, Astro, Svelte, Markdown, HTML, etc.).
 pub const LINTABLE_EXTENSIONS: &[&str] =
     constcat::concat_slices!([&str]: VALID_EXTENSIONS, LINT_PARTIAL_LOADER_EXTENSIONS);
 
@@ -31,6 +36,9 @@ impl PartialLoader {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "md" | "markdown" => Some(MarkdownPartialLoader::new(source_text, false).parse()),
+            "mdx" => Some(MarkdownPartialLoader::new(source_text, true).parse()),
+            "html" | "htm" => Some(HtmlPartialLoader::new(source_text).parse()),
             _ => None,
         }
     }
@@ -39,7 +47,8 @@ impl PartialLoader {
 /// Find closing angle for situations where there is another `>` in between.
 /// e.g. `<script generic="T extends Record<string, string>">`
 /// or `<script attribute="text with > inside">`
-fn find_script_closing_angle(source_text: &str, pointer: usize) -> Option<usize> {
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn find_script_closing_angle(source_text: &str, pointer: usize) -> Option<usize> {
     let mut open_angle = 0;
     let mut in_quote: Option<char> = None;
 
@@ -571,8 +571,41 @@ mod vitest {
 }
 
 mod node {
+    pub mod hashbang;
+    pub mod no_deprecated_api;
     pub mod no_exports_assign;
     pub mod no_new_require;
+    pub mod no_path_concat;
+    pub mod no_process_exit;
+    pub mod no_sync;
+    pub mod no_unsupported_features_es_builtins;
+    pub mod no_unsupported_features_es_syntax;
+    pub mod no_unsupported_features_node_builtins;
+    pub mod prefer_global_buffer;
+    pub mod prefer_global_console;
+    pub mod prefer_global_process;
+    pub mod prefer_global_text_decoder;
+    pub mod prefer_global_text_encoder;
+    pub mod prefer_global_url;
+    pub mod prefer_global_url_search_params;
+    pub mod prefer_promises_dns;
+    pub mod prefer_promises_fs;
+}
+
+mod regexp {
+    pub mod no_dupe_characters_character_class;
+    pub mod no_super_linear_backtracking;
+    pub mod no_useless_quantifier;
+    pub mod optimal_quantifier_concatenation;
+    pub mod prefer_character_class;
+}
+
+mod vue {
+    pub mod no_mutating_props;
+    pub mod no_unused_vars;
+    pub mod no_use_v_if_with_v_for;
+    pub mod require_v_for_key;
+    pub mod valid_v_for;
 }
 
 oxc_macros::declare_all_lint_rules! {
@@ -868,8 +901,25 @@ oxc_macros::declare_all_lint_rules! {
     nextjs::no_typos,
     nextjs::no_unwanted_polyfillio,
     nextjs::no_html_link_for_pages,
+    node::hashbang,
+    node::no_deprecated_api,
     node::no_exports_assign,
     node::no_new_require,
+    node::no_path_concat,
+    node::no_process_exit,
+    node::no_sync,
+    node::no_unsupported_features_es_builtins,
+    node::no_unsupported_features_es_syntax,
+    node::no_unsupported_features_node_builtins,
+    node::prefer_global_buffer,
+    node::prefer_global_console,
+    node::prefer_global_process,
+    node::prefer_global_text_decoder,
+    node::prefer_global_text_encoder,
+    node::prefer_global_url,
+    node::prefer_global_url_search_params,
+    node::prefer_promises_dns,
+    node::prefer_promises_fs,
     oxc::approx_constant,
     oxc::bad_array_method_on_arguments,
     oxc::bad_bitwise_operator,
@@ -949,6 +999,11 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_array_as_prop,
     react_perf::jsx_no_new_function_as_prop,
     react_perf::jsx_no_new_object_as_prop,
+    regexp::no_dupe_characters_character_class,
+    regexp::no_super_linear_backtracking,
+    regexp::no_useless_quantifier,
+    regexp::optimal_quantifier_concatenation,
+    regexp::prefer_character_class,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
     typescript::ban_ts_comment,
@@ -1095,6 +1150,11 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::switch_case_braces,
     unicorn::text_encoding_identifier_case,
     unicorn::throw_new_error,
+    vue::no_mutating_props,
+    vue::no_unused_vars,
+    vue::no_use_v_if_with_v_for,
+    vue::require_v_for_key,
+    vue::valid_v_for,
     vitest::no_conditional_tests,
     vitest::no_import_node_test,
     vitest::prefer_to_be_falsy,
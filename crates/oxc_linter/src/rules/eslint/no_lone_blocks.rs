@@ -56,6 +56,9 @@ impl Rule for NoLoneBlocks {
         let AstKind::BlockStatement(stmt) = node.kind() else {
             return;
         };
+        if ctx.markup().is_some_and(|markup| markup.is_lowered_statement(stmt.span)) {
+            return;
+        }
 
         let parent_node = ctx.nodes().parent_node(node.id());
 
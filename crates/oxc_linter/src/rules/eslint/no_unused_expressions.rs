@@ -58,6 +58,10 @@ impl Rule for NoUnusedExpressions {
         let AstKind::ExpressionStatement(expression_stmt) = node.kind() else {
             return;
         };
+        // Markup expressions are rendered.
+        if ctx.markup().is_some_and(|markup| markup.is_lowered_statement(expression_stmt.span)) {
+            return;
+        }
 
         if self.is_disallowed(&expression_stmt.expression)
             && !is_parent_arrow_function_expression(node, ctx)
@@ -538,4 +542,29 @@ fn test() {
 
     Tester::new(NoUnusedExpressions::NAME, NoUnusedExpressions::PLUGIN, pass, fail)
         .test_and_snapshot();
+
+    let pass = vec![(
+        r#"
+<script>
+let name = 'world'
+</script>
+<h1 class="title {name}">Hello {name}!</h1>
+{#each [1, 2] as i (i)}{i}{/each}
+"#,
+        None,
+    )];
+    let fail = vec![(
+        r"
+<script>
+let name = 'world'
+</script>
+<button on:click={() => { name; }}>{name}</button>
+",
+        None,
+    )];
+
+    Tester::new(NoUnusedExpressions::NAME, NoUnusedExpressions::PLUGIN, pass, fail)
+        .change_rule_path_extension("svelte")
+        .with_snapshot_suffix("svelte")
+        .test_and_snapshot();
 }
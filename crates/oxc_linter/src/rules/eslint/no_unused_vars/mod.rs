@@ -21,6 +21,7 @@ use symbol::Symbol;
 use crate::{
     context::{ContextHost, LintContext},
     rule::Rule,
+    template::markup::MarkupKind,
 };
 
 #[derive(Debug, Default, Clone)]
@@ -215,19 +216,38 @@ impl Rule for NoUnusedVars {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        // ignore .d.ts and vue/svelte/astro files.
+        // ignore .d.ts files and framework files without a parsed template.
         // 1. declarations have side effects (they get merged together)
-        // 2. vue/svelte/astro scripts declare variables that get used in the template, which
-        //    we can't detect
+        // 2. vue/svelte/astro scripts declare variables that get used in the template,
+        //    which we can't detect if the template could not be parsed.
+        // 3. MDX imports and exports are used by the content's JSX, which is linted separately.
         !ctx.source_type().is_typescript_definition()
-            && !ctx
-                .file_path()
-                .extension()
-                .is_some_and(|ext| ext == "vue" || ext == "svelte" || ext == "astro")
+            && match ctx.file_path().extension() {
+                Some(ext) if ext == "vue" => ctx.vue_template().is_some(),
+                Some(ext) if ext == "svelte" || ext == "astro" => ctx.markup().is_some(),
+                Some(ext) if ext == "mdx" => false,
+                _ => true,
+            }
     }
 }
 
 impl NoUnusedVars {
+    /// Top-level bindings of a Vue component are available in its `<template>`.
+    fn is_used_in_vue_template(symbol: &Symbol<'_, '_>, ctx: &LintContext<'_>) -> bool {
+        symbol.is_root()
+            && ctx.vue_template().is_some_and(|template| template.references(symbol.name()))
+    }
+
+    /// `$store` in a Svelte component subscribes to the top-level `store`.
+    fn is_used_as_svelte_store(symbol: &Symbol<'_, '_>, ctx: &LintContext<'_>) -> bool {
+        symbol.is_root()
+            && ctx.markup().is_some_and(|markup| markup.kind() == MarkupKind::Svelte)
+            && ctx
+                .scoping()
+                .root_unresolved_references()
+                .contains_key(format!("${}", symbol.name()).as_str())
+    }
+
     fn run_on_symbol_internal<'a>(&self, symbol: &Symbol<'_, 'a>, ctx: &LintContext<'a>) {
         let is_ignored = self.is_ignored(symbol);
 
@@ -236,7 +256,10 @@ impl NoUnusedVars {
         }
 
         // Order matters. We want to call cheap/high "yield" functions first.
-        let is_used = symbol.is_exported() || symbol.has_usages(self);
+        let is_used = symbol.is_exported()
+            || Self::is_used_in_vue_template(symbol, ctx)
+            || symbol.has_usages(self)
+            || Self::is_used_as_svelte_store(symbol, ctx);
 
         match (is_used, is_ignored) {
             (true, true) => {
@@ -1349,6 +1349,184 @@ import Layout from '../layouts/Layout.astro';
         .test();
 }
 
+#[test]
+fn test_vue_template() {
+    let pass = vec![
+        r#"
+<script setup lang="ts">
+import { ref } from 'vue'
+import MyButton from './MyButton.vue'
+import TodoItem from './TodoItem.vue'
+import { vFocus } from './directives'
+const count = ref(0)
+const items = ref<string[]>([])
+const input = ref()
+function increment() { count.value++ }
+</script>
+<template>
+  <MyButton @click="increment">{{ count }}</MyButton>
+  <todo-item v-for="item in items" :key="item" :text="item" />
+  <input ref="input" v-focus>
+</template>
+"#,
+        // `<script>` and `<script setup>` share the same scope
+        r"
+<template><div>{{ format(value) }}</div></template>
+<script>
+import { format } from './format'
+</script>
+<script setup>
+const value = 1
+</script>
+",
+        // template which is not HTML
+        r#"
+<template lang="pug">div {{ count }}</template>
+<script setup>
+const count = 1
+</script>
+"#,
+    ];
+
+    let fail = vec![
+        r"
+<script setup>
+import { ref } from 'vue'
+const count = ref(0)
+const unused = 1
+</script>
+<template><div>{{ count }}</div></template>
+",
+        // shadowed by a `v-for` alias
+        r#"
+<script setup>
+const item = 1
+const items = []
+</script>
+<template><div v-for="item in items">{{ item }}</div></template>
+"#,
+        // function parameters are not affected by the template
+        r"
+<template><div>{{ fn }}</div></template>
+<script setup>
+function fn(value) {}
+</script>
+",
+    ];
+
+    Tester::new(NoUnusedVars::NAME, NoUnusedVars::PLUGIN, pass, fail)
+        .change_rule_path_extension("vue")
+        .intentionally_allow_no_fix_tests()
+        .with_snapshot_suffix("vue")
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_svelte_markup() {
+    let pass = vec![
+        r#"
+<script lang="ts">
+import Button from './Button.svelte'
+import { writable } from 'svelte/store'
+import { fade } from 'svelte/transition'
+let name = 'world'
+let items: string[] = []
+let value = ''
+const count = writable(0)
+function greet() {}
+</script>
+<h1 class="title {name}">Hello {name}!</h1>
+<Button on:click={greet}>{$count}</Button>
+<input bind:value transition:fade />
+{#each items as item (item)}<p>{item}</p>{/each}
+"#,
+        r"
+<script>
+let promise = fetch('/')
+</script>
+{#await promise then response}{response.status}{:catch error}{error.message}{/await}
+",
+        r"
+<script>
+export let list
+</script>
+{#snippet row(item)}<td>{item}</td>{/snippet}
+{#each list as entry}{@render row(entry)}{/each}
+",
+        // markup which can't be parsed is not linted
+        r"
+<script>
+let name = 'world'
+</script>
+<h1>{name +}</h1>
+",
+    ];
+
+    let fail = vec![
+        r"
+<script>
+let name = 'world'
+let unused = 1
+</script>
+<h1>Hello {name}!</h1>
+",
+        // shadowed by the item of an `{#each}` block
+        r"
+<script>
+let item = 1
+let items = []
+</script>
+{#each items as item}{item}{/each}
+",
+        r"
+<script>
+let items = []
+</script>
+{#each items as item, index}{item}{/each}
+",
+    ];
+
+    Tester::new(NoUnusedVars::NAME, NoUnusedVars::PLUGIN, pass, fail)
+        .change_rule_path_extension("svelte")
+        .intentionally_allow_no_fix_tests()
+        .with_snapshot_suffix("svelte")
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_astro_markup() {
+    let pass = vec![
+        r"---
+import Layout from '../layouts/Layout.astro'
+const items = ['a', 'b']
+const title = 'Home'
+---
+<Layout title={title}>
+  <ul>{items.map((item) => <li>{item}</li>)}</ul>
+</Layout>
+<script>
+  const button = document.querySelector('button')
+  console.log(button)
+</script>
+",
+    ];
+
+    let fail = vec![
+        r"---
+import Card from '../components/Card.astro'
+const unused = 1
+---
+<Card />
+",
+    ];
+
+    Tester::new(NoUnusedVars::NAME, NoUnusedVars::PLUGIN, pass, fail)
+        .change_rule_path_extension("astro")
+        .intentionally_allow_no_fix_tests()
+        .with_snapshot_suffix("astro")
+        .test_and_snapshot();
+}
+
 // #[test]
 // fn test_template() {
 //     let pass = vec![];
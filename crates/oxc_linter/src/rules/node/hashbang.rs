@@ -0,0 +1,183 @@
+use std::path::{Component, Path, PathBuf};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::find_package_json};
+
+const NODE_HASHBANG: &str = "#!/usr/bin/env node";
+
+fn missing_hashbang_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("This file needs a `{NODE_HASHBANG}` hashbang."))
+        .with_help("The file is listed in the `bin` field of `package.json`.")
+        .with_label(span)
+}
+
+fn unexpected_hashbang_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This file needs no hashbang.")
+        .with_help("Only files listed in the `bin` field of `package.json` are executed directly.")
+        .with_label(span)
+}
+
+fn wrong_hashbang_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("This file needs the hashbang `{NODE_HASHBANG}`.")).with_label(span)
+}
+
+fn crlf_hashbang_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("The hashbang line must end with a Unix linebreak (LF).")
+        .with_help("`env` fails to find `node\\r` when the hashbang ends with CRLF.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Hashbang;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require a correct `#!/usr/bin/env node` hashbang in files listed in the `bin` field of the
+    /// closest `package.json`, and disallow hashbangs in all other files of the package.
+    ///
+    /// Files outside of a package are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// npm links the files in `bin` into the user's `PATH`, and the hashbang tells the shell to
+    /// run them with Node.js. Without it, or with Windows linebreaks, the command fails to run.
+    /// A hashbang in a file that is only imported is misleading.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, with `"bin": "./bin/cli.js"`:
+    /// ```js
+    /// // bin/cli.js
+    /// require("../lib/cli").run();
+    ///
+    /// // lib/cli.js
+    /// #!/usr/bin/env node
+    /// exports.run = () => {};
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule, with `"bin": "./bin/cli.js"`:
+    /// ```js
+    /// // bin/cli.js
+    /// #!/usr/bin/env node
+    /// require("../lib/cli").run();
+    ///
+    /// // lib/cli.js
+    /// exports.run = () => {};
+    /// ```
+    Hashbang,
+    node,
+    style,
+    fix
+);
+
+impl Rule for Hashbang {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(program) = ctx.nodes().program() else {
+            return;
+        };
+        let path = ctx.file_path();
+        let Some((package_dir, package_json)) = find_package_json(path) else {
+            return;
+        };
+        let is_bin = bin_paths(&package_json, package_dir).any(|bin| bin == normalize(path));
+
+        let Some(hashbang) = &program.hashbang else {
+            if is_bin {
+                ctx.diagnostic_with_fix(missing_hashbang_diagnostic(Span::empty(0)), |fixer| {
+                    fixer.insert_text_before_range(Span::empty(0), format!("{NODE_HASHBANG}\n"))
+                });
+            }
+            return;
+        };
+
+        let source_text = ctx.source_text();
+        let line_end = hashbang.span.end as usize;
+        if !is_bin {
+            let end = line_end + usize::from(source_text[line_end..].starts_with('\n'));
+            #[expect(clippy::cast_possible_truncation)]
+            let removed = Span::new(hashbang.span.start, end as u32);
+            ctx.diagnostic_with_fix(unexpected_hashbang_diagnostic(hashbang.span), |fixer| {
+                fixer.delete_range(removed)
+            });
+            return;
+        }
+
+        let command = hashbang.value.as_str().trim_end_matches('\r').trim();
+        if !is_node_command(command) {
+            ctx.diagnostic_with_fix(wrong_hashbang_diagnostic(hashbang.span), |fixer| {
+                fixer.replace(hashbang.span, NODE_HASHBANG)
+            });
+        } else if source_text[line_end..].starts_with("\r\n") {
+            ctx.diagnostic_with_fix(crlf_hashbang_diagnostic(hashbang.span), |fixer| {
+                fixer.delete_range(Span::sized(hashbang.span.end, 1))
+            });
+        }
+    }
+}
+
+/// `/usr/bin/env node`, optionally with flags, or a direct path to a `node` binary.
+fn is_node_command(command: &str) -> bool {
+    let mut parts = command.split_whitespace();
+    match parts.next() {
+        Some(program) if program.ends_with("/env") => {
+            parts.find(|part| !part.starts_with('-')) == Some("node")
+        }
+        Some(program) => program == "node" || program.ends_with("/node"),
+        None => false,
+    }
+}
+
+fn bin_paths<'j>(
+    package_json: &'j serde_json::Value,
+    package_dir: &'j Path,
+) -> impl Iterator<Item = PathBuf> + 'j {
+    let bin = package_json.get("bin");
+    let paths: Box<dyn Iterator<Item = &serde_json::Value>> = match bin {
+        Some(serde_json::Value::Object(bins)) => Box::new(bins.values()),
+        Some(bin) => Box::new(std::iter::once(bin)),
+        None => Box::new(std::iter::empty()),
+    };
+    paths.filter_map(serde_json::Value::as_str).map(move |bin| normalize(&package_dir.join(bin)))
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    path.components().filter(|component| *component != Component::CurDir).collect()
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let lib = || Some(PathBuf::from("../node/hashbang/lib/index.js"));
+
+    let pass = vec![
+        ("#!/usr/bin/env node\nconsole.log('hi');", None, None, None),
+        ("#!/usr/bin/env -S node --experimental-vm-modules\nrun();", None, None, None),
+        ("#!/usr/local/bin/node\nrun();", None, None, None),
+        ("module.exports = {};", None, None, lib()),
+    ];
+
+    let fail = vec![
+        ("console.log('hi');", None, None, None),
+        ("#!/bin/sh\nconsole.log('hi');", None, None, None),
+        ("#!/usr/bin/env node\r\nconsole.log('hi');", None, None, None),
+        ("#!/usr/bin/env node\nmodule.exports = {};", None, None, lib()),
+    ];
+
+    let fix = vec![
+        ("console.log('hi');", "#!/usr/bin/env node\nconsole.log('hi');"),
+        ("#!/usr/bin/env bash\nrun();", "#!/usr/bin/env node\nrun();"),
+        ("#!/usr/bin/env node\r\nrun();", "#!/usr/bin/env node\nrun();"),
+    ];
+
+    Tester::new(Hashbang::NAME, Hashbang::PLUGIN, pass, fail)
+        .change_rule_path("../node/hashbang/bin/cli.js")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
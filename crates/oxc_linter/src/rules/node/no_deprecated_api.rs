@@ -0,0 +1,327 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{NodeAccessBase, NodeAccessKind, collect_node_accesses},
+};
+
+fn no_deprecated_api_diagnostic(span: Span, name: &str, api: &DeprecatedApi) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn(format!("`{name}` was deprecated since v{}.", api.since))
+        .with_label(span);
+    match api.replacement {
+        Some(replacement) => diagnostic.with_help(format!("Use {replacement} instead.")),
+        None => diagnostic,
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecatedApi(Box<NoDeprecatedApiConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecatedApiConfig {
+    ignore_module_items: Vec<CompactStr>,
+    ignore_global_items: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoDeprecatedApi {
+    type Target = NoDeprecatedApiConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow deprecated Node.js APIs, such as `new Buffer()`, `fs.exists()` or the `domain`
+    /// module.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated APIs may be removed in future Node.js versions, and often have security or
+    /// correctness problems their replacements fix.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const fs = require("fs");
+    /// fs.exists("./foo", () => {});
+    ///
+    /// const buf = new Buffer(10);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const fs = require("fs");
+    /// fs.access("./foo", () => {});
+    ///
+    /// const buf = Buffer.alloc(10);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-deprecated-api": ["error", {
+    ///     "ignoreModuleItems": ["url.parse"],
+    ///     "ignoreGlobalItems": ["new Buffer()"]
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// Items are named as they are reported, e.g. `domain`, `fs.exists`, `buffer.Buffer()` or
+    /// `new Buffer()`.
+    NoDeprecatedApi,
+    node,
+    pedantic
+);
+
+impl Rule for NoDeprecatedApi {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let items = |key: &str| {
+            config
+                .and_then(|config| config.get(key))
+                .and_then(serde_json::Value::as_array)
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactStr::from)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Self(Box::new(NoDeprecatedApiConfig {
+            ignore_module_items: items("ignoreModuleItems"),
+            ignore_global_items: items("ignoreGlobalItems"),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        for access in collect_node_accesses(ctx, &DEPRECATED_MODULES, &DEPRECATED_GLOBALS) {
+            let Some(api) = DEPRECATED_APIS
+                .iter()
+                .find(|api| api.kind == access.kind && access.is(api.base, api.path))
+            else {
+                continue;
+            };
+            let name = access.name();
+            let ignored = match access.base {
+                NodeAccessBase::Module(_) => &self.ignore_module_items,
+                NodeAccessBase::Global => &self.ignore_global_items,
+            };
+            if ignored.iter().any(|item| item == name.as_str()) {
+                continue;
+            }
+            ctx.diagnostic(no_deprecated_api_diagnostic(access.span, &name, api));
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct DeprecatedApi {
+    base: NodeAccessBase<'static>,
+    path: &'static [&'static str],
+    kind: NodeAccessKind,
+    since: &'static str,
+    replacement: Option<&'static str>,
+}
+
+const fn module(
+    module: &'static str,
+    path: &'static [&'static str],
+    since: &'static str,
+    replacement: Option<&'static str>,
+) -> DeprecatedApi {
+    DeprecatedApi {
+        base: NodeAccessBase::Module(module),
+        path,
+        kind: NodeAccessKind::Read,
+        since,
+        replacement,
+    }
+}
+
+const fn global(
+    path: &'static [&'static str],
+    since: &'static str,
+    replacement: Option<&'static str>,
+) -> DeprecatedApi {
+    DeprecatedApi {
+        base: NodeAccessBase::Global,
+        path,
+        kind: NodeAccessKind::Read,
+        since,
+        replacement,
+    }
+}
+
+const fn with_kind(api: DeprecatedApi, kind: NodeAccessKind) -> DeprecatedApi {
+    DeprecatedApi { kind, ..api }
+}
+
+const DEPRECATED_MODULES: [&str; 22] = [
+    "_linklist",
+    "_stream_wrap",
+    "async_hooks",
+    "buffer",
+    "constants",
+    "crypto",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "module",
+    "net",
+    "path",
+    "process",
+    "punycode",
+    "sys",
+    "tls",
+    "tty",
+    "url",
+    "util",
+    "vm",
+    "repl",
+];
+
+const DEPRECATED_GLOBALS: [&str; 6] = ["Buffer", "GLOBAL", "Intl", "process", "require", "root"];
+
+const BUFFER: Option<&str> = Some("`Buffer.alloc()` or `Buffer.from()`");
+const TLS_SOCKET: Option<&str> = Some("`tls.TLSSocket`");
+const TYPE_CHECK: Option<&str> = Some("`typeof` or `instanceof` checks");
+
+#[rustfmt::skip]
+const DEPRECATED_APIS: &[DeprecatedApi] = &[
+    module("_linklist", &[], "5.0.0", None),
+    module("_stream_wrap", &[], "12.0.0", None),
+    module("async_hooks", &["currentId"], "8.2.0", Some("`async_hooks.executionAsyncId()`")),
+    module("async_hooks", &["triggerId"], "8.2.0", Some("`async_hooks.triggerAsyncId()`")),
+    with_kind(module("buffer", &["Buffer"], "6.0.0", BUFFER), NodeAccessKind::Call),
+    with_kind(module("buffer", &["Buffer"], "6.0.0", BUFFER), NodeAccessKind::Construct),
+    module("buffer", &["SlowBuffer"], "6.0.0", Some("`Buffer.allocUnsafeSlow()`")),
+    module("constants", &[], "6.3.0", Some("the `constants` property of each module")),
+    module("crypto", &["_toBuf"], "11.0.0", None),
+    module("crypto", &["Credentials"], "0.12.0", Some("`tls.SecureContext`")),
+    module("crypto", &["DEFAULT_ENCODING"], "10.0.0", None),
+    module("crypto", &["createCipher"], "10.0.0", Some("`crypto.createCipheriv()`")),
+    module("crypto", &["createCredentials"], "0.12.0", Some("`tls.createSecureContext()`")),
+    module("crypto", &["createDecipher"], "10.0.0", Some("`crypto.createDecipheriv()`")),
+    module("crypto", &["fips"], "10.0.0", Some("`crypto.getFips()` and `crypto.setFips()`")),
+    module("crypto", &["prng"], "11.0.0", Some("`crypto.randomBytes()`")),
+    module("crypto", &["pseudoRandomBytes"], "11.0.0", Some("`crypto.randomBytes()`")),
+    module("crypto", &["rng"], "11.0.0", Some("`crypto.randomBytes()`")),
+    module("domain", &[], "4.0.0", None),
+    module("events", &["EventEmitter", "listenerCount"], "3.2.0", Some("`emitter.listenerCount()`")),
+    module("events", &["listenerCount"], "3.2.0", Some("`emitter.listenerCount()`")),
+    module("fs", &["exists"], "4.0.0", Some("`fs.stat()` or `fs.access()`")),
+    module("fs", &["lchmod"], "0.4.0", None),
+    module("fs", &["lchmodSync"], "0.4.0", None),
+    module("http", &["createClient"], "0.10.0", Some("`http.request()`")),
+    module("module", &["Module", "createRequireFromPath"], "12.2.0", Some("`module.createRequire()`")),
+    module("module", &["createRequireFromPath"], "12.2.0", Some("`module.createRequire()`")),
+    module("module", &["_debug"], "9.0.0", None),
+    module("net", &["_setSimultaneousAccepts"], "12.0.0", None),
+    module("path", &["_makeLong"], "9.0.0", Some("`path.toNamespacedPath()`")),
+    module("process", &["EventEmitter"], "0.6.0", Some("`require(\"events\")`")),
+    module("process", &["assert"], "10.0.0", Some("`require(\"assert\")`")),
+    module("process", &["binding"], "10.9.0", None),
+    module("punycode", &[], "7.0.0", Some("the userland `punycode` package")),
+    module("repl", &["REPLServer", "prototype", "parseREPLKeyword"], "15.0.0", None),
+    module("sys", &[], "1.0.0", Some("the `util` module")),
+    module("tls", &["CleartextStream"], "0.10.0", None),
+    module("tls", &["CryptoStream"], "0.12.0", TLS_SOCKET),
+    module("tls", &["SecurePair"], "6.0.0", TLS_SOCKET),
+    module("tls", &["convertNPNProtocols"], "10.0.0", None),
+    module("tls", &["createSecurePair"], "6.0.0", TLS_SOCKET),
+    module("tls", &["parseCertString"], "8.6.0", Some("`querystring.parse()`")),
+    module("tty", &["setRawMode"], "0.10.0", Some("`tty.ReadStream#setRawMode()`")),
+    module("url", &["parse"], "11.0.0", Some("the `URL` constructor")),
+    module("url", &["resolve"], "11.0.0", Some("the `URL` constructor")),
+    module("util", &["_extend"], "6.0.0", Some("`Object.assign()`")),
+    module("util", &["debug"], "0.11.3", Some("`console.error()`")),
+    module("util", &["error"], "0.11.3", Some("`console.error()`")),
+    module("util", &["isArray"], "4.0.0", Some("`Array.isArray()`")),
+    module("util", &["isBoolean"], "4.0.0", TYPE_CHECK),
+    module("util", &["isBuffer"], "4.0.0", Some("`Buffer.isBuffer()`")),
+    module("util", &["isDate"], "4.0.0", TYPE_CHECK),
+    module("util", &["isError"], "4.0.0", TYPE_CHECK),
+    module("util", &["isFunction"], "4.0.0", TYPE_CHECK),
+    module("util", &["isNull"], "4.0.0", TYPE_CHECK),
+    module("util", &["isNullOrUndefined"], "4.0.0", TYPE_CHECK),
+    module("util", &["isNumber"], "4.0.0", TYPE_CHECK),
+    module("util", &["isObject"], "4.0.0", TYPE_CHECK),
+    module("util", &["isPrimitive"], "4.0.0", TYPE_CHECK),
+    module("util", &["isRegExp"], "4.0.0", TYPE_CHECK),
+    module("util", &["isString"], "4.0.0", TYPE_CHECK),
+    module("util", &["isSymbol"], "4.0.0", TYPE_CHECK),
+    module("util", &["isUndefined"], "4.0.0", TYPE_CHECK),
+    module("util", &["log"], "6.0.0", Some("a third party module")),
+    module("util", &["print"], "0.11.3", Some("`console.log()`")),
+    module("util", &["pump"], "0.10.0", Some("`stream.Readable#pipe()`")),
+    module("util", &["puts"], "0.11.3", Some("`console.log()`")),
+    module("vm", &["runInDebugContext"], "8.0.0", None),
+    with_kind(global(&["Buffer"], "6.0.0", BUFFER), NodeAccessKind::Call),
+    with_kind(global(&["Buffer"], "6.0.0", BUFFER), NodeAccessKind::Construct),
+    global(&["GLOBAL"], "6.0.0", Some("`global`")),
+    global(&["Intl", "v8BreakIterator"], "7.0.0", None),
+    global(&["process", "EventEmitter"], "0.6.0", Some("`require(\"events\")`")),
+    global(&["process", "assert"], "10.0.0", Some("`require(\"assert\")`")),
+    global(&["process", "binding"], "10.9.0", None),
+    global(&["require", "extensions"], "0.12.0", None),
+    global(&["root"], "6.0.0", Some("`global`")),
+];
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"require("buffer").Buffer.from("a")"#, None),
+        (r#"const { Buffer } = require("buffer"); Buffer.alloc(10)"#, None),
+        (r#"const fs = require("fs"); fs.access("foo", () => {})"#, None),
+        (r#"import { readFile } from "node:fs"; readFile("foo", () => {})"#, None),
+        ("Buffer.from('a')", None),
+        ("let Buffer = class {}; new Buffer()", None),
+        ("function f(fs) { fs.exists('foo') }", None),
+        ("const url = new URL('https://example.com')", None),
+        ("process.exit(0)", None),
+        (
+            r#"require("url").parse("a")"#,
+            Some(serde_json::json!([{ "ignoreModuleItems": ["url.parse"] }])),
+        ),
+        ("new Buffer(10)", Some(serde_json::json!([{ "ignoreGlobalItems": ["new Buffer()"] }]))),
+    ];
+
+    let fail = vec![
+        (r#"require("fs").exists("foo", () => {})"#, None),
+        (r#"const fs = require("fs"); fs.exists("foo", () => {})"#, None),
+        (r#"const { exists } = require("node:fs"); exists("foo", () => {})"#, None),
+        (r#"import { exists } from "fs""#, None),
+        (r#"import fs from "fs"; fs.exists("foo")"#, None),
+        (r#"import * as util from "util"; util.isArray([])"#, None),
+        (r#"const domain = require("domain")"#, None),
+        (r#"import punycode from "punycode""#, None),
+        ("new Buffer(10)", None),
+        ("Buffer(10)", None),
+        (r#"const { Buffer } = require("buffer"); new Buffer(10)"#, None),
+        (r#"new (require("buffer").Buffer)(10)"#, None),
+        ("process.binding('fs')", None),
+        ("require.extensions['.txt'] = () => {}", None),
+        (r#"const { parse } = require("url")"#, None),
+        (r#"require("crypto").createCipher("aes192", "secret")"#, None),
+        (
+            r#"const events = require("events"); events.EventEmitter.listenerCount(emitter, "x")"#,
+            None,
+        ),
+        ("GLOBAL.foo = 1", None),
+    ];
+
+    Tester::new(NoDeprecatedApi::NAME, NoDeprecatedApi::PLUGIN, pass, fail).test_and_snapshot();
+}
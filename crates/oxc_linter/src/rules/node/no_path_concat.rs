@@ -0,0 +1,145 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BinaryExpression, Expression, TemplateLiteral},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::Span;
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_path_concat_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use `path.join()` or `path.resolve()` instead of string concatenation.")
+        .with_help(
+            "Concatenating `__dirname` or `__filename` with path separators is not portable.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoPathConcat;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow string concatenation with `__dirname` and `__filename`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Building file paths with string concatenation hard codes the path separator, which
+    /// differs between operating systems. `path.join()` and `path.resolve()` use the correct
+    /// separator for the platform and normalize the result.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const fullPath = __dirname + "/foo.js";
+    /// const fullPath = `${__filename}/../foo.js`;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const fullPath = path.join(__dirname, "foo.js");
+    /// const fullPath = path.resolve(__filename, "../foo.js");
+    /// const fullPath = __dirname + ".js";
+    /// ```
+    NoPathConcat,
+    node,
+    suspicious
+);
+
+impl Rule for NoPathConcat {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+                if is_path_concat(binary, ctx) {
+                    ctx.diagnostic(no_path_concat_diagnostic(binary.span));
+                }
+            }
+            AstKind::TemplateLiteral(template) => {
+                if is_path_template(template, ctx) {
+                    ctx.diagnostic(no_path_concat_diagnostic(template.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_path_global(expr: &Expression, ctx: &LintContext) -> bool {
+    let Expression::Identifier(ident) = expr.without_parentheses() else {
+        return false;
+    };
+    ["__dirname", "__filename"]
+        .iter()
+        .any(|name| ident.is_global_reference_name(name, ctx.scoping()))
+}
+
+fn is_separator(c: char) -> bool {
+    c == '/' || c == '\\'
+}
+
+/// `__dirname + "/foo"` or `"foo/" + __dirname`
+fn is_path_concat(binary: &BinaryExpression, ctx: &LintContext) -> bool {
+    let string_edge = |expr: &Expression, start: bool| match expr.without_parentheses() {
+        Expression::StringLiteral(lit) => {
+            let value = lit.value.as_str();
+            if start { value.starts_with(is_separator) } else { value.ends_with(is_separator) }
+        }
+        Expression::TemplateLiteral(template) => {
+            let quasi = if start { template.quasis.first() } else { template.quasis.last() };
+            quasi.is_some_and(|quasi| {
+                let value = quasi.value.raw.as_str();
+                if start { value.starts_with(is_separator) } else { value.ends_with(is_separator) }
+            })
+        }
+        _ => false,
+    };
+    (is_path_global(&binary.left, ctx) && string_edge(&binary.right, true))
+        || (is_path_global(&binary.right, ctx) && string_edge(&binary.left, false))
+}
+
+/// `${__dirname}/foo`
+fn is_path_template(template: &TemplateLiteral, ctx: &LintContext) -> bool {
+    template.expressions.iter().enumerate().any(|(i, expr)| {
+        is_path_global(expr, ctx)
+            && (template.quasis[i].value.raw.ends_with(is_separator)
+                || template.quasis[i + 1].value.raw.starts_with(is_separator))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var fullPath = dirname + \"foo.js\";",
+        "var fullPath = __dirname == \"foo.js\";",
+        "if (fullPath === __dirname) {}",
+        "if (__dirname === fullPath) {}",
+        "var fullPath = \"/foo.js\" + __filename;",
+        "var fullPath = __filename + \".map\";",
+        "var fullPath = `${__filename}.map`;",
+        "var fullPath = __filename + (test ? \".js\" : \".ts\");",
+        "var fullPath = path.join(__dirname, \"foo.js\");",
+        "var __dirname = \"/\"; var fullPath = __dirname + \"/foo.js\";",
+        "function f(__dirname) { return `${__dirname}/foo.js` }",
+    ];
+
+    let fail = vec![
+        "var fullPath = __dirname + \"/foo.js\";",
+        "var fullPath = __filename + \"/foo.js\";",
+        "var fullPath = \"/foo.js/\" + __filename;",
+        "var fullPath = __dirname + \"\\\\foo.js\";",
+        "var fullPath = __dirname + `/foo.js`;",
+        "var fullPath = `${__dirname}/foo.js`;",
+        "var fullPath = `${__filename}/foo.js`;",
+        "var fullPath = `/foo.js/${__filename}`;",
+        "var fullPath = `${__dirname}\\\\foo.js`;",
+    ];
+
+    Tester::new(NoPathConcat::NAME, NoPathConcat::PLUGIN, pass, fail).test_and_snapshot();
+}
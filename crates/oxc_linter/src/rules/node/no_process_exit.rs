@@ -0,0 +1,94 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_process_exit_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Don't use `process.exit()`.")
+        .with_help("Throw an error or set `process.exitCode` instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoProcessExit;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the use of `process.exit()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `process.exit()` ends the process immediately, even if there are pending asynchronous
+    /// operations such as writes to `stdout` or open requests. When used in a server or a
+    /// library, it can stop the application without any chance of cleaning up or reporting
+    /// the error.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// if (mustExit) {
+    ///     process.exit(1);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// if (mustExit) {
+    ///     throw new Error("Something went wrong");
+    /// }
+    ///
+    /// process.exitCode = 1;
+    /// ```
+    NoProcessExit,
+    node,
+    restriction
+);
+
+impl Rule for NoProcessExit {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(member) = call.callee.without_parentheses().as_member_expression() else {
+            return;
+        };
+        let Expression::Identifier(object) = member.object().without_parentheses() else {
+            return;
+        };
+        if member.static_property_name() == Some("exit")
+            && object.is_global_reference_name("process", ctx.scoping())
+        {
+            ctx.diagnostic(no_process_exit_diagnostic(call.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "process.exitCode = 1",
+        "process.exit",
+        "var exit = process.exit",
+        "f(process.exit)",
+        "const process = { exit() {} }; process.exit(1)",
+        "function f(process) { process.exit(1) }",
+        "foo.exit(1)",
+    ];
+
+    let fail = vec![
+        "process.exit(0)",
+        "process.exit(1)",
+        "process['exit']()",
+        "process.exit?.(1)",
+        "function f() { if (error) { process.exit(1) } }",
+    ];
+
+    Tester::new(NoProcessExit::NAME, NoProcessExit::PLUGIN, pass, fail).test_and_snapshot();
+}
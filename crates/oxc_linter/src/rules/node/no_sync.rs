@@ -0,0 +1,162 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, MemberExpression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_sync_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected sync method: `{name}`."))
+        .with_help("Use the asynchronous version of this function instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSync(Box<NoSyncConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSyncConfig {
+    /// Allow synchronous calls outside of functions.
+    allow_at_root_level: bool,
+    /// Names of functions to allow.
+    ignores: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoSync {
+    type Target = NoSyncConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow synchronous methods, i.e. functions whose name ends with `Sync` such as
+    /// `fs.readFileSync()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Synchronous methods block the event loop until they finish. In a server, a single slow
+    /// file system call stops every other request from being handled in the meantime.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// fs.existsSync(somePath);
+    ///
+    /// function foo() {
+    ///     var contents = fs.readFileSync(somePath).toString();
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// obj.sync();
+    ///
+    /// async(function () {
+    ///     // ...
+    /// });
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-sync": ["error", { "allowAtRootLevel": false, "ignores": ["readFileSync"] }]
+    /// }
+    /// ```
+    ///
+    /// - `allowAtRootLevel`: allow synchronous methods outside of functions, e.g. while a
+    ///   module is being loaded.
+    /// - `ignores`: names of synchronous functions to allow.
+    NoSync,
+    node,
+    restriction
+);
+
+impl Rule for NoSync {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self(Box::new(NoSyncConfig {
+            allow_at_root_level: config
+                .and_then(|config| config.get("allowAtRootLevel"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+            ignores: config
+                .and_then(|config| config.get("ignores"))
+                .and_then(serde_json::Value::as_array)
+                .map(|ignores| {
+                    ignores
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactStr::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let callee = call.callee.without_parentheses();
+        let name = match callee {
+            Expression::Identifier(ident) => ident.name.as_str(),
+            _ => {
+                match callee.as_member_expression().and_then(MemberExpression::static_property_name)
+                {
+                    Some(name) => name,
+                    None => return,
+                }
+            }
+        };
+        if !name.ends_with("Sync") || self.ignores.iter().any(|ignore| ignore == name) {
+            return;
+        }
+        if self.allow_at_root_level
+            && !ctx.nodes().ancestors(node.id()).any(|ancestor| ancestor.kind().is_function_like())
+        {
+            return;
+        }
+        ctx.diagnostic(no_sync_diagnostic(callee.span(), name));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var foo = fs.foo.foo();", None),
+        ("var foo = fs.readFile();", None),
+        ("var foo = sync();", None),
+        ("var foo = fs.Sync;", None),
+        ("var foo = fs.readFileSync;", None),
+        ("var foo = fs.fooSync();", Some(serde_json::json!([{ "allowAtRootLevel": true }]))),
+        ("if (true) { fs.fooSync(); }", Some(serde_json::json!([{ "allowAtRootLevel": true }]))),
+        ("fs.readFileSync(path);", Some(serde_json::json!([{ "ignores": ["readFileSync"] }]))),
+    ];
+
+    let fail = vec![
+        ("var foo = fs.fooSync();", None),
+        ("var foo = fs['fooSync']();", None),
+        ("var foo = fooSync();", None),
+        ("function foo() { var bar = fs.fooSync(); }", None),
+        ("var a = function someFunction() { fs.fooSync(); };", None),
+        (
+            "var a = function someFunction() { fs.fooSync(); };",
+            Some(serde_json::json!([{ "allowAtRootLevel": true }])),
+        ),
+        ("const a = () => fs.fooSync();", Some(serde_json::json!([{ "allowAtRootLevel": true }]))),
+        ("fs.readFileSync(path);", Some(serde_json::json!([{ "ignores": ["fooSync"] }]))),
+    ];
+
+    Tester::new(NoSync::NAME, NoSync::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,181 @@
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        NodeAccessBase, NodeAccessKind, NodeVersion, UnsupportedFeaturesConfig,
+        collect_node_accesses, unsupported_feature_diagnostic,
+    },
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsupportedFeaturesEsBuiltins(Box<UnsupportedFeaturesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow ECMAScript built-in globals and static methods that are not available in the
+    /// Node.js versions the project supports.
+    ///
+    /// The supported versions are read from the `engines.node` field of the closest
+    /// `package.json`, or from the `version` option. Nothing is reported if neither is set.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Built-ins such as `Object.hasOwn()` or `Promise.any()` only exist in newer versions of V8.
+    /// On older Node.js versions they are `undefined`, and calling them throws a `TypeError`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `"engines": { "node": ">=14.0.0" }`:
+    /// ```js
+    /// Object.hasOwn(obj, "key");
+    /// const first = await Promise.any(promises);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `"engines": { "node": ">=14.0.0" }`:
+    /// ```js
+    /// Object.prototype.hasOwnProperty.call(obj, "key");
+    /// const results = await Promise.allSettled(promises);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-unsupported-features-es-builtins": ["error", {
+    ///     "version": ">=14.0.0",
+    ///     "ignores": ["Object.hasOwn"]
+    ///   }]
+    /// }
+    /// ```
+    NoUnsupportedFeaturesEsBuiltins,
+    node,
+    correctness
+);
+
+impl Rule for NoUnsupportedFeaturesEsBuiltins {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(UnsupportedFeaturesConfig::from_configuration(&value)))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(range) = self.0.version_range(ctx) else {
+            return;
+        };
+
+        for access in collect_node_accesses(ctx, &[], &ES_GLOBALS) {
+            if access.kind != NodeAccessKind::Read {
+                continue;
+            }
+            let Some((_, since)) =
+                ES_BUILTINS.iter().find(|(path, _)| access.is(NodeAccessBase::Global, path))
+            else {
+                continue;
+            };
+            let name = access.name();
+            if range.min < *since && !self.0.is_ignored(&name) {
+                ctx.diagnostic(unsupported_feature_diagnostic(
+                    access.span,
+                    &format!("`{name}`"),
+                    *since,
+                    &range,
+                ));
+            }
+        }
+    }
+}
+
+const ES_GLOBALS: [&str; 18] = [
+    "AggregateError",
+    "Array",
+    "Atomics",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "FinalizationRegistry",
+    "Intl",
+    "Iterator",
+    "Map",
+    "Math",
+    "Object",
+    "Promise",
+    "Reflect",
+    "SharedArrayBuffer",
+    "String",
+    "WeakRef",
+    "globalThis",
+];
+
+#[rustfmt::skip]
+const ES_BUILTINS: &[(&[&str], NodeVersion)] = &[
+    (&["AggregateError"], NodeVersion(15, 0, 0)),
+    (&["Array", "fromAsync"], NodeVersion(22, 0, 0)),
+    (&["Atomics"], NodeVersion(8, 10, 0)),
+    (&["Atomics", "waitAsync"], NodeVersion(16, 0, 0)),
+    (&["BigInt"], NodeVersion(10, 4, 0)),
+    (&["BigInt64Array"], NodeVersion(10, 4, 0)),
+    (&["BigUint64Array"], NodeVersion(10, 4, 0)),
+    (&["FinalizationRegistry"], NodeVersion(14, 6, 0)),
+    (&["Intl", "DisplayNames"], NodeVersion(14, 0, 0)),
+    (&["Intl", "ListFormat"], NodeVersion(12, 0, 0)),
+    (&["Intl", "Locale"], NodeVersion(12, 0, 0)),
+    (&["Intl", "RelativeTimeFormat"], NodeVersion(12, 0, 0)),
+    (&["Intl", "Segmenter"], NodeVersion(16, 0, 0)),
+    (&["Intl", "supportedValuesOf"], NodeVersion(18, 0, 0)),
+    (&["Iterator"], NodeVersion(22, 0, 0)),
+    (&["Map", "groupBy"], NodeVersion(21, 0, 0)),
+    (&["Math", "sumPrecise"], NodeVersion(24, 0, 0)),
+    (&["Object", "fromEntries"], NodeVersion(12, 0, 0)),
+    (&["Object", "groupBy"], NodeVersion(21, 0, 0)),
+    (&["Object", "hasOwn"], NodeVersion(16, 9, 0)),
+    (&["Promise", "allSettled"], NodeVersion(12, 9, 0)),
+    (&["Promise", "any"], NodeVersion(15, 0, 0)),
+    (&["Promise", "try"], NodeVersion(23, 0, 0)),
+    (&["Promise", "withResolvers"], NodeVersion(22, 0, 0)),
+    (&["SharedArrayBuffer"], NodeVersion(8, 10, 0)),
+    (&["String", "raw"], NodeVersion(4, 0, 0)),
+    (&["WeakRef"], NodeVersion(14, 6, 0)),
+    (&["globalThis"], NodeVersion(12, 0, 0)),
+];
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let version = |range: &str| Some(serde_json::json!([{ "version": range }]));
+
+    let pass = vec![
+        // no version range configured
+        ("Object.hasOwn(obj, 'key')", None),
+        ("Object.hasOwn(obj, 'key')", version(">=16.9.0")),
+        ("Promise.allSettled(promises)", version(">=12.9.0")),
+        ("Object.keys(obj)", version(">=8.0.0")),
+        ("const Promise = { any() {} }; Promise.any()", version(">=12.0.0")),
+        ("function f(Object) { Object.hasOwn(obj, 'key') }", version(">=12.0.0")),
+        (
+            "Object.hasOwn(obj, 'key')",
+            Some(serde_json::json!([{ "version": ">=14.0.0", "ignores": ["Object.hasOwn"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("Object.hasOwn(obj, 'key')", version(">=16.0.0")),
+        ("Promise.any(promises)", version(">=14.0.0")),
+        ("new AggregateError([])", version(">=14.0.0")),
+        ("new WeakRef(obj)", version(">=14.0.0")),
+        ("Object.groupBy(items, fn)", version("^18.0.0 || ^20.0.0 || >=22.0.0")),
+        ("const { withResolvers } = Promise", version(">=20.0.0")),
+        ("Array.fromAsync(iterable)", version(">=20.0.0")),
+        ("globalThis.foo = 1", version(">=10.0.0")),
+    ];
+
+    Tester::new(
+        NoUnsupportedFeaturesEsBuiltins::NAME,
+        NoUnsupportedFeaturesEsBuiltins::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
@@ -0,0 +1,280 @@
+use oxc_ast::{
+    AstKind,
+    ast::{ObjectPropertyKind, PropertyKey, RegExpFlags},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::LogicalOperator;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{NodeVersion, UnsupportedFeaturesConfig, unsupported_feature_diagnostic},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsupportedFeaturesEsSyntax(Box<UnsupportedFeaturesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow ECMAScript syntax that is not supported by the Node.js versions the project
+    /// supports.
+    ///
+    /// The supported versions are read from the `engines.node` field of the closest
+    /// `package.json`, or from the `version` option. Nothing is reported if neither is set.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unsupported syntax is a `SyntaxError` when the file is loaded, so the whole module
+    /// fails, not just the code path using the new syntax.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `"engines": { "node": ">=12.0.0" }`:
+    /// ```js
+    /// const name = user?.profile?.name;
+    /// const port = options.port ?? 8080;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `"engines": { "node": ">=12.0.0" }`:
+    /// ```js
+    /// const name = user && user.profile && user.profile.name;
+    /// const port = options.port != null ? options.port : 8080;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-unsupported-features-es-syntax": ["error", {
+    ///     "version": ">=12.0.0",
+    ///     "ignores": ["optional-chaining"]
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// Features that can be ignored: `async-functions`, `async-iteration`, `bigint`,
+    /// `class-fields`, `class-static-block`, `dynamic-import`, `logical-assignment-operators`,
+    /// `nullish-coalescing-operators`, `numeric-separators`, `object-rest-spread`,
+    /// `optional-catch-binding`, `optional-chaining`, `private-in`, `private-methods`,
+    /// `regexp-d-flag`, `regexp-s-flag`, `regexp-v-flag` and `top-level-await`.
+    NoUnsupportedFeaturesEsSyntax,
+    node,
+    correctness
+);
+
+impl Rule for NoUnsupportedFeaturesEsSyntax {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(UnsupportedFeaturesConfig::from_configuration(&value)))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(range) = self.0.version_range(ctx) else {
+            return;
+        };
+
+        for node in ctx.nodes().iter() {
+            syntax_features(node, ctx, &mut |feature, span| {
+                if range.min < feature.since && !self.0.is_ignored(feature.name) {
+                    ctx.diagnostic(unsupported_feature_diagnostic(
+                        span,
+                        feature.description,
+                        feature.since,
+                        &range,
+                    ));
+                }
+            });
+        }
+    }
+}
+
+struct SyntaxFeature {
+    /// Name used in the `ignores` option.
+    name: &'static str,
+    description: &'static str,
+    since: NodeVersion,
+}
+
+const fn feature(
+    name: &'static str,
+    description: &'static str,
+    since: NodeVersion,
+) -> SyntaxFeature {
+    SyntaxFeature { name, description, since }
+}
+
+const ASYNC_FUNCTIONS: SyntaxFeature =
+    feature("async-functions", "Async functions", NodeVersion(7, 6, 0));
+const ASYNC_ITERATION: SyntaxFeature =
+    feature("async-iteration", "Async iteration", NodeVersion(10, 0, 0));
+const BIGINT: SyntaxFeature = feature("bigint", "BigInt literals", NodeVersion(10, 4, 0));
+const CLASS_FIELDS: SyntaxFeature = feature("class-fields", "Class fields", NodeVersion(12, 0, 0));
+const CLASS_STATIC_BLOCK: SyntaxFeature =
+    feature("class-static-block", "Class static initialization blocks", NodeVersion(16, 11, 0));
+const DYNAMIC_IMPORT: SyntaxFeature =
+    feature("dynamic-import", "Dynamic `import()`", NodeVersion(13, 2, 0));
+const LOGICAL_ASSIGNMENT: SyntaxFeature =
+    feature("logical-assignment-operators", "Logical assignment operators", NodeVersion(15, 0, 0));
+const NULLISH_COALESCING: SyntaxFeature =
+    feature("nullish-coalescing-operators", "Nullish coalescing", NodeVersion(14, 0, 0));
+const NUMERIC_SEPARATORS: SyntaxFeature =
+    feature("numeric-separators", "Numeric separators", NodeVersion(12, 5, 0));
+const OBJECT_REST_SPREAD: SyntaxFeature =
+    feature("object-rest-spread", "Object rest/spread properties", NodeVersion(8, 3, 0));
+const OPTIONAL_CATCH_BINDING: SyntaxFeature =
+    feature("optional-catch-binding", "Optional catch binding", NodeVersion(10, 0, 0));
+const OPTIONAL_CHAINING: SyntaxFeature =
+    feature("optional-chaining", "Optional chaining", NodeVersion(14, 0, 0));
+const PRIVATE_IN: SyntaxFeature =
+    feature("private-in", "`#field in obj` checks", NodeVersion(16, 4, 0));
+const PRIVATE_METHODS: SyntaxFeature =
+    feature("private-methods", "Private methods", NodeVersion(14, 6, 0));
+const REGEXP_D_FLAG: SyntaxFeature =
+    feature("regexp-d-flag", "The RegExp `d` flag", NodeVersion(16, 0, 0));
+const REGEXP_S_FLAG: SyntaxFeature =
+    feature("regexp-s-flag", "The RegExp `s` flag", NodeVersion(8, 10, 0));
+const REGEXP_V_FLAG: SyntaxFeature =
+    feature("regexp-v-flag", "The RegExp `v` flag", NodeVersion(20, 0, 0));
+const TOP_LEVEL_AWAIT: SyntaxFeature =
+    feature("top-level-await", "Top-level `await`", NodeVersion(14, 8, 0));
+
+fn syntax_features(
+    node: &AstNode,
+    ctx: &LintContext,
+    report: &mut impl FnMut(&SyntaxFeature, Span),
+) {
+    match node.kind() {
+        AstKind::ChainExpression(chain) => report(&OPTIONAL_CHAINING, chain.span),
+        AstKind::LogicalExpression(logical) if logical.operator == LogicalOperator::Coalesce => {
+            report(&NULLISH_COALESCING, logical.span);
+        }
+        AstKind::AssignmentExpression(assign) if assign.operator.is_logical() => {
+            report(&LOGICAL_ASSIGNMENT, assign.span);
+        }
+        AstKind::NumericLiteral(lit) if lit.raw.is_some_and(|raw| raw.contains('_')) => {
+            report(&NUMERIC_SEPARATORS, lit.span);
+        }
+        AstKind::BigIntLiteral(lit) => {
+            report(&BIGINT, lit.span);
+            if lit.raw.is_some_and(|raw| raw.contains('_')) {
+                report(&NUMERIC_SEPARATORS, lit.span);
+            }
+        }
+        AstKind::PropertyDefinition(prop) => report(&CLASS_FIELDS, prop.span),
+        AstKind::MethodDefinition(method)
+            if matches!(method.key, PropertyKey::PrivateIdentifier(_)) =>
+        {
+            report(&PRIVATE_METHODS, method.span);
+        }
+        AstKind::StaticBlock(block) => report(&CLASS_STATIC_BLOCK, block.span),
+        AstKind::PrivateInExpression(expr) => report(&PRIVATE_IN, expr.span),
+        AstKind::ImportExpression(expr) => report(&DYNAMIC_IMPORT, expr.span),
+        AstKind::CatchClause(clause) if clause.param.is_none() => {
+            report(&OPTIONAL_CATCH_BINDING, clause.span);
+        }
+        AstKind::RegExpLiteral(lit) => {
+            for (flag, feature) in [
+                (RegExpFlags::D, &REGEXP_D_FLAG),
+                (RegExpFlags::S, &REGEXP_S_FLAG),
+                (RegExpFlags::V, &REGEXP_V_FLAG),
+            ] {
+                if lit.regex.flags.contains(flag) {
+                    report(feature, lit.span);
+                }
+            }
+        }
+        AstKind::Function(func) if func.r#async => {
+            report(if func.generator { &ASYNC_ITERATION } else { &ASYNC_FUNCTIONS }, func.span);
+        }
+        AstKind::ArrowFunctionExpression(arrow) if arrow.r#async => {
+            report(&ASYNC_FUNCTIONS, arrow.span);
+        }
+        AstKind::AwaitExpression(expr) if is_top_level(node, ctx) => {
+            report(&TOP_LEVEL_AWAIT, expr.span);
+        }
+        AstKind::ForOfStatement(stmt) if stmt.r#await => {
+            report(
+                if is_top_level(node, ctx) { &TOP_LEVEL_AWAIT } else { &ASYNC_ITERATION },
+                stmt.span,
+            );
+        }
+        AstKind::ObjectPattern(pattern) => {
+            if let Some(rest) = &pattern.rest {
+                report(&OBJECT_REST_SPREAD, rest.span);
+            }
+        }
+        AstKind::ObjectAssignmentTarget(target) => {
+            if let Some(rest) = &target.rest {
+                report(&OBJECT_REST_SPREAD, rest.span);
+            }
+        }
+        AstKind::ObjectExpression(object) => {
+            for property in &object.properties {
+                if let ObjectPropertyKind::SpreadProperty(spread) = property {
+                    report(&OBJECT_REST_SPREAD, spread.span());
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_top_level(node: &AstNode, ctx: &LintContext) -> bool {
+    !ctx.nodes().ancestors(node.id()).any(|ancestor| ancestor.kind().is_function_like())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let version = |range: &str| Some(serde_json::json!([{ "version": range }]));
+
+    let pass = vec![
+        // no version range configured
+        ("a?.b", None),
+        ("a?.b", version(">=14.0.0")),
+        ("a ?? b", version("^14.0.0 || >=16")),
+        ("a ||= b", version(">=15.0.0")),
+        ("1_000_000", version(">=12.5.0")),
+        ("class A { #x = 1; static y = 2 }", version(">=12.0.0")),
+        ("class A { static {} }", version(">=16.11.0")),
+        ("/a/dgs", version(">=16.0.0")),
+        ("try {} catch {}", version(">=10.0.0")),
+        ("async function f() { await x }", version(">=8.0.0")),
+        ("const { a, ...rest } = obj", version(">=8.3.0")),
+        (
+            "a?.b",
+            Some(serde_json::json!([{ "version": ">=12.0.0", "ignores": ["optional-chaining"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("a?.b", version(">=12.0.0")),
+        ("a?.()", version(">=12.0.0")),
+        ("a ?? b", version(">=12.0.0")),
+        ("a ||= b; a &&= b; a ??= b", version(">=14.0.0")),
+        ("1_000_000", version(">=12.0.0")),
+        ("10n", version(">=10.0.0")),
+        ("class A { x = 1 }", version(">=10.0.0")),
+        ("class A { #m() {} }", version(">=12.0.0")),
+        ("class A { static {} }", version(">=16.0.0")),
+        ("class A { #x; has(o) { return #x in o } }", version(">=16.0.0")),
+        ("import('./foo.js')", version(">=12.0.0")),
+        ("try {} catch {}", version(">=8.0.0")),
+        ("/a/d", version(">=14.0.0")),
+        ("/[a&&b]/v", version(">=18.0.0")),
+        ("await foo()", version(">=14.0.0")),
+        ("async function f() { for await (const x of xs) {} }", version(">=8.0.0")),
+        ("const { a, ...rest } = obj; const copy = { ...obj }", version(">=8.0.0")),
+    ];
+
+    Tester::new(
+        NoUnsupportedFeaturesEsSyntax::NAME,
+        NoUnsupportedFeaturesEsSyntax::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
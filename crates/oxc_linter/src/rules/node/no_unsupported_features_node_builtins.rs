@@ -0,0 +1,373 @@
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        NodeAccessBase, NodeAccessKind, NodeVersion, UnsupportedFeaturesConfig,
+        collect_node_accesses, unsupported_feature_diagnostic,
+    },
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsupportedFeaturesNodeBuiltins(Box<UnsupportedFeaturesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow Node.js built-in modules, module members and global variables that are not
+    /// available in the Node.js versions the project supports.
+    ///
+    /// The supported versions are read from the `engines.node` field of the closest
+    /// `package.json`, or from the `version` option. Nothing is reported if neither is set.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Using an API newer than the oldest supported Node.js version makes the code crash for
+    /// users on that version, usually with an unhelpful `TypeError` or `ERR_UNKNOWN_BUILTIN_MODULE`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `"engines": { "node": ">=14.0.0" }`:
+    /// ```js
+    /// const response = await fetch("https://example.com");
+    /// const fs = require("node:fs");
+    /// require("fs").rmSync("dist", { recursive: true });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `"engines": { "node": ">=14.0.0" }`:
+    /// ```js
+    /// const { readFile } = require("fs/promises");
+    /// const url = new URL("https://example.com");
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-unsupported-features-node-builtins": ["error", {
+    ///     "version": ">=16.0.0",
+    ///     "ignores": ["fetch", "fs.cp"]
+    ///   }]
+    /// }
+    /// ```
+    NoUnsupportedFeaturesNodeBuiltins,
+    node,
+    correctness
+);
+
+impl Rule for NoUnsupportedFeaturesNodeBuiltins {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(UnsupportedFeaturesConfig::from_configuration(&value)))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(range) = self.0.version_range(ctx) else {
+            return;
+        };
+
+        for access in collect_node_accesses(ctx, &BUILTIN_MODULES, &BUILTIN_GLOBALS) {
+            if access.kind != NodeAccessKind::Read {
+                continue;
+            }
+            if access.node_protocol && access.path.is_empty() && range.min < NODE_PROTOCOL {
+                let name = format!("`node:{}`", access.name());
+                ctx.diagnostic(unsupported_feature_diagnostic(
+                    access.span,
+                    &format!("The `node:` protocol of {name}"),
+                    NODE_PROTOCOL,
+                    &range,
+                ));
+                continue;
+            }
+            let Some(builtin) = BUILTINS.iter().find(|builtin| {
+                access.is(builtin.base, builtin.path)
+                    && (access.node_protocol || !builtin.node_protocol_only)
+            }) else {
+                continue;
+            };
+            let name = access.name();
+            if range.min < builtin.since && !self.0.is_ignored(&name) {
+                ctx.diagnostic(unsupported_feature_diagnostic(
+                    access.span,
+                    &format!("`{name}`"),
+                    builtin.since,
+                    &range,
+                ));
+            }
+        }
+    }
+}
+
+/// Version from which both `require` and `import` support the `node:` protocol.
+const NODE_PROTOCOL: NodeVersion = NodeVersion(14, 18, 0);
+
+struct Builtin {
+    base: NodeAccessBase<'static>,
+    path: &'static [&'static str],
+    since: NodeVersion,
+    /// Modules such as `node:test` can only be imported with the `node:` protocol.
+    node_protocol_only: bool,
+}
+
+const fn module(
+    module: &'static str,
+    path: &'static [&'static str],
+    since: NodeVersion,
+) -> Builtin {
+    Builtin { base: NodeAccessBase::Module(module), path, since, node_protocol_only: false }
+}
+
+const fn prefixed_module(module: &'static str, since: NodeVersion) -> Builtin {
+    Builtin { base: NodeAccessBase::Module(module), path: &[], since, node_protocol_only: true }
+}
+
+const fn global(path: &'static [&'static str], since: NodeVersion) -> Builtin {
+    Builtin { base: NodeAccessBase::Global, path, since, node_protocol_only: false }
+}
+
+const BUILTIN_MODULES: [&str; 58] = [
+    "assert",
+    "assert/strict",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "dns/promises",
+    "domain",
+    "events",
+    "fs",
+    "fs/promises",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "path/posix",
+    "path/win32",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "readline/promises",
+    "repl",
+    "sea",
+    "sqlite",
+    "stream",
+    "stream/consumers",
+    "stream/promises",
+    "stream/web",
+    "string_decoder",
+    "sys",
+    "test",
+    "timers",
+    "timers/promises",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "util/types",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+    "inspector/promises",
+    "test/reporters",
+];
+
+const BUILTIN_GLOBALS: [&str; 30] = [
+    "AbortController",
+    "AbortSignal",
+    "Blob",
+    "BroadcastChannel",
+    "CompressionStream",
+    "CustomEvent",
+    "DOMException",
+    "DecompressionStream",
+    "Event",
+    "EventTarget",
+    "File",
+    "FormData",
+    "Headers",
+    "MessageChannel",
+    "Navigator",
+    "ReadableStream",
+    "Request",
+    "Response",
+    "TextDecoder",
+    "TextEncoder",
+    "URL",
+    "URLSearchParams",
+    "WebSocket",
+    "WritableStream",
+    "crypto",
+    "fetch",
+    "navigator",
+    "performance",
+    "queueMicrotask",
+    "structuredClone",
+];
+
+#[rustfmt::skip]
+const BUILTINS: &[Builtin] = &[
+    module("assert/strict", &[], NodeVersion(15, 0, 0)),
+    module("assert", &["match"], NodeVersion(16, 0, 0)),
+    module("assert", &["doesNotMatch"], NodeVersion(16, 0, 0)),
+    module("async_hooks", &[], NodeVersion(8, 0, 0)),
+    module("async_hooks", &["AsyncLocalStorage"], NodeVersion(13, 10, 0)),
+    module("buffer", &["Blob"], NodeVersion(15, 7, 0)),
+    module("buffer", &["File"], NodeVersion(20, 0, 0)),
+    module("crypto", &["randomUUID"], NodeVersion(14, 17, 0)),
+    module("crypto", &["webcrypto"], NodeVersion(15, 0, 0)),
+    module("crypto", &["hash"], NodeVersion(21, 7, 0)),
+    module("diagnostics_channel", &[], NodeVersion(15, 1, 0)),
+    module("dns/promises", &[], NodeVersion(15, 0, 0)),
+    module("events", &["on"], NodeVersion(13, 6, 0)),
+    module("events", &["once"], NodeVersion(11, 13, 0)),
+    module("fs", &["cp"], NodeVersion(16, 7, 0)),
+    module("fs", &["cpSync"], NodeVersion(16, 7, 0)),
+    module("fs", &["opendir"], NodeVersion(12, 12, 0)),
+    module("fs", &["promises"], NodeVersion(10, 0, 0)),
+    module("fs", &["rm"], NodeVersion(14, 14, 0)),
+    module("fs", &["rmSync"], NodeVersion(14, 14, 0)),
+    module("fs", &["statfs"], NodeVersion(19, 6, 0)),
+    module("fs/promises", &[], NodeVersion(14, 0, 0)),
+    module("http2", &[], NodeVersion(8, 4, 0)),
+    module("inspector", &[], NodeVersion(8, 0, 0)),
+    module("module", &["createRequire"], NodeVersion(12, 2, 0)),
+    module("os", &["availableParallelism"], NodeVersion(18, 14, 0)),
+    module("path/posix", &[], NodeVersion(15, 3, 0)),
+    module("path/win32", &[], NodeVersion(15, 3, 0)),
+    module("perf_hooks", &[], NodeVersion(8, 5, 0)),
+    module("readline/promises", &[], NodeVersion(17, 0, 0)),
+    module("stream", &["Readable", "from"], NodeVersion(12, 3, 0)),
+    module("stream/consumers", &[], NodeVersion(16, 7, 0)),
+    module("stream/promises", &[], NodeVersion(15, 0, 0)),
+    module("stream/web", &[], NodeVersion(16, 5, 0)),
+    module("timers/promises", &[], NodeVersion(15, 0, 0)),
+    module("trace_events", &[], NodeVersion(10, 0, 0)),
+    module("util", &["parseArgs"], NodeVersion(18, 3, 0)),
+    module("util", &["stripVTControlCharacters"], NodeVersion(16, 11, 0)),
+    module("util", &["styleText"], NodeVersion(20, 12, 0)),
+    module("util", &["types"], NodeVersion(10, 0, 0)),
+    module("util/types", &[], NodeVersion(15, 3, 0)),
+    module("wasi", &[], NodeVersion(13, 3, 0)),
+    module("worker_threads", &[], NodeVersion(11, 7, 0)),
+    module("worker_threads", &["BroadcastChannel"], NodeVersion(15, 4, 0)),
+    prefixed_module("sea", NodeVersion(20, 12, 0)),
+    prefixed_module("sqlite", NodeVersion(22, 5, 0)),
+    prefixed_module("test", NodeVersion(18, 0, 0)),
+    prefixed_module("test/reporters", NodeVersion(19, 9, 0)),
+    module("inspector/promises", &[], NodeVersion(19, 0, 0)),
+    global(&["AbortController"], NodeVersion(15, 0, 0)),
+    global(&["AbortSignal"], NodeVersion(15, 0, 0)),
+    global(&["AbortSignal", "timeout"], NodeVersion(17, 3, 0)),
+    global(&["AbortSignal", "any"], NodeVersion(20, 3, 0)),
+    global(&["Blob"], NodeVersion(18, 0, 0)),
+    global(&["BroadcastChannel"], NodeVersion(18, 0, 0)),
+    global(&["CompressionStream"], NodeVersion(18, 0, 0)),
+    global(&["CustomEvent"], NodeVersion(19, 0, 0)),
+    global(&["DOMException"], NodeVersion(17, 0, 0)),
+    global(&["DecompressionStream"], NodeVersion(18, 0, 0)),
+    global(&["Event"], NodeVersion(15, 0, 0)),
+    global(&["EventTarget"], NodeVersion(15, 0, 0)),
+    global(&["File"], NodeVersion(20, 0, 0)),
+    global(&["FormData"], NodeVersion(18, 0, 0)),
+    global(&["Headers"], NodeVersion(18, 0, 0)),
+    global(&["MessageChannel"], NodeVersion(15, 0, 0)),
+    global(&["Navigator"], NodeVersion(21, 0, 0)),
+    global(&["ReadableStream"], NodeVersion(18, 0, 0)),
+    global(&["Request"], NodeVersion(18, 0, 0)),
+    global(&["Response"], NodeVersion(18, 0, 0)),
+    global(&["TextDecoder"], NodeVersion(11, 0, 0)),
+    global(&["TextEncoder"], NodeVersion(11, 0, 0)),
+    global(&["URL"], NodeVersion(10, 0, 0)),
+    global(&["URL", "canParse"], NodeVersion(19, 9, 0)),
+    global(&["URLSearchParams"], NodeVersion(10, 0, 0)),
+    global(&["WebSocket"], NodeVersion(22, 0, 0)),
+    global(&["WritableStream"], NodeVersion(18, 0, 0)),
+    global(&["crypto"], NodeVersion(19, 0, 0)),
+    global(&["fetch"], NodeVersion(18, 0, 0)),
+    global(&["navigator"], NodeVersion(21, 0, 0)),
+    global(&["performance"], NodeVersion(16, 0, 0)),
+    global(&["queueMicrotask"], NodeVersion(11, 0, 0)),
+    global(&["structuredClone"], NodeVersion(17, 0, 0)),
+];
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let version = |range: &str| Some(serde_json::json!([{ "version": range }]));
+
+    let pass = vec![
+        // no version range configured
+        ("fetch('https://example.com')", None),
+        ("fetch('https://example.com')", version(">=18.0.0")),
+        ("require('fs/promises')", version(">=14.0.0")),
+        ("import { rm } from 'fs'", version("^14.14.0 || >=16")),
+        ("require('node:fs')", version(">=16.0.0")),
+        ("import test from 'node:test'", version(">=18.0.0")),
+        ("import test from 'test'", version(">=16.0.0")),
+        ("const fetch = () => {}; fetch()", version(">=14.0.0")),
+        (
+            "structuredClone({})",
+            Some(serde_json::json!([{ "version": ">=14.0.0", "ignores": ["structuredClone"] }])),
+        ),
+        ("new URL('https://example.com')", version(">=12.0.0")),
+    ];
+
+    let fail = vec![
+        ("fetch('https://example.com')", version(">=16.0.0")),
+        ("const res = await fetch('https://example.com')", version("^16.0.0 || ^18.0.0")),
+        ("require('fs/promises')", version(">=12.0.0")),
+        ("require('fs').rmSync('dist')", version(">=14.0.0")),
+        ("const { cp } = require('fs')", version(">=16.0.0")),
+        ("import { cp } from 'fs'", version(">=16.0.0")),
+        ("require('node:fs')", version(">=14.0.0")),
+        ("import test from 'node:test'", version(">=16.0.0")),
+        ("new AbortController()", version(">=14.0.0")),
+        ("AbortSignal.timeout(1000)", version(">=16.0.0")),
+        ("structuredClone({})", version(">=16.0.0")),
+        ("const { parseArgs } = require('util')", version(">=16.0.0")),
+    ];
+
+    Tester::new(
+        NoUnsupportedFeaturesNodeBuiltins::NAME,
+        NoUnsupportedFeaturesNodeBuiltins::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
+
+#[test]
+fn test_engines() {
+    use crate::tester::Tester;
+
+    let pass = vec!["performance.now()", "require('node:fs')"];
+
+    let fail = vec!["fetch('https://example.com')", "structuredClone({})"];
+
+    Tester::new(
+        NoUnsupportedFeaturesNodeBuiltins::NAME,
+        NoUnsupportedFeaturesNodeBuiltins::PLUGIN,
+        pass,
+        fail,
+    )
+    .change_rule_path("../node/engines/index.js")
+    .with_snapshot_suffix("engines")
+    .test_and_snapshot();
+}
@@ -0,0 +1,78 @@
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{PreferGlobalMode, check_prefer_global},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferGlobalBuffer(PreferGlobalMode);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using the `Buffer` class consistently, either as the global variable `Buffer` or as
+    /// `require("buffer").Buffer`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `Buffer` is available both as a global variable and from the `buffer` module. Mixing the
+    /// two in a code base makes it harder to tell where the value comes from.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```js
+    /// const { Buffer } = require("buffer");
+    /// Buffer.alloc(10);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```js
+    /// Buffer.alloc(10);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `"always"` (default): require the global variable `Buffer`.
+    /// - `"never"`: require `require("buffer").Buffer`.
+    PreferGlobalBuffer,
+    node,
+    style
+);
+
+impl Rule for PreferGlobalBuffer {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(PreferGlobalMode::from_configuration(&value))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        check_prefer_global(ctx, self.0, "Buffer", "buffer", &["Buffer"]);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("Buffer.alloc(10)", None),
+        ("var buffer_ = Buffer", None),
+        (
+            "const { Buffer } = require(\"buffer\"); Buffer.alloc(10)",
+            Some(serde_json::json!(["never"])),
+        ),
+        ("const { Buffer } = require(\"buffer\");", Some(serde_json::json!(["never"]))),
+    ];
+
+    let fail = vec![
+        ("const { Buffer } = require(\"buffer\");", None),
+        ("const Buffer = require(\"buffer\").Buffer;", None),
+        ("import { Buffer } from \"node:buffer\";", None),
+        ("Buffer.alloc(10)", Some(serde_json::json!(["never"]))),
+    ];
+
+    Tester::new(PreferGlobalBuffer::NAME, PreferGlobalBuffer::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
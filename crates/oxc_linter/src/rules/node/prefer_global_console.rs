@@ -0,0 +1,78 @@
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{PreferGlobalMode, check_prefer_global},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferGlobalConsole(PreferGlobalMode);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using the `console` object consistently, either as the global variable `console` or as
+    /// `require("console")`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `console` is available both as a global variable and from the `console` module. Mixing the
+    /// two in a code base makes it harder to tell where the value comes from.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```js
+    /// const console = require("console");
+    /// console.log(10);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```js
+    /// console.log(10);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `"always"` (default): require the global variable `console`.
+    /// - `"never"`: require `require("console")`.
+    PreferGlobalConsole,
+    node,
+    style
+);
+
+impl Rule for PreferGlobalConsole {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(PreferGlobalMode::from_configuration(&value))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        check_prefer_global(ctx, self.0, "console", "console", &[]);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("console.log(10)", None),
+        ("var console_ = console", None),
+        (
+            "const console = require(\"console\"); console.log(10)",
+            Some(serde_json::json!(["never"])),
+        ),
+        ("const console = require(\"console\");", Some(serde_json::json!(["never"]))),
+    ];
+
+    let fail = vec![
+        ("const console = require(\"console\");", None),
+        ("import console from \"console\";", None),
+        ("import console from \"node:console\";", None),
+        ("console.log(10)", Some(serde_json::json!(["never"]))),
+    ];
+
+    Tester::new(PreferGlobalConsole::NAME, PreferGlobalConsole::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
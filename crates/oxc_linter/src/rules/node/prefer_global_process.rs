@@ -0,0 +1,78 @@
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{PreferGlobalMode, check_prefer_global},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferGlobalProcess(PreferGlobalMode);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using the `process` object consistently, either as the global variable `process` or as
+    /// `require("process")`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `process` is available both as a global variable and from the `process` module. Mixing the
+    /// two in a code base makes it harder to tell where the value comes from.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```js
+    /// const process = require("process");
+    /// process.exit();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```js
+    /// process.exit();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `"always"` (default): require the global variable `process`.
+    /// - `"never"`: require `require("process")`.
+    PreferGlobalProcess,
+    node,
+    style
+);
+
+impl Rule for PreferGlobalProcess {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(PreferGlobalMode::from_configuration(&value))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        check_prefer_global(ctx, self.0, "process", "process", &[]);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("process.exit()", None),
+        ("var process_ = process", None),
+        (
+            "const process = require(\"process\"); process.exit()",
+            Some(serde_json::json!(["never"])),
+        ),
+        ("const process = require(\"process\");", Some(serde_json::json!(["never"]))),
+    ];
+
+    let fail = vec![
+        ("const process = require(\"process\");", None),
+        ("import process from \"process\";", None),
+        ("import process from \"node:process\";", None),
+        ("process.exit()", Some(serde_json::json!(["never"]))),
+    ];
+
+    Tester::new(PreferGlobalProcess::NAME, PreferGlobalProcess::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
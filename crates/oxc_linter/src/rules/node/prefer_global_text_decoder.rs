@@ -0,0 +1,78 @@
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{PreferGlobalMode, check_prefer_global},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferGlobalTextDecoder(PreferGlobalMode);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using the `TextDecoder` class consistently, either as the global variable `TextDecoder` or as
+    /// `require("util").TextDecoder`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `TextDecoder` is available both as a global variable and from the `util` module. Mixing the
+    /// two in a code base makes it harder to tell where the value comes from.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```js
+    /// const { TextDecoder } = require("util");
+    /// new TextDecoder();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```js
+    /// new TextDecoder();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `"always"` (default): require the global variable `TextDecoder`.
+    /// - `"never"`: require `require("util").TextDecoder`.
+    PreferGlobalTextDecoder,
+    node,
+    style
+);
+
+impl Rule for PreferGlobalTextDecoder {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(PreferGlobalMode::from_configuration(&value))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        check_prefer_global(ctx, self.0, "TextDecoder", "util", &["TextDecoder"]);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("new TextDecoder()", None),
+        ("var textdecoder_ = TextDecoder", None),
+        (
+            "const { TextDecoder } = require(\"util\"); new TextDecoder()",
+            Some(serde_json::json!(["never"])),
+        ),
+        ("const { TextDecoder } = require(\"util\");", Some(serde_json::json!(["never"]))),
+    ];
+
+    let fail = vec![
+        ("const { TextDecoder } = require(\"util\");", None),
+        ("const TextDecoder = require(\"util\").TextDecoder;", None),
+        ("import { TextDecoder } from \"node:util\";", None),
+        ("new TextDecoder()", Some(serde_json::json!(["never"]))),
+    ];
+
+    Tester::new(PreferGlobalTextDecoder::NAME, PreferGlobalTextDecoder::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,78 @@
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{PreferGlobalMode, check_prefer_global},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferGlobalTextEncoder(PreferGlobalMode);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using the `TextEncoder` class consistently, either as the global variable `TextEncoder` or as
+    /// `require("util").TextEncoder`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `TextEncoder` is available both as a global variable and from the `util` module. Mixing the
+    /// two in a code base makes it harder to tell where the value comes from.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```js
+    /// const { TextEncoder } = require("util");
+    /// new TextEncoder();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```js
+    /// new TextEncoder();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `"always"` (default): require the global variable `TextEncoder`.
+    /// - `"never"`: require `require("util").TextEncoder`.
+    PreferGlobalTextEncoder,
+    node,
+    style
+);
+
+impl Rule for PreferGlobalTextEncoder {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(PreferGlobalMode::from_configuration(&value))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        check_prefer_global(ctx, self.0, "TextEncoder", "util", &["TextEncoder"]);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("new TextEncoder()", None),
+        ("var textencoder_ = TextEncoder", None),
+        (
+            "const { TextEncoder } = require(\"util\"); new TextEncoder()",
+            Some(serde_json::json!(["never"])),
+        ),
+        ("const { TextEncoder } = require(\"util\");", Some(serde_json::json!(["never"]))),
+    ];
+
+    let fail = vec![
+        ("const { TextEncoder } = require(\"util\");", None),
+        ("const TextEncoder = require(\"util\").TextEncoder;", None),
+        ("import { TextEncoder } from \"node:util\";", None),
+        ("new TextEncoder()", Some(serde_json::json!(["never"]))),
+    ];
+
+    Tester::new(PreferGlobalTextEncoder::NAME, PreferGlobalTextEncoder::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,77 @@
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{PreferGlobalMode, check_prefer_global},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferGlobalUrl(PreferGlobalMode);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using the `URL` class consistently, either as the global variable `URL` or as
+    /// `require("url").URL`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `URL` is available both as a global variable and from the `url` module. Mixing the
+    /// two in a code base makes it harder to tell where the value comes from.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```js
+    /// const { URL } = require("url");
+    /// new URL("https://example.com");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```js
+    /// new URL("https://example.com");
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `"always"` (default): require the global variable `URL`.
+    /// - `"never"`: require `require("url").URL`.
+    PreferGlobalUrl,
+    node,
+    style
+);
+
+impl Rule for PreferGlobalUrl {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(PreferGlobalMode::from_configuration(&value))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        check_prefer_global(ctx, self.0, "URL", "url", &["URL"]);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("new URL(\"https://example.com\")", None),
+        ("var url_ = URL", None),
+        (
+            "const { URL } = require(\"url\"); new URL(\"https://example.com\")",
+            Some(serde_json::json!(["never"])),
+        ),
+        ("const { URL } = require(\"url\");", Some(serde_json::json!(["never"]))),
+    ];
+
+    let fail = vec![
+        ("const { URL } = require(\"url\");", None),
+        ("const URL = require(\"url\").URL;", None),
+        ("import { URL } from \"node:url\";", None),
+        ("new URL(\"https://example.com\")", Some(serde_json::json!(["never"]))),
+    ];
+
+    Tester::new(PreferGlobalUrl::NAME, PreferGlobalUrl::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,78 @@
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{PreferGlobalMode, check_prefer_global},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferGlobalUrlSearchParams(PreferGlobalMode);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using the `URLSearchParams` class consistently, either as the global variable `URLSearchParams` or as
+    /// `require("url").URLSearchParams`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `URLSearchParams` is available both as a global variable and from the `url` module. Mixing the
+    /// two in a code base makes it harder to tell where the value comes from.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```js
+    /// const { URLSearchParams } = require("url");
+    /// new URLSearchParams("a=b");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```js
+    /// new URLSearchParams("a=b");
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `"always"` (default): require the global variable `URLSearchParams`.
+    /// - `"never"`: require `require("url").URLSearchParams`.
+    PreferGlobalUrlSearchParams,
+    node,
+    style
+);
+
+impl Rule for PreferGlobalUrlSearchParams {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(PreferGlobalMode::from_configuration(&value))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        check_prefer_global(ctx, self.0, "URLSearchParams", "url", &["URLSearchParams"]);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("new URLSearchParams(\"a=b\")", None),
+        ("var urlsearchparams_ = URLSearchParams", None),
+        (
+            "const { URLSearchParams } = require(\"url\"); new URLSearchParams(\"a=b\")",
+            Some(serde_json::json!(["never"])),
+        ),
+        ("const { URLSearchParams } = require(\"url\");", Some(serde_json::json!(["never"]))),
+    ];
+
+    let fail = vec![
+        ("const { URLSearchParams } = require(\"url\");", None),
+        ("const URLSearchParams = require(\"url\").URLSearchParams;", None),
+        ("import { URLSearchParams } from \"node:url\";", None),
+        ("new URLSearchParams(\"a=b\")", Some(serde_json::json!(["never"]))),
+    ];
+
+    Tester::new(PreferGlobalUrlSearchParams::NAME, PreferGlobalUrlSearchParams::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,106 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{NodeAccessBase, NodeAccessKind, collect_node_accesses},
+};
+
+fn prefer_promises_dns_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Prefer `dns.promises.{name}()` over the callback based `dns.{name}()`."
+    ))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferPromisesDns;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using `dns.promises` instead of the callback based API of the `dns` module.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The callback based `dns` API leads to deeply nested code and makes error handling easy to
+    /// forget. The promise based API of `dns/promises` works with `async`/`await` and
+    /// `try`/`catch`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const dns = require("dns");
+    /// dns.lookup("example.com", (err, address) => {});
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const dns = require("dns").promises;
+    /// const { address } = await dns.lookup("example.com");
+    ///
+    /// import { lookup } from "dns/promises";
+    /// const { address } = await lookup("example.com");
+    /// ```
+    PreferPromisesDns,
+    node,
+    style
+);
+
+impl Rule for PreferPromisesDns {
+    fn run_once(&self, ctx: &LintContext) {
+        for access in collect_node_accesses(ctx, &["dns"], &[]) {
+            if access.kind != NodeAccessKind::Read || access.base != NodeAccessBase::Module("dns") {
+                continue;
+            }
+            if let [name] = access.path[..] {
+                if DNS_CALLBACK_FUNCTIONS.contains(&name) {
+                    ctx.diagnostic(prefer_promises_dns_diagnostic(access.span, name));
+                }
+            }
+        }
+    }
+}
+
+const DNS_CALLBACK_FUNCTIONS: [&str; 16] = [
+    "lookup",
+    "lookupService",
+    "resolve",
+    "resolve4",
+    "resolve6",
+    "resolveAny",
+    "resolveCaa",
+    "resolveCname",
+    "resolveMx",
+    "resolveNaptr",
+    "resolveNs",
+    "resolvePtr",
+    "resolveSoa",
+    "resolveSrv",
+    "resolveTxt",
+    "reverse",
+];
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const dns = require(\"dns\"); dns.setServers([\"8.8.8.8\"])",
+        "const { promises } = require(\"dns\"); promises.lookup(\"example.com\")",
+        "const dns = require(\"dns\").promises; dns.lookup(\"example.com\")",
+        "import { lookup } from \"dns/promises\"; lookup(\"example.com\")",
+    ];
+
+    let fail = vec![
+        "const dns = require(\"dns\"); dns.lookup(\"example.com\", cb)",
+        "const { resolve4 } = require(\"dns\"); resolve4(\"example.com\", cb)",
+        "import { reverse } from \"node:dns\"",
+        "import dns from \"dns\"; dns.resolveTxt(\"example.com\", cb)",
+    ];
+
+    Tester::new(PreferPromisesDns::NAME, PreferPromisesDns::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,121 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{NodeAccessBase, NodeAccessKind, collect_node_accesses},
+};
+
+fn prefer_promises_fs_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Prefer `fs.promises.{name}()` over the callback based `fs.{name}()`."
+    ))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferPromisesFs;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using `fs.promises` instead of the callback based API of the `fs` module.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The callback based `fs` API leads to deeply nested code and makes error handling easy to
+    /// forget. The promise based API of `fs/promises` works with `async`/`await` and `try`/`catch`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const fs = require("fs");
+    /// fs.readFile("foo.txt", (err, data) => {});
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const fs = require("fs").promises;
+    /// const data = await fs.readFile("foo.txt");
+    ///
+    /// import { readFile } from "fs/promises";
+    /// const contents = await readFile("foo.txt");
+    /// ```
+    PreferPromisesFs,
+    node,
+    style
+);
+
+impl Rule for PreferPromisesFs {
+    fn run_once(&self, ctx: &LintContext) {
+        for access in collect_node_accesses(ctx, &["fs"], &[]) {
+            if access.kind != NodeAccessKind::Read || access.base != NodeAccessBase::Module("fs") {
+                continue;
+            }
+            if let [name] = access.path[..] {
+                if FS_CALLBACK_FUNCTIONS.contains(&name) {
+                    ctx.diagnostic(prefer_promises_fs_diagnostic(access.span, name));
+                }
+            }
+        }
+    }
+}
+
+const FS_CALLBACK_FUNCTIONS: [&str; 28] = [
+    "access",
+    "appendFile",
+    "chmod",
+    "chown",
+    "copyFile",
+    "cp",
+    "lchown",
+    "link",
+    "lstat",
+    "lutimes",
+    "mkdir",
+    "mkdtemp",
+    "open",
+    "opendir",
+    "readFile",
+    "readdir",
+    "readlink",
+    "realpath",
+    "rename",
+    "rm",
+    "rmdir",
+    "stat",
+    "statfs",
+    "symlink",
+    "truncate",
+    "unlink",
+    "utimes",
+    "writeFile",
+];
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const fs = require(\"fs\"); fs.createReadStream(\"foo\")",
+        "const fs = require(\"fs\"); fs.readFileSync(\"foo\")",
+        "const { promises } = require(\"fs\"); promises.readFile(\"foo\")",
+        "const fs = require(\"fs\").promises; fs.readFile(\"foo\")",
+        "import { readFile } from \"fs/promises\"; readFile(\"foo\")",
+        "import fs from \"node:fs/promises\"; fs.readFile(\"foo\")",
+        "function f(fs) { fs.readFile(\"foo\", cb) }",
+    ];
+
+    let fail = vec![
+        "const fs = require(\"fs\"); fs.readFile(\"foo\", cb)",
+        "const { readFile } = require(\"fs\"); readFile(\"foo\", cb)",
+        "require(\"node:fs\").stat(\"foo\", cb)",
+        "import { writeFile } from \"fs\"",
+        "import * as fs from \"fs\"; fs.mkdir(\"foo\", cb)",
+    ];
+
+    Tester::new(PreferPromisesFs::NAME, PreferPromisesFs::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,227 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{
+        CharacterClass, CharacterClassContents, CharacterClassContentsKind, CharacterKind, Pattern,
+    },
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::{ContentEq, GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{RegexCharSet, run_on_regex_node_with_flags},
+};
+
+fn duplicate_diagnostic(span: Span, element: &str, other_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected duplicate `{element}` in character class."))
+        .with_help("Remove the duplicate.")
+        .with_labels([span.label("duplicate"), other_span.label("already matched here")])
+}
+
+fn same_characters_diagnostic(
+    span: Span,
+    element: &str,
+    other: &str,
+    other_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{element}` matches the same characters as `{other}`."))
+        .with_help("Remove the duplicate.")
+        .with_labels([span.label("duplicate"), other_span.label("already matched here")])
+}
+
+fn subset_diagnostic(span: Span, element: &str, other: &str, other_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{element}` is already included in `{other}`."))
+        .with_help(format!("Remove `{element}`."))
+        .with_labels([span.label("redundant"), other_span.label("already matched here")])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDupeCharactersCharacterClass;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow elements of a character class that match characters which are already matched
+    /// by another element of the same class.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Duplicate characters and overlapping ranges or escapes in a character class are
+    /// redundant. They are usually a typo or a misunderstanding of what a range or an escape
+    /// like `\w` matches, e.g. `[\w_]` or `[A-z]`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /[aa]/;
+    /// var foo = /[\w_]/;
+    /// var foo = /[0-9\d]/;
+    /// var foo = /[a-zA-Z]/i;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /[a]/;
+    /// var foo = /[\w]/;
+    /// var foo = /[\d]/;
+    /// var foo = /[a-z]/i;
+    /// ```
+    NoDupeCharactersCharacterClass,
+    regexp,
+    correctness,
+    fix
+);
+
+impl Rule for NoDupeCharactersCharacterClass {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node_with_flags(node, ctx, |pattern, regex| {
+            for class in collect_character_classes(pattern) {
+                if class.kind != CharacterClassContentsKind::Union {
+                    continue;
+                }
+                let elements = &class.body;
+                let char_sets = elements
+                    .iter()
+                    .map(|element| RegexCharSet::from_class_contents(element, regex.flags))
+                    .collect::<Vec<_>>();
+
+                for (index, element) in elements.iter().enumerate() {
+                    if is_surrogate(element) {
+                        continue;
+                    }
+                    let Some(other) = find_covering_element(index, elements, &char_sets) else {
+                        continue;
+                    };
+                    let span = element.span();
+                    let other_span = elements[other].span();
+                    let name = element.to_string();
+                    let other_name = elements[other].to_string();
+                    let diagnostic = if char_sets[index] != char_sets[other] {
+                        subset_diagnostic(span, &name, &other_name, other_span)
+                    } else if name == other_name {
+                        duplicate_diagnostic(span, &name, other_span)
+                    } else {
+                        same_characters_diagnostic(span, &name, &other_name, other_span)
+                    };
+
+                    if regex.is_literal && can_remove(index, class) {
+                        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(span));
+                    } else {
+                        ctx.diagnostic(diagnostic);
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Find an element that matches every character `elements[index]` matches. Of two elements
+/// matching the same characters, the first one is kept.
+fn find_covering_element(
+    index: usize,
+    elements: &[CharacterClassContents],
+    char_sets: &[Option<RegexCharSet>],
+) -> Option<usize> {
+    let element = &elements[index];
+    let Some(char_set) = &char_sets[index] else {
+        // Characters of `\p{...}` are unknown, but identical escapes are still duplicates.
+        return elements[..index].iter().position(|other| other.content_eq(element));
+    };
+    elements.iter().zip(char_sets).enumerate().position(|(other_index, (other, other_set))| {
+        if other_index == index || is_surrogate(other) {
+            return false;
+        }
+        let Some(other_set) = other_set else {
+            return false;
+        };
+        char_set.is_subset_of(other_set) && (other_index < index || char_set != other_set)
+    })
+}
+
+/// Spans of lone surrogates that are half of a pair are not valid source positions.
+fn is_surrogate(element: &CharacterClassContents) -> bool {
+    matches!(element, CharacterClassContents::Character(ch)
+        if ch.kind == CharacterKind::Symbol && (0xD800..=0xDFFF).contains(&ch.value))
+}
+
+/// Removing an element next to a literal `-` could turn its neighbors into a range, and removing
+/// the first element before a `^` would negate the class.
+fn can_remove(index: usize, class: &CharacterClass) -> bool {
+    let is_symbol = |index: usize, symbol: char| {
+        matches!(class.body.get(index), Some(CharacterClassContents::Character(ch))
+            if ch.kind == CharacterKind::Symbol && ch.value == symbol as u32)
+    };
+    let next_to_dash = (index > 0 && is_symbol(index - 1, '-')) || is_symbol(index + 1, '-');
+    let before_caret = index == 0 && is_symbol(1, '^');
+    !next_to_dash && !before_caret
+}
+
+fn collect_character_classes<'a>(pattern: &Pattern<'a>) -> Vec<&'a CharacterClass<'a>> {
+    let mut collector = CharacterClassCollector { classes: vec![] };
+    collector.visit_pattern(pattern);
+    collector.classes
+}
+
+struct CharacterClassCollector<'a> {
+    classes: Vec<&'a CharacterClass<'a>>,
+}
+
+impl<'a> Visit<'a> for CharacterClassCollector<'a> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::CharacterClass(class) = kind {
+            self.classes.push(class);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var re = /[a-z]/",
+        "var re = /[abc]/",
+        "var re = /[\\d_a-f]/",
+        "var re = /[a-zA-Z]/",
+        "var re = /[a-c-e]/",
+        "var re = /[\\p{L}\\p{N}]/u",
+        "var re = /[a-cb-d]/",
+        "var re = /[😀😃]/u",
+        "var re = /[\\s\\S]/",
+    ];
+
+    let fail = vec![
+        "var re = /[aa]/",
+        "var re = /[\\w_]/",
+        "var re = /[0-9\\d]/",
+        "var re = /[a-zA-Z]/i",
+        "var re = /[a\\x61]/",
+        "var re = /[\\p{L}\\p{L}]/u",
+        "var re = /[^\\s\\r\\n]/",
+        "var re = /[.\\s.]/",
+        "var re = /[a-z-a]/",
+        "var re = /[\\q{abc}\\q{abc}]/v",
+        "var re = RegExp('[aa]')",
+    ];
+
+    let fix = vec![
+        ("var re = /[aa]/", "var re = /[a]/"),
+        ("var re = /[\\w_]/", "var re = /[\\w]/"),
+        ("var re = /[0-9\\dx]/", "var re = /[0-9x]/"),
+        ("var re = /[a-zA-Z]/i", "var re = /[a-z]/i"),
+        ("var re = /[^\\s\\r\\n]/", "var re = /[^\\s]/"),
+    ];
+
+    Tester::new(
+        NoDupeCharactersCharacterClass::NAME,
+        NoDupeCharactersCharacterClass::PLUGIN,
+        pass,
+        fail,
+    )
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
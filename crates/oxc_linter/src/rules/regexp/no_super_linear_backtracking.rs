@@ -0,0 +1,266 @@
+use oxc_ast::ast::RegExpFlags;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{Alternative, Disjunction, Quantifier, Term},
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::GetSpan;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        RegexCharSet, regex_term_can_be_empty, regex_term_is_always_empty,
+        run_on_regex_node_with_flags,
+    },
+};
+
+fn nested_quantifier_diagnostic(inner: &Quantifier, outer: &Quantifier) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The quantifier `{inner}` can reach itself via the loop `{outer}`, which can cause exponential backtracking."
+    ))
+    .with_help("Make sure that every iteration of the outer loop has to match something the inner loop cannot.")
+    .with_labels([inner.span.label("inner loop"), outer.span.label("outer loop")])
+}
+
+fn overlapping_alternatives_diagnostic(
+    first: &Term,
+    second: &Term,
+    quantifier: &Quantifier,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The alternatives `{first}` and `{second}` of `{quantifier}` can match the same characters, which can cause exponential backtracking."
+    ))
+    .with_help("Make the alternatives disjoint, e.g. by merging them into one character class.")
+    .with_labels([first.span().label("this alternative"), second.span().label("overlaps with this one")])
+}
+
+fn exchange_diagnostic(start: &Quantifier, end: &Quantifier) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The quantifier `{start}` can exchange characters with `{end}`, which can cause polynomial backtracking."
+    ))
+    .with_help("Make sure that the two quantifiers cannot match the same characters.")
+    .with_labels([start.span.label("this quantifier"), end.span.label("can exchange characters with this one")])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSuperLinearBacktracking;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow regular expressions whose matching time can grow exponentially or polynomially
+    /// with the length of the input, also known as ReDoS (Regular expression Denial of
+    /// Service).
+    ///
+    /// This rule reports:
+    /// - unbounded quantifiers nested in unbounded quantifiers, where the inner loop alone can
+    ///   make up an iteration of the outer loop, e.g. `(a+)+` or `(\w+\s?)*`.
+    /// - unbounded quantifiers of alternatives that can match the same character, e.g.
+    ///   `(?:\d|\w)+`.
+    /// - unbounded quantifiers that follow each other and can match the same characters, e.g.
+    ///   `\s*\s*` or `\d+\w*`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript regex engines backtrack. When a match fails, they retry every way the input
+    /// can be split between the ambiguous quantifiers. For an input of only a few dozen
+    /// characters, this can take seconds or years and blocks the event loop in the meantime.
+    /// If the input is controlled by users, this allows denial of service attacks.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /(a+)+b/;
+    /// var foo = /^(\w+\s?)*$/;
+    /// var foo = /(?:\d|\w)+x/;
+    /// var foo = /\s*\s*$/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /a+b/;
+    /// var foo = /^\w+(?:\s\w+)*$/;
+    /// var foo = /\w+x/;
+    /// var foo = /\s*$/;
+    /// ```
+    NoSuperLinearBacktracking,
+    regexp,
+    perf
+);
+
+impl Rule for NoSuperLinearBacktracking {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node_with_flags(node, ctx, |pattern, regex| {
+            let mut collector = Collector { quantifiers: vec![], alternatives: vec![] };
+            collector.visit_pattern(pattern);
+
+            for quantifier in collector.quantifiers {
+                if quantifier.max.is_some() {
+                    continue;
+                }
+                if let Some(inner) = find_self_reaching_loop(&quantifier.body) {
+                    ctx.diagnostic(nested_quantifier_diagnostic(inner, quantifier));
+                } else if let Some((first, second)) =
+                    find_overlapping_alternatives(&quantifier.body, regex.flags)
+                {
+                    ctx.diagnostic(overlapping_alternatives_diagnostic(first, second, quantifier));
+                }
+            }
+
+            for alternative in collector.alternatives {
+                check_adjacent_quantifiers(alternative, regex.flags, &mut |start, end| {
+                    ctx.diagnostic(exchange_diagnostic(start, end));
+                });
+            }
+        });
+    }
+}
+
+/// Find an unbounded quantifier in `term` that can match everything `term` matches in one
+/// iteration on its own, so that text can be split between iterations of the inner and the
+/// outer loop in exponentially many ways.
+fn find_self_reaching_loop<'t, 'a>(term: &'t Term<'a>) -> Option<&'t Quantifier<'a>> {
+    match term {
+        Term::Quantifier(quantifier) if quantifier.max.is_none() => {
+            (!regex_term_is_always_empty(&quantifier.body)).then_some(&**quantifier)
+        }
+        Term::Quantifier(quantifier) => find_self_reaching_loop(&quantifier.body),
+        Term::CapturingGroup(group) => find_self_reaching_loop_in(&group.body),
+        Term::IgnoreGroup(group) => find_self_reaching_loop_in(&group.body),
+        _ => None,
+    }
+}
+
+fn find_self_reaching_loop_in<'t, 'a>(
+    disjunction: &'t Disjunction<'a>,
+) -> Option<&'t Quantifier<'a>> {
+    disjunction.body.iter().find_map(|alternative| {
+        alternative.body.iter().enumerate().find_map(|(index, term)| {
+            let rest_can_be_empty = alternative
+                .body
+                .iter()
+                .enumerate()
+                .all(|(other, term)| other == index || regex_term_can_be_empty(term));
+            if rest_can_be_empty { find_self_reaching_loop(term) } else { None }
+        })
+    })
+}
+
+/// Find two single-character alternatives of a group that can match the same character.
+fn find_overlapping_alternatives<'t, 'a>(
+    term: &'t Term<'a>,
+    flags: RegExpFlags,
+) -> Option<(&'t Term<'a>, &'t Term<'a>)> {
+    let disjunction = match term {
+        Term::CapturingGroup(group) => &group.body,
+        Term::IgnoreGroup(group) => &group.body,
+        _ => return None,
+    };
+    let alternatives = disjunction
+        .body
+        .iter()
+        .filter_map(|alternative| match alternative.body.as_slice() {
+            [term] => Some((term, RegexCharSet::from_term(term, flags)?)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    alternatives.iter().enumerate().find_map(|(index, (first, first_set))| {
+        alternatives[index + 1..]
+            .iter()
+            .find(|(_, second_set)| first_set.intersects(second_set))
+            .map(|(second, _)| (*first, *second))
+    })
+}
+
+/// Report unbounded quantifiers of single characters that are only separated by terms that
+/// can match the empty string, and can both match the same character.
+fn check_adjacent_quantifiers<'a>(
+    alternative: &Alternative<'a>,
+    flags: RegExpFlags,
+    report: &mut impl FnMut(&Quantifier<'a>, &Quantifier<'a>),
+) {
+    fn unbounded_char_set<'t, 'a>(
+        term: &'t Term<'a>,
+        flags: RegExpFlags,
+    ) -> Option<(&'t Quantifier<'a>, RegexCharSet)> {
+        match term {
+            Term::Quantifier(quantifier) if quantifier.max.is_none() => {
+                RegexCharSet::from_term(&quantifier.body, flags).map(|set| (&**quantifier, set))
+            }
+            _ => None,
+        }
+    }
+    for (index, term) in alternative.body.iter().enumerate() {
+        let Some((start, start_set)) = unbounded_char_set(term, flags) else {
+            continue;
+        };
+        for next in &alternative.body[index + 1..] {
+            if let Some((end, end_set)) = unbounded_char_set(next, flags) {
+                if start_set.intersects(&end_set) {
+                    report(start, end);
+                    break;
+                }
+            }
+            if !regex_term_can_be_empty(next) {
+                break;
+            }
+        }
+    }
+}
+
+struct Collector<'a> {
+    quantifiers: Vec<&'a Quantifier<'a>>,
+    alternatives: Vec<&'a Alternative<'a>>,
+}
+
+impl<'a> Visit<'a> for Collector<'a> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        match kind {
+            RegExpAstKind::Quantifier(quantifier) => self.quantifiers.push(quantifier),
+            RegExpAstKind::Alternative(alternative) => self.alternatives.push(alternative),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "/regexp/",
+        "/a+b+/",
+        "/(ab+)+/",
+        "/(?:a+b)*/",
+        "/^\\w+(?:\\s\\w+)*$/",
+        "/(?:a|b)+/",
+        "/(?:\\d|[a-f])+/",
+        "/\\d+x\\d+/",
+        "/\\s*$/",
+        "/(?:\\b)*a/",
+        "/[a-z]+\\p{N}+/u",
+    ];
+
+    let fail = vec![
+        "/(a+)+b/",
+        "/(a*)*b/",
+        "/^(\\w+\\s?)*$/",
+        "/(?:(?:a+)?)+b/",
+        "/(?:\\d|\\w)+x/",
+        "/(a|a)*b/",
+        "/\\s*\\s*$/",
+        "/\\d+\\w*/",
+        "/a*b?a*c/",
+        "/\\d+\\s*\\w+/",
+        "/[a-z]+[A-Z]+/i",
+        "/.*\\w+=/",
+        "new RegExp('(x+x+)+y')",
+    ];
+
+    Tester::new(NoSuperLinearBacktracking::NAME, NoSuperLinearBacktracking::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
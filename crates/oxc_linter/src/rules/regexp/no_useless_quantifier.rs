@@ -0,0 +1,203 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{CharacterKind, Disjunction, Pattern, Quantifier, Term},
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{regex_term_has_captures, regex_term_is_always_empty, run_on_regex_node_with_flags},
+};
+
+fn const_one_diagnostic(span: Span, quantifier: &Quantifier) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unnecessary quantifier in `{quantifier}`."))
+        .with_help("Quantifiers of the form `{1}` and `{1,1}` have no effect.")
+        .with_label(span)
+}
+
+fn empty_diagnostic(span: Span, quantifier: &Quantifier) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unnecessary quantifier in `{quantifier}`."))
+        .with_help("The quantified element can only match the empty string.")
+        .with_label(span)
+}
+
+fn empty_question_mark_diagnostic(span: Span, quantifier: &Quantifier) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unnecessary quantifier in `{quantifier}`."))
+        .with_help("The quantified element can already match the empty string.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessQuantifier;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow quantifiers that do not change what a regular expression matches.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A quantifier like `{1}`, a quantifier on an element that only matches the empty
+    /// string, or a `?` on an element that can already match the empty string is just noise.
+    /// It is often a sign that the pattern does not do what its author intended.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /a{1}/;
+    /// var foo = /(?:a|b|)?/;
+    /// var foo = /(?:\b)+/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /a/;
+    /// var foo = /(?:a|b|)/;
+    /// var foo = /a?/;
+    /// ```
+    NoUselessQuantifier,
+    regexp,
+    correctness,
+    fix
+);
+
+impl Rule for NoUselessQuantifier {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node_with_flags(node, ctx, |pattern, regex| {
+            for quantifier in collect_quantifiers(pattern) {
+                let span = quantifier.span;
+                let body = &quantifier.body;
+                let (diagnostic, fixable) = if quantifier.min == 1 && quantifier.max == Some(1) {
+                    (const_one_diagnostic(span, quantifier), !is_followed_by_digit(quantifier, ctx))
+                } else if regex_term_is_always_empty(body)
+                    && (quantifier.min > 0 || matches_empty_unconditionally(body))
+                {
+                    // Without the quantifier, groups in the element always take part in the match.
+                    (empty_diagnostic(span, quantifier), !regex_term_has_captures(body))
+                } else if quantifier.min == 0
+                    && quantifier.max == Some(1)
+                    && quantifier.greedy
+                    && matches_empty_unconditionally(body)
+                    && !regex_term_has_captures(body)
+                {
+                    (empty_question_mark_diagnostic(span, quantifier), true)
+                } else {
+                    continue;
+                };
+
+                if !regex.is_literal || !fixable {
+                    ctx.diagnostic(diagnostic);
+                } else {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        fixer.replace(span, body.to_string())
+                    });
+                }
+            }
+        });
+    }
+}
+
+/// Removing the quantifier in `/\1{1}0/` or `/\0{1}1/` would change the escape.
+fn is_followed_by_digit(quantifier: &Quantifier, ctx: &LintContext) -> bool {
+    let ends_with_number = match &quantifier.body {
+        Term::IndexedReference(_) => true,
+        Term::Character(ch) => matches!(
+            ch.kind,
+            CharacterKind::Null
+                | CharacterKind::Octal1
+                | CharacterKind::Octal2
+                | CharacterKind::Octal3
+        ),
+        _ => false,
+    };
+    ends_with_number
+        && ctx.source_text()[quantifier.span.end as usize..]
+            .starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Whether `term` can match the empty string without depending on an assertion, so that making
+/// it optional or repeating it zero times does not let it match anywhere it could not before.
+fn matches_empty_unconditionally(term: &Term) -> bool {
+    let disjunction_matches_empty = |disjunction: &Disjunction| {
+        disjunction
+            .body
+            .iter()
+            .any(|alternative| alternative.body.iter().all(matches_empty_unconditionally))
+    };
+    match term {
+        Term::Quantifier(quantifier) => {
+            quantifier.min == 0 || matches_empty_unconditionally(&quantifier.body)
+        }
+        Term::CapturingGroup(group) => disjunction_matches_empty(&group.body),
+        Term::IgnoreGroup(group) => disjunction_matches_empty(&group.body),
+        _ => false,
+    }
+}
+
+fn collect_quantifiers<'a>(pattern: &Pattern<'a>) -> Vec<&'a Quantifier<'a>> {
+    let mut collector = QuantifierCollector { quantifiers: vec![] };
+    collector.visit_pattern(pattern);
+    collector.quantifiers
+}
+
+struct QuantifierCollector<'a> {
+    quantifiers: Vec<&'a Quantifier<'a>>,
+}
+
+impl<'a> Visit<'a> for QuantifierCollector<'a> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::Quantifier(quantifier) = kind {
+            self.quantifiers.push(quantifier);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "/a*/",
+        "/(?:a)?/",
+        "/(?:a|b?)??/",
+        "/(?:\\b|a)?/",
+        "/(?:\\b)*a/",
+        "/(?:a|)*/",
+        "/a{2}/",
+        "/a{0,1}?/",
+        "/(a|)?/",
+        "/(?=a)*/",
+        "new RegExp('a*')",
+    ];
+
+    let fail = vec![
+        "/a{1}/",
+        "/a{1,1}?/",
+        "/(?:a+b*|c*)?/",
+        "/(?:a|b?)?/",
+        "/(?:\\b)+/",
+        "/(?:)*/",
+        "/(?=a)+/",
+        "/(?:a{0})+/",
+        "/()*/",
+        "/\\1{1}0(a)/",
+        "new RegExp('a{1}')",
+    ];
+
+    let fix = vec![
+        ("/a{1}/", "/a/"),
+        ("/[a-z]{1,1}?/", "/[a-z]/"),
+        ("/(?:a+b*|c*)?/", "/(?:a+b*|c*)/"),
+        ("/(?:\\b)+/", "/(?:\\b)/"),
+        ("/(?=a)+/", "/(?=a)/"),
+    ];
+
+    Tester::new(NoUselessQuantifier::NAME, NoUselessQuantifier::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,266 @@
+use itertools::Itertools;
+
+use oxc_allocator::{Allocator, CloneIn};
+use oxc_ast::ast::RegExpFlags;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{Alternative, Pattern, Quantifier, Term},
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::{ContentEq, GetSpan, SPAN, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        RegexCharSet, regex_term_has_captures, regex_term_is_always_empty,
+        run_on_regex_node_with_flags,
+    },
+};
+
+fn combine_diagnostic(span: Span, left: &Term, right: &Term, combined: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{left}` and `{right}` can be combined into one quantifier `{combined}`."
+    ))
+    .with_help(format!("Replace them with `{combined}`."))
+    .with_label(span)
+}
+
+fn remove_diagnostic(span: Span, removed: &Term, kept: &Term) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{removed}` can be removed because it is already included by `{kept}`."
+    ))
+    .with_help(format!("Remove `{removed}`."))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OptimalQuantifierConcatenation;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require adjacent quantifiers to be written as a single quantifier when they repeat the
+    /// same element, and disallow quantifiers that are swallowed by an adjacent unbounded one.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `\d+\d*` matches the same strings as `\d+`, and `\w+\d*` the same as `\w+`. The longer
+    /// forms are harder to read, and since both quantifiers can match the same characters the
+    /// regex engine has to try every way of splitting the input between them before it can
+    /// fail, which can make matching much slower.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /\d+\d*/;
+    /// var foo = /aa*/;
+    /// var foo = /a{2}a{3}/;
+    /// var foo = /\w+\d*/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /\d+/;
+    /// var foo = /a+/;
+    /// var foo = /a{5}/;
+    /// var foo = /\w+/;
+    /// ```
+    OptimalQuantifierConcatenation,
+    regexp,
+    style,
+    fix
+);
+
+impl Rule for OptimalQuantifierConcatenation {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node_with_flags(node, ctx, |pattern, regex| {
+            for alternative in collect_alternatives(pattern) {
+                for (left, right) in alternative.body.iter().tuple_windows() {
+                    let Some(fix) = check_pair(left, right, regex.flags) else {
+                        continue;
+                    };
+                    let (diagnostic, span, replacement) = match fix {
+                        PairFix::Combine(combined) => {
+                            let span = Span::new(left.span().start, right.span().end);
+                            (combine_diagnostic(span, left, right, &combined), span, combined)
+                        }
+                        PairFix::Remove { removed, kept } => (
+                            remove_diagnostic(removed.span(), removed, kept),
+                            removed.span(),
+                            String::new(),
+                        ),
+                    };
+                    if regex.is_literal {
+                        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                            fixer.replace(span, replacement)
+                        });
+                    } else {
+                        ctx.diagnostic(diagnostic);
+                    }
+                }
+            }
+        });
+    }
+}
+
+enum PairFix<'t, 'a> {
+    /// Replace both terms with this quantifier.
+    Combine(String),
+    /// Remove `removed`, which only matches what `kept` would match anyway.
+    Remove { removed: &'t Term<'a>, kept: &'t Term<'a> },
+}
+
+/// A term as an element with a repetition count. Terms that are not quantified repeat once.
+struct Repetition<'t, 'a> {
+    element: &'t Term<'a>,
+    min: u64,
+    max: Option<u64>,
+    greedy: bool,
+    is_quantifier: bool,
+}
+
+impl<'t, 'a> Repetition<'t, 'a> {
+    fn new(term: &'t Term<'a>) -> Option<Self> {
+        let repetition = match term {
+            Term::Quantifier(quantifier) => Self {
+                element: &quantifier.body,
+                min: quantifier.min,
+                max: quantifier.max,
+                greedy: quantifier.greedy,
+                is_quantifier: true,
+            },
+            Term::BoundaryAssertion(_) | Term::LookAroundAssertion(_) => return None,
+            _ => Self { element: term, min: 1, max: Some(1), greedy: true, is_quantifier: false },
+        };
+        // The text matched by captures would change, and empty elements are another rule's job.
+        (!regex_term_has_captures(repetition.element)
+            && !regex_term_is_always_empty(repetition.element))
+        .then_some(repetition)
+    }
+
+    fn is_fixed(&self) -> bool {
+        self.max == Some(self.min)
+    }
+}
+
+fn check_pair<'t, 'a>(
+    left_term: &'t Term<'a>,
+    right_term: &'t Term<'a>,
+    flags: RegExpFlags,
+) -> Option<PairFix<'t, 'a>> {
+    let left = Repetition::new(left_term)?;
+    let right = Repetition::new(right_term)?;
+    if !left.is_quantifier && !right.is_quantifier {
+        return None;
+    }
+
+    if left.element.content_eq(right.element) {
+        // `a+?a*` prefers a different number of `a`s than any single quantifier.
+        if !left.is_fixed() && !right.is_fixed() && left.greedy != right.greedy {
+            return None;
+        }
+        let greedy = if left.is_fixed() { right.greedy } else { left.greedy };
+        let min = left.min + right.min;
+        let max = left.max.zip(right.max).map(|(left, right)| left + right);
+        return Some(PairFix::Combine(print_repetition(left.element, min, max, greedy)));
+    }
+
+    let left_set = RegexCharSet::from_term(left.element, flags)?;
+    let right_set = RegexCharSet::from_term(right.element, flags)?;
+    // An optional element next to a greedy unbounded quantifier of a superset never matches
+    // anything: the unbounded quantifier takes every character it could match.
+    if left.max.is_none() && left.greedy && right.min == 0 && right_set.is_subset_of(&left_set) {
+        return Some(PairFix::Remove { removed: right_term, kept: left_term });
+    }
+    if right.max.is_none() && right.greedy && left.min == 0 && left_set.is_subset_of(&right_set) {
+        return Some(PairFix::Remove { removed: left_term, kept: right_term });
+    }
+    None
+}
+
+fn print_repetition(element: &Term, min: u64, max: Option<u64>, greedy: bool) -> String {
+    if min == 1 && max == Some(1) {
+        return element.to_string();
+    }
+    let allocator = Allocator::default();
+    let body = element.clone_in(&allocator);
+    // `{2}?` is the same as `{2}`.
+    let greedy = greedy || max == Some(min);
+    Quantifier { span: SPAN, min, max, greedy, body }.to_string()
+}
+
+fn collect_alternatives<'a>(pattern: &Pattern<'a>) -> Vec<&'a Alternative<'a>> {
+    let mut collector = AlternativeCollector { alternatives: vec![] };
+    collector.visit_pattern(pattern);
+    collector.alternatives
+}
+
+struct AlternativeCollector<'a> {
+    alternatives: Vec<&'a Alternative<'a>>,
+}
+
+impl<'a> Visit<'a> for AlternativeCollector<'a> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::Alternative(alternative) = kind {
+            self.alternatives.push(alternative);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "/\\w+\\d+/",
+        "/\\w+a/",
+        "/\\d*\\w/",
+        "/a+b+/",
+        "/aa/",
+        "/a+?a*/",
+        "/(a)a*/",
+        "/\\w+?\\d*/",
+        "/(?:ab)+c/",
+        "/\\d+\\b\\d*/",
+        "/[a-z]+\\p{L}*/u",
+    ];
+
+    let fail = vec![
+        "/\\d+\\d*/",
+        "/aa*/",
+        "/a*a/",
+        "/a{2}a{3}/",
+        "/a+?a{2}/",
+        "/(?:ab)(?:ab)+/",
+        "/\\w+\\d*/",
+        "/\\d?\\w+/",
+        "/[a-z]+[A-Z]*/i",
+        "/.*\\w?/s",
+        "new RegExp('a+a*')",
+    ];
+
+    let fix = vec![
+        ("/\\d+\\d*/", "/\\d+/"),
+        ("/aa*/", "/a+/"),
+        ("/a*a/", "/a+/"),
+        ("/xa{2}a{3}y/", "/xa{5}y/"),
+        ("/a+?a{2}/", "/a{3,}?/"),
+        ("/a{1,2}a{0,3}/", "/a{1,5}/"),
+        ("/(?:ab)(?:ab)+/", "/(?:ab){2,}/"),
+        ("/\\w+\\d*/", "/\\w+/"),
+        ("/\\d?\\w+$/", "/\\w+$/"),
+    ];
+
+    Tester::new(
+        OptimalQuantifierConcatenation::NAME,
+        OptimalQuantifierConcatenation::PLUGIN,
+        pass,
+        fail,
+    )
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
@@ -0,0 +1,224 @@
+use oxc_ast::ast::RegExpFlags;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{
+        Character, CharacterClassContents, CharacterClassContentsKind, CharacterKind, Disjunction,
+        Pattern, Term,
+    },
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::run_on_regex_node_with_flags};
+
+fn prefer_character_class_diagnostic(span: Span, class: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected alternatives of single characters.")
+        .with_help(format!("Use a character class instead: `{class}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct PreferCharacterClass {
+    /// The minimum number of single-character alternatives to report.
+    min_alternatives: usize,
+}
+
+impl Default for PreferCharacterClass {
+    fn default() -> Self {
+        Self { min_alternatives: 3 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using character classes instead of alternatives that each match a single
+    /// character.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `(?:a|b|c)` and `[abc]` match the same characters, but the character class is shorter,
+    /// and regex engines match it much faster since they do not have to try every alternative
+    /// in turn and backtrack between them.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /a|b|c/;
+    /// var foo = /(a|b|c)/;
+    /// var foo = /(?:\d|x|[a-f])+/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /[abc]/;
+    /// var foo = /([abc])/;
+    /// var foo = /[\dxa-f]+/;
+    /// var foo = /a|b|cd/;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "regexp/prefer-character-class": ["error", { "minAlternatives": 3 }]
+    /// }
+    /// ```
+    ///
+    /// - `minAlternatives`: the minimum number of alternatives before they are reported.
+    ///   Defaults to `3`.
+    PreferCharacterClass,
+    regexp,
+    style,
+    fix
+);
+
+impl Rule for PreferCharacterClass {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let min_alternatives = value
+            .get(0)
+            .and_then(|config| config.get("minAlternatives"))
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|min| usize::try_from(min).ok())
+            .unwrap_or(3);
+        Self { min_alternatives: min_alternatives.max(2) }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node_with_flags(node, ctx, |pattern, regex| {
+            for disjunction in collect_disjunctions(pattern) {
+                if disjunction.body.len() < self.min_alternatives {
+                    continue;
+                }
+                let Some(class) = merge_into_character_class(disjunction, regex.flags) else {
+                    continue;
+                };
+                let diagnostic = prefer_character_class_diagnostic(disjunction.span, &class);
+                if regex.is_literal {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        fixer.replace(disjunction.span, class)
+                    });
+                } else {
+                    ctx.diagnostic(diagnostic);
+                }
+            }
+        });
+    }
+}
+
+/// Print a character class matching the same characters as `disjunction`, if every
+/// alternative matches exactly one character.
+fn merge_into_character_class(disjunction: &Disjunction, flags: RegExpFlags) -> Option<String> {
+    let unicode_sets = flags.contains(RegExpFlags::V);
+    let mut class = String::from("[");
+    for alternative in &disjunction.body {
+        let [term] = alternative.body.as_slice() else {
+            return None;
+        };
+        match term {
+            Term::Character(ch) => push_class_character(&mut class, ch, unicode_sets),
+            Term::CharacterClassEscape(escape) => class.push_str(&escape.to_string()),
+            Term::UnicodePropertyEscape(escape) if !escape.strings => {
+                class.push_str(&escape.to_string());
+            }
+            Term::CharacterClass(inner)
+                if !inner.negative
+                    && !inner.strings
+                    && inner.kind == CharacterClassContentsKind::Union =>
+            {
+                for contents in &inner.body {
+                    match contents {
+                        CharacterClassContents::Character(ch) => {
+                            push_class_character(&mut class, ch, unicode_sets);
+                        }
+                        CharacterClassContents::CharacterClassRange(range) => {
+                            push_class_character(&mut class, &range.min, unicode_sets);
+                            class.push('-');
+                            push_class_character(&mut class, &range.max, unicode_sets);
+                        }
+                        _ => class.push_str(&contents.to_string()),
+                    }
+                }
+            }
+            _ => return None,
+        }
+    }
+    class.push(']');
+    Some(class)
+}
+
+/// Push `ch` into a character class, escaping characters that can appear unescaped outside of
+/// a character class but have a meaning inside one.
+fn push_class_character(class: &mut String, ch: &Character, unicode_sets: bool) {
+    let needs_escape = ch.kind == CharacterKind::Symbol
+        && char::from_u32(ch.value).is_some_and(|c| {
+            if unicode_sets { "&!#%,:;<=>@`~-^".contains(c) } else { "-]^".contains(c) }
+        });
+    if needs_escape {
+        class.push('\\');
+    }
+    class.push_str(&ch.to_string());
+}
+
+fn collect_disjunctions<'a>(pattern: &Pattern<'a>) -> Vec<&'a Disjunction<'a>> {
+    let mut collector = DisjunctionCollector { disjunctions: vec![] };
+    collector.visit_pattern(pattern);
+    collector.disjunctions
+}
+
+struct DisjunctionCollector<'a> {
+    disjunctions: Vec<&'a Disjunction<'a>>,
+}
+
+impl<'a> Visit<'a> for DisjunctionCollector<'a> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::Disjunction(disjunction) = kind {
+            self.disjunctions.push(disjunction);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("/regexp/", None),
+        ("/a|b/", None),
+        ("/a|b|cd/", None),
+        ("/(?:a|b|c)d/", Some(serde_json::json!([{ "minAlternatives": 4 }]))),
+        ("/a|b|[^c]/", None),
+        ("/a|b|\\b/", None),
+        ("/a|b|./", None),
+        ("/[abc]/", None),
+        ("/(a)|b|c/", None),
+    ];
+
+    let fail = vec![
+        ("/a|b|c/", None),
+        ("/(a|b|c)/", None),
+        ("/(?:a|b|c)+/", None),
+        ("/(?=a|b|c)/", None),
+        ("/(?:\\d|x|[a-f])/", None),
+        ("/(?:-|a|])/", None),
+        ("/a|b/", Some(serde_json::json!([{ "minAlternatives": 2 }]))),
+        ("/(?:\\p{L}|_|\\d)/u", None),
+        ("/(?:&|!|a)/v", None),
+        ("new RegExp('a|b|c')", None),
+    ];
+
+    let fix = vec![
+        ("/a|b|c/", "/[abc]/"),
+        ("/(a|b|c)/", "/([abc])/"),
+        ("/(?:\\d|x|[a-f])+/", "/(?:[\\dxa-f])+/"),
+        ("/(?:-|a|]|[x-])/", "/(?:[\\-a\\]x\\-])/"),
+        ("/(?:\\p{L}|_|\\d)/u", "/(?:[\\p{L}_\\d])/u"),
+        ("/(?:&|!|a)/v", "/(?:[\\&\\!a])/v"),
+    ];
+
+    Tester::new(PreferCharacterClass::NAME, PreferCharacterClass::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
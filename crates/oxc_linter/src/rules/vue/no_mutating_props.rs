@@ -0,0 +1,516 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrayExpressionElement, AssignmentExpression, CallExpression, Declaration,
+        ExportDefaultDeclarationKind, Expression, IdentifierReference, MemberExpression,
+        ObjectPropertyKind, Program, SimpleAssignmentTarget, Statement, TSSignature, TSType,
+        UpdateExpression,
+    },
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    template::vue::{VueDirective, VueDirectiveValue, VueElement, VueNode},
+};
+
+fn no_mutating_props_diagnostic(prop: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected mutation of \"{prop}\" prop."))
+        .with_help("Props are read-only. Emit an event to ask the parent component to change the value instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMutatingProps;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows mutating component props, both in the `<template>` and
+    /// through the object returned by `defineProps` in `<script setup>`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Props flow one way, from the parent to the child. Mutations are
+    /// overwritten whenever the parent re-renders, and mutating nested
+    /// objects silently changes the state of the parent.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <input v-model="todo.text" />
+    ///   <button @click="count++">Add</button>
+    /// </template>
+    /// <script setup>
+    /// const props = defineProps(['todo', 'count'])
+    /// props.todo.done = true
+    /// </script>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <input :value="todo.text" @input="emit('update:text', $event.target.value)" />
+    ///   <button @click="emit('increment')">Add</button>
+    /// </template>
+    /// <script setup>
+    /// const props = defineProps(['todo', 'count'])
+    /// const emit = defineEmits(['update:text', 'increment'])
+    /// </script>
+    /// ```
+    NoMutatingProps,
+    vue,
+    correctness
+);
+
+/// Props declared by the component.
+#[derive(Default)]
+struct Props<'a> {
+    names: Vec<String>,
+    /// Variable holding the result of `defineProps`, e.g. `props` in `const props = defineProps()`.
+    variable: Option<(&'a str, SymbolId)>,
+}
+
+impl Props<'_> {
+    fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|prop| prop == name)
+    }
+}
+
+impl Rule for NoMutatingProps {
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.vue_template().is_some()
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let props = collect_props(ctx);
+        if props.names.is_empty() && props.variable.is_none() {
+            return;
+        }
+
+        // Mutations through the `defineProps` result in `<script setup>`.
+        if let Some((_, symbol_id)) = props.variable {
+            for node in ctx.nodes() {
+                let target = match node.kind() {
+                    AstKind::AssignmentExpression(assignment) => {
+                        assignment.left.as_simple_assignment_target()
+                    }
+                    AstKind::UpdateExpression(update) => Some(&update.argument),
+                    _ => None,
+                };
+                let Some((ident, Some(prop))) = target.and_then(target_root) else { continue };
+                let reference = ctx.scoping().get_reference(ident.reference_id());
+                if reference.symbol_id() == Some(symbol_id) {
+                    ctx.diagnostic(no_mutating_props_diagnostic(prop, node.kind().span()));
+                }
+            }
+        }
+
+        if let Some(template) = ctx.vue_template() {
+            let mut checker = TemplateChecker { props: &props, shadowed: vec![], ctx };
+            checker.check_nodes(&template.children);
+        }
+    }
+}
+
+/// Collect props declared with `defineProps` or the `props` option.
+fn collect_props<'a>(ctx: &LintContext<'a>) -> Props<'a> {
+    let mut props = Props::default();
+    let Some(program) = ctx.nodes().program() else { return props };
+
+    for node in ctx.nodes() {
+        match node.kind() {
+            AstKind::CallExpression(call) if is_define_props(call) => {
+                add_define_props_names(call, program, &mut props.names);
+            }
+            AstKind::VariableDeclarator(declarator) => {
+                let Some(init) = &declarator.init else { continue };
+                let Expression::CallExpression(call) = init.get_inner_expression() else {
+                    continue;
+                };
+                let define_props = if call.callee_name() == Some("withDefaults") {
+                    call.arguments.first().and_then(|arg| arg.as_expression())
+                } else {
+                    Some(init)
+                };
+                let is_define_props = define_props.is_some_and(|expr| {
+                    matches!(expr.get_inner_expression(), Expression::CallExpression(call) if is_define_props(call))
+                });
+                if let (true, Some(ident)) =
+                    (is_define_props, declarator.id.get_binding_identifier())
+                {
+                    props.variable = Some((ident.name.as_str(), ident.symbol_id()));
+                }
+            }
+            AstKind::ExportDefaultDeclaration(export) => {
+                let options = match &export.declaration {
+                    ExportDefaultDeclarationKind::ObjectExpression(object) => Some(&**object),
+                    // `defineComponent({ ... })`
+                    ExportDefaultDeclarationKind::CallExpression(call) => {
+                        match call.arguments.first().and_then(|arg| arg.as_expression()) {
+                            Some(Expression::ObjectExpression(object)) => Some(&**object),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                let props_option = options.and_then(|options| {
+                    options.properties.iter().find_map(|property| match property {
+                        ObjectPropertyKind::ObjectProperty(property)
+                            if property.key.is_specific_static_name("props") =>
+                        {
+                            Some(&property.value)
+                        }
+                        _ => None,
+                    })
+                });
+                if let Some(value) = props_option {
+                    add_runtime_prop_names(value, &mut props.names);
+                }
+            }
+            _ => {}
+        }
+    }
+    props
+}
+
+fn is_define_props(call: &CallExpression) -> bool {
+    matches!(&call.callee, Expression::Identifier(ident) if ident.name == "defineProps")
+}
+
+fn add_define_props_names(call: &CallExpression, program: &Program, names: &mut Vec<String>) {
+    if let Some(arg) = call.arguments.first().and_then(|arg| arg.as_expression()) {
+        add_runtime_prop_names(arg, names);
+    }
+    if let Some(ty) = call.type_arguments.as_ref().and_then(|args| args.params.first()) {
+        add_type_prop_names(ty, program, names);
+    }
+}
+
+/// `['foo', 'bar']` or `{ foo: String, bar: { type: Number } }`
+fn add_runtime_prop_names(expr: &Expression, names: &mut Vec<String>) {
+    match expr.get_inner_expression() {
+        Expression::ArrayExpression(array) => {
+            names.extend(array.elements.iter().filter_map(|element| match element {
+                ArrayExpressionElement::StringLiteral(lit) => Some(lit.value.to_string()),
+                _ => None,
+            }));
+        }
+        Expression::ObjectExpression(object) => {
+            names.extend(object.properties.iter().filter_map(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    property.key.static_name().map(|name| name.to_string())
+                }
+                ObjectPropertyKind::SpreadProperty(_) => None,
+            }));
+        }
+        _ => {}
+    }
+}
+
+/// `defineProps<{ foo: string }>()` or `defineProps<Props>()` with `Props`
+/// declared in the same file.
+fn add_type_prop_names(ty: &TSType, program: &Program, names: &mut Vec<String>) {
+    let add_members = |members: &[TSSignature], names: &mut Vec<String>| {
+        names.extend(members.iter().filter_map(|member| match member {
+            TSSignature::TSPropertySignature(property) => {
+                property.key.static_name().map(|name| name.to_string())
+            }
+            _ => None,
+        }));
+    };
+
+    match ty {
+        TSType::TSTypeLiteral(literal) => add_members(&literal.members, names),
+        TSType::TSTypeReference(reference) => {
+            let Some(name) = reference.type_name.get_identifier_reference().map(|ident| ident.name)
+            else {
+                return;
+            };
+            for statement in &program.body {
+                let declaration = match statement {
+                    Statement::ExportNamedDeclaration(export) => export.declaration.as_ref(),
+                    _ => statement.as_declaration(),
+                };
+                match declaration {
+                    Some(Declaration::TSInterfaceDeclaration(interface))
+                        if interface.id.name == name =>
+                    {
+                        add_members(&interface.body.body, names);
+                    }
+                    Some(Declaration::TSTypeAliasDeclaration(alias)) if alias.id.name == name => {
+                        add_type_prop_names(&alias.type_annotation, program, names);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The identifier at the root of an assignment target, along with the first
+/// property accessed on it, e.g. `foo` and `bar` for `foo.bar.baz`.
+fn target_root<'a, 'b>(
+    target: &'b SimpleAssignmentTarget<'a>,
+) -> Option<(&'b IdentifierReference<'a>, Option<&'a str>)> {
+    match target {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => Some((ident, None)),
+        _ => member_root(target.as_member_expression()?),
+    }
+}
+
+fn member_root<'a, 'b>(
+    member: &'b MemberExpression<'a>,
+) -> Option<(&'b IdentifierReference<'a>, Option<&'a str>)> {
+    let mut property = member.static_property_name();
+    let mut object = member.object();
+    loop {
+        match object.get_inner_expression() {
+            Expression::Identifier(ident) => return Some((ident, property)),
+            expr => {
+                let member = expr.as_member_expression()?;
+                property = member.static_property_name();
+                object = member.object();
+            }
+        }
+    }
+}
+
+fn expression_root<'a, 'b>(
+    expr: &'b Expression<'a>,
+) -> Option<(&'b IdentifierReference<'a>, Option<&'a str>)> {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => Some((ident, None)),
+        expr => member_root(expr.as_member_expression()?),
+    }
+}
+
+struct TemplateChecker<'p, 'a, 'c> {
+    props: &'p Props<'a>,
+    /// `v-for` aliases and slot props in scope.
+    shadowed: Vec<&'a str>,
+    ctx: &'c LintContext<'a>,
+}
+
+impl<'a> TemplateChecker<'_, 'a, '_> {
+    fn check_nodes(&mut self, nodes: &[VueNode<'a>]) {
+        for node in nodes {
+            match node {
+                VueNode::Element(element) => self.check_element(element),
+                VueNode::Interpolation(interpolation) => {
+                    if let Some(expression) = &interpolation.expression {
+                        self.visit_expression(expression);
+                    }
+                }
+                VueNode::Text(_) | VueNode::Comment(_) => {}
+            }
+        }
+    }
+
+    fn check_element(&mut self, element: &VueElement<'a>) {
+        if let Some(VueDirectiveValue::For(for_expression)) =
+            element.directive("for").and_then(|directive| directive.value.as_ref())
+        {
+            self.visit_expression(&for_expression.right);
+        }
+
+        let shadowed_len = self.shadowed.len();
+        self.shadowed.extend(element.scope_variables().into_iter().map(|(name, _)| name));
+        for directive in element.directives() {
+            match &directive.value {
+                Some(VueDirectiveValue::For(_)) | None => {}
+                Some(VueDirectiveValue::Expression(expression)) if directive.name == "model" => {
+                    self.check_v_model(directive, expression);
+                }
+                Some(VueDirectiveValue::Expression(expression)) => {
+                    self.visit_expression(expression);
+                }
+                Some(VueDirectiveValue::Statements(statements)) => {
+                    self.visit_statements(statements);
+                }
+                Some(VueDirectiveValue::Params(params)) => self.visit_formal_parameters(params),
+            }
+        }
+        self.check_nodes(&element.children);
+        self.shadowed.truncate(shadowed_len);
+    }
+
+    fn check_v_model(&self, directive: &VueDirective, expression: &Expression<'a>) {
+        if let Some((ident, property)) = expression_root(expression) {
+            if let Some(prop) = self.mutated_prop(ident.name.as_str(), property) {
+                self.ctx.diagnostic(no_mutating_props_diagnostic(prop, directive.span));
+            }
+        }
+    }
+
+    /// Returns the name of the prop mutated through `root.property`.
+    fn mutated_prop(&self, root: &'a str, property: Option<&'a str>) -> Option<&'a str> {
+        if self.shadowed.contains(&root) {
+            return None;
+        }
+        let is_props_object =
+            root == "$props" || self.props.variable.is_some_and(|(variable, _)| variable == root);
+        if is_props_object {
+            property
+        } else if self.props.contains(root) {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    fn check_target(&self, target: Option<&SimpleAssignmentTarget<'a>>, span: Span) {
+        let Some((ident, property)) = target.and_then(target_root) else { return };
+        if let Some(prop) = self.mutated_prop(ident.name.as_str(), property) {
+            self.ctx.diagnostic(no_mutating_props_diagnostic(prop, span));
+        }
+    }
+}
+
+impl<'a> Visit<'a> for TemplateChecker<'_, 'a, '_> {
+    fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+        self.check_target(it.left.as_simple_assignment_target(), it.span);
+        walk::walk_assignment_expression(self, it);
+    }
+
+    fn visit_update_expression(&mut self, it: &UpdateExpression<'a>) {
+        self.check_target(Some(&it.argument), it.span);
+        walk::walk_update_expression(self, it);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"
+<template><div>{{ count }}</div></template>
+<script setup>
+const props = defineProps(['count'])
+</script>
+",
+        r#"
+<template><input v-model="text" @click="count = 1"></template>
+<script setup>
+import { ref } from 'vue'
+defineProps(['value'])
+const text = ref('')
+const count = ref(0)
+</script>
+"#,
+        r#"
+<template><div v-for="value in list" @click="value = 1"></div></template>
+<script setup>
+defineProps(['value', 'list'])
+</script>
+"#,
+        r#"
+<template><my-list><template #default="{ value }"><input v-model="value.text"></template></my-list></template>
+<script setup>
+defineProps({ value: Object })
+</script>
+"#,
+        r#"
+<template><input :value="todo.text" @input="emit('update', $event.target.value)"></template>
+<script setup lang="ts">
+defineProps<{ todo: Todo }>()
+const emit = defineEmits(['update'])
+</script>
+"#,
+        r"
+<template><div></div></template>
+<script setup>
+const props = defineProps(['count'])
+const local = { count: 0 }
+local.count = props.count
+</script>
+",
+        r"
+<template><div @click='count++'></div></template>
+<script>
+export default {
+  data() { return { count: 0 } },
+}
+</script>
+",
+    ];
+
+    let fail = vec![
+        r#"
+<template><input v-model="value"></template>
+<script setup>
+defineProps(['value'])
+</script>
+"#,
+        r#"
+<template><input v-model="todo.text"></template>
+<script setup>
+defineProps({ todo: Object })
+</script>
+"#,
+        r#"
+<template><button @click="count++">{{ count }}</button></template>
+<script setup lang="ts">
+defineProps<{ count: number }>()
+</script>
+"#,
+        r#"
+<template><button @click="props.count = 0; emit('reset')"></button></template>
+<script setup lang="ts">
+interface Props { count: number }
+const props = withDefaults(defineProps<Props>(), { count: 0 })
+const emit = defineEmits(['reset'])
+</script>
+"#,
+        r#"
+<template><button @click="$props.todo.done = true"></button></template>
+<script setup>
+defineProps(['todo'])
+</script>
+"#,
+        r#"
+<template><div v-for="item in list" @click="todo = item"></div></template>
+<script setup>
+defineProps(['todo', 'list'])
+</script>
+"#,
+        r"
+<template><div></div></template>
+<script setup>
+const props = defineProps(['todo', 'count'])
+props.todo.done = true
+props.count++
+</script>
+",
+        r#"
+<template><input v-model="value"></template>
+<script>
+export default {
+  props: ['value'],
+}
+</script>
+"#,
+        r#"
+<template><input v-model="value"></template>
+<script>
+import { defineComponent } from 'vue'
+export default defineComponent({
+  props: { value: String },
+})
+</script>
+"#,
+    ];
+
+    Tester::new(NoMutatingProps::NAME, NoMutatingProps::PLUGIN, pass, fail)
+        .change_rule_path_extension("vue")
+        .test_and_snapshot();
+}
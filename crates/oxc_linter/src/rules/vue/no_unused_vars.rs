@@ -0,0 +1,116 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_unused_vars_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is defined but never used."))
+        .with_help(format!("Remove '{name}', or prefix it with an underscore to ignore it."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedVars;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows unused variable definitions of `v-for` directives and slot
+    /// props (`v-slot` or `slot-scope`).
+    ///
+    /// Variables whose name starts with `_` are ignored.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unused variables are usually leftovers of refactoring and make the
+    /// template harder to read.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <ol v-for="i in 5">
+    ///     <li>item</li>
+    ///   </ol>
+    /// </template>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <ol v-for="i in 5">
+    ///     <li>{{ i }}</li>
+    ///   </ol>
+    /// </template>
+    /// ```
+    NoUnusedVars,
+    vue,
+    correctness
+);
+
+impl Rule for NoUnusedVars {
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.vue_template().is_some()
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(template) = ctx.vue_template() else { return };
+        template.for_each_element(|element, _| {
+            let variables = element.scope_variables();
+            if variables.is_empty() {
+                return;
+            }
+            let references = element.scope_references();
+            for (name, span) in variables {
+                if !name.starts_with('_')
+                    && !references.iter().any(|(reference, _)| *reference == name)
+                {
+                    ctx.diagnostic(no_unused_vars_diagnostic(name, span));
+                }
+            }
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"<template><ol v-for="i in 5"><li>{{ i }}</li></ol></template>"#,
+        r#"<template><ol v-for="i in 5"><li :prop="i"></li></ol></template>"#,
+        r#"<template><ol v-for="i in 5" :key="i"></ol></template>"#,
+        r#"<template><div v-for="(item, index) in items" :key="index">{{ item }}</div></template>"#,
+        r#"<template><div v-for="({ id, name }) in items" :key="id">{{ name }}</div></template>"#,
+        r#"<template><div v-for="(_item, index) in items" :key="index"></div></template>"#,
+        r#"<template><div v-for="x in list"><span v-for="y in x" :key="y">{{ y }}</span></div></template>"#,
+        r#"<template><div v-for="x in list" @click="select(x)"></div></template>"#,
+        r#"<template><div v-for="x in list" @click="selected = x; emit('change')"></div></template>"#,
+        r#"<template><my-list><template #default="{ item }">{{ item.name }}</template></my-list></template>"#,
+        r#"<template><my-list v-slot="props">{{ props.item }}</my-list></template>"#,
+        r#"<template><div slot-scope="props">{{ props }}</div></template>"#,
+        r#"<template><div v-for="x in list"><p v-if="x.visible"></p></div></template>"#,
+    ];
+
+    let fail = vec![
+        r#"<template><ol v-for="i in 5"><li>item</li></ol></template>"#,
+        r#"<template><div v-for="(item, index) in items">{{ item }}</div></template>"#,
+        r#"<template><div v-for="({ id, name }) in items" :key="id"></div></template>"#,
+        // shadowed by the inner alias
+        r#"<template><div v-for="x in list"><span v-for="x in other">{{ x }}</span></div></template>"#,
+        // the iterated value is outside of the scope of the aliases
+        r#"<template><div v-for="x in x"></div></template>"#,
+        r#"<template><my-list><template #default="{ item }">text</template></my-list></template>"#,
+        r#"<template><my-list v-slot="props"></my-list></template>"#,
+        r#"<template><div slot-scope="props"></div></template>"#,
+    ];
+
+    Tester::new(NoUnusedVars::NAME, NoUnusedVars::PLUGIN, pass, fail)
+        .change_rule_path_extension("vue")
+        .test_and_snapshot();
+}
@@ -0,0 +1,108 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn filtered_list_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The '{name}' variable inside 'v-for' directive should be replaced with a computed property that returns filtered array instead. You should not mix 'v-for' with 'v-if'."
+    ))
+    .with_label(span)
+}
+
+fn move_to_wrapper_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This 'v-if' should be moved to the wrapper element.").with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUseVIfWithVFor;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows using `v-if` on the same element as `v-for`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `v-if` has a higher priority than `v-for` in Vue 3, so the condition
+    /// cannot use the iteration variables. In Vue 2 the priority is reversed,
+    /// and the condition is evaluated for every item on every render.
+    ///
+    /// Filter the list with a computed property instead, or move the `v-if`
+    /// to a wrapper element if it does not depend on the item.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <li v-for="user in users" v-if="user.isActive" :key="user.id">{{ user.name }}</li>
+    ///   <li v-for="user in users" v-if="shouldShowUsers" :key="user.id">{{ user.name }}</li>
+    /// </template>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <li v-for="user in activeUsers" :key="user.id">{{ user.name }}</li>
+    ///   <ul v-if="shouldShowUsers">
+    ///     <li v-for="user in users" :key="user.id">{{ user.name }}</li>
+    ///   </ul>
+    /// </template>
+    /// ```
+    NoUseVIfWithVFor,
+    vue,
+    correctness
+);
+
+impl Rule for NoUseVIfWithVFor {
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.vue_template().is_some()
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(template) = ctx.vue_template() else { return };
+        template.for_each_element(|element, _| {
+            let (Some(_), Some(v_if)) = (element.directive("for"), element.directive("if")) else {
+                return;
+            };
+            let aliases = element.scope_variables();
+            let alias_reference = v_if
+                .references()
+                .into_iter()
+                .find(|(name, _)| aliases.iter().any(|(alias, _)| alias == name));
+            match alias_reference {
+                Some((name, span)) => ctx.diagnostic(filtered_list_diagnostic(name, span)),
+                None => ctx.diagnostic(move_to_wrapper_diagnostic(v_if.span)),
+            }
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"<template><ul v-if="shown"><li v-for="x in list" :key="x.id">{{ x }}</li></ul></template>"#,
+        r#"<template><li v-for="x in activeList" :key="x.id">{{ x }}</li></template>"#,
+        r#"<template><template v-for="x in list"><li v-if="x.active" :key="x.id">{{ x }}</li></template></template>"#,
+        r#"<template><div v-if="shown"></div><div v-for="x in list" :key="x"></div></template>"#,
+    ];
+
+    let fail = vec![
+        r#"<template><li v-for="x in list" v-if="x.active" :key="x.id">{{ x }}</li></template>"#,
+        r#"<template><li v-if="x.active" v-for="x in list" :key="x.id">{{ x }}</li></template>"#,
+        r#"<template><li v-for="(x, i) in list" v-if="i > 2" :key="x.id">{{ x }}</li></template>"#,
+        r#"<template><li v-for="{ active } in list" v-if="active">{{ x }}</li></template>"#,
+        r#"<template><li v-for="x in list" v-if="shown" :key="x.id">{{ x }}</li></template>"#,
+    ];
+
+    Tester::new(NoUseVIfWithVFor::NAME, NoUseVIfWithVFor::PLUGIN, pass, fail)
+        .change_rule_path_extension("vue")
+        .test_and_snapshot();
+}
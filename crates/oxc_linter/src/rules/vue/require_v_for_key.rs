@@ -0,0 +1,118 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    template::vue::{VueElement, VueNode},
+};
+
+fn require_v_for_key_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Elements in iteration expect to have 'v-bind:key' directives.")
+        .with_help("Add a unique `:key` to the element, e.g. `:key=\"item.id\"`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireVForKey;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires `v-bind:key` on elements rendered with `v-for`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Without a key, Vue patches elements in place when the list changes,
+    /// which breaks the state of elements and child components that depend on
+    /// their position, e.g. focused inputs or running transitions.
+    ///
+    /// Custom components are checked by `vue/valid-v-for` instead.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <div v-for="todo in todos" />
+    /// </template>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <div v-for="todo in todos" :key="todo.id" />
+    /// </template>
+    /// ```
+    RequireVForKey,
+    vue,
+    correctness
+);
+
+impl Rule for RequireVForKey {
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.vue_template().is_some()
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(template) = ctx.vue_template() else { return };
+        template.for_each_element(|element, _| {
+            if element.directive("for").is_some() {
+                check_key(element, ctx);
+            }
+        });
+    }
+}
+
+fn check_key(element: &VueElement, ctx: &LintContext) {
+    if element.bind_directive("key").is_some() {
+        return;
+    }
+    // `<template>` and `<slot>` are not rendered, so the key belongs on their children.
+    if element.is_template() || element.name == "slot" {
+        for child in &element.children {
+            if let VueNode::Element(child) = child {
+                check_key(child, ctx);
+            }
+        }
+    } else if !element.is_custom_component() {
+        ctx.diagnostic(require_v_for_key_diagnostic(element.name_span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"<template><div v-for="x in list" :key="x"></div></template>"#,
+        r#"<template><div v-for="x in list" v-bind:key="x.id"></div></template>"#,
+        r#"<template><div><div v-for="x in list" :key="x.id"></div></div></template>"#,
+        r#"<template><template v-for="x in list"><div :key="x"></div></template></template>"#,
+        r#"<template><template v-for="x in list" :key="x"><div></div></template></template>"#,
+        r#"<template><slot v-for="x in list"><div :key="x"></div></slot></template>"#,
+        r#"<template><custom-component v-for="x in list"></custom-component></template>"#,
+        r#"<template><MyComponent v-for="x in list" /></template>"#,
+        r#"<template><div :is="component" v-for="x in list"></div></template>"#,
+        r#"<template><Foo v-for="x in list"></Foo></template>"#,
+        r"<script>const div = 1</script>",
+    ];
+
+    let fail = vec![
+        r#"<template><div v-for="x in list"></div></template>"#,
+        r#"<template><div><div v-for="x in list"></div></div></template>"#,
+        r#"<template><div v-for="x in list" :[key]="x"></div></template>"#,
+        r#"<template><template v-for="x in list"><div></div></template></template>"#,
+        r#"<template><slot v-for="x in list"><div></div></slot></template>"#,
+        r#"<template>
+  <ul>
+    <li v-for="(todo, index) in todos">{{ index }}: {{ todo.text }}</li>
+  </ul>
+</template>"#,
+    ];
+
+    Tester::new(RequireVForKey::NAME, RequireVForKey::PLUGIN, pass, fail)
+        .change_rule_path_extension("vue")
+        .test_and_snapshot();
+}
@@ -0,0 +1,227 @@
+use oxc_ast::ast::BindingPatternKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    template::vue::{VueDirective, VueDirectiveValue, VueElement, VueNode},
+};
+
+fn unexpected_argument_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("'v-for' directives require no argument.").with_label(span)
+}
+
+fn unexpected_modifier_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("'v-for' directives require no modifier.").with_label(span)
+}
+
+fn missing_value_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("'v-for' directives require that attribute value.").with_label(span)
+}
+
+fn invalid_syntax_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("'v-for' directives require the special syntax '<alias> in <expression>'.")
+        .with_label(span)
+}
+
+fn invalid_alias_diagnostic(alias: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid alias '{alias}'."))
+        .with_help("The key and index aliases of 'v-for' must be identifiers.")
+        .with_label(span)
+}
+
+fn missing_key_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Custom elements in iteration require 'v-bind:key' directives.")
+        .with_label(span)
+}
+
+fn key_not_using_alias_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Expected 'v-bind:key' directive to use the variables which are defined by the 'v-for' directive.",
+    )
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidVFor;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces valid `v-for` directives.
+    ///
+    /// This rule reports `v-for` directives which
+    /// - have an argument or modifiers,
+    /// - have no value, or a value which is not `<alias> in <expression>`,
+    /// - have key or index aliases which are not identifiers,
+    /// - are on custom components without `v-bind:key`,
+    /// - have a `v-bind:key` which does not use the iteration aliases.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Invalid `v-for` directives fail to compile, and keys which do not
+    /// depend on the iterated item do not identify it, defeating the purpose
+    /// of the key.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <div v-for />
+    ///   <div v-for="todo" />
+    ///   <div v-for:todo="todo in todos" />
+    ///   <div v-for="(todo, {index}) in todos" :key="todo.id" />
+    ///   <MyComponent v-for="todo in todos" />
+    ///   <MyComponent v-for="todo in todos" :key="foo" />
+    /// </template>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <div v-for="todo in todos" />
+    ///   <div v-for="(todo, index) of todos" :key="index" />
+    ///   <MyComponent v-for="todo in todos" :key="todo.id" />
+    /// </template>
+    /// ```
+    ValidVFor,
+    vue,
+    correctness
+);
+
+impl Rule for ValidVFor {
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.vue_template().is_some()
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(template) = ctx.vue_template() else { return };
+        template.for_each_element(|element, _| {
+            if let Some(directive) = element.directive("for") {
+                check_directive(element, directive, ctx);
+            }
+        });
+    }
+}
+
+fn check_directive(element: &VueElement, directive: &VueDirective, ctx: &LintContext) {
+    if let Some(argument) = &directive.argument {
+        ctx.diagnostic(unexpected_argument_diagnostic(argument.span));
+    }
+    if !directive.modifiers.is_empty() {
+        ctx.diagnostic(unexpected_modifier_diagnostic(directive.span));
+    }
+
+    let Some(value_span) =
+        directive.value_span.filter(|span| !span.source_text(ctx.source_text()).trim().is_empty())
+    else {
+        ctx.diagnostic(missing_value_diagnostic(directive.span));
+        return;
+    };
+    let Some(VueDirectiveValue::For(for_expression)) = &directive.value else {
+        ctx.diagnostic(invalid_syntax_diagnostic(value_span));
+        return;
+    };
+
+    // The value alias may be destructured, but the key and index must be identifiers.
+    let params = &for_expression.left;
+    for param in params.items.iter().skip(1) {
+        if !matches!(param.pattern.kind, BindingPatternKind::BindingIdentifier(_)) {
+            let span = param.span;
+            ctx.diagnostic(invalid_alias_diagnostic(span.source_text(ctx.source_text()), span));
+        }
+    }
+    if let Some(rest) = &params.rest {
+        ctx.diagnostic(invalid_alias_diagnostic(
+            rest.span.source_text(ctx.source_text()),
+            rest.span,
+        ));
+    }
+
+    let aliases = element.scope_variables();
+    if element.is_template() {
+        // A `<template>` is not rendered, its child elements are keyed instead.
+        if let Some(key) = element.bind_directive("key") {
+            check_key_uses_alias(key, &aliases, ctx);
+        }
+        for child in &element.children {
+            if let VueNode::Element(child) = child {
+                if child.directive("for").is_none() {
+                    check_key(child, &aliases, ctx);
+                }
+            }
+        }
+    } else {
+        check_key(element, &aliases, ctx);
+    }
+}
+
+fn check_key(element: &VueElement, aliases: &[(&str, Span)], ctx: &LintContext) {
+    match element.bind_directive("key") {
+        Some(key) => check_key_uses_alias(key, aliases, ctx),
+        None if element.is_custom_component() => {
+            ctx.diagnostic(missing_key_diagnostic(element.name_span));
+        }
+        None => {}
+    }
+}
+
+fn check_key_uses_alias(key: &VueDirective, aliases: &[(&str, Span)], ctx: &LintContext) {
+    if key.value.is_none() {
+        return;
+    }
+    let references = key.references();
+    if !references.iter().any(|(name, _)| aliases.iter().any(|(alias, _)| alias == name)) {
+        ctx.diagnostic(key_not_using_alias_diagnostic(key.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"<template><div v-for="x in list"></div></template>"#,
+        r#"<template><div v-for="x of list"></div></template>"#,
+        r#"<template><div v-for="(x, i) in list"></div></template>"#,
+        r#"<template><div v-for="(x, i, k) in list"></div></template>"#,
+        r#"<template><div v-for="({ id, name }, i) in list"></div></template>"#,
+        r#"<template><div v-for="[a, b] in list"></div></template>"#,
+        r#"<template><div v-for="n in 10"></div></template>"#,
+        r#"<template><div v-for="x in list" :key="x.id"></div></template>"#,
+        r#"<template><div v-for="(x, i) in list" :key="`item-${i}`"></div></template>"#,
+        r#"<template><your-component v-for="x in list" :key="x.id"></your-component></template>"#,
+        r#"<template><YourComponent v-for="x in list" :key="x.id" /></template>"#,
+        r#"<template><div v-for="x in list" :is="x.component" :key="x.id"></div></template>"#,
+        r#"<template><template v-for="x in list"><custom-component :key="x" /></template></template>"#,
+        r#"<template><template v-for="x in list" :key="x"><div /></template></template>"#,
+        r#"<template><div v-for="x in list"><your-component v-for="y in x" :key="y" /></div></template>"#,
+        r"<script>const list = []</script>",
+    ];
+
+    let fail = vec![
+        r#"<template><div v-for:aaa="x in list"></div></template>"#,
+        r#"<template><div v-for.aaa="x in list"></div></template>"#,
+        r"<template><div v-for></div></template>",
+        r#"<template><div v-for=""></div></template>"#,
+        r#"<template><div v-for="foo"></div></template>"#,
+        r#"<template><div v-for="x in"></div></template>"#,
+        r#"<template><div v-for="(x, {i}) in list"></div></template>"#,
+        r#"<template><div v-for="(x, i, [k]) in list"></div></template>"#,
+        r#"<template><div v-for="(x, ...rest) in list"></div></template>"#,
+        r#"<template><your-component v-for="x in list"></your-component></template>"#,
+        r#"<template><YourComponent v-for="x in list" /></template>"#,
+        r#"<template><div v-for="x in list" :is="x.component"></div></template>"#,
+        r#"<template><YourComponent v-for="x in list" :key="foo" /></template>"#,
+        r#"<template><div v-for="x in list" :key="foo"></div></template>"#,
+        r#"<template><template v-for="x in list"><custom-component /></template></template>"#,
+        r#"<template><template v-for="x in list"><div :key="foo" /></template></template>"#,
+    ];
+
+    Tester::new(ValidVFor::NAME, ValidVFor::PLUGIN, pass, fail)
+        .change_rule_path_extension("vue")
+        .test_and_snapshot();
+}
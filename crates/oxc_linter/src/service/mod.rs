@@ -22,6 +22,9 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Parse with error recovery, so files with syntax errors are still linted
+    recover_from_errors: bool,
 }
 
 impl LintServiceOptions {
@@ -30,7 +33,7 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), tsconfig: None, cross_module: false }
+        Self { cwd: cwd.into(), tsconfig: None, cross_module: false, recover_from_errors: false }
     }
 
     #[inline]
@@ -55,6 +58,15 @@ impl LintServiceOptions {
         self
     }
 
+    /// Lint files with syntax errors, using the part of the AST the parser could recover.
+    /// Syntax errors are reported along with the lint diagnostics.
+    #[inline]
+    #[must_use]
+    pub fn with_recover_from_errors(mut self, recover_from_errors: bool) -> Self {
+        self.recover_from_errors = recover_from_errors;
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
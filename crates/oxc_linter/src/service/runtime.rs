@@ -15,7 +15,8 @@ use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use self_cell::self_cell;
 use smallvec::SmallVec;
 
-use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool};
+use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool, Vec as ArenaVec};
+use oxc_ast::ast::Statement;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_resolver::Resolver;
@@ -27,6 +28,11 @@ use crate::{
     fixer::PossibleFixes,
     loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
     module_record::ModuleRecord,
+    template::{
+        self, Template,
+        markup::{MarkupKind, MarkupParser},
+        vue::VueTemplateParser,
+    },
     utils::read_to_arena_str,
 };
 
@@ -41,6 +47,7 @@ pub struct Runtime {
     paths: IndexSet<Arc<OsStr>, FxBuildHasher>,
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
+    recover_from_errors: bool,
 
     pub(super) file_system: Box<dyn RuntimeFileSystem + Sync + Send>,
 
@@ -108,6 +115,12 @@ struct SectionContent<'a> {
     /// None if section parsing failed. The corresponding item with the same index in
     /// `ProcessedModule.section_module_records` would be `Err(Vec<OxcDiagnostic>)`.
     semantic: Option<Semantic<'a>>,
+    /// Template of a framework file. Only set on the section containing the
+    /// merged scripts of the file.
+    template: Option<Template<'a>>,
+    /// Syntax errors which parsing recovered from. Always empty unless
+    /// `LintServiceOptions::recover_from_errors` is enabled.
+    errors: Vec<OxcDiagnostic>,
 }
 
 /// A module with its source text and semantic, ready to be linted.
@@ -188,6 +201,7 @@ impl Runtime {
             paths: IndexSet::with_capacity_and_hasher(0, FxBuildHasher),
             linter,
             resolver,
+            recover_from_errors: options.recover_from_errors,
             file_system: Box::new(OsFileSystem),
         }
     }
@@ -507,6 +521,11 @@ impl Runtime {
                     // Otherwise, spans for fixes will be incorrect due to varying size of the
                     // source code after each fix.
                     let mut fix_offset: i32 = 0;
+                    // End of the last section whose fixes were applied. Sections of framework
+                    // files can overlap, e.g. client-side scripts of an Astro page are inside
+                    // the section of its lowered markup. The fixes of a section overlapping a
+                    // fixed one are not applied, as its source text is outdated.
+                    let mut fixed_end: u32 = 0;
 
                     let path = Path::new(&module_to_lint.path);
 
@@ -520,12 +539,22 @@ impl Runtime {
                         .zip(dep.section_contents.drain(..))
                     {
                         let mut messages = match record_result {
-                            Ok(module_record) => me.linter.run(
-                                path,
-                                Rc::new(section.semantic.unwrap()),
-                                Arc::clone(&module_record),
-                                allocator_guard,
-                            ),
+                            Ok(module_record) => {
+                                let mut messages = me.linter.run(
+                                    path,
+                                    Rc::new(section.semantic.unwrap()),
+                                    Arc::clone(&module_record),
+                                    section.template.map(Rc::new),
+                                    allocator_guard,
+                                );
+                                messages.extend(
+                                    section
+                                        .errors
+                                        .into_iter()
+                                        .map(|err| Message::new(err, PossibleFixes::None)),
+                                );
+                                messages
+                            }
                             Err(errors) => errors
                                 .into_iter()
                                 .map(|err| Message::new(err, PossibleFixes::None))
@@ -533,9 +562,10 @@ impl Runtime {
                         };
 
                         let source_text = section.source.source_text;
-                        if me.linter.options().fix.is_some() {
+                        if me.linter.options().fix.is_some() && section.source.start >= fixed_end {
                             let fix_result = Fixer::new(source_text, messages).fix();
                             if fix_result.fixed {
+                                fixed_end = section.source.start + source_text.len() as u32;
                                 // write to file, replacing only the changed part
                                 let start =
                                     section.source.start.saturating_add_signed(fix_offset) as usize;
@@ -631,10 +661,14 @@ impl Runtime {
                                     );
                                 }
                                 Ok(module_record) => {
+                                    messages.lock().unwrap().extend(
+                                        section.errors.into_iter().map(std::convert::Into::into),
+                                    );
                                     let section_message = me.linter.run(
                                         Path::new(&module.path),
                                         Rc::new(section.semantic.unwrap()),
                                         Arc::clone(&module_record),
+                                        section.template.map(Rc::new),
                                         allocator_guard,
                                     );
 
@@ -765,6 +799,7 @@ impl Runtime {
                                         Path::new(&module.path),
                                         Rc::new(section.semantic.unwrap()),
                                         Arc::clone(&module_record),
+                                        section.template.map(Rc::new),
                                         allocator_guard,
                                     ),
                                     Err(errors) => errors
@@ -890,33 +925,95 @@ impl Runtime {
         allocator: &'a Allocator,
         mut out_sections: Option<&mut SectionContents<'a>>,
     ) -> SmallVec<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]> {
-        let section_sources = PartialLoader::parse(ext, source_text)
+        let mut section_sources = PartialLoader::parse(ext, source_text)
             .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]);
 
+        // Scripts of a Vue component are parsed as one program spanning the whole file, so that
+        // spans of template nodes and script nodes are relative to the same position.
+        let mut template = None;
+        let mut markup_statements = None;
+        let mut parse_texts =
+            section_sources.iter().map(|source| source.source_text).collect::<Vec<_>>();
+        if ext == "vue" {
+            let (merged_source, masked_text) =
+                template::merge_sections(allocator, source_text, &section_sources);
+            template = VueTemplateParser::new(allocator, source_text, merged_source.source_type)
+                .parse()
+                .map(Template::Vue);
+            if template.is_some() {
+                section_sources = vec![merged_source];
+                parse_texts = vec![masked_text];
+            }
+        } else if let Some(kind) = MarkupKind::from_extension(ext) {
+            // The markup is lowered into the program of the merged scripts. Client-side scripts
+            // of an Astro page run in the browser, so only its frontmatter is merged.
+            let (component_sources, client_sources): (Vec<_>, Vec<_>) =
+                section_sources.iter().copied().partition(|source| {
+                    kind == MarkupKind::Svelte
+                        || source_text[..source.start as usize].ends_with("---")
+                });
+            let (mut merged_source, masked_text) =
+                template::merge_sections(allocator, source_text, &component_sources);
+            let markup_start = match kind {
+                MarkupKind::Svelte => 0,
+                MarkupKind::Astro => {
+                    merged_source.source_type = SourceType::ts();
+                    component_sources.first().map_or(0, |frontmatter| {
+                        frontmatter.source_text.len() + frontmatter.start as usize + 3
+                    })
+                }
+            };
+            if let Some((statements, markup)) = MarkupParser::new(
+                allocator,
+                source_text,
+                merged_source.source_type,
+                kind,
+                markup_start,
+            )
+            .parse()
+            {
+                template = Some(Template::Markup(markup));
+                markup_statements = Some(statements);
+                parse_texts = std::iter::once(masked_text)
+                    .chain(client_sources.iter().map(|source| source.source_text))
+                    .collect();
+                section_sources = std::iter::once(merged_source).chain(client_sources).collect();
+            }
+        }
+
         let mut section_module_records = SmallVec::<
             [Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1],
         >::with_capacity(section_sources.len());
-        for section_source in section_sources {
+        for (section_source, parse_text) in section_sources.into_iter().zip(parse_texts) {
             match self.process_source_section(
                 path,
                 allocator,
                 section_source.source_text,
+                parse_text,
+                markup_statements.take(),
                 section_source.source_type,
                 check_syntax_errors,
             ) {
-                Ok((record, semantic)) => {
+                Ok((record, semantic, errors)) => {
                     section_module_records.push(Ok(record));
                     if let Some(sections) = &mut out_sections {
                         sections.push(SectionContent {
                             source: section_source,
                             semantic: Some(semantic),
+                            template: template.take(),
+                            errors,
                         });
                     }
                 }
                 Err(err) => {
                     section_module_records.push(Err(err));
                     if let Some(sections) = &mut out_sections {
-                        sections.push(SectionContent { source: section_source, semantic: None });
+                        sections.push(SectionContent {
+                            source: section_source,
+                            semantic: None,
+                            template: None,
+                            errors: vec![],
+                        });
                     }
                 }
             }
@@ -924,25 +1021,39 @@ impl Runtime {
         section_module_records
     }
 
+    /// `parse_text` is the text to parse `source_text` from. It differs from `source_text`
+    /// only for merged sections of framework files, see [`template::merge_sections`].
+    /// `markup` are the lowered statements of Svelte or Astro markup, appended to the program.
+    #[expect(clippy::too_many_arguments)]
     fn process_source_section<'a>(
         &self,
         path: &Path,
         allocator: &'a Allocator,
         source_text: &'a str,
+        parse_text: &'a str,
+        markup: Option<ArenaVec<'a, Statement<'a>>>,
         source_type: SourceType,
         check_syntax_errors: bool,
-    ) -> Result<(ResolvedModuleRecord, Semantic<'a>), Vec<OxcDiagnostic>> {
-        let ret = Parser::new(allocator, source_text, source_type)
+    ) -> Result<(ResolvedModuleRecord, Semantic<'a>, Vec<OxcDiagnostic>), Vec<OxcDiagnostic>> {
+        let mut ret = Parser::new(allocator, parse_text, source_type)
             .with_options(ParseOptions {
                 parse_regular_expression: true,
                 allow_return_outside_function: true,
+                recover_from_errors: self.recover_from_errors,
                 ..ParseOptions::default()
             })
             .parse();
 
-        if !ret.errors.is_empty() {
+        let recovered = self.recover_from_errors && !ret.panicked && !ret.is_flow_language;
+        if !ret.errors.is_empty() && !recovered {
             return Err(if ret.is_flow_language { vec![] } else { ret.errors });
         }
+        let mut errors = ret.errors;
+
+        ret.program.source_text = source_text;
+        if let Some(markup) = markup {
+            ret.program.body.extend(markup);
+        }
 
         let semantic_ret = SemanticBuilder::new()
             .with_cfg(true)
@@ -952,7 +1063,10 @@ impl Runtime {
             .build(allocator.alloc(ret.program));
 
         if !semantic_ret.errors.is_empty() {
-            return Err(semantic_ret.errors);
+            if !recovered {
+                return Err(semantic_ret.errors);
+            }
+            errors.extend(semantic_ret.errors);
         }
 
         let mut semantic = semantic_ret.semantic;
@@ -978,6 +1092,6 @@ impl Runtime {
                 })
                 .collect();
         }
-        Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic))
+        Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic, errors))
     }
 }
@@ -0,0 +1,717 @@
+//! Markup of Svelte components and Astro pages.
+//!
+//! Unlike a Vue template, which is kept as a separate tree for the rules of
+//! the `vue` plugin, markup is lowered to JavaScript statements which are
+//! appended to the program of the file's scripts. Semantic analysis then
+//! resolves references from the markup like those of any other code, and the
+//! regular JavaScript rules lint the markup expressions.
+//!
+//! Each construct is lowered to its closest JavaScript equivalent, so that the
+//! variables it declares get the right scope:
+//!
+//! | Markup | Lowered to |
+//! |--------|------------|
+//! | `{expression}`, `attribute={expression}` | `expression;` |
+//! | `<Component>`, `<ui.Button>` | `Component;`, `ui.Button;` |
+//! | `{#if a}…{:else if b}…{:else}…{/if}` | `if (a) {…} else if (b) {…} else {…}` |
+//! | `{#each list as item, i (key)}…{:else}…{/each}` | `for (const [item, i] of list) { key; … } {…}` |
+//! | `{#await p}…{:then v}…{:catch e}…{/await}` | `try { const v = p; … } catch (e) {…}` |
+//! | `{#key k}…{/key}` | `{ k; … }` |
+//! | `{#snippet name(a)}…{/snippet}` | `function name(a) {…}` |
+//! | `{@const x = y}` | `const x = y;` |
+//! | `{@html a}`, `{@render a()}`, `{@debug a}` | `a;`, `a();`, `a;` |
+//! | `bind:value={x}` | `x; [x] = [];` |
+//! | `use:action={p}`, `transition:fade={p}` | `action(p);`, `fade(p);` |
+//! | `let:item={alias}` | `{ let alias; … }` around the children |
+//!
+//! Statements which don't exist in the source text are recorded in
+//! [`Markup`], so that rules about the shape of statements can skip them.
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Allocator, Vec as ArenaVec};
+use oxc_ast::{
+    AstBuilder,
+    ast::{BindingPattern, Expression, FormalParameters, Statement},
+};
+use oxc_ast_visit::VisitMut;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+use crate::template::{SpanOffset, VOID_ELEMENTS};
+
+mod svelte;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkupKind {
+    Svelte,
+    Astro,
+}
+
+impl MarkupKind {
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "svelte" => Some(Self::Svelte),
+            "astro" => Some(Self::Astro),
+            _ => None,
+        }
+    }
+}
+
+/// Markup of a Svelte component or an Astro page, lowered to statements at
+/// the end of the program.
+#[derive(Debug)]
+pub struct Markup {
+    kind: MarkupKind,
+    /// Spans of the statements created by lowering.
+    lowered_statements: FxHashSet<Span>,
+}
+
+impl Markup {
+    pub fn kind(&self) -> MarkupKind {
+        self.kind
+    }
+
+    /// Whether the statement at `span` was created by lowering markup, as
+    /// opposed to being written in a script or inside a markup expression.
+    pub fn is_lowered_statement(&self, span: Span) -> bool {
+        self.lowered_statements.contains(&span)
+    }
+}
+
+/// Value of an attribute.
+enum AttributeValue<'a> {
+    /// Quoted or unquoted text.
+    Text,
+    /// `{expression}` or a template literal, with the span of the braces or backticks.
+    Expression(Expression<'a>, Span),
+}
+
+/// Parser lowering the markup of a Svelte component or an Astro page.
+///
+/// Like [`VueTemplateParser`](super::vue::VueTemplateParser), this is not a
+/// spec-compliant HTML parser. It only recognizes what is needed to find
+/// expressions and the scopes around them.
+pub struct MarkupParser<'a> {
+    allocator: &'a Allocator,
+    ast: AstBuilder<'a>,
+    source_text: &'a str,
+    /// Source type for expressions.
+    source_type: SourceType,
+    kind: MarkupKind,
+    pos: usize,
+    /// Names of currently open elements, innermost last.
+    open_elements: Vec<&'a str>,
+    lowered_statements: FxHashSet<Span>,
+}
+
+impl<'a> MarkupParser<'a> {
+    /// `start` is the offset where the markup begins, i.e. after the
+    /// frontmatter of an Astro page.
+    pub fn new(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+        kind: MarkupKind,
+        start: usize,
+    ) -> Self {
+        Self {
+            allocator,
+            ast: AstBuilder::new(allocator),
+            source_text,
+            // Astro expressions may contain JSX.
+            source_type: source_type.with_jsx(kind == MarkupKind::Astro),
+            kind,
+            pos: start,
+            open_elements: vec![],
+            lowered_statements: FxHashSet::default(),
+        }
+    }
+
+    /// Returns `None` if an expression or a block can't be parsed, rather
+    /// than linting the scripts against partial markup.
+    pub fn parse(mut self) -> Option<(ArenaVec<'a, Statement<'a>>, Markup)> {
+        let statements = self.parse_fragment()?;
+        // The fragment stops early at a block continuation or a closing block
+        // tag without an open block, e.g. a stray `{/if}`.
+        if self.pos < self.source_text.len() {
+            return None;
+        }
+        Some((statements, Markup { kind: self.kind, lowered_statements: self.lowered_statements }))
+    }
+
+    // NOTE: the loader checked that source_text.len() is less than u32::MAX
+    #[expect(clippy::cast_possible_truncation)]
+    fn offset(pos: usize) -> u32 {
+        pos as u32
+    }
+
+    fn span(start: usize, end: usize) -> Span {
+        Span::new(Self::offset(start), Self::offset(end))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source_text[self.pos..]
+    }
+
+    fn lowered(&mut self, statement: Statement<'a>) -> Statement<'a> {
+        self.lowered_statements.insert(statement.span());
+        statement
+    }
+
+    fn lowered_expression(&mut self, span: Span, expression: Expression<'a>) -> Statement<'a> {
+        let statement = self.ast.statement_expression(span, expression);
+        self.lowered(statement)
+    }
+
+    /// Parse children up to an end tag of an open element, or, in Svelte, up
+    /// to the next block continuation or closing block tag.
+    fn parse_fragment(&mut self) -> Option<ArenaVec<'a, Statement<'a>>> {
+        let mut statements = self.ast.vec();
+        while self.pos < self.source_text.len() {
+            let rest = self.rest();
+            if let Some(end_tag) = rest.strip_prefix("</") {
+                let name = tag_name(end_tag);
+                if self.open_elements.iter().any(|open| open.eq_ignore_ascii_case(name)) {
+                    return Some(statements);
+                }
+                // Stray end tag
+                self.pos += rest.find('>').map_or(rest.len(), |i| i + 1);
+            } else if rest.starts_with("<!--") {
+                self.pos += rest.find("-->").map_or(rest.len(), |end| end + 3);
+            } else if rest.starts_with('<')
+                && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+            {
+                self.parse_element(&mut statements)?;
+            } else if rest.starts_with('{') {
+                if self.kind == MarkupKind::Svelte {
+                    match rest.as_bytes().get(1) {
+                        Some(b':') => return Some(statements),
+                        // `{/if}`, but not a `{/* comment */}`
+                        Some(b'/') if !rest[2..].starts_with(['*', '/']) => {
+                            return Some(statements);
+                        }
+                        Some(b'#') => {
+                            self.parse_block(&mut statements)?;
+                            continue;
+                        }
+                        Some(b'@') => {
+                            self.parse_tag(&mut statements)?;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                }
+                self.parse_mustache(&mut statements)?;
+            } else {
+                // Always consume at least one character, e.g. a `<` which does not start a tag.
+                let first_len = rest.chars().next().map_or(1, char::len_utf8);
+                self.pos += rest[first_len..]
+                    .find(['<', '{'])
+                    .map_or(rest.len(), |offset| offset + first_len);
+            }
+        }
+        Some(statements)
+    }
+
+    fn parse_element(&mut self, statements: &mut ArenaVec<'a, Statement<'a>>) -> Option<()> {
+        let start = self.pos;
+        let name_start = start + 1;
+        let name = tag_name(&self.source_text[name_start..]);
+        self.pos = name_start + name.len();
+
+        let is_component = self.is_component(name);
+        if is_component {
+            let component = self.parse_expression(name, name_start)?;
+            let statement = self.lowered_expression(Self::span(name_start, self.pos), component);
+            statements.push(statement);
+        }
+
+        // Variables declared by `let:` directives, scoped to the children.
+        let mut scope = self.ast.vec();
+        let self_closing = self.parse_attributes(statements, &mut scope)?;
+
+        if ["script", "style"].iter().any(|raw| raw.eq_ignore_ascii_case(name)) {
+            // Scripts are linted as separate sections, skip to the end tag.
+            let end_tag = format!("</{name}");
+            let rest = self.rest();
+            self.pos += rest.find(&end_tag).map_or(rest.len(), |offset| {
+                offset + rest[offset..].find('>').map_or(end_tag.len(), |i| i + 1)
+            });
+            return Some(());
+        }
+        if self_closing || VOID_ELEMENTS.iter().any(|void| void.eq_ignore_ascii_case(name)) {
+            return Some(());
+        }
+
+        self.open_elements.push(name);
+        let mut children = self.parse_fragment()?;
+        self.open_elements.pop();
+        // An end tag for an ancestor implicitly closes the element, and is consumed by the ancestor.
+        let rest = self.rest();
+        if rest
+            .strip_prefix("</")
+            .is_some_and(|end_tag| tag_name(end_tag).eq_ignore_ascii_case(name))
+        {
+            self.pos += rest.find('>').map_or(rest.len(), |i| i + 1);
+        }
+
+        if is_component && self.kind == MarkupKind::Svelte {
+            // Snippets declared inside a component are passed to it as props.
+            let snippets = children
+                .iter()
+                .filter_map(|statement| match statement {
+                    Statement::FunctionDeclaration(function) => function.id.as_ref(),
+                    _ => None,
+                })
+                .map(|id| (id.span, id.name))
+                .collect::<Vec<_>>();
+            for (span, name) in snippets {
+                let reference = self.ast.expression_identifier(span, name);
+                let statement = self.lowered_expression(span, reference);
+                children.push(statement);
+            }
+        }
+
+        if scope.is_empty() {
+            statements.extend(children);
+        } else {
+            scope.extend(children);
+            let block = self.ast.statement_block(Self::span(start, self.pos), scope);
+            let block = self.lowered(block);
+            statements.push(block);
+        }
+        Some(())
+    }
+
+    fn is_component(&self, name: &str) -> bool {
+        if name.contains(':') || (self.kind == MarkupKind::Astro && name == "Fragment") {
+            return false;
+        }
+        name.starts_with(|c: char| c.is_ascii_uppercase()) || name.contains('.')
+    }
+
+    /// Parse attributes up to and including the end of the start tag, lowering
+    /// their expressions into `statements`. Returns whether the tag is self-closing.
+    fn parse_attributes(
+        &mut self,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        scope: &mut ArenaVec<'a, Statement<'a>>,
+    ) -> Option<bool> {
+        loop {
+            self.pos += self.rest().len() - self.rest().trim_start().len();
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Some(false);
+            }
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Some(true);
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                return Some(false);
+            }
+            if rest.starts_with('{') {
+                // `{name}` shorthand or `{...spread}`
+                self.parse_mustache(statements)?;
+                continue;
+            }
+
+            let start = self.pos;
+            let name_len = rest
+                .char_indices()
+                .find(|&(offset, c)| {
+                    c.is_ascii_whitespace()
+                        || c == '='
+                        || c == '>'
+                        || (c == '/' && rest[offset..].starts_with("/>"))
+                })
+                .map_or(rest.len(), |(offset, _)| offset);
+            if name_len == 0 {
+                // Stray `=`
+                self.pos += 1;
+                continue;
+            }
+            let name = &rest[..name_len];
+            self.pos += name_len;
+
+            let mut value = None;
+            if let Some(after_eq) = self.rest().trim_start().strip_prefix('=') {
+                self.pos = self.source_text.len() - after_eq.trim_start().len();
+                if let AttributeValue::Expression(expression, span) =
+                    self.parse_attribute_value(statements)?
+                {
+                    value = Some((expression, span));
+                }
+            }
+
+            match self.kind {
+                MarkupKind::Svelte => {
+                    let span = Self::span(start, self.pos);
+                    self.lower_svelte_attribute(name, start, value, span, statements, scope)?;
+                }
+                MarkupKind::Astro => {
+                    if let Some((expression, span)) = value {
+                        let statement = self.lowered_expression(span, expression);
+                        statements.push(statement);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Expressions inside a quoted Svelte value, e.g. `class="item {active}"`,
+    /// are lowered right away.
+    fn parse_attribute_value(
+        &mut self,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+    ) -> Option<AttributeValue<'a>> {
+        let rest = self.rest();
+        match rest.chars().next() {
+            Some('{') => {
+                let start = self.pos;
+                let (expression, end) = self.parse_braced_expression(start + 1)?;
+                self.pos = end;
+                Some(expression.map_or(AttributeValue::Text, |expression| {
+                    AttributeValue::Expression(expression, Self::span(start, end))
+                }))
+            }
+            // Astro supports template literals as attribute values.
+            Some('`') if self.kind == MarkupKind::Astro => {
+                let mut expression =
+                    Parser::new(self.allocator, rest, self.source_type).parse_expression().ok()?;
+                if !matches!(expression, Expression::TemplateLiteral(_)) {
+                    return None;
+                }
+                SpanOffset(Self::offset(self.pos)).visit_expression(&mut expression);
+                let span = expression.span();
+                self.pos = span.end as usize;
+                Some(AttributeValue::Expression(expression, span))
+            }
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                loop {
+                    let rest = self.rest();
+                    let Some(offset) =
+                        rest.find(|c| c == quote || (c == '{' && self.kind == MarkupKind::Svelte))
+                    else {
+                        self.pos = self.source_text.len();
+                        return Some(AttributeValue::Text);
+                    };
+                    self.pos += offset;
+                    if rest[offset..].starts_with(quote) {
+                        self.pos += 1;
+                        return Some(AttributeValue::Text);
+                    }
+                    self.parse_mustache(statements)?;
+                }
+            }
+            _ => {
+                self.pos +=
+                    rest.find(|c: char| c.is_ascii_whitespace() || c == '>').unwrap_or(rest.len());
+                Some(AttributeValue::Text)
+            }
+        }
+    }
+
+    /// Lower the `{expression}` at the current position.
+    fn parse_mustache(&mut self, statements: &mut ArenaVec<'a, Statement<'a>>) -> Option<()> {
+        let start = self.pos;
+        let (expression, end) = self.parse_braced_expression(start + 1)?;
+        self.pos = end;
+        if let Some(expression) = expression {
+            let statement = self.lowered_expression(Self::span(start, end), expression);
+            statements.push(statement);
+        }
+        Some(())
+    }
+
+    /// Parse an expression starting at `content_start` and ending at a `}`.
+    ///
+    /// The closing brace is found by the parser rather than by matching
+    /// brackets, as Astro expressions may contain JSX text with any characters.
+    /// A leading `...` of a spread attribute is skipped.
+    ///
+    /// Returns the expression, or `None` for an empty `{}` or `{/* comment */}`,
+    /// and the offset after the closing brace.
+    fn parse_braced_expression(
+        &self,
+        content_start: usize,
+    ) -> Option<(Option<Expression<'a>>, usize)> {
+        let mut content_start = content_start;
+        let trivia = trivia_len(&self.source_text[content_start..]);
+        let content = &self.source_text[content_start + trivia..];
+        if content.starts_with('}') {
+            return Some((None, content_start + trivia + 1));
+        }
+        if content.starts_with("...") {
+            content_start += trivia + 3;
+        }
+
+        let text = &self.source_text[content_start..];
+        // `parse_expression` stops at the end of the expression, which must be followed by `}`.
+        let mut expression =
+            Parser::new(self.allocator, text, self.source_type).parse_expression().ok()?;
+        let expression_end = content_start + expression.span().end as usize;
+        let after = &self.source_text[expression_end..];
+        let trivia = trivia_len(after);
+        if !after[trivia..].starts_with('}') {
+            return None;
+        }
+        SpanOffset(Self::offset(content_start)).visit_expression(&mut expression);
+        Some((Some(expression), expression_end + trivia + 1))
+    }
+
+    /// Parse `text`, which starts at `start`, as a whole expression.
+    fn parse_expression(&self, text: &'a str, start: usize) -> Option<Expression<'a>> {
+        let mut expression =
+            Parser::new(self.allocator, text, self.source_type).parse_expression().ok()?;
+        // `parse_expression` stops at the end of the expression, and does not
+        // report trailing tokens.
+        if expression.span().end as usize != text.trim_end().len() {
+            return None;
+        }
+        SpanOffset(Self::offset(start)).visit_expression(&mut expression);
+        Some(expression)
+    }
+
+    fn parse_statements(&self, text: &'a str, start: usize) -> Option<ArenaVec<'a, Statement<'a>>> {
+        let ret = Parser::new(self.allocator, text, self.source_type).parse();
+        if !ret.errors.is_empty() || ret.panicked {
+            return None;
+        }
+        let mut statements = ret.program.body;
+        SpanOffset(Self::offset(start)).visit_statements(&mut statements);
+        Some(statements)
+    }
+
+    /// Parse the parameters of an arrow function, e.g. `(item, index)`.
+    fn parse_params(&self, text: &str, start: usize) -> Option<FormalParameters<'a>> {
+        let source = self.allocator.alloc_str(&format!("{text}=>0"));
+        let Expression::ArrowFunctionExpression(arrow) =
+            Parser::new(self.allocator, source, self.source_type).parse_expression().ok()?
+        else {
+            return None;
+        };
+        if arrow.span.end as usize != source.len() {
+            return None;
+        }
+        let mut params = arrow.unbox().params.unbox();
+        SpanOffset(Self::offset(start)).visit_formal_parameters(&mut params);
+        Some(params)
+    }
+
+    /// Parse a binding pattern, e.g. `item` or `{ id, name }`.
+    fn parse_pattern(&self, text: &str, start: usize) -> Option<BindingPattern<'a>> {
+        // `start` is never 0, a pattern is always preceded by some markup.
+        let mut params = self.parse_params(&format!("({text})"), start - 1)?;
+        if params.items.len() != 1 || params.rest.is_some() {
+            return None;
+        }
+        params.items.pop().map(|param| param.pattern)
+    }
+}
+
+/// Name of the tag at the start of `text`, which follows `<` or `</`.
+fn tag_name(text: &str) -> &str {
+    let len =
+        text.find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>').unwrap_or(text.len());
+    &text[..len]
+}
+
+/// Length of the whitespace and comments at the start of `text`.
+fn trivia_len(text: &str) -> usize {
+    let mut rest = text;
+    loop {
+        let trimmed = rest.trim_start();
+        if let Some(comment) = trimmed.strip_prefix("/*") {
+            rest = comment.find("*/").map_or("", |end| &comment[end + 2..]);
+        } else if let Some(comment) = trimmed.strip_prefix("//") {
+            rest = comment.find('\n').map_or("", |end| &comment[end..]);
+        } else {
+            return text.len() - trimmed.len();
+        }
+    }
+}
+
+/// Find the `}` closing a block tag, skipping over nested brackets, strings,
+/// template literals and comments.
+pub(crate) fn find_closing_brace(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0u32;
+    let mut i = 0;
+    while let Some(&b) = bytes.get(i) {
+        match b {
+            b'{' | b'(' | b'[' => depth += 1,
+            b'}' | b')' | b']' => {
+                if depth == 0 {
+                    return (b == b'}').then_some(i);
+                }
+                depth -= 1;
+            }
+            b'"' | b'\'' => {
+                i += 1;
+                while *bytes.get(i)? != b {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            b'`' => {
+                i += 1;
+                loop {
+                    match *bytes.get(i)? {
+                        b'`' => break,
+                        b'\\' => i += 1,
+                        b'$' if bytes.get(i + 1) == Some(&b'{') => {
+                            i += 2 + find_closing_brace(&text[i + 2..])?;
+                        }
+                        _ => {}
+                    }
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => i += text[i..].find('\n')?,
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i += text[i..].find("*/")? + 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Find `keyword` preceded by whitespace outside of brackets and strings,
+/// e.g. `as` in `items as item`.
+fn find_keyword(text: &str, keyword: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0u32;
+    let mut quote = None;
+    for (i, &b) in bytes.iter().enumerate() {
+        if let Some(q) = quote {
+            if b == q && bytes[i - 1] != b'\\' {
+                quote = None;
+            }
+            continue;
+        }
+        match b {
+            b'"' | b'\'' | b'`' => quote = Some(b),
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            _ if depth == 0
+                && i > 0
+                && bytes[i - 1].is_ascii_whitespace()
+                && text[i..].starts_with(keyword)
+                && text[i + keyword.len()..]
+                    .chars()
+                    .next()
+                    .is_none_or(|c| c.is_ascii_whitespace()) =>
+            {
+                return Some(i);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstBuilder;
+    use oxc_codegen::Codegen;
+    use oxc_span::{GetSpan, SourceType, Span};
+
+    use super::{MarkupKind, MarkupParser, find_closing_brace};
+
+    /// Lower `source_text` and print the result.
+    fn lower(kind: MarkupKind, source_text: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let (statements, markup) =
+            MarkupParser::new(&allocator, source_text, source_type, kind, 0).parse()?;
+        for statement in &statements {
+            assert!(markup.is_lowered_statement(statement.span()));
+        }
+        let ast = AstBuilder::new(&allocator);
+        let program =
+            ast.program(Span::default(), source_type, "", ast.vec(), None, ast.vec(), statements);
+        Some(Codegen::new().build(&program).code)
+    }
+
+    #[test]
+    fn closing_brace() {
+        assert_eq!(find_closing_brace("a}"), Some(1));
+        assert_eq!(find_closing_brace("{ a: '}' }}"), Some(10));
+        assert_eq!(find_closing_brace("`${ { a } }`}"), Some(12));
+        assert_eq!(find_closing_brace("a /* } */ }"), Some(10));
+        assert_eq!(find_closing_brace("a)}"), None);
+        assert_eq!(find_closing_brace("'}"), None);
+    }
+
+    #[test]
+    fn svelte_expressions() {
+        let code = lower(
+            MarkupKind::Svelte,
+            r#"<script>let a = 1</script>
+<h1 class="title {size}" {hidden} {...rest}>{a + 1} {/* comment */}</h1>
+<Button on:click|once={() => a++} bind:value={b.c} bind:checked use:tooltip={a} class:active />
+<style>p { color: red }</style>"#,
+        )
+        .unwrap();
+        assert_eq!(
+            code,
+            "size;\nhidden;\nrest;\na + 1;\nButton;\n() => a++;\nb.c;\n[b.c] = [];\nchecked;\n[checked] = [];\ntooltip(a);\nactive;\n"
+        );
+    }
+
+    #[test]
+    fn svelte_blocks() {
+        let code = lower(
+            MarkupKind::Svelte,
+            "{#if a}{a}{:else if b}{b}{:else}{c}{/if}\
+             {#each list as { id }, i (id)}{i}{:else}empty{/each}\
+             {#await p}{:then v}{v}{:catch e}{e}{/await}\
+             {#key k}{k}{/key}\
+             <List>{#snippet row(item)}{@const x = item.x}{@html x}{/snippet}</List>\
+             <Slot let:item={alias}>{alias}</Slot>",
+        )
+        .unwrap();
+        assert_eq!(
+            code,
+            "if (a) {\n\ta;\n} else if (b) {\n\tb;\n} else {\n\tc;\n}\n\
+             for (const [{ id }, i] of list) {\n\tid;\n\ti;\n}\n{}\n\
+             try {\n\tconst v = p;\n\tv;\n} catch (e) {\n\te;\n}\n\
+             {\n\tk;\n\tk;\n}\n\
+             List;\nfunction row(item) {\n\tconst x = item.x;\n\tx;\n}\nrow;\n\
+             Slot;\n{\n\tlet alias;\n\talias;\n}\n"
+        );
+    }
+
+    #[test]
+    fn svelte_invalid() {
+        assert!(lower(MarkupKind::Svelte, "{a +}").is_none());
+        assert!(lower(MarkupKind::Svelte, "{#if a}").is_none());
+        assert!(lower(MarkupKind::Svelte, "{/if}").is_none());
+        assert!(lower(MarkupKind::Svelte, "{#each list as}{/each}").is_none());
+    }
+
+    #[test]
+    fn astro_expressions() {
+        let code = lower(
+            MarkupKind::Astro,
+            r#"<ul class:list={["a", { b }]} title=`Hi ${name}` data-x="{not}">
+  {items.map((item) => <li>{item}'s</li>)}
+  <Fragment set:html={html} />
+  <Card.Body client:load {...props} />
+</ul>
+<script>const x = 1</script>"#,
+        )
+        .unwrap();
+        assert_eq!(
+            code,
+            "[\"a\", { b }];\n`Hi ${name}`;\nitems.map((item) => <li>{item}'s</li>);\nhtml;\nCard.Body;\nprops;\n"
+        );
+    }
+}
@@ -0,0 +1,534 @@
+//! Blocks, tags and directives of Svelte markup.
+//!
+//! <https://svelte.dev/docs/svelte/basic-markup>
+
+use oxc_allocator::{CloneIn, Vec as ArenaVec};
+use oxc_ast::{
+    NONE,
+    ast::{
+        Argument, AssignmentOperator, AssignmentTarget, AssignmentTargetMaybeDefault, Expression,
+        ForStatementLeft, FormalParameterKind, FunctionType, SimpleAssignmentTarget, Statement,
+        VariableDeclarationKind,
+    },
+};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_identifier_name;
+
+use super::{MarkupParser, find_closing_brace, find_keyword};
+
+/// A tag such as `{#if a}`, `{:else}`, `{/if}` or `{@html a}`.
+struct BlockTag<'a> {
+    /// Offset of the opening `{`.
+    start: usize,
+    /// `#` for an opening tag, `:` for a continuation, `/` for a closing tag,
+    /// and `@` for a special tag.
+    sigil: u8,
+    name: &'a str,
+    /// Text after the name, up to the closing `}`.
+    header: &'a str,
+    header_start: usize,
+}
+
+/// Part of an `{#await}` block.
+enum AwaitBranch<'a> {
+    Pending,
+    /// `{:then value}`, with the text of the binding and its offset.
+    Then(&'a str, usize),
+    /// `{:catch error}`, with the text of the binding and its offset.
+    Catch(&'a str, usize),
+}
+
+impl<'a> MarkupParser<'a> {
+    fn parse_block_tag(&mut self) -> Option<BlockTag<'a>> {
+        let start = self.pos;
+        let sigil = *self.rest().strip_prefix('{')?.as_bytes().first()?;
+        if !matches!(sigil, b'#' | b':' | b'/' | b'@') {
+            return None;
+        }
+        let name_start = start + 2;
+        let name_len = self.source_text[name_start..]
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(self.source_text.len() - name_start);
+        let header_start = name_start + name_len;
+        let header_len = find_closing_brace(&self.source_text[header_start..])?;
+        self.pos = header_start + header_len + 1;
+        Some(BlockTag {
+            start,
+            sigil,
+            name: &self.source_text[name_start..header_start],
+            header: &self.source_text[header_start..header_start + header_len],
+            header_start,
+        })
+    }
+
+    /// Consume the closing tag of the block `name`.
+    fn expect_block_end(&mut self, name: &str) -> Option<()> {
+        let tag = self.parse_block_tag()?;
+        (tag.sigil == b'/' && tag.name == name).then_some(())
+    }
+
+    fn parse_block_body(&mut self) -> Option<Statement<'a>> {
+        let start = self.pos;
+        let body = self.parse_fragment()?;
+        let block = self.ast.statement_block(Self::span(start, self.pos), body);
+        Some(self.lowered(block))
+    }
+
+    /// Lower the block starting with `{#` at the current position.
+    pub(super) fn parse_block(
+        &mut self,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+    ) -> Option<()> {
+        let tag = self.parse_block_tag()?;
+        match tag.name {
+            "if" => {
+                let statement = self.parse_if(tag.start, tag.header, tag.header_start)?;
+                statements.push(statement);
+            }
+            "each" => self.parse_each(&tag, statements)?,
+            "await" => {
+                let statement = self.parse_await(&tag)?;
+                statements.push(statement);
+            }
+            "key" => {
+                let key = self.parse_expression(tag.header, tag.header_start)?;
+                let key_span = Self::span(tag.header_start, tag.header_start + tag.header.len());
+                let mut body = self.ast.vec1(self.lowered_expression(key_span, key));
+                body.extend(self.parse_fragment()?);
+                self.expect_block_end("key")?;
+                let block = self.ast.statement_block(Self::span(tag.start, self.pos), body);
+                statements.push(self.lowered(block));
+            }
+            "snippet" => {
+                let statement = self.parse_snippet(&tag)?;
+                statements.push(statement);
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+
+    /// `{#if a}…{:else if b}…{:else}…{/if}`
+    fn parse_if(
+        &mut self,
+        start: usize,
+        test: &'a str,
+        test_start: usize,
+    ) -> Option<Statement<'a>> {
+        let test = self.parse_expression(test, test_start)?;
+        let consequent = self.parse_block_body()?;
+        let tag = self.parse_block_tag()?;
+        let alternate = match (tag.sigil, tag.name) {
+            (b'/', "if") => None,
+            (b':', "else") => {
+                let header = tag.header.trim_start();
+                let header_start = tag.header_start + (tag.header.len() - header.len());
+                match header.strip_prefix("if") {
+                    Some(test) if test.starts_with(|c: char| c.is_ascii_whitespace()) => {
+                        Some(self.parse_if(tag.start, test, header_start + 2)?)
+                    }
+                    _ => {
+                        let alternate = self.parse_block_body()?;
+                        self.expect_block_end("if")?;
+                        Some(alternate)
+                    }
+                }
+            }
+            _ => return None,
+        };
+        let statement =
+            self.ast.statement_if(Self::span(start, self.pos), test, consequent, alternate);
+        Some(self.lowered(statement))
+    }
+
+    /// `{#each list as item, index (key)}…{:else}…{/each}`
+    fn parse_each(
+        &mut self,
+        tag: &BlockTag<'a>,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+    ) -> Option<()> {
+        let (list, binding) = match find_keyword(tag.header, "as") {
+            Some(i) => (&tag.header[..i], Some((&tag.header[i + 2..], tag.header_start + i + 2))),
+            // The item can be omitted since Svelte 5.
+            None => (tag.header, None),
+        };
+        let list_span = Self::span(tag.header_start, tag.header_start + list.trim_end().len());
+        let list = self.parse_expression(list, tag.header_start)?;
+
+        let mut body = self.ast.vec();
+        let mut left = None;
+        if let Some((mut binding, binding_start)) = binding {
+            let trimmed = binding.trim_end();
+            if trimmed.ends_with(')') {
+                // A binding pattern can't end with `)`, so this is the key.
+                let key_start = find_key_start(trimmed)?;
+                let key_text = &trimmed[key_start + 1..trimmed.len() - 1];
+                let key = self.parse_expression(key_text, binding_start + key_start + 1)?;
+                let key_span = Self::span(binding_start + key_start, binding_start + trimmed.len());
+                body.push(self.lowered_expression(key_span, key));
+                binding = &binding[..key_start];
+            }
+            left = Some(self.parse_each_binding(binding, binding_start)?);
+        }
+
+        let body_start = self.pos;
+        body.extend(self.parse_fragment()?);
+        let body_end = self.pos;
+        let body = self.ast.statement_block(Self::span(body_start, body_end), body);
+        let body = self.lowered(body);
+
+        let tag_end = self.parse_block_tag()?;
+        let fallback = match (tag_end.sigil, tag_end.name) {
+            (b'/', "each") => None,
+            (b':', "else") => {
+                let fallback = self.parse_block_body()?;
+                self.expect_block_end("each")?;
+                Some(fallback)
+            }
+            _ => return None,
+        };
+
+        let span = Self::span(tag.start, if fallback.is_some() { tag_end.start } else { self.pos });
+        let each = if let Some(left) = left {
+            self.ast.statement_for_of(span, false, left, list, body)
+        } else {
+            let list = self.lowered_expression(list_span, list);
+            self.ast.statement_block(span, self.ast.vec_from_array([list, body]))
+        };
+        statements.push(self.lowered(each));
+        statements.extend(fallback);
+        Some(())
+    }
+
+    /// Declare the item and the index of an `{#each}` block as `const [item, index]`.
+    fn parse_each_binding(&self, text: &str, start: usize) -> Option<ForStatementLeft<'a>> {
+        let mut params = self.parse_params(&format!("({text})"), start - 1)?;
+        if params.rest.is_some() {
+            return None;
+        }
+        let pattern = match params.items.len() {
+            1 => params.items.pop()?.pattern,
+            2 => {
+                let span = Span::new(params.items[0].span.start, params.items[1].span.end);
+                let elements = self
+                    .ast
+                    .vec_from_iter(params.items.into_iter().map(|param| Some(param.pattern)));
+                let kind = self.ast.binding_pattern_kind_array_pattern(span, elements, NONE);
+                self.ast.binding_pattern(kind, NONE, false)
+            }
+            _ => return None,
+        };
+        let span = pattern.span();
+        let kind = VariableDeclarationKind::Const;
+        let declarator = self.ast.variable_declarator(span, kind, pattern, None, false);
+        Some(self.ast.for_statement_left_variable_declaration(
+            span,
+            kind,
+            self.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// `{#await promise}…{:then value}…{:catch error}…{/await}`, where the
+    /// pending part can be skipped with `{#await promise then value}` or
+    /// `{#await promise catch error}`.
+    fn parse_await(&mut self, tag: &BlockTag<'a>) -> Option<Statement<'a>> {
+        let header = tag.header;
+        let then = find_keyword(header, "then").map(|i| (i, 4));
+        let catch = find_keyword(header, "catch").map(|i| (i, 5));
+        let (promise, mut branch) = match then.into_iter().chain(catch).min() {
+            Some((i, len)) => {
+                let binding = (&header[i + len..], tag.header_start + i + len);
+                let branch = if len == 4 {
+                    AwaitBranch::Then(binding.0, binding.1)
+                } else {
+                    AwaitBranch::Catch(binding.0, binding.1)
+                };
+                (&header[..i], branch)
+            }
+            None => (header, AwaitBranch::Pending),
+        };
+        let promise_span =
+            Self::span(tag.header_start, tag.header_start + promise.trim_end().len());
+        let promise = self.parse_expression(promise, tag.header_start)?;
+
+        let mut value = None;
+        let mut catch = None;
+        let mut try_body = self.ast.vec();
+        let mut catch_body = self.ast.vec();
+        let mut catch_start = tag.start;
+        loop {
+            match branch {
+                AwaitBranch::Pending => {}
+                AwaitBranch::Then(binding, start) => {
+                    if !binding.trim().is_empty() {
+                        value = Some(self.parse_pattern(binding, start)?);
+                    }
+                }
+                AwaitBranch::Catch(binding, start) => {
+                    let error = if binding.trim().is_empty() {
+                        None
+                    } else {
+                        Some(self.parse_pattern(binding, start)?)
+                    };
+                    catch = Some(error);
+                }
+            }
+            let children = self.parse_fragment()?;
+            if catch.is_some() {
+                catch_body.extend(children);
+            } else {
+                try_body.extend(children);
+            }
+            let next = self.parse_block_tag()?;
+            branch = match (next.sigil, next.name) {
+                (b':', "then") => AwaitBranch::Then(next.header, next.header_start),
+                (b':', "catch") if catch.is_none() => {
+                    catch_start = next.start;
+                    AwaitBranch::Catch(next.header, next.header_start)
+                }
+                (b'/', "await") => break,
+                _ => return None,
+            };
+        }
+
+        let promise = match value {
+            Some(pattern) => {
+                let kind = VariableDeclarationKind::Const;
+                let declarator =
+                    self.ast.variable_declarator(promise_span, kind, pattern, Some(promise), false);
+                let declaration = self.ast.declaration_variable(
+                    promise_span,
+                    kind,
+                    self.ast.vec1(declarator),
+                    false,
+                );
+                self.lowered(Statement::from(declaration))
+            }
+            None => self.lowered_expression(promise_span, promise),
+        };
+        try_body.insert(0, promise);
+
+        let span = Self::span(tag.start, self.pos);
+        let statement = match catch {
+            Some(error) => {
+                let block = self.ast.block_statement(Self::span(tag.start, catch_start), try_body);
+                let catch_span = Self::span(catch_start, self.pos);
+                let param = error.map(|pattern| self.ast.catch_parameter(pattern.span(), pattern));
+                let body = self.ast.block_statement(catch_span, catch_body);
+                let handler = self.ast.catch_clause(catch_span, param, body);
+                self.ast.statement_try(span, block, Some(handler), NONE)
+            }
+            None => self.ast.statement_block(span, try_body),
+        };
+        Some(self.lowered(statement))
+    }
+
+    /// `{#snippet name(params)}…{/snippet}`
+    fn parse_snippet(&mut self, tag: &BlockTag<'a>) -> Option<Statement<'a>> {
+        let header = tag.header.trim_start();
+        let name_start = tag.header_start + (tag.header.len() - header.len());
+        let params_start = header.find(['(', '<'])?;
+        let name = header[..params_start].trim_end();
+        if !is_identifier_name(name) {
+            return None;
+        }
+        let mut params =
+            self.parse_params(header[params_start..].trim_end(), name_start + params_start)?;
+        params.kind = FormalParameterKind::FormalParameter;
+
+        let body_start = self.pos;
+        let body = self.parse_fragment()?;
+        let body = self.ast.function_body(Self::span(body_start, self.pos), self.ast.vec(), body);
+        self.expect_block_end("snippet")?;
+
+        let id = self.ast.binding_identifier(Self::span(name_start, name_start + name.len()), name);
+        let function = self.ast.declaration_function(
+            Self::span(tag.start, self.pos),
+            FunctionType::FunctionDeclaration,
+            Some(id),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            self.ast.alloc(params),
+            NONE,
+            Some(self.ast.alloc(body)),
+        );
+        Some(self.lowered(Statement::from(function)))
+    }
+
+    /// Lower the special tag starting with `{@` at the current position.
+    pub(super) fn parse_tag(&mut self, statements: &mut ArenaVec<'a, Statement<'a>>) -> Option<()> {
+        let tag = self.parse_block_tag()?;
+        match tag.name {
+            // `{@html content}`, `{@render snippet()}`
+            "html" | "render" => {
+                let expression = self.parse_expression(tag.header, tag.header_start)?;
+                let statement =
+                    self.lowered_expression(Self::span(tag.start, self.pos), expression);
+                statements.push(statement);
+            }
+            // `{@debug a, b}`
+            "debug" => {
+                let mut start = tag.header_start;
+                for name in tag.header.split(',') {
+                    if !name.trim().is_empty() {
+                        let expression = self.parse_expression(name, start)?;
+                        let statement = self.lowered_expression(expression.span(), expression);
+                        statements.push(statement);
+                    }
+                    start += name.len() + 1;
+                }
+            }
+            // `{@const x = y}` is parsed as is.
+            "const" => {
+                let start = tag.start + 2;
+                let text = &self.source_text[start..tag.header_start + tag.header.len()];
+                let declaration = self.parse_statements(text, start)?;
+                if declaration.len() != 1
+                    || !matches!(declaration[0], Statement::VariableDeclaration(_))
+                {
+                    return None;
+                }
+                statements.extend(declaration);
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+
+    /// Lower an attribute or a directive, e.g. `on:click|once={handler}`.
+    ///
+    /// <https://svelte.dev/docs/svelte/basic-markup#Element-attributes>
+    pub(super) fn lower_svelte_attribute(
+        &mut self,
+        name: &'a str,
+        name_start: usize,
+        value: Option<(Expression<'a>, Span)>,
+        span: Span,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        scope: &mut ArenaVec<'a, Statement<'a>>,
+    ) -> Option<()> {
+        let Some((directive, argument)) = name.split_once(':') else {
+            if let Some((expression, span)) = value {
+                statements.push(self.lowered_expression(span, expression));
+            }
+            return Some(());
+        };
+        // Strip modifiers, e.g. `on:click|preventDefault`.
+        let argument = argument.split('|').next().unwrap_or_default();
+        let argument_start = name_start + directive.len() + 1;
+        let argument_span = Self::span(argument_start, argument_start + argument.len());
+        // `bind:value`, `class:active` and `style:color` are shorthands for an expression of the same name.
+        let shorthand = || {
+            is_identifier_name(argument)
+                .then(|| self.ast.expression_identifier(argument_span, argument))
+        };
+
+        match directive {
+            // Bindings are read and written to by the element, lowered to `target; [target] = [];`.
+            "bind" => {
+                let Some(target) = value.map(|(expression, _)| expression).or_else(shorthand)
+                else {
+                    return Some(());
+                };
+                let target_span = target.span();
+                let read = target.clone_in(self.allocator);
+                statements.push(self.lowered_expression(target_span, read));
+                let statement = match Self::assignment_target(target) {
+                    Ok(target) => {
+                        let elements =
+                            self.ast.vec1(Some(AssignmentTargetMaybeDefault::from(target)));
+                        let pattern = self.ast.assignment_target_pattern_array_assignment_target(
+                            target_span,
+                            elements,
+                            None,
+                        );
+                        let assignment = self.ast.expression_assignment(
+                            span,
+                            AssignmentOperator::Assign,
+                            AssignmentTarget::from(pattern),
+                            self.ast.expression_array(span, self.ast.vec()),
+                        );
+                        self.lowered_expression(span, assignment)
+                    }
+                    Err(target) => self.lowered_expression(span, target),
+                };
+                statements.push(statement);
+            }
+            // Actions, transitions and animations are called with the element and the value.
+            "use" | "transition" | "in" | "out" | "animate" => {
+                let callee = self.parse_expression(argument, argument_start)?;
+                let arguments =
+                    self.ast.vec_from_iter(value.map(|(expression, _)| Argument::from(expression)));
+                let call = self.ast.expression_call(span, callee, NONE, arguments, false);
+                statements.push(self.lowered_expression(span, call));
+            }
+            // Slot props, in scope of the children.
+            "let" => {
+                let pattern = if let Some((_, value_span)) = value {
+                    let start = value_span.start as usize + 1;
+                    let text = &self.source_text[start..value_span.end as usize - 1];
+                    self.parse_pattern(text, start)?
+                } else {
+                    let id =
+                        self.ast.binding_pattern_kind_binding_identifier(argument_span, argument);
+                    self.ast.binding_pattern(id, NONE, false)
+                };
+                let kind = VariableDeclarationKind::Let;
+                let declarator = self.ast.variable_declarator(span, kind, pattern, None, false);
+                let declaration =
+                    self.ast.declaration_variable(span, kind, self.ast.vec1(declarator), false);
+                scope.push(self.lowered(Statement::from(declaration)));
+            }
+            "class" | "style" => {
+                if let Some(expression) = value.map(|(expression, _)| expression).or_else(shorthand)
+                {
+                    let span = expression.span();
+                    statements.push(self.lowered_expression(span, expression));
+                }
+            }
+            _ => {
+                if let Some((expression, span)) = value {
+                    statements.push(self.lowered_expression(span, expression));
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn assignment_target(
+        expression: Expression<'a>,
+    ) -> Result<AssignmentTarget<'a>, Expression<'a>> {
+        let target = match expression {
+            Expression::Identifier(ident) => {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident)
+            }
+            expression if expression.is_member_expression() => {
+                SimpleAssignmentTarget::from(expression.into_member_expression())
+            }
+            expression => return Err(expression),
+        };
+        Ok(AssignmentTarget::from(target))
+    }
+}
+
+/// Find the `(` opening the key at the end of an `{#each}` binding.
+fn find_key_start(text: &str) -> Option<usize> {
+    let mut depth = 0u32;
+    for (i, b) in text.bytes().enumerate().rev() {
+        match b {
+            b')' => depth += 1,
+            b'(' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
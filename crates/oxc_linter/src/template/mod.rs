@@ -0,0 +1,111 @@
+//! Templates of framework files, such as the `<template>` block of Vue
+//! single-file components or the markup of Svelte components.
+//!
+//! [`PartialLoader`](crate::loader::PartialLoader) extracts each `<script>`
+//! block of a framework file as a separate source section. When a file also
+//! has a template, all of its script blocks are merged into a single section
+//! spanning the whole file instead, so that template nodes and script nodes
+//! share one coordinate space and rules can relate the two.
+
+use oxc_allocator::Allocator;
+use oxc_ast_visit::VisitMut;
+use oxc_span::{SourceType, Span};
+
+use crate::loader::JavaScriptSource;
+
+pub mod markup;
+pub mod vue;
+
+use markup::Markup;
+use vue::VueTemplate;
+
+/// <https://html.spec.whatwg.org/multipage/syntax.html#void-elements>
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Template of the framework file being linted, attached to the merged section of its scripts.
+#[derive(Debug)]
+pub enum Template<'a> {
+    Vue(VueTemplate<'a>),
+    /// Svelte or Astro markup, lowered into the program of the merged section.
+    Markup(Markup),
+}
+
+/// Moves every span in an AST by a fixed offset.
+///
+/// Used to map nodes parsed from a slice of the source text back to
+/// positions in the whole file.
+pub struct SpanOffset(pub u32);
+
+impl VisitMut<'_> for SpanOffset {
+    fn visit_span(&mut self, span: &mut Span) {
+        span.start += self.0;
+        span.end += self.0;
+    }
+}
+
+/// Merge the script sections of a framework file into a single section spanning the whole file.
+///
+/// Returns the merged section and the text to parse it from. The text has the
+/// same length as `source_text`, with everything outside of `sections`
+/// replaced by whitespace, so that parsed spans are relative to the start of
+/// the file. Line breaks are preserved to keep line numbers intact.
+pub fn merge_sections<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    sections: &[JavaScriptSource<'a>],
+) -> (JavaScriptSource<'a>, &'a str) {
+    let mut masked = source_text
+        .bytes()
+        .map(|b| if matches!(b, b'\n' | b'\r') { b } else { b' ' })
+        .collect::<Vec<_>>();
+    for section in sections {
+        let start = section.start as usize;
+        let end = start + section.source_text.len();
+        masked[start..end].copy_from_slice(section.source_text.as_bytes());
+        // Separate the section from the next one with a line break, so that automatic semicolon
+        // insertion terminates its last statement. An explicit `;` would become part of the
+        // statement's span.
+        if let Some(b) = masked.get_mut(end) {
+            *b = b'\n';
+        }
+    }
+    // SAFETY: Multi-byte characters are either copied whole from `source_text`
+    // or replaced with one ASCII space per byte, so the result is valid UTF-8.
+    let masked = allocator.alloc_str(unsafe { std::str::from_utf8_unchecked(&masked) });
+
+    // TypeScript is a superset of JavaScript, so use it if any section needs it.
+    let source_type = sections
+        .iter()
+        .map(|section| section.source_type)
+        .find(|source_type| source_type.is_typescript())
+        .or_else(|| sections.first().map(|section| section.source_type))
+        .unwrap_or_else(SourceType::mjs);
+
+    (JavaScriptSource::partial(source_text, source_type, 0), masked)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::SourceType;
+
+    use super::merge_sections;
+    use crate::loader::PartialLoader;
+
+    #[test]
+    fn merge_vue_sections() {
+        let allocator = Allocator::default();
+        let source_text = "<template>{{ a }}</template>\n<script>a</script><script setup lang=\"ts\">let 日: T</script>";
+        let sections = PartialLoader::parse("vue", source_text).unwrap();
+        let (section, masked) = merge_sections(&allocator, source_text, &sections);
+
+        assert_eq!(section.start, 0);
+        assert_eq!(section.source_text, source_text);
+        assert_eq!(section.source_type, SourceType::ts());
+        assert_eq!(masked.len(), source_text.len());
+        assert_eq!(masked.split_whitespace().collect::<Vec<_>>(), ["a", "let", "日:", "T"]);
+    }
+}
@@ -0,0 +1,391 @@
+//! AST for the `<template>` block of Vue single-file components.
+//!
+//! The markup structure is parsed by [`VueTemplateParser`], while every
+//! expression inside it (interpolations, directive values, `v-for` aliases,
+//! slot props) is parsed by [`oxc_parser`]. All spans are relative to the
+//! start of the `.vue` file.
+//!
+//! <https://vuejs.org/api/sfc-spec.html#template>
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::ast::{
+    BindingPattern, BindingPatternKind, Expression, FormalParameters, IdentifierReference,
+    Statement,
+};
+use oxc_ast_visit::Visit;
+use oxc_span::{GetSpan, Span};
+
+mod parser;
+
+pub use parser::VueTemplateParser;
+
+/// Root of a parsed Vue `<template>` block.
+#[derive(Debug)]
+pub struct VueTemplate<'a> {
+    /// Span of the template content, excluding the `<template>` and `</template>` tags.
+    pub span: Span,
+    pub children: ArenaVec<'a, VueNode<'a>>,
+    /// Names of free variables referenced from the template, including
+    /// components used as tags and custom directives.
+    references: FxHashSet<&'a str>,
+}
+
+impl<'a> VueTemplate<'a> {
+    /// Returns `true` if `name` is referenced anywhere in the template without
+    /// being shadowed by a `v-for` alias or a slot prop.
+    ///
+    /// `<script setup>` bindings referenced this way are used by the compiled
+    /// render function.
+    pub fn references(&self, name: &str) -> bool {
+        self.references.contains(name)
+    }
+
+    /// Visit every element in document order, along with its ancestors
+    /// (outermost first).
+    pub fn for_each_element<F>(&self, mut f: F)
+    where
+        F: FnMut(&VueElement<'a>, &[&VueElement<'a>]),
+    {
+        fn walk<'a, 'e, F>(
+            nodes: &'e [VueNode<'a>],
+            ancestors: &mut Vec<&'e VueElement<'a>>,
+            f: &mut F,
+        ) where
+            F: FnMut(&VueElement<'a>, &[&VueElement<'a>]),
+        {
+            for node in nodes {
+                if let VueNode::Element(element) = node {
+                    f(element, ancestors);
+                    ancestors.push(element);
+                    walk(&element.children, ancestors, f);
+                    ancestors.pop();
+                }
+            }
+        }
+
+        walk(&self.children, &mut vec![], &mut f);
+    }
+}
+
+#[derive(Debug)]
+pub enum VueNode<'a> {
+    Element(ArenaBox<'a, VueElement<'a>>),
+    /// `{{ expression }}`
+    Interpolation(ArenaBox<'a, VueInterpolation<'a>>),
+    Text(Span),
+    /// `<!-- comment -->`
+    Comment(Span),
+}
+
+#[derive(Debug)]
+pub struct VueInterpolation<'a> {
+    /// Span including the `{{` and `}}` delimiters.
+    pub span: Span,
+    /// `None` if the expression failed to parse.
+    pub expression: Option<Expression<'a>>,
+}
+
+#[derive(Debug)]
+pub struct VueElement<'a> {
+    pub span: Span,
+    /// Tag name as written in the source, e.g. `div`, `MyComponent` or `my-component`.
+    pub name: &'a str,
+    pub name_span: Span,
+    pub attributes: ArenaVec<'a, VueAttribute<'a>>,
+    pub children: ArenaVec<'a, VueNode<'a>>,
+}
+
+impl<'a> VueElement<'a> {
+    pub fn directives(&self) -> impl Iterator<Item = &VueDirective<'a>> {
+        self.attributes.iter().filter_map(|attr| match attr {
+            VueAttribute::Directive(directive) => Some(&**directive),
+            VueAttribute::Static(_) => None,
+        })
+    }
+
+    /// Find a directive by its name without the `v-` prefix, e.g. `for` or `if`.
+    pub fn directive(&self, name: &str) -> Option<&VueDirective<'a>> {
+        self.directives().find(|directive| directive.name == name)
+    }
+
+    /// Find a `v-bind` directive with a static argument, e.g. `:key` or `v-bind:key`.
+    pub fn bind_directive(&self, argument: &str) -> Option<&VueDirective<'a>> {
+        self.directives().find(|directive| {
+            directive.name == "bind"
+                && directive
+                    .argument
+                    .as_ref()
+                    .is_some_and(|arg| !arg.dynamic && arg.name == argument)
+        })
+    }
+
+    pub fn static_attribute(&self, name: &str) -> Option<&VueStaticAttribute<'a>> {
+        self.attributes.iter().find_map(|attr| match attr {
+            VueAttribute::Static(attr) if attr.name == name => Some(&**attr),
+            _ => None,
+        })
+    }
+
+    /// `true` for `<template>` elements nested in the root template.
+    pub fn is_template(&self) -> bool {
+        self.name == "template"
+    }
+
+    /// `true` if the element is a component rather than a native HTML element.
+    ///
+    /// Follows the heuristic of `eslint-plugin-vue`: PascalCase names, names
+    /// containing a dash, `<component>` and elements with an `is` attribute
+    /// are treated as components.
+    pub fn is_custom_component(&self) -> bool {
+        self.name.starts_with(|c: char| c.is_ascii_uppercase())
+            || self.name.contains('-')
+            || self.name == "component"
+            || self.bind_directive("is").is_some()
+            || self.static_attribute("is").is_some()
+    }
+
+    /// Names of variables introduced by this element for itself and its
+    /// children, i.e. `v-for` aliases and slot props.
+    pub fn scope_variables(&self) -> Vec<(&'a str, Span)> {
+        let mut names = vec![];
+        for directive in self.directives() {
+            match &directive.value {
+                Some(VueDirectiveValue::For(for_expr)) => bound_names(&for_expr.left, &mut names),
+                Some(VueDirectiveValue::Params(params)) => bound_names(params, &mut names),
+                _ => {}
+            }
+        }
+        names
+    }
+
+    /// Variables referenced where the [`scope_variables`](Self::scope_variables)
+    /// of this element are visible: its directives (except for the iterated
+    /// value of `v-for`) and its descendants. References to names shadowed by
+    /// descendants are excluded.
+    pub fn scope_references(&self) -> Vec<(&'a str, Span)> {
+        fn collect_nodes<'a>(
+            nodes: &[VueNode<'a>],
+            shadowed: &mut Vec<&'a str>,
+            references: &mut Vec<(&'a str, Span)>,
+        ) {
+            for node in nodes {
+                match node {
+                    VueNode::Element(element) => {
+                        collect_element(element, false, shadowed, references);
+                    }
+                    VueNode::Interpolation(interpolation) => {
+                        if let Some(expression) = &interpolation.expression {
+                            let mut collector = ReferenceCollector::default();
+                            collector.visit_expression(expression);
+                            push_unshadowed(collector.references, shadowed, references);
+                        }
+                    }
+                    VueNode::Text(_) | VueNode::Comment(_) => {}
+                }
+            }
+        }
+
+        fn collect_element<'a>(
+            element: &VueElement<'a>,
+            is_root: bool,
+            shadowed: &mut Vec<&'a str>,
+            references: &mut Vec<(&'a str, Span)>,
+        ) {
+            let shadowed_len = shadowed.len();
+            if !is_root {
+                if let Some(VueDirectiveValue::For(for_expression)) =
+                    element.directive("for").and_then(|directive| directive.value.as_ref())
+                {
+                    let mut collector = ReferenceCollector::default();
+                    collector.visit_expression(&for_expression.right);
+                    push_unshadowed(collector.references, shadowed, references);
+                }
+                shadowed.extend(element.scope_variables().into_iter().map(|(name, _)| name));
+            }
+            for directive in element.directives() {
+                let mut collector = ReferenceCollector::default();
+                collector.visit_directive(directive);
+                push_unshadowed(collector.references, shadowed, references);
+            }
+            collect_nodes(&element.children, shadowed, references);
+            shadowed.truncate(shadowed_len);
+        }
+
+        fn push_unshadowed<'a>(
+            found: Vec<(&'a str, Span)>,
+            shadowed: &[&'a str],
+            references: &mut Vec<(&'a str, Span)>,
+        ) {
+            references.extend(found.into_iter().filter(|(name, _)| !shadowed.contains(name)));
+        }
+
+        let mut references = vec![];
+        collect_element(self, true, &mut vec![], &mut references);
+        references
+    }
+}
+
+#[derive(Debug)]
+pub enum VueAttribute<'a> {
+    Static(ArenaBox<'a, VueStaticAttribute<'a>>),
+    Directive(ArenaBox<'a, VueDirective<'a>>),
+}
+
+impl GetSpan for VueAttribute<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Static(attr) => attr.span,
+            Self::Directive(directive) => directive.span,
+        }
+    }
+}
+
+/// A plain HTML attribute, e.g. `class="foo"`.
+#[derive(Debug)]
+pub struct VueStaticAttribute<'a> {
+    pub span: Span,
+    pub name: &'a str,
+    /// Attribute value without quotes. `None` for boolean attributes.
+    pub value: Option<&'a str>,
+}
+
+/// A directive, e.g. `v-if="ok"`, `:key="id"`, `@click.stop="onClick"` or `#default="props"`.
+#[derive(Debug)]
+pub struct VueDirective<'a> {
+    pub span: Span,
+    /// Directive name without the `v-` prefix. Shorthands are expanded, so
+    /// `:` is `bind`, `@` is `on` and `#` is `slot`.
+    pub name: &'a str,
+    pub argument: Option<VueDirectiveArgument<'a>>,
+    pub modifiers: ArenaVec<'a, &'a str>,
+    /// Span of the attribute value without quotes. `None` if there is no value.
+    pub value_span: Option<Span>,
+    /// `None` if there is no value or the value failed to parse.
+    pub value: Option<VueDirectiveValue<'a>>,
+}
+
+impl<'a> VueDirective<'a> {
+    /// `true` if the directive has a value which could not be parsed.
+    pub fn has_invalid_value(&self) -> bool {
+        self.value.is_none() && self.value_span.is_some_and(|span| !span.is_empty())
+    }
+
+    /// Variables referenced by the dynamic argument and the value of the directive.
+    pub fn references(&self) -> Vec<(&'a str, Span)> {
+        let mut collector = ReferenceCollector::default();
+        collector.visit_directive(self);
+        if let Some(value @ VueDirectiveValue::For(_)) = &self.value {
+            collector.visit_directive_value(value);
+        }
+        collector.references
+    }
+}
+
+#[derive(Debug)]
+pub struct VueDirectiveArgument<'a> {
+    pub span: Span,
+    /// Argument name. For dynamic arguments, the source text between the brackets.
+    pub name: &'a str,
+    /// `true` for dynamic arguments, e.g. `:[key]="value"`.
+    pub dynamic: bool,
+    /// The parsed expression of a dynamic argument. `None` for static
+    /// arguments, or if the expression failed to parse.
+    pub expression: Option<Expression<'a>>,
+}
+
+#[derive(Debug)]
+pub enum VueDirectiveValue<'a> {
+    Expression(Expression<'a>),
+    /// Inline `v-on` handler statements, e.g. `@click="count++; emit('change')"`.
+    Statements(ArenaVec<'a, Statement<'a>>),
+    /// `v-for="(item, index) in items"`
+    For(ArenaBox<'a, VueForExpression<'a>>),
+    /// Slot props, e.g. `v-slot="{ item }"` or `slot-scope="props"`.
+    Params(ArenaBox<'a, FormalParameters<'a>>),
+}
+
+#[derive(Debug)]
+pub struct VueForExpression<'a> {
+    /// Iteration aliases, e.g. `item, index` in `(item, index) in items`.
+    pub left: ArenaBox<'a, FormalParameters<'a>>,
+    /// The iterated value, e.g. `items` in `(item, index) in items`.
+    pub right: Expression<'a>,
+}
+
+/// Collect the names bound by `v-for` aliases or slot props.
+pub fn bound_names<'a>(params: &FormalParameters<'a>, names: &mut Vec<(&'a str, Span)>) {
+    fn collect<'a>(pattern: &BindingPattern<'a>, names: &mut Vec<(&'a str, Span)>) {
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                names.push((ident.name.as_str(), ident.span));
+            }
+            BindingPatternKind::ObjectPattern(object) => {
+                for property in &object.properties {
+                    collect(&property.value, names);
+                }
+                if let Some(rest) = &object.rest {
+                    collect(&rest.argument, names);
+                }
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                for element in array.elements.iter().flatten() {
+                    collect(element, names);
+                }
+                if let Some(rest) = &array.rest {
+                    collect(&rest.argument, names);
+                }
+            }
+            BindingPatternKind::AssignmentPattern(assignment) => collect(&assignment.left, names),
+        }
+    }
+
+    for param in &params.items {
+        collect(&param.pattern, names);
+    }
+    if let Some(rest) = &params.rest {
+        collect(&rest.argument, names);
+    }
+}
+
+/// Collects the names and spans of [`IdentifierReference`]s in template expressions.
+#[derive(Default)]
+pub struct ReferenceCollector<'a> {
+    pub references: Vec<(&'a str, Span)>,
+}
+
+impl<'a> ReferenceCollector<'a> {
+    /// Visit the dynamic argument and the value of a directive, except for
+    /// `v-for` values, whose aliases and iterated value are in different scopes.
+    pub fn visit_directive(&mut self, directive: &VueDirective<'a>) {
+        if let Some(expression) =
+            directive.argument.as_ref().and_then(|argument| argument.expression.as_ref())
+        {
+            self.visit_expression(expression);
+        }
+        match &directive.value {
+            Some(VueDirectiveValue::For(_)) | None => {}
+            Some(value) => self.visit_directive_value(value),
+        }
+    }
+
+    pub fn visit_directive_value(&mut self, value: &VueDirectiveValue<'a>) {
+        match value {
+            VueDirectiveValue::Expression(expr) => self.visit_expression(expr),
+            VueDirectiveValue::Statements(stmts) => self.visit_statements(stmts),
+            VueDirectiveValue::For(for_expr) => {
+                // Aliases only bind names, but their default values may reference variables.
+                self.visit_formal_parameters(&for_expr.left);
+                self.visit_expression(&for_expr.right);
+            }
+            VueDirectiveValue::Params(params) => self.visit_formal_parameters(params),
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.references.push((it.name.as_str(), it.span));
+    }
+}
@@ -0,0 +1,749 @@
+use memchr::memmem::Finder;
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Allocator, Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::ast::{Expression, FormalParameters, Statement};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+use super::{
+    ReferenceCollector, VueAttribute, VueDirective, VueDirectiveArgument, VueDirectiveValue,
+    VueElement, VueForExpression, VueInterpolation, VueNode, VueStaticAttribute, VueTemplate,
+};
+use crate::{
+    loader::find_script_closing_angle,
+    template::{SpanOffset, VOID_ELEMENTS},
+};
+
+/// Directives provided by Vue itself. Any other `v-` attribute is a custom directive
+/// which must be in scope as `vNameOfDirective`.
+const BUILTIN_DIRECTIVES: [&str; 16] = [
+    "bind", "cloak", "else", "else-if", "for", "html", "if", "is", "memo", "model", "on", "once",
+    "pre", "show", "slot", "text",
+];
+
+/// Parser for the root `<template>` block of a Vue single-file component.
+///
+/// This is not a spec-compliant HTML parser. It recognizes elements, text,
+/// comments and `{{ }}` interpolations, which is enough to give lint rules the
+/// structure of the template. End tags close the nearest matching open
+/// element, and unmatched end tags are ignored.
+pub struct VueTemplateParser<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    /// Source type for expressions. TypeScript if any `<script>` block uses TypeScript.
+    source_type: SourceType,
+    pos: usize,
+    /// Names of currently open elements, innermost last.
+    open_elements: Vec<&'a str>,
+    /// Start of the `</template>` tag closing the root template.
+    root_end_tag_start: Option<usize>,
+}
+
+impl<'a> VueTemplateParser<'a> {
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
+        Self {
+            allocator,
+            source_text,
+            source_type: source_type.with_jsx(false),
+            pos: 0,
+            open_elements: vec![],
+            root_end_tag_start: None,
+        }
+    }
+
+    /// Returns `None` if there is no `<template>` block, or if it is written
+    /// in a language other than HTML, e.g. `<template lang="pug">`.
+    pub fn parse(mut self) -> Option<VueTemplate<'a>> {
+        self.pos = self.find_root_template()?;
+        let start = self.pos;
+
+        self.open_elements.push("template");
+        let children = self.parse_children();
+        // Exclude `</template>`, if the template is closed.
+        let end = self.root_end_tag_start.unwrap_or(self.pos);
+
+        let mut template = VueTemplate {
+            span: Span::new(Self::offset(start), Self::offset(end)),
+            children,
+            references: FxHashSet::default(),
+        };
+        let mut references = FxHashSet::default();
+        self.collect_references(&template.children, &mut vec![], &mut references);
+        template.references = references;
+        Some(template)
+    }
+
+    // NOTE: the loader checked that source_text.len() is less than u32::MAX
+    #[expect(clippy::cast_possible_truncation)]
+    fn offset(pos: usize) -> u32 {
+        pos as u32
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source_text[self.pos..]
+    }
+
+    /// Find the top-level `<template>` block, skipping over `<script>`,
+    /// `<style>` and custom blocks which may contain the text `<template`.
+    ///
+    /// Returns the offset of the template content.
+    fn find_root_template(&self) -> Option<usize> {
+        let source_text = self.source_text;
+        let mut pos = 0;
+        loop {
+            pos += source_text[pos..].find('<')?;
+            let rest = &source_text[pos..];
+            if rest.starts_with("<!--") {
+                pos += rest.find("-->").map_or(rest.len(), |end| end + 3);
+                continue;
+            }
+
+            let name_len = rest[1..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+                .unwrap_or(rest.len() - 1);
+            if name_len == 0 {
+                pos += 1;
+                continue;
+            }
+            let name = &rest[1..=name_len];
+            let name_end = pos + 1 + name_len;
+            let closing_angle = name_end + find_script_closing_angle(source_text, name_end)?;
+
+            if name == "template" {
+                let attributes = &source_text[name_end..closing_angle];
+                if attributes.ends_with('/') || !is_html_lang(attributes) {
+                    return None;
+                }
+                return Some(closing_angle + 1);
+            }
+
+            // Skip the whole block.
+            let end_tag = format!("</{name}");
+            pos = Finder::new(&end_tag)
+                .find(&source_text.as_bytes()[closing_angle..])
+                .map_or(closing_angle + 1, |offset| closing_angle + offset + end_tag.len());
+        }
+    }
+
+    fn parse_children(&mut self) -> ArenaVec<'a, VueNode<'a>> {
+        let mut children = ArenaVec::new_in(self.allocator);
+        while self.pos < self.source_text.len() {
+            let rest = self.rest();
+            if let Some(end_tag) = rest.strip_prefix("</") {
+                let name_len = end_tag
+                    .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                    .unwrap_or(end_tag.len());
+                let name = &end_tag[..name_len];
+                let end = self.pos + rest.find('>').map_or(rest.len(), |i| i + 1);
+                match self.open_elements.iter().rposition(|open| open.eq_ignore_ascii_case(name)) {
+                    Some(index) => {
+                        // An end tag for an ancestor implicitly closes the current element,
+                        // and is consumed by the ancestor.
+                        if index == self.open_elements.len() - 1 {
+                            if index == 0 {
+                                self.root_end_tag_start = Some(self.pos);
+                            }
+                            self.pos = end;
+                        }
+                        return children;
+                    }
+                    // Stray end tag
+                    None => self.pos = end,
+                }
+            } else if rest.starts_with("<!--") {
+                let len = rest.find("-->").map_or(rest.len(), |end| end + 3);
+                let span = Span::new(Self::offset(self.pos), Self::offset(self.pos + len));
+                children.push(VueNode::Comment(span));
+                self.pos += len;
+            } else if rest.starts_with('<')
+                && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+            {
+                let element = self.parse_element();
+                children.push(VueNode::Element(ArenaBox::new_in(element, self.allocator)));
+            } else if rest.starts_with("{{") {
+                let interpolation = self.parse_interpolation();
+                children
+                    .push(VueNode::Interpolation(ArenaBox::new_in(interpolation, self.allocator)));
+            } else {
+                let start = self.pos;
+                // Always consume at least one character, e.g. a `<` which does not start a tag.
+                let first_len = rest.chars().next().map_or(1, char::len_utf8);
+                let len = rest[first_len..]
+                    .find(['<', '{'])
+                    .map_or(rest.len(), |offset| offset + first_len);
+                self.pos += len;
+                // Merge with a preceding text node if we stopped at a lone `<` or `{`.
+                if let Some(VueNode::Text(span)) = children.last_mut() {
+                    if span.end == Self::offset(start) {
+                        span.end = Self::offset(self.pos);
+                        continue;
+                    }
+                }
+                children
+                    .push(VueNode::Text(Span::new(Self::offset(start), Self::offset(self.pos))));
+            }
+        }
+        children
+    }
+
+    fn parse_interpolation(&mut self) -> VueInterpolation<'a> {
+        let start = self.pos;
+        let content_start = start + 2;
+        let rest = &self.source_text[content_start..];
+        let (content_end, end) = match rest.find("}}") {
+            Some(offset) => (content_start + offset, content_start + offset + 2),
+            None => (self.source_text.len(), self.source_text.len()),
+        };
+        self.pos = end;
+        let expression =
+            self.parse_expression(&self.source_text[content_start..content_end], content_start);
+        VueInterpolation { span: Span::new(Self::offset(start), Self::offset(end)), expression }
+    }
+
+    fn parse_element(&mut self) -> VueElement<'a> {
+        let start = self.pos;
+        let rest = self.rest();
+        let name_len = rest[1..]
+            .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+            .unwrap_or(rest.len() - 1);
+        let name = &rest[1..=name_len];
+        let name_span = Span::new(Self::offset(start + 1), Self::offset(start + 1 + name_len));
+        self.pos += 1 + name_len;
+
+        let (attributes, self_closing) = self.parse_attributes();
+        let is_void = VOID_ELEMENTS.iter().any(|void| void.eq_ignore_ascii_case(name));
+        let is_raw_text = ["script", "style"].iter().any(|raw| raw.eq_ignore_ascii_case(name))
+            || attributes.iter().any(
+                |attr| matches!(attr, VueAttribute::Directive(directive) if directive.name == "pre"),
+            );
+
+        let children = if self_closing || is_void {
+            ArenaVec::new_in(self.allocator)
+        } else if is_raw_text {
+            // The content is not compiled by Vue, skip to the end tag.
+            let end_tag = format!("</{name}");
+            self.pos = Finder::new(&end_tag).find(self.rest().as_bytes()).map_or(
+                self.source_text.len(),
+                |offset| {
+                    let end = self.pos + offset;
+                    end + self.source_text[end..].find('>').map_or(end_tag.len(), |i| i + 1)
+                },
+            );
+            ArenaVec::new_in(self.allocator)
+        } else {
+            self.open_elements.push(name);
+            let children = self.parse_children();
+            self.open_elements.pop();
+            children
+        };
+
+        VueElement {
+            span: Span::new(Self::offset(start), Self::offset(self.pos)),
+            name,
+            name_span,
+            attributes,
+            children,
+        }
+    }
+
+    /// Parse attributes up to and including the end of the start tag.
+    /// Returns the attributes and whether the tag is self-closing.
+    fn parse_attributes(&mut self) -> (ArenaVec<'a, VueAttribute<'a>>, bool) {
+        let mut attributes = ArenaVec::new_in(self.allocator);
+        loop {
+            self.pos += self.rest().len() - self.rest().trim_start().len();
+            let rest = self.rest();
+            if rest.is_empty() {
+                return (attributes, false);
+            }
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return (attributes, true);
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                return (attributes, false);
+            }
+
+            let name_start = self.pos;
+            let mut name_len = 0;
+            let mut in_brackets = false;
+            for (offset, c) in rest.char_indices() {
+                match c {
+                    '[' => in_brackets = true,
+                    ']' => in_brackets = false,
+                    '=' | '>' if !in_brackets => break,
+                    '/' if !in_brackets && rest[offset..].starts_with("/>") => break,
+                    c if c.is_ascii_whitespace() && !in_brackets => break,
+                    _ => {}
+                }
+                name_len = offset + c.len_utf8();
+            }
+            if name_len == 0 {
+                // Stray `/` or `=`
+                self.pos += 1;
+                continue;
+            }
+            let name = &rest[..name_len];
+            self.pos += name_len;
+
+            let after_name = self.rest().trim_start();
+            let mut value = None;
+            if let Some(after_eq) = after_name.strip_prefix('=') {
+                let after_eq_trimmed = after_eq.trim_start();
+                let value_start = self.source_text.len() - after_eq_trimmed.len();
+                if let Some(quote @ ('"' | '\'')) = after_eq_trimmed.chars().next() {
+                    let content_start = value_start + 1;
+                    let content_len = self.source_text[content_start..]
+                        .find(quote)
+                        .unwrap_or(self.source_text.len() - content_start);
+                    value = Some((content_start, content_len));
+                    self.pos = (content_start + content_len + 1).min(self.source_text.len());
+                } else {
+                    let len = after_eq_trimmed
+                        .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                        .unwrap_or(after_eq_trimmed.len());
+                    value = Some((value_start, len));
+                    self.pos = value_start + len;
+                }
+            }
+
+            let span = Span::new(Self::offset(name_start), Self::offset(self.pos));
+            attributes.push(self.build_attribute(name, name_start, value, span));
+        }
+    }
+
+    fn build_attribute(
+        &self,
+        name: &'a str,
+        name_start: usize,
+        value: Option<(usize, usize)>,
+        span: Span,
+    ) -> VueAttribute<'a> {
+        let value_text = value.map(|(start, len)| &self.source_text[start..start + len]);
+        let value_span =
+            value.map(|(start, len)| Span::new(Self::offset(start), Self::offset(start + len)));
+
+        // Split the name into the directive name and the remaining argument and modifiers.
+        let (directive_name, rest, rest_start, has_argument) =
+            if let Some(after_prefix) = name.strip_prefix("v-") {
+                let len = after_prefix.find([':', '.']).unwrap_or(after_prefix.len());
+                let after_name = &after_prefix[len..];
+                match after_name.strip_prefix(':') {
+                    Some(rest) => (&after_prefix[..len], rest, 3 + len, true),
+                    None => (&after_prefix[..len], after_name, 2 + len, false),
+                }
+            } else if let Some(rest) = name.strip_prefix([':', '.']) {
+                ("bind", rest, 1, true)
+            } else if let Some(rest) = name.strip_prefix('@') {
+                ("on", rest, 1, true)
+            } else if let Some(rest) = name.strip_prefix('#') {
+                ("slot", rest, 1, true)
+            } else if name == "slot-scope" || name == "scope" {
+                // Vue 2 slot props
+                ("slot-scope", "", name.len(), false)
+            } else {
+                let attribute = VueStaticAttribute { span, name, value: value_text };
+                return VueAttribute::Static(ArenaBox::new_in(attribute, self.allocator));
+            };
+
+        let mut argument = None;
+        let mut modifiers_text = rest;
+        if has_argument && !rest.is_empty() && !rest.starts_with('.') {
+            let (arg_name, arg_len, dynamic) = if let Some(dynamic) = rest.strip_prefix('[') {
+                let len = dynamic.find(']').unwrap_or(dynamic.len());
+                (&dynamic[..len], (len + 2).min(rest.len()), true)
+            } else {
+                let len = rest.find('.').unwrap_or(rest.len());
+                (&rest[..len], len, false)
+            };
+            let arg_start = name_start + rest_start;
+            let expression =
+                if dynamic { self.parse_expression(arg_name, arg_start + 1) } else { None };
+            argument = Some(VueDirectiveArgument {
+                span: Span::new(Self::offset(arg_start), Self::offset(arg_start + arg_len)),
+                name: arg_name,
+                dynamic,
+                expression,
+            });
+            modifiers_text = &rest[arg_len..];
+        }
+
+        let mut modifiers = ArenaVec::new_in(self.allocator);
+        if name.starts_with('.') {
+            // `.prop` shorthand for `v-bind:prop.prop`
+            modifiers.push("prop");
+        }
+        modifiers.extend(modifiers_text.split('.').filter(|modifier| !modifier.is_empty()));
+
+        let value = match (value_text, value) {
+            (Some(text), Some((start, _))) if !text.trim().is_empty() => match directive_name {
+                "for" => self.parse_for(text, start),
+                "slot" | "slot-scope" => self.parse_params(text, start).map(|params| {
+                    VueDirectiveValue::Params(ArenaBox::new_in(params, self.allocator))
+                }),
+                "on" => self.parse_handler(text, start),
+                _ => self.parse_expression(text, start).map(VueDirectiveValue::Expression),
+            },
+            _ => None,
+        };
+
+        let directive =
+            VueDirective { span, name: directive_name, argument, modifiers, value_span, value };
+        VueAttribute::Directive(ArenaBox::new_in(directive, self.allocator))
+    }
+
+    /// Parse an expression from a slice of the source text starting at `start`.
+    fn parse_expression(&self, text: &'a str, start: usize) -> Option<Expression<'a>> {
+        let mut expression =
+            Parser::new(self.allocator, text, self.source_type).parse_expression().ok()?;
+        // `parse_expression` stops at the end of the expression, and does not
+        // report trailing tokens.
+        if expression.span().end as usize != text.trim_end().len() {
+            return None;
+        }
+        SpanOffset(Self::offset(start)).visit_expression(&mut expression);
+        Some(expression)
+    }
+
+    /// `v-on` values are either a handler expression or inline statements.
+    fn parse_handler(&self, text: &'a str, start: usize) -> Option<VueDirectiveValue<'a>> {
+        if let Some(expression) = self.parse_expression(text, start) {
+            return Some(VueDirectiveValue::Expression(expression));
+        }
+        self.parse_statements(text, start).map(VueDirectiveValue::Statements)
+    }
+
+    fn parse_statements(&self, text: &'a str, start: usize) -> Option<ArenaVec<'a, Statement<'a>>> {
+        let ret = Parser::new(self.allocator, text, self.source_type).parse();
+        if !ret.errors.is_empty() || ret.panicked {
+            return None;
+        }
+        let mut statements = ret.program.body;
+        SpanOffset(Self::offset(start)).visit_statements(&mut statements);
+        Some(statements)
+    }
+
+    /// Parse `v-for` aliases or slot props as the parameters of an arrow function.
+    fn parse_params(&self, text: &'a str, start: usize) -> Option<FormalParameters<'a>> {
+        let text = text.trim_end();
+        let (source, base) = if text.trim_start().starts_with('(') && text.ends_with(')') {
+            (self.allocator.alloc_str(&format!("{text}=>0")), start)
+        } else {
+            // `start` is never 0, the value is preceded by at least the attribute name and `=`.
+            (self.allocator.alloc_str(&format!("({text})=>0")), start - 1)
+        };
+        let Expression::ArrowFunctionExpression(arrow) =
+            Parser::new(self.allocator, source, self.source_type).parse_expression().ok()?
+        else {
+            return None;
+        };
+        let mut params = arrow.unbox().params.unbox();
+        SpanOffset(Self::offset(base)).visit_formal_parameters(&mut params);
+        Some(params)
+    }
+
+    /// `v-for="alias in expression"`, where `in` may also be `of`.
+    ///
+    /// <https://vuejs.org/api/built-in-directives.html#v-for>
+    fn parse_for(&self, text: &'a str, start: usize) -> Option<VueDirectiveValue<'a>> {
+        let bytes = text.as_bytes();
+        let (left_end, right_start) = bytes.iter().enumerate().find_map(|(i, b)| {
+            if !b.is_ascii_whitespace() {
+                return None;
+            }
+            let after_ws = text[i..].trim_start();
+            let keyword_start = text.len() - after_ws.len();
+            let after_keyword =
+                after_ws.strip_prefix("in").or_else(|| after_ws.strip_prefix("of"))?;
+            if !after_keyword.starts_with(|c: char| c.is_ascii_whitespace()) {
+                return None;
+            }
+            Some((i, keyword_start + 2))
+        })?;
+
+        let left = text[..left_end].trim();
+        let left_start = start + (text[..left_end].len() - text[..left_end].trim_start().len());
+        let right = &text[right_start..];
+        let right_start = start + right_start + (right.len() - right.trim_start().len());
+        if left.is_empty() {
+            return None;
+        }
+
+        let left = self.parse_params(left, left_start)?;
+        let right = self.parse_expression(right.trim(), right_start)?;
+        let for_expression =
+            VueForExpression { left: ArenaBox::new_in(left, self.allocator), right };
+        Some(VueDirectiveValue::For(ArenaBox::new_in(for_expression, self.allocator)))
+    }
+
+    /// Collect the names of free variables referenced in the template.
+    fn collect_references(
+        &self,
+        nodes: &[VueNode<'a>],
+        scope: &mut Vec<&'a str>,
+        references: &mut FxHashSet<&'a str>,
+    ) {
+        for node in nodes {
+            match node {
+                VueNode::Interpolation(interpolation) => {
+                    if let Some(expression) = &interpolation.expression {
+                        let mut collector = ReferenceCollector::default();
+                        collector.visit_expression(expression);
+                        Self::add_references(&collector.references, scope, references);
+                    }
+                }
+                VueNode::Element(element) => {
+                    self.collect_element_references(element, scope, references);
+                }
+                VueNode::Text(_) | VueNode::Comment(_) => {}
+            }
+        }
+    }
+
+    fn collect_element_references(
+        &self,
+        element: &VueElement<'a>,
+        scope: &mut Vec<&'a str>,
+        references: &mut FxHashSet<&'a str>,
+    ) {
+        if element.is_custom_component() && element.name != "component" {
+            references.insert(element.name);
+            if element.name.contains('-') {
+                let pascal_case = self.allocator.alloc_str(&to_pascal_case(element.name));
+                references.insert(pascal_case);
+            }
+        }
+
+        // The iterated value of `v-for` is evaluated outside of the scope of its aliases.
+        for directive in element.directives() {
+            if let Some(VueDirectiveValue::For(for_expression)) = &directive.value {
+                let mut collector = ReferenceCollector::default();
+                collector.visit_expression(&for_expression.right);
+                Self::add_references(&collector.references, scope, references);
+            }
+        }
+
+        let scope_len = scope.len();
+        scope.extend(element.scope_variables().into_iter().map(|(name, _)| name));
+
+        for attribute in &element.attributes {
+            match attribute {
+                VueAttribute::Directive(directive) => {
+                    if !BUILTIN_DIRECTIVES.contains(&directive.name)
+                        && directive.name != "slot-scope"
+                    {
+                        let name = format!("v-{}", directive.name);
+                        references.insert(self.allocator.alloc_str(&to_camel_case(&name)));
+                    }
+                    let mut collector = ReferenceCollector::default();
+                    collector.visit_directive(directive);
+                    Self::add_references(&collector.references, scope, references);
+                }
+                // Template refs, e.g. `<input ref="input">` with `const input = ref()`
+                VueAttribute::Static(attribute) if attribute.name == "ref" => {
+                    if let Some(value) = attribute.value {
+                        references.insert(value);
+                    }
+                }
+                VueAttribute::Static(_) => {}
+            }
+        }
+
+        self.collect_references(&element.children, scope, references);
+        scope.truncate(scope_len);
+    }
+
+    fn add_references(
+        found: &[(&'a str, Span)],
+        scope: &[&'a str],
+        references: &mut FxHashSet<&'a str>,
+    ) {
+        references.extend(found.iter().map(|(name, _)| *name).filter(|name| !scope.contains(name)));
+    }
+}
+
+/// `true` unless a `lang` attribute selects a language other than HTML.
+fn is_html_lang(attributes: &str) -> bool {
+    let Some((_, after)) = attributes.split_once("lang") else {
+        return true;
+    };
+    let lang = after
+        .trim_start()
+        .trim_start_matches('=')
+        .trim_start()
+        .trim_start_matches(['"', '\''])
+        .split(['"', '\'', ' ', '>'])
+        .next()
+        .unwrap_or_default();
+    lang.is_empty() || lang == "html"
+}
+
+/// `my-component` -> `MyComponent`
+fn to_pascal_case(name: &str) -> String {
+    name.split('-')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect()
+}
+
+/// `v-focus-trap` -> `vFocusTrap`
+fn to_camel_case(name: &str) -> String {
+    let pascal_case = to_pascal_case(name);
+    let mut chars = pascal_case.chars();
+    chars
+        .next()
+        .map_or_else(String::new, |first| first.to_ascii_lowercase().to_string() + chars.as_str())
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::{GetSpan, SourceType};
+
+    use super::VueTemplateParser;
+    use crate::template::vue::{VueAttribute, VueDirectiveValue, VueNode, VueTemplate};
+
+    fn parse<'a>(allocator: &'a Allocator, source_text: &'a str) -> VueTemplate<'a> {
+        VueTemplateParser::new(allocator, source_text, SourceType::mjs()).parse().unwrap()
+    }
+
+    #[test]
+    fn no_template() {
+        let allocator = Allocator::default();
+        let source_text = "<script>const template = '<template></template>'</script>";
+        assert!(
+            VueTemplateParser::new(&allocator, source_text, SourceType::mjs()).parse().is_none()
+        );
+
+        let source_text = r#"<template lang="pug">div</template>"#;
+        assert!(
+            VueTemplateParser::new(&allocator, source_text, SourceType::mjs()).parse().is_none()
+        );
+    }
+
+    #[test]
+    fn elements_and_interpolations() {
+        let allocator = Allocator::default();
+        let source_text = r"
+<script setup>
+const msg = '<template>'
+</script>
+<template>
+  <div class='a'>{{ msg }} <br> <p>text</div>
+  <!-- {{ comment }} -->
+  <template v-if='ok'><span /></template>
+</template>
+";
+        let template = parse(&allocator, source_text);
+        assert_eq!(
+            template.span.source_text(source_text).trim(),
+            "<div class='a'>{{ msg }} <br> <p>text</div>
+  <!-- {{ comment }} -->
+  <template v-if='ok'><span /></template>"
+        );
+
+        let elements: Vec<_> = template
+            .children
+            .iter()
+            .filter_map(|node| match node {
+                VueNode::Element(element) => Some(element),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(elements.len(), 2);
+        let div = elements[0];
+        assert_eq!(div.name, "div");
+        assert_eq!(
+            div.span.source_text(source_text),
+            "<div class='a'>{{ msg }} <br> <p>text</div>"
+        );
+        // `<br>` is a void element, `<p>` is implicitly closed by `</div>`
+        let child_names: Vec<_> = div
+            .children
+            .iter()
+            .filter_map(|node| match node {
+                VueNode::Element(element) => Some(element.name),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(child_names, ["br", "p"]);
+
+        let VueNode::Interpolation(interpolation) = &div.children[0] else { panic!() };
+        assert_eq!(
+            interpolation.expression.as_ref().unwrap().span().source_text(source_text),
+            "msg"
+        );
+
+        assert!(elements[1].is_template());
+        assert!(elements[1].directive("if").is_some());
+        assert!(template.references("msg"));
+        assert!(template.references("ok"));
+        assert!(!template.references("comment"));
+    }
+
+    #[test]
+    fn directives() {
+        let allocator = Allocator::default();
+        let source_text = r#"<template>
+  <MyList v-for="({ id, label }, index) of items" :key="id" @click.stop="select(index)" v-on:change="a++; b()" v-bind:[attr].camel="label" #item="{ item }" v-focus-trap />
+</template>"#;
+        let template = parse(&allocator, source_text);
+        let VueNode::Element(element) = &template.children[1] else { panic!() };
+        assert!(element.is_custom_component());
+
+        let directives: Vec<_> = element.directives().collect();
+        let names: Vec<_> = directives.iter().map(|directive| directive.name).collect();
+        assert_eq!(names, ["for", "bind", "on", "on", "bind", "slot", "focus-trap"]);
+
+        let Some(VueDirectiveValue::For(for_expression)) = &directives[0].value else { panic!() };
+        assert_eq!(for_expression.left.span.source_text(source_text), "({ id, label }, index)");
+        assert_eq!(for_expression.right.span().source_text(source_text), "items");
+
+        assert_eq!(directives[1].argument.as_ref().unwrap().name, "key");
+        assert_eq!(directives[2].argument.as_ref().unwrap().name, "click");
+        assert_eq!(directives[2].modifiers.as_slice(), ["stop"]);
+        assert!(matches!(directives[3].value, Some(VueDirectiveValue::Statements(_))));
+        let argument = directives[4].argument.as_ref().unwrap();
+        assert!(argument.dynamic);
+        assert_eq!(argument.name, "attr");
+        assert_eq!(argument.span.source_text(source_text), "[attr]");
+        assert_eq!(directives[4].modifiers.as_slice(), ["camel"]);
+        assert!(matches!(directives[5].value, Some(VueDirectiveValue::Params(_))));
+
+        let scope: Vec<_> = element.scope_variables().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(scope, ["id", "label", "index", "item"]);
+
+        assert!(template.references("items"));
+        assert!(template.references("select"));
+        assert!(template.references("attr"));
+        assert!(template.references("MyList"));
+        assert!(template.references("vFocusTrap"));
+        assert!(!template.references("id"));
+        assert!(!template.references("index"));
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        let allocator = Allocator::default();
+        let source_text =
+            r#"<template><div v-if="a b" :foo="" v-for="item">{{ ( }}</div></template>"#;
+        let template = parse(&allocator, source_text);
+        let VueNode::Element(element) = &template.children[0] else { panic!() };
+        let VueAttribute::Directive(v_if) = &element.attributes[0] else { panic!() };
+        assert!(v_if.has_invalid_value());
+        let VueAttribute::Directive(bind) = &element.attributes[1] else { panic!() };
+        assert!(bind.value.is_none());
+        assert!(!bind.has_invalid_value());
+        let VueAttribute::Directive(v_for) = &element.attributes[2] else { panic!() };
+        assert!(v_for.has_invalid_value());
+        let VueNode::Interpolation(interpolation) = &element.children[0] else { panic!() };
+        assert!(interpolation.expression.is_none());
+    }
+}
@@ -564,12 +564,11 @@ impl Tester {
             return TestResult::Fixed(fix_result.fixed_code.to_string());
         }
 
-        let diagnostic_path = if self.plugins.has_import() {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
-        } else {
-            &self.rule_path
-        }
-        .to_string_lossy();
+        let diagnostic_path = self
+            .rule_path
+            .strip_prefix(&self.current_working_directory)
+            .unwrap_or(&self.rule_path)
+            .to_string_lossy();
 
         let handler = GraphicalReportHandler::new()
             .with_links(false)
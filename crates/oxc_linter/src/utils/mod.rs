@@ -15,6 +15,7 @@ mod express;
 mod jest;
 mod jsdoc;
 mod nextjs;
+mod node;
 mod promise;
 mod react;
 mod react_perf;
@@ -24,7 +25,7 @@ mod url;
 mod vitest;
 
 pub use self::{
-    comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*,
+    comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, node::*, promise::*, react::*,
     react_perf::*, regex::*, unicorn::*, url::*, vitest::*,
 };
 
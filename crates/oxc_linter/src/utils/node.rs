@@ -0,0 +1,533 @@
+use std::{fmt, fs, path::Path};
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        BindingPatternKind, CallExpression, Expression, ImportDeclarationSpecifier, PropertyKey,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{IsGlobalReference, SymbolId};
+use oxc_span::{CompactStr, GetSpan, Span};
+
+use crate::{AstNode, LintContext};
+
+/// Where a [`NodeAccess`] originates from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeAccessBase<'a> {
+    /// `require("fs")` or `import ... from "fs"`, with any `node:` prefix removed.
+    Module(&'a str),
+    /// A global variable. Its name is the first segment of [`NodeAccess::path`].
+    Global,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeAccessKind {
+    Read,
+    Call,
+    Construct,
+}
+
+/// A use of a Node.js module, a global variable, or one of their members.
+///
+/// Reading `fs.exists` produces a [`NodeAccessKind::Read`] access, calling it additionally
+/// produces a [`NodeAccessKind::Call`] access spanning the whole call. Members imported by name
+/// are read at their import or destructuring site.
+#[derive(Debug)]
+pub struct NodeAccess<'a> {
+    pub base: NodeAccessBase<'a>,
+    pub path: Vec<&'a str>,
+    pub kind: NodeAccessKind,
+    pub span: Span,
+    /// Whether the module was imported with the `node:` protocol.
+    pub node_protocol: bool,
+}
+
+impl NodeAccess<'_> {
+    /// `true` if this is an access of `base` followed by `path`.
+    pub fn is(&self, base: NodeAccessBase<'_>, path: &[&str]) -> bool {
+        self.base == base && self.path == path
+    }
+
+    /// Human readable name, e.g. `domain`, `fs.exists` or `new Buffer()`.
+    pub fn name(&self) -> String {
+        let mut name = match self.base {
+            NodeAccessBase::Module(module) if self.path.is_empty() => module.to_string(),
+            NodeAccessBase::Module(module) => format!("{module}.{}", self.path.join(".")),
+            NodeAccessBase::Global => self.path.join("."),
+        };
+        match self.kind {
+            NodeAccessKind::Read => {}
+            NodeAccessKind::Call => name.push_str("()"),
+            NodeAccessKind::Construct => name = format!("new {name}()"),
+        }
+        name
+    }
+}
+
+/// Collect all accesses of the given Node.js `modules` and `globals`, following variables they
+/// are assigned to and members they are destructured into.
+///
+/// ```js
+/// const fs = require("fs");        // fs
+/// fs.promises.readFile();          // fs.promises, fs.promises.readFile, fs.promises.readFile()
+/// import { exists } from "fs";     // fs.exists
+/// new Buffer(10);                  // Buffer, new Buffer()
+/// ```
+pub fn collect_node_accesses<'a>(
+    ctx: &LintContext<'a>,
+    modules: &[&str],
+    globals: &[&'a str],
+) -> Vec<NodeAccess<'a>> {
+    let mut tracker = NodeAccessTracker { ctx, accesses: vec![], node_protocol: false };
+
+    if !modules.is_empty() {
+        for node in ctx.nodes().iter() {
+            match node.kind() {
+                AstKind::ImportDeclaration(decl) => {
+                    let Some(module) = tracker.module(decl.source.value.as_str(), modules) else {
+                        continue;
+                    };
+                    tracker.push(module, vec![], NodeAccessKind::Read, decl.span);
+                    for specifier in decl.specifiers.iter().flatten() {
+                        let local = specifier.local();
+                        match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(named) => {
+                                let path = vec![named.imported.name().as_str()];
+                                tracker.push(
+                                    module,
+                                    path.clone(),
+                                    NodeAccessKind::Read,
+                                    named.span,
+                                );
+                                tracker.track_symbol(local.symbol_id(), module, &path);
+                            }
+                            _ => tracker.track_symbol(local.symbol_id(), module, &[]),
+                        }
+                    }
+                }
+                AstKind::CallExpression(call) => {
+                    let Some(specifier) = require_specifier(call, ctx) else {
+                        continue;
+                    };
+                    let Some(module) = tracker.module(specifier, modules) else {
+                        continue;
+                    };
+                    tracker.push(module, vec![], NodeAccessKind::Read, call.span);
+                    tracker.track_expression(node, module, &[]);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    tracker.node_protocol = false;
+    for &global in globals {
+        let Some(reference_ids) = ctx.scoping().root_unresolved_references().get(global) else {
+            continue;
+        };
+        for &reference_id in reference_ids {
+            let node = ctx.nodes().get_node(ctx.scoping().get_reference(reference_id).node_id());
+            let path = vec![global];
+            let span = node.kind().span();
+            tracker.push(NodeAccessBase::Global, path.clone(), NodeAccessKind::Read, span);
+            tracker.track_call(node, NodeAccessBase::Global, &path);
+            tracker.track_expression(node, NodeAccessBase::Global, &path);
+        }
+    }
+
+    tracker.accesses.sort_unstable_by_key(|access| access.span);
+    tracker.accesses
+}
+
+/// Returns `"fs"` for `require("fs")`, if `require` is the global CommonJS `require`.
+pub fn require_specifier<'a>(call: &CallExpression<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let Expression::Identifier(callee) = &call.callee else {
+        return None;
+    };
+    if !callee.is_global_reference_name("require", ctx.scoping()) || call.arguments.len() != 1 {
+        return None;
+    }
+    match call.arguments[0].as_expression()? {
+        Expression::StringLiteral(lit) => Some(lit.value.as_str()),
+        Expression::TemplateLiteral(lit) => lit.single_quasi().map(|quasi| quasi.as_str()),
+        _ => None,
+    }
+}
+
+struct NodeAccessTracker<'a, 'c> {
+    ctx: &'c LintContext<'a>,
+    accesses: Vec<NodeAccess<'a>>,
+    /// Whether the module currently being tracked was imported with the `node:` protocol.
+    node_protocol: bool,
+}
+
+impl<'a, 'c> NodeAccessTracker<'a, 'c> {
+    fn module(&mut self, specifier: &'a str, modules: &[&str]) -> Option<NodeAccessBase<'a>> {
+        let module = specifier.strip_prefix("node:").unwrap_or(specifier);
+        self.node_protocol = module.len() != specifier.len();
+        modules.contains(&module).then_some(NodeAccessBase::Module(module))
+    }
+
+    fn push(
+        &mut self,
+        base: NodeAccessBase<'a>,
+        path: Vec<&'a str>,
+        kind: NodeAccessKind,
+        span: Span,
+    ) {
+        self.accesses.push(NodeAccess {
+            base,
+            path,
+            kind,
+            span,
+            node_protocol: self.node_protocol,
+        });
+    }
+
+    /// Track all reads of a variable holding the value at `path`.
+    fn track_symbol(&mut self, symbol_id: SymbolId, base: NodeAccessBase<'a>, path: &[&'a str]) {
+        for reference in self.ctx.scoping().get_resolved_references(symbol_id) {
+            if !reference.is_read() {
+                continue;
+            }
+            let node = self.ctx.nodes().get_node(reference.node_id());
+            if !path.is_empty() {
+                self.track_call(node, base, path);
+            }
+            self.track_expression(node, base, path);
+        }
+    }
+
+    /// Record a call or construction of `node`, which evaluates to the value at `path`.
+    fn track_call(&mut self, node: &'c AstNode<'a>, base: NodeAccessBase<'a>, path: &[&'a str]) {
+        let (node, parent) = self.outermost(node);
+        let span = node.kind().span();
+        let (kind, span) = match parent.kind() {
+            AstKind::CallExpression(call) if call.callee.span() == span => {
+                (NodeAccessKind::Call, call.span)
+            }
+            AstKind::NewExpression(new) if new.callee.span() == span => {
+                (NodeAccessKind::Construct, new.span)
+            }
+            _ => return,
+        };
+        self.push(base, path.to_vec(), kind, span);
+    }
+
+    /// Follow `node`, which evaluates to the value at `path`, into member accesses and variables.
+    fn track_expression(
+        &mut self,
+        node: &'c AstNode<'a>,
+        base: NodeAccessBase<'a>,
+        path: &[&'a str],
+    ) {
+        let (node, parent) = self.outermost(node);
+        let span = node.kind().span();
+        let property = match parent.kind() {
+            AstKind::StaticMemberExpression(member) if member.object.span() == span => {
+                Some(member.property.name.as_str())
+            }
+            AstKind::ComputedMemberExpression(member) if member.object.span() == span => {
+                member.static_property_name().map(|name| name.as_str())
+            }
+            AstKind::VariableDeclarator(decl)
+                if decl.init.as_ref().is_some_and(|init| init.span() == span) =>
+            {
+                self.track_binding(&decl.id.kind, base, path);
+                None
+            }
+            _ => None,
+        };
+        if let Some(property) = property {
+            let mut path = path.to_vec();
+            path.push(property);
+            self.push(base, path.clone(), NodeAccessKind::Read, parent.kind().span());
+            self.track_call(parent, base, &path);
+            self.track_expression(parent, base, &path);
+        }
+    }
+
+    fn track_binding(
+        &mut self,
+        binding: &BindingPatternKind<'a>,
+        base: NodeAccessBase<'a>,
+        path: &[&'a str],
+    ) {
+        match binding {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                self.track_symbol(ident.symbol_id(), base, path);
+            }
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                self.track_binding(&pattern.left.kind, base, path);
+            }
+            BindingPatternKind::ObjectPattern(pattern) => {
+                for property in &pattern.properties {
+                    let name = match &property.key {
+                        PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
+                        PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+                        _ => continue,
+                    };
+                    let mut path = path.to_vec();
+                    path.push(name);
+                    self.push(base, path.clone(), NodeAccessKind::Read, property.span);
+                    self.track_binding(&property.value.kind, base, &path);
+                }
+            }
+            BindingPatternKind::ArrayPattern(_) => {}
+        }
+    }
+
+    /// Skip over parentheses, type assertions and optional chains wrapping `node`, returning the
+    /// outermost wrapper and its parent.
+    fn outermost(&self, mut node: &'c AstNode<'a>) -> (&'c AstNode<'a>, &'c AstNode<'a>) {
+        loop {
+            let parent = self.ctx.nodes().parent_node(node.id());
+            match parent.kind() {
+                AstKind::ParenthesizedExpression(_)
+                | AstKind::ChainExpression(_)
+                | AstKind::TSAsExpression(_)
+                | AstKind::TSNonNullExpression(_)
+                | AstKind::TSSatisfiesExpression(_) => node = parent,
+                _ => return (node, parent),
+            }
+        }
+    }
+}
+
+/// Option of the `prefer-global-*` rules.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PreferGlobalMode {
+    /// Use the global variable instead of importing it from its module.
+    #[default]
+    Always,
+    /// Import the value from its module instead of using the global variable.
+    Never,
+}
+
+impl PreferGlobalMode {
+    pub fn from_configuration(value: &serde_json::Value) -> Self {
+        match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("never") => Self::Never,
+            _ => Self::Always,
+        }
+    }
+}
+
+fn prefer_global_diagnostic(span: Span, global: &str, module: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected use of `{module}`."))
+        .with_help(format!("Use the global variable `{global}` instead."))
+        .with_label(span)
+}
+
+fn prefer_module_diagnostic(span: Span, global: &str, module: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected use of the global variable `{global}`."))
+        .with_help(format!("Use `{module}` instead."))
+        .with_label(span)
+}
+
+/// Shared implementation of the `prefer-global-*` rules, where `global` is also available as
+/// `path` of the Node.js `module`.
+pub fn check_prefer_global(
+    ctx: &LintContext,
+    mode: PreferGlobalMode,
+    global: &'static str,
+    module: &'static str,
+    path: &[&str],
+) {
+    let required = if path.is_empty() {
+        format!("require(\"{module}\")")
+    } else {
+        format!("require(\"{module}\").{}", path.join("."))
+    };
+    match mode {
+        PreferGlobalMode::Always => {
+            for access in collect_node_accesses(ctx, &[module], &[]) {
+                if access.kind == NodeAccessKind::Read
+                    && access.is(NodeAccessBase::Module(module), path)
+                {
+                    ctx.diagnostic(prefer_global_diagnostic(access.span, global, &required));
+                }
+            }
+        }
+        PreferGlobalMode::Never => {
+            for access in collect_node_accesses(ctx, &[], &[global]) {
+                if access.kind == NodeAccessKind::Read
+                    && access.is(NodeAccessBase::Global, &[global])
+                {
+                    ctx.diagnostic(prefer_module_diagnostic(access.span, global, &required));
+                }
+            }
+        }
+    }
+}
+
+/// A Node.js version, e.g. `14.18.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NodeVersion(pub u32, pub u32, pub u32);
+
+impl NodeVersion {
+    /// Parse a version like `14`, `v14.18` or `14.18.0`, treating `x` and `*` as zero.
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().trim_start_matches(['v', '=']);
+        let mut parts = version.split('-').next()?.split('.').map(|part| match part {
+            "x" | "X" | "*" => Some(0),
+            part => part.parse().ok(),
+        });
+        let major = parts.next()??;
+        let minor = parts.next().unwrap_or(Some(0))?;
+        let patch = parts.next().unwrap_or(Some(0))?;
+        Some(Self(major, minor, patch))
+    }
+}
+
+impl fmt::Display for NodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+/// The Node.js version range the linted file must support.
+#[derive(Debug, Clone)]
+pub struct NodeVersionRange {
+    /// The range as written, e.g. `>=14.0.0 <20`.
+    pub range: String,
+    /// Lowest version matched by the range.
+    pub min: NodeVersion,
+}
+
+impl NodeVersionRange {
+    /// Parse an npm semver range. Only the lower bound is kept, since that is the version whose
+    /// features may be relied upon.
+    pub fn parse(range: &str) -> Option<Self> {
+        let min = range.split("||").map(range_lower_bound).try_fold(None, |min, bound| {
+            let bound = bound?;
+            Some(Some(min.map_or(bound, |min: NodeVersion| min.min(bound))))
+        })??;
+        Some(Self { range: range.trim().to_string(), min })
+    }
+
+    /// Resolve the version range from the rule's `version` option, falling back to the `engines.node`
+    /// field of the closest `package.json`.
+    pub fn resolve(option: Option<&str>, ctx: &LintContext) -> Option<Self> {
+        if let Some(range) = option {
+            return Self::parse(range);
+        }
+        let (_, package_json) = find_package_json(ctx.file_path())?;
+        Self::parse(package_json.get("engines")?.get("node")?.as_str()?)
+    }
+}
+
+/// Options shared by the `no-unsupported-features-*` rules.
+#[derive(Debug, Default, Clone)]
+pub struct UnsupportedFeaturesConfig {
+    /// Version range overriding `engines.node` of `package.json`.
+    pub version: Option<CompactStr>,
+    /// Names of features to allow.
+    pub ignores: Vec<CompactStr>,
+}
+
+impl UnsupportedFeaturesConfig {
+    pub fn from_configuration(value: &serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self {
+            version: config
+                .and_then(|config| config.get("version"))
+                .and_then(serde_json::Value::as_str)
+                .map(CompactStr::from),
+            ignores: config
+                .and_then(|config| config.get("ignores"))
+                .and_then(serde_json::Value::as_array)
+                .map(|ignores| {
+                    ignores
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactStr::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// The configured version range. `None` if neither the `version` option nor `engines.node`
+    /// is set, in which case nothing is reported.
+    pub fn version_range(&self, ctx: &LintContext) -> Option<NodeVersionRange> {
+        NodeVersionRange::resolve(self.version.as_deref(), ctx)
+    }
+
+    pub fn is_ignored(&self, name: &str) -> bool {
+        self.ignores.iter().any(|ignore| ignore == name)
+    }
+}
+
+pub fn unsupported_feature_diagnostic(
+    span: Span,
+    name: &str,
+    since: NodeVersion,
+    range: &NodeVersionRange,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{name} is not supported until Node.js {since}."))
+        .with_help(format!(
+            "The configured version range is `{}`. Raise the minimum supported version or avoid this feature.",
+            range.range
+        ))
+        .with_label(span)
+}
+
+/// Lower bound of a set of space separated comparators, e.g. `>=12.5 <16` or `1.2 - 3`.
+fn range_lower_bound(comparators: &str) -> Option<NodeVersion> {
+    let mut min = NodeVersion(0, 0, 0);
+    let mut tokens = comparators.split_whitespace().peekable();
+    while let Some(token) = tokens.next() {
+        // hyphen range, `1.2 - 3` is `>=1.2 <=3`
+        if tokens.peek() == Some(&"-") {
+            tokens.next();
+            tokens.next();
+        }
+        let (operator, version) = token.split_at(
+            token.find(|c: char| c.is_ascii_digit() || c == 'v' || c == '*').unwrap_or(0),
+        );
+        if operator.starts_with('<') {
+            continue;
+        }
+        let mut bound = NodeVersion::parse(version)?;
+        if operator == ">" {
+            bound.2 += 1;
+        }
+        min = min.max(bound);
+    }
+    Some(min)
+}
+
+/// Find the closest `package.json` to `path` and parse it, returning its directory as well.
+pub fn find_package_json(path: &Path) -> Option<(&Path, serde_json::Value)> {
+    path.ancestors().skip(1).take_while(|dir| !dir.as_os_str().is_empty()).find_map(|dir| {
+        let text = fs::read_to_string(dir.join("package.json")).ok()?;
+        serde_json::from_str(&text).ok().map(|json| (dir, json))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{NodeVersion, NodeVersionRange};
+
+    #[test]
+    fn version_range_lower_bound() {
+        let cases = [
+            (">=14.0.0", NodeVersion(14, 0, 0)),
+            ("^16.3", NodeVersion(16, 3, 0)),
+            ("~12.22.1", NodeVersion(12, 22, 1)),
+            (">12.0.0", NodeVersion(12, 0, 1)),
+            (">=12 <20", NodeVersion(12, 0, 0)),
+            ("18.x || >=16.9", NodeVersion(16, 9, 0)),
+            ("^14.18.0 || ^16.0.0 || >=18", NodeVersion(14, 18, 0)),
+            ("10.13 - 16", NodeVersion(10, 13, 0)),
+            ("v20", NodeVersion(20, 0, 0)),
+            ("*", NodeVersion(0, 0, 0)),
+        ];
+        for (range, min) in cases {
+            assert_eq!(NodeVersionRange::parse(range).unwrap().min, min, "{range}");
+        }
+        assert!(NodeVersionRange::parse("latest").is_none());
+    }
+}
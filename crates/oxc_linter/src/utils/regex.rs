@@ -1,6 +1,15 @@
 use oxc_allocator::Allocator;
-use oxc_ast::{AstKind, ast::Argument};
-use oxc_regular_expression::{ConstructorParser, Options, ast::Pattern};
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, RegExpFlags},
+};
+use oxc_regular_expression::{
+    ConstructorParser, Options,
+    ast::{
+        CharacterClass, CharacterClassContents, CharacterClassContentsKind,
+        CharacterClassEscapeKind, Disjunction, Pattern, Term,
+    },
+};
 use oxc_semantic::IsGlobalReference;
 use oxc_span::Span;
 
@@ -10,59 +19,65 @@ pub fn run_on_regex_node<'a, 'b, M>(node: &'a AstNode<'b>, ctx: &'a LintContext<
 where
     M: FnOnce(&Pattern<'_>, Span),
 {
-    match node.kind() {
+    run_on_regex_node_with_flags(node, ctx, |pattern, regex| cb(pattern, regex.span));
+}
+
+/// Where a pattern passed to the callback of [`run_on_regex_node_with_flags`] comes from.
+#[derive(Debug, Clone, Copy)]
+pub struct RegexSource {
+    /// Span of the regex literal, or of the pattern string passed to `RegExp`.
+    pub span: Span,
+    /// Flags of the regex. Empty if the flags are not a string literal.
+    pub flags: RegExpFlags,
+    /// `true` for regex literals. Spans of patterns parsed from string literals point into the
+    /// string's source text, which may contain string escapes, so they are not safe to fix.
+    pub is_literal: bool,
+}
+
+/// Like [`run_on_regex_node`], but also passes the flags of the regex to the callback.
+pub fn run_on_regex_node_with_flags<'a, 'b, M>(
+    node: &'a AstNode<'b>,
+    ctx: &'a LintContext<'b>,
+    cb: M,
+) where
+    M: FnOnce(&Pattern<'_>, RegexSource),
+{
+    let arguments = match node.kind() {
         AstKind::RegExpLiteral(reg) => {
             if let Some(pat) = &reg.regex.pattern.pattern {
-                cb(pat, reg.span);
+                cb(pat, RegexSource { span: reg.span, flags: reg.regex.flags, is_literal: true });
             }
+            return;
         }
         AstKind::NewExpression(expr)
             if expr.callee.is_global_reference_name("RegExp", ctx.semantic().scoping()) =>
         {
-            // note: improvements required for strings used via identifier references
-            // Missing or non-string arguments will be runtime errors, but are not covered by this rule.
-            match (&expr.arguments.first(), &expr.arguments.get(1)) {
-                (Some(Argument::StringLiteral(pattern)), Some(Argument::StringLiteral(flags))) => {
-                    let allocator = Allocator::default();
-                    if let Some(pat) = parse_regex(&allocator, pattern.span, Some(flags.span), ctx)
-                    {
-                        cb(&pat, pattern.span);
-                    }
-                }
-                (Some(Argument::StringLiteral(pattern)), _) => {
-                    let allocator = Allocator::default();
-                    if let Some(pat) = parse_regex(&allocator, pattern.span, None, ctx) {
-                        cb(&pat, pattern.span);
-                    }
-                }
-                _ => {}
-            }
+            &expr.arguments
         }
-
         // RegExp()
         AstKind::CallExpression(expr)
             if expr.callee.is_global_reference_name("RegExp", ctx.semantic().scoping()) =>
         {
-            // note: improvements required for strings used via identifier references
-            // Missing or non-string arguments will be runtime errors, but are not covered by this rule.
-            match (&expr.arguments.first(), &expr.arguments.get(1)) {
-                (Some(Argument::StringLiteral(pattern)), Some(Argument::StringLiteral(flags))) => {
-                    let allocator = Allocator::default();
-                    if let Some(pat) = parse_regex(&allocator, pattern.span, Some(flags.span), ctx)
-                    {
-                        cb(&pat, pattern.span);
-                    }
-                }
-                (Some(Argument::StringLiteral(pattern)), _) => {
-                    let allocator = Allocator::default();
-                    if let Some(pat) = parse_regex(&allocator, pattern.span, None, ctx) {
-                        cb(&pat, pattern.span);
-                    }
-                }
-                _ => {}
-            }
+            &expr.arguments
         }
-        _ => {}
+        _ => return,
+    };
+
+    // note: improvements required for strings used via identifier references
+    // Missing or non-string arguments will be runtime errors, but are not covered by this rule.
+    let Some(Argument::StringLiteral(pattern)) = arguments.first() else {
+        return;
+    };
+    let flags = match arguments.get(1) {
+        Some(Argument::StringLiteral(flags)) => Some(flags),
+        _ => None,
+    };
+    let allocator = Allocator::default();
+    if let Some(pat) = parse_regex(&allocator, pattern.span, flags.map(|flags| flags.span), ctx) {
+        let flags = flags.map_or(RegExpFlags::empty(), |flags| {
+            flags.value.chars().filter_map(|c| RegExpFlags::try_from(c).ok()).collect()
+        });
+        cb(&pat, RegexSource { span: pattern.span, flags, is_literal: false });
     }
 }
 
@@ -85,3 +100,280 @@ fn parse_regex<'a>(
     let Ok(pattern) = parser.parse() else { return None };
     Some(pattern)
 }
+
+/// Whether `term` can match the empty string.
+pub fn regex_term_can_be_empty(term: &Term) -> bool {
+    match term {
+        Term::BoundaryAssertion(_)
+        | Term::LookAroundAssertion(_)
+        | Term::IndexedReference(_)
+        | Term::NamedReference(_) => true,
+        Term::Quantifier(quantifier) => {
+            quantifier.min == 0 || regex_term_can_be_empty(&quantifier.body)
+        }
+        Term::CapturingGroup(group) => regex_disjunction_can_be_empty(&group.body),
+        Term::IgnoreGroup(group) => regex_disjunction_can_be_empty(&group.body),
+        Term::Character(_)
+        | Term::Dot(_)
+        | Term::CharacterClassEscape(_)
+        | Term::UnicodePropertyEscape(_)
+        | Term::CharacterClass(_) => false,
+    }
+}
+
+/// Whether `term` can only match the empty string, e.g. `\b` or `(?:)`.
+pub fn regex_term_is_always_empty(term: &Term) -> bool {
+    match term {
+        Term::BoundaryAssertion(_) | Term::LookAroundAssertion(_) => true,
+        Term::Quantifier(quantifier) => {
+            quantifier.max == Some(0) || regex_term_is_always_empty(&quantifier.body)
+        }
+        Term::CapturingGroup(group) => regex_disjunction_is_always_empty(&group.body),
+        Term::IgnoreGroup(group) => regex_disjunction_is_always_empty(&group.body),
+        _ => false,
+    }
+}
+
+fn regex_disjunction_can_be_empty(disjunction: &Disjunction) -> bool {
+    disjunction.body.iter().any(|alternative| alternative.body.iter().all(regex_term_can_be_empty))
+}
+
+fn regex_disjunction_is_always_empty(disjunction: &Disjunction) -> bool {
+    disjunction
+        .body
+        .iter()
+        .all(|alternative| alternative.body.iter().all(regex_term_is_always_empty))
+}
+
+/// Whether `term` contains a capturing group or a backreference, which make the term's text
+/// observable beyond what it matches.
+pub fn regex_term_has_captures(term: &Term) -> bool {
+    let has_captures = |disjunction: &Disjunction| {
+        disjunction
+            .body
+            .iter()
+            .any(|alternative| alternative.body.iter().any(regex_term_has_captures))
+    };
+    match term {
+        Term::CapturingGroup(_) | Term::IndexedReference(_) | Term::NamedReference(_) => true,
+        Term::Quantifier(quantifier) => regex_term_has_captures(&quantifier.body),
+        Term::IgnoreGroup(group) => has_captures(&group.body),
+        Term::LookAroundAssertion(assertion) => has_captures(&assertion.body),
+        _ => false,
+    }
+}
+
+const DIGIT_RANGES: &[(u32, u32)] = &[(0x30, 0x39)];
+const WORD_RANGES: &[(u32, u32)] = &[(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)];
+const SPACE_RANGES: &[(u32, u32)] = &[
+    (0x09, 0x0D),
+    (0x20, 0x20),
+    (0xA0, 0xA0),
+    (0x1680, 0x1680),
+    (0x2000, 0x200A),
+    (0x2028, 0x2029),
+    (0x202F, 0x202F),
+    (0x205F, 0x205F),
+    (0x3000, 0x3000),
+    (0xFEFF, 0xFEFF),
+];
+const LINE_TERMINATOR_RANGES: &[(u32, u32)] = &[(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)];
+
+/// Ranges longer than this are not case folded, to keep folding cheap. They already contain
+/// most of the case variants that matter in practice.
+const MAX_CASE_FOLDED_RANGE: u32 = 0x400;
+
+/// The set of characters a single-character regex term can match, as sorted, non-overlapping
+/// and non-adjacent inclusive ranges of code points.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RegexCharSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl RegexCharSet {
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut ranges = ranges.into_iter().collect::<Vec<_>>();
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                Some(last) if min <= last.1.saturating_add(1) => last.1 = last.1.max(max),
+                _ => merged.push((min, max)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    /// The characters matched by `term`, or `None` if `term` does not always match exactly one
+    /// character, or matches characters that are not known here, such as `\p{...}`.
+    pub fn from_term(term: &Term, flags: RegExpFlags) -> Option<Self> {
+        match term {
+            Term::Character(ch) => Some(Self::from_ranges([(ch.value, ch.value)]).fold_case(flags)),
+            Term::CharacterClassEscape(escape) => Some(Self::from_escape(escape.kind, flags)),
+            Term::Dot(_) => {
+                let all = Self::from_ranges([(0, max_code_point(flags))]);
+                if flags.contains(RegExpFlags::S) {
+                    Some(all)
+                } else {
+                    Some(all.difference(&Self::from_ranges(LINE_TERMINATOR_RANGES.iter().copied())))
+                }
+            }
+            Term::CharacterClass(class) => Self::from_class(class, flags),
+            _ => None,
+        }
+    }
+
+    /// The characters matched by an element of a character class. See [`Self::from_term`].
+    pub fn from_class_contents(
+        contents: &CharacterClassContents,
+        flags: RegExpFlags,
+    ) -> Option<Self> {
+        match contents {
+            CharacterClassContents::Character(ch) => {
+                Some(Self::from_ranges([(ch.value, ch.value)]).fold_case(flags))
+            }
+            CharacterClassContents::CharacterClassRange(range) => {
+                Some(Self::from_ranges([(range.min.value, range.max.value)]).fold_case(flags))
+            }
+            CharacterClassContents::CharacterClassEscape(escape) => {
+                Some(Self::from_escape(escape.kind, flags))
+            }
+            CharacterClassContents::NestedCharacterClass(class) => Self::from_class(class, flags),
+            CharacterClassContents::UnicodePropertyEscape(_)
+            | CharacterClassContents::ClassStringDisjunction(_) => None,
+        }
+    }
+
+    fn from_class(class: &CharacterClass, flags: RegExpFlags) -> Option<Self> {
+        if class.kind != CharacterClassContentsKind::Union || class.strings {
+            return None;
+        }
+        let mut set = Self::default();
+        for contents in &class.body {
+            set = set.union(&Self::from_class_contents(contents, flags)?);
+        }
+        if class.negative {
+            set = Self::from_ranges([(0, max_code_point(flags))]).difference(&set);
+        }
+        Some(set)
+    }
+
+    fn from_escape(kind: CharacterClassEscapeKind, flags: RegExpFlags) -> Self {
+        let (ranges, negative) = match kind {
+            CharacterClassEscapeKind::D => (DIGIT_RANGES, false),
+            CharacterClassEscapeKind::NegativeD => (DIGIT_RANGES, true),
+            CharacterClassEscapeKind::S => (SPACE_RANGES, false),
+            CharacterClassEscapeKind::NegativeS => (SPACE_RANGES, true),
+            CharacterClassEscapeKind::W => (WORD_RANGES, false),
+            CharacterClassEscapeKind::NegativeW => (WORD_RANGES, true),
+        };
+        let set = Self::from_ranges(ranges.iter().copied());
+        if negative {
+            Self::from_ranges([(0, max_code_point(flags))]).difference(&set)
+        } else {
+            set
+        }
+    }
+
+    /// Add the simple case variants of all characters if the `i` flag is set.
+    fn fold_case(self, flags: RegExpFlags) -> Self {
+        if !flags.contains(RegExpFlags::I) {
+            return self;
+        }
+        let mut ranges = self.ranges.clone();
+        for &(min, max) in &self.ranges {
+            if max - min > MAX_CASE_FOLDED_RANGE {
+                continue;
+            }
+            for c in (min..=max).filter_map(char::from_u32) {
+                for variant in [simple_case(c.to_lowercase()), simple_case(c.to_uppercase())]
+                    .into_iter()
+                    .flatten()
+                {
+                    ranges.push((variant, variant));
+                }
+            }
+        }
+        Self::from_ranges(ranges)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).copied())
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len());
+        for &(mut min, max) in &self.ranges {
+            for &(other_min, other_max) in &other.ranges {
+                if other_max < min || other_min > max {
+                    continue;
+                }
+                if other_min > min {
+                    ranges.push((min, other_min - 1));
+                }
+                min = other_max.saturating_add(1);
+                if other_max >= max {
+                    break;
+                }
+            }
+            if min <= max {
+                ranges.push((min, max));
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.difference(other) != *self
+    }
+}
+
+fn max_code_point(flags: RegExpFlags) -> u32 {
+    if flags.intersects(RegExpFlags::U | RegExpFlags::V) { 0x10_FFFF } else { 0xFFFF }
+}
+
+fn simple_case(mut mapping: impl Iterator<Item = char>) -> Option<u32> {
+    let c = mapping.next()?;
+    mapping.next().is_none().then_some(c as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::RegExpFlags;
+    use oxc_regular_expression::{LiteralParser, Options};
+
+    use super::RegexCharSet;
+
+    fn char_set(source: &str, flags: &str) -> Option<RegexCharSet> {
+        let allocator = Allocator::default();
+        let pattern = LiteralParser::new(&allocator, source, Some(flags), Options::default())
+            .parse()
+            .unwrap();
+        let flags = flags.chars().filter_map(|c| RegExpFlags::try_from(c).ok()).collect();
+        RegexCharSet::from_term(&pattern.body.body[0].body[0], flags)
+    }
+
+    #[test]
+    fn char_set_from_term() {
+        let digits = char_set(r"\d", "").unwrap();
+        assert_eq!(char_set("[0-9]", ""), Some(digits.clone()));
+        assert!(digits.is_subset_of(&char_set(r"\w", "").unwrap()));
+        assert!(!digits.intersects(&char_set(r"\D", "").unwrap()));
+        assert!(digits.intersects(&char_set("[5-x]", "").unwrap()));
+        assert!(char_set(r"[^\n]", "").unwrap().is_subset_of(&char_set(".", "s").unwrap()));
+        assert!(!char_set(r"[^\n]", "").unwrap().is_subset_of(&char_set(".", "").unwrap()));
+        assert_eq!(char_set("a", "i"), char_set("[aA]", ""));
+        assert_eq!(char_set("[a-z]", "i"), char_set("[A-Za-z]", "i"));
+        assert_eq!(char_set(r"\p{L}", "u"), None);
+        assert_eq!(char_set("(a)", ""), None);
+    }
+}
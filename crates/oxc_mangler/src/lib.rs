@@ -4,6 +4,7 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use keep_names::collect_name_symbols;
 use rustc_hash::FxHashSet;
+use top_level::rename_top_level;
 
 use base54::base54;
 use oxc_allocator::{Allocator, Vec};
@@ -15,8 +16,10 @@ use oxc_span::Atom;
 
 pub(crate) mod base54;
 mod keep_names;
+mod top_level;
 
 pub use keep_names::MangleOptionsKeepNames;
+pub use top_level::{TopLevelRenameOptions, TopLevelRenameReturn};
 
 #[derive(Default, Debug, Clone, Copy)]
 pub struct MangleOptions {
@@ -265,6 +268,26 @@ impl<'t> Mangler<'t> {
         semantic.into_scoping()
     }
 
+    /// Mangles the program like [Self::build], and renames top level declarations which collide with
+    /// top level declarations of other scripts, so that scripts can be concatenated.
+    ///
+    /// Colliding declarations are renamed to `name$1`, `name$2`, ..., unless they are in
+    /// [TopLevelRenameOptions::reserved]. Other top level declarations are kept, as with `top_level: false`.
+    /// Nothing is renamed if the program contains direct `eval`.
+    #[must_use]
+    pub fn build_with_top_level_renames(
+        mut self,
+        program: &Program<'_>,
+        options: &TopLevelRenameOptions,
+    ) -> TopLevelRenameReturn {
+        let mut semantic =
+            SemanticBuilder::new().with_scope_tree_child_ids(true).build(program).semantic;
+        let renamed = rename_top_level(semantic.scoping_mut(), options);
+        self.options.top_level = false;
+        self.build_with_semantic(&mut semantic, program);
+        TopLevelRenameReturn { scoping: semantic.into_scoping(), renamed }
+    }
+
     /// # Panics
     ///
     /// Panics if the child_ids does not exist in scope_tree.
@@ -542,6 +565,13 @@ impl<'t> Mangler<'t> {
     }
 }
 
+/// Names in the order they are assigned by the mangler, shortest first.
+///
+/// Keywords are not skipped.
+pub fn mangled_names() -> impl Iterator<Item = InlineString<7, u8>> {
+    (0..).map(base54)
+}
+
 fn is_special_name(name: &str) -> bool {
     matches!(name, "exports" | "arguments")
 }
@@ -0,0 +1,84 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_semantic::{Scoping, SymbolId};
+
+/// Options for [crate::Mangler::build_with_top_level_renames].
+#[derive(Debug, Default, Clone)]
+pub struct TopLevelRenameOptions {
+    /// Global names which must be kept, e.g. names read by other code through `window`.
+    ///
+    /// Top level declarations with these names are never renamed, and no declaration is renamed to them.
+    pub reserved: FxHashSet<String>,
+
+    /// Top level bindings of the other scripts this script is concatenated with.
+    ///
+    /// When renaming several scripts, rename them in order, and pass the top level bindings
+    /// of the scripts before, after they are renamed. Otherwise two scripts declaring the same name
+    /// may both rename it to the same new name.
+    pub other_bindings: FxHashSet<String>,
+}
+
+pub struct TopLevelRenameReturn {
+    /// Symbols with renamed top level declarations and mangled nested declarations.
+    /// Pass to `oxc_codegen` with `Codegen::with_scoping`.
+    pub scoping: Scoping,
+
+    /// Renamed top level declarations, from original name to new name.
+    pub renamed: FxHashMap<String, String>,
+}
+
+/// Rename top level declarations which collide with `options.other_bindings` to `name$1`, `name$2`, ...
+///
+/// Declarations are renamed in declaration order, and new names are the first ones not used by any
+/// symbol or unresolved reference in this script, so the result is deterministic.
+pub fn rename_top_level(
+    scoping: &mut Scoping,
+    options: &TopLevelRenameOptions,
+) -> FxHashMap<String, String> {
+    let mut renamed = FxHashMap::default();
+    // Code evaluated by a direct `eval` may reference the declarations by name.
+    if scoping.root_scope_flags().contains_direct_eval() {
+        return renamed;
+    }
+
+    let root_scope_id = scoping.root_scope_id();
+    let mut colliding = scoping
+        .get_bindings(root_scope_id)
+        .iter()
+        .filter(|(name, _)| {
+            options.other_bindings.contains(**name) && !options.reserved.contains(**name)
+        })
+        .map(|(_, symbol_id)| *symbol_id)
+        .collect::<Vec<SymbolId>>();
+    if colliding.is_empty() {
+        return renamed;
+    }
+    colliding.sort_unstable();
+
+    // Nested declarations are included, so that a renamed declaration is not shadowed
+    // where it is referenced.
+    let mut used_names = scoping
+        .symbol_names()
+        .chain(scoping.root_unresolved_references().keys().copied())
+        .map(str::to_string)
+        .collect::<FxHashSet<_>>();
+
+    for symbol_id in colliding {
+        let name = scoping.symbol_name(symbol_id).to_string();
+        let mut i = 1;
+        let new_name = loop {
+            let new_name = format!("{name}${i}");
+            if !used_names.contains(&new_name)
+                && !options.other_bindings.contains(&new_name)
+                && !options.reserved.contains(&new_name)
+            {
+                break new_name;
+            }
+            i += 1;
+        };
+        scoping.rename_symbol(symbol_id, root_scope_id, &new_name);
+        used_names.insert(new_name.clone());
+        renamed.insert(name, new_name);
+    }
+    renamed
+}
@@ -4,7 +4,7 @@ use std::path::Path;
 use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions, CommentOptions};
 use oxc_mangler::MangleOptions;
-use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
+use oxc_minifier::{CompressOptions, Minifier, MinifierOptions, MinifierPass};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 use pico_args::Arguments;
@@ -12,6 +12,9 @@ use pico_args::Arguments;
 // Instruction:
 // create a `test.js`,
 // run `cargo run -p oxc_minifier --example minifier` or `just example minifier`
+//
+// `--report` prints the changes made by each pass.
+// `--disable <pass>` skips a pass, e.g. `--disable fold_constants`. Can be repeated.
 
 fn main() -> std::io::Result<()> {
     let mut args = Arguments::from_env();
@@ -19,6 +22,8 @@ fn main() -> std::io::Result<()> {
     let mangle = args.contains("--mangle");
     let nospace = args.contains("--nospace");
     let twice = args.contains("--twice");
+    let report = args.contains("--report");
+    let disabled_passes = args.values_from_str::<_, MinifierPass>("--disable").unwrap();
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
     let path = Path::new(&name);
@@ -26,12 +31,13 @@ fn main() -> std::io::Result<()> {
     let source_type = SourceType::from_path(path).unwrap();
 
     let mut allocator = Allocator::default();
-    let printed = minify(&allocator, &source_text, source_type, mangle, nospace);
+    let compress = CompressOptions { report, disabled_passes, ..CompressOptions::smallest() };
+    let printed = minify(&allocator, &source_text, source_type, mangle, nospace, compress.clone());
     println!("{printed}");
 
     if twice {
         allocator.reset();
-        let printed2 = minify(&allocator, &printed, source_type, mangle, nospace);
+        let printed2 = minify(&allocator, &printed, source_type, mangle, nospace, compress);
         println!("{printed2}");
         println!("same = {}", printed == printed2);
     }
@@ -45,14 +51,16 @@ fn minify(
     source_type: SourceType,
     mangle: bool,
     nospace: bool,
+    compress: CompressOptions,
 ) -> String {
     let ret = Parser::new(allocator, source_text, source_type).parse();
     let mut program = ret.program;
-    let options = MinifierOptions {
-        mangle: mangle.then(MangleOptions::default),
-        compress: Some(CompressOptions::smallest()),
-    };
+    let options =
+        MinifierOptions { mangle: mangle.then(MangleOptions::default), compress: Some(compress) };
     let ret = Minifier::new(options).build(allocator, &mut program);
+    if let Some(report) = ret.report {
+        println!("{report}");
+    }
     Codegen::new()
         .with_options(CodegenOptions {
             minify: nospace,
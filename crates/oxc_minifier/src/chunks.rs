@@ -0,0 +1,363 @@
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Allocator, TakeIn, Vec as ArenaVec};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_mangler::{Mangler, mangled_names};
+use oxc_semantic::Scoping;
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::keyword::is_reserved_keyword;
+
+use crate::{Compressor, Minifier};
+
+/// A chunk in a graph of chunks which import from each other, minified by [Minifier::build_chunks].
+pub struct MinifierChunk<'a, 'p> {
+    pub program: &'p mut Program<'a>,
+
+    /// Entry chunks are imported by code outside of the chunk graph, so their export names are kept.
+    pub is_entry: bool,
+
+    /// Chunks imported by this chunk, keyed by the module specifier in `import` and `export ... from`
+    /// declarations and `import()` expressions.
+    ///
+    /// e.g. `"./chunk-a1b2.js"` -> index of that chunk in the slice passed to [Minifier::build_chunks].
+    ///
+    /// Specifiers which are not in this map are treated as external modules.
+    pub imports: FxHashMap<String, usize>,
+}
+
+pub struct MinifierChunksReturn {
+    /// Mangled symbols of each chunk, in the same order as the chunks.
+    /// Pass to `oxc_codegen` with `Codegen::with_scoping`.
+    pub scopings: Vec<Option<Scoping>>,
+
+    /// Renamed exports of each chunk, from original export name to new export name.
+    ///
+    /// Exports of entry chunks are never renamed. Neither are exports of chunks whose namespace
+    /// object is observable: chunks imported with `import * as ns`, `export * from` or `import()`,
+    /// and chunks containing `export * from`.
+    pub renamed_exports: Vec<FxHashMap<String, String>>,
+}
+
+impl Minifier {
+    /// Minify chunks which import from each other.
+    ///
+    /// Each chunk is compressed and mangled as with [Minifier::build].
+    /// In addition, when mangling is enabled, export names which are only imported by other chunks
+    /// in the graph are shortened, and imports of them in the other chunks are updated to match.
+    pub fn build_chunks<'a>(
+        self,
+        allocator: &'a Allocator,
+        chunks: &mut [MinifierChunk<'a, '_>],
+    ) -> MinifierChunksReturn {
+        if let Some(options) = &self.options.compress {
+            for chunk in chunks.iter_mut() {
+                Compressor::new(allocator).build(chunk.program, options.clone());
+            }
+        }
+
+        let Some(mangle_options) = self.options.mangle else {
+            return MinifierChunksReturn {
+                scopings: chunks.iter().map(|_| None).collect(),
+                renamed_exports: chunks.iter().map(|_| FxHashMap::default()).collect(),
+            };
+        };
+
+        let renamed_exports = ChunkGraph::new(chunks).rename_exports();
+        let ast = AstBuilder::new(allocator);
+        for (chunk, renamed) in chunks.iter_mut().zip(&renamed_exports) {
+            ExportsRenamer { ast, chunk_renamed: renamed, all_renamed: &renamed_exports }
+                .rename(chunk);
+        }
+
+        let scopings = chunks
+            .iter()
+            .map(|chunk| Some(Mangler::default().with_options(mangle_options).build(chunk.program)))
+            .collect();
+        MinifierChunksReturn { scopings, renamed_exports }
+    }
+}
+
+/// Export names of each chunk, and how often they are imported by other chunks.
+struct ChunkGraph {
+    /// Whether export names of a chunk can be renamed.
+    renamable: Vec<bool>,
+    /// Identifier export names of each chunk, in source order, with the number of imports of each.
+    exports: Vec<Vec<(String, usize)>>,
+    /// Export names of each chunk which are kept (`default` and string literal names).
+    kept_exports: Vec<Vec<String>>,
+}
+
+impl ChunkGraph {
+    fn new(chunks: &[MinifierChunk<'_, '_>]) -> Self {
+        let mut graph = Self {
+            renamable: chunks.iter().map(|chunk| !chunk.is_entry).collect(),
+            exports: vec![],
+            kept_exports: vec![],
+        };
+        for chunk in chunks {
+            let (exports, kept_exports) = Self::collect_exports(chunk.program);
+            graph.exports.push(exports.into_iter().map(|name| (name, 0)).collect());
+            graph.kept_exports.push(kept_exports);
+        }
+        for (index, chunk) in chunks.iter().enumerate() {
+            graph.collect_imports(index, chunk);
+        }
+        graph
+    }
+
+    fn collect_exports(program: &Program<'_>) -> (Vec<String>, Vec<String>) {
+        let mut exports = vec![];
+        let mut kept_exports = vec![];
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    if let Some(declaration) =
+                        decl.declaration.as_ref().filter(|decl| !decl.is_typescript_syntax())
+                    {
+                        exports.extend(
+                            Self::declaration_bound_names(declaration)
+                                .into_iter()
+                                .map(|name| name.to_string()),
+                        );
+                    }
+                    for specifier in &decl.specifiers {
+                        Self::push_export(&specifier.exported, &mut exports, &mut kept_exports);
+                    }
+                }
+                Statement::ExportDefaultDeclaration(_) => kept_exports.push("default".to_string()),
+                Statement::ExportAllDeclaration(decl) => {
+                    if let Some(exported) = &decl.exported {
+                        Self::push_export(exported, &mut exports, &mut kept_exports);
+                    }
+                }
+                _ => {}
+            }
+        }
+        (exports, kept_exports)
+    }
+
+    /// `default` is imported by default imports, so it is kept like string literal names.
+    fn push_export(
+        exported: &ModuleExportName<'_>,
+        exports: &mut Vec<String>,
+        kept_exports: &mut Vec<String>,
+    ) {
+        match exported.identifier_name().filter(|name| name != "default") {
+            Some(name) => exports.push(name.to_string()),
+            None => kept_exports.push(exported.name().to_string()),
+        }
+    }
+
+    fn collect_imports(&mut self, index: usize, chunk: &MinifierChunk<'_, '_>) {
+        let resolve =
+            |source: &StringLiteral<'_>| chunk.imports.get(source.value.as_str()).copied();
+        for stmt in &chunk.program.body {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    let Some(target) = resolve(&decl.source) else { continue };
+                    for specifier in decl.specifiers.iter().flatten() {
+                        match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                self.add_import(target, &specifier.imported.name());
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                                self.renamable[target] = false;
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {}
+                        }
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    let Some(target) = decl.source.as_ref().and_then(resolve) else { continue };
+                    for specifier in &decl.specifiers {
+                        self.add_import(target, &specifier.local.name());
+                    }
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    // Exports of `export * from` are shadowed by local exports,
+                    // so renaming local exports could change which binding is exported.
+                    if decl.exported.is_none() {
+                        self.renamable[index] = false;
+                    }
+                    if let Some(target) = resolve(&decl.source) {
+                        self.renamable[target] = false;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut dynamic_imports = DynamicImports::default();
+        dynamic_imports.visit_program(chunk.program);
+        for source in dynamic_imports.sources {
+            if let Some(&target) = chunk.imports.get(source) {
+                self.renamable[target] = false;
+            }
+        }
+    }
+
+    fn add_import(&mut self, target: usize, name: &str) {
+        if let Some((_, count)) = self.exports[target].iter_mut().find(|(export, _)| export == name)
+        {
+            *count += 1;
+        }
+    }
+
+    /// Assign the shortest names to the most imported exports.
+    fn rename_exports(self) -> Vec<FxHashMap<String, String>> {
+        self.exports
+            .into_iter()
+            .zip(self.kept_exports)
+            .zip(self.renamable)
+            .map(|((mut exports, kept_exports), renamable)| {
+                if !renamable {
+                    return FxHashMap::default();
+                }
+                exports.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+                let mut names = mangled_names().filter(|name| {
+                    let name = name.as_str();
+                    name != "default"
+                        && !is_reserved_keyword(name)
+                        && !kept_exports.iter().any(|kept| kept == name)
+                });
+                exports
+                    .into_iter()
+                    .filter_map(|(export, _)| {
+                        let name = names.next()?;
+                        Some((export, name.as_str().to_string()))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn declaration_bound_names<'a>(declaration: &Declaration<'a>) -> Vec<Atom<'a>> {
+        let idents = match declaration {
+            Declaration::VariableDeclaration(decl) => decl
+                .declarations
+                .iter()
+                .flat_map(|declarator| declarator.id.get_binding_identifiers())
+                .collect(),
+            _ => declaration.id().into_iter().collect::<Vec<_>>(),
+        };
+        idents.into_iter().map(|ident| ident.name).collect()
+    }
+}
+
+/// Rewrite export and import names in a chunk.
+struct ExportsRenamer<'a, 'r> {
+    ast: AstBuilder<'a>,
+    /// Renamed exports of this chunk.
+    chunk_renamed: &'r FxHashMap<String, String>,
+    /// Renamed exports of all chunks.
+    all_renamed: &'r [FxHashMap<String, String>],
+}
+
+impl<'a> ExportsRenamer<'a, '_> {
+    fn rename(&self, chunk: &mut MinifierChunk<'a, '_>) {
+        let body = chunk.program.body.take_in(self.ast);
+        let mut new_body = self.ast.vec_with_capacity(body.len());
+        for mut stmt in body {
+            match &mut stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    let target = decl
+                        .source
+                        .as_ref()
+                        .and_then(|source| chunk.imports.get(source.value.as_str()));
+                    for specifier in &mut decl.specifiers {
+                        if let Some(&target) = target {
+                            self.rename_module_export_name(
+                                &mut specifier.local,
+                                &self.all_renamed[target],
+                            );
+                        }
+                        self.rename_module_export_name(&mut specifier.exported, self.chunk_renamed);
+                    }
+                    // `export const foo = 1` -> `const foo = 1; export { foo as e }`
+                    if let Some(declaration) = decl.declaration.take_if(|declaration| {
+                        !declaration.is_typescript_syntax()
+                            && ChunkGraph::declaration_bound_names(declaration)
+                                .iter()
+                                .any(|name| self.chunk_renamed.contains_key(name.as_str()))
+                    }) {
+                        decl.specifiers = self.export_specifiers(&declaration);
+                        new_body.push(Statement::from(declaration));
+                    }
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    if let Some(exported) = &mut decl.exported {
+                        self.rename_module_export_name(exported, self.chunk_renamed);
+                    }
+                }
+                Statement::ImportDeclaration(decl) => {
+                    let Some(&target) = chunk.imports.get(decl.source.value.as_str()) else {
+                        new_body.push(stmt);
+                        continue;
+                    };
+                    for specifier in decl.specifiers.iter_mut().flatten() {
+                        if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier {
+                            self.rename_module_export_name(
+                                &mut specifier.imported,
+                                &self.all_renamed[target],
+                            );
+                        }
+                    }
+                }
+                _ => {}
+            }
+            new_body.push(stmt);
+        }
+        chunk.program.body = new_body;
+    }
+
+    fn export_specifiers(
+        &self,
+        declaration: &Declaration<'a>,
+    ) -> ArenaVec<'a, ExportSpecifier<'a>> {
+        self.ast.vec_from_iter(ChunkGraph::declaration_bound_names(declaration).into_iter().map(
+            |name| {
+                let exported = self
+                    .chunk_renamed
+                    .get(name.as_str())
+                    .map_or(name, |new_name| self.ast.atom(new_name));
+                self.ast.export_specifier(
+                    SPAN,
+                    self.ast.module_export_name_identifier_reference(SPAN, name),
+                    self.ast.module_export_name_identifier_name(SPAN, exported),
+                    ImportOrExportKind::Value,
+                )
+            },
+        ))
+    }
+
+    fn rename_module_export_name(
+        &self,
+        name: &mut ModuleExportName<'a>,
+        renamed: &FxHashMap<String, String>,
+    ) {
+        let Some(identifier_name) = name.identifier_name() else { return };
+        if let Some(new_name) = renamed.get(identifier_name.as_str()) {
+            *name =
+                self.ast.module_export_name_identifier_name(name.span(), self.ast.atom(new_name));
+        }
+    }
+}
+
+/// Collect string literal sources of `import()` expressions.
+#[derive(Default)]
+struct DynamicImports<'a> {
+    sources: Vec<&'a str>,
+}
+
+impl<'a> Visit<'a> for DynamicImports<'a> {
+    fn visit_import_expression(&mut self, it: &ImportExpression<'a>) {
+        if let Expression::StringLiteral(source) = &it.source {
+            self.sources.push(source.value.as_str());
+        }
+        oxc_ast_visit::walk::walk_import_expression(self, it);
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
         DeadCodeElimination, LatePeepholeOptimizations, Normalize, NormalizeOptions,
         PeepholeOptimizations,
     },
+    report::{MinifierReport, PrintedSize},
     state::MinifierState,
 };
 
@@ -21,9 +22,14 @@ impl<'a> Compressor<'a> {
         Self { allocator }
     }
 
-    pub fn build(self, program: &mut Program<'a>, options: CompressOptions) {
+    /// Returns a report of changes made by each pass if [CompressOptions::report] is enabled.
+    pub fn build(
+        self,
+        program: &mut Program<'a>,
+        options: CompressOptions,
+    ) -> Option<MinifierReport> {
         let scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
-        self.build_with_scoping(program, scoping, options);
+        self.build_with_scoping(program, scoping, options)
     }
 
     pub fn build_with_scoping(
@@ -31,16 +37,28 @@ impl<'a> Compressor<'a> {
         program: &mut Program<'a>,
         scoping: Scoping,
         options: CompressOptions,
-    ) {
+    ) -> Option<MinifierReport> {
+        let original_size = options.report.then(|| program.printed_size());
         let state = MinifierState::new(program.source_type, options);
         let mut ctx = ReusableTraverseCtx::new(state, scoping, self.allocator);
         let normalize_options =
             NormalizeOptions { convert_while_to_fors: true, convert_const_to_let: true };
         Normalize::new(normalize_options).build(program, &mut ctx);
-        PeepholeOptimizations::new().run_in_loop(program, &mut ctx);
+        let mut peephole = PeepholeOptimizations::new();
+        peephole.run_in_loop(program, &mut ctx);
         LatePeepholeOptimizations::new().build(program, &mut ctx);
+        let original_size = original_size?;
+        let mut report = peephole.into_report().unwrap_or_default();
+        report.original_size = original_size;
+        report.final_size = program.printed_size();
+        Some(report)
     }
 
+    /// Remove dead code, such as branches made unreachable by replacing `process.env.NODE_ENV`,
+    /// without running the full minifier.
+    ///
+    /// Runs to a fixed point, so functions, variables and imports only referenced
+    /// from removed code are also removed, unless [CompressOptions::unused] is `Keep`.
     pub fn dead_code_elimination(self, program: &mut Program<'a>, options: CompressOptions) {
         let scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
         self.dead_code_elimination_with_scoping(program, scoping, options);
@@ -57,6 +75,6 @@ impl<'a> Compressor<'a> {
         let normalize_options =
             NormalizeOptions { convert_while_to_fors: false, convert_const_to_let: false };
         Normalize::new(normalize_options).build(program, &mut ctx);
-        DeadCodeElimination::new().build(program, &mut ctx);
+        DeadCodeElimination::new().run_in_loop(program, &mut ctx);
     }
 }
@@ -74,6 +74,9 @@ impl<'a> oxc_ecmascript::side_effects::MayHaveSideEffectsContext<'a> for Ctx<'a,
     }
 
     fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+        if self.state.options.unsafe_options.pure_getters {
+            return PropertyReadSideEffects::None;
+        }
         self.state.options.treeshake.property_read_side_effects
     }
 
@@ -0,0 +1,402 @@
+use std::{borrow::Cow, fmt::Write};
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::constant_evaluation::ConstantValue;
+use oxc_span::Span;
+use oxc_syntax::number::ToJsString;
+
+/// Minify JSON source text by removing whitespace.
+///
+/// Strings and numbers are copied as written, so escapes and number precision are preserved.
+///
+/// # Errors
+///
+/// Returns an error if `source_text` is not a JSON value.
+pub fn minify_json(source_text: &str) -> Result<String, Vec<OxcDiagnostic>> {
+    JsonMinifier { source_text, pos: 0, json: String::with_capacity(source_text.len()) }
+        .minify()
+        .map_err(|error| vec![error])
+}
+
+/// Whitespace stripper following the JSON grammar of [RFC 8259](https://datatracker.ietf.org/doc/html/rfc8259).
+///
+/// Nesting is tracked with an explicit stack instead of recursion,
+/// so deeply nested input cannot overflow the stack.
+struct JsonMinifier<'s> {
+    source_text: &'s str,
+    pos: usize,
+    json: String,
+}
+
+impl JsonMinifier<'_> {
+    fn minify(mut self) -> Result<String, OxcDiagnostic> {
+        // Open `{` and `[` containers.
+        let mut containers = vec![];
+        'value: loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b'{') => {
+                    self.copy_byte();
+                    self.skip_whitespace();
+                    if self.peek() == Some(b'}') {
+                        self.copy_byte();
+                    } else {
+                        containers.push(b'{');
+                        self.member_name()?;
+                        continue 'value;
+                    }
+                }
+                Some(b'[') => {
+                    self.copy_byte();
+                    self.skip_whitespace();
+                    if self.peek() == Some(b']') {
+                        self.copy_byte();
+                    } else {
+                        containers.push(b'[');
+                        continue 'value;
+                    }
+                }
+                Some(b'"') => self.string()?,
+                Some(b'-' | b'0'..=b'9') => self.number()?,
+                Some(b't') => self.keyword("true")?,
+                Some(b'f') => self.keyword("false")?,
+                Some(b'n') => self.keyword("null")?,
+                _ => return Err(self.unexpected()),
+            }
+
+            // After a value, close containers until another value is expected.
+            loop {
+                self.skip_whitespace();
+                let Some(&container) = containers.last() else {
+                    if self.pos < self.source_text.len() {
+                        return Err(self.unexpected());
+                    }
+                    return Ok(self.json);
+                };
+                match (container, self.peek()) {
+                    (b'{', Some(b',')) => {
+                        self.copy_byte();
+                        self.skip_whitespace();
+                        self.member_name()?;
+                        continue 'value;
+                    }
+                    (b'[', Some(b',')) => {
+                        self.copy_byte();
+                        continue 'value;
+                    }
+                    (b'{', Some(b'}')) | (b'[', Some(b']')) => {
+                        self.copy_byte();
+                        containers.pop();
+                    }
+                    _ => return Err(self.unexpected()),
+                }
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.source_text.as_bytes().get(self.pos).copied()
+    }
+
+    fn peek_is(&self, predicate: impl Fn(u8) -> bool) -> bool {
+        self.peek().is_some_and(predicate)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek_is(|b| matches!(b, b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Copy the ASCII byte at the current position to the output.
+    fn copy_byte(&mut self) {
+        self.json.push(char::from(self.source_text.as_bytes()[self.pos]));
+        self.pos += 1;
+    }
+
+    fn copy_from(&mut self, start: usize) {
+        self.json.push_str(&self.source_text[start..self.pos]);
+    }
+
+    /// `"name" :`
+    fn member_name(&mut self) -> Result<(), OxcDiagnostic> {
+        if self.peek() != Some(b'"') {
+            return Err(self.unexpected());
+        }
+        self.string()?;
+        self.skip_whitespace();
+        if self.peek() != Some(b':') {
+            return Err(self.unexpected());
+        }
+        self.copy_byte();
+        Ok(())
+    }
+
+    fn string(&mut self) -> Result<(), OxcDiagnostic> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => {
+                            self.pos += 1;
+                        }
+                        Some(b'u') => {
+                            self.pos += 1;
+                            for _ in 0..4 {
+                                if !self.peek_is(|b| b.is_ascii_hexdigit()) {
+                                    return Err(self.unexpected());
+                                }
+                                self.pos += 1;
+                            }
+                        }
+                        _ => return Err(self.unexpected()),
+                    }
+                }
+                // Control characters must be escaped.
+                Some(b) if b >= 0x20 => self.pos += 1,
+                _ => return Err(self.unexpected()),
+            }
+        }
+        self.pos += 1;
+        self.copy_from(start);
+        Ok(())
+    }
+
+    /// `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?`
+    fn number(&mut self) -> Result<(), OxcDiagnostic> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => self.digits()?,
+            _ => return Err(self.unexpected()),
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            self.digits()?;
+        }
+        if self.peek_is(|b| matches!(b, b'e' | b'E')) {
+            self.pos += 1;
+            if self.peek_is(|b| matches!(b, b'+' | b'-')) {
+                self.pos += 1;
+            }
+            self.digits()?;
+        }
+        self.copy_from(start);
+        Ok(())
+    }
+
+    /// One or more digits.
+    fn digits(&mut self) -> Result<(), OxcDiagnostic> {
+        if !self.peek_is(|b| b.is_ascii_digit()) {
+            return Err(self.unexpected());
+        }
+        while self.peek_is(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        Ok(())
+    }
+
+    fn keyword(&mut self, keyword: &str) -> Result<(), OxcDiagnostic> {
+        if !self.source_text[self.pos..].starts_with(keyword) {
+            return Err(self.unexpected());
+        }
+        self.pos += keyword.len();
+        self.json.push_str(keyword);
+        Ok(())
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn unexpected(&self) -> OxcDiagnostic {
+        let start = self.pos as u32;
+        match self.source_text[self.pos..].chars().next() {
+            Some(c) => OxcDiagnostic::error("Unexpected token")
+                .with_label(Span::new(start, start + c.len_utf8() as u32)),
+            None => OxcDiagnostic::error("Unexpected end of file").with_label(Span::empty(start)),
+        }
+    }
+}
+
+/// Prints JSON-compatible object and array literals as minified JSON text.
+///
+/// Values other than objects and arrays are evaluated with `value_of`.
+pub struct JsonWriter<F> {
+    value_of: F,
+    json: String,
+}
+
+impl<'a, F: Fn(&Expression<'a>) -> Option<ConstantValue<'a>>> JsonWriter<F> {
+    pub(crate) fn new(value_of: F) -> Self {
+        Self { value_of, json: String::new() }
+    }
+
+    pub(crate) fn into_json(self) -> String {
+        self.json
+    }
+
+    /// Returns `None` if `expr` is not JSON-compatible.
+    pub(crate) fn write_value(&mut self, expr: &Expression<'a>) -> Option<()> {
+        match expr {
+            Expression::ObjectExpression(e) => self.write_object(e),
+            Expression::ArrayExpression(e) => self.write_array(e),
+            _ => match (self.value_of)(expr)? {
+                ConstantValue::String(s) => {
+                    self.write_string(&s);
+                    Some(())
+                }
+                ConstantValue::Number(n) if n.is_finite() => {
+                    self.write_number(n);
+                    Some(())
+                }
+                ConstantValue::Boolean(b) => {
+                    self.json.push_str(if b { "true" } else { "false" });
+                    Some(())
+                }
+                ConstantValue::Null => {
+                    self.json.push_str("null");
+                    Some(())
+                }
+                ConstantValue::Number(_) | ConstantValue::BigInt(_) | ConstantValue::Undefined => {
+                    None
+                }
+            },
+        }
+    }
+
+    fn write_object(&mut self, e: &ObjectExpression<'a>) -> Option<()> {
+        self.json.push('{');
+        for (i, prop) in e.properties.iter().enumerate() {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else { return None };
+            if prop.kind != PropertyKind::Init || prop.method {
+                return None;
+            }
+            let key = match &prop.key {
+                PropertyKey::StaticIdentifier(ident) => Cow::Borrowed(ident.name.as_str()),
+                PropertyKey::StringLiteral(lit) => Cow::Borrowed(lit.value.as_str()),
+                PropertyKey::NumericLiteral(lit) if lit.value.is_finite() => {
+                    Cow::Owned(lit.value.to_js_string())
+                }
+                _ => return None,
+            };
+            // `__proto__: x` sets the prototype, but `JSON.parse` defines an own property.
+            if key == "__proto__" {
+                return None;
+            }
+            if i > 0 {
+                self.json.push(',');
+            }
+            self.write_string(&key);
+            self.json.push(':');
+            self.write_value(&prop.value)?;
+        }
+        self.json.push('}');
+        Some(())
+    }
+
+    fn write_array(&mut self, e: &ArrayExpression<'a>) -> Option<()> {
+        self.json.push('[');
+        for (i, element) in e.elements.iter().enumerate() {
+            // Holes and spread elements have no JSON equivalent.
+            let element = element.as_expression()?;
+            if i > 0 {
+                self.json.push(',');
+            }
+            self.write_value(element)?;
+        }
+        self.json.push(']');
+        Some(())
+    }
+
+    fn write_string(&mut self, s: &str) {
+        self.json.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => self.json.push_str("\\\""),
+                '\\' => self.json.push_str("\\\\"),
+                '\n' => self.json.push_str("\\n"),
+                '\r' => self.json.push_str("\\r"),
+                '\t' => self.json.push_str("\\t"),
+                '\u{8}' => self.json.push_str("\\b"),
+                '\u{c}' => self.json.push_str("\\f"),
+                c if c < ' ' => {
+                    let _ = write!(self.json, "\\u{:04x}", u32::from(c));
+                }
+                c => self.json.push(c),
+            }
+        }
+        self.json.push('"');
+    }
+
+    fn write_number(&mut self, n: f64) {
+        if n == 0.0 {
+            self.json.push_str(if n.is_sign_negative() { "-0" } else { "0" });
+        } else {
+            self.json.push_str(&n.to_js_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::minify_json;
+
+    #[track_caller]
+    fn test(source_text: &str, expected: &str) {
+        assert_eq!(minify_json(source_text).unwrap(), expected);
+    }
+
+    #[track_caller]
+    fn test_invalid(source_text: &str) {
+        assert!(minify_json(source_text).is_err(), "{source_text}");
+    }
+
+    #[test]
+    fn minify() {
+        test(
+            "{ \"a\": [1, 2.5, -0, 1e21], \"b\": { \"c\": null } }",
+            r#"{"a":[1,2.5,-0,1e21],"b":{"c":null}}"#,
+        );
+        test("[true, false, \"\\u0001\\n\\\"\"]", r#"[true,false,"\u0001\n\""]"#);
+        test("{ \"__proto__\": 1, \"__proto__\": 2 }", r#"{"__proto__":1,"__proto__":2}"#);
+        test("  \"x\"  ", r#""x""#);
+        test("\t[ { } , [ ] ]\r\n", "[{},[]]");
+        test("\"a b\\/\\u00e9 é\"", r#""a b\/\u00e9 é""#);
+        // Numbers are not re-printed.
+        test(
+            "[12345678901234567890, 1E+2, 0.10, -0.0e-0]",
+            "[12345678901234567890,1E+2,0.10,-0.0e-0]",
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        test_invalid("");
+        test_invalid("{ \"a\": 1 ");
+        test_invalid("[1,, 2]");
+        test_invalid("[1, 2,]");
+        test_invalid("{ \"a\": 1, }");
+        test_invalid("{ a: 1 }");
+        test_invalid("{ \"a\" 1 }");
+        test_invalid("['a']");
+        for number in ["0x1", "01", ".5", "1.", "+1", "1e", "-", "Infinity", "NaN"] {
+            test_invalid(number);
+        }
+        test_invalid("[1] // comment");
+        test_invalid("/* comment */ 1");
+        test_invalid("undefined");
+        test_invalid("nul");
+        test_invalid("\"\\x41\"");
+        test_invalid("\"\\u00\"");
+        test_invalid("\"a\nb\"");
+        test_invalid("\"a");
+        test_invalid("1 2");
+        test_invalid("[[[[");
+    }
+}
@@ -2,11 +2,14 @@
 
 #![allow(clippy::literal_string_with_formatting_args, clippy::needless_pass_by_ref_mut)]
 
+mod chunks;
 mod compressor;
 mod ctx;
+mod json;
 mod keep_var;
 mod options;
 mod peephole;
+mod report;
 mod state;
 
 #[cfg(test)]
@@ -19,7 +22,13 @@ use oxc_semantic::{Scoping, SemanticBuilder, Stats};
 
 pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
 
-pub use crate::{compressor::Compressor, options::*};
+pub use crate::{
+    chunks::{MinifierChunk, MinifierChunksReturn},
+    compressor::Compressor,
+    json::minify_json,
+    options::*,
+    report::{IterationReport, MinifierPass, MinifierReport, PassReport},
+};
 
 #[derive(Debug, Clone)]
 pub struct MinifierOptions {
@@ -35,6 +44,9 @@ impl Default for MinifierOptions {
 
 pub struct MinifierReturn {
     pub scoping: Option<Scoping>,
+
+    /// Changes made by each compression pass, if [CompressOptions::report] is enabled.
+    pub report: Option<MinifierReport>,
 }
 
 pub struct Minifier {
@@ -47,14 +59,14 @@ impl Minifier {
     }
 
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> MinifierReturn {
-        let stats = if let Some(options) = self.options.compress {
+        let (stats, report) = if let Some(options) = self.options.compress {
             let semantic = SemanticBuilder::new().build(program).semantic;
             let stats = semantic.stats();
             let scoping = semantic.into_scoping();
-            Compressor::new(allocator).build_with_scoping(program, scoping, options);
-            stats
+            let report = Compressor::new(allocator).build_with_scoping(program, scoping, options);
+            (stats, report)
         } else {
-            Stats::default()
+            (Stats::default(), None)
         };
         let scoping = self.options.mangle.map(|options| {
             let mut semantic = SemanticBuilder::new()
@@ -65,6 +77,6 @@ impl Minifier {
             Mangler::default().with_options(options).build_with_semantic(&mut semantic, program);
             semantic.into_scoping()
         });
-        MinifierReturn { scoping }
+        MinifierReturn { scoping, report }
     }
 }
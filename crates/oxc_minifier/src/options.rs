@@ -1,5 +1,7 @@
 use oxc_syntax::es_target::ESTarget;
 
+use crate::MinifierPass;
+
 pub use oxc_ecmascript::side_effects::PropertyReadSideEffects;
 
 #[derive(Debug, Clone)]
@@ -30,9 +32,41 @@ pub struct CompressOptions {
     /// Keep function / class names.
     pub keep_names: CompressOptionsKeepNames,
 
+    /// Inline single-use functions and variables, and propagate constant values.
+    ///
+    /// Default all `false`
+    pub inline: CompressOptionsInline,
+
+    /// Transformations which are only correct under assumptions about the input code.
+    ///
+    /// Default all `false`
+    pub unsafe_options: CompressOptionsUnsafe,
+
+    /// Rewrite pure, JSON-compatible object and array literals whose JSON text is at least
+    /// this many bytes into `JSON.parse('...')` calls, which V8 parses much faster.
+    ///
+    /// <https://v8.dev/blog/cost-of-javascript-2019#json>
+    ///
+    /// Default `None`
+    pub json_parse_threshold: Option<usize>,
+
     /// Treeshake Options .
     /// <https://rollupjs.org/configuration-options/#treeshake>
     pub treeshake: TreeShakeOptions,
+
+    /// Collect a [crate::MinifierReport] of the changes made by each pass in each iteration,
+    /// returned in [crate::MinifierReturn::report].
+    ///
+    /// This prints the AST before and after every pass, so is very slow.
+    /// It is intended for debugging the minifier.
+    ///
+    /// Default `false`
+    pub report: bool,
+
+    /// Passes to skip, for bisecting minifier bugs.
+    ///
+    /// Default empty
+    pub disabled_passes: Vec<MinifierPass>,
 }
 
 impl Default for CompressOptions {
@@ -49,7 +83,12 @@ impl CompressOptions {
             drop_debugger: true,
             drop_console: false,
             unused: CompressOptionsUnused::Remove,
+            inline: CompressOptionsInline::all_false(),
+            unsafe_options: CompressOptionsUnsafe::all_false(),
+            json_parse_threshold: None,
             treeshake: TreeShakeOptions::default(),
+            report: false,
+            disabled_passes: vec![],
         }
     }
 
@@ -60,7 +99,12 @@ impl CompressOptions {
             drop_debugger: false,
             drop_console: false,
             unused: CompressOptionsUnused::Keep,
+            inline: CompressOptionsInline::all_false(),
+            unsafe_options: CompressOptionsUnsafe::all_false(),
+            json_parse_threshold: None,
             treeshake: TreeShakeOptions::default(),
+            report: false,
+            disabled_passes: vec![],
         }
     }
 }
@@ -108,6 +152,181 @@ impl CompressOptionsKeepNames {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompressOptionsInline {
+    /// Replace references to variables which are never reassigned and are initialized with
+    /// a primitive value (e.g. `const DEBUG = false`) with the value.
+    ///
+    /// Values are only copied to multiple use sites if they are short.
+    /// Long values are only inlined into a single use site, and only when unused declarations are removed.
+    ///
+    /// Default `false`
+    pub constants: bool,
+
+    /// Move the initializer of a variable which is referenced once into its use site,
+    /// when the use is in the following statement and nothing between them can observe the move.
+    ///
+    /// e.g. `let x = foo(); x.bar()` -> `foo().bar()`
+    ///
+    /// Default `false`
+    pub variables: bool,
+
+    /// Inline functions which are called once, and whose body is a single `return` statement.
+    ///
+    /// e.g. `function f() { return a + b } g(f())` -> `g(a + b)`
+    ///
+    /// Only applies when unused declarations are removed.
+    ///
+    /// Default `false`
+    pub functions: bool,
+
+    /// Replace reads of properties of objects which are never mutated and never escape
+    /// with the property values, by the same rules as [Self::constants].
+    /// The object is removed once all reads are replaced.
+    ///
+    /// e.g. `const Foo = { A: 1, B: 2 }; f(Foo.A)` -> `f(1)`
+    ///
+    /// Also applies to TypeScript enums, which are compiled to
+    /// `var Foo = (Foo => { Foo[Foo.A = 1] = 'A'; return Foo })(Foo || {})`.
+    ///
+    /// Default `false`
+    pub properties: bool,
+}
+
+impl CompressOptionsInline {
+    pub fn all_false() -> Self {
+        Self { constants: false, variables: false, functions: false, properties: false }
+    }
+
+    pub fn all_true() -> Self {
+        Self { constants: true, variables: true, functions: true, properties: true }
+    }
+}
+
+/// Opt-in transformations which may change the behavior of some code, similar to Terser's `unsafe_*` options.
+///
+/// Each option documents the assumption it makes about the input.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompressOptionsUnsafe {
+    /// Convert function expressions which do not use `this`, `arguments` or `new.target`
+    /// into arrow functions.
+    ///
+    /// e.g. `f(function () { return 1 })` -> `f(() => 1)`
+    ///
+    /// Assumes the functions are never called with `new`, and that their `prototype` is not used.
+    ///
+    /// Default `false`
+    pub arrows: bool,
+
+    /// Convert function expression properties in object literals into methods.
+    ///
+    /// e.g. `({ a: function () {} })` -> `({ a() {} })`
+    ///
+    /// Assumes the functions are never called with `new`, and that their `prototype` is not used.
+    ///
+    /// Default `false`
+    pub methods: bool,
+
+    /// Invert relational comparisons when negated.
+    ///
+    /// e.g. `!(a < b)` -> `a >= b`
+    ///
+    /// Assumes neither operand is (or converts to) `NaN`.
+    ///
+    /// Default `false`
+    pub comps: bool,
+
+    /// Combine constants in chains of multiplications.
+    ///
+    /// e.g. `x * 2 * 3` -> `x * 6`
+    ///
+    /// Assumes the result is not affected by floating point rounding of the intermediate value.
+    ///
+    /// Default `false`
+    pub math: bool,
+
+    /// Replace `void 0` with a variable named `undefined` which is in scope,
+    /// so the mangler can shorten it.
+    ///
+    /// e.g. `(function (undefined) { return void 0 })()` -> `(function (undefined) { return undefined })()`
+    ///
+    /// Assumes the variable is always `undefined`, as in the `(function (undefined) { ... })()` pattern.
+    ///
+    /// Default `false`
+    pub undefined: bool,
+
+    /// Convert `RegExp` calls with string literal arguments into regular expression literals.
+    ///
+    /// e.g. `new RegExp("ab+c", "i")` -> `/ab+c/i`
+    ///
+    /// Assumes the global `RegExp` is not modified, and that the pattern is valid.
+    /// Invalid patterns become syntax errors rather than runtime errors.
+    ///
+    /// Default `false`
+    pub regexp: bool,
+
+    /// Treat property reads as side effect free, so unused reads can be removed.
+    ///
+    /// e.g. `a.b.c;` -> `;`
+    ///
+    /// Assumes there are no getters with side effects, and that objects are not `null` or `undefined`.
+    /// Overrides [TreeShakeOptions::property_read_side_effects].
+    ///
+    /// Default `false`
+    pub pure_getters: bool,
+
+    /// Remove unused trailing function parameters. Equivalent to Terser's `keep_fargs: false`.
+    ///
+    /// e.g. `function f(a, b) { return a }` -> `function f(a) { return a }`
+    ///
+    /// Assumes `Function.prototype.length` is not used.
+    ///
+    /// Default `false`
+    #[doc(alias = "keep_fargs")]
+    pub drop_fargs: bool,
+
+    /// Inline functions declared once and referenced once as function expressions at the reference.
+    ///
+    /// e.g. `function f() { g() } h(f)` -> `h(function () { g() })`
+    ///
+    /// Assumes function identity is not observed, as the reference may be evaluated multiple times
+    /// (e.g. in a loop) and creates a new function each time.
+    /// Only applies when unused declarations are removed.
+    ///
+    /// Default `false`
+    pub reduce_funcs: bool,
+}
+
+impl CompressOptionsUnsafe {
+    pub fn all_false() -> Self {
+        Self {
+            arrows: false,
+            methods: false,
+            comps: false,
+            math: false,
+            undefined: false,
+            regexp: false,
+            pure_getters: false,
+            drop_fargs: false,
+            reduce_funcs: false,
+        }
+    }
+
+    pub fn all_true() -> Self {
+        Self {
+            arrows: true,
+            methods: true,
+            comps: true,
+            math: true,
+            undefined: true,
+            regexp: true,
+            pure_getters: true,
+            drop_fargs: true,
+            reduce_funcs: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TreeShakeOptions {
     /// Whether to respect the pure annotations.
@@ -144,6 +363,17 @@ pub struct TreeShakeOptions {
     ///
     /// Default `true`
     pub unknown_global_side_effects: bool,
+
+    /// Whether imported modules have side effects when evaluated.
+    ///
+    /// When `false`, an import declaration whose bindings are all removed is removed entirely,
+    /// instead of being kept as a bare `import 'module'`.
+    /// Bare imports in the source are always kept.
+    ///
+    /// <https://rollupjs.org/configuration-options/#treeshake-modulesideeffects>
+    ///
+    /// Default `true`
+    pub module_side_effects: bool,
 }
 
 impl Default for TreeShakeOptions {
@@ -153,6 +383,7 @@ impl Default for TreeShakeOptions {
             manual_pure_functions: vec![],
             property_read_side_effects: PropertyReadSideEffects::default(),
             unknown_global_side_effects: true,
+            module_side_effects: true,
         }
     }
 }
@@ -0,0 +1,95 @@
+use oxc_ast::{NONE, ast::*};
+use oxc_ecmascript::{constant_evaluation::ConstantEvaluation, side_effects::MayHaveSideEffects};
+use oxc_span::GetSpan;
+use oxc_syntax::reference::ReferenceFlags;
+
+use crate::{ctx::Ctx, json::JsonWriter};
+
+use super::LatePeepholeOptimizations;
+
+impl<'a> LatePeepholeOptimizations {
+    /// Converts large object and array literals into `JSON.parse` calls,
+    /// with [crate::CompressOptions::json_parse_threshold].
+    ///
+    /// <https://v8.dev/blog/cost-of-javascript-2019#json>
+    ///
+    /// `x = { "a": [1, 2] }` -> `x = JSON.parse('{"a":[1,2]}')`
+    pub fn convert_to_json_parse(expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        let Some(threshold) = ctx.options().json_parse_threshold else { return };
+        if !matches!(expr, Expression::ObjectExpression(_) | Expression::ArrayExpression(_)) {
+            return;
+        }
+        if ctx.scoping().find_binding(ctx.current_scope_id(), "JSON").is_some() {
+            return;
+        }
+        let json = {
+            let ctx = &*ctx;
+            let value_of = |e: &Expression<'a>| {
+                if e.may_have_side_effects(ctx) { None } else { e.evaluate_value(ctx) }
+            };
+            let mut writer = JsonWriter::new(value_of);
+            if writer.write_value(expr).is_none() {
+                return;
+            }
+            writer.into_json()
+        };
+        if json.len() < threshold {
+            return;
+        }
+        let span = expr.span();
+        let json_parse = Expression::from(ctx.ast.member_expression_static(
+            span,
+            ctx.create_unbound_ident_expr(span, "JSON".into(), ReferenceFlags::Read),
+            ctx.ast.identifier_name(span, "parse"),
+            false,
+        ));
+        let arg = ctx.ast.expression_string_literal(span, ctx.ast.atom(&json), None);
+        *expr = ctx.ast.expression_call(
+            span,
+            json_parse,
+            NONE,
+            ctx.ast.vec1(Argument::from(arg)),
+            false,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CompressOptions,
+        tester::{default_options, test_options, test_same_options},
+    };
+
+    fn options(threshold: usize) -> CompressOptions {
+        CompressOptions { json_parse_threshold: Some(threshold), ..default_options() }
+    }
+
+    #[test]
+    fn convert_to_json_parse() {
+        let options = options(10);
+        test_options(
+            "x = { a: [1, 2.5, -1], 'b c': { d: null, e: true } }",
+            r#"x = JSON.parse('{"a":[1,2.5,-1],"b c":{"d":null,"e":true}}')"#,
+            &options,
+        );
+        test_options(
+            "x = [1 + 1, 'a' + 'b', 'ccccc']",
+            r#"x = JSON.parse('[2,"ab","ccccc"]')"#,
+            &options,
+        );
+        test_options("x = ['\"\\naaaaa']", r#"x = JSON.parse('["\\"\\naaaaa"]')"#, &options);
+        // Below threshold.
+        test_same_options("x = { a: 1 }", &options);
+        // Not JSON-compatible.
+        test_same_options("x = { aaaaaaaaaa: void 0 }", &options);
+        test_same_options("x = { aaaaaaaaaa: 1, b }", &options);
+        test_same_options("x = { aaaaaaaaaa: foo() }", &options);
+        test_same_options("x = [1, , 2, 'aaaaaaaaaa']", &options);
+        test_same_options("x = { __proto__: null, aaaaaaaaaa: 1 }", &options);
+        test_same_options("x = { aaaaaaaaaa: 1, f() {} }", &options);
+        test_same_options("x = { [a]: 1, aaaaaaaaaa: 1 }", &options);
+        test_same_options("let JSON; x = { aaaaaaaaaa: 1 }", &options);
+        test_same_options("x = { aaaaaaaaaa: 1 }", &default_options());
+    }
+}
@@ -9,7 +9,7 @@ use oxc_span::GetSpan;
 use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
 use oxc_traverse::Ancestor;
 
-use crate::ctx::Ctx;
+use crate::ctx::{Ctx, is_exact_int64};
 
 use super::{PeepholeOptimizations, State};
 
@@ -343,7 +343,8 @@ impl<'a> PeepholeOptimizations {
                             && right.abs() <= 255.0
                             && right.fract() == 0.0)
                 })
-                .and_then(|_| ctx.eval_binary(e)),
+                .and_then(|_| ctx.eval_binary(e))
+                .or_else(|| Self::try_fold_multiplication_chain(e, ctx)),
             BinaryOperator::Division => Self::extract_numeric_values(e)
                 .filter(|(_, right)| *right == 0.0 || right.is_nan() || right.is_infinite())
                 .and_then(|_| ctx.eval_binary(e)),
@@ -553,6 +554,44 @@ impl<'a> PeepholeOptimizations {
         ))
     }
 
+    /// `x * 2 * 3` -> `x * 6`
+    ///
+    /// Only with [crate::CompressOptionsUnsafe::math], as multiplying the constants first
+    /// may round differently.
+    fn try_fold_multiplication_chain(
+        e: &mut BinaryExpression<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if e.operator != BinaryOperator::Multiplication || !ctx.options().unsafe_options.math {
+            return None;
+        }
+        let Expression::NumericLiteral(right) = &e.right else { return None };
+        let right = right.value;
+        let Expression::BinaryExpression(left) = &mut e.left else { return None };
+        if left.operator != BinaryOperator::Multiplication {
+            return None;
+        }
+        let (constant, expr_to_move) = if let Expression::NumericLiteral(n) = &left.right {
+            (n.value, &mut left.left)
+        } else if let Expression::NumericLiteral(n) = &left.left {
+            (n.value, &mut left.right)
+        } else {
+            return None;
+        };
+        let value = constant * right;
+        // Only fold into integers, which are never longer than the original constants.
+        if !value.is_finite() || !is_exact_int64(value) {
+            return None;
+        }
+        let span = e.right.span();
+        Some(ctx.ast.expression_binary(
+            e.span,
+            expr_to_move.take_in(ctx.ast),
+            BinaryOperator::Multiplication,
+            ctx.value_to_expr(span, ConstantValue::Number(value)),
+        ))
+    }
+
     fn try_fold_number_constructor(
         e: &CallExpression<'a>,
         ctx: &mut Ctx<'a, '_>,
@@ -837,7 +876,10 @@ mod test {
     static MAX_SAFE_INT: i64 = 9_007_199_254_740_991_i64;
     static NEG_MAX_SAFE_INT: i64 = -9_007_199_254_740_991_i64;
 
-    use crate::tester::test;
+    use crate::{
+        CompressOptions, CompressOptionsUnsafe,
+        tester::{default_options, test, test_options, test_same_options},
+    };
 
     // wrap with a function call so it doesn't get removed.
     fn fold(source_text: &str, expected: &str) {
@@ -1927,6 +1969,20 @@ mod test {
         fold("alert(12 & x & 20)", "alert(x & 4)");
     }
 
+    #[test]
+    fn test_unsafe_math() {
+        let options = CompressOptions {
+            unsafe_options: CompressOptionsUnsafe { math: true, ..Default::default() },
+            ..default_options()
+        };
+        test_options("alert(x * 12 * 20)", "alert(x * 240)", &options);
+        test_options("alert(12 * x * 20)", "alert(x * 240)", &options);
+        test_options("x = y + z * 24 * 60 * 60 * 1000", "x = y + z * 864e5", &options);
+        test_same_options("alert(x * 0.1 * 3)", &options);
+        test_same_options("alert(x * y * 3)", &options);
+        test_same_options("alert(x + 12 + 20)", &options);
+    }
+
     #[test]
     fn test_to_number() {
         fold("x = +''", "x = 0");
@@ -0,0 +1,1359 @@
+use std::mem;
+
+use oxc_allocator::{CloneIn, TakeIn, Vec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_ecmascript::{
+    constant_evaluation::{ConstantEvaluation, ConstantValue},
+    side_effects::MayHaveSideEffects,
+};
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolId};
+use oxc_span::{Atom, GetSpan};
+use oxc_syntax::number::ToJsString;
+use oxc_traverse::Ancestor;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    CompressOptionsUnused, MinifierPass,
+    ctx::{Ctx, is_exact_int64},
+    state::{ConstantObject, InlineFunction},
+};
+
+use super::{PeepholeOptimizations, State};
+
+/// Symbol-aware inlining.
+///
+/// * Propagate constant values into their use sites: `const a = 1; f(a)` -> `f(1)`
+/// * Inline single-use functions: `function f() { return a } g(f())` -> `g(a)`
+/// * Collapse single-use variables into the next statement: `let a = f(); a.b()` -> `f().b()`
+/// * Move single-use function declarations to their reference: `function f() {} g(f)` -> `g(function () {})`
+/// * Inline properties of objects which are never mutated: `const o = { a: 1 }; f(o.a)` -> `f(1)`
+///
+/// All rewrites rely on reference counts in `Scoping`, so none are performed
+/// when direct `eval` is present, or on top level symbols in scripts which may be used by other scripts.
+impl<'a> PeepholeOptimizations {
+    fn can_inline_symbol(symbol_id: SymbolId, ctx: &Ctx<'a, '_>) -> bool {
+        let scoping = ctx.scoping();
+        if scoping.root_scope_flags().contains_direct_eval() {
+            return false;
+        }
+        if ctx.source_type().is_script()
+            && scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id()
+        {
+            return false;
+        }
+        !scoping.symbol_is_mutated(symbol_id)
+    }
+
+    /// Save values of `let` and `const` declarations which are never reassigned.
+    pub fn record_constant_values(decl: &VariableDeclaration<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !ctx.options().inline.constants
+            || ctx.options().disabled_passes.contains(&MinifierPass::Inline)
+            || !decl.kind.is_lexical()
+            || Self::is_using(decl.kind)
+        {
+            return;
+        }
+        for declarator in &decl.declarations {
+            let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                continue;
+            };
+            let Some(init) = &declarator.init else { continue };
+            let symbol_id = ident.symbol_id();
+            if !Self::can_inline_symbol(symbol_id, ctx) || init.may_have_side_effects(ctx) {
+                continue;
+            }
+            if let Some(value) = init.evaluate_value(ctx) {
+                ctx.state.constant_values.insert(symbol_id, value);
+            }
+        }
+    }
+
+    /// Save variables whose declarator has been visited, for [Self::is_initialized_at_reference].
+    pub fn record_initialized_variable(declarator: &VariableDeclarator<'a>, ctx: &mut Ctx<'a, '_>) {
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return };
+        if declarator.init.is_none() {
+            return;
+        }
+        // `switch (x) { case 0: const a = 1; break; case 1: f(a) }` may skip the declaration.
+        if matches!(ctx.ancestor(1), Ancestor::SwitchCaseConsequent(_)) {
+            return;
+        }
+        ctx.state.initialized_symbols.insert(ident.symbol_id());
+    }
+
+    /// Whether a variable is initialized when the reference being visited is evaluated.
+    ///
+    /// References are visited in source order, so a reference visited after the declarator
+    /// is evaluated after it, unless it is in a function declaration which is hoisted above it,
+    /// e.g. `f(); const a = 1; function f() { return a }`.
+    fn is_initialized_at_reference(symbol_id: SymbolId, ctx: &Ctx<'a, '_>) -> bool {
+        if !ctx.state.initialized_symbols.contains(&symbol_id) {
+            return false;
+        }
+        let symbol_scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+        ctx.ancestors().all(|ancestor| {
+            let (r#type, scope_id) = match ancestor {
+                Ancestor::FunctionParams(func) => (func.r#type(), func.scope_id()),
+                Ancestor::FunctionBody(func) => (func.r#type(), func.scope_id()),
+                _ => return true,
+            };
+            // A function declaration in a nested block is hoisted to the start of that block,
+            // which is evaluated after the declarator.
+            *r#type != FunctionType::FunctionDeclaration
+                || ctx.scoping().scope_parent_id(scope_id.get().unwrap()) != Some(symbol_scope_id)
+        })
+    }
+
+    /// `const a = 1; f(a)` -> `const a = 1; f(1)`
+    ///
+    /// Values are inlined if the symbol is only referenced once (the declaration is then removed as unused),
+    /// or if the value is no longer than a mangled identifier would be.
+    pub fn inline_constant(
+        &self,
+        expr: &mut Expression<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !ctx.options().inline.constants {
+            return;
+        }
+        let Expression::Identifier(ident) = expr else { return };
+        match ctx.parent() {
+            Ancestor::UnaryExpressionArgument(e) if e.operator().is_delete() => return,
+            Ancestor::ObjectPropertyValue(prop) if *prop.shorthand() => return,
+            _ => {}
+        }
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(value) = ctx.state.constant_values.get(&symbol_id) else { return };
+        if !Self::can_inline_symbol(symbol_id, ctx)
+            || !Self::is_initialized_at_reference(symbol_id, ctx)
+        {
+            return;
+        }
+        let is_single_use = ctx.options().unused != CompressOptionsUnused::Keep
+            && ctx.scoping().get_resolved_reference_ids(symbol_id).len() == 1;
+        if !is_single_use && !Self::is_short_constant(value) {
+            return;
+        }
+        let value = value.clone();
+        let span = ident.span;
+        ctx.delete_reference_for_identifier(ident);
+        *expr = ctx.value_to_expr(span, value);
+        state.changed = true;
+    }
+
+    fn is_short_constant(value: &ConstantValue<'a>) -> bool {
+        match value {
+            ConstantValue::Boolean(_) | ConstantValue::Null | ConstantValue::Undefined => true,
+            ConstantValue::Number(n) => is_exact_int64(*n) && n.abs() < 1000.0,
+            ConstantValue::String(s) => s.len() <= 1,
+            ConstantValue::BigInt(_) => false,
+        }
+    }
+
+    /// Save functions of the form `function f() { return expr }` which are called exactly once,
+    /// and with [crate::CompressOptionsUnsafe::reduce_funcs], other functions which are referenced exactly once.
+    pub fn record_inline_function(func: &Function<'a>, ctx: &mut Ctx<'a, '_>) -> bool {
+        let options = ctx.options();
+        if !(options.inline.functions || options.unsafe_options.reduce_funcs)
+            || options.unused == CompressOptionsUnused::Keep
+        {
+            return false;
+        }
+        let Some(symbol_id) = func.id.as_ref().map(BindingIdentifier::symbol_id) else {
+            return false;
+        };
+        let inline_function = ctx
+            .options()
+            .inline
+            .functions
+            .then(|| Self::get_inline_function_expression(func, symbol_id, ctx))
+            .flatten()
+            .map(|expression| (expression, true))
+            .or_else(|| {
+                Self::get_reduced_function_expression(func, symbol_id, ctx)
+                    .map(|expression| (expression, false))
+            });
+        if let Some((expression, replaces_call)) = inline_function {
+            let scope_id = func.scope_id();
+            ctx.state
+                .inline_functions
+                .insert(symbol_id, InlineFunction { scope_id, expression, replaces_call })
+                .is_none()
+        } else {
+            ctx.state.inline_functions.remove(&symbol_id);
+            false
+        }
+    }
+
+    /// Whether a function declaration is referenced exactly once, and can be moved to the reference.
+    fn is_single_use_function_declaration(
+        func: &Function<'a>,
+        symbol_id: SymbolId,
+        ctx: &Ctx<'a, '_>,
+    ) -> bool {
+        matches!(ctx.parent(), Ancestor::ProgramBody(_) | Ancestor::FunctionBodyStatements(_))
+            && func.is_declaration()
+            && Self::can_inline_symbol(symbol_id, ctx)
+            && ctx.scoping().symbol_redeclarations(symbol_id).is_empty()
+            && ctx.scoping().get_resolved_reference_ids(symbol_id).len() == 1
+    }
+
+    fn get_inline_function_expression(
+        func: &Function<'a>,
+        symbol_id: SymbolId,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !Self::is_single_use_function_declaration(func, symbol_id, ctx)
+            || func.r#async
+            || func.generator
+            || func.params.has_parameter()
+        {
+            return None;
+        }
+        let body = func.body.as_ref()?;
+        if !body.directives.is_empty() {
+            return None;
+        }
+        let argument = match body.statements.as_slice() {
+            [] => None,
+            [Statement::ReturnStatement(ret)] => ret.argument.as_ref(),
+            _ => return None,
+        };
+        let Some(argument) = argument else {
+            return Some(ctx.ast.void_0(func.span));
+        };
+        let mut checker = InlineFunctionBodyChecker { can_inline: true };
+        checker.visit_expression(argument);
+        checker.can_inline.then(|| argument.clone_in_with_semantic_ids(ctx.ast.allocator))
+    }
+
+    /// Copy of a function declaration as a function expression, to replace its only reference.
+    fn get_reduced_function_expression(
+        func: &Function<'a>,
+        symbol_id: SymbolId,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !ctx.options().unsafe_options.reduce_funcs
+            || func.body.is_none()
+            || !Self::is_single_use_function_declaration(func, symbol_id, ctx)
+        {
+            return None;
+        }
+        let mut function = func.clone_in_with_semantic_ids(ctx.ast.allocator);
+        function.r#type = FunctionType::FunctionExpression;
+        if !ctx.options().keep_names.function {
+            function.id = None;
+        }
+        Some(Expression::FunctionExpression(ctx.ast.alloc(function)))
+    }
+
+    /// `function f() { return a } g(f())` -> `g(a)`
+    ///
+    /// The function declaration is removed as unused afterwards.
+    pub fn inline_function_call(
+        &self,
+        expr: &mut Expression<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        let Expression::CallExpression(call_expr) = expr else { return };
+        if call_expr.optional || !call_expr.arguments.is_empty() {
+            return;
+        }
+        let Expression::Identifier(callee) = &call_expr.callee else { return };
+        let Some(reference_id) = callee.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(inline_function) = ctx.state.inline_functions.get(&symbol_id) else { return };
+        if !inline_function.replaces_call {
+            return;
+        }
+        let current_scope_id = ctx.current_scope_id();
+        if ctx.scoping().get_resolved_reference_ids(symbol_id).len() != 1
+            || ctx
+                .scoping()
+                .scope_ancestors(current_scope_id)
+                .any(|id| id == inline_function.scope_id)
+        {
+            return;
+        }
+        // All identifiers in the returned expression must refer to the same symbols at the call site.
+        let mut checker = ReferencesResolveSame {
+            scoping: ctx.scoping(),
+            scope_id: current_scope_id,
+            inner_scope_id: None,
+            resolve_same: true,
+        };
+        checker.visit_expression(&inline_function.expression);
+        if !checker.resolve_same {
+            return;
+        }
+        let Some(inline_function) = ctx.state.inline_functions.remove(&symbol_id) else { return };
+        ctx.delete_reference_for_identifier(callee);
+        *expr = inline_function.expression;
+        state.changed = true;
+    }
+
+    /// `function f() { g() } h(f)` -> `h(function () { g() })`
+    ///
+    /// The function declaration is removed as unused afterwards.
+    pub fn reduce_function_reference(
+        &self,
+        expr: &mut Expression<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !ctx.options().unsafe_options.reduce_funcs {
+            return;
+        }
+        let Expression::Identifier(ident) = expr else { return };
+        if let Ancestor::ObjectPropertyValue(prop) = ctx.parent() {
+            if *prop.shorthand() {
+                return;
+            }
+        }
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(inline_function) = ctx.state.inline_functions.get(&symbol_id) else { return };
+        if inline_function.replaces_call {
+            return;
+        }
+        let current_scope_id = ctx.current_scope_id();
+        if ctx.scoping().get_resolved_reference_ids(symbol_id).len() != 1
+            || ctx
+                .scoping()
+                .scope_ancestors(current_scope_id)
+                .any(|id| id == inline_function.scope_id)
+        {
+            return;
+        }
+        // Identifiers in the function which refer to outer symbols must refer to the same symbols
+        // at the reference.
+        let mut checker = ReferencesResolveSame {
+            scoping: ctx.scoping(),
+            scope_id: current_scope_id,
+            inner_scope_id: Some(inline_function.scope_id),
+            resolve_same: true,
+        };
+        checker.visit_expression(&inline_function.expression);
+        if !checker.resolve_same {
+            return;
+        }
+        let Some(inline_function) = ctx.state.inline_functions.remove(&symbol_id) else { return };
+        ctx.delete_reference_for_identifier(ident);
+        ctx.scoping_mut().change_scope_parent_id(inline_function.scope_id, Some(current_scope_id));
+        *expr = inline_function.expression;
+        state.changed = true;
+    }
+
+    /// `let a = f(); a.b()` -> `f().b()`
+    ///
+    /// A variable which is only referenced in the next statement is moved into it,
+    /// if there is nothing with side effects evaluated before the reference.
+    pub fn collapse_single_use_variables(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !ctx.options().inline.variables {
+            return;
+        }
+        let mut i = 0;
+        while i + 1 < stmts.len() {
+            let (current, rest) = stmts.split_at_mut(i + 1);
+            let Statement::VariableDeclaration(decl) = &mut current[i] else {
+                i += 1;
+                continue;
+            };
+            if Self::is_using(decl.kind) {
+                i += 1;
+                continue;
+            }
+            // Declarators are evaluated in order, so only the last one can be moved.
+            while let Some(declarator) = decl.declarations.last_mut() {
+                let Some(symbol_id) = Self::get_single_use_variable(declarator, ctx) else {
+                    break;
+                };
+                let replacement_has_side_effects =
+                    declarator.init.as_ref().is_some_and(|init| init.may_have_side_effects(ctx));
+                let mut substitution = SingleUseSubstitution {
+                    symbol_id,
+                    replacement: declarator.init.take(),
+                    replacement_has_side_effects,
+                };
+                let result =
+                    Self::substitute_single_use_in_statement(&mut rest[0], &mut substitution, ctx);
+                if result == SubstituteResult::Done {
+                    decl.declarations.pop();
+                    state.changed = true;
+                } else {
+                    declarator.init = substitution.replacement;
+                    break;
+                }
+            }
+            if decl.declarations.is_empty() {
+                stmts.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    fn get_single_use_variable(
+        declarator: &VariableDeclarator<'a>,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<SymbolId> {
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return None;
+        };
+        declarator.init.as_ref()?;
+        let symbol_id = ident.symbol_id();
+        if !Self::can_inline_symbol(symbol_id, ctx)
+            || !ctx.scoping().symbol_redeclarations(symbol_id).is_empty()
+            || ctx.scoping().get_resolved_reference_ids(symbol_id).len() != 1
+        {
+            return None;
+        }
+        Some(symbol_id)
+    }
+
+    fn substitute_single_use_in_statement(
+        stmt: &mut Statement<'a>,
+        substitution: &mut SingleUseSubstitution<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> SubstituteResult {
+        match stmt {
+            Statement::ExpressionStatement(s) => {
+                Self::substitute_single_use_in_expression(&mut s.expression, substitution, ctx)
+            }
+            Statement::ReturnStatement(s) => match &mut s.argument {
+                Some(argument) => {
+                    Self::substitute_single_use_in_expression(argument, substitution, ctx)
+                }
+                None => SubstituteResult::Stop,
+            },
+            Statement::ThrowStatement(s) => {
+                Self::substitute_single_use_in_expression(&mut s.argument, substitution, ctx)
+            }
+            Statement::IfStatement(s) => {
+                Self::substitute_single_use_in_expression(&mut s.test, substitution, ctx)
+            }
+            Statement::SwitchStatement(s) => {
+                Self::substitute_single_use_in_expression(&mut s.discriminant, substitution, ctx)
+            }
+            Statement::VariableDeclaration(decl) if !Self::is_using(decl.kind) => {
+                for declarator in &mut decl.declarations {
+                    if let Some(init) = &mut declarator.init {
+                        match Self::substitute_single_use_in_expression(init, substitution, ctx) {
+                            SubstituteResult::Continue => {}
+                            result => return result,
+                        }
+                    }
+                    // Destructuring may call getters.
+                    if !declarator.id.kind.is_binding_identifier() {
+                        return SubstituteResult::Stop;
+                    }
+                }
+                SubstituteResult::Stop
+            }
+            _ => SubstituteResult::Stop,
+        }
+    }
+
+    /// Visit `expr` in evaluation order, and replace the reference to the substituted symbol.
+    ///
+    /// Stops at the first expression which may have side effects, or which may be affected by
+    /// side effects of the replacement.
+    fn substitute_single_use_in_expression(
+        expr: &mut Expression<'a>,
+        substitution: &mut SingleUseSubstitution<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> SubstituteResult {
+        let result = match expr {
+            Expression::Identifier(ident) => {
+                if Self::is_reference_to(ident, substitution.symbol_id, ctx) {
+                    ctx.delete_reference_for_identifier(ident);
+                    if let Some(replacement) = substitution.replacement.take() {
+                        *expr = replacement;
+                    }
+                    return SubstituteResult::Done;
+                }
+                SubstituteResult::Continue
+            }
+            Expression::ParenthesizedExpression(e) => {
+                Self::substitute_single_use_in_expression(&mut e.expression, substitution, ctx)
+            }
+            Expression::UnaryExpression(e) if !e.operator.is_delete() => {
+                Self::substitute_single_use_in_expression(&mut e.argument, substitution, ctx)
+            }
+            Expression::BinaryExpression(e) => {
+                let e = &mut **e;
+                Self::substitute_single_use_in_expressions(
+                    [&mut e.left, &mut e.right],
+                    substitution,
+                    ctx,
+                )
+            }
+            // Only the first operand is always evaluated.
+            Expression::LogicalExpression(e) => {
+                match Self::substitute_single_use_in_expression(&mut e.left, substitution, ctx) {
+                    SubstituteResult::Continue => SubstituteResult::Stop,
+                    result => result,
+                }
+            }
+            Expression::ConditionalExpression(e) => {
+                match Self::substitute_single_use_in_expression(&mut e.test, substitution, ctx) {
+                    SubstituteResult::Continue => SubstituteResult::Stop,
+                    result => result,
+                }
+            }
+            Expression::SequenceExpression(e) => Self::substitute_single_use_in_expressions(
+                e.expressions.iter_mut(),
+                substitution,
+                ctx,
+            ),
+            Expression::StaticMemberExpression(e) => {
+                Self::substitute_single_use_in_expression(&mut e.object, substitution, ctx)
+            }
+            Expression::ComputedMemberExpression(e) => {
+                let e = &mut **e;
+                Self::substitute_single_use_in_expressions(
+                    [&mut e.object, &mut e.expression],
+                    substitution,
+                    ctx,
+                )
+            }
+            Expression::CallExpression(e) => {
+                // `let a = b.c; a()` -> `b.c()` would change `this` of the call,
+                // and `let a = eval; a()` -> `eval()` would make an indirect eval direct.
+                if let Expression::Identifier(callee) = &e.callee {
+                    if Self::is_reference_to(callee, substitution.symbol_id, ctx)
+                        && substitution.replacement.as_ref().is_some_and(|replacement| {
+                            replacement.is_member_expression()
+                                || matches!(replacement, Expression::ChainExpression(_))
+                                || replacement.is_specific_id("eval")
+                        })
+                    {
+                        return SubstituteResult::Stop;
+                    }
+                }
+                match Self::substitute_single_use_in_expression(&mut e.callee, substitution, ctx) {
+                    SubstituteResult::Continue => Self::substitute_single_use_in_arguments(
+                        &mut e.arguments,
+                        substitution,
+                        ctx,
+                    ),
+                    result => result,
+                }
+            }
+            Expression::NewExpression(e) => {
+                match Self::substitute_single_use_in_expression(&mut e.callee, substitution, ctx) {
+                    SubstituteResult::Continue => Self::substitute_single_use_in_arguments(
+                        &mut e.arguments,
+                        substitution,
+                        ctx,
+                    ),
+                    result => result,
+                }
+            }
+            Expression::ArrayExpression(e) => {
+                let mut result = SubstituteResult::Continue;
+                for element in &mut e.elements {
+                    result = match element {
+                        ArrayExpressionElement::Elision(_) => SubstituteResult::Continue,
+                        element => match element.as_expression_mut() {
+                            Some(element) => Self::substitute_single_use_in_expression(
+                                element,
+                                substitution,
+                                ctx,
+                            ),
+                            None => SubstituteResult::Stop,
+                        },
+                    };
+                    if result != SubstituteResult::Continue {
+                        break;
+                    }
+                }
+                result
+            }
+            Expression::ObjectExpression(e) => {
+                let mut result = SubstituteResult::Continue;
+                for property in &mut e.properties {
+                    result = match property {
+                        ObjectPropertyKind::ObjectProperty(prop)
+                            if !prop.computed && !prop.shorthand =>
+                        {
+                            Self::substitute_single_use_in_expression(
+                                &mut prop.value,
+                                substitution,
+                                ctx,
+                            )
+                        }
+                        _ => SubstituteResult::Stop,
+                    };
+                    if result != SubstituteResult::Continue {
+                        break;
+                    }
+                }
+                result
+            }
+            Expression::TemplateLiteral(e) => Self::substitute_single_use_in_expressions(
+                e.expressions.iter_mut(),
+                substitution,
+                ctx,
+            ),
+            Expression::AssignmentExpression(e) => {
+                if e.operator.is_assign()
+                    && matches!(e.left, AssignmentTarget::AssignmentTargetIdentifier(_))
+                {
+                    match Self::substitute_single_use_in_expression(&mut e.right, substitution, ctx)
+                    {
+                        SubstituteResult::Continue => SubstituteResult::Stop,
+                        result => result,
+                    }
+                } else {
+                    SubstituteResult::Stop
+                }
+            }
+            _ => SubstituteResult::Continue,
+        };
+        if result == SubstituteResult::Continue
+            && !Self::can_substitute_past(expr, substitution, ctx)
+        {
+            return SubstituteResult::Stop;
+        }
+        result
+    }
+
+    fn substitute_single_use_in_expressions<'e>(
+        exprs: impl IntoIterator<Item = &'e mut Expression<'a>>,
+        substitution: &mut SingleUseSubstitution<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> SubstituteResult
+    where
+        'a: 'e,
+    {
+        for expr in exprs {
+            match Self::substitute_single_use_in_expression(expr, substitution, ctx) {
+                SubstituteResult::Continue => {}
+                result => return result,
+            }
+        }
+        SubstituteResult::Continue
+    }
+
+    fn substitute_single_use_in_arguments(
+        arguments: &mut Vec<'a, Argument<'a>>,
+        substitution: &mut SingleUseSubstitution<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> SubstituteResult {
+        for argument in arguments {
+            let Some(argument) = argument.as_expression_mut() else {
+                return SubstituteResult::Stop;
+            };
+            match Self::substitute_single_use_in_expression(argument, substitution, ctx) {
+                SubstituteResult::Continue => {}
+                result => return result,
+            }
+        }
+        SubstituteResult::Continue
+    }
+
+    /// Whether evaluation of `expr` can be moved after the replacement.
+    fn can_substitute_past(
+        expr: &Expression<'a>,
+        substitution: &SingleUseSubstitution<'a>,
+        ctx: &Ctx<'a, '_>,
+    ) -> bool {
+        if expr.may_have_side_effects(ctx) {
+            return false;
+        }
+        if !substitution.replacement_has_side_effects {
+            return true;
+        }
+        // The replacement may change the value of anything else.
+        match expr {
+            Expression::Identifier(ident) => ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| ctx.scoping().get_reference(reference_id).symbol_id())
+                .is_some_and(|symbol_id| !ctx.scoping().symbol_is_mutated(symbol_id)),
+            _ => expr.is_literal() || expr.is_void_0(),
+        }
+    }
+
+    /// Find objects whose properties can be inlined. Run once, before the first iteration.
+    ///
+    /// An object can be inlined if all references to it read a property with a static key,
+    /// other than as the callee of a call (which would pass the object as `this`).
+    pub fn collect_constant_objects(program: &Program<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !ctx.options().inline.properties
+            || ctx.options().disabled_passes.contains(&MinifierPass::Inline)
+        {
+            return;
+        }
+        let mut collector = ConstantObjectCollector {
+            ctx,
+            objects: FxHashMap::default(),
+            escaped: FxHashSet::default(),
+            reads: vec![],
+            executed_once: false,
+        };
+        collector.visit_program(program);
+        let ConstantObjectCollector { mut objects, escaped, reads, .. } = collector;
+        for (symbol_id, key) in reads {
+            if let Some((_, count)) =
+                objects.get_mut(&symbol_id).and_then(|object| object.properties.get_mut(&key))
+            {
+                *count += 1;
+            }
+        }
+        for (symbol_id, object) in objects {
+            if !escaped.contains(&symbol_id) && Self::can_inline_symbol(symbol_id, ctx) {
+                ctx.state.constant_objects.insert(symbol_id, object);
+            }
+        }
+    }
+
+    /// `const o = { a: 1 }; f(o.a)` -> `const o = { a: 1 }; f(1)`
+    ///
+    /// Values are inlined by the same rules as [Self::inline_constant].
+    pub fn inline_constant_property(
+        &self,
+        expr: &mut Expression<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if ctx.state.constant_objects.is_empty() {
+            return;
+        }
+        if let Expression::CallExpression(call) = expr {
+            Self::remove_enum_self_reference(call, state, ctx);
+            return;
+        }
+        let Some(member) = expr.as_member_expression() else { return };
+        let Some((ident, key)) = Self::member_static_key(member, ctx) else { return };
+        let Some(symbol_id) = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| ctx.scoping().get_reference(reference_id).symbol_id())
+        else {
+            return;
+        };
+        let Some((value, reads)) = ctx
+            .state
+            .constant_objects
+            .get(&symbol_id)
+            .and_then(|object| object.properties.get(&key))
+        else {
+            return;
+        };
+        if !Self::is_initialized_at_reference(symbol_id, ctx) {
+            return;
+        }
+        let is_single_use = ctx.options().unused != CompressOptionsUnused::Keep && *reads == 1;
+        if !is_single_use && !Self::is_short_constant(value) {
+            return;
+        }
+        let value = value.clone();
+        let span = member.span();
+        ctx.delete_reference_for_identifier(ident);
+        *expr = ctx.value_to_expr(span, value);
+        state.changed = true;
+    }
+
+    /// `var Foo = (Foo => { ... })(Foo || {})` -> `var Foo = /* @__PURE__ */ (Foo => { ... })({})`
+    ///
+    /// Marked as pure so the enum is removed once all its members are inlined.
+    fn remove_enum_self_reference(
+        call: &mut CallExpression<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        let [arg] = call.arguments.as_mut_slice() else { return };
+        let Argument::LogicalExpression(e) = arg else { return };
+        let Expression::Identifier(ident) = &e.left else { return };
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        if ctx
+            .state
+            .constant_objects
+            .get(&symbol_id)
+            .is_none_or(|object| object.self_reference != Some(reference_id))
+        {
+            return;
+        }
+        ctx.delete_reference_for_identifier(ident);
+        let right = e.right.take_in(ctx.ast);
+        *arg = Argument::from(right);
+        call.pure = true;
+        state.changed = true;
+    }
+
+    /// `o.a`, `o['a']` or `o[0]` -> (`o`, `a`)
+    fn member_static_key<'m>(
+        member: &'m MemberExpression<'a>,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<(&'m IdentifierReference<'a>, Atom<'a>)> {
+        let Expression::Identifier(ident) = member.object() else { return None };
+        let key = match member {
+            MemberExpression::StaticMemberExpression(e) => e.property.name,
+            MemberExpression::ComputedMemberExpression(e) => Self::literal_key(&e.expression, ctx)?,
+            MemberExpression::PrivateFieldExpression(_) => return None,
+        };
+        Some((ident, key))
+    }
+
+    /// Property key of a literal, as a string.
+    fn literal_key(expr: &Expression<'a>, ctx: &Ctx<'a, '_>) -> Option<Atom<'a>> {
+        match expr {
+            Expression::StringLiteral(lit) => Some(lit.value),
+            Expression::NumericLiteral(lit) => Some(ctx.ast.atom(&lit.value.to_js_string())),
+            _ => None,
+        }
+    }
+
+    fn is_using(kind: VariableDeclarationKind) -> bool {
+        matches!(kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing)
+    }
+
+    fn is_reference_to(
+        ident: &IdentifierReference<'a>,
+        symbol_id: SymbolId,
+        ctx: &Ctx<'a, '_>,
+    ) -> bool {
+        ident.reference_id.get().is_some_and(|reference_id| {
+            ctx.scoping().get_reference(reference_id).symbol_id() == Some(symbol_id)
+        })
+    }
+}
+
+struct SingleUseSubstitution<'a> {
+    symbol_id: SymbolId,
+    /// `None` after the substitution is done.
+    replacement: Option<Expression<'a>>,
+    replacement_has_side_effects: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SubstituteResult {
+    /// The reference was replaced.
+    Done,
+    /// The reference was not found, and it is safe to continue looking in the following expressions.
+    Continue,
+    /// The reference was not found, and it is unsafe to continue.
+    Stop,
+}
+
+/// Check that an expression does not depend on the function it is returned from.
+struct InlineFunctionBodyChecker {
+    can_inline: bool,
+}
+
+impl<'a> Visit<'a> for InlineFunctionBodyChecker {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == "arguments" {
+            self.can_inline = false;
+        }
+    }
+
+    fn visit_this_expression(&mut self, _: &ThisExpression) {
+        self.can_inline = false;
+    }
+
+    fn visit_super(&mut self, _: &Super) {
+        self.can_inline = false;
+    }
+
+    fn visit_meta_property(&mut self, _: &MetaProperty<'a>) {
+        self.can_inline = false;
+    }
+
+    fn visit_private_identifier(&mut self, _: &PrivateIdentifier<'a>) {
+        self.can_inline = false;
+    }
+
+    fn visit_function(&mut self, _: &Function<'a>, _: ScopeFlags) {
+        self.can_inline = false;
+    }
+
+    fn visit_arrow_function_expression(&mut self, _: &ArrowFunctionExpression<'a>) {
+        self.can_inline = false;
+    }
+
+    fn visit_class(&mut self, _: &Class<'a>) {
+        self.can_inline = false;
+    }
+}
+
+/// Check that all identifiers in an expression resolve to the same symbols in another scope.
+struct ReferencesResolveSame<'s> {
+    scoping: &'s Scoping,
+    scope_id: ScopeId,
+    /// Symbols declared in this scope or its descendants are moved along with the expression,
+    /// so are not checked.
+    inner_scope_id: Option<ScopeId>,
+    resolve_same: bool,
+}
+
+impl<'a> Visit<'a> for ReferencesResolveSame<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.scoping.get_reference(reference_id).symbol_id());
+        if let (Some(inner_scope_id), Some(symbol_id)) = (self.inner_scope_id, symbol_id) {
+            let symbol_scope_id = self.scoping.symbol_scope_id(symbol_id);
+            if self.scoping.scope_ancestors(symbol_scope_id).any(|id| id == inner_scope_id) {
+                return;
+            }
+        }
+        if self.scoping.find_binding(self.scope_id, &ident.name) != symbol_id {
+            self.resolve_same = false;
+        }
+    }
+}
+
+/// Find objects which are never mutated and never escape, for
+/// [PeepholeOptimizations::collect_constant_objects].
+struct ConstantObjectCollector<'a, 'c, 'b> {
+    ctx: &'c Ctx<'a, 'b>,
+    objects: FxHashMap<SymbolId, ConstantObject<'a>>,
+    /// Symbols which are referenced other than by reading a property.
+    escaped: FxHashSet<SymbolId>,
+    /// Property reads `o.a`.
+    reads: std::vec::Vec<(SymbolId, Atom<'a>)>,
+    /// Whether the variable declaration being visited is directly in a program or function body,
+    /// so is executed at most once.
+    executed_once: bool,
+}
+
+impl<'a> ConstantObjectCollector<'a, '_, '_> {
+    fn symbol_id(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.ctx.scoping().get_reference(reference_id).symbol_id())
+    }
+
+    /// `o.a = 1`, `o.a()`, `delete o.a`
+    fn escape_member_object(&mut self, member: &MemberExpression<'a>) {
+        if let Expression::Identifier(ident) = member.object() {
+            if let Some(symbol_id) = self.symbol_id(ident) {
+                self.escaped.insert(symbol_id);
+            }
+        }
+    }
+
+    fn visit_body_statements(&mut self, stmts: &Vec<'a, Statement<'a>>) {
+        for stmt in stmts {
+            self.executed_once = matches!(stmt, Statement::VariableDeclaration(_));
+            self.visit_statement(stmt);
+        }
+        self.executed_once = false;
+    }
+
+    fn constant_value(&self, expr: &Expression<'a>) -> Option<ConstantValue<'a>> {
+        if expr.may_have_side_effects(self.ctx) { None } else { expr.evaluate_value(self.ctx) }
+    }
+
+    /// `const o = { a: 1 }`
+    fn object_literal(&self, object: &ObjectExpression<'a>) -> Option<ConstantObject<'a>> {
+        let mut properties = FxHashMap::default();
+        for prop in &object.properties {
+            // Spread properties may override any property.
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else { return None };
+            // Accessors and methods receive the object as `this`, and may mutate it,
+            // e.g. `{ a: 1, get b() { this.a = 2 } }`.
+            if prop.kind != PropertyKind::Init
+                || prop.method
+                || matches!(prop.value, Expression::FunctionExpression(_))
+            {
+                return None;
+            }
+            let key = match &prop.key {
+                PropertyKey::StaticIdentifier(ident) => ident.name,
+                key => PeepholeOptimizations::literal_key(key.as_expression()?, self.ctx)?,
+            };
+            // `__proto__: x` sets the prototype instead of defining a property,
+            // whose accessors would also receive the object as `this`.
+            if key == "__proto__" && !prop.computed && !prop.shorthand {
+                if !prop.value.is_null() {
+                    return None;
+                }
+                continue;
+            }
+            match self.constant_value(&prop.value) {
+                Some(value) => properties.insert(key, (value, 0)),
+                // Overrides the previous value, e.g. `{ a: 1, a: f() }`.
+                None => properties.remove(&key),
+            };
+        }
+        Some(ConstantObject { properties, self_reference: None })
+    }
+
+    /// `var Foo = (Foo => { Foo[Foo['A'] = 0] = 'A'; Foo['B'] = 'b'; return Foo })(Foo || {})`,
+    /// as emitted for TypeScript enums.
+    fn enum_iife(
+        &self,
+        symbol_id: SymbolId,
+        call: &CallExpression<'a>,
+        executed_once: bool,
+    ) -> Option<ConstantObject<'a>> {
+        let (params, statements) = match call.callee.without_parentheses() {
+            Expression::ArrowFunctionExpression(arrow) if !arrow.r#async && !arrow.expression => {
+                (&arrow.params, &arrow.body.statements)
+            }
+            Expression::FunctionExpression(func) if !func.r#async && !func.generator => {
+                (&func.params, &func.body.as_ref()?.statements)
+            }
+            _ => return None,
+        };
+        let [param] = params.items.as_slice() else { return None };
+        if params.rest.is_some() {
+            return None;
+        }
+        let param_id = param.pattern.get_binding_identifier()?.symbol_id();
+        let is_param = |expr: &Expression<'a>| {
+            matches!(expr, Expression::Identifier(ident)
+                if PeepholeOptimizations::is_reference_to(ident, param_id, self.ctx))
+        };
+
+        let [arg] = call.arguments.as_slice() else { return None };
+        let self_reference = match arg.as_expression()? {
+            Expression::ObjectExpression(object) if object.properties.is_empty() => None,
+            Expression::LogicalExpression(e) if e.operator == LogicalOperator::Or => {
+                let Expression::Identifier(ident) = &e.left else { return None };
+                let Expression::ObjectExpression(object) = &e.right else { return None };
+                // `Foo` is only known to be `undefined` if this is its only declaration,
+                // and it is executed once.
+                if !object.properties.is_empty()
+                    || !executed_once
+                    || self.symbol_id(ident) != Some(symbol_id)
+                    || !self.ctx.scoping().symbol_redeclarations(symbol_id).is_empty()
+                {
+                    return None;
+                }
+                Some(ident.reference_id())
+            }
+            _ => return None,
+        };
+
+        let (Statement::ReturnStatement(ret), members) = statements.split_last()? else {
+            return None;
+        };
+        if !ret.argument.as_ref().is_some_and(is_param) {
+            return None;
+        }
+        let mut properties = FxHashMap::default();
+        for stmt in members {
+            let Statement::ExpressionStatement(stmt) = stmt else { return None };
+            let Expression::AssignmentExpression(assign) = &stmt.expression else { return None };
+            if assign.operator != AssignmentOperator::Assign {
+                return None;
+            }
+            let member = assign.left.as_simple_assignment_target()?.as_member_expression()?;
+            if !is_param(member.object()) {
+                return None;
+            }
+            match member {
+                // `Foo[Foo['A'] = 0] = 'A'`
+                MemberExpression::ComputedMemberExpression(e)
+                    if matches!(e.expression, Expression::AssignmentExpression(_)) =>
+                {
+                    let Expression::AssignmentExpression(inner) = &e.expression else {
+                        return None;
+                    };
+                    let inner_member =
+                        inner.left.as_simple_assignment_target()?.as_member_expression()?;
+                    let (object, key) =
+                        PeepholeOptimizations::member_static_key(inner_member, self.ctx)?;
+                    let Expression::StringLiteral(name) = &assign.right else { return None };
+                    if inner.operator != AssignmentOperator::Assign
+                        || !PeepholeOptimizations::is_reference_to(object, param_id, self.ctx)
+                        || name.value != key
+                    {
+                        return None;
+                    }
+                    let ConstantValue::Number(n) = self.constant_value(&inner.right)? else {
+                        return None;
+                    };
+                    properties.insert(key, (ConstantValue::Number(n), 0));
+                    let reverse_key = self.ctx.ast.atom(&n.to_js_string());
+                    properties.insert(reverse_key, (ConstantValue::String(key.into()), 0));
+                }
+                // `Foo['B'] = 'b'`
+                _ => {
+                    let (_, key) = PeepholeOptimizations::member_static_key(member, self.ctx)?;
+                    properties.insert(key, (self.constant_value(&assign.right)?, 0));
+                }
+            }
+        }
+        Some(ConstantObject { properties, self_reference })
+    }
+}
+
+impl<'a> Visit<'a> for ConstantObjectCollector<'a, '_, '_> {
+    fn visit_program(&mut self, program: &Program<'a>) {
+        self.visit_body_statements(&program.body);
+    }
+
+    fn visit_function_body(&mut self, body: &FunctionBody<'a>) {
+        self.visit_body_statements(&body.statements);
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        let executed_once = mem::take(&mut self.executed_once);
+        for declarator in &decl.declarations {
+            let (Some(ident), Some(init)) =
+                (declarator.id.get_binding_identifier(), &declarator.init)
+            else {
+                self.visit_variable_declarator(declarator);
+                continue;
+            };
+            let symbol_id = ident.symbol_id();
+            match init {
+                Expression::ObjectExpression(object) if decl.kind.is_lexical() => {
+                    if let Some(object) = self.object_literal(object) {
+                        self.objects.insert(symbol_id, object);
+                    }
+                }
+                Expression::CallExpression(call) if decl.kind.is_var() => {
+                    if let Some(object) = self.enum_iife(symbol_id, call, executed_once) {
+                        self.objects.insert(symbol_id, object);
+                        // The initializer only references the enum parameter, and `Foo` in `Foo || {}`.
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            self.visit_variable_declarator(declarator);
+        }
+    }
+
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        if let Some(Declaration::VariableDeclaration(var_decl)) = &decl.declaration {
+            for declarator in &var_decl.declarations {
+                if let Some(ident) = declarator.id.get_binding_identifier() {
+                    self.escaped.insert(ident.symbol_id());
+                }
+            }
+        }
+        walk::walk_export_named_declaration(self, decl);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(symbol_id) = self.symbol_id(ident) {
+            self.escaped.insert(symbol_id);
+        }
+    }
+
+    fn visit_member_expression(&mut self, member: &MemberExpression<'a>) {
+        if let Some((ident, key)) = PeepholeOptimizations::member_static_key(member, self.ctx) {
+            if let Some(symbol_id) = self.symbol_id(ident) {
+                self.reads.push((symbol_id, key));
+                return;
+            }
+        }
+        walk::walk_member_expression(self, member);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        if let Some(member) = target.as_member_expression() {
+            self.escape_member_object(member);
+        }
+        walk::walk_simple_assignment_target(self, target);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Some(member) = call.callee.without_parentheses().as_member_expression() {
+            self.escape_member_object(member);
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_tagged_template_expression(&mut self, e: &TaggedTemplateExpression<'a>) {
+        if let Some(member) = e.tag.without_parentheses().as_member_expression() {
+            self.escape_member_object(member);
+        }
+        walk::walk_tagged_template_expression(self, e);
+    }
+
+    fn visit_unary_expression(&mut self, e: &UnaryExpression<'a>) {
+        if e.operator.is_delete() {
+            if let Some(member) = e.argument.without_parentheses().as_member_expression() {
+                self.escape_member_object(member);
+            }
+        }
+        walk::walk_unary_expression(self, e);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CompressOptions, CompressOptionsInline, CompressOptionsUnsafe, CompressOptionsUnused,
+        tester::{test_options, test_same_options},
+    };
+
+    fn options() -> CompressOptions {
+        CompressOptions { inline: CompressOptionsInline::all_true(), ..CompressOptions::smallest() }
+    }
+
+    #[track_caller]
+    fn test(source_text: &str, expected: &str) {
+        test_options(source_text, expected, &options());
+    }
+
+    #[track_caller]
+    fn test_same(source_text: &str) {
+        test_same_options(source_text, &options());
+    }
+
+    #[test]
+    fn inline_constants() {
+        test("const a = 1; console.log(a, a)", "console.log(1, 1)");
+        test("let a = !0; console.log(a, a)", "console.log(!0, !0)");
+        test("const a = 'x'; console.log(a, a)", "console.log('x', 'x')");
+        test("const a = 'long string'; console.log(a)", "console.log('long string')");
+        test_same("const a = 'long string'; console.log(a, a)");
+        test_same("const a = 1000; console.log(a, a)");
+        test_same("let a = 1; function f() { a = 2 } console.log(a, a, f)");
+        test_same("const a = 1; console.log({ a })");
+
+        let options = CompressOptions { unused: CompressOptionsUnused::Keep, ..options() };
+        test_options("const a = 1; console.log(a, a)", "const a = 1; console.log(1, 1)", &options);
+        test_same_options("const a = 'long string'; console.log(a)", &options);
+
+        // References evaluated before the declaration.
+        let options = CompressOptions {
+            inline: CompressOptionsInline { constants: true, ..CompressOptionsInline::all_false() },
+            ..super::test::options()
+        };
+        test_same_options("f(); const a = 1; function f() { return a }", &options);
+        test_same_options("g(() => a); const a = 1", &options);
+        test_same_options("switch (x) { case 0: let a = 1; break; default: f(a) }", &options);
+        test_options("const a = 1; g(() => a)", "g(() => 1)", &options);
+        test_options(
+            "const a = 1; { f(); function f() { g(a) } }",
+            "{ f(); function f() { g(1) } }",
+            &options,
+        );
+    }
+
+    #[test]
+    fn inline_functions() {
+        test("function f() { return a + b } g(f())", "g(a + b)");
+        test("function f() {} g(f(), 1)", "g(void 0, 1)");
+        test_same("function f(x) { return x } g(f(1))");
+        test_same("function f() { return this } g(f())");
+        test_same("function f() { return a } g(f(), f())");
+        test_same("let a = x; function f() { return a } function g(a) { return f() } h(g)");
+    }
+
+    #[test]
+    fn inline_properties() {
+        let options = CompressOptions {
+            inline: CompressOptionsInline {
+                properties: true,
+                ..CompressOptionsInline::all_false()
+            },
+            ..CompressOptions::smallest()
+        };
+        let test =
+            |source_text: &str, expected: &str| test_options(source_text, expected, &options);
+        let test_same = |source_text: &str| test_same_options(source_text, &options);
+
+        test("const o = { a: 1, 'b': 'x', 2: !0 }; f(o.a, o['b'], o[2], o.a)", "f(1, 'x', !0, 1)");
+        test("const o = { a: 'long string', b: 2 }; f(o.a, o.b)", "f('long string', 2)");
+        test(
+            "const o = { a: 'long string', b: 2 }; f(o.a, o.a, o.b)",
+            "const o = { a: 'long string', b: 2 }; f(o.a, o.a, 2)",
+        );
+        test_same("const o = { a: 1 }; f(o.a, o.a = 2)");
+        test_same("const o = { a: 1 }; f(o.a, o.a++)");
+        test_same("const o = { a: 1 }; f(o.a, delete o.a)");
+        test_same("const o = { a: 1 }; f(o.a, o.a())");
+        test_same("const o = { a: 1 }; f(o.a, o)");
+        test_same("const o = { a: 1 }; f(o.a, o[a])");
+        test_same("const o = { a: 1, ...p }; f(o.a)");
+        test_same("const o = { a: 1, get a() { return 2 } }; f(o.a)");
+        // Accessors and methods may mutate the object through `this`.
+        test(
+            "const o = { a: 1, get b() { this.a = 2 } }; o.b; f(o.a)",
+            "const o = { a: 1, get b() { this.a = 2 } }; o.b, f(o.a)",
+        );
+        test(
+            "const o = { a: 1, __proto__: { get b() { this.a = 2 } } }; o.b; f(o.a)",
+            "const o = { a: 1, __proto__: { get b() { this.a = 2 } } }; o.b, f(o.a)",
+        );
+        test_same("const o = { a: 1, m() { this.a = 2 } }; f(o.a)");
+        test_same("var o = { a: 1 }; f(o.a)");
+        test_same("export const o = { a: 1 }; f(o.a)");
+        // References evaluated before the declaration.
+        test_same("f(); const o = { a: 1 }; function f() { return o.a }");
+        test_same("g(() => o.a); const o = { a: 1 }");
+        test("const o = { a: 1 }; g(() => o.a)", "g(() => 1)");
+
+        test(
+            "var Foo = (Foo => { Foo[Foo['A'] = 0] = 'A'; Foo['B'] = 'b'; return Foo })(Foo || {});
+            f(Foo.A, Foo[0], Foo.B)",
+            "f(0, 'A', 'b')",
+        );
+        test(
+            "var Foo = (function (Foo) { Foo[Foo['A'] = 0] = 'A'; return Foo })(Foo || {});
+            f(Foo.A)",
+            "f(0)",
+        );
+    }
+
+    #[test]
+    fn reduce_funcs() {
+        let options = CompressOptions {
+            unsafe_options: CompressOptionsUnsafe { reduce_funcs: true, ..Default::default() },
+            ..CompressOptions::smallest()
+        };
+        test_options("function f(a) { g(a) } h(f)", "h(function (a) { g(a) })", &options);
+        test_options("h(f); function f() { g() }", "h(function () { g() })", &options);
+        test_options(
+            "function f() { function g() { a() } return g } h(f)",
+            "h(function () { return function () { a() } })",
+            &options,
+        );
+        test_options(
+            "function f() { return a } function g(a) { return f } h(g)",
+            "function f() { return a } h(function (a) { return f })",
+            &options,
+        );
+        test_same_options("function f() { g() } h(f, f)", &options);
+        test_same_options("function f() { f() } h(f)", &options);
+        test_same_options("function f() {} h({ f })", &options);
+        test_same_options("function f() {} export { f }", &options);
+        test_same_options("function f() { g() } h(f)", &CompressOptions::smallest());
+    }
+
+    #[test]
+    fn collapse_single_use_variables() {
+        test("let a = foo(); a.b()", "foo().b()");
+        test("let a = foo(); return a", "return foo()");
+        test("let a = foo(); throw a", "throw foo()");
+        test("let a = foo(); if (a) bar()", "foo() && bar()");
+        test("let a = foo(), b = bar(); b.c(a)", "let a = foo(); bar().c(a)");
+        test_same("let a = foo(); bar(a)");
+        test_same("let a = foo(); bar.c = a");
+        test_same("let a = foo(); bar() || a.b()");
+        test_same("let a = foo(); a.b(), a.c()");
+        test_same("let a = b.c; a()");
+    }
+}
@@ -12,7 +12,7 @@ impl<'a> PeepholeOptimizations {
         &self,
         stmt: &mut Statement<'a>,
         ctx: &mut Ctx<'a, '_>,
-    ) {
+    ) -> bool {
         let expr = match stmt {
             Statement::IfStatement(s) => Some(&mut s.test),
             Statement::WhileStatement(s) => Some(&mut s.test),
@@ -21,9 +21,7 @@ impl<'a> PeepholeOptimizations {
             _ => None,
         };
 
-        if let Some(expr) = expr {
-            self.try_fold_expr_in_boolean_context(expr, ctx);
-        }
+        expr.is_some_and(|expr| self.try_fold_expr_in_boolean_context(expr, ctx))
     }
 
     /// Simplify syntax when we know it's used inside a boolean context, e.g. `if (boolean_context) {}`.
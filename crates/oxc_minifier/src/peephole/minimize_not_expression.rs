@@ -45,6 +45,21 @@ impl<'a> PeepholeOptimizations {
                 e.operator = e.operator.equality_inverse_operator().unwrap();
                 Some(expr.argument.take_in(ctx.ast))
             }
+            // `!(a < b)` => `a >= b`
+            // `!(a <= b)` => `a > b`
+            // Unsafe, as both are false when either operand is `NaN`.
+            Expression::BinaryExpression(e)
+                if e.operator.is_compare() && ctx.options().unsafe_options.comps =>
+            {
+                e.operator = match e.operator {
+                    BinaryOperator::LessThan => BinaryOperator::GreaterEqualThan,
+                    BinaryOperator::LessEqualThan => BinaryOperator::GreaterThan,
+                    BinaryOperator::GreaterThan => BinaryOperator::LessEqualThan,
+                    BinaryOperator::GreaterEqualThan => BinaryOperator::LessThan,
+                    _ => unreachable!(),
+                };
+                Some(expr.argument.take_in(ctx.ast))
+            }
             // "!(a, b)" => "a, !b"
             Expression::SequenceExpression(sequence_expr) => {
                 if let Some(last_expr) = sequence_expr.expressions.last_mut() {
@@ -61,7 +76,10 @@ impl<'a> PeepholeOptimizations {
 
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use crate::{
+        CompressOptions, CompressOptionsUnsafe,
+        tester::{default_options, test, test_options, test_same},
+    };
 
     #[test]
     fn minimize_duplicate_nots() {
@@ -91,4 +109,17 @@ mod test {
         test("!(a === 1 ? void 0 : a.b)", "a !== 1 && a.b;");
         test("!(a, b)", "a, b");
     }
+
+    #[test]
+    fn unsafe_comps() {
+        let options = CompressOptions {
+            unsafe_options: CompressOptionsUnsafe { comps: true, ..Default::default() },
+            ..default_options()
+        };
+        test_options("var k = !(a < b)", "var k = a >= b", &options);
+        test_options("var k = !(a <= b)", "var k = a > b", &options);
+        test_options("var k = !(a > b)", "var k = a <= b", &options);
+        test_options("var k = !(a >= b)", "var k = a < b", &options);
+        test_same("var k = !(a < b)");
+    }
 }
@@ -1,7 +1,9 @@
 #![allow(clippy::unused_self)]
 
 mod convert_to_dotted_properties;
+mod convert_to_json_parse;
 mod fold_constants;
+mod inline;
 mod minimize_conditional_expression;
 mod minimize_conditions;
 mod minimize_expression_in_boolean_context;
@@ -18,7 +20,7 @@ mod replace_known_methods;
 mod substitute_alternate_syntax;
 
 use oxc_ast_visit::Visit;
-use oxc_semantic::ReferenceId;
+use oxc_semantic::{ReferenceId, SymbolId};
 use rustc_hash::FxHashSet;
 
 use oxc_allocator::Vec;
@@ -29,6 +31,7 @@ use oxc_traverse::{ReusableTraverseCtx, Traverse, traverse_mut_with_ctx};
 
 use crate::{
     ctx::{Ctx, TraverseCtx},
+    report::{IterationReport, MinifierPass, MinifierReport, PrintedSize},
     state::MinifierState,
 };
 
@@ -37,6 +40,10 @@ pub use self::normalize::{Normalize, NormalizeOptions};
 #[derive(Debug, Default, Clone, Copy)]
 pub struct State {
     pub changed: bool,
+    /// Printed size of the node after the last pass which ran on it.
+    ///
+    /// Only set if [crate::CompressOptions::report] is enabled.
+    printed_size: Option<usize>,
 }
 
 pub struct PeepholeOptimizations {
@@ -50,6 +57,8 @@ pub struct PeepholeOptimizations {
     /// Track the current function as a stack.
     current_function:
         NonEmptyStack<(ScopeId, /* prev changed */ bool, /* current changed */ bool)>,
+    /// Changes made by each pass, if [crate::CompressOptions::report] is enabled.
+    report: Option<MinifierReport>,
 }
 
 impl<'a> PeepholeOptimizations {
@@ -59,9 +68,14 @@ impl<'a> PeepholeOptimizations {
             prev_functions_changed: FxHashSet::default(),
             functions_changed: FxHashSet::default(),
             current_function: NonEmptyStack::new((ScopeId::new(0), true, false)),
+            report: None,
         }
     }
 
+    pub fn into_report(self) -> Option<MinifierReport> {
+        self.report
+    }
+
     pub fn build(
         &mut self,
         program: &mut Program<'a>,
@@ -90,6 +104,42 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// Run a pass, unless it is disabled by [crate::CompressOptions::disabled_passes].
+    ///
+    /// Changes made by the pass are recorded if [crate::CompressOptions::report] is enabled.
+    /// The node is printed at most once before the first pass, and after each pass which changed it.
+    #[expect(clippy::cast_possible_wrap)]
+    fn run_pass<'b, T: PrintedSize>(
+        &mut self,
+        pass: MinifierPass,
+        node: &mut T,
+        state: &mut State,
+        ctx: &mut Ctx<'a, 'b>,
+        f: impl FnOnce(&Self, &mut T, &mut State, &mut Ctx<'a, 'b>),
+    ) {
+        if ctx.options().disabled_passes.contains(&pass) {
+            return;
+        }
+        if self.report.is_none() {
+            f(self, node, state, ctx);
+            return;
+        }
+        let size_before = *state.printed_size.get_or_insert_with(|| node.printed_size());
+        let prev_changed = std::mem::take(&mut state.changed);
+        f(self, node, state, ctx);
+        if state.changed {
+            let size_after = node.printed_size();
+            state.printed_size = Some(size_after);
+            let bytes_saved = size_before as isize - size_after as isize;
+            if let Some(iteration) =
+                self.report.as_mut().and_then(|report| report.iterations.last_mut())
+            {
+                iteration.record(pass, bytes_saved);
+            }
+        }
+        state.changed |= prev_changed;
+    }
+
     fn mark_current_function_as_changed(&mut self) {
         let (_scope_id, _prev_changed, current_changed) = self.current_function.last_mut();
         *current_changed = true;
@@ -117,6 +167,29 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// Delete references which are no longer in the AST from `Scoping`.
+    ///
+    /// Returns the symbols which the deleted references were resolved to.
+    fn remove_deleted_references(
+        program: &Program<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> FxHashSet<SymbolId> {
+        let refs_before =
+            ctx.scoping().resolved_references().flatten().copied().collect::<FxHashSet<_>>();
+        let mut counter = ReferencesCounter::default();
+        counter.visit_program(program);
+        let mut symbols = FxHashSet::default();
+        for reference_id_to_remove in refs_before.difference(&counter.refs) {
+            if let Some(symbol_id) =
+                ctx.scoping().get_reference(*reference_id_to_remove).symbol_id()
+            {
+                symbols.insert(symbol_id);
+            }
+            ctx.scoping_mut().delete_reference(*reference_id_to_remove);
+        }
+        symbols
+    }
+
     pub fn commutative_pair<'x, A, F, G, RetF: 'x, RetG: 'x>(
         pair: (&'x A, &'x A),
         check_a: F,
@@ -145,19 +218,27 @@ impl<'a> PeepholeOptimizations {
 }
 
 impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
-    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.enter_program_or_function(program.scope_id());
+        if ctx.state.options.report {
+            let report = self.report.get_or_insert_default();
+            report.iterations.push(IterationReport::new(self.iteration));
+        }
+        if self.iteration == 0 {
+            Self::collect_constant_objects(program, &mut Ctx::new(ctx));
+        }
+        ctx.state.initialized_symbols.clear();
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.exit_program_or_function();
 
-        let refs_before =
-            ctx.scoping().resolved_references().flatten().copied().collect::<FxHashSet<_>>();
-        let mut counter = ReferencesCounter::default();
-        counter.visit_program(program);
-        for reference_id_to_remove in refs_before.difference(&counter.refs) {
-            ctx.scoping_mut().delete_reference(*reference_id_to_remove);
+        Self::remove_deleted_references(program, ctx);
+
+        if let Some(iteration) =
+            self.report.as_mut().and_then(|report| report.iterations.last_mut())
+        {
+            iteration.size = program.printed_size();
         }
     }
 
@@ -165,7 +246,22 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         self.enter_program_or_function(func.scope_id());
     }
 
-    fn exit_function(&mut self, _: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut ctx = Ctx::new(ctx);
+        let mut state = State::default();
+        self.run_pass(
+            MinifierPass::RemoveDeadCode,
+            func,
+            &mut state,
+            &mut ctx,
+            Self::remove_unused_function_parameters,
+        );
+        if Self::record_inline_function(func, &mut ctx) {
+            state.changed = true;
+        }
+        if state.changed {
+            self.mark_current_function_as_changed();
+        }
         self.exit_program_or_function();
     }
 
@@ -175,7 +271,20 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.minimize_statements(stmts, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::Inline,
+            stmts,
+            &mut state,
+            &mut ctx,
+            Self::collapse_single_use_variables,
+        );
+        self.run_pass(
+            MinifierPass::MinimizeStatements,
+            stmts,
+            &mut state,
+            &mut ctx,
+            Self::minimize_statements,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -187,14 +296,36 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.try_fold_stmt_in_boolean_context(stmt, &mut ctx);
-        self.remove_dead_code_exit_statement(stmt, &mut state, &mut ctx);
-        if let Statement::IfStatement(if_stmt) = stmt {
-            if let Some(folded_stmt) = self.try_minimize_if(if_stmt, &mut state, &mut ctx) {
-                *stmt = folded_stmt;
-                self.mark_current_function_as_changed();
-            }
-        }
+        self.run_pass(
+            MinifierPass::MinimizeExpressionInBooleanContext,
+            stmt,
+            &mut state,
+            &mut ctx,
+            |this, stmt, state, ctx| {
+                state.changed |= this.try_fold_stmt_in_boolean_context(stmt, ctx);
+            },
+        );
+        self.run_pass(
+            MinifierPass::RemoveDeadCode,
+            stmt,
+            &mut state,
+            &mut ctx,
+            Self::remove_dead_code_exit_statement,
+        );
+        self.run_pass(
+            MinifierPass::MinimizeIfStatement,
+            stmt,
+            &mut state,
+            &mut ctx,
+            |this, stmt, state, ctx| {
+                if let Statement::IfStatement(if_stmt) = stmt {
+                    if let Some(folded_stmt) = this.try_minimize_if(if_stmt, state, ctx) {
+                        *stmt = folded_stmt;
+                        state.changed = true;
+                    }
+                }
+            },
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -206,7 +337,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut state = State::default();
         let mut ctx = Ctx::new(ctx);
-        self.minimize_for_statement(stmt, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::MinimizeForStatement,
+            stmt,
+            &mut state,
+            &mut ctx,
+            Self::minimize_for_statement,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -218,12 +355,26 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_return_statement(stmt, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            stmt,
+            &mut state,
+            &mut ctx,
+            Self::substitute_return_statement,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
     }
 
+    fn exit_variable_declarator(
+        &mut self,
+        decl: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        Self::record_initialized_variable(decl, &mut Ctx::new(ctx));
+    }
+
     fn exit_variable_declaration(
         &mut self,
         decl: &mut VariableDeclaration<'a>,
@@ -234,7 +385,14 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_variable_declaration(decl, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            decl,
+            &mut state,
+            &mut ctx,
+            Self::substitute_variable_declaration,
+        );
+        Self::record_constant_values(decl, &mut ctx);
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -246,11 +404,53 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.fold_constants_exit_expression(expr, &mut state, &mut ctx);
-        self.minimize_conditions_exit_expression(expr, &mut state, &mut ctx);
-        self.remove_dead_code_exit_expression(expr, &mut state, &mut ctx);
-        self.replace_known_methods_exit_expression(expr, &mut state, &mut ctx);
-        self.substitute_exit_expression(expr, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::Inline,
+            expr,
+            &mut state,
+            &mut ctx,
+            |this, expr, state, ctx| {
+                this.inline_constant(expr, state, ctx);
+                this.inline_constant_property(expr, state, ctx);
+                this.inline_function_call(expr, state, ctx);
+                this.reduce_function_reference(expr, state, ctx);
+            },
+        );
+        self.run_pass(
+            MinifierPass::FoldConstants,
+            expr,
+            &mut state,
+            &mut ctx,
+            Self::fold_constants_exit_expression,
+        );
+        self.run_pass(
+            MinifierPass::MinimizeConditions,
+            expr,
+            &mut state,
+            &mut ctx,
+            Self::minimize_conditions_exit_expression,
+        );
+        self.run_pass(
+            MinifierPass::RemoveDeadCode,
+            expr,
+            &mut state,
+            &mut ctx,
+            Self::remove_dead_code_exit_expression,
+        );
+        self.run_pass(
+            MinifierPass::ReplaceKnownMethods,
+            expr,
+            &mut state,
+            &mut ctx,
+            Self::replace_known_methods_exit_expression,
+        );
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            expr,
+            &mut state,
+            &mut ctx,
+            Self::substitute_exit_expression,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -261,10 +461,19 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
             return;
         }
         let mut ctx = Ctx::new(ctx);
-
-        if expr.operator.is_not()
-            && self.try_fold_expr_in_boolean_context(&mut expr.argument, &mut ctx)
-        {
+        let mut state = State::default();
+        self.run_pass(
+            MinifierPass::MinimizeExpressionInBooleanContext,
+            expr,
+            &mut state,
+            &mut ctx,
+            |this, expr, state, ctx| {
+                if expr.operator.is_not() {
+                    state.changed |= this.try_fold_expr_in_boolean_context(&mut expr.argument, ctx);
+                }
+            },
+        );
+        if state.changed {
             self.mark_current_function_as_changed();
         }
     }
@@ -275,7 +484,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_call_expression(expr, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            expr,
+            &mut state,
+            &mut ctx,
+            Self::substitute_call_expression,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -287,7 +502,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_new_expression(expr, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            expr,
+            &mut state,
+            &mut ctx,
+            Self::substitute_new_expression,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -299,7 +520,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_object_property(prop, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            prop,
+            &mut state,
+            &mut ctx,
+            Self::substitute_object_property,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -315,7 +542,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_assignment_target_property(node, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            node,
+            &mut state,
+            &mut ctx,
+            Self::substitute_assignment_target_property,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -331,7 +564,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_assignment_target_property_property(prop, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            prop,
+            &mut state,
+            &mut ctx,
+            Self::substitute_assignment_target_property_property,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -343,7 +582,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_binding_property(prop, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            prop,
+            &mut state,
+            &mut ctx,
+            Self::substitute_binding_property,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -359,7 +604,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_method_definition(prop, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            prop,
+            &mut state,
+            &mut ctx,
+            Self::substitute_method_definition,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -375,7 +626,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_property_definition(prop, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            prop,
+            &mut state,
+            &mut ctx,
+            Self::substitute_property_definition,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -391,7 +648,13 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         }
         let mut ctx = Ctx::new(ctx);
         let mut state = State::default();
-        self.substitute_accessor_property(prop, &mut state, &mut ctx);
+        self.run_pass(
+            MinifierPass::SubstituteAlternateSyntax,
+            prop,
+            &mut state,
+            &mut ctx,
+            Self::substitute_accessor_property,
+        );
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -417,6 +680,12 @@ impl<'a> LatePeepholeOptimizations {
 }
 
 impl<'a> Traverse<'a, MinifierState<'a>> for LatePeepholeOptimizations {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut ctx = Ctx::new(ctx);
+        // Before visiting children, so only the outermost literal is converted.
+        Self::convert_to_json_parse(expr, &mut ctx);
+    }
+
     fn exit_member_expression(
         &mut self,
         expr: &mut MemberExpression<'a>,
@@ -452,11 +721,20 @@ impl<'a> Traverse<'a, MinifierState<'a>> for LatePeepholeOptimizations {
 
 pub struct DeadCodeElimination {
     inner: PeepholeOptimizations,
+    /// Symbols which lost references to removed code.
+    /// Their declarations are removed once they become unused.
+    orphaned_symbols: FxHashSet<SymbolId>,
+    /// Whether the current pass removed references to any symbol.
+    has_new_orphaned_symbols: bool,
 }
 
 impl<'a> DeadCodeElimination {
     pub fn new() -> Self {
-        Self { inner: PeepholeOptimizations::new() }
+        Self {
+            inner: PeepholeOptimizations::new(),
+            orphaned_symbols: FxHashSet::default(),
+            has_new_orphaned_symbols: false,
+        }
     }
 
     pub fn build(
@@ -466,13 +744,42 @@ impl<'a> DeadCodeElimination {
     ) {
         traverse_mut_with_ctx(self, program, ctx);
     }
+
+    /// Walk the ast until no more references are removed,
+    /// so declarations only referenced from removed code are also removed.
+    ///
+    /// Terminates because each walk except the last removes at least one reference.
+    pub fn run_in_loop(
+        &mut self,
+        program: &mut Program<'a>,
+        ctx: &mut ReusableTraverseCtx<'a, MinifierState<'a>>,
+    ) {
+        loop {
+            self.build(program, ctx);
+            if !self.has_new_orphaned_symbols {
+                break;
+            }
+        }
+    }
 }
 
 impl<'a> Traverse<'a, MinifierState<'a>> for DeadCodeElimination {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let symbols = PeepholeOptimizations::remove_deleted_references(program, ctx);
+        self.has_new_orphaned_symbols = !symbols.is_empty();
+        self.orphaned_symbols.extend(symbols);
+    }
+
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut state = State::default();
         let mut ctx = Ctx::new(ctx);
         self.inner.remove_dead_code_exit_statement(stmt, &mut state, &mut ctx);
+        PeepholeOptimizations::remove_orphaned_declaration(
+            stmt,
+            &self.orphaned_symbols,
+            &mut state,
+            &mut ctx,
+        );
     }
 
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
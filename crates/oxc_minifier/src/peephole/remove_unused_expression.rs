@@ -667,7 +667,7 @@ impl<'a> PeepholeOptimizations {
 #[cfg(test)]
 mod test {
     use crate::{
-        CompressOptions, TreeShakeOptions,
+        CompressOptions, CompressOptionsUnsafe, TreeShakeOptions,
         tester::{default_options, test, test_options, test_same, test_same_options},
     };
 
@@ -967,4 +967,17 @@ mod test {
             &options,
         );
     }
+
+    #[test]
+    fn unsafe_pure_getters() {
+        let options = CompressOptions {
+            unsafe_options: CompressOptionsUnsafe { pure_getters: true, ..Default::default() },
+            ..default_options()
+        };
+        test_options("let a; a.b", "let a", &options);
+        test_options("let a; a.b.c, a[0]", "let a", &options);
+        test_options("let a; a.b, foo()", "let a; foo()", &options);
+        test_same_options("let a; a.b()", &options);
+        test_same("let a; a.b");
+    }
 }
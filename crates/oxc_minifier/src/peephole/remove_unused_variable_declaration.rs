@@ -1,4 +1,8 @@
 use oxc_ast::ast::*;
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_semantic::SymbolId;
+use oxc_traverse::Ancestor;
+use rustc_hash::FxHashSet;
 
 use crate::{CompressOptionsUnused, ctx::Ctx};
 
@@ -45,6 +49,124 @@ impl<'a> PeepholeOptimizations {
         None
     }
 
+    /// Remove variable declarators and import specifiers of `orphaned` symbols which are now unused.
+    ///
+    /// `orphaned` are symbols which lost references when [super::DeadCodeElimination] removed code,
+    /// so declarations which were already unused in the source are kept.
+    ///
+    /// `if (false) foo(); import { foo } from 'x'` -> `import 'x'`
+    pub fn remove_orphaned_declaration(
+        stmt: &mut Statement<'a>,
+        orphaned: &FxHashSet<SymbolId>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if ctx.state.options.unused == CompressOptionsUnused::Keep
+            || orphaned.is_empty()
+            || Self::keep_top_level_var_in_script_mode(ctx)
+            // It is unsafe to remove if direct eval is involved.
+            || ctx.scoping().root_scope_flags().contains_direct_eval()
+        {
+            return;
+        }
+        let is_orphaned = |ident: &BindingIdentifier<'a>, ctx: &Ctx<'a, '_>| {
+            ident.symbol_id.get().is_some_and(|symbol_id| {
+                orphaned.contains(&symbol_id) && ctx.scoping().symbol_is_unused(symbol_id)
+            })
+        };
+        match stmt {
+            Statement::VariableDeclaration(var_decl) => {
+                if matches!(
+                    var_decl.kind,
+                    VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing
+                ) {
+                    return;
+                }
+                let len = var_decl.declarations.len();
+                var_decl.declarations.retain(|decl| {
+                    !(decl.id.get_binding_identifier().is_some_and(|ident| is_orphaned(ident, ctx))
+                        && decl.init.as_ref().is_none_or(|init| !init.may_have_side_effects(ctx)))
+                });
+                if var_decl.declarations.len() == len {
+                    return;
+                }
+                state.changed = true;
+                if var_decl.declarations.is_empty() {
+                    *stmt = ctx.ast.statement_empty(var_decl.span);
+                }
+            }
+            Statement::ImportDeclaration(import_decl) => {
+                let Some(specifiers) = &mut import_decl.specifiers else { return };
+                let len = specifiers.len();
+                specifiers.retain(|specifier| !is_orphaned(specifier.local(), ctx));
+                if specifiers.len() == len {
+                    return;
+                }
+                state.changed = true;
+                if !specifiers.is_empty() {
+                    return;
+                }
+                if ctx.state.options.treeshake.module_side_effects {
+                    import_decl.specifiers = None;
+                } else {
+                    *stmt = ctx.ast.statement_empty(import_decl.span);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `function f(a, b) { return a }` -> `function f(a) { return a }`
+    ///
+    /// Only with [crate::CompressOptionsUnsafe::drop_fargs], as this changes `Function.prototype.length`.
+    pub fn remove_unused_function_parameters(
+        &self,
+        func: &mut Function<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !ctx.options().unsafe_options.drop_fargs || func.body.is_none() {
+            return;
+        }
+        // Parameters may be read through `arguments` or `eval`.
+        if ctx.scoping().root_scope_flags().contains_direct_eval()
+            || ctx.scoping().root_unresolved_references().contains_key("arguments")
+        {
+            return;
+        }
+        // Setters must have exactly one parameter.
+        match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) if method.kind().is_set() => return,
+            Ancestor::ObjectPropertyValue(prop) if *prop.kind() == PropertyKind::Set => return,
+            _ => {}
+        }
+        let params = &mut func.params;
+        if params.rest.as_ref().is_some_and(|rest| Self::is_unused_binding(&rest.argument, ctx)) {
+            params.rest = None;
+            state.changed = true;
+        }
+        if params.rest.is_some() {
+            return;
+        }
+        while params.items.last().is_some_and(|param| {
+            param.decorators.is_empty()
+                && !param.has_modifier()
+                && Self::is_unused_binding(&param.pattern, ctx)
+        }) {
+            params.items.pop();
+            state.changed = true;
+        }
+    }
+
+    fn is_unused_binding(pattern: &BindingPattern<'a>, ctx: &Ctx<'a, '_>) -> bool {
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                ctx.scoping().symbol_is_unused(ident.symbol_id())
+            }
+            _ => false,
+        }
+    }
+
     pub fn remove_unused_assignment_expression(
         &self,
         _e: &mut Expression<'a>,
@@ -94,9 +216,9 @@ mod test {
     use oxc_span::SourceType;
 
     use crate::{
-        CompressOptions,
+        CompressOptions, CompressOptionsUnsafe,
         tester::{
-            test_options, test_options_source_type, test_same_options,
+            default_options, test_options, test_options_source_type, test_same_options,
             test_same_options_source_type,
         },
     };
@@ -119,6 +241,26 @@ mod test {
         test_same_options("export function foo() {} foo()", &options);
     }
 
+    #[test]
+    fn remove_unused_function_parameters() {
+        let options = CompressOptions {
+            unsafe_options: CompressOptionsUnsafe { drop_fargs: true, ..Default::default() },
+            ..default_options()
+        };
+        test_options("function f(a, b) { return a }", "function f(a) { return a }", &options);
+        test_options("function f(a, b, ...c) { return a }", "function f(a) { return a }", &options);
+        test_options("x = function (a, b) {}", "x = function () {}", &options);
+        test_options("x = { a(b) {} }", "x = { a() {} }", &options);
+        test_same_options("function f(a, b) { return b }", &options);
+        test_same_options("function f(a, b = foo()) { return a }", &options);
+        test_same_options("function f(a, { b }) { return a }", &options);
+        test_same_options("function f(a, b) { return arguments }", &options);
+        test_same_options("function f(a, b) { eval('b') }", &options);
+        test_same_options("x = { set a(b) {} }", &options);
+        test_same_options("class C { set a(b) {} }", &options);
+        test_same_options("function f(a, b) { return a }", &default_options());
+    }
+
     #[test]
     #[ignore]
     fn remove_unused_assignment_expression() {
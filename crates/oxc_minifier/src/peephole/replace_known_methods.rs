@@ -4,8 +4,8 @@ use std::borrow::Cow;
 use oxc_allocator::TakeIn;
 use oxc_ast::ast::*;
 use oxc_ecmascript::{
-    StringCharAt, StringCharAtResult, StringCharCodeAt, StringIndexOf, StringLastIndexOf,
-    StringSubstring, ToBigInt, ToInt32, ToIntegerIndex,
+    RegExpExec, StringCharAt, StringCharAtResult, StringCharCodeAt, StringIndexOf,
+    StringLastIndexOf, StringSubstring, ToBigInt, ToInt32, ToIntegerIndex,
     constant_evaluation::{ConstantEvaluation, ConstantValue, DetermineValueType},
     is_global_reference::IsGlobalReference,
     side_effects::MayHaveSideEffects,
@@ -83,6 +83,7 @@ impl<'a> PeepholeOptimizations {
             "min" | "max" => Self::try_fold_math_variadic(*span, arguments, name, object, ctx),
             "of" => Self::try_fold_array_of(*span, arguments, name, object, ctx),
             "startsWith" => Self::try_fold_starts_with(*span, arguments, object, ctx),
+            "test" => Self::try_fold_regexp_test(*span, arguments, object, ctx),
             _ => None,
         };
         if let Some(replacement) = replacement {
@@ -258,6 +259,20 @@ impl<'a> PeepholeOptimizations {
         }
         let Expression::StringLiteral(s) = object else { return None };
         let search_value = args.first().unwrap();
+        if let Argument::RegExpLiteral(regex) = search_value {
+            // `replaceAll` throws a TypeError for a non-global RegExp
+            if name == "replaceAll" && !regex.regex.flags.contains(RegExpFlags::G) {
+                return None;
+            }
+            let replace_value = match args.get(1).unwrap() {
+                Argument::SpreadElement(_) => return None,
+                arg @ match_expression!(Argument) => {
+                    arg.to_expression().get_side_free_string_value(ctx)?
+                }
+            };
+            let result = regex.regex.regexp_replace(s.value.as_str(), &replace_value)?;
+            return Some(ctx.ast.expression_string_literal(span, ctx.ast.atom(&result), None));
+        }
         let search_value = match search_value {
             Argument::SpreadElement(_) => return None,
             match_expression!(Argument) => {
@@ -286,6 +301,20 @@ impl<'a> PeepholeOptimizations {
         Some(ctx.ast.expression_string_literal(span, ctx.ast.atom_from_cow(&result), None))
     }
 
+    /// `/abc/.test("abc")` -> `true`
+    fn try_fold_regexp_test(
+        span: Span,
+        args: &Arguments<'a>,
+        object: &Expression<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        let Expression::RegExpLiteral(regex) = object else { return None };
+        let [arg @ match_expression!(Argument)] = args.as_slice() else { return None };
+        let input = arg.to_expression().get_side_free_string_value(ctx)?;
+        let result = regex.regex.regexp_test(&input)?;
+        Some(ctx.ast.expression_boolean_literal(span, result))
+    }
+
     #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_lossless)]
     fn try_fold_string_from_char_code(
         span: Span,
@@ -1223,8 +1252,13 @@ mod test {
         test("x = 'acaca'.replace('c','x')", "x = 'axaca'");
         test("x = 'ab'.replace('','x')", "x = 'xab'");
 
-        test_same("'acaca'.replace(/c/,'x')"); // this will affect the global RegExp props
-        test_same("'acaca'.replace(/c/g,'x')"); // this will affect the global RegExp props
+        test("x = 'acaca'.replace(/c/,'x')", "x = 'axaca'");
+        test("x = 'acaca'.replace(/c/g,'x')", "x = 'axaxa'");
+        test("x = 'a-b'.replace(/-/g, '')", "x = 'ab'");
+        test("x = 'a-b'.replace(/(\\w)-(\\w)/, '$2$1')", "x = 'ba'");
+        test("x = 'abc'.replace(/(?<x>b)/, '[$<x>]')", "x = 'a[b]c'");
+        test_same("x = 'acaca'.replace(/c/g, foo())");
+        test_same("x = 'acaca'.replace(/(/g,'x')"); // invalid pattern
 
         // not a literal
         test_same("x.replace('x','c')");
@@ -1251,7 +1285,7 @@ mod test {
         test_same("x = 'a'.replaceAll({ [Symbol.replace]() { return 'x' } }, 'c')"); // can be folded to "x"
 
         test_same("x = 'acaca'.replaceAll(/c/,'x')"); // this should throw
-        test_same("x = 'acaca'.replaceAll(/c/g,'x')"); // this will affect the global RegExp props
+        test("x = 'acaca'.replaceAll(/c/g,'x')", "x = 'axaxa'");
 
         // not a literal
         test_same("x.replaceAll('x','c')");
@@ -2128,4 +2162,15 @@ mod test {
             "const node_env = 'production'; v = !0",
         );
     }
+
+    #[test]
+    fn test_fold_regexp_test() {
+        test("v = /abc/.test('abc')", "v = !0");
+        test("v = /^b/.test('abc')", "v = !1");
+        test("v = /(?<=a)b/u.test('ab')", "v = !0");
+        test("v = /\\p{Lu}/iv.test('a')", "v = !0");
+        test_same("v = /abc/.test(foo)");
+        test_same("v = /abc/.test('abc', foo())");
+        test_same("v = /(a+)+b/.test('aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa')"); // backtracking limit
+    }
 }
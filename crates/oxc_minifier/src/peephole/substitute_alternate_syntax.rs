@@ -2,6 +2,7 @@ use std::iter::repeat_with;
 
 use oxc_allocator::{CloneIn, TakeIn, Vec};
 use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
 use oxc_ecmascript::constant_evaluation::DetermineValueType;
 use oxc_ecmascript::{ToJsString, ToNumber, side_effects::MayHaveSideEffects};
 use oxc_span::GetSpan;
@@ -11,6 +12,8 @@ use oxc_syntax::{
     identifier::is_identifier_name,
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
+    reference::ReferenceFlags,
+    scope::ScopeFlags,
 };
 use oxc_traverse::Ancestor;
 
@@ -40,6 +43,7 @@ impl<'a> PeepholeOptimizations {
         }
 
         self.try_compress_property_key(&mut prop.key, &mut prop.computed, state, ctx);
+        self.try_compress_function_property_to_method(prop, state, ctx);
     }
 
     pub fn substitute_assignment_target_property_property(
@@ -217,7 +221,9 @@ impl<'a> PeepholeOptimizations {
                 .and_then(|name| {
                     Self::try_fold_object_or_array_constructor(e.span, name, &mut e.arguments, ctx)
                 })
-                .or_else(|| self.try_fold_simple_function_call(e, ctx)),
+                .or_else(|| self.try_fold_simple_function_call(e, ctx))
+                .or_else(|| Self::try_fold_regexp_constructor(e, ctx)),
+            Expression::FunctionExpression(e) => Self::try_compress_function_to_arrow(e, ctx),
             _ => None,
         } {
             *expr = folded_expr;
@@ -1012,6 +1018,123 @@ impl<'a> PeepholeOptimizations {
         state.changed = true;
     }
 
+    /// `RegExp("a", "g")` -> `/a/g`
+    ///
+    /// Only with [crate::CompressOptionsUnsafe::regexp].
+    /// `new RegExp()` is converted to `RegExp()` first by [Self::try_fold_new_expression].
+    fn try_fold_regexp_constructor(
+        e: &CallExpression<'a>,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !ctx.options().unsafe_options.regexp {
+            return None;
+        }
+        let Expression::Identifier(ident) = &e.callee else { return None };
+        if ident.name != "RegExp" || !ctx.is_global_reference(ident) {
+            return None;
+        }
+        let (pattern, flags) = match e.arguments.as_slice() {
+            [Argument::StringLiteral(pattern)] => (pattern, None),
+            [Argument::StringLiteral(pattern), Argument::StringLiteral(flags)] => {
+                (pattern, Some(flags))
+            }
+            _ => return None,
+        };
+        // Patterns which can not be written between slashes as is, or would form a comment.
+        let text = pattern.value;
+        if text.is_empty()
+            || text.starts_with('*')
+            || text.ends_with('\\')
+            || text.contains(['/', '\n', '\r', '\u{2028}', '\u{2029}'])
+        {
+            return None;
+        }
+        let mut regexp_flags = RegExpFlags::empty();
+        for c in flags.map_or("", |flags| flags.value.as_str()).chars() {
+            let flag = RegExpFlags::try_from(c).ok()?;
+            if regexp_flags.contains(flag) {
+                return None;
+            }
+            regexp_flags |= flag;
+        }
+        if regexp_flags.contains(RegExpFlags::U | RegExpFlags::V) {
+            return None;
+        }
+        let regex = RegExp { pattern: RegExpPattern { text, pattern: None }, flags: regexp_flags };
+        Some(ctx.ast.expression_reg_exp_literal(e.span, regex, None))
+    }
+
+    /// `function () { return a }` -> `() => { return a }`
+    ///
+    /// Only with [crate::CompressOptionsUnsafe::arrows].
+    fn try_compress_function_to_arrow(
+        func: &mut Function<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !ctx.options().unsafe_options.arrows
+            || func.id.is_some()
+            || func.generator
+            || func.this_param.is_some()
+            || func.body.is_none()
+        {
+            return None;
+        }
+        // Methods and accessors are shorter than arrow function properties.
+        if let Ancestor::ObjectPropertyValue(prop) = ctx.parent() {
+            if *prop.method()
+                || *prop.kind() != PropertyKind::Init
+                || ctx.options().unsafe_options.methods
+            {
+                return None;
+            }
+        }
+        let mut checker = ArrowFunctionChecker { uses_function_bindings: false };
+        checker.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            checker.visit_function_body(body);
+        }
+        if checker.uses_function_bindings {
+            return None;
+        }
+        let scope_id = func.scope_id();
+        ctx.scoping_mut().scope_flags_mut(scope_id).insert(ScopeFlags::Arrow);
+        Some(ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+            func.span,
+            false,
+            func.r#async,
+            func.type_parameters.take(),
+            func.params.take_in_box(ctx.ast),
+            func.return_type.take(),
+            func.body.take()?,
+            scope_id,
+            func.pure,
+        ))
+    }
+
+    /// `{ a: function () {} }` -> `{ a() {} }`
+    ///
+    /// Only with [crate::CompressOptionsUnsafe::methods].
+    fn try_compress_function_property_to_method(
+        &self,
+        prop: &mut ObjectProperty<'a>,
+        state: &mut State,
+        ctx: &Ctx<'a, '_>,
+    ) {
+        if !ctx.options().unsafe_options.methods || prop.method || prop.kind != PropertyKind::Init {
+            return;
+        }
+        let Expression::FunctionExpression(func) = &prop.value else { return };
+        if func.id.is_some() {
+            return;
+        }
+        // `{ __proto__: function () {} }` sets the prototype, while `{ __proto__() {} }` does not.
+        if !prop.computed && prop.key.is_specific_static_name("__proto__") {
+            return;
+        }
+        prop.method = true;
+        state.changed = true;
+    }
+
     /// Remove name from function expressions if it is not used.
     ///
     /// e.g. `var a = function f() {}` -> `var a = function () {}`
@@ -1064,12 +1187,34 @@ impl<'a> LatePeepholeOptimizations {
         if let Some(folded_expr) = match expr {
             Expression::BooleanLiteral(_) => Self::try_compress_boolean(expr, ctx),
             Expression::ArrayExpression(_) => Self::try_compress_array_expression(expr, ctx),
+            Expression::UnaryExpression(_) => Self::try_compress_void_0_to_undefined(expr, ctx),
             _ => None,
         } {
             *expr = folded_expr;
         }
     }
 
+    /// `void 0` -> `undefined`, where `undefined` is a variable in scope.
+    ///
+    /// Only with [crate::CompressOptionsUnsafe::undefined].
+    fn try_compress_void_0_to_undefined(
+        expr: &Expression<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !ctx.options().unsafe_options.undefined || !expr.is_void_0() {
+            return None;
+        }
+        let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "undefined")?;
+        let span = expr.span();
+        // Do not reference a lexical binding before its declaration.
+        if ctx.scoping().symbol_span(symbol_id).end > span.start
+            || ctx.scoping().symbol_is_mutated(symbol_id)
+        {
+            return None;
+        }
+        Some(ctx.create_bound_ident_expr(span, "undefined".into(), symbol_id, ReferenceFlags::Read))
+    }
+
     /// `new Int8Array(0)` -> `new Int8Array()` (also for other TypedArrays)
     fn try_compress_typed_array_constructor(e: &mut NewExpression<'a>, ctx: &mut Ctx<'a, '_>) {
         let Expression::Identifier(ident) = &e.callee else { return };
@@ -1267,17 +1412,51 @@ where
     }
 }
 
+/// Check whether a function uses `this`, `arguments` or `new.target`,
+/// which are not bound by arrow functions.
+struct ArrowFunctionChecker {
+    uses_function_bindings: bool,
+}
+
+impl<'a> Visit<'a> for ArrowFunctionChecker {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == "arguments" {
+            self.uses_function_bindings = true;
+        }
+    }
+
+    fn visit_this_expression(&mut self, _: &ThisExpression) {
+        self.uses_function_bindings = true;
+    }
+
+    fn visit_super(&mut self, _: &Super) {
+        self.uses_function_bindings = true;
+    }
+
+    fn visit_meta_property(&mut self, it: &MetaProperty<'a>) {
+        if it.meta.name == "new" {
+            self.uses_function_bindings = true;
+        }
+    }
+
+    fn visit_function(&mut self, _: &Function<'a>, _: ScopeFlags) {}
+}
+
 /// Port from <https://github.com/google/closure-compiler/blob/v20240609/test/com/google/javascript/jscomp/PeepholeSubstituteAlternateSyntaxTest.java>
 #[cfg(test)]
 mod test {
     use oxc_syntax::es_target::ESTarget;
 
     use crate::{
-        CompressOptions,
+        CompressOptions, CompressOptionsUnsafe,
         options::CompressOptionsKeepNames,
-        tester::{default_options, test, test_same, test_same_options},
+        tester::{default_options, test, test_options, test_same, test_same_options},
     };
 
+    fn unsafe_options(unsafe_options: CompressOptionsUnsafe) -> CompressOptions {
+        CompressOptions { unsafe_options, ..default_options() }
+    }
+
     #[test]
     fn test_fold_return_result() {
         test("function f(){return !1;}", "function f(){return !1}");
@@ -1959,4 +2138,81 @@ mod test {
         test("var {y: z, 'z': y} = x", "var {y: z, z: y} = x");
         test("var {y: y, 'z': z} = x", "var {y, z} = x");
     }
+
+    #[test]
+    fn test_unsafe_arrows() {
+        let options = unsafe_options(CompressOptionsUnsafe { arrows: true, ..Default::default() });
+        test_options("var a = function () { return 1 }", "var a = () => 1", &options);
+        test_options(
+            "var a = async function (b) { await b }",
+            "var a = async (b) => { await b }",
+            &options,
+        );
+        test_options(
+            "var a = function () { return function () { return this } }",
+            "var a = () => function () { return this }",
+            &options,
+        );
+        test_options("var a = { b: function () {} }", "var a = { b: () => {} }", &options);
+        test_same_options("var a = function () { return () => this }", &options);
+        test_same_options("var a = function () { return arguments }", &options);
+        test_same_options("var a = function () { return new.target }", &options);
+        test_same_options("var a = function* () {}", &options);
+        test_same_options("var a = { get b() {} }", &options);
+        test_same("var a = function () {}");
+    }
+
+    #[test]
+    fn test_unsafe_methods() {
+        let options = unsafe_options(CompressOptionsUnsafe { methods: true, ..Default::default() });
+        test_options("var a = { b: function () {} }", "var a = { b() {} }", &options);
+        test_options("var a = { b: async function* () {} }", "var a = { async *b() {} }", &options);
+        test_options("var a = { 'b c': function () {} }", "var a = { 'b c'() {} }", &options);
+        test_same_options("var a = { __proto__: function () {} }", &options);
+        test_same_options("var a = { b: function c() { return c } }", &options);
+        test_same("var a = { b: function () {} }");
+
+        let options = unsafe_options(CompressOptionsUnsafe {
+            arrows: true,
+            methods: true,
+            ..Default::default()
+        });
+        test_options(
+            "var a = { b: function () { return 1 } }",
+            "var a = { b() { return 1 } }",
+            &options,
+        );
+    }
+
+    #[test]
+    fn test_unsafe_regexp() {
+        let options = unsafe_options(CompressOptionsUnsafe { regexp: true, ..Default::default() });
+        test_options("x = RegExp('a', 'g')", "x = /a/g", &options);
+        test_options("x = new RegExp('a+b', 'gi')", "x = /a+b/gi", &options);
+        test_options("x = RegExp('\\\\d')", "x = /\\d/", &options);
+        test_same_options("x = RegExp('a/b')", &options);
+        test_same_options("x = RegExp('')", &options);
+        test_same_options("x = RegExp('*')", &options);
+        test_same_options("x = RegExp('a', 'gg')", &options);
+        test_same_options("x = RegExp('a', 'uv')", &options);
+        test_same_options("x = RegExp('a', b)", &options);
+        test_same_options("let RegExp; x = RegExp('a')", &options);
+        test_same("x = RegExp('a', 'g')");
+    }
+
+    #[test]
+    fn test_unsafe_undefined() {
+        let options =
+            unsafe_options(CompressOptionsUnsafe { undefined: true, ..Default::default() });
+        test_options(
+            "function f(undefined) { g(void 0) }",
+            "function f(undefined) { g(undefined) }",
+            &options,
+        );
+        test_same_options("function f(undefined) { g(undefined) }", &options);
+        test_same_options("function f() { g(void 0) }", &options);
+        test_same_options("function f() { g(void 0); let undefined }", &options);
+        test_same_options("function f(undefined) { undefined = 1, g(void 0) }", &options);
+        test_same("function f(undefined) { g(void 0) }");
+    }
 }
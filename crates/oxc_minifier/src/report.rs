@@ -0,0 +1,205 @@
+use std::{fmt, str::FromStr};
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
+use oxc_codegen::{Codegen, CodegenOptions, Context, Gen, GenExpr};
+use oxc_syntax::precedence::Precedence;
+
+/// A pass of the peephole optimizer.
+///
+/// Passes are named after the modules in `oxc_minifier/src/peephole`, and can be disabled
+/// with [crate::CompressOptions::disabled_passes] to bisect minifier bugs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MinifierPass {
+    FoldConstants,
+    Inline,
+    MinimizeConditions,
+    MinimizeExpressionInBooleanContext,
+    MinimizeForStatement,
+    MinimizeIfStatement,
+    MinimizeStatements,
+    RemoveDeadCode,
+    ReplaceKnownMethods,
+    SubstituteAlternateSyntax,
+}
+
+impl MinifierPass {
+    pub const ALL: [Self; 10] = [
+        Self::FoldConstants,
+        Self::Inline,
+        Self::MinimizeConditions,
+        Self::MinimizeExpressionInBooleanContext,
+        Self::MinimizeForStatement,
+        Self::MinimizeIfStatement,
+        Self::MinimizeStatements,
+        Self::RemoveDeadCode,
+        Self::ReplaceKnownMethods,
+        Self::SubstituteAlternateSyntax,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::FoldConstants => "fold_constants",
+            Self::Inline => "inline",
+            Self::MinimizeConditions => "minimize_conditions",
+            Self::MinimizeExpressionInBooleanContext => "minimize_expression_in_boolean_context",
+            Self::MinimizeForStatement => "minimize_for_statement",
+            Self::MinimizeIfStatement => "minimize_if_statement",
+            Self::MinimizeStatements => "minimize_statements",
+            Self::RemoveDeadCode => "remove_dead_code",
+            Self::ReplaceKnownMethods => "replace_known_methods",
+            Self::SubstituteAlternateSyntax => "substitute_alternate_syntax",
+        }
+    }
+}
+
+impl fmt::Display for MinifierPass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MinifierPass {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|pass| pass.as_str() == s).ok_or_else(|| {
+            let names = Self::ALL.map(Self::as_str).join(", ");
+            format!("Unknown minifier pass `{s}`, expected one of: {names}")
+        })
+    }
+}
+
+/// Changes made by the minifier, collected when [crate::CompressOptions::report] is enabled.
+///
+/// Sizes are the length of the output of `oxc_codegen` with [CodegenOptions::minify],
+/// before mangling.
+#[derive(Debug, Default, Clone)]
+pub struct MinifierReport {
+    /// Size of the program before compression.
+    pub original_size: usize,
+    /// Iterations of the peephole optimizer's fixed-point loop.
+    pub iterations: Vec<IterationReport>,
+    /// Size of the program after compression.
+    pub final_size: usize,
+}
+
+/// Changes made in one iteration of the peephole optimizer's fixed-point loop.
+#[derive(Debug, Clone)]
+pub struct IterationReport {
+    pub iteration: u8,
+    /// Passes which made changes in this iteration.
+    pub passes: Vec<PassReport>,
+    /// Size of the program at the end of this iteration.
+    pub size: usize,
+}
+
+/// Changes made by a single pass in one iteration.
+#[derive(Debug, Clone, Copy)]
+pub struct PassReport {
+    pub pass: MinifierPass,
+    /// Number of nodes changed by the pass.
+    pub changes: usize,
+    /// Bytes saved by the pass. Negative if the pass made output larger.
+    pub bytes_saved: isize,
+}
+
+impl IterationReport {
+    pub(crate) fn new(iteration: u8) -> Self {
+        Self { iteration, passes: vec![], size: 0 }
+    }
+
+    pub(crate) fn record(&mut self, pass: MinifierPass, bytes_saved: isize) {
+        if let Some(report) = self.passes.iter_mut().find(|report| report.pass == pass) {
+            report.changes += 1;
+            report.bytes_saved += bytes_saved;
+        } else {
+            self.passes.push(PassReport { pass, changes: 1, bytes_saved });
+        }
+    }
+}
+
+impl fmt::Display for MinifierReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "original size: {}", self.original_size)?;
+        for iteration in &self.iterations {
+            writeln!(f, "iteration {} (size {}):", iteration.iteration, iteration.size)?;
+            for pass in &iteration.passes {
+                writeln!(
+                    f,
+                    "  {:<40} {:>6} changes {:>8} bytes saved",
+                    pass.pass.as_str(),
+                    pass.changes,
+                    pass.bytes_saved
+                )?;
+            }
+        }
+        write!(f, "final size: {}", self.final_size)
+    }
+}
+
+/// Size of an AST node when printed by the minifying code generator.
+pub trait PrintedSize {
+    fn printed_size(&self) -> usize;
+}
+
+fn codegen() -> Codegen<'static> {
+    Codegen::new().with_options(CodegenOptions::minify())
+}
+
+impl PrintedSize for Program<'_> {
+    fn printed_size(&self) -> usize {
+        codegen().build(self).code.len()
+    }
+}
+
+impl PrintedSize for ArenaVec<'_, Statement<'_>> {
+    fn printed_size(&self) -> usize {
+        self.iter().map(PrintedSize::printed_size).sum()
+    }
+}
+
+macro_rules! impl_printed_size {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl PrintedSize for $ty<'_> {
+                fn printed_size(&self) -> usize {
+                    let mut codegen = codegen();
+                    self.print(&mut codegen, Context::empty());
+                    codegen.into_source_text().len()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_printed_size_expr {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl PrintedSize for $ty<'_> {
+                fn printed_size(&self) -> usize {
+                    let mut codegen = codegen();
+                    self.print_expr(&mut codegen, Precedence::Lowest, Context::empty());
+                    codegen.into_source_text().len()
+                }
+            }
+        )*
+    };
+}
+
+impl_printed_size!(
+    Statement,
+    Function,
+    ForStatement,
+    ReturnStatement,
+    VariableDeclaration,
+    ObjectProperty,
+    AssignmentTargetProperty,
+    AssignmentTargetPropertyProperty,
+    BindingProperty,
+    MethodDefinition,
+    PropertyDefinition,
+    AccessorProperty,
+);
+
+impl_printed_size_expr!(Expression, UnaryExpression, CallExpression, NewExpression);
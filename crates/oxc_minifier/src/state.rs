@@ -1,8 +1,9 @@
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
+use oxc_ast::ast::Expression;
 use oxc_ecmascript::constant_evaluation::ConstantValue;
-use oxc_semantic::SymbolId;
-use oxc_span::SourceType;
+use oxc_semantic::{ReferenceId, ScopeId, SymbolId};
+use oxc_span::{Atom, SourceType};
 
 use crate::CompressOptions;
 
@@ -16,10 +17,59 @@ pub struct MinifierState<'a> {
     /// Values are saved during constant evaluation phase.
     /// Values are read during [oxc_ecmascript::is_global_reference::IsGlobalReference::get_constant_value_for_reference_id].
     pub constant_values: FxHashMap<SymbolId, ConstantValue<'a>>,
+
+    /// Functions which can be inlined into their only call site.
+    ///
+    /// Recorded when exiting a function declaration, read when exiting a call expression or identifier.
+    /// See [crate::CompressOptionsInline::functions] and [crate::CompressOptionsUnsafe::reduce_funcs].
+    pub inline_functions: FxHashMap<SymbolId, InlineFunction<'a>>,
+
+    /// Objects which are never mutated and never escape, so reads of their properties can be inlined.
+    ///
+    /// Collected before the first iteration of the peephole optimizer.
+    /// See [crate::CompressOptionsInline::properties].
+    pub constant_objects: FxHashMap<SymbolId, ConstantObject<'a>>,
+
+    /// Variables whose declarator with an initializer has been visited in the current iteration
+    /// of the peephole optimizer.
+    ///
+    /// Used to not inline [Self::constant_values] and [Self::constant_objects]
+    /// into references before the declaration, e.g. `f(a); const a = 1`.
+    pub initialized_symbols: FxHashSet<SymbolId>,
+}
+
+/// A function of the form `function f() { return expr }`,
+/// or with [crate::CompressOptionsUnsafe::reduce_funcs], any function which is referenced once.
+pub struct InlineFunction<'a> {
+    /// Scope of the function.
+    pub scope_id: ScopeId,
+    /// Copy of the returned expression, or of the whole function as a function expression,
+    /// with the same reference IDs as the original.
+    pub expression: Expression<'a>,
+    /// Whether `expression` replaces a call of the function (`f()`),
+    /// or a reference to the function (`f`).
+    pub replaces_call: bool,
+}
+
+/// An object which is never mutated, whose properties are only read with static keys.
+pub struct ConstantObject<'a> {
+    /// Values of properties which are constant, and the number of times each is read.
+    pub properties: FxHashMap<Atom<'a>, (ConstantValue<'a>, /* reads */ usize)>,
+    /// `Foo` in `Foo || {}` in `var Foo = (Foo => { ... })(Foo || {})`, emitted for TypeScript enums.
+    ///
+    /// `Foo` is always `undefined` there, so the reference can be removed.
+    pub self_reference: Option<ReferenceId>,
 }
 
 impl MinifierState<'_> {
     pub fn new(source_type: SourceType, options: CompressOptions) -> Self {
-        Self { source_type, options, constant_values: FxHashMap::default() }
+        Self {
+            source_type,
+            options,
+            constant_values: FxHashMap::default(),
+            inline_functions: FxHashMap::default(),
+            constant_objects: FxHashMap::default(),
+            initialized_symbols: FxHashSet::default(),
+        }
     }
 }
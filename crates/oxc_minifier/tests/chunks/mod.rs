@@ -0,0 +1,120 @@
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_minifier::{MangleOptions, Minifier, MinifierChunk, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+/// `(source text, is entry, imports)`
+type Chunk<'s> = (&'s str, bool, &'s [(&'s str, usize)]);
+
+/// Minify chunks, returning the output and renamed exports of each.
+fn build_chunks(chunks: &[Chunk]) -> Vec<(String, FxHashMap<String, String>)> {
+    let allocator = Allocator::default();
+    let mut programs = chunks
+        .iter()
+        .map(|(source_text, _, _)| {
+            Parser::new(&allocator, source_text, SourceType::mjs()).parse().program
+        })
+        .collect::<Vec<_>>();
+    let mut minifier_chunks = programs
+        .iter_mut()
+        .zip(chunks)
+        .map(|(program, (_, is_entry, imports))| MinifierChunk {
+            program,
+            is_entry: *is_entry,
+            imports: imports
+                .iter()
+                .map(|(source, index)| ((*source).to_string(), *index))
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    let options = MinifierOptions { mangle: Some(MangleOptions::default()), compress: None };
+    let ret = Minifier::new(options).build_chunks(&allocator, &mut minifier_chunks);
+    programs
+        .iter()
+        .zip(ret.scopings)
+        .zip(ret.renamed_exports)
+        .map(|((program, scoping), renamed)| {
+            (Codegen::new().with_scoping(scoping).build(program).code, renamed)
+        })
+        .collect()
+}
+
+#[test]
+fn shared_exports() {
+    let chunks = build_chunks(&[
+        ("import { used, rare } from './shared.js'; used(rare)", true, &[("./shared.js", 2)]),
+        ("import { used } from './shared.js'; export { used }", true, &[("./shared.js", 2)]),
+        (
+            "export function used() {} export const rare = 1; const x = 2; export { x as unused }",
+            false,
+            &[],
+        ),
+    ]);
+
+    // Most imported export gets the shortest name.
+    let renamed = &chunks[2].1;
+    assert_eq!(renamed["used"], "e");
+    assert_eq!(renamed["rare"], "t");
+    assert_eq!(renamed["unused"], "n");
+    assert!(chunks[0].1.is_empty() && chunks[1].1.is_empty());
+
+    assert!(chunks[0].0.contains("import { e as used, t as rare } from \"./shared.js\";"));
+    assert!(chunks[1].0.contains("import { e as used } from \"./shared.js\";"));
+    assert!(chunks[1].0.contains("export { used };"));
+    assert!(chunks[2].0.contains("export { used as e };"));
+    assert!(chunks[2].0.contains("export { rare as t };"));
+    assert!(chunks[2].0.contains("export { x as n };"));
+}
+
+#[test]
+fn re_exports() {
+    let chunks = build_chunks(&[
+        ("import { b } from './b.js'; b()", true, &[("./b.js", 1)]),
+        ("export { a as b } from './c.js'", false, &[("./c.js", 2)]),
+        ("export const a = 1", false, &[]),
+    ]);
+    assert_eq!(chunks[1].1["b"], "e");
+    assert_eq!(chunks[2].1["a"], "e");
+    assert!(chunks[0].0.contains("import { e as b } from \"./b.js\";"));
+    assert!(chunks[1].0.contains("export { e } from \"./c.js\";"));
+}
+
+#[test]
+fn observable_namespaces_are_kept() {
+    let chunks = build_chunks(&[
+        (
+            "import * as ns from './a.js'; import('./b.js'); export * from './c.js'",
+            true,
+            &[("./a.js", 1), ("./b.js", 2), ("./c.js", 3)],
+        ),
+        ("export const a = 1", false, &[]),
+        ("export const b = 1", false, &[]),
+        ("export const c = 1", false, &[]),
+        ("export * from './a.js'; export const d = 1", false, &[("./a.js", 1)]),
+    ]);
+    for (code, renamed) in &chunks[1..] {
+        assert!(renamed.is_empty(), "{code}");
+    }
+}
+
+#[test]
+fn kept_export_names_are_not_reused() {
+    let chunks =
+        build_chunks(&[("const x = 1; export { x as 'e' }; export const y = 2", false, &[])]);
+    assert_eq!(chunks[0].1["y"], "t");
+}
+
+#[test]
+fn default_export_specifiers_are_kept() {
+    let chunks = build_chunks(&[
+        ("import a from './a.js'; a()", true, &[("./a.js", 1)]),
+        ("function x() {} export { x as default }; export const y = 2", false, &[]),
+    ]);
+    assert!(!chunks[1].1.contains_key("default"));
+    assert_eq!(chunks[1].1["y"], "e");
+    assert!(chunks[0].0.contains("import a from \"./a.js\";"));
+    assert!(chunks[1].0.contains("export { x as default };"), "{}", chunks[1].0);
+}
@@ -2,7 +2,7 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler, TopLevelRenameOptions};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -23,6 +23,44 @@ fn direct_eval() {
     assert_eq!(mangled, "function foo() {\n\tlet NO_MANGLE;\n\teval(\"\");\n}\n");
 }
 
+#[test]
+fn top_level_renames() {
+    let rename = |source_text: &str, options: &TopLevelRenameOptions| {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::cjs()).parse().program;
+        let ret = Mangler::new().build_with_top_level_renames(&program, options);
+        let code = Codegen::new().with_scoping(Some(ret.scoping)).build(&program).code;
+        let mut renamed = ret.renamed.into_iter().collect::<Vec<_>>();
+        renamed.sort_unstable();
+        (code, renamed)
+    };
+    let options = TopLevelRenameOptions {
+        reserved: std::iter::once("api".to_string()).collect(),
+        other_bindings: ["foo", "bar", "bar$1", "api"].into_iter().map(String::from).collect(),
+    };
+
+    let (code, renamed) = rename(
+        "var foo = 1; function bar(x) { return foo + x } let baz = bar(foo$1); var api = {}",
+        &options,
+    );
+    assert_eq!(
+        code,
+        "var foo$2 = 1;\nfunction bar$2(r) {\n\treturn foo$2 + r;\n}\nlet baz = bar$2(foo$1);\nvar api = {};\n"
+    );
+    assert_eq!(
+        renamed,
+        [("bar".to_string(), "bar$2".to_string()), ("foo".to_string(), "foo$2".to_string())]
+    );
+
+    // A renamed declaration is not shadowed where it is referenced.
+    let (code, _) = rename("var foo; function f(foo$1) { return foo + foo$1 }", &options);
+    assert_eq!(code, "var foo$2;\nfunction f(t) {\n\treturn foo$2 + t;\n}\n");
+
+    let (code, renamed) = rename("var foo; eval('foo')", &options);
+    assert_eq!(code, "var foo;\neval(\"foo\");\n");
+    assert!(renamed.is_empty());
+}
+
 #[test]
 fn mangler() {
     let cases = [
@@ -1,5 +1,6 @@
 #![allow(clippy::literal_string_with_formatting_args)]
 
+mod chunks;
 mod ecmascript;
 mod mangler;
 mod peephole;
@@ -3,7 +3,9 @@ use cow_utils::CowUtils;
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
 use oxc_minifier::CompressOptions;
+use oxc_minifier::CompressOptionsUnused;
 use oxc_minifier::Compressor;
+use oxc_minifier::TreeShakeOptions;
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -38,6 +40,14 @@ fn test_same(source_text: &str) {
     test(source_text, source_text);
 }
 
+#[track_caller]
+fn test_module(source_text: &str, expected: &str, options: CompressOptions) {
+    let source_type = SourceType::mjs();
+    let result = run(source_text, source_type, Some(options));
+    let expected = run(expected, source_type, None);
+    assert_eq!(result, expected, "\nfor source\n{source_text}\nexpect\n{expected}\ngot\n{result}");
+}
+
 #[test]
 fn dce_if_statement() {
     test("if (true) { foo }", "foo");
@@ -177,6 +187,57 @@ fn dce_var_hoisting() {
     );
 }
 
+#[test]
+fn dce_unreferenced_declarations() {
+    let options = CompressOptions { unused: CompressOptionsUnused::Remove, ..default_options() };
+    let dev = "'production' == 'development'";
+
+    test_module(&format!("function log() {{}} if ({dev}) log()"), "", options.clone());
+    // Functions only referenced from removed functions are removed in the next iteration.
+    test_module(
+        &format!("function a() {{}} function b() {{ a() }} if ({dev}) b()"),
+        "",
+        options.clone(),
+    );
+    test_module(
+        &format!("import {{ a, b }} from 'x'; if ({dev}) a(); b()"),
+        "import { b } from 'x'; b()",
+        options.clone(),
+    );
+    test_module(
+        &format!("import debug from 'debug'; const log = debug('app'); if ({dev}) log()"),
+        "import debug from 'debug'; const log = debug('app')",
+        options.clone(),
+    );
+    test_module(
+        &format!("import * as devtools from 'devtools'; const x = devtools; if ({dev}) x()"),
+        "import 'devtools'",
+        options.clone(),
+    );
+    test_module(
+        &format!("import {{ a }} from 'x'; if ({dev}) a()"),
+        "",
+        CompressOptions {
+            treeshake: TreeShakeOptions {
+                module_side_effects: false,
+                ..TreeShakeOptions::default()
+            },
+            ..options.clone()
+        },
+    );
+    // Declarations which are unused in the source, and exports, are kept.
+    test_module(
+        &format!("import {{ a }} from 'x'; const b = 1; export function c() {{}} if ({dev}) c()"),
+        "import { a } from 'x'; const b = 1; export function c() {}",
+        options,
+    );
+    test_module(
+        &format!("import {{ a }} from 'x'; if ({dev}) a()"),
+        "import { a } from 'x'",
+        default_options(),
+    );
+}
+
 #[test]
 fn pure_comment_for_pure_global_constructors() {
     test("var x = new WeakSet", "var x = /* @__PURE__ */ new WeakSet();\n");
@@ -3,6 +3,7 @@ mod dead_code_elimination;
 mod esbuild;
 mod minimize_exit_points;
 mod oxc;
+mod report;
 mod statement_fusion;
 
 use oxc_minifier::{CompressOptions, CompressOptionsUnused};
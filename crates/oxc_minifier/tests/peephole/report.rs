@@ -0,0 +1,47 @@
+use oxc_allocator::Allocator;
+use oxc_minifier::{CompressOptions, Compressor, MinifierPass};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use super::default_options;
+
+#[test]
+fn report() {
+    let allocator = Allocator::default();
+    let source_text = "x = 1 + 2; if (!!y) z()";
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let options = CompressOptions { report: true, ..default_options() };
+    let report = Compressor::new(&allocator).build(&mut program, options).unwrap();
+
+    assert!(report.final_size < report.original_size);
+    let first = &report.iterations[0];
+    assert_eq!(first.iteration, 0);
+    let fold_constants =
+        first.passes.iter().find(|pass| pass.pass == MinifierPass::FoldConstants).unwrap();
+    assert_eq!(fold_constants.changes, 1);
+    assert_eq!(fold_constants.bytes_saved, 2);
+    assert!(report.final_size <= report.iterations.last().unwrap().size);
+    assert!(!report.to_string().is_empty());
+}
+
+#[test]
+fn no_report() {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, "x = 1 + 2", SourceType::mjs()).parse().program;
+    assert!(Compressor::new(&allocator).build(&mut program, default_options()).is_none());
+}
+
+#[test]
+fn disabled_passes() {
+    let options =
+        CompressOptions { disabled_passes: vec![MinifierPass::FoldConstants], ..default_options() };
+    crate::test("x = 1 + 2", "x = 1 + 2", options);
+}
+
+#[test]
+fn pass_names() {
+    for pass in MinifierPass::ALL {
+        assert_eq!(pass.as_str().parse::<MinifierPass>(), Ok(pass));
+    }
+    assert!("foo".parse::<MinifierPass>().is_err());
+}
@@ -1,8 +1,10 @@
 mod comment;
 mod error;
+mod source_map;
 
 pub use comment::*;
 pub use error::*;
+pub use source_map::*;
 
 use oxc_ast::{CommentKind, ast::Program};
 use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
@@ -69,6 +71,7 @@ pub fn get_source_type(
         Some("ts") => SourceType::ts(),
         Some("tsx") => SourceType::tsx(),
         Some("dts") => SourceType::d_ts(),
+        Some("flow") => SourceType::flow(),
         _ => SourceType::from_path(filename).unwrap_or_default(),
     };
     match source_type {
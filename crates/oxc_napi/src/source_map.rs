@@ -0,0 +1,42 @@
+use napi_derive::napi;
+
+// Aligned with Rollup's `ExistingRawSourceMap`, plus the `debugId` field of the Debug ID proposal.
+//
+// <https://github.com/tc39/ecma426/blob/main/proposals/debug-id.md>
+//
+// Unlike `oxc_sourcemap::napi::SourceMap`, `x_google_ignoreList` and `debugId` are kept.
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct SourceMap {
+    pub file: Option<String>,
+    pub mappings: String,
+    pub names: Vec<String>,
+    pub source_root: Option<String>,
+    pub sources: Vec<String>,
+    pub sources_content: Option<Vec<String>>,
+    pub version: u8,
+    #[napi(js_name = "x_google_ignoreList")]
+    pub x_google_ignorelist: Option<Vec<u32>>,
+    pub debug_id: Option<String>,
+}
+
+impl From<oxc_sourcemap::SourceMap> for SourceMap {
+    fn from(source_map: oxc_sourcemap::SourceMap) -> Self {
+        let x_google_ignorelist = source_map.get_x_google_ignore_list().map(<[u32]>::to_vec);
+        let debug_id = source_map.get_debug_id().map(String::from);
+        let json = source_map.to_json();
+        Self {
+            file: json.file,
+            mappings: json.mappings,
+            names: json.names,
+            source_root: json.source_root,
+            sources: json.sources,
+            sources_content: json.sources_content.map(|content| {
+                content.into_iter().map(Option::unwrap_or_default).collect::<Vec<_>>()
+            }),
+            version: 3,
+            x_google_ignorelist,
+            debug_id,
+        }
+    }
+}
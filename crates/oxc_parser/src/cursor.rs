@@ -17,7 +17,8 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
-    fatal_error: Option<FatalError>,
+    tokens_len: usize,
+    fatal_error: Option<FatalError<'a>>,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -101,6 +102,7 @@ impl<'a> ParserImpl<'a> {
     #[inline]
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.collect_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_token();
     }
@@ -108,10 +110,21 @@ impl<'a> ParserImpl<'a> {
     /// Move to the next `JSXChild`
     /// Checks if the current token is escaped if it is a keyword
     pub(crate) fn advance_for_jsx_child(&mut self) {
+        self.collect_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_jsx_child();
     }
 
+    /// Record current token in `tokens`, if `collect_tokens` option is enabled.
+    #[inline]
+    fn collect_token(&mut self) {
+        if self.options.collect_tokens
+            && !matches!(self.cur_kind(), Kind::Eof | Kind::HashbangComment)
+        {
+            self.tokens.push(self.token);
+        }
+    }
+
     /// Advance and return true if we are at `Kind`, return false otherwise
     #[inline]
     #[must_use = "Use `bump` instead of `eat` if you are ignoring the return value"]
@@ -151,7 +164,7 @@ impl<'a> ParserImpl<'a> {
         } else {
             let span = Span::empty(self.prev_token_end);
             let error = diagnostics::auto_semicolon_insertion(span);
-            self.set_fatal_error(error);
+            self.set_fatal_error_missing_token(error);
         }
     }
 
@@ -167,7 +180,11 @@ impl<'a> ParserImpl<'a> {
         if !self.at(kind) {
             let range = self.cur_token().span();
             let error = diagnostics::expect_token(kind.to_str(), self.cur_kind().to_str(), range);
-            self.set_fatal_error(error);
+            if self.cur_kind() == Kind::Eof {
+                self.set_fatal_error_missing_token(error);
+            } else {
+                self.set_fatal_error(error);
+            }
         }
     }
 
@@ -210,7 +227,7 @@ impl<'a> ParserImpl<'a> {
 
     /// Tell lexer to continue reading jsx identifier if the lexer character position is at `-` for `<component-name>`
     pub(crate) fn continue_lex_jsx_identifier(&mut self) {
-        if let Some(token) = self.lexer.continue_lex_jsx_identifier() {
+        if let Some(token) = self.lexer.continue_lex_jsx_identifier(self.token.start()) {
             self.token = token;
         }
     }
@@ -261,18 +278,26 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_len: self.tokens.len(),
             fatal_error: self.fatal_error.take(),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos, fatal_error } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos,
+            tokens_len,
+            fatal_error,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_pos);
+        self.tokens.truncate(tokens_len);
         self.fatal_error = fatal_error;
     }
 
@@ -3,15 +3,26 @@
 use oxc_allocator::Dummy;
 use oxc_diagnostics::OxcDiagnostic;
 
-use crate::{ParserImpl, diagnostics, lexer::Kind};
+use crate::{
+    ParserImpl, diagnostics,
+    lexer::{Kind, LexerCheckpoint, Token},
+};
 
 /// Fatal parsing error.
 #[derive(Debug, Clone)]
-pub struct FatalError {
+pub struct FatalError<'a> {
     /// The fatal error
     pub error: OxcDiagnostic,
     /// Length of `errors` at time fatal error is recorded
     pub errors_len: usize,
+    /// `true` if the error is a missing token, which error recovery can insert
+    pub missing_token: bool,
+    /// Current token at time fatal error is recorded
+    pub token: Token,
+    /// End of previous token at time fatal error is recorded
+    pub prev_token_end: u32,
+    /// Lexer state at time fatal error is recorded
+    pub lexer: LexerCheckpoint<'a>,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -53,9 +64,30 @@ impl<'a> ParserImpl<'a> {
     /// Advance lexer's cursor to end of file.
     #[cold]
     pub(crate) fn set_fatal_error(&mut self, error: OxcDiagnostic) {
+        self.set_fatal_error_impl(error, false);
+    }
+
+    /// Set fatal error for a missing token.
+    ///
+    /// In error recovery mode, the token is treated as inserted at current position.
+    /// See [`ParserImpl::parse_with_recovery`].
+    #[cold]
+    pub(crate) fn set_fatal_error_missing_token(&mut self, error: OxcDiagnostic) {
+        self.set_fatal_error_impl(error, true);
+    }
+
+    fn set_fatal_error_impl(&mut self, error: OxcDiagnostic, missing_token: bool) {
         if self.fatal_error.is_none() {
+            let lexer = self.lexer.checkpoint();
             self.lexer.advance_to_end();
-            self.fatal_error = Some(FatalError { error, errors_len: self.errors.len() });
+            self.fatal_error = Some(FatalError {
+                error,
+                errors_len: self.errors.len(),
+                missing_token,
+                token: self.token,
+                prev_token_end: self.prev_token_end,
+                lexer,
+            });
         }
     }
 
@@ -68,4 +100,120 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn has_fatal_error(&self) -> bool {
         matches!(self.cur_kind(), Kind::Eof | Kind::Undetermined) || self.fatal_error.is_some()
     }
+
+    /// Parse an item of a list (statement or class element) with `parse`.
+    ///
+    /// If `recover_from_errors` option is enabled and a fatal error occurs while parsing the item,
+    /// the error is recorded and parsing continues:
+    ///
+    /// * If the error is a missing token which can be inserted (a semicolon which automatic
+    ///   semicolon insertion cannot insert, or any token expected at end of file),
+    ///   the item is kept as if the token was present.
+    /// * Otherwise, parser rewinds to start of the item, skips tokens up to the next
+    ///   synchronization point, and returns `None`.
+    ///
+    /// Returns `Some` if the item was parsed successfully, or if recovery is not enabled
+    /// (in which case the fatal error remains set).
+    pub(crate) fn parse_with_recovery<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> T,
+    ) -> Option<T> {
+        if !self.options.recover_from_errors {
+            return Some(parse(self));
+        }
+
+        let checkpoint = self.checkpoint();
+        let ctx = self.ctx;
+        let start = self.token.start();
+        let errors_len = self.errors.len();
+
+        let item = parse(self);
+        let Some(fatal_error) = self.fatal_error.take() else {
+            return Some(item);
+        };
+
+        self.ctx = ctx;
+        // Errors recorded after the fatal error are a consequence of it
+        self.errors.truncate(fatal_error.errors_len);
+
+        // Insert missing token, if parser has not moved on since the error
+        if fatal_error.missing_token
+            && self.cur_kind() == fatal_error.token.kind()
+            && self.token.start() == fatal_error.token.start()
+        {
+            self.lexer.rewind(fatal_error.lexer);
+            self.token = fatal_error.token;
+            self.prev_token_end = fatal_error.prev_token_end;
+            self.error(fatal_error.error);
+            return Some(item);
+        }
+
+        let item_errors = self.errors.split_off(errors_len);
+        self.rewind(checkpoint);
+        self.errors.extend(item_errors);
+        self.error(fatal_error.error);
+        self.skip_to_synchronization_point(start, fatal_error.token.start());
+        None
+    }
+
+    /// Skip tokens following a fatal error, up to the next synchronization point, which is:
+    ///
+    /// * After a `;`.
+    /// * Before a `}` which closes the enclosing block or class body.
+    /// * Before a token on a new line.
+    ///
+    /// Only positions after start of the item (`start`) and at or after the erroring token
+    /// (`error_pos`) are synchronization points. Except for `}` closing the enclosing block,
+    /// they must also be outside any brackets opened within the item.
+    /// At least one token is always skipped.
+    fn skip_to_synchronization_point(&mut self, start: u32, error_pos: u32) {
+        // Unclosed `(`, `[`, `{` and template literal heads
+        let mut open = vec![];
+        loop {
+            let token = self.cur_token();
+            let kind = token.kind();
+            if kind == Kind::Eof {
+                break;
+            }
+
+            let after_error = token.start() >= error_pos && token.start() > start;
+            let at_boundary = after_error && open.is_empty();
+            // A `}` with no matching `{` closes the enclosing block, even if a `(` or `[` is unclosed
+            let closes_block = kind == Kind::RCurly
+                && !open.iter().any(|&k| matches!(k, Kind::LCurly | Kind::TemplateHead));
+            if (after_error && closes_block) || (at_boundary && token.is_on_new_line()) {
+                break;
+            }
+
+            match kind {
+                Kind::LCurly | Kind::LParen | Kind::LBrack | Kind::TemplateHead => open.push(kind),
+                Kind::RCurly => {
+                    if let Some(index) =
+                        open.iter().rposition(|&k| matches!(k, Kind::LCurly | Kind::TemplateHead))
+                    {
+                        open.truncate(index + 1);
+                        if open.pop() == Some(Kind::TemplateHead) {
+                            // Continue the template literal after the substitution
+                            self.re_lex_template_substitution_tail();
+                            if self.at(Kind::TemplateMiddle) {
+                                open.push(Kind::TemplateHead);
+                            }
+                        }
+                    }
+                }
+                Kind::RParen | Kind::RBrack => {
+                    let opening = if kind == Kind::RParen { Kind::LParen } else { Kind::LBrack };
+                    if let Some(index) = open.iter().rposition(|&k| k == opening) {
+                        open.truncate(index);
+                    }
+                }
+                Kind::Semicolon if at_boundary => {
+                    self.bump_any();
+                    break;
+                }
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
 }
@@ -0,0 +1,146 @@
+//! [Flow](https://flow.org)
+//!
+//! Flow annotations are parsed with the TypeScript grammar (`is_ts` is set for Flow sources),
+//! this module only covers the syntax which differs from TypeScript.
+//! Constructs are lowered to the closest TypeScript AST node so that downstream tools
+//! (e.g. the transformer which strips them) work without knowing about Flow.
+
+use oxc_allocator::Vec;
+use oxc_ast::{NONE, ast::*};
+
+use crate::{ParserImpl, lexer::Kind, modifiers::Modifiers};
+
+impl<'a> ParserImpl<'a> {
+    /// Variance sigils on object type properties and type parameters: `+a` / `-a`.
+    /// They have no TypeScript equivalent and are discarded.
+    pub(crate) fn eat_flow_variance(&mut self) {
+        if self.source_type.is_flow() && matches!(self.cur_kind(), Kind::Plus | Kind::Minus) {
+            self.bump_any();
+        }
+    }
+
+    /// `|}` closing an exact object type `{| ... |}`.
+    pub(crate) fn at_flow_exact_object_end(&mut self) -> bool {
+        self.source_type.is_flow()
+            && self.at(Kind::Pipe)
+            && self.lexer.peek_token().kind() == Kind::RCurly
+    }
+
+    /// Object type `{ ... }` and exact object type `{| ... |}`.
+    ///
+    /// Exactness is not represented, spreads `...T` and the inexact marker `...` are discarded.
+    pub(crate) fn parse_flow_object_type(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly);
+        let mut members = self.ast.vec();
+        // `{||}` is lexed as `{`, `||`, `}`
+        if self.eat(Kind::Pipe2) {
+            self.expect(Kind::RCurly);
+            return self.ast.ts_type_type_literal(self.end_span(span), members);
+        }
+        let exact = self.eat(Kind::Pipe);
+        loop {
+            let kind = self.cur_kind();
+            if self.has_fatal_error()
+                || matches!(kind, Kind::Eof | Kind::RCurly)
+                || (exact && kind == Kind::Pipe)
+            {
+                break;
+            }
+            if self.eat(Kind::Dot3) {
+                let inexact_marker =
+                    matches!(self.cur_kind(), Kind::Comma | Kind::Semicolon | Kind::RCurly)
+                        || (exact && self.at(Kind::Pipe));
+                if !inexact_marker {
+                    self.parse_ts_type();
+                }
+                if !self.eat(Kind::Comma) {
+                    self.bump(Kind::Semicolon);
+                }
+                continue;
+            }
+            self.eat_flow_variance();
+            members.push(self.parse_ts_type_signature());
+        }
+        if exact {
+            self.expect(Kind::Pipe);
+        }
+        self.expect(Kind::RCurly);
+        self.ast.ts_type_type_literal(self.end_span(span), members)
+    }
+
+    /// `opaque type`
+    pub(crate) fn at_flow_opaque_type(&mut self) -> bool {
+        self.source_type.is_flow()
+            && self.at(Kind::Ident)
+            && self.cur_src() == "opaque"
+            && self.lexer.peek_token().kind() == Kind::Type
+    }
+
+    /// `opaque type T: Super = Impl;`, represented as `type T = Impl;`.
+    ///
+    /// The supertype is discarded. `declare opaque type T;` has no underlying type
+    /// and is represented as `declare type T = unknown;`.
+    pub(crate) fn parse_flow_opaque_type_alias(
+        &mut self,
+        span: u32,
+        modifiers: &Modifiers<'a>,
+    ) -> Declaration<'a> {
+        self.bump_any(); // bump `opaque`
+        self.expect(Kind::Type);
+        let id = self.parse_binding_identifier();
+        let params = self.parse_ts_type_parameters();
+        if self.eat(Kind::Colon) {
+            self.parse_ts_type();
+        }
+        let ty = if self.eat(Kind::Eq) {
+            self.parse_ts_type()
+        } else {
+            let span = self.end_span(span);
+            if !modifiers.contains_declare() {
+                self.expect(Kind::Eq);
+            }
+            self.ast.ts_type_unknown_keyword(span)
+        };
+        self.asi();
+        self.ast.declaration_ts_type_alias(
+            self.end_span(span),
+            id,
+            params,
+            ty,
+            modifiers.contains_declare(),
+        )
+    }
+
+    /// `declare export <declaration>`, represented as `export declare <declaration>`.
+    pub(crate) fn parse_flow_declare_export(
+        &mut self,
+        span: u32,
+        modifiers: &Modifiers<'a>,
+        decorators: Vec<'a, Decorator<'a>>,
+    ) -> Statement<'a> {
+        self.bump_any(); // bump `export`
+        let declaration = self.parse_declaration(self.start_span(), modifiers, decorators);
+        let decl = self.ast.alloc_export_named_declaration(
+            self.end_span(span),
+            Some(declaration),
+            self.ast.vec(),
+            None,
+            ImportOrExportKind::Type,
+            NONE,
+        );
+        Statement::ExportNamedDeclaration(decl)
+    }
+
+    /// An expression inside parentheses, optionally followed by a type cast:
+    /// `(expression: Type)`, represented as `expression as Type`.
+    pub(crate) fn parse_flow_type_cast(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        let expression = self.parse_assignment_expression_or_higher();
+        if !self.eat(Kind::Colon) {
+            return expression;
+        }
+        let type_annotation = self.parse_ts_type();
+        self.ast.expression_ts_as(self.end_span(span), expression, type_annotation)
+    }
+}
@@ -0,0 +1,368 @@
+//! Incremental parsing.
+//!
+//! After an edit, top-level statements before and after the edited range are reused from the
+//! previous [`Program`], and only the statements in between are parsed again.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Program, RegExpLiteral};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_span::{GetSpan, Span};
+
+use crate::{MAX_LEN, ParserImpl, ParserReturn, context::StatementContext};
+
+/// A change to source text: `span` of the previous source text is replaced with `new_text`.
+#[derive(Debug, Clone, Copy)]
+pub struct TextEdit<'e> {
+    /// Range of the previous source text which is replaced
+    pub span: Span,
+    /// Text which replaces `span`
+    pub new_text: &'e str,
+}
+
+impl<'e> TextEdit<'e> {
+    /// Create a [`TextEdit`] replacing `span` of the previous source text with `new_text`.
+    pub fn new(span: Span, new_text: &'e str) -> Self {
+        Self { span, new_text }
+    }
+
+    /// Check that applying this edit to `previous_source` results in `source`.
+    fn transforms(&self, previous_source: &str, source: &str) -> bool {
+        let (start, end) = (self.span.start as usize, self.span.end as usize);
+        let new_end = start + self.new_text.len();
+        start <= end
+            && previous_source
+                .get(..start)
+                .is_some_and(|before| source.get(..start) == Some(before))
+            && source.get(start..new_end) == Some(self.new_text)
+            && previous_source.get(end..).is_some_and(|after| source.get(new_end..) == Some(after))
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Parse source text after `edit`, reusing top-level statements of the `previous` parse
+    /// which precede or follow the edited range.
+    ///
+    /// Parsing starts after the last statement which ends before the edit (the statement
+    /// preceding it is parsed again, as the edit may extend it, e.g. `a` followed by `(b)`),
+    /// and stops at a statement boundary which is the start of a statement after the edit.
+    ///
+    /// Returns `None` if a full parse is required:
+    /// * Previous or new source text has syntax errors.
+    /// * The edit is in the first statement, directives or hashbang.
+    /// * Tokens are collected, or source type is unambiguous.
+    #[expect(clippy::cast_possible_truncation)]
+    pub(crate) fn parse_incremental(
+        mut self,
+        previous: ParserReturn<'a>,
+        edit: TextEdit<'_>,
+    ) -> Option<ParserReturn<'a>> {
+        let ParserReturn { program, errors, irregular_whitespaces, panicked, .. } = previous;
+        if panicked
+            || !errors.is_empty()
+            || self.options.collect_tokens
+            || self.source_type.is_unambiguous()
+            || program.source_type != self.source_type
+            || self.source_text.len() > MAX_LEN
+            || !edit.transforms(program.source_text, self.source_text)
+        {
+            return None;
+        }
+        let Program { hashbang, directives, body, comments, .. } = program;
+
+        let shift = Shift {
+            edit_end: edit.span.end,
+            new_end: edit.span.start + edit.new_text.len() as u32,
+        };
+
+        let prefix_len =
+            body.partition_point(|stmt| stmt.span().end < edit.span.start).saturating_sub(1);
+        if prefix_len == 0 {
+            return None;
+        }
+        let prefix_end = body[prefix_len - 1].span().end;
+        let suffix_start = body.partition_point(|stmt| stmt.span().start <= edit.span.end);
+        let suffix_starts = body[suffix_start..]
+            .iter()
+            .map(|stmt| shift.position(stmt.span().start))
+            .collect::<Vec<_>>();
+
+        let mut statements = self.ast.vec_with_capacity(body.len());
+        let mut body = body.into_iter();
+        for stmt in body.by_ref().take(prefix_len) {
+            if let Some(module_decl) = stmt.as_module_declaration() {
+                self.module_record_builder.visit_module_declaration(module_decl);
+            }
+            statements.push(stmt);
+        }
+
+        // Parse until reaching start of a statement which can be reused
+        self.lexer.resume_at(prefix_end);
+        self.bump_any();
+        let mut index = 0;
+        let reused_index = loop {
+            if self.has_fatal_error() {
+                break None;
+            }
+            let start = self.cur_token().start();
+            index += suffix_starts[index..].partition_point(|&suffix_start| suffix_start < start);
+            if suffix_starts.get(index) == Some(&start) {
+                break Some(index);
+            }
+            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            if let Some(module_decl) = stmt.as_module_declaration() {
+                self.module_record_builder.visit_module_declaration(module_decl);
+            }
+            statements.push(stmt);
+        };
+
+        self.check_unfinished_errors();
+        if self.fatal_error.is_some() || !self.errors.is_empty() || !self.lexer.errors.is_empty() {
+            return None;
+        }
+
+        // End of reparsed source text, in new and previous source text
+        let (reparsed_end, previous_reparsed_end) = match reused_index {
+            Some(index) => {
+                let start = suffix_starts[index];
+                (start, start - shift.new_end + shift.edit_end)
+            }
+            None => (self.source_text.len() as u32, u32::MAX),
+        };
+        if let Some(index) = reused_index {
+            let mut shift_spans = ShiftSpans { allocator: self.ast.allocator, shift };
+            for mut stmt in body.skip(suffix_start - prefix_len + index) {
+                shift_spans.visit_statement(&mut stmt);
+                if let Some(module_decl) = stmt.as_module_declaration() {
+                    self.module_record_builder.visit_module_declaration(module_decl);
+                }
+                statements.push(stmt);
+            }
+        }
+
+        let trivia = &self.lexer.trivia_builder;
+        let comments = self.ast.vec_from_iter(
+            comments
+                .iter()
+                .take_while(|comment| comment.span.end <= prefix_end)
+                .copied()
+                .chain(
+                    trivia
+                        .comments
+                        .iter()
+                        .take_while(|comment| comment.span.start < reparsed_end)
+                        .copied(),
+                )
+                .chain(
+                    comments
+                        .iter()
+                        .filter(|comment| comment.span.start >= previous_reparsed_end)
+                        .map(|comment| {
+                            let mut comment = *comment;
+                            comment.span = shift.span(comment.span);
+                            comment.attached_to = shift.position(comment.attached_to);
+                            comment
+                        }),
+                ),
+        );
+        let irregular_whitespaces = irregular_whitespaces
+            .iter()
+            .take_while(|span| span.end <= prefix_end)
+            .copied()
+            .chain(
+                trivia
+                    .irregular_whitespaces
+                    .iter()
+                    .copied()
+                    .filter(|span| span.start < reparsed_end),
+            )
+            .chain(
+                irregular_whitespaces
+                    .iter()
+                    .filter(|span| span.start >= previous_reparsed_end)
+                    .map(|&span| shift.span(span)),
+            )
+            .collect();
+
+        let program = self.ast.program(
+            Span::new(0, self.source_text.len() as u32),
+            self.source_type,
+            self.source_text,
+            comments,
+            hashbang,
+            directives,
+            statements,
+        );
+
+        let (module_record, module_record_errors) = self.module_record_builder.build();
+        let errors = if self.is_ts { vec![] } else { module_record_errors };
+
+        Some(ParserReturn {
+            program,
+            module_record,
+            errors,
+            irregular_whitespaces,
+            panicked: false,
+            is_flow_language: false,
+            tokens: vec![],
+        })
+    }
+}
+
+/// Mapping of positions after an edit in previous source text to positions in new source text.
+#[derive(Clone, Copy)]
+struct Shift {
+    /// End of edit in previous source text
+    edit_end: u32,
+    /// End of edit in new source text
+    new_end: u32,
+}
+
+impl Shift {
+    fn position(self, position: u32) -> u32 {
+        // Leave positions before the edit unchanged (e.g. empty spans of nodes without a position)
+        if position >= self.edit_end { position - self.edit_end + self.new_end } else { position }
+    }
+
+    fn span(self, span: Span) -> Span {
+        Span::new(self.position(span.start), self.position(span.end))
+    }
+}
+
+/// Visitor to move spans of a reused statement to their positions in new source text.
+struct ShiftSpans<'a> {
+    #[cfg_attr(not(feature = "regular_expression"), expect(dead_code))]
+    allocator: &'a Allocator,
+    shift: Shift,
+}
+
+impl<'a> VisitMut<'a> for ShiftSpans<'a> {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = self.shift.span(*span);
+    }
+
+    fn visit_reg_exp_literal(&mut self, it: &mut RegExpLiteral<'a>) {
+        walk_mut::walk_reg_exp_literal(self, it);
+
+        // Spans in parsed pattern are not visited, so parse it again at the new position
+        #[cfg(feature = "regular_expression")]
+        if it.regex.pattern.pattern.is_some() {
+            use oxc_allocator::Box;
+            use oxc_regular_expression::{LiteralParser, Options};
+
+            let (Some(raw), pattern) = (it.raw, it.regex.pattern.text.as_str()) else { return };
+            let pattern_span_offset = it.span.start + 1;
+            #[expect(clippy::cast_possible_truncation)]
+            let flags_span_offset = pattern_span_offset + pattern.len() as u32 + 1;
+            let flags = &raw.as_str()[pattern.len() + 2..];
+            it.regex.pattern.pattern = LiteralParser::new(
+                self.allocator,
+                pattern,
+                Some(flags),
+                Options { pattern_span_offset, flags_span_offset },
+            )
+            .parse()
+            .ok()
+            .map(|pattern| Box::new_in(pattern, self.allocator));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::{Address, Allocator, GetAddress};
+    use oxc_span::{SourceType, Span};
+
+    use crate::{ParseOptions, Parser, TextEdit};
+
+    /// Replace the range of `source` marked with `«»` with `new_text`, and check incremental parse
+    /// gives same result as a full parse. Returns indexes of top-level statements which were reused.
+    fn reparse(source: &str, new_text: &str) -> Vec<usize> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts().with_module(true);
+        let options = ParseOptions { parse_regular_expression: true, ..ParseOptions::default() };
+
+        let start = source.find('«').unwrap();
+        let end = source.find('»').unwrap() - '«'.len_utf8();
+        let source = source.chars().filter(|&ch| ch != '«' && ch != '»').collect::<String>();
+        let new_source = format!("{}{new_text}{}", &source[..start], &source[end..]);
+        let (source, new_source) = (allocator.alloc_str(&source), allocator.alloc_str(&new_source));
+        #[expect(clippy::cast_possible_truncation)]
+        let edit = TextEdit::new(Span::new(start as u32, end as u32), new_text);
+
+        let previous = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        let previous_addresses =
+            previous.program.body.iter().map(GetAddress::address).collect::<Vec<Address>>();
+        let ret = Parser::new(&allocator, new_source, source_type)
+            .with_options(options)
+            .parse_incremental(previous, edit);
+        let expected =
+            Parser::new(&allocator, new_source, source_type).with_options(options).parse();
+
+        assert_eq!(format!("{:?}", ret.program), format!("{:?}", expected.program), "{new_source}");
+        assert_eq!(ret.irregular_whitespaces, expected.irregular_whitespaces);
+        assert_eq!(ret.panicked, expected.panicked);
+        assert_eq!(
+            ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            expected.errors.iter().map(ToString::to_string).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            format!("{:?}", ret.module_record),
+            format!("{:?}", expected.module_record),
+            "{new_source}"
+        );
+
+        ret.program
+            .body
+            .iter()
+            .enumerate()
+            .filter(|(_, stmt)| previous_addresses.contains(&stmt.address()))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    #[test]
+    fn reuse_statements() {
+        // Edit in middle statement
+        let source =
+            "import a from 'a';\nlet b = 1;\n«foo(b)»;\n/* c */ export const c = /x+/g;\nbar();";
+        assert_eq!(reparse(source, "foo(b, 2)"), [0, 3, 4]);
+        // Insert a statement
+        let source =
+            "import a from 'a';\nlet b = 1;\nfoo(b);\n«»/* c */ export const c = /x+/g;\nbar();";
+        assert_eq!(reparse(source, "let d = `${b}`;\n"), [0, 1, 4, 5]);
+        // Delete a statement
+        let source =
+            "import a from 'a';\nlet b = 1;\n«foo(b);\n»/* c */ export const c = /x+/g;\nbar();";
+        assert_eq!(reparse(source, ""), [0, 2, 3]);
+        // Edit in last statement
+        let source =
+            "import a from 'a';\nlet b = 1;\nfoo(b);\n/* c */ export const c = /x+/g;\n«bar»();";
+        assert_eq!(reparse(source, "baz"), [0, 1, 2]);
+        // Edit which extends the preceding statement
+        assert_eq!(reparse("a;\nb\n«»c;\nd;", "(x);\n"), [0, 3]);
+        // Edits which affect parsing of following statements
+        assert_eq!(reparse("a;\nb;\n«»c;\nd; /**/\ne;", "/*"), [0, 2]);
+        assert_eq!(reparse("a;\nb;\n«»c;\nd; // `\ne;", "`"), [0, 3]);
+        // Edit in whitespace and comments
+        assert_eq!(reparse("a;\nb; «// x»\n\nc;\nd;", "/* y */"), [0, 2, 3]);
+    }
+
+    #[test]
+    fn fallback_to_full_parse() {
+        // Edit in first statement
+        assert!(reparse("«a»;\nb;", "x").is_empty());
+        // Edit in directives
+        assert!(reparse("'«use strict»';\na;\nb;", "abc").is_empty());
+        // Edit causes a syntax error
+        assert!(reparse("a;\nb;\n«c»;\nd;", "c(").is_empty());
+        // Edit fixes a syntax error
+        assert!(reparse("a;\nb;\nc«)»", ";").is_empty());
+        // Edit inconsistent with source text
+        let allocator = Allocator::default();
+        let previous = Parser::new(&allocator, "a;\nb;\nc;", SourceType::mjs()).parse();
+        let ret = Parser::new(&allocator, "a;\nb;\nd;", SourceType::mjs())
+            .parse_incremental(previous, TextEdit::new(Span::new(3, 4), "x"));
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.body.len(), 3);
+    }
+}
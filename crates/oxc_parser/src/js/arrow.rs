@@ -132,6 +132,8 @@ impl<'a> ParserImpl<'a> {
                         }
 
                         match third {
+                            // In Flow, "(a:" may also be a type cast "(a: T)".
+                            Kind::Colon if self.source_type.is_flow() => Tristate::Maybe,
                             // If we have something like "(a:", then we must have a
                             // type-annotated parameter in an arrow function expression.
                             Kind::Colon => Tristate::True,
@@ -187,16 +187,18 @@ impl<'a> ParserImpl<'a> {
 
     fn parse_class_body(&mut self) -> Box<'a, ClassBody<'a>> {
         let span = self.start_span();
-        let class_elements = self.parse_normal_list(Kind::LCurly, Kind::RCurly, |p| {
+        self.expect(Kind::LCurly);
+        let mut class_elements = self.ast.vec();
+        while !self.at(Kind::RCurly) && !self.has_fatal_error() {
             // Skip empty class element `;`
-            if p.eat(Kind::Semicolon) {
-                while p.eat(Kind::Semicolon) {}
-                if p.at(Kind::RCurly) {
-                    return None;
-                }
+            if self.eat(Kind::Semicolon) {
+                continue;
             }
-            Some(Self::parse_class_element(p))
-        });
+            if let Some(element) = self.parse_with_recovery(Self::parse_class_element) {
+                class_elements.push(element);
+            }
+        }
+        self.expect(Kind::RCurly);
         self.ast.alloc_class_body(self.end_span(span), class_elements)
     }
 
@@ -232,6 +234,8 @@ impl<'a> ParserImpl<'a> {
             return self.parse_class_static_block(span);
         }
 
+        self.eat_flow_variance();
+
         let r#abstract = modifiers.contains(ModifierKind::Abstract);
 
         let r#type = if r#abstract {
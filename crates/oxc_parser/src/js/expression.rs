@@ -203,12 +203,13 @@ impl<'a> ParserImpl<'a> {
         let span = self.start_span();
         self.bump_any(); // `bump` `(`
         let expr_span = self.start_span();
+        let parse_element: fn(&mut Self) -> Expression<'a> = if self.source_type.is_flow() {
+            Self::parse_flow_type_cast
+        } else {
+            Self::parse_assignment_expression_or_higher
+        };
         let (mut expressions, comma_span) = self.context(Context::In, Context::Decorator, |p| {
-            p.parse_delimited_list(
-                Kind::RParen,
-                Kind::Comma,
-                Self::parse_assignment_expression_or_higher,
-            )
+            p.parse_delimited_list(Kind::RParen, Kind::Comma, parse_element)
         });
 
         if let Some(comma_span) = comma_span {
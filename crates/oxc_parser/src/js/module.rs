@@ -125,6 +125,15 @@ impl<'a> ParserImpl<'a> {
                     }
                 }
             }
+        } else if self.source_type.is_flow() && token_after_import.kind() == Kind::Typeof {
+            // Flow `import typeof ...`
+            self.bump_any();
+            import_kind = ImportOrExportKind::Type;
+            if self.cur_kind().is_binding_identifier() {
+                // `import typeof something ...`
+                identifier_after_import = Some(self.parse_binding_identifier());
+                has_default_specifier = true;
+            }
         } else if token_after_import.kind() == Kind::Defer && self.at(Kind::Star) {
             // `import defer * ...`
             phase = Some(ImportPhase::Defer);
@@ -718,7 +727,10 @@ impl<'a> ParserImpl<'a> {
         let mut property_name: Option<ModuleExportName<'a>> = None;
         let mut name = self.parse_module_export_name();
 
-        if self.is_ts && name.is_identifier() && type_or_name_token_kind == Kind::Type {
+        // Flow also allows `typeof` modifiers: `import { typeof something } from "mod"`
+        let is_type_modifier = type_or_name_token_kind == Kind::Type
+            || (self.source_type.is_flow() && type_or_name_token_kind == Kind::Typeof);
+        if self.is_ts && name.is_identifier() && is_type_modifier {
             // If the first token of an import/export specifier is 'type', there are a lot of possibilities,
             // especially if we see 'as' afterwards:
             //
@@ -41,7 +41,12 @@ impl<'a> ParserImpl<'a> {
             if !is_top_level && self.at(Kind::RCurly) {
                 break;
             }
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            let Some(stmt) = self.parse_with_recovery(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            }) else {
+                expecting_directives = false;
+                continue;
+            };
 
             if is_top_level {
                 if let Some(module_decl) = stmt.as_module_declaration() {
@@ -140,6 +145,9 @@ impl<'a> ParserImpl<'a> {
             {
                 self.parse_ts_declaration_statement(self.start_span())
             }
+            Kind::Ident if self.at_flow_opaque_type() => {
+                self.parse_ts_declaration_statement(self.start_span())
+            }
             _ => self.parse_expression_or_labeled_statement(),
         };
 
@@ -207,8 +215,11 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::LCurly);
         let mut body = self.ast.vec();
         while !self.at(Kind::RCurly) && !self.has_fatal_error() {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
-            body.push(stmt);
+            if let Some(stmt) = self.parse_with_recovery(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            }) {
+                body.push(stmt);
+            }
         }
         self.expect(Kind::RCurly);
         self.ast.alloc_block_statement(self.end_span(span), body)
@@ -621,8 +632,11 @@ impl<'a> ParserImpl<'a> {
         while !matches!(self.cur_kind(), Kind::Case | Kind::Default | Kind::RCurly)
             && !self.has_fatal_error()
         {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
-            consequent.push(stmt);
+            if let Some(stmt) = self.parse_with_recovery(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            }) {
+                consequent.push(stmt);
+            }
         }
         Some(self.ast.switch_case(self.end_span(span), test, consequent))
     }
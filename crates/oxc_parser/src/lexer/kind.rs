@@ -8,6 +8,7 @@ use std::fmt::{self, Display};
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 #[repr(u8)]
 #[non_exhaustive]
+#[allow(missing_docs, clippy::allow_attributes)]
 pub enum Kind {
     #[default]
     Eof = 0,
@@ -200,6 +201,7 @@ pub enum Kind {
 #[allow(clippy::enum_glob_use, clippy::allow_attributes)]
 use Kind::*;
 
+#[allow(missing_docs, clippy::allow_attributes)]
 impl Kind {
     #[inline]
     pub fn is_eof(self) -> bool {
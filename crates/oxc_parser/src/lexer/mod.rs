@@ -187,6 +187,16 @@ impl<'a> Lexer<'a> {
         self.source.advance_to_end();
     }
 
+    /// Continue lexing from `offset`, which must be the end of a token
+    /// (other than `=` or `(`) in a source text which was lexed before.
+    ///
+    /// Used for incremental parsing, to lex only the part of source text which changed.
+    pub fn resume_at(&mut self, offset: u32) {
+        self.source.set_offset(offset);
+        self.token = Token::default();
+        self.trivia_builder.resume_after_token();
+    }
+
     // ---------- Private Methods ---------- //
     fn error(&mut self, error: OxcDiagnostic) {
         self.errors.push(error);
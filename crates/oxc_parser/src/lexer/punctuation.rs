@@ -58,8 +58,10 @@ impl Lexer<'_> {
         }
     }
 
+    /// Re-lex the current `>` token, combining it with any following `>` or `=`.
     pub(crate) fn re_lex_right_angle(&mut self) -> Token {
-        self.token.set_start(self.offset());
+        // `>` has already been consumed
+        self.token.set_start(self.offset() - 1);
         let kind = self.read_right_angle();
         self.finish_next(kind)
     }
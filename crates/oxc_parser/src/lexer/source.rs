@@ -196,6 +196,18 @@ impl<'a> Source<'a> {
         self.ptr = self.end;
     }
 
+    /// Move current position to `offset` bytes from start of source.
+    ///
+    /// # Panic
+    /// Panics if `offset` is out of bounds, or not on a UTF-8 character boundary.
+    #[inline]
+    pub(super) fn set_offset(&mut self, offset: u32) {
+        let offset = offset as usize;
+        assert!(self.whole().is_char_boundary(offset));
+        // SAFETY: Checked above that `offset` is in bounds of source text, and on a UTF-8 char boundary
+        self.ptr = unsafe { self.start.add(offset) };
+    }
+
     /// Advance `Source`'s cursor by one byte if it is equal to the given ASCII value.
     ///
     /// # SAFETY
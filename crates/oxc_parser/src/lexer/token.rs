@@ -40,6 +40,9 @@ const _: () = {
     assert!(is_valid_shift(HAS_SEPARATOR_SHIFT));
 };
 
+/// A token produced by the lexer.
+///
+/// Stores kind, span and flags, packed into a single `u128`.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Token(u128);
@@ -86,11 +89,13 @@ impl Token {
 
 // Getters and setters
 impl Token {
+    /// Span of token in source text.
     #[inline]
     pub fn span(&self) -> Span {
         Span::new(self.start(), self.end())
     }
 
+    /// Start offset of token in source text (UTF-8 bytes).
     #[inline]
     pub fn start(&self) -> u32 {
         ((self.0 >> START_SHIFT) & START_MASK) as u32
@@ -102,6 +107,7 @@ impl Token {
         self.0 |= u128::from(start) << START_SHIFT;
     }
 
+    /// End offset of token in source text (UTF-8 bytes).
     #[inline]
     pub fn end(&self) -> u32 {
         ((self.0 >> END_SHIFT) & END_MASK) as u32
@@ -115,6 +121,7 @@ impl Token {
         self.0 |= u128::from(end) << END_SHIFT;
     }
 
+    /// Kind of token.
     #[inline]
     pub fn kind(&self) -> Kind {
         // SAFETY: `Kind` is `#[repr(u8)]`. Only `Token::default` and `Token::set_kind` set these bits,
@@ -129,6 +136,7 @@ impl Token {
         self.0 |= u128::from(kind as u8) << KIND_SHIFT;
     }
 
+    /// `true` if there is a line break between previous token and this one.
     #[inline]
     pub fn is_on_new_line(&self) -> bool {
         // Use a pointer read rather than arithmetic as it produces less instructions.
@@ -143,6 +151,7 @@ impl Token {
         self.0 |= u128::from(value) << IS_ON_NEW_LINE_SHIFT;
     }
 
+    /// `true` if token contains escape sequences (identifiers, strings and templates).
     #[inline]
     pub fn escaped(&self) -> bool {
         // Use a pointer read rather than arithmetic as it produces less instructions.
@@ -157,6 +166,7 @@ impl Token {
         self.0 |= u128::from(escaped) << ESCAPED_SHIFT;
     }
 
+    /// `true` if string token contains lone surrogates.
     #[inline]
     pub fn lone_surrogates(&self) -> bool {
         // Use a pointer read rather than arithmetic as it produces less instructions.
@@ -171,6 +181,7 @@ impl Token {
         self.0 |= u128::from(value) << LONE_SURROGATES_SHIFT;
     }
 
+    /// `true` if numeric token contains `_` separators.
     #[inline]
     pub fn has_separator(&self) -> bool {
         // Use a pointer read rather than arithmetic as it produces less instructions.
@@ -91,6 +91,14 @@ impl TriviaBuilder {
         self.saw_newline = false;
     }
 
+    /// Set state as if a token was just lexed, so comments are attributed correctly
+    /// when lexing continues from end of a token. See `Lexer::resume_at`.
+    pub fn resume_after_token(&mut self) {
+        self.processed = self.comments.len();
+        self.saw_newline = false;
+        self.previous_kind = Kind::Undetermined;
+    }
+
     /// Determines if the current line comment should be treated as a trailing comment.
     ///
     /// A line comment should be treated as trailing when both of the following conditions are met:
@@ -69,10 +69,12 @@
 mod context;
 mod cursor;
 mod error_handler;
+mod incremental;
 mod modifiers;
 mod module_record;
 mod state;
 
+mod flow;
 mod js;
 mod jsx;
 mod ts;
@@ -86,6 +88,9 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
+pub use incremental::TextEdit;
+pub use lexer::{Kind, Token};
+
 use oxc_allocator::{Allocator, Box as ArenaBox, Dummy};
 use oxc_ast::{
     AstBuilder,
@@ -98,7 +103,7 @@ use oxc_syntax::module_record::ModuleRecord;
 use crate::{
     context::{Context, StatementContext},
     error_handler::FatalError,
-    lexer::{Lexer, Token},
+    lexer::Lexer,
     module_record::ModuleRecordBuilder,
     state::ParserState,
 };
@@ -180,6 +185,13 @@ pub struct ParserReturn<'a> {
 
     /// Whether the file is [flow](https://flow.org).
     pub is_flow_language: bool,
+
+    /// All tokens in source text, in source order, excluding comments and hashbang.
+    ///
+    /// Only populated when [`ParseOptions::collect_tokens`] is `true`.
+    /// Tokens which the parser re-lexed (e.g. `/` as the start of a regular expression,
+    /// `}` as the start of a template continuation) are stored in their final form.
+    pub tokens: Vec<Token>,
 }
 
 /// Parse options
@@ -221,6 +233,22 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Recover from syntax errors which would otherwise terminate parsing.
+    ///
+    /// When enabled, the parser inserts missing semicolons, and tokens missing at end of file.
+    /// Statements and class elements which cannot be parsed are skipped, up to the next
+    /// statement / class member boundary, and parsing continues. All errors are still reported
+    /// in [`ParserReturn::errors`], but [`ParserReturn::program`] contains the rest of the file,
+    /// so semantic analysis and linting can still run on it e.g. in an editor.
+    ///
+    /// Default: `false`
+    pub recover_from_errors: bool,
+
+    /// Retain all tokens produced by the lexer in [`ParserReturn::tokens`].
+    ///
+    /// Default: `false`
+    pub collect_tokens: bool,
 }
 
 impl Default for ParseOptions {
@@ -231,6 +259,8 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            recover_from_errors: false,
+            collect_tokens: false,
         }
     }
 }
@@ -318,6 +348,51 @@ mod parser_parse {
             parser.parse()
         }
 
+        /// Parse source text after an edit, reusing unaffected top-level statements of the
+        /// `previous` parse of source text before the edit.
+        ///
+        /// `previous` must have been parsed with the same allocator, source type and options.
+        /// Spans of statements which follow the edit are moved to their new positions, and only
+        /// statements which the edit affects are parsed again. Falls back to a full parse when
+        /// reusing statements is not possible (e.g. previous or new source has syntax errors).
+        ///
+        /// The returned [`ParserReturn`] is the same as [`Parser::parse`] would return.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{Parser, TextEdit};
+        /// use oxc_span::{SourceType, Span};
+        ///
+        /// let allocator = Allocator::new();
+        /// let source_type = SourceType::mjs();
+        /// let ret = Parser::new(&allocator, "let a = 1;\nlet b = 2;\nlet c = 3;", source_type).parse();
+        ///
+        /// // Replace `2` with `20`
+        /// let edit = TextEdit::new(Span::new(19, 20), "20");
+        /// let source_text = "let a = 1;\nlet b = 20;\nlet c = 3;";
+        /// let ret = Parser::new(&allocator, source_text, source_type).parse_incremental(ret, edit);
+        /// assert_eq!(ret.program.body.len(), 3);
+        /// ```
+        pub fn parse_incremental(
+            self,
+            previous: ParserReturn<'a>,
+            edit: TextEdit<'_>,
+        ) -> ParserReturn<'a> {
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                UniquePromise::new(),
+            );
+            if let Some(ret) = parser.parse_incremental(previous, edit) {
+                return ret;
+            }
+            self.parse()
+        }
+
         /// Parse a single [`Expression`].
         ///
         /// # Example
@@ -369,7 +444,7 @@ struct ParserImpl<'a> {
     /// Note: favor adding to `Diagnostics` instead of raising Err
     errors: Vec<OxcDiagnostic>,
 
-    fatal_error: Option<FatalError>,
+    fatal_error: Option<FatalError<'a>>,
 
     /// The current parsing token
     token: Token,
@@ -391,6 +466,9 @@ struct ParserImpl<'a> {
 
     /// Precomputed typescript detection
     is_ts: bool,
+
+    /// Tokens consumed so far. Only populated if `options.collect_tokens` is `true`.
+    tokens: Vec<Token>,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -419,7 +497,8 @@ impl<'a> ParserImpl<'a> {
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
             module_record_builder: ModuleRecordBuilder::new(allocator),
-            is_ts: source_type.is_typescript(),
+            is_ts: source_type.is_typescript() || source_type.is_flow(),
+            tokens: vec![],
         }
     }
 
@@ -469,8 +548,8 @@ impl<'a> ParserImpl<'a> {
             errors.reserve(self.lexer.errors.len() + self.errors.len());
             errors.extend(self.lexer.errors);
             errors.extend(self.errors);
-            // Skip checking for exports in TypeScript {
-            if !self.source_type.is_typescript() {
+            // Skip checking for exports in TypeScript and Flow {
+            if !self.is_ts {
                 errors.extend(module_record_errors);
             }
         }
@@ -493,6 +572,7 @@ impl<'a> ParserImpl<'a> {
             irregular_whitespaces,
             panicked,
             is_flow_language,
+            tokens: self.tokens,
         }
     }
 
@@ -635,6 +715,38 @@ mod test {
         }
     }
 
+    #[test]
+    fn flow_language() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::flow();
+        let sources = [
+            "// @flow\nimport type { A } from './a'; import typeof B, { C } from './b';",
+            "import { type D, typeof E, f } from './c';",
+            "type T<+U, -V: string = 'x'> = { +a: U, -b: ?V, ...A, [key: string]: number, ... };",
+            "type E = {| a: number, b?: string | number |}; type F = {||}; type G = {| |};",
+            "opaque type O: string = string; declare opaque type P; export opaque type Q = O;",
+            "declare export function f(x: number): string; declare export class K { m(): void }",
+            "function h<T: Object>(a: ?T, b?: number = 1): Array<T> { return [a]; }",
+            "const arrow = (a: number): number => a;",
+            "class C<+T> extends B<T> { +p: T; static -q: number = 1; }",
+            "const el = <div>{(x: any)}</div>;",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+            assert!(!ret.is_flow_language);
+        }
+
+        let source = "opaque type O = string; ((x: any): string);";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(matches!(ret.program.body[0], Statement::TSTypeAliasDeclaration(_)));
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[1] else { unreachable!() };
+        let Expression::TSAsExpression(expr) = stmt.expression.without_parentheses() else {
+            unreachable!()
+        };
+        assert!(matches!(expr.expression.without_parentheses(), Expression::TSAsExpression(_)));
+    }
+
     #[test]
     fn ts_module_declaration() {
         let allocator = Allocator::default();
@@ -756,6 +868,112 @@ mod test {
         }
     }
 
+    #[test]
+    fn tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts();
+        let opts = ParseOptions { collect_tokens: true, ..ParseOptions::default() };
+
+        let source = "#!/usr/bin/env node\nlet x = /a/g; // c\n`a${b}c`;";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        let tokens = ret
+            .tokens
+            .iter()
+            .map(|token| (token.kind(), token.span().source_text(source)))
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (Kind::Let, "let"),
+                (Kind::Ident, "x"),
+                (Kind::Eq, "="),
+                (Kind::RegExp, "/a/g"),
+                (Kind::Semicolon, ";"),
+                (Kind::TemplateHead, "`a${"),
+                (Kind::Ident, "b"),
+                (Kind::TemplateTail, "}c`"),
+                (Kind::Semicolon, ";"),
+            ]
+        );
+
+        // Tokens from speculative parsing which was rewound are discarded
+        let source = "f<T>(x); (a): b => a; x >>= 1;";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        let tokens =
+            ret.tokens.iter().map(|token| token.span().source_text(source)).collect::<String>();
+        assert_eq!(tokens, source.chars().filter(|&ch| ch != ' ').collect::<String>());
+        assert!(ret.tokens.windows(2).all(|pair| pair[0].end() <= pair[1].start()));
+
+        let source = "<a b-c='d'>e{f}</a>";
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        let tokens = ret
+            .tokens
+            .iter()
+            .map(|token| token.span().source_text(source))
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(
+            tokens,
+            ["<", "a", "b-c", "=", "'d'", ">", "e", "{", "f", "}", "<", "/", "a", ">"]
+        );
+
+        // Not collected by default
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.tokens.is_empty());
+    }
+
+    #[test]
+    fn recover_from_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let opts = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+
+        let cases = [
+            // Skip to synchronization point
+            ("let x = ; let y = 1;", 1, vec!["let y = 1;"]),
+            ("let x = {\n  a: \n};\nfoo();", 1, vec!["foo();"]),
+            ("if (a) { b( }\nfoo();", 1, vec!["if (a) { b( }", "foo();"]),
+            ("`${a(}`; b;", 1, vec!["b;"]),
+            ("}\na;", 1, vec!["a;"]),
+            // Insert missing tokens
+            ("let x = 1 let y = 2", 1, vec!["let x = 1", "let y = 2"]),
+            ("if (a) { b c }", 1, vec!["if (a) { b c }"]),
+            ("function f() { a(", 2, vec!["function f() { a("]),
+            ("class A { foo() {} bar( baz() {} }", 1, vec!["class A { foo() {} bar( baz() {} }"]),
+        ];
+        for (source, errors, statements) in cases {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), errors, "{source}");
+            let body = ret
+                .program
+                .body
+                .iter()
+                .map(|stmt| stmt.span().source_text(source))
+                .collect::<std::vec::Vec<_>>();
+            assert_eq!(body, statements, "{source}");
+        }
+
+        // Recovered class body keeps valid elements
+        let source = "class A { foo() {} bar = ; baz() {} }";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        let Statement::ClassDeclaration(class) = &ret.program.body[0] else { unreachable!() };
+        let elements = class
+            .body
+            .body
+            .iter()
+            .map(|element| element.span().source_text(source))
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(elements, ["foo() {}", "baz() {}"]);
+
+        // Not enabled by default
+        let ret = Parser::new(&allocator, "let x = ; let y = 1;", source_type).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.is_empty());
+    }
+
     #[test]
     fn memory_leak() {
         let allocator = Allocator::default();
@@ -445,6 +445,8 @@ impl<'a> ParserImpl<'a> {
     fn can_follow_modifier(&self) -> bool {
         match self.cur_kind() {
             Kind::PrivateIdentifier | Kind::LBrack | Kind::LCurly | Kind::Star | Kind::Dot3 => true,
+            // Flow variance: `static +x: T`
+            Kind::Plus | Kind::Minus => self.source_type.is_flow(),
             kind => kind.is_identifier_or_keyword(),
         }
     }
@@ -398,9 +398,16 @@ impl<'a> ParserImpl<'a> {
             .ctx
             .union_ambient_if(modifiers.contains_declare())
             .and_await(modifiers.contains_async());
-        let decl = self.parse_declaration(start_span, &modifiers, self.ast.vec());
+        let stmt = if self.source_type.is_flow()
+            && modifiers.contains_declare()
+            && self.at(Kind::Export)
+        {
+            self.parse_flow_declare_export(start_span, &modifiers, self.ast.vec())
+        } else {
+            Statement::from(self.parse_declaration(start_span, &modifiers, self.ast.vec()))
+        };
         self.ctx = reserved_ctx;
-        Statement::from(decl)
+        stmt
     }
 
     pub(crate) fn parse_declaration(
@@ -421,6 +428,9 @@ impl<'a> ParserImpl<'a> {
                 Declaration::TSModuleDeclaration(decl)
             }
             Kind::Type => self.parse_ts_type_alias_declaration(start_span, modifiers),
+            Kind::Ident if self.at_flow_opaque_type() => {
+                self.parse_flow_opaque_type_alias(start_span, modifiers)
+            }
             Kind::Enum => self.parse_ts_enum_declaration(start_span, modifiers),
             Kind::Interface => {
                 self.bump_any();
@@ -573,6 +583,7 @@ impl<'a> ParserImpl<'a> {
                     return self.cur_kind().is_binding_identifier()
                         && !self.cur_token().is_on_new_line();
                 }
+                Kind::Ident if self.at_flow_opaque_type() => return true,
                 Kind::Module | Kind::Namespace => {
                     self.bump_any();
                     return !self.cur_token().is_on_new_line()
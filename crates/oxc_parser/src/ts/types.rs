@@ -188,6 +188,7 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type_parameter(&mut self) -> TSTypeParameter<'a> {
         let span = self.start_span();
 
+        self.eat_flow_variance();
         let modifiers = self.parse_modifiers(true, false);
         self.verify_modifiers(
             &modifiers,
@@ -233,9 +234,10 @@ impl<'a> ParserImpl<'a> {
         let has_leading_operator = self.eat(kind);
         /* hasLeadingOperator && parseFunctionOrConstructorTypeToError(isUnionType) ||*/
         let mut ty = parse_constituent_type(self);
-        if self.at(kind) || has_leading_operator {
+        if self.at_type_operator(kind) || has_leading_operator {
             let mut types = self.ast.vec1(ty);
-            while self.eat(kind) {
+            while self.at_type_operator(kind) {
+                self.bump_any();
                 types.push(
                     /*parseFunctionOrConstructorTypeToError(isUnionType) || */
                     parse_constituent_type(self),
@@ -251,6 +253,11 @@ impl<'a> ParserImpl<'a> {
         ty
     }
 
+    fn at_type_operator(&mut self, kind: Kind) -> bool {
+        // `|` in `{| a: T |}` closes a Flow exact object type.
+        self.at(kind) && !(kind == Kind::Pipe && self.at_flow_exact_object_end())
+    }
+
     fn parse_type_operator_or_higher(&mut self) -> TSType<'a> {
         match self.cur_kind() {
             Kind::KeyOf => self.parse_type_operator(TSTypeOperatorOperator::Keyof),
@@ -651,6 +658,9 @@ impl<'a> ParserImpl<'a> {
     }
 
     fn parse_type_literal(&mut self) -> TSType<'a> {
+        if self.source_type.is_flow() {
+            return self.parse_flow_object_type();
+        }
         let span = self.start_span();
         let member_list = self.parse_normal_list(Kind::LCurly, Kind::RCurly, |p| {
             Some(Self::parse_ts_type_signature(p))
@@ -696,7 +706,8 @@ impl<'a> ParserImpl<'a> {
     }
 
     fn parse_ts_type_constraint(&mut self) -> Option<TSType<'a>> {
-        if !self.at(Kind::Extends) {
+        // Flow bounds `<T: Bound>` are represented as constraints.
+        if !(self.at(Kind::Extends) || (self.source_type.is_flow() && self.at(Kind::Colon))) {
             return None;
         }
         self.bump_any();
@@ -0,0 +1,203 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/generators/relocate.rs`.
+
+#![allow(clippy::match_same_arms)]
+
+use oxc_allocator::{MalformedImage, Relocate, Relocator};
+
+use crate::ast::*;
+
+impl Relocate for Pattern<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Disjunction<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Alternative<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Term<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::BoundaryAssertion(it) => Relocate::relocate(it, relocator),
+                Self::LookAroundAssertion(it) => Relocate::relocate(it, relocator),
+                Self::Quantifier(it) => Relocate::relocate(it, relocator),
+                Self::Character(it) => Relocate::relocate(it, relocator),
+                Self::CharacterClassEscape(it) => Relocate::relocate(it, relocator),
+                Self::UnicodePropertyEscape(it) => Relocate::relocate(it, relocator),
+                Self::CharacterClass(it) => Relocate::relocate(it, relocator),
+                Self::CapturingGroup(it) => Relocate::relocate(it, relocator),
+                Self::IgnoreGroup(it) => Relocate::relocate(it, relocator),
+                Self::IndexedReference(it) => Relocate::relocate(it, relocator),
+                Self::NamedReference(it) => Relocate::relocate(it, relocator),
+                Self::Dot(_) => Ok(()),
+            }
+        }
+    }
+}
+
+impl Relocate for BoundaryAssertion {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for LookAroundAssertion<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Quantifier<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for Character {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for CharacterClassEscape {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for UnicodePropertyEscape<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Relocate::relocate(&mut self.value, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for CharacterClass<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for CharacterClassContents<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            match self {
+                Self::CharacterClassRange(it) => Relocate::relocate(it, relocator),
+                Self::CharacterClassEscape(it) => Relocate::relocate(it, relocator),
+                Self::UnicodePropertyEscape(it) => Relocate::relocate(it, relocator),
+                Self::Character(it) => Relocate::relocate(it, relocator),
+                Self::NestedCharacterClass(it) => Relocate::relocate(it, relocator),
+                Self::ClassStringDisjunction(it) => Relocate::relocate(it, relocator),
+            }
+        }
+    }
+}
+
+impl Relocate for CharacterClassRange {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for ClassStringDisjunction<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for ClassString<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for CapturingGroup<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for IgnoreGroup<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.body, relocator)?;
+            Ok(())
+        }
+    }
+}
+
+impl Relocate for IndexedReference {
+    #[inline]
+    unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        Ok(())
+    }
+}
+
+impl Relocate for NamedReference<'_> {
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe {
+            Relocate::relocate(&mut self.name, relocator)?;
+            Ok(())
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 mod ast_impl;
 mod diagnostics;
+mod matcher;
 mod options;
 mod parser;
 mod surrogate_pair;
@@ -11,11 +12,13 @@ mod generated {
     pub mod assert_layouts;
     mod derive_clone_in;
     mod derive_content_eq;
+    mod relocate;
 }
 
 pub mod ast;
 pub use crate::{
     ast_impl::visit,
+    matcher::{Match, MatchError, MatchOptions, Matcher, unicode_property_ranges},
     options::Options,
     parser::{ConstructorParser, LiteralParser},
 };
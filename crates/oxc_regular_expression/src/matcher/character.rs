@@ -0,0 +1,286 @@
+//! Character predicates of the matcher, including case-insensitive comparison.
+//!
+//! Case-insensitive matching uses the `Canonicalize` operation of the specification: simple case
+//! folding with the `u` and `v` flags, and upper-casing otherwise.
+//! <https://tc39.es/ecma262/#sec-runtime-semantics-canonicalize-ch>
+
+use regex_syntax::{
+    ParserBuilder,
+    hir::{Class, ClassUnicode, ClassUnicodeRange, HirKind},
+};
+
+use crate::ast::{
+    CharacterClass, CharacterClassContents, CharacterClassContentsKind, CharacterClassEscapeKind,
+    UnicodePropertyEscape,
+};
+
+use super::Matcher;
+
+/// Code point ranges matched by `\p{name}` or `\p{name=value}`, in ascending order.
+///
+/// Returns `None` for properties which are unknown to the bundled Unicode data, e.g. properties
+/// added in a newer Unicode version.
+pub fn unicode_property_ranges(name: &str, value: Option<&str>) -> Option<Vec<(u32, u32)>> {
+    let pattern = match value {
+        Some(value) => format!(r"\p{{{name}={value}}}"),
+        None => format!(r"\p{{{name}}}"),
+    };
+    let hir = ParserBuilder::new().build().parse(&pattern).ok()?;
+    match hir.kind() {
+        HirKind::Class(Class::Unicode(class)) => Some(
+            class.ranges().iter().map(|range| (range.start() as u32, range.end() as u32)).collect(),
+        ),
+        // A property with a single code point is simplified to a literal
+        HirKind::Literal(literal) => {
+            let ch = std::str::from_utf8(&literal.0).ok()?.chars().next()? as u32;
+            Some(vec![(ch, ch)])
+        }
+        _ => None,
+    }
+}
+
+pub fn is_line_terminator(ch: u32) -> bool {
+    matches!(ch, 0x0A | 0x0D | 0x2028 | 0x2029)
+}
+
+fn is_white_space(ch: u32) -> bool {
+    matches!(ch, 0x09 | 0x0B | 0x0C | 0x20 | 0xA0 | 0x1680 | 0x202F | 0x205F | 0x3000 | 0xFEFF)
+        || (0x2000..=0x200A).contains(&ch)
+}
+
+fn is_basic_word_character(ch: u32) -> bool {
+    char::from_u32(ch).is_some_and(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+/// `Canonicalize` without the `u` and `v` flags.
+fn legacy_canonicalize(ch: u32) -> u32 {
+    let Some(c) = char::from_u32(ch) else { return ch };
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        // Do not map non-ASCII characters to ASCII characters, e.g. `ſ` to `S`
+        (Some(upper), None) if ch < 128 || upper as u32 >= 128 => upper as u32,
+        _ => ch,
+    }
+}
+
+impl Matcher<'_, '_> {
+    /// Other characters which `Canonicalize` to the same character as `ch`.
+    fn case_variants(&self, ch: u32) -> Vec<u32> {
+        let Some(c) = char::from_u32(ch) else { return vec![] };
+        let mut class = ClassUnicode::new([ClassUnicodeRange::new(c, c)]);
+        class.case_fold_simple();
+        let variants = class
+            .iter()
+            .flat_map(|range| range.start() as u32..=range.end() as u32)
+            .filter(|&variant| variant != ch);
+        if self.is_unicode() {
+            variants.collect()
+        } else {
+            let canonical = legacy_canonicalize(ch);
+            variants
+                .filter(|&variant| variant <= 0xFFFF && legacy_canonicalize(variant) == canonical)
+                .collect()
+        }
+    }
+
+    pub fn chars_equal(&self, a: u32, b: u32, ignore_case: bool) -> bool {
+        if a == b || !ignore_case {
+            return a == b;
+        }
+        if self.is_unicode() {
+            self.case_variants(a).contains(&b)
+        } else {
+            legacy_canonicalize(a) == legacy_canonicalize(b)
+        }
+    }
+
+    /// Whether `ch`, or a character which is equal to it when ignoring case, is in `set`.
+    fn contains_ignoring_case(
+        &self,
+        ch: u32,
+        ignore_case: bool,
+        set: impl Fn(u32) -> bool,
+    ) -> bool {
+        set(ch) || (ignore_case && self.case_variants(ch).into_iter().any(set))
+    }
+
+    /// Whether `ch` is in the complement of `set`, like `\P{...}` or `\W`.
+    ///
+    /// Without the `v` flag, the complement is taken before case-insensitive comparison, so
+    /// `/\P{Ll}/iu` matches `a` because it matches `A`.
+    fn complement_contains(&self, ch: u32, ignore_case: bool, set: impl Fn(u32) -> bool) -> bool {
+        if ignore_case && !self.options.unicode_sets {
+            !set(ch) || self.case_variants(ch).into_iter().any(|variant| !set(variant))
+        } else {
+            !self.contains_ignoring_case(ch, ignore_case, set)
+        }
+    }
+
+    /// `IsWordChar`, which includes `ſ` and `K` (Kelvin sign) when ignoring case with the `u`
+    /// or `v` flag, because they canonicalize to `s` and `k`.
+    pub fn is_word_character(&self, ch: u32, ignore_case: bool) -> bool {
+        is_basic_word_character(ch)
+            || (ignore_case && self.is_unicode() && matches!(ch, 0x017F | 0x212A))
+    }
+
+    pub fn class_escape_contains(
+        &self,
+        kind: CharacterClassEscapeKind,
+        ch: u32,
+        ignore_case: bool,
+    ) -> bool {
+        match kind {
+            CharacterClassEscapeKind::D => char::from_u32(ch).is_some_and(|ch| ch.is_ascii_digit()),
+            CharacterClassEscapeKind::NegativeD => {
+                !char::from_u32(ch).is_some_and(|ch| ch.is_ascii_digit())
+            }
+            CharacterClassEscapeKind::S => is_white_space(ch) || is_line_terminator(ch),
+            CharacterClassEscapeKind::NegativeS => !is_white_space(ch) && !is_line_terminator(ch),
+            CharacterClassEscapeKind::W => self.contains_ignoring_case(ch, ignore_case, |ch| {
+                self.is_word_character(ch, ignore_case)
+            }),
+            CharacterClassEscapeKind::NegativeW => {
+                self.complement_contains(ch, ignore_case, |ch| {
+                    self.is_word_character(ch, ignore_case)
+                })
+            }
+        }
+    }
+
+    pub fn property_contains(
+        &self,
+        escape: &UnicodePropertyEscape,
+        ch: u32,
+        ignore_case: bool,
+    ) -> bool {
+        let ranges = self.property_ranges(escape);
+        let set = |ch: u32| {
+            ranges
+                .binary_search_by(|&(min, max)| {
+                    if max < ch {
+                        std::cmp::Ordering::Less
+                    } else if min > ch {
+                        std::cmp::Ordering::Greater
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                })
+                .is_ok()
+        };
+        if escape.negative {
+            self.complement_contains(ch, ignore_case, set)
+        } else {
+            self.contains_ignoring_case(ch, ignore_case, set)
+        }
+    }
+
+    /// Whether the single character `ch` is in `class`.
+    pub fn class_contains(&self, class: &CharacterClass, ch: u32, ignore_case: bool) -> bool {
+        let mut contents =
+            class.body.iter().map(|item| self.class_item_contains(item, ch, ignore_case));
+        let found = match class.kind {
+            CharacterClassContentsKind::Union => contents.any(|found| found),
+            CharacterClassContentsKind::Intersection => contents.all(|found| found),
+            CharacterClassContentsKind::Subtraction => {
+                contents.next().unwrap_or(false) && !contents.any(|found| found)
+            }
+        };
+        found != class.negative
+    }
+
+    fn class_item_contains(
+        &self,
+        item: &CharacterClassContents,
+        ch: u32,
+        ignore_case: bool,
+    ) -> bool {
+        match item {
+            CharacterClassContents::Character(character) => {
+                self.chars_equal(character.value, ch, ignore_case)
+            }
+            CharacterClassContents::CharacterClassRange(range) => {
+                self.contains_ignoring_case(ch, ignore_case, |ch| {
+                    (range.min.value..=range.max.value).contains(&ch)
+                })
+            }
+            CharacterClassContents::CharacterClassEscape(escape) => {
+                self.class_escape_contains(escape.kind, ch, ignore_case)
+            }
+            CharacterClassContents::UnicodePropertyEscape(escape) => {
+                self.property_contains(escape, ch, ignore_case)
+            }
+            CharacterClassContents::NestedCharacterClass(class) => {
+                self.class_contains(class, ch, ignore_case)
+            }
+            CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                disjunction.body.iter().any(|string| match string.body.as_slice() {
+                    [character] => self.chars_equal(character.value, ch, ignore_case),
+                    _ => false,
+                })
+            }
+        }
+    }
+
+    /// Strings in `class` which are not a single character, including the empty string.
+    pub fn class_strings(&self, class: &CharacterClass, ignore_case: bool) -> Vec<Vec<u32>> {
+        if !class.strings {
+            return vec![];
+        }
+        let Some((first, rest)) = class.body.split_first() else { return vec![] };
+        let mut strings = match class.kind {
+            CharacterClassContentsKind::Union => class
+                .body
+                .iter()
+                .flat_map(|item| self.class_item_strings(item, ignore_case))
+                .collect(),
+            CharacterClassContentsKind::Intersection => {
+                let mut strings = self.class_item_strings(first, ignore_case);
+                strings.retain(|string| {
+                    rest.iter().all(|item| self.class_item_has_string(item, string, ignore_case))
+                });
+                strings
+            }
+            CharacterClassContentsKind::Subtraction => {
+                let mut strings = self.class_item_strings(first, ignore_case);
+                strings.retain(|string| {
+                    !rest.iter().any(|item| self.class_item_has_string(item, string, ignore_case))
+                });
+                strings
+            }
+        };
+        strings.sort_unstable();
+        strings.dedup();
+        strings
+    }
+
+    fn class_item_strings(
+        &self,
+        item: &CharacterClassContents,
+        ignore_case: bool,
+    ) -> Vec<Vec<u32>> {
+        match item {
+            CharacterClassContents::ClassStringDisjunction(disjunction) => disjunction
+                .body
+                .iter()
+                .filter(|string| string.body.len() != 1)
+                .map(|string| string.body.iter().map(|character| character.value).collect())
+                .collect(),
+            CharacterClassContents::NestedCharacterClass(class) => {
+                self.class_strings(class, ignore_case)
+            }
+            _ => vec![],
+        }
+    }
+
+    fn class_item_has_string(
+        &self,
+        item: &CharacterClassContents,
+        string: &[u32],
+        ignore_case: bool,
+    ) -> bool {
+        self.class_item_strings(item, ignore_case).iter().any(|other| {
+            other.len() == string.len()
+                && other.iter().zip(string).all(|(&a, &b)| self.chars_equal(a, b, ignore_case))
+        })
+    }
+}
@@ -0,0 +1,429 @@
+//! Backtracking execution, following the continuation-passing structure of the
+//! [pattern semantics](https://tc39.es/ecma262/#sec-pattern-semantics).
+//!
+//! Every matcher receives a continuation which matches the rest of the pattern, and returns
+//! `Ok(true)` only if the whole pattern matched. Captures are mutated in place, and restored
+//! whenever a path fails.
+
+use std::{cmp::Reverse, ops::Range};
+
+use crate::{
+    ast::{
+        BoundaryAssertionKind, CharacterClass, Disjunction, LookAroundAssertion,
+        LookAroundAssertionKind, Modifier, Modifiers, Quantifier, Term,
+    },
+    surrogate_pair,
+};
+
+use super::{MatchError, Matcher, character::is_line_terminator};
+
+/// Maximum number of steps for one `exec`, to stop catastrophic backtracking.
+const STEP_LIMIT: usize = 100_000;
+
+/// Maximum nesting of continuations, to stop long inputs from overflowing the stack.
+const DEPTH_LIMIT: usize = 1_000;
+
+type MatchResult = Result<bool, MatchError>;
+
+type MatchCaptures = (Range<usize>, Vec<Option<Range<usize>>>);
+
+type Continuation<'c, 'e, 'a> = dyn FnMut(&mut Exec<'e, 'a>, usize) -> MatchResult + 'c;
+
+/// Flags which can change within the pattern, and the direction of matching.
+#[derive(Clone, Copy)]
+struct Mode {
+    ignore_case: bool,
+    multiline: bool,
+    dot_all: bool,
+    /// `false` inside of lookbehind assertions, which match from right to left.
+    forward: bool,
+}
+
+impl Mode {
+    fn with_modifiers(mut self, modifiers: &Modifiers) -> Self {
+        let apply = |enabled: bool, modifier: Modifier| {
+            (enabled || modifiers.enabling.contains(modifier))
+                && !modifiers.disabling.contains(modifier)
+        };
+        self.ignore_case = apply(self.ignore_case, Modifier::I);
+        self.multiline = apply(self.multiline, Modifier::M);
+        self.dot_all = apply(self.dot_all, Modifier::S);
+        self
+    }
+}
+
+pub struct Exec<'e, 'a> {
+    matcher: &'e Matcher<'e, 'a>,
+    input: &'e [u16],
+    /// Start and end of the whole match and each capturing group.
+    captures: Vec<Option<(usize, usize)>>,
+    steps: usize,
+    depth: usize,
+}
+
+impl<'e, 'a> Exec<'e, 'a> {
+    pub fn new(matcher: &'e Matcher<'e, 'a>, input: &'e [u16]) -> Self {
+        let captures = vec![None; matcher.capture_count + 1];
+        Self { matcher, input, captures, steps: 0, depth: 0 }
+    }
+
+    /// Match the pattern starting exactly at `start`, and return the range of the match and
+    /// the captures.
+    pub fn match_at(&mut self, start: usize) -> Result<Option<MatchCaptures>, MatchError> {
+        self.captures.fill(None);
+        let options = self.matcher.options;
+        let mode = Mode {
+            ignore_case: options.ignore_case,
+            multiline: options.multiline,
+            dot_all: options.dot_all,
+            forward: true,
+        };
+        let body = &self.matcher.pattern.body;
+        let mut match_end = start;
+        let matched = self.match_disjunction(body, start, mode, &mut |this: &mut Self, end| {
+            this.captures[0] = Some((start, end));
+            match_end = end;
+            Ok(true)
+        })?;
+        Ok(matched.then(|| {
+            let captures =
+                self.captures.iter().map(|capture| capture.map(|(start, end)| start..end));
+            (start..match_end, captures.collect())
+        }))
+    }
+
+    fn enter(&mut self) -> Result<(), MatchError> {
+        self.steps += 1;
+        self.depth += 1;
+        if self.steps > STEP_LIMIT || self.depth > DEPTH_LIMIT {
+            return Err(MatchError::LimitExceeded);
+        }
+        Ok(())
+    }
+
+    fn match_disjunction(
+        &mut self,
+        disjunction: &'e Disjunction<'a>,
+        pos: usize,
+        mode: Mode,
+        k: &mut Continuation<'_, 'e, 'a>,
+    ) -> MatchResult {
+        for alternative in &disjunction.body {
+            if self.match_terms(&alternative.body, pos, mode, k)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Match the terms of an alternative, from the last term inside of lookbehind assertions.
+    fn match_terms(
+        &mut self,
+        terms: &'e [Term<'a>],
+        pos: usize,
+        mode: Mode,
+        k: &mut Continuation<'_, 'e, 'a>,
+    ) -> MatchResult {
+        let split = if mode.forward { terms.split_first() } else { terms.split_last() };
+        let Some((term, rest)) = split else { return k(self, pos) };
+        self.enter()?;
+        let matched = self.match_term(term, pos, mode, &mut |this: &mut Self, pos| {
+            this.match_terms(rest, pos, mode, k)
+        })?;
+        self.depth -= 1;
+        Ok(matched)
+    }
+
+    fn match_term(
+        &mut self,
+        term: &'e Term<'a>,
+        pos: usize,
+        mode: Mode,
+        k: &mut Continuation<'_, 'e, 'a>,
+    ) -> MatchResult {
+        let ignore_case = mode.ignore_case;
+        match term {
+            Term::BoundaryAssertion(assertion) => {
+                if self.is_assertion_satisfied(assertion.kind, pos, mode) {
+                    k(self, pos)
+                } else {
+                    Ok(false)
+                }
+            }
+            Term::LookAroundAssertion(assertion) => self.match_look_around(assertion, pos, mode, k),
+            Term::Quantifier(quantifier) => {
+                self.match_repeat(quantifier, quantifier.min, quantifier.max, pos, mode, k)
+            }
+            Term::Character(character) => self.match_character(pos, mode, k, |matcher, ch| {
+                matcher.chars_equal(character.value, ch, ignore_case)
+            }),
+            Term::Dot(_) => {
+                self.match_character(pos, mode, k, |_, ch| mode.dot_all || !is_line_terminator(ch))
+            }
+            Term::CharacterClassEscape(escape) => {
+                self.match_character(pos, mode, k, |matcher, ch| {
+                    matcher.class_escape_contains(escape.kind, ch, ignore_case)
+                })
+            }
+            Term::UnicodePropertyEscape(escape) => {
+                self.match_character(pos, mode, k, |matcher, ch| {
+                    matcher.property_contains(escape, ch, ignore_case)
+                })
+            }
+            Term::CharacterClass(class) if class.strings => {
+                self.match_class_with_strings(class, pos, mode, k)
+            }
+            Term::CharacterClass(class) => self.match_character(pos, mode, k, |matcher, ch| {
+                matcher.class_contains(class, ch, ignore_case)
+            }),
+            Term::CapturingGroup(group) => {
+                let index = self.matcher.group_index(group);
+                self.match_disjunction(&group.body, pos, mode, &mut |this: &mut Self, end| {
+                    let previous = this.captures[index];
+                    this.captures[index] = Some(if mode.forward { (pos, end) } else { (end, pos) });
+                    if k(this, end)? {
+                        return Ok(true);
+                    }
+                    this.captures[index] = previous;
+                    Ok(false)
+                })
+            }
+            Term::IgnoreGroup(group) => {
+                let inner = group.modifiers.as_ref().map_or(mode, |m| mode.with_modifiers(m));
+                self.match_disjunction(&group.body, pos, inner, k)
+            }
+            Term::IndexedReference(reference) => {
+                self.match_backreference(&[reference.index as usize], pos, mode, k)
+            }
+            Term::NamedReference(reference) => {
+                let indices = self.matcher.named_group_indices(&reference.name);
+                self.match_backreference(indices, pos, mode, k)
+            }
+        }
+    }
+
+    /// Read the character after `pos`, or before `pos` when matching backward, and return it
+    /// with the position on its other side.
+    fn read(&self, pos: usize, forward: bool) -> Option<(u32, usize)> {
+        let input = self.input;
+        let unicode = self.matcher.is_unicode();
+        if forward {
+            let unit = u32::from(*input.get(pos)?);
+            if unicode && surrogate_pair::is_lead_surrogate(unit) {
+                if let Some(&trail) = input.get(pos + 1) {
+                    let trail = u32::from(trail);
+                    if surrogate_pair::is_trail_surrogate(trail) {
+                        return Some((
+                            surrogate_pair::combine_surrogate_pair(unit, trail),
+                            pos + 2,
+                        ));
+                    }
+                }
+            }
+            Some((unit, pos + 1))
+        } else {
+            let unit = u32::from(*input.get(pos.checked_sub(1)?)?);
+            if unicode && surrogate_pair::is_trail_surrogate(unit) && pos >= 2 {
+                let lead = u32::from(input[pos - 2]);
+                if surrogate_pair::is_lead_surrogate(lead) {
+                    return Some((surrogate_pair::combine_surrogate_pair(lead, unit), pos - 2));
+                }
+            }
+            Some((unit, pos - 1))
+        }
+    }
+
+    fn match_character(
+        &mut self,
+        pos: usize,
+        mode: Mode,
+        k: &mut Continuation<'_, 'e, 'a>,
+        predicate: impl FnOnce(&Matcher, u32) -> bool,
+    ) -> MatchResult {
+        match self.read(pos, mode.forward) {
+            Some((ch, next)) if predicate(self.matcher, ch) => k(self, next),
+            _ => Ok(false),
+        }
+    }
+
+    fn is_assertion_satisfied(&self, kind: BoundaryAssertionKind, pos: usize, mode: Mode) -> bool {
+        let input = self.input;
+        match kind {
+            BoundaryAssertionKind::Start => {
+                pos == 0 || (mode.multiline && is_line_terminator(input[pos - 1].into()))
+            }
+            BoundaryAssertionKind::End => {
+                pos == input.len() || (mode.multiline && is_line_terminator(input[pos].into()))
+            }
+            BoundaryAssertionKind::Boundary | BoundaryAssertionKind::NegativeBoundary => {
+                let is_word = |index: usize| {
+                    self.matcher.is_word_character(input[index].into(), mode.ignore_case)
+                };
+                let before = pos > 0 && is_word(pos - 1);
+                let after = pos < input.len() && is_word(pos);
+                (before != after) == (kind == BoundaryAssertionKind::Boundary)
+            }
+        }
+    }
+
+    fn match_look_around(
+        &mut self,
+        assertion: &'e LookAroundAssertion<'a>,
+        pos: usize,
+        mode: Mode,
+        k: &mut Continuation<'_, 'e, 'a>,
+    ) -> MatchResult {
+        let (forward, negative) = match assertion.kind {
+            LookAroundAssertionKind::Lookahead => (true, false),
+            LookAroundAssertionKind::NegativeLookahead => (true, true),
+            LookAroundAssertionKind::Lookbehind => (false, false),
+            LookAroundAssertionKind::NegativeLookbehind => (false, true),
+        };
+        let saved = self.captures.clone();
+        // Assertions are atomic: once the body matched, there is no backtracking into it.
+        let matched = self.match_disjunction(
+            &assertion.body,
+            pos,
+            Mode { forward, ..mode },
+            &mut |_: &mut Self, _| Ok(true),
+        )?;
+        if negative {
+            self.captures = saved;
+            return if matched { Ok(false) } else { k(self, pos) };
+        }
+        if matched && k(self, pos)? {
+            return Ok(true);
+        }
+        self.captures = saved;
+        Ok(false)
+    }
+
+    /// `RepeatMatcher`, which matches `quantifier.body` at least `min` and at most `max` more
+    /// times.
+    fn match_repeat(
+        &mut self,
+        quantifier: &'e Quantifier<'a>,
+        min: u64,
+        max: Option<u64>,
+        pos: usize,
+        mode: Mode,
+        k: &mut Continuation<'_, 'e, 'a>,
+    ) -> MatchResult {
+        if max == Some(0) {
+            return k(self, pos);
+        }
+        if min == 0 && !quantifier.greedy && k(self, pos)? {
+            return Ok(true);
+        }
+        self.enter()?;
+        // Captures inside of the quantifier are reset for every iteration.
+        let captures = self.matcher.quantifier_captures(quantifier);
+        let saved =
+            self.captures[captures.clone()].iter_mut().map(Option::take).collect::<Vec<_>>();
+        let matched =
+            self.match_term(&quantifier.body, pos, mode, &mut |this: &mut Self, end| {
+                // An iteration which matches the empty string cannot satisfy the remaining optional
+                // iterations, otherwise `(?:)*` would loop forever.
+                if min == 0 && end == pos {
+                    return Ok(false);
+                }
+                let max = max.map(|max| max - 1);
+                this.match_repeat(quantifier, min.saturating_sub(1), max, end, mode, k)
+            })?;
+        self.depth -= 1;
+        if matched {
+            return Ok(true);
+        }
+        self.captures[captures].copy_from_slice(&saved);
+        if min == 0 && quantifier.greedy { k(self, pos) } else { Ok(false) }
+    }
+
+    fn match_backreference(
+        &mut self,
+        indices: &[usize],
+        pos: usize,
+        mode: Mode,
+        k: &mut Continuation<'_, 'e, 'a>,
+    ) -> MatchResult {
+        // A reference to a group which did not participate matches the empty string.
+        let Some((start, end)) =
+            indices.iter().find_map(|&index| self.captures.get(index).copied().flatten())
+        else {
+            return k(self, pos);
+        };
+        let len = end - start;
+        let target = if mode.forward {
+            pos..pos + len
+        } else {
+            let Some(target_start) = pos.checked_sub(len) else { return Ok(false) };
+            target_start..pos
+        };
+        if target.end > self.input.len()
+            || !self.units_equal(start..end, target.clone(), mode.ignore_case)
+        {
+            return Ok(false);
+        }
+        k(self, if mode.forward { target.end } else { target.start })
+    }
+
+    /// Compare two ranges of the input with the same length, character by character.
+    fn units_equal(&self, a: Range<usize>, b: Range<usize>, ignore_case: bool) -> bool {
+        if !ignore_case {
+            return self.input[a] == self.input[b];
+        }
+        let (mut a, b_end, mut b) = (a.start, b.end, b.start);
+        while b < b_end {
+            let (Some((ch_a, next_a)), Some((ch_b, next_b))) =
+                (self.read(a, true), self.read(b, true))
+            else {
+                return false;
+            };
+            if !self.matcher.chars_equal(ch_a, ch_b, true) {
+                return false;
+            }
+            (a, b) = (next_a, next_b);
+        }
+        b == b_end
+    }
+
+    /// Match a class of the `v` flag which can contain strings, trying the longest strings
+    /// first, then single characters, then the empty string.
+    fn match_class_with_strings(
+        &mut self,
+        class: &'e CharacterClass<'a>,
+        pos: usize,
+        mode: Mode,
+        k: &mut Continuation<'_, 'e, 'a>,
+    ) -> MatchResult {
+        let mut strings = self.matcher.class_strings(class, mode.ignore_case);
+        strings.sort_by_key(|string| Reverse(string.len()));
+        for string in strings.iter().filter(|string| string.len() > 1) {
+            if let Some(end) = self.match_string(string, pos, mode) {
+                if k(self, end)? {
+                    return Ok(true);
+                }
+            }
+        }
+        let ignore_case = mode.ignore_case;
+        if self.match_character(pos, mode, k, |matcher, ch| {
+            matcher.class_contains(class, ch, ignore_case)
+        })? {
+            return Ok(true);
+        }
+        if strings.iter().any(Vec::is_empty) { k(self, pos) } else { Ok(false) }
+    }
+
+    /// Match the code points of `string` at `pos`, and return the position on the other side.
+    fn match_string(&self, string: &[u32], mut pos: usize, mode: Mode) -> Option<usize> {
+        let mut match_next = |expected: u32| {
+            let (ch, next) = self.read(pos, mode.forward)?;
+            self.matcher.chars_equal(expected, ch, mode.ignore_case).then(|| pos = next)
+        };
+        if mode.forward {
+            string.iter().try_for_each(|&ch| match_next(ch))?;
+        } else {
+            string.iter().rev().try_for_each(|&ch| match_next(ch))?;
+        }
+        Some(pos)
+    }
+}
@@ -0,0 +1,554 @@
+//! Backtracking matcher which executes a [`Pattern`] against a string, following the semantics
+//! of [RegExpBuiltinExec](https://tc39.es/ecma262/#sec-regexpbuiltinexec).
+//!
+//! Strings are matched as UTF-16 code units, and all indices are UTF-16 code unit offsets, so
+//! results can be used as JavaScript's `lastIndex` and match indices directly.
+
+mod character;
+mod exec;
+
+use std::{fmt, ops::Range};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    ast::{CapturingGroup, Pattern, Quantifier, UnicodePropertyEscape},
+    surrogate_pair,
+    visit::{RegExpAstKind, Visit},
+};
+
+pub use character::unicode_property_ranges;
+use exec::Exec;
+
+/// Flags which change how a pattern is matched.
+///
+/// The `d` flag has no equivalent, because match indices are always available from [`Match`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MatchOptions {
+    /// `g` flag
+    pub global: bool,
+    /// `i` flag
+    pub ignore_case: bool,
+    /// `m` flag
+    pub multiline: bool,
+    /// `s` flag
+    pub dot_all: bool,
+    /// `u` flag
+    pub unicode: bool,
+    /// `v` flag
+    pub unicode_sets: bool,
+    /// `y` flag
+    pub sticky: bool,
+}
+
+impl MatchOptions {
+    /// Create options from flags text like `"gi"`.
+    ///
+    /// Returns `None` if `flags` contains an unknown flag.
+    pub fn from_flags(flags: &str) -> Option<Self> {
+        let mut options = Self::default();
+        for flag in flags.chars() {
+            match flag {
+                'd' => {}
+                'g' => options.global = true,
+                'i' => options.ignore_case = true,
+                'm' => options.multiline = true,
+                's' => options.dot_all = true,
+                'u' => options.unicode = true,
+                'v' => options.unicode_sets = true,
+                'y' => options.sticky = true,
+                _ => return None,
+            }
+        }
+        Some(options)
+    }
+}
+
+/// Reasons why a pattern cannot be executed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchError {
+    /// The pattern uses a property of strings, or a Unicode property which is not known to the
+    /// bundled Unicode data.
+    UnsupportedProperty,
+    /// Matching took too many steps, e.g. because of catastrophic backtracking.
+    LimitExceeded,
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedProperty => f.write_str("unsupported Unicode property"),
+            Self::LimitExceeded => f.write_str("backtracking limit exceeded"),
+        }
+    }
+}
+
+impl std::error::Error for MatchError {}
+
+/// The result of a successful match.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match<'m> {
+    range: Range<usize>,
+    captures: Vec<Option<Range<usize>>>,
+    names: &'m [(&'m str, Vec<usize>)],
+}
+
+impl<'m> Match<'m> {
+    /// Range of the whole match.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    pub fn start(&self) -> usize {
+        self.range.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.range.end
+    }
+
+    /// Range of the capturing group with `index`, where index `0` is the whole match.
+    ///
+    /// Returns `None` if the group did not participate in the match.
+    pub fn get(&self, index: usize) -> Option<Range<usize>> {
+        self.captures.get(index).cloned().flatten()
+    }
+
+    /// Ranges of the whole match and all capturing groups, in the order of their opening
+    /// parentheses.
+    pub fn captures(&self) -> &[Option<Range<usize>>] {
+        &self.captures
+    }
+
+    /// Range of the capturing group named `name`.
+    ///
+    /// With duplicate named groups, this is the group which participated in the match.
+    pub fn name(&self, name: &str) -> Option<Range<usize>> {
+        let (_, indices) = self.names.iter().find(|(group_name, _)| *group_name == name)?;
+        indices.iter().find_map(|&index| self.get(index))
+    }
+
+    /// Names of the capturing groups, in the order of their first occurrence.
+    pub fn group_names(&self) -> impl Iterator<Item = &'m str> {
+        self.names.iter().map(|(name, _)| *name)
+    }
+}
+
+/// Executes a parsed [`Pattern`].
+///
+/// ```ignore
+/// let matcher = Matcher::new(&pattern, MatchOptions::from_flags("i").unwrap())?;
+/// let input = "a-B".encode_utf16().collect::<Vec<_>>();
+/// let found = matcher.exec(&input, 0)?.map(|m| m.range());
+/// ```
+pub struct Matcher<'p, 'a> {
+    pattern: &'p Pattern<'a>,
+    options: MatchOptions,
+    capture_count: usize,
+    /// Capture index of each capturing group, keyed by the address of the group.
+    group_indices: FxHashMap<usize, usize>,
+    /// Capture indices of the groups inside of each quantifier, keyed by the address of the
+    /// quantifier.
+    quantifier_captures: FxHashMap<usize, Range<usize>>,
+    /// Capture indices of each group name.
+    names: Vec<(&'a str, Vec<usize>)>,
+    /// Code point ranges of each Unicode property escape, keyed by the address of the escape.
+    properties: FxHashMap<usize, Vec<(u32, u32)>>,
+}
+
+impl<'p, 'a> Matcher<'p, 'a> {
+    /// Prepare `pattern` for matching.
+    ///
+    /// `pattern` must have been parsed with the same `u` and `v` flags as in `options`.
+    pub fn new(pattern: &'p Pattern<'a>, options: MatchOptions) -> Result<Self, MatchError> {
+        let mut analyzer = Analyzer::default();
+        analyzer.visit_pattern(pattern);
+        if let Some(error) = analyzer.error {
+            return Err(error);
+        }
+        Ok(Self {
+            pattern,
+            options,
+            capture_count: analyzer.capture_count,
+            group_indices: analyzer.group_indices,
+            quantifier_captures: analyzer.quantifier_captures,
+            names: analyzer.names,
+            properties: analyzer.properties,
+        })
+    }
+
+    /// Number of capturing groups in the pattern.
+    pub fn capture_count(&self) -> usize {
+        self.capture_count
+    }
+
+    /// Whether the pattern contains named capturing groups.
+    pub fn has_named_groups(&self) -> bool {
+        !self.names.is_empty()
+    }
+
+    /// Search `input` for a match, starting at `last_index`.
+    ///
+    /// Like `RegExpBuiltinExec`, `last_index` is ignored unless the `g` or `y` flag is set, and
+    /// with the `y` flag only a match starting exactly at `last_index` is found.
+    pub fn exec(&self, input: &[u16], last_index: usize) -> Result<Option<Match<'_>>, MatchError> {
+        let mut last_index =
+            if self.options.global || self.options.sticky { last_index } else { 0 };
+        let mut exec = Exec::new(self, input);
+        while last_index <= input.len() {
+            if let Some((range, captures)) = exec.match_at(last_index)? {
+                return Ok(Some(Match { range, captures, names: &self.names }));
+            }
+            if self.options.sticky {
+                break;
+            }
+            last_index = self.advance_index(input, last_index);
+        }
+        Ok(None)
+    }
+
+    /// Whether `input` contains a match, like `RegExp.prototype.test` with `lastIndex` at `0`.
+    pub fn is_match(&self, input: &str) -> Result<bool, MatchError> {
+        let input = input.encode_utf16().collect::<Vec<_>>();
+        Ok(self.exec(&input, 0)?.is_some())
+    }
+
+    /// The index after the character at `index`, which skips a whole surrogate pair with the
+    /// `u` and `v` flags.
+    ///
+    /// <https://tc39.es/ecma262/#sec-advancestringindex>
+    pub fn advance_index(&self, input: &[u16], index: usize) -> usize {
+        let is_pair = self.is_unicode()
+            && input.get(index).is_some_and(|&unit| surrogate_pair::is_lead_surrogate(unit.into()))
+            && input
+                .get(index + 1)
+                .is_some_and(|&unit| surrogate_pair::is_trail_surrogate(unit.into()));
+        index + if is_pair { 2 } else { 1 }
+    }
+
+    fn is_unicode(&self) -> bool {
+        self.options.unicode || self.options.unicode_sets
+    }
+
+    fn group_index(&self, group: &CapturingGroup) -> usize {
+        self.group_indices[&address(group)]
+    }
+
+    fn quantifier_captures(&self, quantifier: &Quantifier) -> Range<usize> {
+        self.quantifier_captures[&address(quantifier)].clone()
+    }
+
+    fn named_group_indices(&self, name: &str) -> &[usize] {
+        self.names
+            .iter()
+            .find(|(group_name, _)| *group_name == name)
+            .map_or(&[], |(_, indices)| indices.as_slice())
+    }
+
+    fn property_ranges(&self, escape: &UnicodePropertyEscape) -> &[(u32, u32)] {
+        &self.properties[&address(escape)]
+    }
+}
+
+fn address<T>(node: &T) -> usize {
+    std::ptr::from_ref(node) as usize
+}
+
+/// Numbers capturing groups and resolves Unicode properties before matching.
+#[derive(Default)]
+struct Analyzer<'a> {
+    capture_count: usize,
+    group_indices: FxHashMap<usize, usize>,
+    quantifier_captures: FxHashMap<usize, Range<usize>>,
+    /// Quantifiers which are being visited, with the capture count when they were entered.
+    quantifiers: Vec<usize>,
+    names: Vec<(&'a str, Vec<usize>)>,
+    properties: FxHashMap<usize, Vec<(u32, u32)>>,
+    error: Option<MatchError>,
+}
+
+impl<'a> Visit<'a> for Analyzer<'a> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        match kind {
+            RegExpAstKind::CapturingGroup(group) => {
+                self.capture_count += 1;
+                self.group_indices.insert(address(group), self.capture_count);
+                if let Some(name) = &group.name {
+                    let name = name.as_str();
+                    match self.names.iter_mut().find(|(group_name, _)| *group_name == name) {
+                        Some((_, indices)) => indices.push(self.capture_count),
+                        None => self.names.push((name, vec![self.capture_count])),
+                    }
+                }
+            }
+            RegExpAstKind::Quantifier(_) => self.quantifiers.push(self.capture_count),
+            RegExpAstKind::UnicodePropertyEscape(escape) => {
+                let ranges = if escape.strings {
+                    None
+                } else {
+                    unicode_property_ranges(&escape.name, escape.value.as_deref())
+                };
+                match ranges {
+                    Some(ranges) => {
+                        self.properties.insert(address(escape), ranges);
+                    }
+                    None => self.error = Some(MatchError::UnsupportedProperty),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn leave_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::Quantifier(quantifier) = kind {
+            let first_index = self.quantifiers.pop().unwrap() + 1;
+            let next_index = self.capture_count + 1;
+            self.quantifier_captures.insert(address(quantifier), first_index..next_index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use crate::{LiteralParser, Options};
+
+    use super::{MatchError, MatchOptions, Matcher};
+
+    type Captures = Vec<Option<(usize, usize)>>;
+
+    /// Ranges of the whole match and all groups of `/pattern/flags` in `input`, or `None`
+    /// for groups which did not participate.
+    fn exec(pattern: &str, flags: &str, input: &str) -> Option<Captures> {
+        exec_at(pattern, flags, input, 0).unwrap()
+    }
+
+    fn exec_at(
+        pattern_text: &str,
+        flags: &str,
+        input: &str,
+        last_index: usize,
+    ) -> Result<Option<Captures>, MatchError> {
+        let allocator = Allocator::default();
+        let pattern = LiteralParser::new(&allocator, pattern_text, Some(flags), Options::default())
+            .parse()
+            .unwrap_or_else(|err| panic!("Failed to parse /{pattern_text}/{flags}: {err}"));
+        let matcher = Matcher::new(&pattern, MatchOptions::from_flags(flags).unwrap())?;
+        let input = input.encode_utf16().collect::<Vec<_>>();
+        Ok(matcher.exec(&input, last_index)?.map(|found| {
+            found.captures().iter().map(|range| range.clone().map(|r| (r.start, r.end))).collect()
+        }))
+    }
+
+    /// The matched text of `/pattern/flags` in `input`.
+    fn find(pattern: &str, flags: &str, input: &str) -> Option<String> {
+        let captures = exec(pattern, flags, input)?;
+        let (start, end) = captures[0].unwrap();
+        let units = input.encode_utf16().collect::<Vec<_>>();
+        Some(String::from_utf16_lossy(&units[start..end]))
+    }
+
+    #[test]
+    fn characters() {
+        for (pattern, flags, input, expected) in [
+            ("abc", "", "xxabcxx", Some("abc")),
+            ("abd", "", "abc", None),
+            ("a.c", "", "a\nc abc", Some("abc")),
+            ("a.c", "s", "a\nc", Some("a\nc")),
+            (r"\d+", "", "ab123c", Some("123")),
+            (r"\D\S\W", "", "a b!", Some(" b!")),
+            (r"\s+", "", "a \t\u{3000}\u{FEFF}b", Some(" \t\u{3000}\u{FEFF}")),
+            ("[a-c]+", "", "xbcaz", Some("bca")),
+            ("[^a-c]+", "", "abxyc", Some("xy")),
+            (r"[\d-]+", "", "a1-2b", Some("1-2")),
+            ("ABC", "i", "xabc", Some("abc")),
+            ("[a-z]+", "i", "12AbC", Some("AbC")),
+            ("[^a-z]+", "i", "AbC12", Some("12")),
+            ("\u{017F}", "i", "s", None),
+            ("\u{017F}", "iu", "S", Some("S")),
+            (r"\w", "iu", "\u{017F}", Some("\u{017F}")),
+            (r"\w", "i", "\u{017F}", None),
+            (r"\W", "iu", "\u{017F}", None),
+            ("\u{00DF}", "i", "\u{1E9E}", None),
+            ("\u{00DF}", "iu", "\u{1E9E}", Some("\u{1E9E}")),
+            (".", "", "😀", Some("\u{FFFD}")),
+            (".", "u", "😀", Some("😀")),
+            ("^.$", "", "😀", None),
+            ("^.$", "u", "😀", Some("😀")),
+            ("[😀]", "u", "x😀", Some("😀")),
+            (r"\u{1F600}", "u", "😀", Some("😀")),
+            (r"\uD83D", "u", "😀", None),
+            (r"\uD83D", "", "😀", Some("\u{FFFD}")),
+        ] {
+            assert_eq!(
+                find(pattern, flags, input).as_deref(),
+                expected,
+                "/{pattern}/{flags} on {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn assertions() {
+        for (pattern, flags, input, expected) in [
+            ("^b", "", "a\nb", None),
+            ("^b", "m", "a\nb", Some("b")),
+            ("a$", "", "a\nb", None),
+            ("a$", "m", "a\nb", Some("a")),
+            (r"\bb\w*", "", "ab bc", Some("bc")),
+            (r"\Bb\w*", "", "ab bc", Some("b")),
+            ("a(?=b)", "", "acab", Some("a")),
+            ("a(?!b)", "", "abac", Some("a")),
+            (r"(?<=\$)\d+", "", "x1 $42", Some("42")),
+            (r"(?<!\$)\b\d+", "", "$42 17", Some("17")),
+            ("(?<=(a+))b", "", "aaab", Some("b")),
+            ("(?i:a)b", "", "Ab AB", Some("Ab")),
+            ("(?-i:a)b", "i", "AB aB", Some("aB")),
+            ("(?s:.).", "", "\n\nx", Some("\nx")),
+            ("(?m:^)b", "", "a\nb", Some("b")),
+        ] {
+            assert_eq!(
+                find(pattern, flags, input).as_deref(),
+                expected,
+                "/{pattern}/{flags} on {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn quantifiers() {
+        for (pattern, flags, input, expected) in [
+            ("a*", "", "aaa", Some("aaa")),
+            ("a*?", "", "aaa", Some("")),
+            ("a+?", "", "aaa", Some("a")),
+            ("a{2}", "", "aaa", Some("aa")),
+            ("a{2,}", "", "aaaa", Some("aaaa")),
+            ("a{1,2}?b", "", "aab", Some("aab")),
+            ("a{3}", "", "aa", None),
+            ("(?:a|ab)c", "", "abc", Some("abc")),
+            ("(?:a|b)*c", "", "ababd ac", Some("ac")),
+            ("(?:)*a", "", "a", Some("a")),
+            ("(?:a*)*b", "", "aab", Some("aab")),
+            ("(a*)+", "", "b", Some("")),
+        ] {
+            assert_eq!(
+                find(pattern, flags, input).as_deref(),
+                expected,
+                "/{pattern}/{flags} on {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn captures() {
+        assert_eq!(exec("(a)|(b)", "", "b"), Some(vec![Some((0, 1)), None, Some((0, 1))]));
+        // Captures are reset for every iteration of a quantifier
+        assert_eq!(
+            exec("(?:(a)|b)+", "", "ab"),
+            Some(vec![Some((0, 2)), None]),
+            "/(?:(a)|b)+/ on ab"
+        );
+        assert_eq!(exec("(z)((a+)?(b+)?(c))*", "", "zaacbbbcac").unwrap()[4], None);
+        assert_eq!(exec(r"(a)\1", "", "aa a"), Some(vec![Some((0, 2)), Some((0, 1))]));
+        assert_eq!(exec(r"(a)\1", "i", "aA"), Some(vec![Some((0, 2)), Some((0, 1))]));
+        assert_eq!(exec(r"\1(a)", "", "a"), Some(vec![Some((0, 1)), Some((0, 1))]));
+        assert_eq!(exec(r"(?<=\1(a))b", "", "aab"), Some(vec![Some((2, 3)), Some((1, 2))]));
+        assert_eq!(exec(r"(?<a>x)\k<a>", "", "xx"), Some(vec![Some((0, 2)), Some((0, 1))]));
+        assert_eq!(
+            exec(r"(?:(?<a>x)|(?<a>y))\k<a>", "", "yy"),
+            Some(vec![Some((0, 2)), None, Some((0, 1))])
+        );
+        assert_eq!(exec("(?=(a+))a*b\\1", "", "baaabac"), Some(vec![Some((3, 6)), Some((3, 4))]));
+    }
+
+    #[test]
+    fn named_groups() {
+        let allocator = Allocator::default();
+        let pattern = LiteralParser::new(
+            &allocator,
+            r"(?<year>\d{4})-(?<month>\d{2})|(?<month>\d{2})/(?<year>\d{4})",
+            Some(""),
+            Options::default(),
+        )
+        .parse()
+        .unwrap();
+        let matcher = Matcher::new(&pattern, MatchOptions::default()).unwrap();
+        assert!(matcher.has_named_groups());
+        assert_eq!(matcher.capture_count(), 4);
+        let input = "on 12/2024".encode_utf16().collect::<Vec<_>>();
+        let found = matcher.exec(&input, 0).unwrap().unwrap();
+        assert_eq!(found.range(), 3..10);
+        assert_eq!(found.name("month"), Some(3..5));
+        assert_eq!(found.name("year"), Some(6..10));
+        assert_eq!(found.name("day"), None);
+        assert_eq!(found.group_names().collect::<Vec<_>>(), ["year", "month"]);
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(exec_at("a", "", "aba", 1).unwrap().unwrap()[0], Some((0, 1)));
+        assert_eq!(exec_at("a", "g", "aba", 1).unwrap().unwrap()[0], Some((2, 3)));
+        assert_eq!(exec_at("a", "g", "aba", 4).unwrap(), None);
+        assert_eq!(exec_at("a", "y", "aba", 1).unwrap(), None);
+        assert_eq!(exec_at("b", "y", "aba", 1).unwrap().unwrap()[0], Some((1, 2)));
+        // Without `u`, the search can start in the middle of a surrogate pair
+        assert_eq!(exec_at("", "g", "😀", 1).unwrap().unwrap()[0], Some((1, 1)));
+    }
+
+    #[test]
+    fn unicode_properties() {
+        for (pattern, flags, input, expected) in [
+            (r"\p{L}+", "u", "12äbç3", Some("äbç")),
+            (r"\P{L}+", "u", "ab12c", Some("12")),
+            (r"\p{Script=Greek}+", "u", "abαβγ", Some("αβγ")),
+            (r"\p{Lu}", "u", "aB", Some("B")),
+            (r"\p{Lu}", "iu", "aB", Some("a")),
+            (r"\P{Ll}", "iu", "a", Some("a")),
+            (r"\P{Ll}", "iv", "a", None),
+            (r"[\p{ASCII}--\p{L}]+", "v", "ab12c", Some("12")),
+            (r"[\p{L}&&\p{ASCII}]+", "v", "äab", Some("ab")),
+            (r"\p{Emoji_Presentation}", "u", "a😀", Some("😀")),
+        ] {
+            assert_eq!(
+                find(pattern, flags, input).as_deref(),
+                expected,
+                "/{pattern}/{flags} on {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn class_strings() {
+        for (pattern, flags, input, expected) in [
+            (r"[\q{abc|a}]", "v", "abc", Some("abc")),
+            (r"[\q{abc|a}]c", "v", "abc", None),
+            (r"[\q{abc|a}]bc", "v", "abc", Some("abc")),
+            (r"[\q{ab|c}--\q{ab}]+", "v", "abcc", Some("cc")),
+            (r"[\q{ab|cd}&&\q{AB}]", "vi", "cdab", Some("ab")),
+            (r"[\q{}x]", "v", "y", Some("")),
+            (r"(?<=[\q{ab}])c", "v", "abc", Some("c")),
+        ] {
+            assert_eq!(
+                find(pattern, flags, input).as_deref(),
+                expected,
+                "/{pattern}/{flags} on {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(exec_at(r"\p{RGI_Emoji}", "v", "😀", 0), Err(MatchError::UnsupportedProperty));
+        assert_eq!(
+            exec_at("(a+)+b", "", &"a".repeat(40), 0),
+            Err(MatchError::LimitExceeded),
+            "catastrophic backtracking"
+        );
+        assert_eq!(
+            exec_at("a*b", "", &"a".repeat(100_000), 0),
+            Err(MatchError::LimitExceeded),
+            "deep recursion"
+        );
+    }
+}
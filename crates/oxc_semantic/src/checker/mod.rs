@@ -109,7 +109,10 @@ pub fn check<'a>(kind: AstKind<'a>, ctx: &SemanticBuilder<'a>) {
                 js::check_variable_declarator_redeclaration(decl, ctx);
             }
         }
-        AstKind::TSTypeAnnotation(annot) => ts::check_ts_type_annotation(annot, ctx),
+        // Flow maybe types (`?T`) are represented as JSDoc nullable types.
+        AstKind::TSTypeAnnotation(annot) if !ctx.source_type.is_flow() => {
+            ts::check_ts_type_annotation(annot, ctx);
+        }
         AstKind::TSInterfaceDeclaration(decl) => ts::check_ts_interface_declaration(decl, ctx),
         AstKind::TSTypeParameter(param) => ts::check_ts_type_parameter(param, ctx),
         AstKind::TSModuleDeclaration(decl) => ts::check_ts_module_declaration(decl, ctx),
@@ -7,7 +7,7 @@ use oxc_index::{Idx, IndexVec};
 use oxc_span::{Atom, Span};
 use oxc_syntax::{
     node::NodeId,
-    reference::{Reference, ReferenceId},
+    reference::{Reference, ReferenceFlags, ReferenceId},
     scope::{ScopeFlags, ScopeId},
     symbol::{SymbolFlags, SymbolId},
 };
@@ -896,3 +896,369 @@ impl Scoping {
         }
     }
 }
+
+/// Magic bytes at start of a [`Scoping`] snapshot.
+const SNAPSHOT_MAGIC: [u8; 8] = *b"OXCSCOPE";
+
+/// Version of [`Scoping`] snapshot format.
+///
+/// Must be incremented whenever the format changes.
+const SNAPSHOT_VERSION: u32 = 1;
+
+// Snapshots
+impl Scoping {
+    /// Serialize this [`Scoping`] into a binary snapshot, appended to `out`.
+    ///
+    /// The snapshot can be loaded with [`Scoping::read_snapshot`]. Together with a snapshot of the AST
+    /// (see [`oxc_ast::snapshot`]), this allows caching the results of parsing and semantic analysis.
+    ///
+    /// Semantic IDs in the AST are preserved by AST snapshots, so they remain valid for the loaded
+    /// [`Scoping`].
+    pub fn write_snapshot(&self, out: &mut Vec<u8>) {
+        let cell = self.cell.borrow_dependent();
+        let mut writer = SnapshotWriter(out);
+        writer.bytes(&SNAPSHOT_MAGIC);
+        writer.u32(SNAPSHOT_VERSION);
+
+        // Symbols
+        writer.len(self.symbols_len());
+        for symbol_id in self.symbol_ids() {
+            writer.span(self.symbol_spans[symbol_id]);
+            writer.u32(self.symbol_flags[symbol_id].bits());
+            writer.id(self.symbol_scope_ids[symbol_id]);
+            writer.id(self.symbol_declarations[symbol_id]);
+            writer.str(&cell.symbol_names[symbol_id.index()]);
+            writer.ids(&cell.resolved_references[symbol_id.index()]);
+        }
+
+        let mut redeclarations = cell.symbol_redeclarations.iter().collect::<Vec<_>>();
+        redeclarations.sort_unstable_by_key(|&(symbol_id, _)| *symbol_id);
+        writer.len(redeclarations.len());
+        for (&symbol_id, redeclarations) in redeclarations {
+            writer.id(symbol_id);
+            writer.len(redeclarations.len());
+            for redeclaration in redeclarations {
+                writer.span(redeclaration.span);
+                writer.id(redeclaration.declaration);
+                writer.u32(redeclaration.flags.bits());
+            }
+        }
+
+        let mut no_side_effects = self.no_side_effects.iter().copied().collect::<Vec<_>>();
+        no_side_effects.sort_unstable();
+        writer.ids(&no_side_effects);
+
+        // References
+        writer.len(self.references.len());
+        for reference in &self.references {
+            writer.id(reference.node_id());
+            writer.optional_id(reference.symbol_id());
+            writer.0.push(reference.flags().bits());
+        }
+
+        // Scopes
+        writer.len(self.scopes_len());
+        for (scope_id, &parent_id) in self.scope_parent_ids.iter_enumerated() {
+            writer.optional_id(parent_id);
+            writer.id(self.scope_node_ids[scope_id]);
+            writer.u32(u32::from(self.scope_flags[scope_id].bits()));
+            writer.len(cell.bindings[scope_id].len());
+            for (&name, &symbol_id) in &cell.bindings[scope_id] {
+                writer.str(name);
+                writer.id(symbol_id);
+            }
+        }
+
+        writer.0.push(u8::from(self.scope_build_child_ids));
+        writer.len(cell.scope_child_ids.len());
+        for child_ids in &cell.scope_child_ids {
+            writer.ids(child_ids);
+        }
+
+        writer.len(cell.root_unresolved_references.len());
+        for (&name, reference_ids) in &cell.root_unresolved_references {
+            writer.str(name);
+            writer.ids(reference_ids);
+        }
+    }
+
+    /// Load a [`Scoping`] from a snapshot created by [`Scoping::write_snapshot`].
+    ///
+    /// Returns `None` if `bytes` is not a valid snapshot, or was created by an incompatible version.
+    pub fn read_snapshot(bytes: &[u8]) -> Option<Self> {
+        let mut reader = SnapshotReader(bytes);
+        if reader.bytes(SNAPSHOT_MAGIC.len())? != SNAPSHOT_MAGIC
+            || reader.u32()? != SNAPSHOT_VERSION
+        {
+            return None;
+        }
+
+        // Symbols
+        let symbols_len = reader.len()?;
+        let mut symbol_spans = IndexVec::with_capacity(symbols_len);
+        let mut symbol_flags = IndexVec::with_capacity(symbols_len);
+        let mut symbol_scope_ids = IndexVec::with_capacity(symbols_len);
+        let mut symbol_declarations = IndexVec::with_capacity(symbols_len);
+        let mut symbol_names = Vec::with_capacity(symbols_len);
+        let mut resolved_references = Vec::with_capacity(symbols_len);
+        for _ in 0..symbols_len {
+            symbol_spans.push(reader.span()?);
+            symbol_flags.push(SymbolFlags::from_bits(reader.u32()?)?);
+            symbol_scope_ids.push(reader.id()?);
+            symbol_declarations.push(reader.id()?);
+            symbol_names.push(reader.str()?);
+            resolved_references.push(reader.ids::<ReferenceId>()?);
+        }
+
+        let redeclarations_len = reader.len()?;
+        let mut symbol_redeclarations = Vec::with_capacity(redeclarations_len);
+        for _ in 0..redeclarations_len {
+            let symbol_id: SymbolId = reader.id()?;
+            let len = reader.len()?;
+            let mut redeclarations = Vec::with_capacity(len);
+            for _ in 0..len {
+                redeclarations.push(Redeclaration {
+                    span: reader.span()?,
+                    declaration: reader.id()?,
+                    flags: SymbolFlags::from_bits(reader.u32()?)?,
+                });
+            }
+            symbol_redeclarations.push((symbol_id, redeclarations));
+        }
+
+        let no_side_effects: FxHashSet<SymbolId> = reader.ids::<SymbolId>()?.into_iter().collect();
+
+        // References
+        let references_len = reader.len()?;
+        let mut references = IndexVec::with_capacity(references_len);
+        for _ in 0..references_len {
+            let node_id = reader.id()?;
+            let symbol_id = reader.optional_id()?;
+            let flags = ReferenceFlags::from_bits(reader.u8()?)?;
+            references.push(match symbol_id {
+                Some(symbol_id) => Reference::new_with_symbol_id(node_id, symbol_id, flags),
+                None => Reference::new(node_id, flags),
+            });
+        }
+
+        // Scopes
+        let scopes_len = reader.len()?;
+        let mut scope_parent_ids = IndexVec::with_capacity(scopes_len);
+        let mut scope_node_ids = IndexVec::with_capacity(scopes_len);
+        let mut scope_flags = IndexVec::with_capacity(scopes_len);
+        let mut bindings = Vec::with_capacity(scopes_len);
+        for _ in 0..scopes_len {
+            scope_parent_ids.push(reader.optional_id()?);
+            scope_node_ids.push(reader.id()?);
+            scope_flags.push(ScopeFlags::from_bits(u16::try_from(reader.u32()?).ok()?)?);
+            let len = reader.len()?;
+            let mut scope_bindings = Vec::with_capacity(len);
+            for _ in 0..len {
+                scope_bindings.push((reader.str()?, reader.id::<SymbolId>()?));
+            }
+            bindings.push(scope_bindings);
+        }
+
+        let scope_build_child_ids = reader.u8()? != 0;
+        let child_ids_len = reader.len()?;
+        let mut scope_child_ids = Vec::with_capacity(child_ids_len);
+        for _ in 0..child_ids_len {
+            scope_child_ids.push(reader.ids::<ScopeId>()?);
+        }
+
+        let unresolved_len = reader.len()?;
+        let mut root_unresolved_references = Vec::with_capacity(unresolved_len);
+        for _ in 0..unresolved_len {
+            root_unresolved_references.push((reader.str()?, reader.ids::<ReferenceId>()?));
+        }
+
+        if !reader.0.is_empty() {
+            return None;
+        }
+
+        // Check that all IDs are in range, so that a corrupt snapshot cannot cause a panic later
+        let is_symbol_id = |id: &SymbolId| id.index() < symbols_len;
+        let is_scope_id = |id: &ScopeId| id.index() < scopes_len;
+        let is_reference_id = |id: &ReferenceId| id.index() < references_len;
+        let is_valid = symbol_scope_ids.iter().all(is_scope_id)
+            && resolved_references.iter().flatten().all(is_reference_id)
+            && symbol_redeclarations.iter().all(|(symbol_id, _)| is_symbol_id(symbol_id))
+            && no_side_effects.iter().all(is_symbol_id)
+            && references.iter().filter_map(Reference::symbol_id).all(|id| is_symbol_id(&id))
+            && scope_parent_ids.iter().flatten().all(is_scope_id)
+            && bindings.iter().flatten().all(|(_, symbol_id)| is_symbol_id(symbol_id))
+            && scope_child_ids.len() == if scope_build_child_ids { scopes_len } else { 0 }
+            && scope_child_ids.iter().flatten().all(is_scope_id)
+            && root_unresolved_references.iter().flat_map(|(_, ids)| ids).all(is_reference_id);
+        if !is_valid {
+            return None;
+        }
+
+        Some(Self {
+            symbol_spans,
+            symbol_flags,
+            symbol_scope_ids,
+            symbol_declarations,
+            references,
+            no_side_effects,
+            scope_parent_ids,
+            scope_build_child_ids,
+            scope_node_ids,
+            scope_flags,
+            cell: ScopingCell::new(Allocator::with_capacity(bytes.len()), |allocator| {
+                ScopingInner {
+                    symbol_names: ArenaVec::from_iter_in(
+                        symbol_names.into_iter().map(|name| Atom::from_in(name, allocator)),
+                        allocator,
+                    ),
+                    resolved_references: ArenaVec::from_iter_in(
+                        resolved_references
+                            .into_iter()
+                            .map(|ids| ArenaVec::from_iter_in(ids, allocator)),
+                        allocator,
+                    ),
+                    symbol_redeclarations: symbol_redeclarations
+                        .into_iter()
+                        .map(|(symbol_id, redeclarations)| {
+                            (symbol_id, ArenaVec::from_iter_in(redeclarations, allocator))
+                        })
+                        .collect(),
+                    bindings: bindings
+                        .into_iter()
+                        .map(|scope_bindings| {
+                            let mut map = Bindings::new_in(allocator);
+                            map.reserve(scope_bindings.len());
+                            for (name, symbol_id) in scope_bindings {
+                                map.insert(allocator.alloc_str(name), symbol_id);
+                            }
+                            map
+                        })
+                        .collect(),
+                    scope_child_ids: ArenaVec::from_iter_in(
+                        scope_child_ids
+                            .into_iter()
+                            .map(|ids| ArenaVec::from_iter_in(ids, allocator)),
+                        allocator,
+                    ),
+                    root_unresolved_references: {
+                        let mut map = UnresolvedReferences::new_in(allocator);
+                        map.reserve(root_unresolved_references.len());
+                        for (name, reference_ids) in root_unresolved_references {
+                            map.insert(
+                                allocator.alloc_str(name),
+                                ArenaVec::from_iter_in(reference_ids, allocator),
+                            );
+                        }
+                        map
+                    },
+                }
+            }),
+        })
+    }
+}
+
+/// Writer for [`Scoping`] snapshots. All integers are little-endian `u32`s.
+struct SnapshotWriter<'w>(&'w mut Vec<u8>);
+
+impl SnapshotWriter<'_> {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn len(&mut self, len: usize) {
+        // IDs are `u32`s, so lengths of all collections fit in `u32`
+        self.u32(len as u32);
+    }
+
+    fn str(&mut self, s: &str) {
+        self.len(s.len());
+        self.bytes(s.as_bytes());
+    }
+
+    fn span(&mut self, span: Span) {
+        self.u32(span.start);
+        self.u32(span.end);
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn id<I: Idx>(&mut self, id: I) {
+        self.u32(id.index() as u32);
+    }
+
+    /// `None` is encoded as `u32::MAX`, which is not a valid ID.
+    fn optional_id<I: Idx>(&mut self, id: Option<I>) {
+        match id {
+            Some(id) => self.id(id),
+            None => self.u32(u32::MAX),
+        }
+    }
+
+    fn ids<I: Idx>(&mut self, ids: &[I]) {
+        self.len(ids.len());
+        for &id in ids {
+            self.id(id);
+        }
+    }
+}
+
+/// Reader for [`Scoping`] snapshots.
+///
+/// All methods return `None` if the snapshot is truncated or contains invalid values.
+struct SnapshotReader<'b>(&'b [u8]);
+
+impl<'b> SnapshotReader<'b> {
+    fn bytes(&mut self, len: usize) -> Option<&'b [u8]> {
+        let (bytes, rest) = self.0.split_at_checked(len)?;
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        let (&byte, rest) = self.0.split_first()?;
+        self.0 = rest;
+        Some(byte)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let (bytes, rest) = self.0.split_first_chunk::<4>()?;
+        self.0 = rest;
+        Some(u32::from_le_bytes(*bytes))
+    }
+
+    /// Read a length. Every item in a collection occupies at least 1 byte, so a length greater than
+    /// the number of remaining bytes is invalid. This prevents huge allocations for corrupt input.
+    fn len(&mut self) -> Option<usize> {
+        let len = self.u32()? as usize;
+        (len <= self.0.len()).then_some(len)
+    }
+
+    fn str(&mut self) -> Option<&'b str> {
+        let len = self.len()?;
+        std::str::from_utf8(self.bytes(len)?).ok()
+    }
+
+    fn span(&mut self) -> Option<Span> {
+        Some(Span::new(self.u32()?, self.u32()?))
+    }
+
+    fn id<I: Idx>(&mut self) -> Option<I> {
+        self.optional_id()?
+    }
+
+    /// Returns `None` if input is truncated, and `Some(None)` if no ID was written.
+    #[expect(clippy::option_option)]
+    fn optional_id<I: Idx>(&mut self) -> Option<Option<I>> {
+        let id = self.u32()?;
+        Some((id != u32::MAX).then(|| I::from_usize(id as usize)))
+    }
+
+    fn ids<I: Idx>(&mut self) -> Option<Vec<I>> {
+        let len = self.len()?;
+        std::iter::repeat_with(|| self.id()).take(len).collect()
+    }
+}
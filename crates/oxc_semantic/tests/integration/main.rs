@@ -4,5 +4,6 @@ pub mod cfg;
 pub mod classes;
 pub mod modules;
 pub mod scopes;
+pub mod snapshot;
 pub mod symbols;
 pub mod util;
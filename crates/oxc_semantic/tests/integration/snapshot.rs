@@ -0,0 +1,102 @@
+use oxc_allocator::Allocator;
+use oxc_ast::snapshot::{self, SnapshotError};
+use oxc_index::Idx;
+use oxc_parser::Parser;
+use oxc_semantic::{ScopeId, Scoping, SemanticBuilder, SymbolId};
+use oxc_span::{ContentEq, SourceType};
+
+const SOURCE: &str = "
+import { a } from 'a';
+let x = 1, y = /ab+c/v;
+var x;
+function foo<T>(p: T, ...rest: string[]): T {
+    class Bar { #priv = `t${p}`; static { undeclared; } }
+    return p ?? a;
+}
+export default () => foo(x, 'str', y);
+";
+
+#[test]
+fn program_and_scoping_round_trip() {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, SOURCE, SourceType::ts()).parse().program;
+    let semantic = SemanticBuilder::new().with_scope_tree_child_ids(true).build(&program).semantic;
+    let scoping = semantic.scoping();
+
+    let mut program_bytes = vec![];
+    snapshot::write_program(&program, &mut program_bytes);
+    let mut scoping_bytes = vec![];
+    scoping.write_snapshot(&mut scoping_bytes);
+
+    let allocator2 = Allocator::default();
+    // SAFETY: Bytes were created by `write_program`
+    let program2 = unsafe { snapshot::read_program(&allocator2, &program_bytes) }.unwrap();
+    assert!(program2.content_eq(&program));
+    assert_eq!(program2.source_text, SOURCE);
+    assert_eq!(program2.scope_id.get(), program.scope_id.get());
+
+    let scoping2 = Scoping::read_snapshot(&scoping_bytes).unwrap();
+    assert_eq!(scoping2.symbols_len(), scoping.symbols_len());
+    for symbol_id in scoping.symbol_ids() {
+        assert_eq!(scoping2.symbol_name(symbol_id), scoping.symbol_name(symbol_id));
+        assert_eq!(scoping2.symbol_flags(symbol_id), scoping.symbol_flags(symbol_id));
+        assert_eq!(
+            scoping2.symbol_redeclarations(symbol_id).len(),
+            scoping.symbol_redeclarations(symbol_id).len()
+        );
+        assert_eq!(
+            &**scoping2.get_resolved_reference_ids(symbol_id),
+            &**scoping.get_resolved_reference_ids(symbol_id)
+        );
+    }
+    assert_eq!(scoping2.scopes_len(), scoping.scopes_len());
+    for scope_id in (0..scoping.scopes_len()).map(ScopeId::from_usize) {
+        assert_eq!(scoping2.scope_parent_id(scope_id), scoping.scope_parent_id(scope_id));
+        assert_eq!(scoping2.scope_flags(scope_id), scoping.scope_flags(scope_id));
+        assert_eq!(scoping2.get_scope_child_ids(scope_id), scoping.get_scope_child_ids(scope_id));
+        for (name, &symbol_id) in scoping.get_bindings(scope_id) {
+            assert_eq!(scoping2.get_binding(scope_id, name), Some(symbol_id));
+        }
+    }
+    assert_eq!(
+        scoping2.root_unresolved_references().keys().count(),
+        scoping.root_unresolved_references().keys().count()
+    );
+    assert!(scoping2.root_unresolved_references().contains_key("undeclared"));
+
+    // Scoping still works after load
+    let mut scoping2 = scoping2;
+    let symbol_id: SymbolId = scoping2.get_root_binding("x").unwrap();
+    scoping2.set_symbol_name(symbol_id, "renamed");
+    assert_eq!(scoping2.symbol_name(symbol_id), "renamed");
+}
+
+#[test]
+fn invalid_snapshots() {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, SOURCE, SourceType::ts()).parse().program;
+    let mut bytes = vec![];
+    snapshot::write_program(&program, &mut bytes);
+
+    let read = |bytes: &[u8]| {
+        let allocator = Allocator::default();
+        // SAFETY: Only the header or length of snapshot is modified
+        unsafe { snapshot::read_program(&allocator, bytes) }.map(|_| ())
+    };
+
+    assert_eq!(read(&[]), Err(SnapshotError::NotASnapshot));
+    assert_eq!(read(&bytes[1..]), Err(SnapshotError::NotASnapshot));
+
+    let mut incompatible_version = bytes.clone();
+    incompatible_version[8] += 1;
+    assert_eq!(read(&incompatible_version), Err(SnapshotError::IncompatibleVersion));
+
+    let mut incompatible_layout = bytes.clone();
+    incompatible_layout[16] ^= 1;
+    assert_eq!(read(&incompatible_layout), Err(SnapshotError::IncompatibleLayout));
+
+    assert_eq!(read(&bytes[..bytes.len() - 16]), Err(SnapshotError::Malformed));
+
+    assert!(Scoping::read_snapshot(&[]).is_none());
+    assert!(Scoping::read_snapshot(b"OXCSCOPE\x01\x00\x00\x00").is_none());
+}
@@ -4,7 +4,10 @@ use std::{
     ops::Deref,
 };
 
-use oxc_allocator::{Allocator, CloneIn, Dummy, FromIn, StringBuilder as ArenaStringBuilder};
+use oxc_allocator::{
+    Allocator, CloneIn, Dummy, FromIn, MalformedImage, Relocate, Relocator,
+    StringBuilder as ArenaStringBuilder,
+};
 #[cfg(feature = "serialize")]
 use oxc_estree::{ESTree, Serializer as ESTreeSerializer};
 #[cfg(feature = "serialize")]
@@ -121,6 +124,14 @@ impl<'a> Dummy<'a> for Atom<'a> {
     }
 }
 
+impl Relocate for Atom<'_> {
+    #[inline]
+    unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+        // SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+        unsafe { self.0.relocate(relocator) }
+    }
+}
+
 impl<'alloc> FromIn<'alloc, &Atom<'alloc>> for Atom<'alloc> {
     fn from_in(s: &Atom<'alloc>, _: &'alloc Allocator) -> Self {
         *s
@@ -42,6 +42,8 @@ pub enum Language {
     TypeScript = 1,
     /// Indicates a TypeScript definition file (`*.d.ts`)
     TypeScriptDefinition = 2,
+    /// Indicates a JavaScript file with [Flow](https://flow.org) type annotations
+    Flow = 3,
 }
 
 /// Script or Module
@@ -302,6 +304,32 @@ impl SourceType {
         }
     }
 
+    /// Creates a [`SourceType`] representing a [`Flow`] file with [`JSX`].
+    ///
+    /// Flow files use `.js` extension, so this is never inferred from a path.
+    ///
+    /// ## Example
+    /// ```
+    /// # use oxc_span::SourceType;
+    ///
+    /// let flow = SourceType::flow();
+    /// assert!(flow.is_flow());
+    /// assert!(!flow.is_javascript());
+    /// assert!(!flow.is_typescript());
+    /// assert!(flow.is_module());
+    /// assert!(flow.is_jsx());
+    /// ```
+    ///
+    /// [`Flow`]: Language::Flow
+    /// [`JSX`]: LanguageVariant::Jsx
+    pub const fn flow() -> Self {
+        Self {
+            language: Language::Flow,
+            module_kind: ModuleKind::Module,
+            variant: LanguageVariant::Jsx,
+        }
+    }
+
     /// Returns `true` if this [`SourceType`] is [script].
     ///
     /// [script]: ModuleKind::Script
@@ -346,6 +374,11 @@ impl SourceType {
         self.language == Language::TypeScriptDefinition
     }
 
+    /// Returns `true` if this is a JavaScript file with Flow type annotations.
+    pub fn is_flow(self) -> bool {
+        self.language == Language::Flow
+    }
+
     /// Returns `true` if this source type is using JSX.
     ///
     /// Note that TSX is considered JSX in this context.
@@ -431,6 +464,18 @@ impl SourceType {
         self
     }
 
+    /// Mark this [`SourceType`] as using [Flow] if `yes` is `true`. No change
+    /// will occur if `yes` is `false`.
+    ///
+    /// [Flow]: Language::Flow
+    #[must_use]
+    pub const fn with_flow(mut self, yes: bool) -> Self {
+        if yes {
+            self.language = Language::Flow;
+        }
+        self
+    }
+
     /// Mark this [`SourceType`] as using [JSX] if `yes` is `true`. No change
     /// will occur if `yes` is `false`.
     ///
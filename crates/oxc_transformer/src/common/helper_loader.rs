@@ -168,6 +168,8 @@ pub enum Helper {
     DecorateParam,
     DecorateMetadata,
     UsingCtx,
+    WrapRegExp,
+    ImportDeferProxy,
 }
 
 impl Helper {
@@ -201,11 +203,13 @@ impl Helper {
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
             Self::UsingCtx => "usingCtx",
+            Self::WrapRegExp => "wrapRegExp",
+            Self::ImportDeferProxy => "importDeferProxy",
         }
     }
 
     pub const fn pure(self) -> bool {
-        matches!(self, Self::ClassPrivateFieldLooseKey)
+        matches!(self, Self::ClassPrivateFieldLooseKey | Self::ImportDeferProxy)
     }
 }
 
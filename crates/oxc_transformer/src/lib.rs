@@ -51,7 +51,7 @@ use es2020::ES2020;
 use es2021::ES2021;
 use es2022::ES2022;
 use jsx::Jsx;
-use proposals::ExplicitResourceManagement;
+use proposals::{ExplicitResourceManagement, ImportDefer, SourcePhaseImports};
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
 use state::TransformState;
@@ -76,7 +76,7 @@ pub use crate::{
         babel::{BabelEnvOptions, BabelOptions},
     },
     plugins::{PluginsOptions, StyledComponentsOptions},
-    proposals::ProposalOptions,
+    proposals::{ESNextOptions, ProposalOptions, SourcePhaseImportsOptions},
     typescript::{RewriteExtensionsMode, TypeScriptOptions},
 };
 
@@ -112,7 +112,7 @@ impl<'a> Transformer<'a> {
             plugins: options.plugins.clone(),
             jsx: options.jsx.clone(),
             env: options.env,
-            proposals: options.proposals,
+            proposals: options.proposals.clone(),
         }
     }
 
@@ -136,6 +136,13 @@ impl<'a> Transformer<'a> {
             );
         }
 
+        if program.source_type.is_flow() {
+            // Flow annotations are parsed into TypeScript AST nodes, so they are stripped by the
+            // TypeScript plugin. Same as `@babel/plugin-transform-flow-strip-types`,
+            // unused value imports are retained.
+            self.typescript.only_remove_type_imports = true;
+        }
+
         let mut transformer = TransformerImpl {
             common: Common::new(&self.env, &self.ctx),
             decorator: Decorator::new(self.decorator, &self.ctx),
@@ -144,9 +151,13 @@ impl<'a> Transformer<'a> {
                 .proposals
                 .explicit_resource_management
                 .then(|| ExplicitResourceManagement::new(&self.ctx)),
-            x0_typescript: program
-                .source_type
-                .is_typescript()
+            import_defer: self.env.esnext.import_defer.then(|| ImportDefer::new(&self.ctx)),
+            source_phase_imports: self
+                .env
+                .esnext
+                .source_phase_imports
+                .then(|| SourcePhaseImports::new(&self.proposals.source_phase_imports)),
+            x0_typescript: (program.source_type.is_typescript() || program.source_type.is_flow())
                 .then(|| TypeScript::new(&self.typescript, &self.ctx)),
             x1_jsx: Jsx::new(self.jsx, self.env.es2018.object_rest_spread, ast_builder, &self.ctx),
             x2_es2022: ES2022::new(
@@ -179,6 +190,8 @@ struct TransformerImpl<'a, 'ctx> {
     decorator: Decorator<'a, 'ctx>,
     plugins: Plugins<'a, 'ctx>,
     explicit_resource_management: Option<ExplicitResourceManagement<'a, 'ctx>>,
+    import_defer: Option<ImportDefer<'a, 'ctx>>,
+    source_phase_imports: Option<SourcePhaseImports<'ctx>>,
     x1_jsx: Jsx<'a, 'ctx>,
     x2_es2022: ES2022<'a, 'ctx>,
     x2_es2021: ES2021<'a, 'ctx>,
@@ -212,6 +225,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
         self.x2_es2022.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
+        if let Some(import_defer) = self.import_defer.as_mut() {
+            import_defer.exit_program(program, ctx);
+        }
+        if let Some(source_phase_imports) = self.source_phase_imports.as_mut() {
+            source_phase_imports.exit_program(program, ctx);
+        }
         self.common.exit_program(program, ctx);
     }
 
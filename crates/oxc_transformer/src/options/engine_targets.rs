@@ -66,6 +66,14 @@ impl EngineTargets {
         false
     }
 
+    /// Returns true if proposals which are not shipped by any engine need to be lowered,
+    /// i.e. any target other than `esnext` is specified.
+    pub fn has_esnext_proposals(&self) -> bool {
+        use super::es_target::{ESTarget, ESVersion};
+        let esnext = ESTarget::ESNext.version();
+        self.iter().any(|(engine, version)| *engine != Engine::Es || version.0 < esnext.0)
+    }
+
     /// Parses the value returned from `browserslist`.
     pub fn parse_versions(versions: Vec<(String, String)>) -> Self {
         let mut engine_targets = Self::default();
@@ -12,6 +12,7 @@ use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
+    proposals::ESNextOptions,
     regexp::RegExpOptions,
 };
 
@@ -40,6 +41,8 @@ pub struct EnvOptions {
     pub es2021: ES2021Options,
 
     pub es2022: ES2022Options,
+
+    pub esnext: ESNextOptions,
 }
 
 impl EnvOptions {
@@ -86,6 +89,7 @@ impl EnvOptions {
                 class_static_block: true,
                 class_properties: Some(ClassPropertiesOptions::default()),
             },
+            esnext: ESNextOptions { import_defer: true, source_phase_imports: true },
         }
     }
 
@@ -188,6 +192,10 @@ impl From<EngineTargets> for EnvOptions {
                 class_static_block: o.has_feature(ES2022ClassStaticBlock),
                 class_properties: o.has_feature(ES2022ClassProperties).then(Default::default),
             },
+            esnext: {
+                let lower = o.has_esnext_proposals();
+                ESNextOptions { import_defer: lower, source_phase_imports: lower }
+            },
         }
     }
 }
@@ -285,9 +285,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2020,
                 es2021,
                 es2022,
+                esnext: env.esnext,
             },
             proposals: ProposalOptions {
                 explicit_resource_management: options.plugins.explicit_resource_management,
+                ..ProposalOptions::default()
             },
             helper_loader,
             plugins,
@@ -0,0 +1,131 @@
+//! Proposal: Deferring Module Evaluation
+//!
+//! This plugin transforms `import defer` declarations.
+//!
+//! When the output is CommonJS, the namespace is replaced with a proxy which evaluates the module
+//! on first access. An ES module cannot load another module synchronously on demand, so when the
+//! output is ESM, the `defer` phase is removed and the module is evaluated eagerly.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import defer * as ns from "x";
+//! ns.foo();
+//! ```
+//!
+//! Output (CommonJS):
+//! ```js
+//! var ns = babelHelpers.importDeferProxy(() => require("x"));
+//! ns.foo();
+//! ```
+//!
+//! Output (ESM):
+//! ```js
+//! import * as ns from "x";
+//! ns.foo();
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-import-defer](https://babeljs.io/docs/babel-plugin-proposal-import-defer).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.9/packages/babel-plugin-proposal-import-defer>
+//! * Deferring Module Evaluation TC39 proposal: <https://github.com/tc39/proposal-defer-import-eval>
+
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct ImportDefer<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ImportDefer<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ImportDefer<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        for stmt in &mut program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.phase != Some(ImportPhase::Defer) {
+                continue;
+            }
+            if !self.ctx.module.is_commonjs() {
+                // `import defer * as ns from "x"` -> `import * as ns from "x"`
+                decl.phase = None;
+                continue;
+            }
+            let Some(ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier)) =
+                decl.specifiers.as_ref().and_then(|specifiers| specifiers.first())
+            else {
+                continue;
+            };
+            let local = BoundIdentifier::from_binding_ident(&specifier.local);
+            let source = decl.source.value;
+            *stmt = self.create_proxy_declaration(&local, source, ctx);
+        }
+    }
+}
+
+impl<'a> ImportDefer<'a, '_> {
+    /// `var ns = babelHelpers.importDeferProxy(() => require("x"));`
+    fn create_proxy_declaration(
+        &self,
+        local: &BoundIdentifier<'a>,
+        source: Atom<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        *ctx.scoping_mut().symbol_flags_mut(local.symbol_id) = SymbolFlags::FunctionScopedVariable;
+
+        let require_symbol_id = ctx.scoping().get_root_binding("require");
+        let callee = ctx.create_ident_expr(
+            SPAN,
+            Atom::from("require"),
+            require_symbol_id,
+            ReferenceFlags::Read,
+        );
+        let argument = Argument::from(ctx.ast.expression_string_literal(SPAN, source, None));
+        let require_call =
+            ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(argument), false);
+
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, require_call)),
+        );
+        let init = ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false,
+        );
+
+        let proxy = self.ctx.helper_call_expr(
+            Helper::ImportDeferProxy,
+            SPAN,
+            ctx.ast.vec1(Argument::from(init)),
+            ctx,
+        );
+
+        let kind = VariableDeclarationKind::Var;
+        let id = local.create_binding_pattern(ctx);
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(proxy), false);
+        Statement::from(ctx.ast.declaration_variable(SPAN, kind, ctx.ast.vec1(declarator), false))
+    }
+}
@@ -1,5 +1,9 @@
 mod explicit_resource_management;
+mod import_defer;
 mod options;
+mod source_phase_imports;
 
 pub use explicit_resource_management::ExplicitResourceManagement;
-pub use options::ProposalOptions;
+pub use import_defer::ImportDefer;
+pub use options::{ESNextOptions, ProposalOptions, SourcePhaseImportsOptions};
+pub use source_phase_imports::SourcePhaseImports;
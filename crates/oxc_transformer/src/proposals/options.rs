@@ -1,10 +1,45 @@
-#[derive(Debug, Clone, Copy)]
+use serde::Deserialize;
+
+#[derive(Debug, Clone)]
 pub struct ProposalOptions {
     pub explicit_resource_management: bool,
+
+    pub source_phase_imports: SourcePhaseImportsOptions,
 }
 
 impl Default for ProposalOptions {
     fn default() -> Self {
-        Self { explicit_resource_management: true }
+        Self {
+            explicit_resource_management: true,
+            source_phase_imports: SourcePhaseImportsOptions::default(),
+        }
     }
 }
+
+/// Import phase proposals, lowered according to [`crate::EnvOptions`] targets.
+///
+/// These proposals are not shipped by any engine yet,
+/// so they are lowered for every target except `esnext`.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ESNextOptions {
+    /// [Deferring Module Evaluation](https://github.com/tc39/proposal-defer-import-eval):
+    /// `import defer * as ns from "mod"`
+    #[serde(skip)]
+    pub import_defer: bool,
+
+    /// [Source Phase Imports](https://github.com/tc39/proposal-source-phase-imports):
+    /// `import source x from "mod"`
+    #[serde(skip)]
+    pub source_phase_imports: bool,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SourcePhaseImportsOptions {
+    /// Function called with the module specifier to load the module source, e.g. `loadSource` or
+    /// `globalThis.loadSource`. The result is awaited.
+    ///
+    /// Defaults to compiling the module as WebAssembly:
+    /// `WebAssembly.compileStreaming(fetch(import.meta.resolve(specifier)))`.
+    pub loader: Option<String>,
+}
@@ -0,0 +1,143 @@
+//! Proposal: Source Phase Imports
+//!
+//! This plugin transforms `import source` declarations into an awaited loader call.
+//!
+//! The loader is configured with [`SourcePhaseImportsOptions::loader`]. By default, the module
+//! is compiled as WebAssembly, which is the only kind of module with a source phase today.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import source libMod from "./lib.wasm";
+//! ```
+//!
+//! Output:
+//! ```js
+//! const libMod = await WebAssembly.compileStreaming(fetch(import.meta.resolve("./lib.wasm")));
+//! ```
+//!
+//! Output with `loader: "loadSource"`:
+//! ```js
+//! const libMod = await loadSource("./lib.wasm");
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-import-wasm-source](https://babeljs.io/docs/babel-plugin-proposal-import-wasm-source).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.9/packages/babel-plugin-proposal-import-wasm-source>
+//! * Source Phase Imports TC39 proposal: <https://github.com/tc39/proposal-source-phase-imports>
+
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+use super::SourcePhaseImportsOptions;
+
+pub struct SourcePhaseImports<'o> {
+    options: &'o SourcePhaseImportsOptions,
+}
+
+impl<'o> SourcePhaseImports<'o> {
+    pub fn new(options: &'o SourcePhaseImportsOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for SourcePhaseImports<'_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        for stmt in &mut program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.phase != Some(ImportPhase::Source) {
+                continue;
+            }
+            let Some(ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier)) =
+                decl.specifiers.as_ref().and_then(|specifiers| specifiers.first())
+            else {
+                continue;
+            };
+            let local = BoundIdentifier::from_binding_ident(&specifier.local);
+            let source = decl.source.value;
+            *stmt = self.create_loader_declaration(&local, source, ctx);
+        }
+    }
+}
+
+impl<'a> SourcePhaseImports<'_> {
+    /// `const x = await loader("x");`
+    fn create_loader_declaration(
+        &self,
+        local: &BoundIdentifier<'a>,
+        source: Atom<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        *ctx.scoping_mut().symbol_flags_mut(local.symbol_id) =
+            SymbolFlags::ConstVariable | SymbolFlags::BlockScopedVariable;
+
+        let specifier = ctx.ast.expression_string_literal(SPAN, source, None);
+        let load = match &self.options.loader {
+            Some(loader) => {
+                let callee = Self::create_loader_callee(loader, ctx);
+                Self::create_call(callee, specifier, ctx)
+            }
+            None => Self::create_wasm_loader_call(specifier, ctx),
+        };
+        let init = ctx.ast.expression_await(SPAN, load);
+
+        let kind = VariableDeclarationKind::Const;
+        let id = local.create_binding_pattern(ctx);
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        Statement::from(ctx.ast.declaration_variable(SPAN, kind, ctx.ast.vec1(declarator), false))
+    }
+
+    /// `WebAssembly.compileStreaming(fetch(import.meta.resolve("x")))`
+    fn create_wasm_loader_call(
+        specifier: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let import_meta = ctx.ast.expression_meta_property(
+            SPAN,
+            ctx.ast.identifier_name(SPAN, "import"),
+            ctx.ast.identifier_name(SPAN, "meta"),
+        );
+        let resolve = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            import_meta,
+            ctx.ast.identifier_name(SPAN, "resolve"),
+            false,
+        ));
+        let url = Self::create_call(resolve, specifier, ctx);
+        let fetch = Self::create_loader_callee("fetch", ctx);
+        let response = Self::create_call(fetch, url, ctx);
+        let compile_streaming = Self::create_loader_callee("WebAssembly.compileStreaming", ctx);
+        Self::create_call(compile_streaming, response, ctx)
+    }
+
+    /// Create a callee from a dotted path, e.g. `globalThis.loadSource`.
+    fn create_loader_callee(path: &str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let mut parts = path.split('.');
+        let object = ctx.ast.atom(parts.next().unwrap());
+        let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), &object);
+        let mut callee = ctx.create_ident_expr(SPAN, object, symbol_id, ReferenceFlags::Read);
+        for property in parts {
+            let property = ctx.ast.identifier_name(SPAN, ctx.ast.atom(property));
+            callee =
+                Expression::from(ctx.ast.member_expression_static(SPAN, callee, property, false));
+        }
+        callee
+    }
+
+    fn create_call(
+        callee: Expression<'a>,
+        argument: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.vec1(Argument::from(argument));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+}
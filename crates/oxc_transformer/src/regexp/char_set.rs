@@ -0,0 +1,431 @@
+//! Sets of code points, and printing them as RegExp pattern syntax.
+
+use std::fmt::Write;
+
+use oxc_regular_expression::ast::CharacterClassEscapeKind;
+
+pub const MAX_BMP: u32 = 0xFFFF;
+pub const MAX_CODE_POINT: u32 = 0x0010_FFFF;
+
+const LEAD_SURROGATE_MIN: u32 = 0xD800;
+const TRAIL_SURROGATE_MIN: u32 = 0xDC00;
+const TRAIL_SURROGATE_MAX: u32 = 0xDFFF;
+
+/// `WhiteSpace` and `LineTerminator` code points matched by `\s`.
+const WHITE_SPACE: &[(u32, u32)] = &[
+    (0x09, 0x0D),
+    (0x20, 0x20),
+    (0xA0, 0xA0),
+    (0x1680, 0x1680),
+    (0x2000, 0x200A),
+    (0x2028, 0x2029),
+    (0x202F, 0x202F),
+    (0x205F, 0x205F),
+    (0x3000, 0x3000),
+    (0xFEFF, 0xFEFF),
+];
+
+const LINE_TERMINATORS: &[(u32, u32)] = &[(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)];
+
+/// A set of code points, stored as sorted, non-overlapping and non-adjacent inclusive ranges.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CodePointSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CodePointSet {
+    pub fn from_ranges(mut ranges: Vec<(u32, u32)>) -> Self {
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                Some(last) if min <= last.1.saturating_add(1) => last.1 = last.1.max(max),
+                _ => merged.push((min, max)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn single(cp: u32) -> Self {
+        Self { ranges: vec![(cp, cp)] }
+    }
+
+    /// Every code point up to `max`, i.e. what `[\s\S]` matches.
+    pub fn all(max: u32) -> Self {
+        Self { ranges: vec![(0, max)] }
+    }
+
+    /// What `.` matches without the `s` flag.
+    pub fn dot(max: u32) -> Self {
+        Self::from_ranges(LINE_TERMINATORS.to_vec()).complement(max)
+    }
+
+    /// What `\d`, `\s` or `\w` match. For `\D`, `\S` and `\W`, what the positive escape matches.
+    ///
+    /// `ignore_case_unicode` adds the characters which `\w` matches case-insensitively in
+    /// `u` and `v` mode, `ſ` (U+017F) and `K` (U+212A).
+    pub fn class_escape(kind: CharacterClassEscapeKind, ignore_case_unicode: bool) -> Self {
+        match kind {
+            CharacterClassEscapeKind::D | CharacterClassEscapeKind::NegativeD => {
+                Self { ranges: vec![(0x30, 0x39)] }
+            }
+            CharacterClassEscapeKind::S | CharacterClassEscapeKind::NegativeS => {
+                Self::from_ranges(WHITE_SPACE.to_vec())
+            }
+            CharacterClassEscapeKind::W | CharacterClassEscapeKind::NegativeW => {
+                let mut ranges = vec![(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)];
+                if ignore_case_unicode {
+                    ranges.extend([(0x017F, 0x017F), (0x212A, 0x212A)]);
+                }
+                Self::from_ranges(ranges)
+            }
+        }
+    }
+
+    pub fn ranges(&self) -> &[(u32, u32)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Add the simple upper and lower case mappings of every code point.
+    pub fn case_closure(&self) -> Self {
+        let mut ranges = self.ranges.clone();
+        for &(min, max) in &self.ranges {
+            for ch in (min..=max).filter_map(char::from_u32) {
+                let lower = single_char(ch.to_lowercase());
+                let upper = single_char(ch.to_uppercase());
+                for mapped in [lower, upper].into_iter().flatten().filter(|&mapped| mapped != ch) {
+                    ranges.push((mapped as u32, mapped as u32));
+                }
+            }
+        }
+        Self::from_ranges(ranges)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).copied().collect())
+    }
+
+    pub fn complement(&self, max: u32) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(min, range_max) in &self.ranges {
+            if min > max {
+                break;
+            }
+            if min > next {
+                ranges.push((next, min - 1));
+            }
+            next = range_max + 1;
+        }
+        if next <= max {
+            ranges.push((next, max));
+        }
+        Self { ranges }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_min, a_max) = self.ranges[i];
+            let (b_min, b_max) = other.ranges[j];
+            let min = a_min.max(b_min);
+            let max = a_max.min(b_max);
+            if min <= max {
+                ranges.push((min, max));
+            }
+            if a_max < b_max {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(MAX_CODE_POINT))
+    }
+}
+
+/// Case mappings to more than one character are not simple case mappings.
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Some(ch),
+        _ => None,
+    }
+}
+
+/// How a printed piece of pattern composes with its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// A single atom which a quantifier applies to as a whole, e.g. `a` or `[a-z]`.
+    Atom,
+    /// A concatenation of atoms, e.g. the surrogate pair `😀`.
+    Sequence,
+    /// Alternatives separated by `|`, which must always be wrapped in a group.
+    Alternation,
+}
+
+/// The syntax a printed pattern has to be valid in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternMode {
+    /// No `u` or `v` flag. Code points above U+FFFF are matched as surrogate pairs.
+    Legacy,
+    /// `u` flag.
+    Unicode,
+    /// `v` flag.
+    UnicodeSets,
+}
+
+impl PatternMode {
+    pub fn is_unicode(self) -> bool {
+        self != Self::Legacy
+    }
+}
+
+/// Print a pattern which matches any code point in `set`, or any string in `strings`.
+///
+/// Strings are tried longest first, the same as the `v` flag does for `\q{...}`.
+pub fn print_set(set: &CodePointSet, strings: &[Vec<u32>], mode: PatternMode) -> (String, Shape) {
+    let mut set = set.clone();
+    let mut multi_char_strings = vec![];
+    let mut has_empty_string = false;
+    for string in strings {
+        match string.as_slice() {
+            [] => has_empty_string = true,
+            [cp] => set = set.union(&CodePointSet::single(*cp)),
+            _ => multi_char_strings.push(string),
+        }
+    }
+    if multi_char_strings.is_empty() && !has_empty_string {
+        return print_code_points(&set, mode);
+    }
+
+    multi_char_strings.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    multi_char_strings.dedup();
+    let mut alternatives = multi_char_strings
+        .into_iter()
+        .map(|string| {
+            let mut alternative = String::new();
+            for &cp in string {
+                push_code_point(&mut alternative, cp, false, mode);
+            }
+            alternative
+        })
+        .collect::<Vec<_>>();
+    if !set.is_empty() {
+        alternatives.push(print_code_points(&set, mode).0);
+    }
+    if has_empty_string {
+        alternatives.push(String::new());
+    }
+    if alternatives.len() == 1 {
+        return (alternatives.pop().unwrap(), Shape::Sequence);
+    }
+    (alternatives.join("|"), Shape::Alternation)
+}
+
+fn print_code_points(set: &CodePointSet, mode: PatternMode) -> (String, Shape) {
+    let max = if mode.is_unicode() { MAX_CODE_POINT } else { MAX_BMP };
+    let (bmp, astral) = if mode.is_unicode() {
+        (set.clone(), CodePointSet::default())
+    } else {
+        (set.intersection(&CodePointSet::all(MAX_BMP)), set.difference(&CodePointSet::all(MAX_BMP)))
+    };
+
+    let mut alternatives = vec![];
+    if bmp.ranges() == [(0, max)] {
+        alternatives.push(r"[\s\S]".to_string());
+    } else if !bmp.is_empty() || astral.is_empty() {
+        alternatives.push(print_class(&bmp, mode));
+    }
+    let pairs = surrogate_pairs(&astral);
+    for (lead, trail) in &pairs {
+        let mut alternative = print_class(lead, mode);
+        alternative.push_str(&print_class(trail, mode));
+        alternatives.push(alternative);
+    }
+
+    match alternatives.len() {
+        1 if pairs.is_empty() => (alternatives.pop().unwrap(), Shape::Atom),
+        1 => (alternatives.pop().unwrap(), Shape::Sequence),
+        _ => (alternatives.join("|"), Shape::Alternation),
+    }
+}
+
+/// Print `set` as a character class, or as a single character if it only has one.
+fn print_class(set: &CodePointSet, mode: PatternMode) -> String {
+    let mut class = String::new();
+    if let [(min, max)] = set.ranges() {
+        if min == max {
+            push_code_point(&mut class, *min, false, mode);
+            return class;
+        }
+    }
+    class.push('[');
+    for &(min, max) in set.ranges() {
+        push_code_point(&mut class, min, true, mode);
+        if max > min {
+            if max > min + 1 {
+                class.push('-');
+            }
+            push_code_point(&mut class, max, true, mode);
+        }
+    }
+    class.push(']');
+    class
+}
+
+/// A range of lead surrogates with the trail surrogate ranges which can follow each of them.
+type LeadTrails = ((u32, u32), Vec<(u32, u32)>);
+
+/// Split code points above U+FFFF into pairs of lead and trail surrogate sets, where every
+/// lead surrogate can be followed by every trail surrogate.
+fn surrogate_pairs(astral: &CodePointSet) -> Vec<(CodePointSet, CodePointSet)> {
+    let split = |cp: u32| {
+        let offset = cp - 0x10000;
+        (LEAD_SURROGATE_MIN + (offset >> 10), TRAIL_SURROGATE_MIN + (offset & 0x3FF))
+    };
+    // Lead surrogates with the trail surrogates which can follow them, in ascending order.
+    let mut trails: Vec<LeadTrails> = vec![];
+    let mut push = |lead: (u32, u32), trail: (u32, u32)| match trails.last_mut() {
+        Some((last_lead, last_trails)) if *last_lead == lead => {
+            last_trails.push(trail);
+        }
+        _ => trails.push((lead, vec![trail])),
+    };
+    for &(min, max) in astral.ranges() {
+        let (mut min_lead, min_trail) = split(min);
+        let (mut max_lead, max_trail) = split(max);
+        if min_lead == max_lead {
+            push((min_lead, min_lead), (min_trail, max_trail));
+            continue;
+        }
+        if min_trail != TRAIL_SURROGATE_MIN {
+            push((min_lead, min_lead), (min_trail, TRAIL_SURROGATE_MAX));
+            min_lead += 1;
+        }
+        let last = (max_trail != TRAIL_SURROGATE_MAX).then(|| {
+            max_lead -= 1;
+            ((max_lead + 1, max_lead + 1), (TRAIL_SURROGATE_MIN, max_trail))
+        });
+        if min_lead <= max_lead {
+            push((min_lead, max_lead), (TRAIL_SURROGATE_MIN, TRAIL_SURROGATE_MAX));
+        }
+        if let Some((lead, trail)) = last {
+            push(lead, trail);
+        }
+    }
+    // Merge consecutive lead surrogates which are followed by the same trail surrogates.
+    let mut pairs: Vec<LeadTrails> = vec![];
+    for (lead, lead_trails) in trails {
+        match pairs.last_mut() {
+            Some((last_lead, last_trails))
+                if *last_trails == lead_trails && last_lead.1 + 1 == lead.0 =>
+            {
+                last_lead.1 = lead.1;
+            }
+            _ => pairs.push((lead, lead_trails)),
+        }
+    }
+    pairs
+        .into_iter()
+        .map(|(lead, trails)| {
+            (CodePointSet { ranges: vec![lead] }, CodePointSet { ranges: trails })
+        })
+        .collect()
+}
+
+/// Push `cp` as a pattern character, escaping it where needed.
+///
+/// Code points above U+FFFF are printed as surrogate pairs outside of `u` and `v` mode, which is
+/// only correct outside of character classes.
+pub fn push_code_point(out: &mut String, cp: u32, in_class: bool, mode: PatternMode) {
+    match cp {
+        0x09 => out.push_str(r"\t"),
+        0x0A => out.push_str(r"\n"),
+        0x0B => out.push_str(r"\v"),
+        0x0C => out.push_str(r"\f"),
+        0x0D => out.push_str(r"\r"),
+        0x20..=0x7E => {
+            #[expect(clippy::cast_possible_truncation)]
+            let ch = cp as u8 as char;
+            let needs_escape = if !in_class {
+                r"^$\.*+?()[]{}|/".contains(ch)
+            } else if mode == PatternMode::UnicodeSets {
+                r"()[]{}/-\|&!#%,:;<=>@`~^$*+.?".contains(ch)
+            } else {
+                r"\]-^[/".contains(ch)
+            };
+            if needs_escape {
+                out.push('\\');
+            }
+            out.push(ch);
+        }
+        0..=0xFF => write!(out, r"\x{cp:02X}").unwrap(),
+        0x100..=MAX_BMP => write!(out, r"\u{cp:04X}").unwrap(),
+        _ if mode.is_unicode() => write!(out, r"\u{{{cp:X}}}").unwrap(),
+        _ => {
+            let offset = cp - 0x10000;
+            let lead = LEAD_SURROGATE_MIN + (offset >> 10);
+            let trail = TRAIL_SURROGATE_MIN + (offset & 0x3FF);
+            write!(out, r"\u{lead:04X}\u{trail:04X}").unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn print(ranges: &[(u32, u32)], mode: PatternMode) -> String {
+        print_set(&CodePointSet::from_ranges(ranges.to_vec()), &[], mode).0
+    }
+
+    #[test]
+    fn set_operations() {
+        let set = CodePointSet::from_ranges(vec![(5, 10), (0, 2), (3, 4), (20, 30)]);
+        assert_eq!(set.ranges(), [(0, 10), (20, 30)]);
+        assert_eq!(set.complement(40).ranges(), [(11, 19), (31, 40)]);
+        let other = CodePointSet::from_ranges(vec![(8, 25)]);
+        assert_eq!(set.intersection(&other).ranges(), [(8, 10), (20, 25)]);
+        assert_eq!(set.difference(&other).ranges(), [(0, 7), (26, 30)]);
+        assert_eq!(set.union(&other).ranges(), [(0, 30)]);
+    }
+
+    #[test]
+    fn print_code_points() {
+        assert_eq!(print(&[(0x61, 0x7A), (0x5F, 0x5F)], PatternMode::Legacy), "[_a-z]");
+        assert_eq!(print(&[(0x2D, 0x2D), (0x5D, 0x5E)], PatternMode::Legacy), r"[\-\]\^]");
+        assert_eq!(print(&[(0x26, 0x26), (0x28, 0x28)], PatternMode::UnicodeSets), r"[\&\(]");
+        assert_eq!(print(&[(0x41, 0x41)], PatternMode::Legacy), "A");
+        assert_eq!(print(&[(0, MAX_BMP)], PatternMode::Legacy), r"[\s\S]");
+        assert_eq!(print(&[(0, MAX_CODE_POINT)], PatternMode::Unicode), r"[\s\S]");
+        assert_eq!(print(&[(0x1F600, 0x1F64F)], PatternMode::Unicode), r"[\u{1F600}-\u{1F64F}]");
+        assert_eq!(print(&[(0x1F600, 0x1F600)], PatternMode::Legacy), r"\uD83D\uDE00");
+        assert_eq!(
+            print(&[(0x61, 0x61), (0x1F600, 0x1F64F)], PatternMode::Legacy),
+            r"a|\uD83D[\uDE00-\uDE4F]"
+        );
+        assert_eq!(
+            print(&[(0x10000, MAX_CODE_POINT)], PatternMode::Legacy),
+            r"[\uD800-\uDBFF][\uDC00-\uDFFF]"
+        );
+        assert_eq!(
+            print(&[(0x103FF, 0x10800)], PatternMode::Legacy),
+            r"\uD800\uDFFF|\uD801[\uDC00-\uDFFF]|\uD802\uDC00"
+        );
+    }
+
+    #[test]
+    fn print_strings() {
+        let set = CodePointSet::from_ranges(vec![(0x61, 0x62)]);
+        let strings = [vec![0x78], vec![0x61, 0x62, 0x63], vec![]];
+        assert_eq!(print_set(&set, &strings, PatternMode::Unicode).0, "abc|[abx]|");
+    }
+}
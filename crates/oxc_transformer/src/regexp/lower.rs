@@ -0,0 +1,443 @@
+//! Rewrite RegExp patterns so that they only use syntax which the target supports.
+//!
+//! Based on [regexpu-core](https://github.com/mathiasbynens/regexpu-core).
+//!
+//! Parts of the pattern which don't need rewriting are copied from the source text. Everything
+//! else is printed from the code points it matches:
+//!
+//! - `\p{...}` and `\P{...}` are expanded into character classes from Unicode data.
+//! - `.` with the `s` flag becomes `[\s\S]`.
+//! - Character classes with `v` flag set operations are computed and printed as `u` flag
+//!   classes, with strings from `\q{...}` as alternatives.
+//! - Without the `u` flag, code points above U+FFFF are matched as surrogate pairs.
+//! - Named groups become unnamed groups, and named backreferences become indexed ones.
+//!   The group names are returned so that they can be passed to the `wrapRegExp` helper.
+//!
+//! Lone surrogates are not special-cased when the `u` flag is removed. e.g. `[^a]` matches the
+//! first half of a surrogate pair on its own, where the `u` flag version would not.
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_regular_expression::{
+    ast::{
+        CharacterClass, CharacterClassContents, CharacterClassContentsKind,
+        CharacterClassEscapeKind, Disjunction, LookAroundAssertionKind, Pattern, Term,
+        UnicodePropertyEscape,
+    },
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::{Atom, GetSpan, Span};
+
+use super::{
+    RegExpOptions,
+    char_set::{
+        CodePointSet, MAX_BMP, MAX_CODE_POINT, PatternMode, Shape, print_set, push_code_point,
+    },
+    unicode_property::unicode_property_code_points,
+};
+
+/// Which features of a RegExp literal have to be rewritten.
+#[derive(Debug, Clone, Copy)]
+pub struct Lowering {
+    /// Rewrite `.` so that the `s` flag can be removed.
+    pub dot_all: bool,
+    /// Rewrite the pattern so that the `u` and `v` flags can be removed.
+    pub unicode: bool,
+    /// Rewrite `v` flag syntax to `u` flag syntax.
+    pub unicode_sets: bool,
+    /// Expand `\p{...}` and `\P{...}`.
+    pub unicode_property_escapes: bool,
+    /// Rewrite named groups to unnamed groups.
+    pub named_capture_groups: bool,
+    /// Lookbehind assertions are not supported, and cannot be rewritten.
+    pub look_behind_assertions: bool,
+}
+
+impl Lowering {
+    pub fn new(options: RegExpOptions, flags: RegExpFlags) -> Self {
+        let unicode = options.unicode_flag && flags.intersects(RegExpFlags::U | RegExpFlags::V);
+        Self {
+            dot_all: options.dot_all_flag && flags.contains(RegExpFlags::S),
+            unicode,
+            unicode_sets: flags.contains(RegExpFlags::V) && (options.set_notation || unicode),
+            unicode_property_escapes: options.unicode_property_escapes || unicode,
+            named_capture_groups: options.named_capture_groups,
+            look_behind_assertions: options.look_behind_assertions,
+        }
+    }
+}
+
+pub struct LoweredPattern<'a> {
+    pub text: String,
+    pub flags: RegExpFlags,
+    /// Names of the groups which were rewritten to unnamed groups, and their group indices.
+    ///
+    /// A name has several indices if it is used by groups in different alternatives.
+    pub group_names: Vec<(&'a str, Vec<u32>)>,
+}
+
+/// Rewrite `pattern`, which was parsed from `source` with `flags`.
+///
+/// Returns `None` if the pattern uses a feature which has to be rewritten but cannot be, e.g. a
+/// lookbehind assertion or a property of strings.
+pub fn lower_pattern<'a>(
+    pattern: &Pattern<'a>,
+    source: &str,
+    flags: RegExpFlags,
+    lowering: Lowering,
+) -> Option<LoweredPattern<'a>> {
+    let source_mode = if flags.contains(RegExpFlags::V) {
+        PatternMode::UnicodeSets
+    } else if flags.contains(RegExpFlags::U) {
+        PatternMode::Unicode
+    } else {
+        PatternMode::Legacy
+    };
+
+    let mut output_flags = flags;
+    let output_mode = if lowering.unicode {
+        output_flags.remove(RegExpFlags::U | RegExpFlags::V);
+        PatternMode::Legacy
+    } else if lowering.unicode_sets {
+        output_flags.remove(RegExpFlags::V);
+        output_flags.insert(RegExpFlags::U);
+        PatternMode::Unicode
+    } else {
+        source_mode
+    };
+    if lowering.dot_all {
+        output_flags.remove(RegExpFlags::S);
+    }
+
+    let mut group_names: Vec<(&'a str, Vec<u32>)> = vec![];
+    if lowering.named_capture_groups {
+        let mut collector = GroupCollector { count: 0, names: vec![] };
+        collector.visit_pattern(pattern);
+        for (name, index) in collector.names {
+            // The names are keys of the object passed to `wrapRegExp`
+            if name.contains('\\') {
+                return None;
+            }
+            match group_names.iter_mut().find(|(other, _)| *other == name) {
+                Some((_, indices)) => indices.push(index),
+                None => group_names.push((name, vec![index])),
+            }
+        }
+    }
+
+    let mut lowerer = PatternLowerer {
+        source,
+        flags,
+        lowering,
+        source_mode,
+        output_mode,
+        group_names: &group_names,
+        out: String::with_capacity(source.len()),
+        after_reference: false,
+    };
+    lowerer.lower_disjunction(&pattern.body)?;
+    let text = lowerer.out;
+    Some(LoweredPattern { text, flags: output_flags, group_names })
+}
+
+struct PatternLowerer<'s, 'a> {
+    source: &'s str,
+    flags: RegExpFlags,
+    lowering: Lowering,
+    source_mode: PatternMode,
+    output_mode: PatternMode,
+    group_names: &'s [(&'a str, Vec<u32>)],
+    out: String,
+    /// Whether the output ends with a backreference, which a following digit would extend.
+    after_reference: bool,
+}
+
+impl<'a> PatternLowerer<'_, 'a> {
+    fn lower_disjunction(&mut self, disjunction: &Disjunction<'a>) -> Option<()> {
+        for (index, alternative) in disjunction.body.iter().enumerate() {
+            if index > 0 {
+                self.push("|");
+            }
+            for term in &alternative.body {
+                self.lower_term(term, false)?;
+            }
+        }
+        Some(())
+    }
+
+    fn lower_term(&mut self, term: &Term<'a>, quantified: bool) -> Option<()> {
+        match term {
+            Term::BoundaryAssertion(_) => self.copy(term.span()),
+            Term::IndexedReference(reference) => {
+                self.copy(reference.span);
+                self.after_reference = true;
+            }
+            Term::LookAroundAssertion(assertion) => {
+                if self.lowering.look_behind_assertions
+                    && matches!(
+                        assertion.kind,
+                        LookAroundAssertionKind::Lookbehind
+                            | LookAroundAssertionKind::NegativeLookbehind
+                    )
+                {
+                    return None;
+                }
+                self.copy(Span::new(assertion.span.start, assertion.body.span.start));
+                self.lower_disjunction(&assertion.body)?;
+                self.push(")");
+            }
+            Term::Quantifier(quantifier) => {
+                self.lower_term(&quantifier.body, true)?;
+                self.copy(Span::new(quantifier.body.span().end, quantifier.span.end));
+            }
+            Term::Character(character) => {
+                if self.lowering.unicode {
+                    let mut text = String::new();
+                    push_code_point(&mut text, character.value, false, self.output_mode);
+                    let shape =
+                        if character.value > MAX_BMP { Shape::Sequence } else { Shape::Atom };
+                    self.push_piece(&text, shape, quantified);
+                } else {
+                    self.copy(character.span);
+                }
+            }
+            Term::Dot(dot) => {
+                let dot_all = self.flags.contains(RegExpFlags::S);
+                if (dot_all && self.lowering.dot_all) || self.lowering.unicode {
+                    let set = if dot_all {
+                        CodePointSet::all(self.max_code_point())
+                    } else {
+                        CodePointSet::dot(self.max_code_point())
+                    };
+                    self.push_set(&set, &[], quantified);
+                } else {
+                    self.copy(dot.span);
+                }
+            }
+            Term::CharacterClassEscape(escape) => {
+                let ignore_case = self.flags.contains(RegExpFlags::I);
+                let changes_without_unicode = matches!(
+                    escape.kind,
+                    CharacterClassEscapeKind::NegativeD
+                        | CharacterClassEscapeKind::NegativeS
+                        | CharacterClassEscapeKind::NegativeW
+                ) || (ignore_case
+                    && escape.kind == CharacterClassEscapeKind::W);
+                if self.lowering.unicode && changes_without_unicode {
+                    let set = self.class_escape_code_points(escape.kind);
+                    self.push_set(&set, &[], quantified);
+                } else {
+                    self.copy(escape.span);
+                }
+            }
+            Term::UnicodePropertyEscape(escape) => {
+                if self.lowering.unicode_property_escapes {
+                    let set = self.property_code_points(escape)?;
+                    self.push_set(&set, &[], quantified);
+                } else {
+                    self.copy(escape.span);
+                }
+            }
+            Term::CharacterClass(class) => {
+                if self.needs_class_rewrite(class) {
+                    let (set, strings) = self.class_code_points(class)?;
+                    self.push_set(&set, &strings, quantified);
+                } else {
+                    self.copy(class.span);
+                }
+            }
+            Term::CapturingGroup(group) => {
+                if group.name.is_some() && self.lowering.named_capture_groups {
+                    self.push("(");
+                } else {
+                    self.copy(Span::new(group.span.start, group.body.span.start));
+                }
+                self.lower_disjunction(&group.body)?;
+                self.push(")");
+            }
+            Term::IgnoreGroup(group) => {
+                self.copy(Span::new(group.span.start, group.body.span.start));
+                self.lower_disjunction(&group.body)?;
+                self.push(")");
+            }
+            Term::NamedReference(reference) => {
+                if self.lowering.named_capture_groups {
+                    let (_, indices) =
+                        self.group_names.iter().find(|(name, _)| *name == reference.name)?;
+                    // A backreference to a group which did not participate in the match matches
+                    // the empty string, so only the group which matched contributes.
+                    let mut text = String::new();
+                    for index in indices {
+                        text.push('\\');
+                        text.push_str(&index.to_string());
+                    }
+                    let shape = if indices.len() > 1 { Shape::Sequence } else { Shape::Atom };
+                    self.push_piece(&text, shape, quantified);
+                    self.after_reference = true;
+                } else {
+                    self.copy(reference.span);
+                }
+            }
+        }
+        Some(())
+    }
+
+    /// Classes are rewritten whenever the syntax inside them changes, which is whenever the
+    /// flags change between `v`, `u` and neither.
+    fn needs_class_rewrite(&self, class: &CharacterClass) -> bool {
+        self.output_mode != self.source_mode
+            || (self.lowering.unicode_property_escapes && class_has_property_escape(class))
+    }
+
+    fn class_code_points(
+        &self,
+        class: &CharacterClass<'a>,
+    ) -> Option<(CodePointSet, Vec<Vec<u32>>)> {
+        let mut result: Option<(CodePointSet, Vec<Vec<u32>>)> = None;
+        for contents in &class.body {
+            let (set, strings) = self.class_contents_code_points(contents)?;
+            result = Some(match result {
+                None => (set, strings),
+                Some((result_set, mut result_strings)) => match class.kind {
+                    CharacterClassContentsKind::Union => {
+                        result_strings.extend(strings);
+                        (result_set.union(&set), result_strings)
+                    }
+                    CharacterClassContentsKind::Intersection => {
+                        result_strings.retain(|string| strings.contains(string));
+                        (result_set.intersection(&set), result_strings)
+                    }
+                    CharacterClassContentsKind::Subtraction => {
+                        result_strings.retain(|string| !strings.contains(string));
+                        (result_set.difference(&set), result_strings)
+                    }
+                },
+            });
+        }
+        let (set, strings) = result.unwrap_or_default();
+        if class.negative {
+            // Negated classes cannot contain strings
+            return Some((self.complement(&set), vec![]));
+        }
+        Some((set, strings))
+    }
+
+    fn class_contents_code_points(
+        &self,
+        contents: &CharacterClassContents<'a>,
+    ) -> Option<(CodePointSet, Vec<Vec<u32>>)> {
+        let set = match contents {
+            CharacterClassContents::CharacterClassRange(range) => {
+                CodePointSet::from_ranges(vec![(range.min.value, range.max.value)])
+            }
+            CharacterClassContents::Character(character) => CodePointSet::single(character.value),
+            CharacterClassContents::CharacterClassEscape(escape) => {
+                self.class_escape_code_points(escape.kind)
+            }
+            CharacterClassContents::UnicodePropertyEscape(escape) => {
+                self.property_code_points(escape)?
+            }
+            CharacterClassContents::NestedCharacterClass(class) => {
+                return self.class_code_points(class);
+            }
+            CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                let strings = disjunction
+                    .body
+                    .iter()
+                    .map(|string| string.body.iter().map(|character| character.value).collect())
+                    .collect();
+                return Some((CodePointSet::default(), strings));
+            }
+        };
+        Some((set, vec![]))
+    }
+
+    fn class_escape_code_points(&self, kind: CharacterClassEscapeKind) -> CodePointSet {
+        let ignore_case_unicode =
+            self.source_mode.is_unicode() && self.flags.contains(RegExpFlags::I);
+        let set = CodePointSet::class_escape(kind, ignore_case_unicode);
+        match kind {
+            CharacterClassEscapeKind::NegativeD
+            | CharacterClassEscapeKind::NegativeS
+            | CharacterClassEscapeKind::NegativeW => self.complement(&set),
+            _ => set,
+        }
+    }
+
+    fn property_code_points(&self, escape: &UnicodePropertyEscape) -> Option<CodePointSet> {
+        if escape.strings {
+            return None;
+        }
+        let value = escape.value.as_ref().map(Atom::as_str);
+        let set = unicode_property_code_points(escape.name.as_str(), value)?;
+        Some(if escape.negative { self.complement(&set) } else { set })
+    }
+
+    /// Complement of `set`. With the `i` flag, `set` is closed under case folding first, so that
+    /// the complement does not match case variants of characters in `set`.
+    fn complement(&self, set: &CodePointSet) -> CodePointSet {
+        if self.flags.contains(RegExpFlags::I) {
+            set.case_closure().complement(self.max_code_point())
+        } else {
+            set.complement(self.max_code_point())
+        }
+    }
+
+    fn max_code_point(&self) -> u32 {
+        if self.source_mode.is_unicode() { MAX_CODE_POINT } else { MAX_BMP }
+    }
+
+    fn push_set(&mut self, set: &CodePointSet, strings: &[Vec<u32>], quantified: bool) {
+        let (text, shape) = print_set(set, strings, self.output_mode);
+        self.push_piece(&text, shape, quantified);
+    }
+
+    fn push_piece(&mut self, text: &str, shape: Shape, quantified: bool) {
+        if shape == Shape::Alternation || (shape == Shape::Sequence && quantified) {
+            self.push("(?:");
+            self.push(text);
+            self.push(")");
+        } else {
+            self.push(text);
+        }
+    }
+
+    fn copy(&mut self, span: Span) {
+        self.push(&self.source[span.start as usize..span.end as usize]);
+    }
+
+    fn push(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        if self.after_reference && text.starts_with(|c: char| c.is_ascii_digit()) {
+            self.out.push_str("(?:)");
+        }
+        self.after_reference = false;
+        self.out.push_str(text);
+    }
+}
+
+fn class_has_property_escape(class: &CharacterClass) -> bool {
+    class.body.iter().any(|contents| match contents {
+        CharacterClassContents::UnicodePropertyEscape(_) => true,
+        CharacterClassContents::NestedCharacterClass(class) => class_has_property_escape(class),
+        _ => false,
+    })
+}
+
+/// Collects the names of capturing groups, with their 1-based group indices.
+struct GroupCollector<'a> {
+    count: u32,
+    names: Vec<(&'a str, u32)>,
+}
+
+impl<'a> Visit<'a> for GroupCollector<'a> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::CapturingGroup(group) = kind {
+            self.count += 1;
+            if let Some(name) = &group.name {
+                self.names.push((name.as_str(), self.count));
+            }
+        }
+    }
+}
@@ -1,10 +1,16 @@
 //! RegExp Transformer
 //!
 //! This module supports various RegExp plugins to handle unsupported RegExp literal features.
-//! When an unsupported feature is detected, these plugins convert the RegExp literal into
-//! a `new RegExp()` constructor call to avoid syntax errors.
+//! When an unsupported feature is detected, the pattern is rewritten into an equivalent pattern
+//! which only uses supported syntax, like [regexpu-core](https://github.com/mathiasbynens/regexpu-core)
+//! does. See [`lower`] for what can be rewritten.
 //!
-//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior.
+//! Features which cannot be rewritten (the `y` and `d` flags, lookbehind assertions and properties
+//! of strings) are handled by converting the RegExp literal into a `new RegExp()` constructor call
+//! to avoid syntax errors.
+//!
+//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have
+//! the correct runtime behavior of features which can not be rewritten.
 //!
 //! ### ES2015
 //!
@@ -47,23 +53,30 @@
 use oxc_ast::{NONE, ast::*};
 use oxc_diagnostics::Result;
 use oxc_regular_expression::ast::{
-    CharacterClass, CharacterClassContents, LookAroundAssertionKind, Pattern, Term,
+    CharacterClass, CharacterClassContents, Disjunction, LookAroundAssertionKind, Pattern, Term,
 };
 use oxc_semantic::ReferenceFlags;
 use oxc_span::{Atom, SPAN};
+use oxc_syntax::number::NumberBase;
 use oxc_traverse::Traverse;
 
 use crate::{
+    common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
     state::TransformState,
 };
 
+mod char_set;
+mod lower;
 mod options;
+mod unicode_property;
 
+use lower::{LoweredPattern, Lowering, lower_pattern};
 pub use options::RegExpOptions;
 
 pub struct RegExp<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
+    options: RegExpOptions,
     unsupported_flags: RegExpFlags,
     some_unsupported_patterns: bool,
     look_behind_assertions: bool,
@@ -104,6 +117,7 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
 
         Self {
             ctx,
+            options,
             unsupported_flags,
             some_unsupported_patterns,
             look_behind_assertions,
@@ -124,7 +138,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for RegExp<'a, '_> {
 }
 
 impl<'a> RegExp<'a, '_> {
-    /// If `RegExpLiteral` contains unsupported syntax or flags, transform to `new RegExp(...)`.
+    /// If `RegExpLiteral` contains unsupported syntax or flags, rewrite the pattern, or transform
+    /// to `new RegExp(...)` if it cannot be rewritten.
     fn transform_regexp(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let Expression::RegExpLiteral(regexp) = expr else {
             unreachable!();
@@ -176,6 +191,11 @@ impl<'a> RegExp<'a, '_> {
             }
         }
 
+        if let Some(lowered) = self.lower_regexp(regexp, ctx) {
+            *expr = lowered;
+            return;
+        }
+
         let callee = {
             let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "RegExp");
             ctx.create_ident_expr(SPAN, Atom::from("RegExp"), symbol_id, ReferenceFlags::read())
@@ -193,11 +213,87 @@ impl<'a> RegExp<'a, '_> {
         *expr = ctx.ast.expression_new(regexp.span, callee, NONE, arguments);
     }
 
+    /// Rewrite the pattern of `regexp` so that it does not need unsupported syntax or flags.
+    ///
+    /// Returns `None` if that is not possible.
+    fn lower_regexp(
+        &self,
+        regexp: &RegExpLiteral<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let flags = regexp.regex.flags;
+        // The `y` and `d` flags change how the RegExp is used, not what the pattern matches
+        if flags.intersects(self.unsupported_flags & (RegExpFlags::Y | RegExpFlags::D)) {
+            return None;
+        }
+
+        // Parse again, with spans relative to the pattern text
+        let pattern_text = regexp.regex.pattern.text.as_str();
+        let flags_text = flags.to_inline_string();
+        let pattern = try_parse_pattern(pattern_text, 0, flags_text.as_str(), 0, ctx).ok()?;
+
+        let lowering = Lowering::new(self.options, flags);
+        let LoweredPattern { text, flags, group_names } =
+            lower_pattern(&pattern, pattern_text, flags, lowering)?;
+
+        // Parsing the result also guards against printing an invalid pattern
+        let text = ctx.ast.atom(&text);
+        let flags_text = ctx.ast.atom(flags.to_inline_string().as_str());
+        let pattern = try_parse_pattern(text.as_str(), 0, flags_text.as_str(), 0, ctx).ok()?;
+        let regex = oxc_ast::ast::RegExp {
+            pattern: RegExpPattern { text, pattern: Some(ctx.ast.alloc(pattern)) },
+            flags,
+        };
+        let literal = ctx.ast.expression_reg_exp_literal(regexp.span, regex, None);
+        if group_names.is_empty() {
+            return Some(literal);
+        }
+
+        // `wrapRegExp(/(b)/, { a: 1 })`
+        let properties = ctx.ast.vec_from_iter(group_names.into_iter().map(|(name, indices)| {
+            let key = ctx.ast.property_key_static_identifier(SPAN, ctx.ast.atom(name));
+            let mut values = indices.into_iter().map(|index| {
+                ctx.ast.expression_numeric_literal(
+                    SPAN,
+                    f64::from(index),
+                    None,
+                    NumberBase::Decimal,
+                )
+            });
+            let value = if values.len() == 1 {
+                values.next().unwrap()
+            } else {
+                ctx.ast.expression_array(
+                    SPAN,
+                    ctx.ast.vec_from_iter(values.map(ArrayExpressionElement::from)),
+                )
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                false,
+                false,
+                false,
+            )
+        }));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(literal),
+            Argument::from(ctx.ast.expression_object(SPAN, properties)),
+        ]);
+        Some(self.ctx.helper_call_expr(Helper::WrapRegExp, regexp.span, arguments, ctx))
+    }
+
     /// Check if the regular expression contains any unsupported syntax.
     ///
     /// Based on parsed regular expression pattern.
     fn has_unsupported_regular_expression_pattern(&self, pattern: &Pattern<'a>) -> bool {
-        pattern.body.body.iter().any(|alternative| {
+        self.disjunction_contains_unsupported(&pattern.body)
+    }
+
+    fn disjunction_contains_unsupported(&self, disjunction: &Disjunction) -> bool {
+        disjunction.body.iter().any(|alternative| {
             alternative.body.iter().any(|term| self.term_contains_unsupported(term))
         })
     }
@@ -206,19 +302,26 @@ impl<'a> RegExp<'a, '_> {
         // Loop because `Term::Quantifier` contains a nested `Term`
         loop {
             match term {
-                Term::CapturingGroup(_) => return self.named_capture_groups,
+                Term::CapturingGroup(group) => {
+                    return (self.named_capture_groups && group.name.is_some())
+                        || self.disjunction_contains_unsupported(&group.body);
+                }
+                Term::IgnoreGroup(group) => {
+                    return self.disjunction_contains_unsupported(&group.body);
+                }
                 Term::UnicodePropertyEscape(_) => return self.unicode_property_escapes,
                 Term::CharacterClass(character_class) => {
                     return self.unicode_property_escapes
                         && character_class_has_unicode_property_escape(character_class);
                 }
                 Term::LookAroundAssertion(assertion) => {
-                    return self.look_behind_assertions
-                        && matches!(
-                            assertion.kind,
-                            LookAroundAssertionKind::Lookbehind
-                                | LookAroundAssertionKind::NegativeLookbehind
-                        );
+                    let is_look_behind = matches!(
+                        assertion.kind,
+                        LookAroundAssertionKind::Lookbehind
+                            | LookAroundAssertionKind::NegativeLookbehind
+                    );
+                    return (self.look_behind_assertions && is_look_behind)
+                        || self.disjunction_contains_unsupported(&assertion.body);
                 }
                 Term::Quantifier(quantifier) => term = &quantifier.body,
                 _ => return false,
@@ -0,0 +1,33 @@
+//! Code points matched by Unicode property escapes (`\p{...}`).
+
+use oxc_regular_expression::unicode_property_ranges;
+
+use super::char_set::CodePointSet;
+
+/// Code points matched by `\p{name}` or `\p{name=value}`.
+///
+/// Returns `None` for properties which are unknown to the Unicode data, e.g. properties added in
+/// a newer Unicode version.
+pub fn unicode_property_code_points(name: &str, value: Option<&str>) -> Option<CodePointSet> {
+    unicode_property_ranges(name, value).map(CodePointSet::from_ranges)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CodePointSet, unicode_property_code_points};
+
+    #[test]
+    fn properties() {
+        let ascii_hex = unicode_property_code_points("ASCII_Hex_Digit", None).unwrap();
+        assert_eq!(ascii_hex.ranges(), [(0x30, 0x39), (0x41, 0x46), (0x61, 0x66)]);
+
+        let greek = unicode_property_code_points("Script", Some("Greek")).unwrap();
+        assert!(!greek.intersection(&CodePointSet::single(0x3B1)).is_empty());
+        assert_eq!(unicode_property_code_points("sc", Some("Grek")), Some(greek));
+
+        let letters = unicode_property_code_points("L", None).unwrap();
+        assert_eq!(unicode_property_code_points("General_Category", Some("Letter")), Some(letters));
+
+        assert!(unicode_property_code_points("Unknown_Property", None).is_none());
+    }
+}
@@ -22,6 +22,8 @@ fn es_target() {
         ("es2019", "1n ** 2n"), // test target error
         ("es2021", "class foo { static {} }"),
         ("es2021", "class Foo { #a; }"),
+        ("es2025", "import defer * as ns from 'x'; ns;"),
+        ("es2025", "import source s from 'x.wasm'; s;"),
     ];
 
     // Test no transformation for esnext.
@@ -63,6 +65,12 @@ fn target_list_pass() {
     assert!(!result.env.es2020.nullish_coalescing_operator);
     assert!(result.env.es2021.logical_assignment_operators);
     assert!(result.env.es2022.class_static_block);
+    assert!(result.env.esnext.import_defer);
+    assert!(result.env.esnext.source_phase_imports);
+
+    let result = TransformOptions::from_target("esnext").unwrap();
+    assert!(!result.env.esnext.import_defer);
+    assert!(!result.env.esnext.source_phase_imports);
 }
 
 #[test]
@@ -0,0 +1,45 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test_with_source_type};
+
+#[test]
+fn strip_types() {
+    let cases = [
+        // The file is kept as a module when all imports and exports are removed.
+        ("import type { A } from './a'; import typeof B from './b';", "export {};"),
+        ("import { type C, typeof D, e } from './c'; e;", "import { e } from './c'; e;"),
+        // Unused value imports are retained, same as `@babel/plugin-transform-flow-strip-types`.
+        ("import { f } from './f';", "import { f } from './f';"),
+        ("type T<+U, -V: string = 'x'> = { +a: U, -b: ?V, ...A, [key: string]: number };", ""),
+        ("type E = {| a: number, b?: string | number |}; type F = {||};", ""),
+        ("opaque type O: string = string; declare opaque type P;", ""),
+        ("export opaque type Q = number; export type { O };", "export {};"),
+        ("declare export function f(x: number): string; declare var x: number;", "export {};"),
+        ("declare class K { m(): void } declare function g(): void;", ""),
+        (
+            "function h<T: Object>(a: ?T, b?: number = 1): Array<T> { return [a]; }",
+            "function h(a, b = 1) { return [a]; }",
+        ),
+        ("const y = (x: any); const z = ((x: any): string);", "const y = x; const z = x;"),
+        ("const arrow = async (a: number): Promise<number> => a;", "const arrow = async (a) => a;"),
+        (
+            "class C<+T> extends Base<T> implements I { +p: T = x; static q: number = 1; }",
+            "class C extends Base { p = x; static q = 1; }",
+        ),
+        ("export default (y: any);", "export default y;"),
+        (
+            "const el = <div>{(x: any)}</div>;",
+            "import { jsx as _jsx } from 'react/jsx-runtime'; const el = /* @__PURE__ */ _jsx('div', { children: x });",
+        ),
+    ];
+
+    let options = TransformOptions::default();
+    for (source, expected) in cases {
+        assert_eq!(
+            test_with_source_type(source, SourceType::flow(), &options),
+            Ok(codegen(expected, SourceType::mjs())),
+            "{source}"
+        );
+    }
+}
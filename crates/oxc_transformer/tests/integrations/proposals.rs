@@ -0,0 +1,52 @@
+use oxc_span::SourceType;
+use oxc_transformer::{
+    ESNextOptions, EnvOptions, HelperLoaderMode, Module, ProposalOptions,
+    SourcePhaseImportsOptions, TransformOptions,
+};
+
+use crate::{codegen, test};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions {
+        env: EnvOptions {
+            esnext: ESNextOptions { import_defer: true, source_phase_imports: true },
+            ..EnvOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    options.helper_loader.mode = HelperLoaderMode::External;
+    options
+}
+
+#[test]
+fn import_defer() {
+    let source = "import defer * as ns from 'x'; import y from 'y'; ns.foo(y);";
+
+    let expected = "import * as ns from 'x'; import y from 'y'; ns.foo(y);";
+    assert_eq!(test(source, &options()), Ok(codegen(expected, SourceType::mjs())));
+
+    let mut options = options();
+    options.env.module = Module::CommonJS;
+    let expected = "var ns = /* @__PURE__ */ babelHelpers.importDeferProxy(() => require('x')); import y from 'y'; ns.foo(y);";
+    assert_eq!(test(source, &options), Ok(codegen(expected, SourceType::mjs())));
+}
+
+#[test]
+fn source_phase_imports() {
+    let source = "import source s from './x.wasm'; WebAssembly.instantiate(s);";
+
+    let expected = "const s = await WebAssembly.compileStreaming(fetch(import.meta.resolve('./x.wasm'))); WebAssembly.instantiate(s);";
+    assert_eq!(test(source, &options()), Ok(codegen(expected, SourceType::mjs())));
+
+    let options = TransformOptions {
+        proposals: ProposalOptions {
+            source_phase_imports: SourcePhaseImportsOptions {
+                loader: Some("globalThis.loadSource".to_string()),
+            },
+            ..ProposalOptions::default()
+        },
+        ..options()
+    };
+    let expected = "const s = await globalThis.loadSource('./x.wasm'); WebAssembly.instantiate(s);";
+    assert_eq!(test(source, &options), Ok(codegen(expected, SourceType::mjs())));
+}
@@ -43,6 +43,13 @@ export interface CompressOptions {
   unused?: true | false | 'keep_assign'
   /** Keep function / class names. */
   keepNames?: CompressOptionsKeepNames
+  /**
+   * Rewrite pure, JSON-compatible object and array literals whose JSON text is at least
+   * this many bytes into `JSON.parse('...')` calls, which V8 parses much faster.
+   *
+   * @default undefined
+   */
+  jsonParseThreshold?: number
 }
 
 export interface CompressOptionsKeepNames {
@@ -99,11 +106,38 @@ export interface MangleOptionsKeepNames {
 /** Minify synchronously. */
 export declare function minify(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): MinifyResult
 
+/** Minify JSON synchronously, by removing whitespace. */
+export declare function minifyJson(filename: string, sourceText: string): MinifyResult
+
 export interface MinifyOptions {
   compress?: boolean | CompressOptions
   mangle?: boolean | MangleOptions
   codegen?: boolean | CodegenOptions
   sourcemap?: boolean
+  /**
+   * Include the source text in the `sourcesContent` field of the source map.
+   *
+   * @default true
+   */
+  sourcemapSourcesContent?: boolean
+  /** Set the `sourceRoot` field of the source map. */
+  sourcemapSourceRoot?: string
+  /**
+   * List the source in the `x_google_ignoreList` field of the source map,
+   * so that debuggers skip it, e.g. for vendor files.
+   *
+   * @default false
+   */
+  sourcemapIgnoreList?: boolean
+  /**
+   * Add a Debug ID, derived from the code, to the `debugId` field of the source map
+   * and as a `//# debugId=` comment at the end of the code.
+   *
+   * @see https://github.com/tc39/ecma426/blob/main/proposals/debug-id.md
+   *
+   * @default false
+   */
+  sourcemapDebugId?: boolean
 }
 
 export interface MinifyResult {
@@ -146,4 +180,5 @@ export interface SourceMap {
   sourcesContent?: Array<string>
   version: number
   x_google_ignoreList?: Array<number>
+  debugId?: string
 }
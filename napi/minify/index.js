@@ -402,4 +402,5 @@ if (!nativeBinding) {
 
 module.exports = nativeBinding
 module.exports.minify = nativeBinding.minify
+module.exports.minifyJson = nativeBinding.minifyJson
 module.exports.Severity = nativeBinding.Severity
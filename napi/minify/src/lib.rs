@@ -18,9 +18,8 @@ use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_minifier::Minifier;
-use oxc_napi::OxcError;
+use oxc_napi::{OxcError, SourceMap};
 use oxc_parser::Parser;
-use oxc_sourcemap::napi::SourceMap;
 use oxc_span::SourceType;
 
 use crate::options::MinifyOptions;
@@ -74,13 +73,30 @@ pub fn minify(
 
     if options.sourcemap == Some(true) {
         codegen_options.source_map_path = Some(PathBuf::from(&filename));
+        codegen_options.source_map_sources_content =
+            options.sourcemap_sources_content.unwrap_or(true);
+        codegen_options.source_map_source_root.clone_from(&options.sourcemap_source_root);
+        codegen_options.source_map_ignore_list = options.sourcemap_ignore_list.unwrap_or_default();
+        codegen_options.source_map_debug_id = options.sourcemap_debug_id.unwrap_or_default();
     }
 
     let ret = Codegen::new().with_options(codegen_options).with_scoping(scoping).build(&program);
 
     MinifyResult {
         code: ret.code,
-        map: ret.map.map(oxc_sourcemap::napi::SourceMap::from),
+        map: ret.map.map(SourceMap::from),
         errors: OxcError::from_diagnostics(&filename, &source_text, parser_ret.errors),
     }
 }
+
+/// Minify JSON synchronously, by removing whitespace.
+#[napi]
+pub fn minify_json(filename: String, source_text: String) -> MinifyResult {
+    match oxc_minifier::minify_json(&source_text) {
+        Ok(code) => MinifyResult { code, ..MinifyResult::default() },
+        Err(errors) => MinifyResult {
+            errors: OxcError::from_diagnostics(&filename, &source_text, errors),
+            ..MinifyResult::default()
+        },
+    }
+}
@@ -41,6 +41,12 @@ pub struct CompressOptions {
 
     /// Keep function / class names.
     pub keep_names: Option<CompressOptionsKeepNames>,
+
+    /// Rewrite pure, JSON-compatible object and array literals whose JSON text is at least
+    /// this many bytes into `JSON.parse('...')` calls, which V8 parses much faster.
+    ///
+    /// @default undefined
+    pub json_parse_threshold: Option<u32>,
 }
 
 impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
@@ -59,7 +65,12 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
             // TODO
             unused: oxc_minifier::CompressOptionsUnused::Keep,
             keep_names: o.keep_names.as_ref().map(Into::into).unwrap_or_default(),
+            inline: default.inline,
+            unsafe_options: default.unsafe_options,
+            json_parse_threshold: o.json_parse_threshold.map(|n| n as usize),
             treeshake: TreeShakeOptions::default(),
+            report: false,
+            disabled_passes: vec![],
         })
     }
 }
@@ -174,6 +185,28 @@ pub struct MinifyOptions {
     pub codegen: Option<Either<bool, CodegenOptions>>,
 
     pub sourcemap: Option<bool>,
+
+    /// Include the source text in the `sourcesContent` field of the source map.
+    ///
+    /// @default true
+    pub sourcemap_sources_content: Option<bool>,
+
+    /// Set the `sourceRoot` field of the source map.
+    pub sourcemap_source_root: Option<String>,
+
+    /// List the source in the `x_google_ignoreList` field of the source map,
+    /// so that debuggers skip it, e.g. for vendor files.
+    ///
+    /// @default false
+    pub sourcemap_ignore_list: Option<bool>,
+
+    /// Add a Debug ID, derived from the code, to the `debugId` field of the source map
+    /// and as a `//# debugId=` comment at the end of the code.
+    ///
+    /// @see https://github.com/tc39/ecma426/blob/main/proposals/debug-id.md
+    ///
+    /// @default false
+    pub sourcemap_debug_id: Option<bool>,
 }
 
 impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
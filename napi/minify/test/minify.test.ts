@@ -1,7 +1,7 @@
 import { Worker } from 'node:worker_threads';
 import { describe, expect, it } from 'vitest';
 
-import { minify } from '../index';
+import { minify, minifyJson } from '../index';
 
 describe('simple', () => {
   const code = '/*! legal comment */\nfunction foo() { var bar; bar(undefined) } foo();';
@@ -27,6 +27,23 @@ describe('simple', () => {
     });
   });
 
+  it('supports source map options', () => {
+    const ret = minify('test.js', code, {
+      sourcemap: true,
+      sourcemapSourcesContent: false,
+      sourcemapSourceRoot: '/src/',
+      sourcemapIgnoreList: true,
+      sourcemapDebugId: true,
+    });
+    expect(ret.code).toMatch(
+      /^function foo\(\)\{var e;e\(void 0\)\}foo\(\);\n\/\/# debugId=[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$/,
+    );
+    expect(ret.map?.sourceRoot).toBe('/src/');
+    expect(ret.map?.sourcesContent ?? []).toStrictEqual([]);
+    expect(ret.map?.x_google_ignoreList).toStrictEqual([0]);
+    expect(ret.code).toContain(`//# debugId=${ret.map?.debugId}\n`);
+  });
+
   it('can turn off everything', () => {
     const ret = minify('test.js', code, { compress: false, mangle: false, codegen: { removeWhitespace: false } });
     expect(ret.code).toBe(
@@ -48,6 +65,25 @@ describe('simple', () => {
   });
 });
 
+describe('json', () => {
+  it('removes whitespace', () => {
+    const ret = minifyJson('test.json', '{ "a": [1, 2], "b": { "c": null } }\n');
+    expect(ret).toStrictEqual({ code: '{"a":[1,2],"b":{"c":null}}', errors: [] });
+  });
+
+  it('returns errors', () => {
+    const ret = minifyJson('test.json', '{ "a": undefined }');
+    expect(ret.code).toBe('');
+    expect(ret.errors.length).toBe(1);
+  });
+
+  it('hoists large literals to JSON.parse', () => {
+    const code = 'x = { a: [1, 2], b: "long string" }';
+    const ret = minify('test.js', code, { compress: { jsonParseThreshold: 10 } });
+    expect(ret.code).toMatch(/^x=JSON\.parse\(.\{"a":\[1,2\],"b":"long string"\}.\);$/);
+  });
+});
+
 describe('worker', () => {
   it('should run', async () => {
     const code = await new Promise((resolve, reject) => {
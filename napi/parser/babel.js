@@ -0,0 +1,513 @@
+'use strict';
+
+// Conversion of ESTree / TS-ESTree AST to the AST shape produced by `@babel/parser`.
+//
+// Used for `astType: 'babel'` option.
+//
+// On Rust side, `Program` is serialized in Babel form (separate `directives` and `interpreter` fields)
+// when using JSON transfer. Raw transfer produces a standard ESTree `Program`.
+// `convertToBabel` handles either.
+//
+// Conversion is performed in place where possible. Nodes which Babel does not have
+// (`ChainExpression`, `ParenthesizedExpression`, `TSParenthesizedType`) are replaced by their contents.
+
+module.exports = { convertToBabel };
+
+/**
+ * Convert ESTree `Program` to Babel `File`, and attach comments to nodes.
+ *
+ * @param {Object} program - ESTree `Program`
+ * @param {Array<Object>} comments - Comments, as returned by `parseSync`
+ * @returns {Object} - Babel `File` node
+ */
+function convertToBabel(program, comments) {
+  program = convertNode(program);
+
+  const babelComments = comments.map(convertComment);
+  attachComments(program, babelComments);
+
+  const file = {
+    type: 'File',
+    start: program.start,
+    end: program.end,
+    program,
+    comments: babelComments,
+  };
+  if (program.range !== undefined) file.range = program.range;
+  return file;
+}
+
+function convertComment(comment) {
+  const babelComment = {
+    type: comment.type === 'Line' ? 'CommentLine' : 'CommentBlock',
+    value: comment.value,
+    start: comment.start,
+    end: comment.end,
+  };
+  if (comment.range !== undefined) babelComment.range = comment.range;
+  return babelComment;
+}
+
+/**
+ * Convert a node and all its descendants. Children are converted before their parent.
+ *
+ * @param {Object} node - ESTree node
+ * @returns {Object} - Babel node (may be a different object from `node`)
+ */
+function convertNode(node) {
+  for (const key in node) {
+    const value = node[key];
+    if (value === null || typeof value !== 'object') continue;
+    if (Array.isArray(value)) {
+      for (let i = 0; i < value.length; i++) {
+        const element = value[i];
+        if (element !== null && typeof element === 'object' && typeof element.type === 'string') {
+          value[i] = convertNode(element);
+        }
+      }
+    } else if (typeof value.type === 'string') {
+      node[key] = convertNode(value);
+    }
+  }
+
+  if (Object.hasOwn(node, 'typeArguments')) {
+    node.typeParameters = node.typeArguments;
+    delete node.typeArguments;
+  }
+  if (Object.hasOwn(node, 'superTypeArguments')) {
+    node.superTypeParameters = node.superTypeArguments;
+    delete node.superTypeArguments;
+  }
+
+  const converter = CONVERTERS[node.type];
+  return converter === undefined ? node : converter(node);
+}
+
+const CONVERTERS = {
+  Program(node) {
+    splitDirectives(node);
+    if (Object.hasOwn(node, 'hashbang')) {
+      const { hashbang } = node;
+      node.interpreter = hashbang === null ? null : { ...hashbang, type: 'InterpreterDirective' };
+      delete node.hashbang;
+      // TS-ESTree `Program` starts at first statement. Babel's covers whole file.
+      node.start = 0;
+    }
+    return node;
+  },
+
+  BlockStatement(node) {
+    splitDirectives(node);
+    return node;
+  },
+
+  Literal(node) {
+    const { raw } = node;
+    delete node.raw;
+    if (node.regex) {
+      const { pattern, flags } = node.regex;
+      delete node.regex;
+      delete node.value;
+      return Object.assign(node, { type: 'RegExpLiteral', pattern, flags, extra: { raw } });
+    }
+    if (node.bigint) {
+      const value = node.bigint;
+      delete node.bigint;
+      return Object.assign(node, { type: 'BigIntLiteral', value, extra: { rawValue: value, raw } });
+    }
+    const { value } = node;
+    if (value === null) {
+      delete node.value;
+      node.type = 'NullLiteral';
+    } else if (typeof value === 'boolean') {
+      node.type = 'BooleanLiteral';
+    } else {
+      node.type = typeof value === 'number' ? 'NumericLiteral' : 'StringLiteral';
+      node.extra = { rawValue: value, raw };
+    }
+    return node;
+  },
+
+  JSXText(node) {
+    node.extra = { rawValue: node.value, raw: node.raw };
+    delete node.raw;
+    return node;
+  },
+
+  TemplateLiteral(node) {
+    // TS-ESTree `TemplateElement` spans include delimiters. Babel's do not.
+    const { quasis } = node;
+    if (quasis[0].start === node.start) {
+      for (const quasi of quasis) {
+        quasi.start++;
+        quasi.end -= quasi.tail ? 1 : 2;
+        if (quasi.range !== undefined) quasi.range = [quasi.start, quasi.end];
+      }
+    }
+    return node;
+  },
+
+  ParenthesizedExpression(node) {
+    const { expression } = node;
+    expression.extra = { ...expression.extra, parenthesized: true, parenStart: node.start };
+    return expression;
+  },
+
+  TSParenthesizedType(node) {
+    return node.typeAnnotation;
+  },
+
+  ChainExpression(node) {
+    // Collect the member / call expressions which form the chain, outermost first.
+    // `TSNonNullExpression`s within the chain (`a?.b!.c`) are skipped over.
+    const spine = [];
+    let last = -1;
+    for (let current = node.expression; current !== null;) {
+      if (current.type === 'MemberExpression') {
+        if (current.optional) last = spine.length;
+        spine.push(current);
+        current = current.object;
+      } else if (current.type === 'CallExpression') {
+        if (current.optional) last = spine.length;
+        spine.push(current);
+        current = current.callee;
+      } else if (current.type === 'TSNonNullExpression') {
+        current = current.expression;
+      } else {
+        break;
+      }
+    }
+    // Babel uses `OptionalMemberExpression` / `OptionalCallExpression` for the part of the chain
+    // from the first `?.` outwards. `x.y?.z` - `x.y` remains a `MemberExpression`.
+    for (let i = 0; i <= last; i++) {
+      const expr = spine[i];
+      expr.type = expr.type === 'MemberExpression' ? 'OptionalMemberExpression' : 'OptionalCallExpression';
+    }
+    return node.expression;
+  },
+
+  ImportExpression(node) {
+    const args = [node.source];
+    if (node.options != null) args.push(node.options);
+    const callee = { type: 'Import', start: node.start, end: node.start + 6 };
+    if (node.range !== undefined) callee.range = [callee.start, callee.end];
+    const call = { type: 'CallExpression', callee, arguments: args, optional: false, start: node.start, end: node.end };
+    if (node.range !== undefined) call.range = node.range;
+    return call;
+  },
+
+  PrivateIdentifier(node) {
+    const id = { type: 'Identifier', name: node.name, start: node.start + 1, end: node.end };
+    if (node.range !== undefined) id.range = [id.start, id.end];
+    delete node.name;
+    node.type = 'PrivateName';
+    node.id = id;
+    return node;
+  },
+
+  Property(node) {
+    const { kind, method, value } = node;
+    delete node.kind;
+    if (kind === 'init' && !method) {
+      node.type = 'ObjectProperty';
+      node.method = false;
+      return node;
+    }
+    delete node.value;
+    node.type = 'ObjectMethod';
+    node.kind = kind === 'init' ? 'method' : kind;
+    node.method = kind === 'init';
+    copyFunction(node, value);
+    return node;
+  },
+
+  MethodDefinition: convertMethod,
+  TSAbstractMethodDefinition: convertMethod,
+
+  PropertyDefinition: convertProperty,
+  TSAbstractPropertyDefinition: convertProperty,
+
+  AccessorProperty(node) {
+    convertProperty(node);
+    node.type = 'ClassAccessorProperty';
+    return node;
+  },
+
+  ExportAllDeclaration(node) {
+    if (node.exported === null) {
+      delete node.exported;
+      return node;
+    }
+    // `export * as ns from 'x'`
+    const { exported } = node;
+    const specifier = { type: 'ExportNamespaceSpecifier', exported, start: node.start + 7, end: exported.end };
+    if (node.range !== undefined) specifier.range = [specifier.start, specifier.end];
+    delete node.exported;
+    node.type = 'ExportNamedDeclaration';
+    node.declaration = null;
+    node.specifiers = [specifier];
+    return node;
+  },
+
+  TSClassImplements: convertHeritage,
+  TSInterfaceHeritage: convertHeritage,
+
+  TSTypeParameter(node) {
+    // Babel 7 represents type parameter name as a string
+    if (node.name !== null && typeof node.name === 'object') node.name = node.name.name;
+    return node;
+  },
+
+  TSMappedType(node) {
+    if (Object.hasOwn(node, 'key')) {
+      const { key, constraint } = node;
+      const typeParameter = {
+        type: 'TSTypeParameter',
+        name: key.name,
+        constraint,
+        start: key.start,
+        end: constraint === null ? key.end : constraint.end,
+      };
+      if (key.range !== undefined) typeParameter.range = [typeParameter.start, typeParameter.end];
+      delete node.key;
+      delete node.constraint;
+      node.typeParameter = typeParameter;
+    }
+    return node;
+  },
+
+  TSEnumDeclaration(node) {
+    if (Object.hasOwn(node, 'body')) {
+      node.members = node.body.members;
+      delete node.body;
+    }
+    return node;
+  },
+
+  TSEnumMember(node) {
+    delete node.computed;
+    return node;
+  },
+
+  TSModuleDeclaration(node) {
+    // TS-ESTree flattens `namespace A.B.C {}` into one node with a `TSQualifiedName` as `id`.
+    // Babel nests a `TSModuleDeclaration` for each part.
+    let { id, body } = node;
+    while (id.type === 'TSQualifiedName') {
+      const inner = {
+        type: 'TSModuleDeclaration',
+        id: id.right,
+        body,
+        kind: node.kind,
+        declare: false,
+        start: id.right.start,
+        end: node.end,
+      };
+      if (node.range !== undefined) inner.range = [inner.start, inner.end];
+      body = inner;
+      id = id.left;
+    }
+    node.id = id;
+    node.body = body;
+    return node;
+  },
+
+  TSTemplateLiteralType(node) {
+    const literal = {
+      type: 'TemplateLiteral',
+      quasis: node.quasis,
+      expressions: node.types,
+      start: node.start,
+      end: node.end,
+    };
+    if (node.range !== undefined) literal.range = node.range;
+    delete node.quasis;
+    delete node.types;
+    node.type = 'TSLiteralType';
+    node.literal = literal;
+    return node;
+  },
+
+  TSImportType(node) {
+    // TS-ESTree wraps the specifier in a `TSLiteralType`
+    if (node.argument.type === 'TSLiteralType') node.argument = node.argument.literal;
+    return node;
+  },
+
+  TSFunctionType: convertSignature,
+  TSConstructorType: convertSignature,
+  TSCallSignatureDeclaration: convertSignature,
+  TSConstructSignatureDeclaration: convertSignature,
+  TSMethodSignature: convertSignature,
+};
+
+/**
+ * Move directives at start of `body` into `directives` array.
+ * No-op if `directives` have already been separated on Rust side.
+ */
+function splitDirectives(node) {
+  if (Object.hasOwn(node, 'directives')) return;
+  const { body } = node;
+  const directives = [];
+  while (directives.length < body.length) {
+    const stmt = body[directives.length];
+    if (stmt.type !== 'ExpressionStatement' || typeof stmt.directive !== 'string') break;
+    const { expression } = stmt;
+    const value = {
+      type: 'DirectiveLiteral',
+      value: stmt.directive,
+      extra: { raw: expression.extra.raw, rawValue: stmt.directive },
+      start: expression.start,
+      end: expression.end,
+    };
+    if (expression.range !== undefined) value.range = expression.range;
+    const directive = { type: 'Directive', value, start: stmt.start, end: stmt.end };
+    if (stmt.range !== undefined) directive.range = stmt.range;
+    directives.push(directive);
+  }
+  if (directives.length > 0) body.splice(0, directives.length);
+  node.directives = directives;
+}
+
+function convertMethod(node) {
+  const { value } = node;
+  delete node.value;
+  if (node.type === 'TSAbstractMethodDefinition') node.abstract = true;
+  if (value.body === null) {
+    node.type = 'TSDeclareMethod';
+  } else {
+    node.type = node.key.type === 'PrivateName' ? 'ClassPrivateMethod' : 'ClassMethod';
+  }
+  copyFunction(node, value);
+  return node;
+}
+
+function convertProperty(node) {
+  if (node.type === 'TSAbstractPropertyDefinition') node.abstract = true;
+  node.type = node.key.type === 'PrivateName' ? 'ClassPrivateProperty' : 'ClassProperty';
+  return node;
+}
+
+/**
+ * Copy fields from `FunctionExpression` to Babel method node.
+ */
+function copyFunction(node, func) {
+  node.id = null;
+  node.generator = func.generator;
+  node.async = func.async;
+  node.params = func.params;
+  node.body = func.body;
+  if (Object.hasOwn(func, 'typeParameters')) node.typeParameters = func.typeParameters;
+  if (Object.hasOwn(func, 'returnType')) node.returnType = func.returnType;
+}
+
+/**
+ * Convert `TSClassImplements` and `TSInterfaceHeritage` to Babel `TSExpressionWithTypeArguments`.
+ * Babel represents qualified names as `TSQualifiedName`, not `MemberExpression`.
+ */
+function convertHeritage(node) {
+  node.type = 'TSExpressionWithTypeArguments';
+  node.expression = memberToQualifiedName(node.expression);
+  return node;
+}
+
+function memberToQualifiedName(node) {
+  if (node.type !== 'MemberExpression') return node;
+  const qualified = {
+    type: 'TSQualifiedName',
+    left: memberToQualifiedName(node.object),
+    right: node.property,
+    start: node.start,
+    end: node.end,
+  };
+  if (node.range !== undefined) qualified.range = node.range;
+  return qualified;
+}
+
+/**
+ * Babel uses `parameters` and `typeAnnotation` for signatures and function types,
+ * where TS-ESTree uses `params` and `returnType`.
+ */
+function convertSignature(node) {
+  node.parameters = node.params;
+  node.typeAnnotation = node.returnType;
+  delete node.params;
+  delete node.returnType;
+  return node;
+}
+
+// Comment attachment
+
+const COMMENT_KEYS = new Set(['leadingComments', 'innerComments', 'trailingComments', 'extra', 'range', 'loc']);
+
+/**
+ * Attach comments to nodes, in same way as Babel.
+ *
+ * A comment is attached as `leadingComments` of the following node and `trailingComments`
+ * of the preceding node, where those are siblings in the deepest node containing the comment.
+ * If the containing node has no children on either side, comment is attached as `innerComments`.
+ *
+ * @param {Object} node - Node
+ * @param {Array<Object>} comments - Comments within `node`, sorted by position
+ * @returns {undefined}
+ */
+function attachComments(node, comments) {
+  if (comments.length === 0) return;
+
+  const children = getChildren(node);
+  const childComments = children.map(() => []);
+
+  let childIndex = 0;
+  for (const comment of comments) {
+    // Skip children which end before the comment
+    while (childIndex < children.length && children[childIndex].end <= comment.start) childIndex++;
+
+    const child = children[childIndex];
+    if (child !== undefined && child.start <= comment.start && comment.end <= child.end) {
+      childComments[childIndex].push(comment);
+      continue;
+    }
+
+    const preceding = childIndex > 0 ? children[childIndex - 1] : null;
+    const following = child === undefined ? null : child;
+    if (following !== null) addComment(following, 'leadingComments', comment);
+    if (preceding !== null) addComment(preceding, 'trailingComments', comment);
+    if (following === null && preceding === null) addComment(node, 'innerComments', comment);
+  }
+
+  for (let i = 0; i < children.length; i++) {
+    attachComments(children[i], childComments[i]);
+  }
+}
+
+function addComment(node, key, comment) {
+  const comments = node[key];
+  if (comments === undefined) {
+    node[key] = [comment];
+  } else {
+    comments.push(comment);
+  }
+}
+
+/**
+ * Get child nodes of `node`, sorted by position.
+ */
+function getChildren(node) {
+  const children = [];
+  for (const key in node) {
+    if (COMMENT_KEYS.has(key)) continue;
+    const value = node[key];
+    if (value === null || typeof value !== 'object') continue;
+    if (Array.isArray(value)) {
+      for (const element of value) {
+        if (element !== null && typeof element === 'object' && typeof element.type === 'string') {
+          children.push(element);
+        }
+      }
+    } else if (typeof value.type === 'string') {
+      children.push(value);
+    }
+  }
+  children.sort((a, b) => a.start - b.start);
+  return children;
+}
@@ -32,6 +32,8 @@ export declare class ParseResult {
   get program(): import("@oxc-project/types").Program
   get module(): EcmaScriptModule
   get comments(): Array<Comment>
+  /** Tokens, if `tokens` option was enabled. Otherwise empty. */
+  get tokens(): Array<Token>
   get errors(): Array<OxcError>
 }
 
@@ -137,8 +139,8 @@ export declare const enum ImportNameKind {
 export declare function parseAsync(filename: string, sourceText: string, options?: ParserOptions | undefined | null): Promise<ParseResult>
 
 export interface ParserOptions {
-  /** Treat the source text as `js`, `jsx`, `ts`, `tsx` or `dts`. */
-  lang?: 'js' | 'jsx' | 'ts' | 'tsx' | 'dts'
+  /** Treat the source text as `js`, `jsx`, `ts`, `tsx`, `dts` or `flow`. */
+  lang?: 'js' | 'jsx' | 'ts' | 'tsx' | 'dts' | 'flow'
   /** Treat the source text as `script` or `module` code. */
   sourceType?: 'script' | 'module' | 'unambiguous' | undefined
   /**
@@ -147,8 +149,12 @@ export interface ParserOptions {
    * `'js'` is default for JS / JSX files.
    * `'ts'` is default for TS / TSX files.
    * The type of the file is determined from `lang` option, or extension of provided `filename`.
+   *
+   * `'babel'` returns an AST in the shape produced by `@babel/parser`, wrapped in a `File` node,
+   * with comments attached to nodes as `leadingComments`, `innerComments` and `trailingComments`.
+   * It includes TypeScript-related properties for TS / TSX files.
    */
-  astType?: 'js' | 'ts'
+  astType?: 'js' | 'ts' | 'babel'
   /**
    * Controls whether the `range` property is included on AST nodes.
    * The `range` property is a `[number, number]` which indicates the start/end offsets
@@ -175,6 +181,14 @@ export interface ParserOptions {
    * @default false
    */
   showSemanticErrors?: boolean
+  /**
+   * Return list of tokens in `tokens` property of result, in ESTree / Espree format.
+   *
+   * Not supported with `experimentalRawTransfer` or `experimentalLazy` options.
+   *
+   * @default false
+   */
+  tokens?: boolean
 }
 
 /** Parse synchronously. */
@@ -183,6 +197,11 @@ export declare function parseSync(filename: string, sourceText: string, options?
 /** Returns `true` if raw transfer is supported on this platform. */
 export declare function rawTransferSupported(): boolean
 
+export interface RegExpToken {
+  pattern: string
+  flags: string
+}
+
 export interface Span {
   start: number
   end: number
@@ -276,6 +295,20 @@ export interface StaticImportEntry {
   isType: boolean
 }
 
+/** Token, in ESTree / Espree format. */
+export interface Token {
+  type: 'Boolean' | 'Identifier' | 'JSXIdentifier' | 'JSXText' | 'Keyword' | 'Null' | 'Numeric' | 'PrivateIdentifier' | 'Punctuator' | 'RegularExpression' | 'String' | 'Template'
+  /**
+   * Source text of token.
+   * For `PrivateIdentifier`s, the name without leading `#`.
+   */
+  value: string
+  /** Pattern and flags, for `RegularExpression` tokens only. */
+  regex?: RegExpToken
+  start: number
+  end: number
+}
+
 export interface ValueSpan {
   value: string
   start: number
@@ -64,7 +64,7 @@ function parseSync(filename, sourceText, options) {
     loadRawTransferLazy();
     return parseSyncLazy(filename, sourceText, options);
   }
-  return wrap(parseSyncBinding(filename, sourceText, options));
+  return wrap(parseSyncBinding(filename, sourceText, options), options);
 }
 
 /**
@@ -96,7 +96,7 @@ async function parseAsync(filename, sourceText, options) {
     loadRawTransferLazy();
     return await parseAsyncLazy(filename, sourceText, options);
   }
-  return wrap(await parseAsyncBinding(filename, sourceText, options));
+  return wrap(await parseAsyncBinding(filename, sourceText, options), options);
 }
 
 /**
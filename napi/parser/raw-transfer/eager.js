@@ -15,7 +15,7 @@ module.exports = { parseSyncRaw, parseAsyncRaw };
 function parseSyncRaw(filename, sourceText, options) {
   let _;
   ({ experimentalRawTransfer: _, ...options } = options);
-  return parseSyncRawImpl(filename, sourceText, options, deserialize);
+  return parseSyncRawImpl(filename, sourceText, options, getDeserializer(options));
 }
 
 /**
@@ -39,10 +39,20 @@ function parseSyncRaw(filename, sourceText, options) {
 function parseAsyncRaw(filename, sourceText, options) {
   let _;
   ({ experimentalRawTransfer: _, ...options } = options);
-  return parseAsyncRawImpl(filename, sourceText, options, deserialize);
+  return parseAsyncRawImpl(filename, sourceText, options, getDeserializer(options));
 }
 
-let deserializeJS = null, deserializeTS = null;
+let deserializeJS = null, deserializeTS = null, convertToBabel = null;
+
+/**
+ * Get deserializer for the AST type requested in `options`.
+ *
+ * @param {Object} options - Parsing options
+ * @returns {function} - Deserializer
+ */
+function getDeserializer(options) {
+  return options.astType === 'babel' ? deserializeBabel : deserialize;
+}
 
 /**
  * Deserialize whole AST from buffer.
@@ -53,6 +63,76 @@ let deserializeJS = null, deserializeTS = null;
  * @returns {Object} - Object with property getters for `program`, `module`, `comments`, and `errors`
  */
 function deserialize(buffer, sourceText, sourceByteLen) {
+  const data = deserializeData(buffer, sourceText, sourceByteLen, true);
+
+  // We cannot lazily deserialize in the getters, because the buffer might be re-used to parse
+  // another file before the getter is called
+  return {
+    get program() {
+      return data.program;
+    },
+    get module() {
+      return data.module;
+    },
+    get comments() {
+      return data.comments;
+    },
+    get errors() {
+      return data.errors;
+    },
+  };
+}
+
+/**
+ * Deserialize whole AST from buffer, and convert it to Babel form.
+ *
+ * `program` is a Babel `File` node. `comments` are the same comment objects as `File`'s `comments`.
+ *
+ * @param {Uint8Array} buffer - Buffer containing AST in raw form
+ * @param {string} sourceText - Source for the file
+ * @param {number} sourceByteLen - Length of source text in UTF-8 bytes
+ * @returns {Object} - Object with property getters for `program`, `module`, `comments`, and `errors`
+ */
+function deserializeBabel(buffer, sourceText, sourceByteLen) {
+  // Babel represents hashbang as `Program::interpreter`, so don't add a comment for it
+  const data = deserializeData(buffer, sourceText, sourceByteLen, false);
+
+  // Buffer has already been returned to cache at this point, so conversion can be lazy
+  let file = null;
+  const getFile = () => {
+    if (file === null) {
+      if (convertToBabel === null) ({ convertToBabel } = require('../babel.js'));
+      file = convertToBabel(data.program, data.comments);
+    }
+    return file;
+  };
+
+  return {
+    get program() {
+      return getFile();
+    },
+    get module() {
+      return data.module;
+    },
+    get comments() {
+      return getFile().comments;
+    },
+    get errors() {
+      return data.errors;
+    },
+  };
+}
+
+/**
+ * Deserialize buffer to JS objects, and return buffer to cache.
+ *
+ * @param {Uint8Array} buffer - Buffer containing AST in raw form
+ * @param {string} sourceText - Source for the file
+ * @param {number} sourceByteLen - Length of source text in UTF-8 bytes
+ * @param {boolean} addHashbangComment - `true` to add a comment for hashbang in JS AST
+ * @returns {Object} - Object containing `program`, `module`, `comments`, and `errors`
+ */
+function deserializeData(buffer, sourceText, sourceByteLen, addHashbangComment) {
   // Lazy load deserializer, and deserialize buffer to JS objects
   let data;
   if (isJsAst(buffer)) {
@@ -61,7 +141,7 @@ function deserialize(buffer, sourceText, sourceByteLen) {
 
     // Add a line comment for hashbang
     const { hashbang } = data.program;
-    if (hashbang !== null) {
+    if (addHashbangComment && hashbang !== null) {
       data.comments.unshift({ type: 'Line', value: hashbang.value, start: hashbang.start, end: hashbang.end });
     }
   } else {
@@ -74,20 +154,5 @@ function deserialize(buffer, sourceText, sourceByteLen) {
   // Return buffer to cache, to be reused
   returnBufferToCache(buffer);
 
-  // We cannot lazily deserialize in the getters, because the buffer might be re-used to parse
-  // another file before the getter is called
-  return {
-    get program() {
-      return data.program;
-    },
-    get module() {
-      return data.module;
-    },
-    get comments() {
-      return data.comments;
-    },
-    get errors() {
-      return data.errors;
-    },
-  };
+  return data;
 }
@@ -22,6 +22,7 @@ use oxc::{
 use oxc_napi::{Comment, OxcError, convert_utf8_to_utf16, get_source_type};
 
 mod convert;
+mod tokens;
 mod types;
 pub use types::{EcmaScriptModule, ParseResult, ParserOptions};
 
@@ -59,12 +60,26 @@ use generated::raw_transfer_constants;
 enum AstType {
     JavaScript,
     TypeScript,
+    /// Babel-compatible AST. Includes TypeScript fields if the file is TypeScript.
+    Babel,
+}
+
+impl AstType {
+    /// Get whether AST includes TypeScript fields.
+    fn include_ts_fields(self, source_type: SourceType) -> bool {
+        match self {
+            AstType::JavaScript => false,
+            AstType::TypeScript => true,
+            AstType::Babel => source_type.is_typescript(),
+        }
+    }
 }
 
 fn get_ast_type(source_type: SourceType, options: &ParserOptions) -> AstType {
     match options.ast_type.as_deref() {
         Some("js") => AstType::JavaScript,
         Some("ts") => AstType::TypeScript,
+        Some("babel") => AstType::Babel,
         _ => {
             if source_type.is_javascript() {
                 AstType::JavaScript
@@ -80,10 +95,12 @@ fn parse<'a>(
     source_type: SourceType,
     source_text: &'a str,
     options: &ParserOptions,
+    collect_tokens: bool,
 ) -> ParserReturn<'a> {
     Parser::new(allocator, source_text, source_type)
         .with_options(ParseOptions {
             preserve_parens: options.preserve_parens.unwrap_or(true),
+            collect_tokens,
             ..ParseOptions::default()
         })
         .parse()
@@ -95,7 +112,7 @@ fn parse_with_return(filename: &str, source_text: String, options: &ParserOption
         get_source_type(filename, options.lang.as_deref(), options.source_type.as_deref());
     let ast_type = get_ast_type(source_type, options);
     let ranges = options.range.unwrap_or(false);
-    let ret = parse(&allocator, source_type, &source_text, options);
+    let ret = parse(&allocator, source_type, &source_text, options, options.tokens == Some(true));
 
     let mut program = ret.program;
     let mut module_record = ret.module_record;
@@ -108,6 +125,9 @@ fn parse_with_return(filename: &str, source_text: String, options: &ParserOption
 
     let mut errors = OxcError::from_diagnostics(filename, &source_text, diagnostics);
 
+    // Must happen before spans in `program` are converted to UTF-16
+    let tokens = tokens::convert_tokens(&source_text, &ret.tokens, &program);
+
     let mut comments =
         convert_utf8_to_utf16(&source_text, &mut program, &mut module_record, &mut errors);
 
@@ -135,11 +155,20 @@ fn parse_with_return(filename: &str, source_text: String, options: &ParserOption
             // See: https://github.com/typescript-eslint/typescript-eslint/issues/6500
             program.to_estree_ts_json_with_fixes(ranges)
         }
+        // Babel represents hashbang as `Program::interpreter`, not a comment.
+        // `BigInt` and `RegExp` literals do not need fixes, as Babel does not set `value` for them.
+        AstType::Babel => {
+            if source_type.is_typescript() {
+                program.to_babel_ts_json(ranges)
+            } else {
+                program.to_babel_js_json(ranges)
+            }
+        }
     };
 
     let module = EcmaScriptModule::from(&module_record);
 
-    ParseResult { program_and_fixes, module, comments, errors }
+    ParseResult { program_and_fixes, module, comments, tokens, errors }
 }
 
 /// Parse synchronously.
@@ -18,7 +18,7 @@ use oxc::{
 use oxc_napi::get_source_type;
 
 use crate::{
-    AstType, ParserOptions, get_ast_type, parse,
+    ParserOptions, get_ast_type, parse,
     raw_transfer_constants::{BLOCK_ALIGN as BUFFER_ALIGN, BUFFER_SIZE},
     raw_transfer_types::{EcmaScriptModule, Error, RawTransferData, RawTransferMetadata},
 };
@@ -220,7 +220,8 @@ unsafe fn parse_raw_impl(
         // SAFETY: Caller guarantees source occupies this region of the buffer and is valid UTF-8
         let source_text = unsafe { str::from_utf8_unchecked(source_text) };
 
-        let ret = parse(&allocator, source_type, source_text, &options);
+        // Tokens are not supported in raw transfer
+        let ret = parse(&allocator, source_type, source_text, &options, false);
         let mut program = ret.program;
         let mut comments = mem::replace(&mut program.comments, ArenaVec::new_in(&allocator));
         let mut module_record = ret.module_record;
@@ -273,7 +274,8 @@ unsafe fn parse_raw_impl(
 
     // Write metadata into end of buffer
     #[allow(clippy::cast_possible_truncation)]
-    let metadata = RawTransferMetadata::new(data_ptr as u32, ast_type == AstType::TypeScript);
+    let metadata =
+        RawTransferMetadata::new(data_ptr as u32, ast_type.include_ts_fields(source_type));
     const RAW_METADATA_OFFSET: usize = BUFFER_SIZE - RAW_METADATA_SIZE;
     const _: () = assert!(is_multiple_of(RAW_METADATA_OFFSET, BUMP_ALIGN));
     // SAFETY: `RAW_METADATA_OFFSET` is less than length of `buffer`.
@@ -0,0 +1,121 @@
+//! Conversion of tokens to ESTree / Espree format.
+
+use std::iter::Peekable;
+
+use oxc::{
+    ast::ast::{JSXAttributeName, JSXElementName, Program},
+    ast_visit::{Visit, utf8_to_utf16::Utf8ToUtf16},
+    parser::{Kind, Token as OxcToken},
+    span::{GetSpan, Span},
+};
+
+use crate::types::{RegExpToken, Token};
+
+/// Convert tokens to ESTree / Espree format, with UTF-16 offsets.
+///
+/// Must be called before spans in `program` are converted to UTF-16.
+pub fn convert_tokens(source_text: &str, tokens: &[OxcToken], program: &Program) -> Vec<Token> {
+    let mut jsx_names =
+        if program.source_type.is_jsx() { JSXNameSpans::collect(program) } else { vec![] }
+            .into_iter()
+            .peekable();
+
+    let span_converter = Utf8ToUtf16::new(source_text);
+    let mut converter = span_converter.converter();
+
+    tokens
+        .iter()
+        .map(|token| {
+            let kind = token.kind();
+            let text = token.span().source_text(source_text);
+
+            let (r#type, value, regex) = match kind {
+                Kind::PrivateIdentifier => ("PrivateIdentifier", &text[1..], None),
+                Kind::RegExp => {
+                    let pattern_end = text.rfind('/').unwrap_or(text.len());
+                    let regex = RegExpToken {
+                        pattern: text.get(1..pattern_end).unwrap_or_default().to_string(),
+                        flags: text.get(pattern_end + 1..).unwrap_or_default().to_string(),
+                    };
+                    ("RegularExpression", text, Some(regex))
+                }
+                _ if kind.is_identifier_name() && is_in_jsx_name(&mut jsx_names, token.start()) => {
+                    ("JSXIdentifier", text, None)
+                }
+                _ => (token_type(kind), text, None),
+            };
+
+            let mut span = token.span();
+            if let Some(converter) = converter.as_mut() {
+                converter.convert_span(&mut span);
+            }
+
+            Token {
+                r#type: r#type.to_string(),
+                value: value.to_string(),
+                regex,
+                start: span.start,
+                end: span.end,
+            }
+        })
+        .collect()
+}
+
+/// Get ESTree token type for a [`Kind`].
+///
+/// Follows Espree: reserved words, `let` and `static` are `Keyword`s,
+/// contextual keywords (`async`, `of`, TypeScript's `type` etc) are `Identifier`s.
+fn token_type(kind: Kind) -> &'static str {
+    match kind {
+        Kind::True | Kind::False => "Boolean",
+        Kind::Null => "Null",
+        Kind::Str => "String",
+        Kind::NoSubstitutionTemplate
+        | Kind::TemplateHead
+        | Kind::TemplateMiddle
+        | Kind::TemplateTail => "Template",
+        Kind::JSXText => "JSXText",
+        Kind::Let | Kind::Static => "Keyword",
+        _ if kind.is_number() => "Numeric",
+        _ if kind.is_reserved_keyword() => "Keyword",
+        _ if kind.is_identifier_name() => "Identifier",
+        _ => "Punctuator",
+    }
+}
+
+/// Check if token starting at `start` is within a JSX element or attribute name.
+///
+/// `spans` are in ascending order, and tokens must be checked in ascending order.
+fn is_in_jsx_name(spans: &mut Peekable<impl Iterator<Item = Span>>, start: u32) -> bool {
+    while let Some(span) = spans.peek() {
+        if span.end <= start {
+            spans.next();
+        } else {
+            return span.start <= start;
+        }
+    }
+    false
+}
+
+/// Visitor to collect spans of JSX element and attribute names, in source order.
+struct JSXNameSpans {
+    spans: Vec<Span>,
+}
+
+impl JSXNameSpans {
+    fn collect(program: &Program) -> Vec<Span> {
+        let mut visitor = Self { spans: vec![] };
+        visitor.visit_program(program);
+        visitor.spans
+    }
+}
+
+impl<'a> Visit<'a> for JSXNameSpans {
+    fn visit_jsx_element_name(&mut self, it: &JSXElementName<'a>) {
+        self.spans.push(it.span());
+    }
+
+    fn visit_jsx_attribute_name(&mut self, it: &JSXAttributeName<'a>) {
+        self.spans.push(it.span());
+    }
+}
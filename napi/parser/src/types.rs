@@ -7,8 +7,8 @@ use oxc_napi::{Comment, OxcError};
 #[napi(object)]
 #[derive(Default)]
 pub struct ParserOptions {
-    /// Treat the source text as `js`, `jsx`, `ts`, `tsx` or `dts`.
-    #[napi(ts_type = "'js' | 'jsx' | 'ts' | 'tsx' | 'dts'")]
+    /// Treat the source text as `js`, `jsx`, `ts`, `tsx`, `dts` or `flow`.
+    #[napi(ts_type = "'js' | 'jsx' | 'ts' | 'tsx' | 'dts' | 'flow'")]
     pub lang: Option<String>,
 
     /// Treat the source text as `script` or `module` code.
@@ -20,7 +20,11 @@ pub struct ParserOptions {
     /// `'js'` is default for JS / JSX files.
     /// `'ts'` is default for TS / TSX files.
     /// The type of the file is determined from `lang` option, or extension of provided `filename`.
-    #[napi(ts_type = "'js' | 'ts'")]
+    ///
+    /// `'babel'` returns an AST in the shape produced by `@babel/parser`, wrapped in a `File` node,
+    /// with comments attached to nodes as `leadingComments`, `innerComments` and `trailingComments`.
+    /// It includes TypeScript-related properties for TS / TSX files.
+    #[napi(ts_type = "'js' | 'ts' | 'babel'")]
     pub ast_type: Option<String>,
 
     /// Controls whether the `range` property is included on AST nodes.
@@ -46,6 +50,13 @@ pub struct ParserOptions {
     ///
     /// @default false
     pub show_semantic_errors: Option<bool>,
+
+    /// Return list of tokens in `tokens` property of result, in ESTree / Espree format.
+    ///
+    /// Not supported with `experimentalRawTransfer` or `experimentalLazy` options.
+    ///
+    /// @default false
+    pub tokens: Option<bool>,
 }
 
 #[napi]
@@ -53,6 +64,7 @@ pub struct ParseResult {
     pub(crate) program_and_fixes: String,
     pub(crate) module: EcmaScriptModule,
     pub(crate) comments: Vec<Comment>,
+    pub(crate) tokens: Vec<Token>,
     pub(crate) errors: Vec<OxcError>,
 }
 
@@ -73,12 +85,40 @@ impl ParseResult {
         mem::take(&mut self.comments)
     }
 
+    /// Tokens, if `tokens` option was enabled. Otherwise empty.
+    #[napi(getter)]
+    pub fn tokens(&mut self) -> Vec<Token> {
+        mem::take(&mut self.tokens)
+    }
+
     #[napi(getter)]
     pub fn errors(&mut self) -> Vec<OxcError> {
         mem::take(&mut self.errors)
     }
 }
 
+/// Token, in ESTree / Espree format.
+#[napi(object)]
+pub struct Token {
+    #[napi(
+        ts_type = "'Boolean' | 'Identifier' | 'JSXIdentifier' | 'JSXText' | 'Keyword' | 'Null' | 'Numeric' | 'PrivateIdentifier' | 'Punctuator' | 'RegularExpression' | 'String' | 'Template'"
+    )]
+    pub r#type: String,
+    /// Source text of token.
+    /// For `PrivateIdentifier`s, the name without leading `#`.
+    pub value: String,
+    /// Pattern and flags, for `RegularExpression` tokens only.
+    pub regex: Option<RegExpToken>,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[napi(object)]
+pub struct RegExpToken {
+    pub pattern: String,
+    pub flags: String,
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct EcmaScriptModule {
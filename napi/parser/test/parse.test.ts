@@ -714,6 +714,115 @@ describe('parse', () => {
   });
 });
 
+describe('tokens', () => {
+  it('are not included by default', () => {
+    const ret = parseSync('test.js', 'let x;');
+    expect(ret.tokens).toEqual([]);
+  });
+
+  it('are included when `tokens` option is true', () => {
+    const ret = parseSync('test.js', 'let a = /x/g; `t${b}`;', { tokens: true });
+    expect(ret.errors.length).toBe(0);
+    expect(ret.tokens).toEqual([
+      { type: 'Keyword', value: 'let', start: 0, end: 3 },
+      { type: 'Identifier', value: 'a', start: 4, end: 5 },
+      { type: 'Punctuator', value: '=', start: 6, end: 7 },
+      { type: 'RegularExpression', value: '/x/g', regex: { pattern: 'x', flags: 'g' }, start: 8, end: 12 },
+      { type: 'Punctuator', value: ';', start: 12, end: 13 },
+      { type: 'Template', value: '`t${', start: 14, end: 18 },
+      { type: 'Identifier', value: 'b', start: 18, end: 19 },
+      { type: 'Template', value: '}`', start: 19, end: 21 },
+      { type: 'Punctuator', value: ';', start: 21, end: 22 },
+    ]);
+  });
+
+  it('include JSX tokens', () => {
+    const ret = parseSync('test.jsx', '<a b-c="d">e</a>', { tokens: true });
+    expect(ret.errors.length).toBe(0);
+    expect(ret.tokens.map(({ type, value }) => [type, value])).toEqual([
+      ['Punctuator', '<'],
+      ['JSXIdentifier', 'a'],
+      ['JSXIdentifier', 'b-c'],
+      ['Punctuator', '='],
+      ['String', '"d"'],
+      ['Punctuator', '>'],
+      ['JSXText', 'e'],
+      ['Punctuator', '<'],
+      ['Punctuator', '/'],
+      ['JSXIdentifier', 'a'],
+      ['Punctuator', '>'],
+    ]);
+  });
+
+  it('have UTF-16 offsets', () => {
+    const ret = parseSync('test.js', "'🤨'; x", { tokens: true });
+    expect(ret.tokens).toEqual([
+      { type: 'String', value: "'🤨'", start: 0, end: 4 },
+      { type: 'Punctuator', value: ';', start: 4, end: 5 },
+      { type: 'Identifier', value: 'x', start: 6, end: 7 },
+    ]);
+  });
+});
+
+describe('`astType: "babel"`', () => {
+  it('wraps program in `File` node', () => {
+    const ret = parseSync('test.js', '"use strict";\nlet x = 1;', { astType: 'babel' });
+    expect(ret.errors.length).toBe(0);
+    // @ts-ignore
+    expect(ret.program.type).toBe('File');
+    // @ts-ignore
+    const { program } = ret.program;
+    expect(program.type).toBe('Program');
+    expect(program.directives).toHaveLength(1);
+    expect(program.directives[0].value.type).toBe('DirectiveLiteral');
+    expect(program.body).toHaveLength(1);
+    expect(program.body[0].declarations[0].init).toEqual({
+      type: 'NumericLiteral',
+      start: 22,
+      end: 23,
+      value: 1,
+      extra: { rawValue: 1, raw: '1' },
+    });
+  });
+
+  it('converts literals, properties and methods', () => {
+    const ret = parseSync('test.js', 'x = { a: "b", c() {} }; class C { #d; e() {} }', { astType: 'babel' });
+    expect(ret.errors.length).toBe(0);
+    // @ts-ignore
+    const { body } = ret.program.program;
+    const [a, c] = body[0].expression.right.properties;
+    expect(a.type).toBe('ObjectProperty');
+    expect(a.value.type).toBe('StringLiteral');
+    expect(a.value.extra).toEqual({ rawValue: 'b', raw: '"b"' });
+    expect(c.type).toBe('ObjectMethod');
+    const [d, e] = body[1].body.body;
+    expect(d.type).toBe('ClassPrivateProperty');
+    expect(d.key.type).toBe('PrivateName');
+    expect(e.type).toBe('ClassMethod');
+  });
+
+  it('converts optional chains', () => {
+    const ret = parseSync('test.js', 'a?.b.c()', { astType: 'babel' });
+    // @ts-ignore
+    const { expression } = ret.program.program.body[0];
+    expect(expression.type).toBe('OptionalCallExpression');
+    expect(expression.optional).toBe(false);
+    expect(expression.callee.type).toBe('OptionalMemberExpression');
+    expect(expression.callee.object.type).toBe('OptionalMemberExpression');
+    expect(expression.callee.object.optional).toBe(true);
+  });
+
+  it('attaches comments to nodes', () => {
+    const ret = parseSync('test.js', '// foo\nlet x; /* bar */', { astType: 'babel' });
+    // @ts-ignore
+    const { program, comments } = ret.program;
+    expect(comments.map((c) => c.type)).toEqual(['CommentLine', 'CommentBlock']);
+    expect(program.body[0].leadingComments[0].value).toBe(' foo');
+    expect(program.body[0].trailingComments[0].value).toBe(' bar ');
+    expect(ret.comments).toBe(comments);
+  });
+});
+
 describe('UTF-16 span', () => {
   it('basic', async () => {
     const code = "'🤨'";
@@ -2,10 +2,10 @@ export * from '@oxc-parser/binding-wasm32-wasi';
 import * as bindings from '@oxc-parser/binding-wasm32-wasi';
 import { wrap } from './wrap.mjs';
 
-export async function parseAsync(...args) {
-  return wrap(await bindings.parseAsync(...args));
+export async function parseAsync(filename, sourceText, options) {
+  return wrap(await bindings.parseAsync(filename, sourceText, options), options);
 }
 
 export function parseSync(filename, sourceText, options) {
-  return wrap(bindings.parseSync(filename, sourceText, options));
+  return wrap(bindings.parseSync(filename, sourceText, options), options);
 }
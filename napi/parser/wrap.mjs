@@ -1,8 +1,14 @@
 // Note: This code is repeated in `wrap.cjs`.
 // Any changes should be applied in that file too.
 
-export function wrap(result) {
-  let program, module, comments, errors;
+import babel from './babel.js';
+
+const { convertToBabel } = babel;
+
+export function wrap(result, options) {
+  if (options?.astType === 'babel') return wrapBabel(result);
+
+  let program, module, comments, tokens, errors;
   return {
     get program() {
       if (!program) program = jsonParseAst(result.program);
@@ -16,6 +22,42 @@ export function wrap(result) {
       if (!comments) comments = result.comments;
       return comments;
     },
+    get tokens() {
+      if (!tokens) tokens = result.tokens;
+      return tokens;
+    },
+    get errors() {
+      if (!errors) errors = result.errors;
+      return errors;
+    },
+  };
+}
+
+// Wrap result of parsing with `astType: 'babel'`.
+//
+// `program` is a Babel `File` node, and `comments` are the same comment objects as `File`'s `comments`,
+// which are also attached to AST nodes.
+function wrapBabel(result) {
+  let file, module, tokens, errors;
+  const getFile = () => {
+    if (!file) file = convertToBabel(JSON.parse(result.program), result.comments);
+    return file;
+  };
+  return {
+    get program() {
+      return getFile();
+    },
+    get module() {
+      if (!module) module = result.module;
+      return module;
+    },
+    get comments() {
+      return getFile().comments;
+    },
+    get tokens() {
+      if (!tokens) tokens = result.tokens;
+      return tokens;
+    },
     get errors() {
       if (!errors) errors = result.errors;
       return errors;
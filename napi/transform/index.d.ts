@@ -35,6 +35,7 @@ export interface SourceMap {
   sourcesContent?: Array<string>
   version: number
   x_google_ignoreList?: Array<number>
+  debugId?: string
 }
 export interface ArrowFunctionsOptions {
   /**
@@ -442,8 +443,8 @@ export declare function transform(filename: string, sourceText: string, options?
  * @see {@link transform}
  */
 export interface TransformOptions {
-  /** Treat the source text as `js`, `jsx`, `ts`, `tsx`, or `dts`. */
-  lang?: 'js' | 'jsx' | 'ts' | 'tsx' | 'dts'
+  /** Treat the source text as `js`, `jsx`, `ts`, `tsx`, `dts`, or `flow`. */
+  lang?: 'js' | 'jsx' | 'ts' | 'tsx' | 'dts' | 'flow'
   /** Treat the source text as `script` or `module` code. */
   sourceType?: 'script' | 'module' | 'unambiguous' | undefined
   /**
@@ -461,6 +462,30 @@ export interface TransformOptions {
    * @see {@link SourceMap}
    */
   sourcemap?: boolean
+  /**
+   * Include the source text in the `sourcesContent` field of the source map.
+   *
+   * @default true
+   */
+  sourcemapSourcesContent?: boolean
+  /** Set the `sourceRoot` field of the source map. */
+  sourcemapSourceRoot?: string
+  /**
+   * List the source in the `x_google_ignoreList` field of the source map,
+   * so that debuggers skip it, e.g. for vendor files.
+   *
+   * @default false
+   */
+  sourcemapIgnoreList?: boolean
+  /**
+   * Add a Debug ID, derived from the code, to the `debugId` field of the source map
+   * and as a `//# debugId=` comment at the end of the code.
+   *
+   * @see https://github.com/tc39/ecma426/blob/main/proposals/debug-id.md
+   *
+   * @default false
+   */
+  sourcemapDebugId?: boolean
   /** Set assumptions in order to produce smaller output. */
   assumptions?: CompilerAssumptions
   /** Configure how TypeScript is transformed. */
@@ -27,8 +27,7 @@ use oxc::{
         ReplaceGlobalDefinesConfig,
     },
 };
-use oxc_napi::{OxcError, get_source_type};
-use oxc_sourcemap::napi::SourceMap;
+use oxc_napi::{OxcError, SourceMap, get_source_type};
 
 use crate::IsolatedDeclarationsOptions;
 
@@ -86,8 +85,8 @@ pub struct TransformResult {
 #[napi(object)]
 #[derive(Default)]
 pub struct TransformOptions {
-    /// Treat the source text as `js`, `jsx`, `ts`, `tsx`, or `dts`.
-    #[napi(ts_type = "'js' | 'jsx' | 'ts' | 'tsx' | 'dts'")]
+    /// Treat the source text as `js`, `jsx`, `ts`, `tsx`, `dts`, or `flow`.
+    #[napi(ts_type = "'js' | 'jsx' | 'ts' | 'tsx' | 'dts' | 'flow'")]
     pub lang: Option<String>,
 
     /// Treat the source text as `script` or `module` code.
@@ -107,6 +106,28 @@ pub struct TransformOptions {
     /// @see {@link SourceMap}
     pub sourcemap: Option<bool>,
 
+    /// Include the source text in the `sourcesContent` field of the source map.
+    ///
+    /// @default true
+    pub sourcemap_sources_content: Option<bool>,
+
+    /// Set the `sourceRoot` field of the source map.
+    pub sourcemap_source_root: Option<String>,
+
+    /// List the source in the `x_google_ignoreList` field of the source map,
+    /// so that debuggers skip it, e.g. for vendor files.
+    ///
+    /// @default false
+    pub sourcemap_ignore_list: Option<bool>,
+
+    /// Add a Debug ID, derived from the code, to the `debugId` field of the source map
+    /// and as a `//# debugId=` comment at the end of the code.
+    ///
+    /// @see https://github.com/tc39/ecma426/blob/main/proposals/debug-id.md
+    ///
+    /// @default false
+    pub sourcemap_debug_id: Option<bool>,
+
     /// Set assumptions in order to produce smaller output.
     pub assumptions: Option<CompilerAssumptions>,
 
@@ -720,6 +741,7 @@ struct Compiler {
     isolated_declaration_options: Option<oxc::isolated_declarations::IsolatedDeclarationsOptions>,
 
     sourcemap: bool,
+    codegen_options: CodegenOptions,
 
     printed: String,
     printed_sourcemap: Option<SourceMap>,
@@ -745,6 +767,14 @@ impl Compiler {
             .map(oxc::isolated_declarations::IsolatedDeclarationsOptions::from);
 
         let sourcemap = options.as_ref().and_then(|o| o.sourcemap).unwrap_or_default();
+        let codegen_options =
+            options.as_mut().map_or_else(CodegenOptions::default, |o| CodegenOptions {
+                source_map_sources_content: o.sourcemap_sources_content.unwrap_or(true),
+                source_map_source_root: o.sourcemap_source_root.take(),
+                source_map_ignore_list: o.sourcemap_ignore_list.unwrap_or_default(),
+                source_map_debug_id: o.sourcemap_debug_id.unwrap_or_default(),
+                ..CodegenOptions::default()
+            });
 
         let define = options
             .as_mut()
@@ -791,6 +821,7 @@ impl Compiler {
             transform_options,
             isolated_declaration_options,
             sourcemap,
+            codegen_options,
             printed: String::default(),
             printed_sourcemap: None,
             declaration: None,
@@ -812,6 +843,10 @@ impl CompilerInterface for Compiler {
         self.sourcemap
     }
 
+    fn codegen_options(&self) -> Option<CodegenOptions> {
+        Some(self.codegen_options.clone())
+    }
+
     fn transform_options(&self) -> Option<&oxc::transformer::TransformOptions> {
         Some(&self.transform_options)
     }
@@ -7,18 +7,19 @@
 use std::{
     borrow::Cow,
     cmp::{Ordering, max, min},
+    fmt::Write as _,
     num,
     sync::atomic,
 };
 
 use phf_codegen::Map as PhfMapGen;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use rustc_hash::FxHashMap;
-use syn::{Expr, Ident, parse_str};
+use syn::{Expr, Ident, LitInt, parse_str};
 
 use crate::{
-    AST_MACROS_CRATE_PATH, Codegen, Generator,
+    AST_CRATE_PATH, AST_MACROS_CRATE_PATH, Codegen, Generator,
     output::{Output, output_path},
     schema::{
         Def, Discriminant, EnumDef, PointerKind, PrimitiveDef, Schema, StructDef, TypeDef, TypeId,
@@ -44,10 +45,11 @@ impl Generator for AssertLayouts {
     }
 
     /// Generate assertions that calculated layouts are correct,
-    /// and struct layout data for `oxc_ast_macros` crate.
+    /// struct layout data for `oxc_ast_macros` crate, and hash of AST layouts for `oxc_ast` crate.
     fn generate_many(&self, schema: &Schema, _codegen: &Codegen) -> Vec<Output> {
         let mut outputs = generate_assertions(schema);
         outputs.push(generate_struct_details(schema));
+        outputs.push(generate_layout_hash(schema));
         outputs
     }
 }
@@ -694,6 +696,81 @@ fn template(krate: &str, assertions_64: &TokenStream, assertions_32: &TokenStrea
     }
 }
 
+/// Crates whose types are included in layout hash generated by [`generate_layout_hash`].
+const LAYOUT_HASH_CRATES: [&str; 2] = ["oxc_ast", "oxc_regular_expression"];
+
+/// Generate hash of layouts of AST types for `oxc_ast` crate.
+///
+/// Hash covers same information as the assertions generated by [`generate_assertions`]
+/// for types in [`LAYOUT_HASH_CRATES`]: size and alignment of each type, and offsets of struct fields.
+///
+/// Used to check that a serialized AST was produced by a binary where AST types have same layouts.
+fn generate_layout_hash(schema: &Schema) -> Output {
+    fn hash(is_64: bool, schema: &Schema) -> LitInt {
+        // Build a string describing layouts of all types, in same order as `assert_layouts.rs` files
+        let mut desc = String::new();
+        for krate in LAYOUT_HASH_CRATES {
+            for type_def in &schema.types {
+                match type_def {
+                    TypeDef::Struct(struct_def) if struct_def.file(schema).krate() == krate => {
+                        let layout = struct_def.platform_layout(is_64);
+                        write!(desc, "{}:{}:{}", struct_def.name(), layout.size, layout.align)
+                            .unwrap();
+                        if !struct_def.is_foreign {
+                            for field in &struct_def.fields {
+                                if field.visibility != Visibility::Private {
+                                    let offset = field.platform_offset(is_64);
+                                    write!(desc, ",{}:{offset}", field.name()).unwrap();
+                                }
+                            }
+                        }
+                        desc.push(';');
+                    }
+                    TypeDef::Enum(enum_def) if enum_def.file(schema).krate() == krate => {
+                        let layout = enum_def.platform_layout(is_64);
+                        write!(desc, "{}:{}:{};", enum_def.name(), layout.size, layout.align)
+                            .unwrap();
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // FNV-1a
+        let hash = desc.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+        let hash = format!(
+            "0x{:04x}_{:04x}_{:04x}_{:04x}",
+            hash >> 48,
+            (hash >> 32) & 0xFFFF,
+            (hash >> 16) & 0xFFFF,
+            hash & 0xFFFF
+        );
+        LitInt::new(&hash, Span::call_site())
+    }
+
+    let hash_64 = hash(true, schema);
+    let hash_32 = hash(false, schema);
+
+    let code = quote! {
+        /// Hash of memory layouts of all AST types.
+        ///
+        /// Changes if size, alignment, or field offsets of any type in the AST change.
+        #[cfg(target_pointer_width = "64")]
+        pub const AST_LAYOUT_HASH: u64 = #hash_64;
+
+        ///@@line_break
+        /// Hash of memory layouts of all AST types.
+        ///
+        /// Changes if size, alignment, or field offsets of any type in the AST change.
+        #[cfg(target_pointer_width = "32")]
+        pub const AST_LAYOUT_HASH: u64 = #hash_32;
+    };
+
+    Output::Rust { path: output_path(AST_CRATE_PATH, "layout_hash.rs"), tokens: code }
+}
+
 /// Generate struct field orders for `oxc_ast_macros` crate.
 ///
 /// `#[ast]` macro will re-order struct fields in order we provide here.
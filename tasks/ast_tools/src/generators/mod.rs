@@ -11,6 +11,7 @@ mod formatter;
 mod get_id;
 mod raw_transfer;
 mod raw_transfer_lazy;
+mod relocate;
 mod scopes_collector;
 mod typescript;
 mod utf8_to_utf16;
@@ -25,6 +26,7 @@ pub use formatter::{
 pub use get_id::GetIdGenerator;
 pub use raw_transfer::RawTransferGenerator;
 pub use raw_transfer_lazy::RawTransferLazyGenerator;
+pub use relocate::RelocateGenerator;
 pub use scopes_collector::ScopesCollectorGenerator;
 pub use typescript::TypescriptGenerator;
 pub use utf8_to_utf16::Utf8ToUtf16ConverterGenerator;
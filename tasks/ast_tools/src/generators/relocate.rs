@@ -0,0 +1,236 @@
+//! Generator for `Relocate` trait impls.
+//!
+//! `Relocate` is used to rebase the pointers in an AST which has been copied to a new address
+//! e.g. when loading an AST from a cache file. See `oxc_allocator::Relocate`.
+//!
+//! Impls are generated for all types reachable from `Program` which either contain pointers
+//! (`Box`, `Vec`, `Atom`, `&str`), or which are contained in a `Box` or `Vec`.
+//! Fields which contain no pointers are skipped.
+
+use oxc_index::{IndexVec, index_vec};
+use proc_macro2::TokenStream;
+use quote::quote;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    Codegen, Generator,
+    output::{Output, output_path},
+    schema::{Def, EnumDef, Schema, StructDef, TypeDef, TypeId},
+};
+
+use super::define_generator;
+
+/// Generator for `Relocate` trait impls.
+pub struct RelocateGenerator;
+
+define_generator!(RelocateGenerator);
+
+impl Generator for RelocateGenerator {
+    fn generate_many(&self, schema: &Schema, _codegen: &Codegen) -> Vec<Output> {
+        let program_type_id = schema.type_names["Program"];
+
+        let mut state = State {
+            reachable: index_vec![false; schema.types.len()],
+            in_container: index_vec![false; schema.types.len()],
+        };
+        state.visit(program_type_id, false, schema);
+
+        let mut impls = FxHashMap::<&str, TokenStream>::default();
+        for type_def in &schema.types {
+            let type_id = type_def.id();
+            if !state.reachable[type_id] {
+                continue;
+            }
+            if !state.in_container[type_id] && !needs_relocation(type_def, schema) {
+                continue;
+            }
+
+            let (krate, tokens) = match type_def {
+                TypeDef::Struct(struct_def) => {
+                    (struct_def.file(schema).krate(), generate_for_struct(struct_def, schema))
+                }
+                TypeDef::Enum(enum_def) => {
+                    (enum_def.file(schema).krate(), generate_for_enum(enum_def, schema))
+                }
+                _ => continue,
+            };
+
+            impls.entry(krate).or_default().extend(tokens);
+        }
+
+        impls
+            .into_iter()
+            .map(|(krate, impls)| {
+                let output = quote! {
+                    #![allow(clippy::match_same_arms)]
+
+                    ///@@line_break
+                    use oxc_allocator::{MalformedImage, Relocate, Relocator};
+
+                    ///@@line_break
+                    use crate::ast::*;
+
+                    #impls
+                };
+
+                Output::Rust {
+                    path: output_path(&format!("crates/{krate}"), "relocate.rs"),
+                    tokens: output,
+                }
+            })
+            .collect()
+    }
+}
+
+/// State for finding types reachable from `Program`.
+struct State {
+    /// `true` for types which are reachable from `Program`
+    reachable: IndexVec<TypeId, bool>,
+    /// `true` for types which appear as `T` in a `Box<T>` or `Vec<T>`
+    in_container: IndexVec<TypeId, bool>,
+}
+
+impl State {
+    fn visit(&mut self, type_id: TypeId, is_in_container: bool, schema: &Schema) {
+        if is_in_container {
+            self.in_container[type_id] = true;
+        }
+        if self.reachable[type_id] {
+            return;
+        }
+        self.reachable[type_id] = true;
+
+        match &schema.types[type_id] {
+            TypeDef::Struct(struct_def) => {
+                for field in &struct_def.fields {
+                    self.visit(field.type_id, false, schema);
+                }
+            }
+            TypeDef::Enum(enum_def) => {
+                for variant in enum_def.all_variants(schema) {
+                    if let Some(field_type_id) = variant.field_type_id {
+                        self.visit(field_type_id, false, schema);
+                    }
+                }
+            }
+            TypeDef::Box(box_def) => self.visit(box_def.inner_type_id, true, schema),
+            TypeDef::Vec(vec_def) => self.visit(vec_def.inner_type_id, true, schema),
+            TypeDef::Option(option_def) => {
+                self.visit(option_def.inner_type_id, is_in_container, schema);
+            }
+            TypeDef::Cell(cell_def) => self.visit(cell_def.inner_type_id, is_in_container, schema),
+            TypeDef::Primitive(_) | TypeDef::Pointer(_) => {}
+        }
+    }
+}
+
+/// Get if a type contains any pointers which need to be relocated.
+///
+/// Recursion cannot loop infinitely, because types can only be recursive via a `Box` or `Vec`,
+/// and we stop at those.
+fn needs_relocation(type_def: &TypeDef, schema: &Schema) -> bool {
+    match type_def {
+        TypeDef::Struct(struct_def) => {
+            struct_def.fields.iter().any(|field| needs_relocation(field.type_def(schema), schema))
+        }
+        TypeDef::Enum(enum_def) => enum_def.all_variants(schema).any(|variant| {
+            variant
+                .field_type(schema)
+                .is_some_and(|field_type| needs_relocation(field_type, schema))
+        }),
+        TypeDef::Primitive(primitive_def) => matches!(primitive_def.name(), "&str" | "Atom"),
+        TypeDef::Option(option_def) => needs_relocation(option_def.inner_type(schema), schema),
+        TypeDef::Cell(cell_def) => needs_relocation(cell_def.inner_type(schema), schema),
+        TypeDef::Box(_) | TypeDef::Vec(_) | TypeDef::Pointer(_) => true,
+    }
+}
+
+fn generate_for_struct(struct_def: &StructDef, schema: &Schema) -> TokenStream {
+    let stmts = struct_def
+        .fields
+        .iter()
+        .filter(|field| needs_relocation(field.type_def(schema), schema))
+        .map(|field| {
+            let ident = field.ident();
+            quote!( Relocate::relocate(&mut self.#ident, relocator)?; )
+        })
+        .collect::<TokenStream>();
+
+    let body = if stmts.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            #stmts
+            Ok(())
+        }
+    };
+
+    generate_impl(&struct_def.ty_anon(schema), &body)
+}
+
+fn generate_for_enum(enum_def: &EnumDef, schema: &Schema) -> TokenStream {
+    let mut skipped_variants = vec![];
+    let mut match_arms = enum_def
+        .all_variants(schema)
+        .filter_map(|variant| {
+            let needs_relocation = variant
+                .field_type(schema)
+                .is_some_and(|field_type| needs_relocation(field_type, schema));
+            let ident = variant.ident();
+            if !needs_relocation {
+                let pattern = if variant.field_type(schema).is_some() {
+                    quote!( Self::#ident(_) )
+                } else {
+                    quote!( Self::#ident )
+                };
+                skipped_variants.push(pattern);
+                return None;
+            }
+
+            Some(quote!( Self::#ident(it) => Relocate::relocate(it, relocator), ))
+        })
+        .collect::<TokenStream>();
+
+    let body = if match_arms.is_empty() {
+        quote!()
+    } else {
+        // List skipped variants explicitly, rather than using `_`, to avoid
+        // `clippy::match_wildcard_for_single_variants` lint
+        if !skipped_variants.is_empty() {
+            match_arms.extend(quote!( #(#skipped_variants)|* => Ok(()), ));
+        }
+        quote! {
+            match self {
+                #match_arms
+            }
+        }
+    };
+
+    generate_impl(&enum_def.ty_anon(schema), &body)
+}
+
+fn generate_impl(ty: &TokenStream, body: &TokenStream) -> TokenStream {
+    if body.is_empty() {
+        return quote! {
+            ///@@line_break
+            impl Relocate for #ty {
+                #[inline]
+                unsafe fn relocate(&mut self, _relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    quote! {
+        ///@@line_break
+        impl Relocate for #ty {
+            unsafe fn relocate(&mut self, relocator: &Relocator<'_>) -> Result<(), MalformedImage> {
+                ///@ SAFETY: Caller guarantees that `self` is within the data known to `relocator`
+                unsafe {
+                    #body
+                }
+            }
+        }
+    }
+}
@@ -283,6 +283,7 @@ const GENERATORS: &[&(dyn Generator + Sync)] = &[
     &generators::VisitGenerator,
     &generators::ScopesCollectorGenerator,
     &generators::Utf8ToUtf16ConverterGenerator,
+    &generators::RelocateGenerator,
     &generators::RawTransferGenerator,
     &generators::RawTransferLazyGenerator,
     &generators::TypescriptGenerator,
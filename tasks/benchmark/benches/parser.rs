@@ -1,7 +1,8 @@
 use oxc_allocator::Allocator;
 use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
 use oxc_benchmark::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
-use oxc_parser::{ParseOptions, Parser};
+use oxc_parser::{ParseOptions, Parser, TextEdit};
+use oxc_span::Span;
 use oxc_tasks_common::TestFiles;
 
 fn bench_parser(criterion: &mut Criterion) {
@@ -29,6 +30,39 @@ fn bench_parser(criterion: &mut Criterion) {
     group.finish();
 }
 
+fn bench_parser_incremental(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("parser_incremental");
+    for file in TestFiles::minimal().files() {
+        let id = BenchmarkId::from_parameter(&file.file_name);
+        let source_text = &file.source_text;
+        let source_type = file.source_type;
+        let options = ParseOptions { parse_regular_expression: true, ..ParseOptions::default() };
+
+        // Insert a space after first `;` in second half of the file.
+        // This is a valid edit wherever the `;` is (in code, a string, comment etc).
+        let Some(offset) = source_text[source_text.len() / 2..].find(';') else { continue };
+        let offset = source_text.len() / 2 + offset + 1;
+        let edited_source_text = format!("{} {}", &source_text[..offset], &source_text[offset..]);
+        #[expect(clippy::cast_possible_truncation)]
+        let edit = TextEdit::new(Span::empty(offset as u32), " ");
+
+        let mut allocator = Allocator::default();
+        group.bench_function(id, |b| {
+            b.iter_with_setup_wrapper(|runner| {
+                allocator.reset();
+                let previous =
+                    Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+                runner.run(|| {
+                    Parser::new(&allocator, &edited_source_text, source_type)
+                        .with_options(options)
+                        .parse_incremental(previous, edit)
+                });
+            });
+        });
+    }
+    group.finish();
+}
+
 fn bench_estree(criterion: &mut Criterion) {
     let mut group = criterion.benchmark_group("estree");
     for file in TestFiles::complicated().files().iter().take(1) {
@@ -60,5 +94,5 @@ fn bench_estree(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(parser, bench_parser, bench_estree);
+criterion_group!(parser, bench_parser, bench_parser_incremental, bench_estree);
 criterion_main!(parser);
@@ -59,6 +59,9 @@ const PROMISE_TEST_PATH: &str =
 const VITEST_TEST_PATH: &str =
     "https://raw.githubusercontent.com/veritem/eslint-plugin-vitest/main/tests";
 
+const VUE_TEST_PATH: &str =
+    "https://raw.githubusercontent.com/vuejs/eslint-plugin-vue/master/tests/lib/rules";
+
 const REGEXP_TEST_PATH: &str = "https://raw.githubusercontent.com/ota-meshi/eslint-plugin-regexp/refs/heads/master/tests/lib/rules";
 
 struct TestCase {
@@ -600,6 +603,7 @@ pub enum RuleKind {
     Promise,
     Vitest,
     Regexp,
+    Vue,
 }
 
 impl RuleKind {
@@ -619,6 +623,7 @@ impl RuleKind {
             "promise" => Self::Promise,
             "vitest" => Self::Vitest,
             "regexp" => Self::Regexp,
+            "vue" => Self::Vue,
             _ => Self::ESLint,
         }
     }
@@ -642,6 +647,7 @@ impl Display for RuleKind {
             Self::Promise => "eslint-plugin-promise",
             Self::Vitest => "eslint-plugin-vitest",
             Self::Regexp => "eslint-plugin-regexp",
+            Self::Vue => "eslint-plugin-vue",
         };
         f.write_str(kind_name)
     }
@@ -671,6 +677,7 @@ fn main() {
         RuleKind::Promise => format!("{PROMISE_TEST_PATH}/{kebab_rule_name}.js"),
         RuleKind::Vitest => format!("{VITEST_TEST_PATH}/{kebab_rule_name}.test.ts"),
         RuleKind::Regexp => format!("{REGEXP_TEST_PATH}/{kebab_rule_name}.ts"),
+        RuleKind::Vue => format!("{VUE_TEST_PATH}/{kebab_rule_name}.js"),
         RuleKind::Oxc => String::new(),
     };
     let language = match rule_kind {
@@ -794,6 +801,7 @@ fn get_mod_name(rule_kind: RuleKind) -> String {
         RuleKind::Vitest => "vitest".into(),
         RuleKind::Node => "node".into(),
         RuleKind::Regexp => "regexp".into(),
+        RuleKind::Vue => "vue".into(),
     }
 }
 
@@ -1 +1,4 @@
 a1 = /a.b/s
+a2 = /a.b/gsi
+a3 = /[.]./s
+a4 = /a.b/
@@ -1 +1,4 @@
-a1 = new RegExp("a.b", "s");
+a1 = /a[\s\S]b/;
+a2 = /a[\s\S]b/gi;
+a3 = /[.][\s\S]/;
+a4 = /a.b/;
@@ -1,2 +1,6 @@
 c1 = /(?<a>b)/
 c2 = /((?<a>b)){2}/
+c3 = /(?<year>\d{4})-(?<month>\d{2})\k<year>/
+c4 = /(?<a>x)|(?<a>y)/
+c5 = /(?<a>x)\k<a>0/
+c6 = /(?<a>x)(?<b>y)\k<b>+/
@@ -1,2 +1,12 @@
-c1 = new RegExp("(?<a>b)", "");
-c2 = new RegExp("((?<a>b)){2}", "");
+c1 = babelHelpers.wrapRegExp(/(b)/, { a: 1 });
+c2 = babelHelpers.wrapRegExp(/((b)){2}/, { a: 2 });
+c3 = babelHelpers.wrapRegExp(/(\d{4})-(\d{2})\1/, {
+  year: 1,
+  month: 2
+});
+c4 = babelHelpers.wrapRegExp(/(x)|(y)/, { a: [1, 2] });
+c5 = babelHelpers.wrapRegExp(/(x)\1(?:)0/, { a: 1 });
+c6 = babelHelpers.wrapRegExp(/(x)(y)\2+/, {
+  a: 1,
+  b: 2
+});
@@ -1,2 +1,8 @@
 d1 = /\p{Emoji}/u
 d2 = /\p{Emoji}{2}/u
+d3 = /\p{ASCII_Hex_Digit}+/u
+d4 = /[\p{Script=Greek}_]/u
+d5 = /\P{ASCII}/u
+d6 = /[^\p{Lu}]/u
+d7 = /\p{Lu}/ui
+d8 = /(?:\p{ASCII_Hex_Digit})/u
@@ -1 +1,6 @@
 x2 = /./u
+x3 = /😀+/u
+x4 = /[😀-🙏a]/u
+x5 = /\u{1F600}/u
+x6 = /\W/u
+x7 = /a\d[^x]/u
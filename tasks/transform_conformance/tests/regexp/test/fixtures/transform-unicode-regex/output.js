@@ -1 +1,6 @@
-x2 = new RegExp(".", "u");
+x2 = /(?:[\x00-\t\v\f\x0E-\u2027\u202A-\uFFFF]|[\uD800-\uDBFF][\uDC00-\uDFFF])/;
+x3 = /(?:\uD83D\uDE00)+/;
+x4 = /(?:a|\uD83D[\uDE00-\uDE4F])/;
+x5 = /\uD83D\uDE00/;
+x6 = /(?:[\x00-\/:-@\[-\^`{-\uFFFF]|[\uD800-\uDBFF][\uDC00-\uDFFF])/;
+x7 = /a\d(?:[\x00-wy-\uFFFF]|[\uD800-\uDBFF][\uDC00-\uDFFF])/;
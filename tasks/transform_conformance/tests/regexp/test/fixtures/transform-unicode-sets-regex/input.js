@@ -1 +1,6 @@
 g1 = /[\p{White_Space}&&\p{ASCII}]/v
+g2 = /[\p{ASCII_Hex_Digit}--[a-f]]/v
+g3 = /[[a-z]--[aeiou]]+/v
+g4 = /[\q{abc|d}x-z]/v
+g5 = /[\&\-!]/v
+g6 = /[^[a-z]&&[^aeiou]]/v
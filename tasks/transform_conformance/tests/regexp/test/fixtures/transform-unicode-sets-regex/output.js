@@ -1 +1,6 @@
-g1 = new RegExp("[\\p{White_Space}&&\\p{ASCII}]", "v");
+g1 = /[\t-\r ]/u;
+g2 = /[0-9A-F]/u;
+g3 = /[b-df-hj-np-tv-z]+/u;
+g4 = /(?:abc|[dx-z])/u;
+g5 = /[!&\-]/u;
+g6 = /[\x00-aeiou{-\u{10FFFF}]/u;